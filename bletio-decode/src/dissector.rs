@@ -0,0 +1,550 @@
+//! Dissection of HCI packets into an annotated, human-readable output, similar to btmon.
+
+use std::fmt::{self, Write};
+use std::time::Duration;
+
+use bletio_hci::capture::PacketDirection;
+use bletio_hci::{
+    Command, CommandOpCode, ConnectionPeerAddress, Event, LeAdvertisingReport, LeMetaEvent, Packet,
+};
+use bletio_host::advertising::{AdStruct, AdvertisingData, LocalNameComplete, ServiceListComplete};
+
+use crate::hex::format_hex;
+
+const HEADER_INDENT: &str = "      ";
+const FIELD_INDENT: &str = "        ";
+
+/// Dissector writing the description of the HCI packets it is given.
+#[derive(Debug, Default)]
+pub(crate) struct Dissector {
+    output: String,
+}
+
+impl Dissector {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the description of all the packets that have been dissected.
+    pub(crate) fn output(&self) -> &str {
+        &self.output
+    }
+
+    /// Take the description of the packets that have been dissected so far.
+    pub(crate) fn take_output(&mut self) -> String {
+        std::mem::take(&mut self.output)
+    }
+
+    /// Dissect all the HCI packets contained in the given bytes, each of them being preceded by
+    /// its HCI packet indicator.
+    ///
+    /// The direction of the data packets is only known if it is given, the direction of the
+    /// commands and events being implicit. The timestamp is displayed if it is given.
+    pub(crate) fn dissect(
+        &mut self,
+        bytes: &[u8],
+        direction: Option<PacketDirection>,
+        timestamp: Option<Duration>,
+    ) {
+        let mut input = bytes;
+        while !input.is_empty() {
+            match Packet::parse(input) {
+                Ok((remaining, packet)) => {
+                    let raw = &input[..input.len() - remaining.len()];
+                    // Writing to a string cannot fail.
+                    self.packet(&packet, raw, direction, timestamp).unwrap();
+                    input = remaining;
+                }
+                Err(_) => {
+                    writeln!(self.output, "! Invalid HCI packet: {}", format_hex(input)).unwrap();
+                    break;
+                }
+            }
+        }
+    }
+
+    fn header(&mut self, line: fmt::Arguments, timestamp: Option<Duration>) -> fmt::Result {
+        write!(self.output, "{line}")?;
+        if let Some(timestamp) = timestamp {
+            write!(
+                self.output,
+                "  [{}.{:06}]",
+                timestamp.as_secs(),
+                timestamp.subsec_micros()
+            )?;
+        }
+        writeln!(self.output)
+    }
+
+    fn field(&mut self, indent: usize, line: fmt::Arguments) -> fmt::Result {
+        writeln!(self.output, "{FIELD_INDENT}{:indent$}{line}", "")
+    }
+
+    /// Write the pretty debug representation of a value, indented as a field.
+    fn debug_fields(&mut self, value: &impl fmt::Debug) -> fmt::Result {
+        for line in format!("{value:#?}").lines() {
+            writeln!(self.output, "{FIELD_INDENT}{line}")?;
+        }
+        Ok(())
+    }
+
+    fn packet(
+        &mut self,
+        packet: &Packet,
+        raw: &[u8],
+        direction: Option<PacketDirection>,
+        timestamp: Option<Duration>,
+    ) -> fmt::Result {
+        match packet {
+            Packet::Command(command) => self.command(command, raw, timestamp),
+            Packet::Event(event) => self.event(event, raw, timestamp),
+            Packet::AclData(_) => {
+                let (handle, flags) = handle_and_flags(raw);
+                let (marker, suffix) = data_direction(direction);
+                self.header(
+                    format_args!(
+                        "{marker} ACL Data{suffix}: Handle {handle} flags 0x{flags:02x} dlen {}",
+                        u16::from_le_bytes([raw[3], raw[4]])
+                    ),
+                    timestamp,
+                )?;
+                self.field(0, format_args!("{}", format_hex(&raw[5..])))
+            }
+            Packet::IsoData(iso_data) => {
+                let (handle, flags) = handle_and_flags(raw);
+                let (marker, suffix) = data_direction(direction);
+                self.header(
+                    format_args!(
+                        "{marker} ISO Data{suffix}: Handle {handle} flags 0x{flags:02x} dlen {}",
+                        u16::from_le_bytes([raw[3], raw[4]]) & 0x3FFF
+                    ),
+                    timestamp,
+                )?;
+                if let Some(sequence_number) = iso_data.packet_sequence_number() {
+                    self.field(0, format_args!("Sequence number: {sequence_number}"))?;
+                }
+                self.field(0, format_args!("{}", format_hex(iso_data.data())))
+            }
+        }
+    }
+
+    fn command(
+        &mut self,
+        command: &Command,
+        raw: &[u8],
+        timestamp: Option<Duration>,
+    ) -> fmt::Result {
+        self.header(
+            format_args!(
+                "< HCI Command: {} plen {}",
+                OpCode(command.opcode()),
+                raw[3]
+            ),
+            timestamp,
+        )?;
+        match command {
+            Command::LeSetAdvertisingData(data) | Command::LeSetScanResponseData(data) => {
+                self.field(0, format_args!("Length: {}", data.data()[0]))?;
+                self.ad_structs(&AdvertisingData::from(data.clone()))
+            }
+            Command::Unsupported(_) => self.field(0, format_args!("{}", format_hex(&raw[4..]))),
+            _ if raw[3] > 0 => self.debug_fields(command),
+            _ => Ok(()),
+        }
+    }
+
+    fn event(&mut self, event: &Event, raw: &[u8], timestamp: Option<Duration>) -> fmt::Result {
+        let name = match event {
+            Event::DisconnectionComplete(_) => "Disconnection Complete",
+            Event::CommandComplete(_) => "Command Complete",
+            Event::CommandStatus(_) => "Command Status",
+            Event::HardwareError(_) => "Hardware Error",
+            Event::NumberOfCompletedPackets(_) => "Number of Completed Packets",
+            Event::LeMeta(_) => "LE Meta Event",
+            Event::Unsupported(_) => "Unknown",
+        };
+        self.header(
+            format_args!("> HCI Event: {name} (0x{:02x}) plen {}", raw[1], raw[2]),
+            timestamp,
+        )?;
+        match event {
+            Event::CommandComplete(event) => {
+                writeln!(
+                    self.output,
+                    "{HEADER_INDENT}{} ncmd {}",
+                    OpCode(event.opcode()),
+                    event.num_hci_command_packets()
+                )?;
+                if event.opcode() != CommandOpCode::Nop {
+                    let status = event.status();
+                    self.field(
+                        0,
+                        format_args!("Status: {status:?} (0x{:02x})", u8::from(status)),
+                    )?;
+                }
+                if raw.len() > 7 {
+                    self.debug_fields(event)?;
+                }
+                Ok(())
+            }
+            Event::CommandStatus(event) => {
+                writeln!(
+                    self.output,
+                    "{HEADER_INDENT}{} ncmd {}",
+                    OpCode(event.opcode()),
+                    event.num_hci_command_packets()
+                )?;
+                let status = event.status();
+                self.field(
+                    0,
+                    format_args!("Status: {status:?} (0x{:02x})", u8::from(status)),
+                )
+            }
+            Event::LeMeta(event) => self.le_meta_event(event, raw),
+            Event::DisconnectionComplete(event) => self.debug_fields(event),
+            Event::HardwareError(event) => {
+                self.field(0, format_args!("Code: 0x{:02x}", event.hardware_code()))
+            }
+            Event::NumberOfCompletedPackets(event) => {
+                self.field(
+                    0,
+                    format_args!("Num handles: {}", event.completed_packets().len()),
+                )?;
+                for completed_packets in event.completed_packets() {
+                    self.field(
+                        0,
+                        format_args!("Handle: {}", completed_packets.connection_handle().value()),
+                    )?;
+                    self.field(
+                        0,
+                        format_args!("Count: {}", completed_packets.num_completed_packets()),
+                    )?;
+                }
+                Ok(())
+            }
+            Event::Unsupported(_) => self.field(0, format_args!("{}", format_hex(&raw[3..]))),
+        }
+    }
+
+    fn le_meta_event(&mut self, event: &LeMetaEvent, raw: &[u8]) -> fmt::Result {
+        let name = match event {
+            LeMetaEvent::LeConnectionComplete(_) => "LE Connection Complete",
+            LeMetaEvent::LeAdvertisingReport(_) => "LE Advertising Report",
+            LeMetaEvent::LeConnectionUpdateComplete(_) => "LE Connection Update Complete",
+            LeMetaEvent::LeCreateBigComplete(_) => "LE Create BIG Complete",
+            LeMetaEvent::LeTerminateBigComplete(_) => "LE Terminate BIG Complete",
+            LeMetaEvent::LeBigSyncEstablished(_) => "LE BIG Sync Established",
+            LeMetaEvent::LeBigSyncLost(_) => "LE BIG Sync Lost",
+            LeMetaEvent::LeBiginfoAdvertisingReport(_) => "LE BIGInfo Advertising Report",
+            LeMetaEvent::Unsupported(_) => "Unknown",
+        };
+        writeln!(self.output, "{HEADER_INDENT}{name} (0x{:02x})", raw[3])?;
+        match event {
+            LeMetaEvent::LeAdvertisingReport(reports) => {
+                self.field(0, format_args!("Num reports: {}", reports.len()))?;
+                for report in reports.iter() {
+                    self.advertising_report(&report)?;
+                }
+                Ok(())
+            }
+            LeMetaEvent::Unsupported(_) => self.field(0, format_args!("{}", format_hex(&raw[4..]))),
+            _ => self.debug_fields(event),
+        }
+    }
+
+    fn advertising_report(&mut self, report: &LeAdvertisingReport) -> fmt::Result {
+        self.field(0, format_args!("Event type: {:?}", report.event_type()))?;
+        self.field(0, format_args!("Address: {}", Address(report.address())))?;
+        let data = AdvertisingData::from(report.data());
+        self.field(0, format_args!("Data length: {}", data_length(&data)))?;
+        self.ad_structs(&data)?;
+        match report.rssi() {
+            Some(rssi) => self.field(0, format_args!("RSSI: {} dBm", rssi.value())),
+            None => self.field(0, format_args!("RSSI: not available")),
+        }
+    }
+
+    fn ad_structs(&mut self, data: &AdvertisingData) -> fmt::Result {
+        for ad_struct in data.iter() {
+            match ad_struct {
+                AdStruct::Appearance(appearance) => {
+                    let value = appearance.value();
+                    self.field(
+                        0,
+                        format_args!("Appearance: {} (0x{:04x})", value.name(), u16::from(value)),
+                    )?;
+                }
+                AdStruct::Flags(flags) => {
+                    let value = flags.value();
+                    self.field(0, format_args!("Flags: 0x{:02x}", value.bits()))?;
+                    for (name, _) in value.iter_names() {
+                        self.field(2, format_args!("{name}"))?;
+                    }
+                }
+                AdStruct::LocalName(local_name) => {
+                    let complete = match local_name.complete() {
+                        LocalNameComplete::Complete => "complete",
+                        LocalNameComplete::Shortened(_) => "short",
+                    };
+                    self.field(0, format_args!("Name ({complete}): {}", local_name.value()))?;
+                }
+                AdStruct::ManufacturerSpecificData(data) => {
+                    let company = data.manufacturer();
+                    self.field(
+                        0,
+                        format_args!("Company: {} ({})", company.name(), u16::from(company)),
+                    )?;
+                    self.field(2, format_args!("Data: {}", format_hex(data.data())))?;
+                }
+                AdStruct::ServiceDataUuid16(data) => {
+                    let uuid = data.uuid();
+                    self.field(
+                        0,
+                        format_args!("Service Data: {} (0x{:04x})", uuid.name(), u16::from(uuid)),
+                    )?;
+                    self.field(2, format_args!("Data: {}", format_hex(data.data())))?;
+                }
+                AdStruct::ServiceUuid16(uuids) => {
+                    self.field(
+                        0,
+                        format_args!(
+                            "16-bit Service UUIDs ({}): {} entr{}",
+                            service_list(uuids.complete()),
+                            uuids.len(),
+                            if uuids.len() == 1 { "y" } else { "ies" }
+                        ),
+                    )?;
+                    for uuid in uuids.iter() {
+                        self.field(
+                            2,
+                            format_args!("{} (0x{:04x})", uuid.name(), u16::from(*uuid)),
+                        )?;
+                    }
+                }
+                AdStruct::TxPowerLevel(tx_power_level) => {
+                    self.field(
+                        0,
+                        format_args!("TX power: {} dBm", tx_power_level.value().value()),
+                    )?;
+                }
+                AdStruct::Unhandled(unhandled) => {
+                    self.field(
+                        0,
+                        format_args!("Unknown AD type (0x{:02x})", unhandled.ad_type()),
+                    )?;
+                    self.field(2, format_args!("Data: {}", format_hex(unhandled.data())))?;
+                }
+                ad_struct => self.field(0, format_args!("{ad_struct:?}"))?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Opcode of a command with its name, its OGF and its OCF.
+struct OpCode(CommandOpCode);
+
+impl fmt::Display for OpCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = u16::from(self.0);
+        match self.0 {
+            CommandOpCode::Unsupported(_) => f.write_str("Unknown")?,
+            opcode => f.write_str(&humanize(&format!("{opcode:?}")))?,
+        }
+        write!(f, " (0x{:02x}|0x{:04x})", value >> 10, value & 0x03FF)
+    }
+}
+
+struct Address<'a>(&'a ConnectionPeerAddress);
+
+impl fmt::Display for Address<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = self.0.value();
+        for (i, byte) in value.iter().rev().enumerate() {
+            if i != 0 {
+                f.write_str(":")?;
+            }
+            write!(f, "{byte:02X}")?;
+        }
+        let kind = match self.0 {
+            ConnectionPeerAddress::PublicDevice(_) => "Public",
+            ConnectionPeerAddress::RandomDevice(_) => "Random",
+            ConnectionPeerAddress::PublicIdentity(_) => "Public Identity",
+            ConnectionPeerAddress::RandomIdentity(_) => "Random Identity",
+        };
+        write!(f, " ({kind})")
+    }
+}
+
+/// Turn a variant name, such as `LeSetAdvertisingParameters`, into words, such as
+/// `LE Set Advertising Parameters`.
+fn humanize(name: &str) -> String {
+    let mut words: Vec<String> = Vec::new();
+    for c in name.chars() {
+        match words.last_mut() {
+            Some(word) if !c.is_ascii_uppercase() => word.push(c),
+            _ => words.push(c.to_string()),
+        }
+    }
+    words
+        .into_iter()
+        .map(|word| match word.as_str() {
+            "Le" | "Bd" | "Big" | "Acl" => word.to_ascii_uppercase(),
+            _ => word,
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn service_list(complete: ServiceListComplete) -> &'static str {
+    match complete {
+        ServiceListComplete::Complete => "complete",
+        ServiceListComplete::Incomplete => "partial",
+    }
+}
+
+fn data_length(data: &AdvertisingData) -> u8 {
+    bletio_hci::AdvertisingData::from(data).data()[0]
+}
+
+fn data_direction(direction: Option<PacketDirection>) -> (char, &'static str) {
+    match direction {
+        Some(PacketDirection::HostToController) => ('<', " TX"),
+        Some(PacketDirection::ControllerToHost) => ('>', " RX"),
+        None => ('=', ""),
+    }
+}
+
+fn handle_and_flags(raw: &[u8]) -> (u16, u8) {
+    let value = u16::from_le_bytes([raw[1], raw[2]]);
+    (value & 0x0FFF, (value >> 12) as u8)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hex::parse_hex;
+
+    fn dissect(hex: &str) -> String {
+        let mut dissector = Dissector::new();
+        dissector.dissect(&parse_hex(hex).unwrap(), None, None);
+        dissector.take_output()
+    }
+
+    #[test]
+    fn test_humanize() {
+        assert_eq!(
+            humanize("LeSetAdvertisingParameters"),
+            "LE Set Advertising Parameters"
+        );
+        assert_eq!(humanize("ReadBdAddr"), "Read BD Addr");
+        assert_eq!(humanize("Reset"), "Reset");
+    }
+
+    #[test]
+    fn test_dissect_command_without_parameters() {
+        assert_eq!(
+            dissect("01 03 0c 00"),
+            "< HCI Command: Reset (0x03|0x0003) plen 0\n"
+        );
+    }
+
+    #[test]
+    fn test_dissect_command_with_parameters() {
+        assert_eq!(
+            dissect("01 0c 20 02 01 00"),
+            "< HCI Command: LE Set Scan Enable (0x08|0x000c) plen 2\n        LeSetScanEnable(\n            Enabled,\n            Disabled,\n        )\n"
+        );
+    }
+
+    #[test]
+    fn test_dissect_set_advertising_data() {
+        let output = dissect(
+            "01 08 20 20 0e 02 01 06 03 19 40 00 06 ff 4c 00 01 02 03 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00",
+        );
+        assert_eq!(
+            output,
+            "< HCI Command: LE Set Advertising Data (0x08|0x0008) plen 32\n        Length: 14\n        Flags: 0x06\n          LE_GENERAL_DISCOVERABLE_MODE\n          BREDR_NOT_SUPPORTED\n        Appearance: Generic Phone (0x0040)\n        Company: Apple, Inc. (76)\n          Data: 01 02 03\n"
+        );
+    }
+
+    #[test]
+    fn test_dissect_set_advertising_data_with_unknown_ad_type() {
+        let output = dissect(
+            "01 08 20 20 07 02 01 06 03 50 ab cd 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00",
+        );
+        assert_eq!(
+            output,
+            "< HCI Command: LE Set Advertising Data (0x08|0x0008) plen 32\n        Length: 7\n        Flags: 0x06\n          LE_GENERAL_DISCOVERABLE_MODE\n          BREDR_NOT_SUPPORTED\n        Unknown AD type (0x50)\n          Data: ab cd\n"
+        );
+    }
+
+    #[test]
+    fn test_dissect_command_complete() {
+        assert_eq!(
+            dissect("04 0e 04 01 03 0c 00"),
+            "> HCI Event: Command Complete (0x0e) plen 4\n      Reset (0x03|0x0003) ncmd 1\n        Status: Success (0x00)\n"
+        );
+        assert_eq!(
+            dissect("04 0e 03 01 00 00"),
+            "> HCI Event: Command Complete (0x0e) plen 3\n      Nop (0x00|0x0000) ncmd 1\n"
+        );
+    }
+
+    #[test]
+    fn test_dissect_command_status() {
+        assert_eq!(
+            dissect("04 0f 04 0c 01 06 04"),
+            "> HCI Event: Command Status (0x0f) plen 4\n      Disconnect (0x01|0x0006) ncmd 1\n        Status: CommandDisallowed (0x0c)\n"
+        );
+    }
+
+    #[test]
+    fn test_dissect_advertising_report() {
+        let output = dissect(
+            "04 3e 19 02 01 00 01 01 02 03 04 05 c6 0d 02 01 06 05 08 62 6c 65 74 03 03 0d 18 c4",
+        );
+        assert_eq!(
+            output,
+            "> HCI Event: LE Meta Event (0x3e) plen 25\n      LE Advertising Report (0x02)\n        Num reports: 1\n        Event type: ConnectableUndirected\n        Address: C6:05:04:03:02:01 (Random)\n        Data length: 13\n        Flags: 0x06\n          LE_GENERAL_DISCOVERABLE_MODE\n          BREDR_NOT_SUPPORTED\n        Name (short): blet\n        16-bit Service UUIDs (complete): 1 entry\n          Heart Rate (0x180d)\n        RSSI: -60 dBm\n"
+        );
+    }
+
+    #[test]
+    fn test_dissect_number_of_completed_packets() {
+        assert_eq!(
+            dissect("04 13 05 01 01 00 02 00"),
+            "> HCI Event: Number of Completed Packets (0x13) plen 5\n        Num handles: 1\n        Handle: 1\n        Count: 2\n"
+        );
+    }
+
+    #[test]
+    fn test_dissect_unknown_event() {
+        assert_eq!(
+            dissect("04 08 03 00 01 00"),
+            "> HCI Event: Unknown (0x08) plen 3\n        00 01 00\n"
+        );
+    }
+
+    #[test]
+    fn test_dissect_acl_data() {
+        let mut dissector = Dissector::new();
+        dissector.dissect(
+            &parse_hex("02 01 20 03 00 aa bb cc").unwrap(),
+            Some(PacketDirection::ControllerToHost),
+            Some(Duration::from_micros(1_500_002)),
+        );
+        assert_eq!(
+            dissector.output(),
+            "> ACL Data RX: Handle 1 flags 0x02 dlen 3  [1.500002]\n        aa bb cc\n"
+        );
+    }
+
+    #[test]
+    fn test_dissect_several_packets() {
+        assert_eq!(
+            dissect("01 03 0c 00 04 0e 04 01 03 0c 00 ff 00"),
+            "< HCI Command: Reset (0x03|0x0003) plen 0\n> HCI Event: Command Complete (0x0e) plen 4\n      Reset (0x03|0x0003) ncmd 1\n        Status: Success (0x00)\n! Invalid HCI packet: ff 00\n"
+        );
+    }
+}
@@ -0,0 +1,100 @@
+//! Parsing of hexadecimal dumps.
+
+use std::fmt;
+
+/// Error occurring when parsing an hexadecimal dump.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum HexError {
+    /// The given character is not an hexadecimal digit nor a separator.
+    InvalidCharacter(char),
+    /// The number of hexadecimal digits is odd.
+    OddLength,
+}
+
+impl fmt::Display for HexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidCharacter(c) => write!(f, "invalid character {c:?} in hex dump"),
+            Self::OddLength => f.write_str("odd number of hex digits in hex dump"),
+        }
+    }
+}
+
+impl std::error::Error for HexError {}
+
+fn is_separator(c: char) -> bool {
+    c.is_whitespace() || matches!(c, ',' | ':' | '-' | ';')
+}
+
+/// Parse an hexadecimal dump, such as `01 03 0c 00`, `01030C00` or `0x01, 0x03, 0x0c, 0x00`.
+///
+/// A byte written with a `0x` prefix may have a single digit.
+pub(crate) fn parse_hex(input: &str) -> Result<Vec<u8>, HexError> {
+    let mut digits = Vec::new();
+    for token in input.split(is_separator).filter(|token| !token.is_empty()) {
+        let (token, prefixed) = match token
+            .strip_prefix("0x")
+            .or_else(|| token.strip_prefix("0X"))
+        {
+            Some(token) => (token, true),
+            None => (token, false),
+        };
+        let token_digits = token
+            .chars()
+            .map(|c| c.to_digit(16).ok_or(HexError::InvalidCharacter(c)))
+            .collect::<Result<Vec<_>, _>>()?;
+        if prefixed && token_digits.len() % 2 == 1 {
+            digits.push(0);
+        }
+        digits.extend(token_digits);
+    }
+    if digits.len() % 2 == 1 {
+        return Err(HexError::OddLength);
+    }
+    Ok(digits
+        .chunks(2)
+        .map(|pair| (pair[0] << 4 | pair[1]) as u8)
+        .collect())
+}
+
+/// Format bytes as an hexadecimal dump, such as `01 03 0c 00`.
+pub(crate) fn format_hex(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_hex() {
+        let expected = vec![0x01, 0x03, 0x0C, 0x00];
+        for input in [
+            "01 03 0c 00",
+            "01030C00",
+            "0x01, 0x03, 0x0c, 0x00",
+            "0x1 0x3 0xc 0x0",
+            "01:03:0c:00\n",
+            "  01-03 0c00  ",
+        ] {
+            assert_eq!(parse_hex(input), Ok(expected.clone()), "{input}");
+        }
+        assert_eq!(parse_hex(""), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_parse_hex_failure() {
+        assert_eq!(parse_hex("01 03 0g"), Err(HexError::InvalidCharacter('g')));
+        assert_eq!(parse_hex("01 03 0"), Err(HexError::OddLength));
+    }
+
+    #[test]
+    fn test_format_hex() {
+        assert_eq!(format_hex(&[0x01, 0x03, 0x0C, 0x00]), "01 03 0c 00");
+        assert_eq!(format_hex(&[]), "");
+    }
+}
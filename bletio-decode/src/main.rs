@@ -0,0 +1,100 @@
+//! Decoder of HCI traffic, turning hex dumps or BTSnoop captures into a human-readable output,
+//! similar to btmon.
+//!
+//! ```text
+//! bletio-decode 01 03 0c 00
+//! bletio-decode --btsnoop capture.btsnoop
+//! echo "04 0e 04 01 03 0c 00" | bletio-decode
+//! ```
+
+mod dissector;
+mod hex;
+
+use std::io::{self, BufRead, Write};
+use std::process::ExitCode;
+
+use bletio_hci::capture::{BtSnoopReader, PacketDirection};
+
+use dissector::Dissector;
+use hex::parse_hex;
+
+const USAGE: &str = "\
+Usage: bletio-decode [HEX]...
+       bletio-decode --btsnoop <FILE>
+
+Decode HCI packets, each of them being preceded by its HCI packet indicator (H4 format).
+
+Without any argument, the hex dumps are read from the standard input, one per line. A line
+may start with '<' (host to controller) or '>' (controller to host) to give the direction of
+the data packets.
+
+Options:
+  -b, --btsnoop <FILE>  Decode a BTSnoop capture (HCI UART datalink)
+  -h, --help            Print this help
+";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("-h" | "--help") => {
+            print!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Some("-b" | "--btsnoop") => match args.get(1) {
+            Some(path) if args.len() == 2 => decode_btsnoop(path),
+            _ => {
+                eprint!("{USAGE}");
+                return ExitCode::FAILURE;
+            }
+        },
+        Some(_) => decode_hex(&args.join(" ")),
+        None => decode_stdin(),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("bletio-decode: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn decode_hex(input: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let mut dissector = Dissector::new();
+    dissector.dissect(&parse_hex(input)?, None, None);
+    io::stdout().write_all(dissector.output().as_bytes())?;
+    Ok(())
+}
+
+fn decode_stdin() -> Result<(), Box<dyn std::error::Error>> {
+    let mut dissector = Dissector::new();
+    let mut stdout = io::stdout().lock();
+    for line in io::stdin().lock().lines() {
+        let line = line?;
+        let (direction, hex) = match line.trim_start().split_at_checked(1) {
+            Some(("<", hex)) => (Some(PacketDirection::HostToController), hex),
+            Some((">", hex)) => (Some(PacketDirection::ControllerToHost), hex),
+            _ => (None, line.as_str()),
+        };
+        match parse_hex(hex) {
+            Ok(bytes) => dissector.dissect(&bytes, direction, None),
+            Err(err) => eprintln!("bletio-decode: {err}: {line}"),
+        }
+        stdout.write_all(dissector.take_output().as_bytes())?;
+    }
+    Ok(())
+}
+
+fn decode_btsnoop(path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let mut dissector = Dissector::new();
+    let mut stdout = io::stdout().lock();
+    let mut start = None;
+    for record in BtSnoopReader::open(path)? {
+        let record = record?;
+        let start = *start.get_or_insert(record.timestamp);
+        let timestamp = record.timestamp.duration_since(start).unwrap_or_default();
+        dissector.dissect(&record.packet, Some(record.direction), Some(timestamp));
+        stdout.write_all(dissector.take_output().as_bytes())?;
+    }
+    Ok(())
+}
@@ -1,14 +1,16 @@
-use bletio_utils::{Buffer, BufferOps};
-use num_enum::TryFromPrimitive;
+use bletio_utils::{Buffer, BufferOps, EncodeToBuffer, Error as UtilsError};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
 
 use crate::{ConnectionHandle, Error};
 
-const ACL_DATA_MAX_SIZE: usize = 27;
+/// Default maximum size of the data of an ACL data packet, corresponding to the LE ACL data
+/// packets length supported by all Controllers.
+pub const ACL_DATA_MAX_SIZE: usize = 27;
 
 /// Packet boundary flag of an ACL data packet.
 ///
 /// See [Core Specification 6.0, Vol. 4, Part E, 5.4.2](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host-controller-interface/host-controller-interface-functional-specification.html#UUID-bc4ffa33-44ef-e93c-16c8-14aa99597cfc).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoPrimitive, TryFromPrimitive)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[num_enum(error_type(name = Error, constructor = Error::InvalidPacketBoundaryFlag))]
 #[repr(u8)]
@@ -27,7 +29,7 @@ pub enum PacketBoundaryFlag {
 /// Broadcast flag of an ACL data packet.
 ///
 /// See [Core Specification 6.0, Vol. 4, Part E, 5.4.2](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host-controller-interface/host-controller-interface-functional-specification.html#UUID-bc4ffa33-44ef-e93c-16c8-14aa99597cfc).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoPrimitive, TryFromPrimitive)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[num_enum(error_type(name = Error, constructor = Error::InvalidBroadcastFlag))]
 #[repr(u8)]
@@ -39,20 +41,20 @@ pub enum BroadcastFlag {
     BrEdrBroadcast,
 }
 
-/// ACL data packet.
+/// ACL data packet, able to hold up to `MAX_SIZE` bytes of data.
 ///
 /// See [Core Specification 6.0, Vol. 4, Part E, 5.4.2](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host-controller-interface/host-controller-interface-functional-specification.html#UUID-bc4ffa33-44ef-e93c-16c8-14aa99597cfc).
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub struct AclData {
+pub struct AclData<const MAX_SIZE: usize = ACL_DATA_MAX_SIZE> {
     handle: ConnectionHandle,
     packet_boundary_flag: PacketBoundaryFlag,
     broadcast_flag: BroadcastFlag,
-    data: Buffer<ACL_DATA_MAX_SIZE>,
+    data: Buffer<MAX_SIZE>,
 }
 
-impl AclData {
-    pub(crate) fn try_new(
+impl<const MAX_SIZE: usize> AclData<MAX_SIZE> {
+    pub fn try_new(
         handle: ConnectionHandle,
         packet_boundary_flag: PacketBoundaryFlag,
         broadcast_flag: BroadcastFlag,
@@ -69,6 +71,39 @@ impl AclData {
             .map_err(|_| Error::DataWillNotFitAclDataPacket)?;
         Ok(s)
     }
+
+    pub fn handle(&self) -> ConnectionHandle {
+        self.handle
+    }
+
+    pub fn packet_boundary_flag(&self) -> PacketBoundaryFlag {
+        self.packet_boundary_flag
+    }
+
+    pub fn broadcast_flag(&self) -> BroadcastFlag {
+        self.broadcast_flag
+    }
+
+    pub fn data(&self) -> &[u8] {
+        self.data.data()
+    }
+}
+
+impl<const MAX_SIZE: usize> EncodeToBuffer for AclData<MAX_SIZE> {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, UtilsError> {
+        buffer.encode_le_u16(
+            self.handle.value()
+                | ((u8::from(self.packet_boundary_flag) as u16) << 12)
+                | ((u8::from(self.broadcast_flag) as u16) << 14),
+        )?;
+        buffer.encode_le_u16(self.data.len() as u16)?;
+        buffer.copy_from_slice(self.data.data())?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        2 * size_of::<u16>() + self.data.len()
+    }
 }
 
 pub(crate) mod parser {
@@ -82,7 +117,7 @@ pub(crate) mod parser {
         input: &[u8],
     ) -> IResult<&[u8], (ConnectionHandle, PacketBoundaryFlag, BroadcastFlag)> {
         map_res(le_u16, |v| {
-            let connection_handle = ConnectionHandle::try_new(v & 0xEFF)?;
+            let connection_handle = ConnectionHandle::try_new(v & 0x0FFF)?;
             let packet_boundary_flag: PacketBoundaryFlag =
                 (((v >> 12) & 0b0011) as u8).try_into()?;
             let broadcast_flag: BroadcastFlag = ((v >> 14) as u8).try_into()?;
@@ -95,7 +130,9 @@ pub(crate) mod parser {
         le_u16(input)
     }
 
-    pub(crate) fn acl_data(input: &[u8]) -> IResult<&[u8], Packet> {
+    pub(crate) fn acl_data<const ACL_DATA_SIZE: usize>(
+        input: &[u8],
+    ) -> IResult<&[u8], Packet<ACL_DATA_SIZE>> {
         let (rest, ((connection_handle, packet_boundary_flag, broadcast_flag), data_total_length)) =
             (connection_handle_and_flags, data_total_length).parse(input)?;
         let (rest, acl_data) = map_res(take(data_total_length), |data| {
@@ -116,7 +153,7 @@ mod tests {
     use rstest::rstest;
 
     use super::*;
-    use crate::packet::parser::packet;
+    use crate::packet::parser::{packet, sized_packet};
     use crate::Packet;
 
     #[rstest]
@@ -129,7 +166,77 @@ mod tests {
             &[12, 0, 5, 0, 18, 1, 8, 0, 24, 0, 40, 0, 0, 0, 42, 0]
         ).unwrap())
     )]
+    #[case(
+        &[2, 0x01, 0x21, 3, 0, 1, 2, 3],
+        Packet::AclData(AclData::try_new(
+            ConnectionHandle::try_new(0x0101).unwrap(),
+            PacketBoundaryFlag::FirstAutomaticallyFlushablePacket,
+            BroadcastFlag::PointToPoint,
+            &[1, 2, 3]
+        ).unwrap())
+    )]
     fn test_acl_data_parsing_success(#[case] input: &[u8], #[case] expected: Packet) {
         assert_eq!(packet(input), Ok((&[] as &[u8], expected)));
     }
+
+    #[rstest]
+    #[case(
+        AclData::try_new(
+            ConnectionHandle::try_new(0x0EFF).unwrap(),
+            PacketBoundaryFlag::ContinuingFragment,
+            BroadcastFlag::PointToPoint,
+            &[1, 2, 3]
+        ).unwrap(),
+        &[0xFF, 0x1E, 3, 0, 1, 2, 3]
+    )]
+    #[case(
+        AclData::try_new(
+            ConnectionHandle::try_new(0x0001).unwrap(),
+            PacketBoundaryFlag::FirstNonAutomaticallyFlushablePacket,
+            BroadcastFlag::BrEdrBroadcast,
+            &[]
+        ).unwrap(),
+        &[0x01, 0x40, 0, 0]
+    )]
+    fn test_acl_data_encoding(#[case] acl_data: AclData, #[case] encoded_data: &[u8]) {
+        let mut buffer = Buffer::<32>::default();
+        assert_eq!(acl_data.encoded_size(), encoded_data.len());
+        assert_eq!(acl_data.encode(&mut buffer), Ok(encoded_data.len()));
+        assert_eq!(buffer.data(), encoded_data);
+        assert_eq!(acl_data.data(), &encoded_data[4..]);
+    }
+
+    #[test]
+    fn test_acl_data_too_big() {
+        assert_eq!(
+            AclData::<2>::try_new(
+                ConnectionHandle::default(),
+                PacketBoundaryFlag::FirstAutomaticallyFlushablePacket,
+                BroadcastFlag::PointToPoint,
+                &[1, 2, 3]
+            ),
+            Err(Error::DataWillNotFitAclDataPacket)
+        );
+    }
+
+    #[test]
+    fn test_acl_data_parsing_custom_max_size() {
+        let mut input = [0; 256];
+        input[..5].copy_from_slice(&[2, 0, 32, 251, 0]);
+        assert!(packet(&input).is_err());
+        let (rest, packet) = sized_packet::<251>(&input).unwrap();
+        assert!(rest.is_empty());
+        assert_eq!(
+            packet,
+            Packet::AclData(
+                AclData::try_new(
+                    ConnectionHandle::try_new(0).unwrap(),
+                    PacketBoundaryFlag::FirstAutomaticallyFlushablePacket,
+                    BroadcastFlag::PointToPoint,
+                    &[0; 251]
+                )
+                .unwrap()
+            )
+        );
+    }
 }
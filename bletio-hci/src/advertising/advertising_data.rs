@@ -67,8 +67,8 @@ impl TryFrom<(u8, [u8; ADVERTISING_DATA_TOTAL_SIZE - 1])> for AdvertisingData {
     }
 }
 
-impl From<&LeAdvertisingReportData> for AdvertisingData {
-    fn from(value: &LeAdvertisingReportData) -> Self {
+impl From<LeAdvertisingReportData<'_>> for AdvertisingData {
+    fn from(value: LeAdvertisingReportData<'_>) -> Self {
         let mut s = AdvertisingData::default();
         // INVARIANT: The buffer is known to be big enough
         s.fill(|buffer| buffer.copy_from_slice(value.data()))
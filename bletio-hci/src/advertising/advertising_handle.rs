@@ -0,0 +1,87 @@
+use bletio_utils::{BufferOps, EncodeToBuffer};
+
+use crate::Error;
+
+/// Identifier of an advertising set.
+///
+/// Range: 0x00 to 0xEF
+///
+/// See [Core Specification 6.0, Vol.4, Part E, 7.8.53](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host-controller-interface/host-controller-interface-functional-specification.html).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AdvertisingHandle {
+    value: u8,
+}
+
+impl AdvertisingHandle {
+    pub const fn try_new(value: u8) -> Result<Self, Error> {
+        if value <= 0xEF {
+            Ok(Self { value })
+        } else {
+            Err(Error::InvalidAdvertisingHandle(value))
+        }
+    }
+
+    pub const fn value(&self) -> u8 {
+        self.value
+    }
+}
+
+impl TryFrom<u8> for AdvertisingHandle {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::try_new(value)
+    }
+}
+
+impl EncodeToBuffer for AdvertisingHandle {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        buffer.try_push(self.value)
+    }
+
+    fn encoded_size(&self) -> usize {
+        size_of::<u8>()
+    }
+}
+
+pub(crate) mod parser {
+    use nom::{combinator::map_res, number::complete::le_u8, IResult, Parser};
+
+    use super::*;
+
+    pub(crate) fn advertising_handle(input: &[u8]) -> IResult<&[u8], AdvertisingHandle> {
+        map_res(le_u8, TryInto::try_into).parse(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bletio_utils::Buffer;
+    use rstest::rstest;
+
+    use super::{parser::*, *};
+
+    #[rstest]
+    #[case(0x00, &[0x00])]
+    #[case(0x05, &[0x05])]
+    #[case(0xEF, &[0xEF])]
+    fn test_advertising_handle_success(#[case] input: u8, #[case] encoded_data: &[u8]) {
+        let handle = AdvertisingHandle::try_new(input).unwrap();
+        assert_eq!(handle.value(), input);
+        let mut buffer = Buffer::<1>::default();
+        assert_eq!(handle.encoded_size(), encoded_data.len());
+        handle.encode(&mut buffer).unwrap();
+        assert_eq!(buffer.data(), encoded_data);
+        assert_eq!(advertising_handle(encoded_data), Ok((&[] as &[u8], handle)));
+    }
+
+    #[rstest]
+    #[case(0xF0)]
+    #[case(0xFF)]
+    fn test_advertising_handle_failure(#[case] input: u8) {
+        let err = AdvertisingHandle::try_new(input);
+        assert_eq!(err, Err(Error::InvalidAdvertisingHandle(input)));
+        assert!(advertising_handle(&[input]).is_err());
+    }
+}
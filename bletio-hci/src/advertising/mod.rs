@@ -2,3 +2,4 @@ pub mod advertising_parameters;
 
 pub(crate) mod advertising_data;
 pub(crate) mod advertising_enable;
+pub(crate) mod advertising_handle;
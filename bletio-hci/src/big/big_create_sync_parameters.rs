@@ -0,0 +1,338 @@
+use bletio_utils::Error as UtilsError;
+use bletio_utils::{BufferOps, EncodeToBuffer};
+use heapless::Vec;
+
+use crate::big::big_parameters::encode_encryption;
+use crate::{BigHandle, BroadcastCode, Error, SyncHandle};
+
+const BIS_NB_MAX: usize = 31;
+
+/// Maximum permitted time between successful receptions of BIS PDUs.
+///
+/// Here are the characteristics of this BIG sync timeout:
+///  - Range: 0x000A to 0x4000
+///  - Default: 0x00C8 (2 s)
+///  - Time = N × 10 ms
+///  - Time Range: 100 ms to 163.84 s
+///
+/// See [Core Specification 6.0, Vol.4, Part E, 7.8.106](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host-controller-interface/host-controller-interface-functional-specification.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BigSyncTimeout {
+    value: u16,
+}
+
+impl BigSyncTimeout {
+    /// Create a valid BIG sync timeout.
+    pub const fn try_new(value: u16) -> Result<Self, Error> {
+        if (value >= 0x000A) && (value <= 0x4000) {
+            Ok(Self { value })
+        } else {
+            Err(Error::InvalidBigSyncTimeout(value))
+        }
+    }
+
+    /// Get the value of the BIG sync timeout in milliseconds.
+    pub const fn milliseconds(&self) -> f32 {
+        (self.value as f32) * 10.0
+    }
+
+    pub const fn value(&self) -> u16 {
+        self.value
+    }
+}
+
+impl Default for BigSyncTimeout {
+    fn default() -> Self {
+        Self { value: 0x00C8 }
+    }
+}
+
+impl TryFrom<u16> for BigSyncTimeout {
+    type Error = Error;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        Self::try_new(value)
+    }
+}
+
+impl EncodeToBuffer for BigSyncTimeout {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, UtilsError> {
+        buffer.encode_le_u16(self.value)
+    }
+
+    fn encoded_size(&self) -> usize {
+        size_of::<u16>()
+    }
+}
+
+/// Parameters to synchronize to a Broadcast Isochronous Group (BIG).
+///
+/// The BIG is described by the BIGInfo received on the periodic advertising train identified
+/// by the sync handle.
+///
+/// See [Core Specification 6.0, Vol.4, Part E, 7.8.106](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host-controller-interface/host-controller-interface-functional-specification.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BigCreateSyncParameters {
+    big_handle: BigHandle,
+    sync_handle: SyncHandle,
+    broadcast_code: Option<BroadcastCode>,
+    mse: u8,
+    big_sync_timeout: BigSyncTimeout,
+    bis: Vec<u8, BIS_NB_MAX>,
+}
+
+impl BigCreateSyncParameters {
+    /// Create valid BIG create sync parameters.
+    ///
+    /// The maximum number of subevents (MSE) must be between 0 (the Controller decides) and 31,
+    /// and between 1 and 31 BIS indices, each of them between 1 and 31, must be given.
+    /// The BIG is decrypted if a broadcast code is given.
+    pub fn try_new(
+        big_handle: BigHandle,
+        sync_handle: SyncHandle,
+        broadcast_code: Option<BroadcastCode>,
+        mse: u8,
+        big_sync_timeout: BigSyncTimeout,
+        bis: &[u8],
+    ) -> Result<Self, Error> {
+        if mse > 0x1F {
+            return Err(Error::InvalidMse(mse));
+        }
+        if bis.is_empty() || bis.len() > BIS_NB_MAX {
+            return Err(Error::InvalidNumBis(bis.len() as u8));
+        }
+        if let Some(index) = bis.iter().find(|index| !(0x01..=0x1F).contains(*index)) {
+            return Err(Error::InvalidBisIndex(*index));
+        }
+        Ok(Self {
+            big_handle,
+            sync_handle,
+            broadcast_code,
+            mse,
+            big_sync_timeout,
+            // INVARIANT: The length of the BIS list has been checked above.
+            bis: bis.try_into().unwrap(),
+        })
+    }
+
+    pub fn big_handle(&self) -> BigHandle {
+        self.big_handle
+    }
+
+    pub fn sync_handle(&self) -> SyncHandle {
+        self.sync_handle
+    }
+
+    pub fn broadcast_code(&self) -> Option<&BroadcastCode> {
+        self.broadcast_code.as_ref()
+    }
+
+    pub fn mse(&self) -> u8 {
+        self.mse
+    }
+
+    pub fn big_sync_timeout(&self) -> BigSyncTimeout {
+        self.big_sync_timeout
+    }
+
+    pub fn bis(&self) -> &[u8] {
+        self.bis.as_slice()
+    }
+}
+
+impl EncodeToBuffer for BigCreateSyncParameters {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, UtilsError> {
+        self.big_handle.encode(buffer)?;
+        self.sync_handle.encode(buffer)?;
+        encode_encryption(&self.broadcast_code, buffer)?;
+        buffer.try_push(self.mse)?;
+        self.big_sync_timeout.encode(buffer)?;
+        buffer.try_push(self.bis.len() as u8)?;
+        buffer.copy_from_slice(self.bis.as_slice())?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        self.big_handle.encoded_size()
+            + self.sync_handle.encoded_size()
+            + 1
+            + size_of::<BroadcastCode>()
+            + size_of::<u8>()
+            + self.big_sync_timeout.encoded_size()
+            + size_of::<u8>()
+            + self.bis.len()
+    }
+}
+
+pub(crate) mod parser {
+    use nom::{
+        bytes::take,
+        combinator::{all_consuming, map_res},
+        number::complete::{le_u16, le_u8},
+        IResult, Parser,
+    };
+
+    use crate::big::big_handle::parser::big_handle;
+    use crate::big::big_parameters::parser::encryption_and_broadcast_code;
+    use crate::scanning::sync_handle::parser::sync_handle;
+
+    use super::*;
+
+    fn big_sync_timeout(input: &[u8]) -> IResult<&[u8], BigSyncTimeout> {
+        map_res(le_u16, TryInto::try_into).parse(input)
+    }
+
+    pub(crate) fn big_create_sync_parameters(
+        input: &[u8],
+    ) -> IResult<&[u8], BigCreateSyncParameters> {
+        let (rest, (big_handle, sync_handle, broadcast_code, mse, big_sync_timeout, num_bis)) = (
+            big_handle,
+            sync_handle,
+            encryption_and_broadcast_code,
+            le_u8,
+            big_sync_timeout,
+            le_u8,
+        )
+            .parse(input)?;
+        all_consuming(map_res(take(num_bis), move |bis| {
+            BigCreateSyncParameters::try_new(
+                big_handle,
+                sync_handle,
+                broadcast_code,
+                mse,
+                big_sync_timeout,
+                bis,
+            )
+        }))
+        .parse(rest)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use approx::assert_relative_eq;
+    use bletio_utils::Buffer;
+    use rstest::rstest;
+
+    use super::{parser::*, *};
+
+    #[rstest]
+    #[case(0x000A, 100f32)]
+    #[case(0x00C8, 2000f32)]
+    #[case(0x4000, 163840f32)]
+    fn test_big_sync_timeout_success(
+        #[case] input: u16,
+        #[case] expected_milliseconds: f32,
+    ) -> Result<(), UtilsError> {
+        let timeout = BigSyncTimeout::try_new(input).unwrap();
+        assert_eq!(timeout.value(), input);
+        assert_relative_eq!(
+            timeout.milliseconds(),
+            expected_milliseconds,
+            epsilon = 1.0e-6
+        );
+        let mut buffer = Buffer::<2>::default();
+        assert_eq!(timeout.encode(&mut buffer)?, 2);
+        assert_eq!(buffer.data(), &input.to_le_bytes());
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(0x0000)]
+    #[case(0x0009)]
+    #[case(0x4001)]
+    fn test_big_sync_timeout_failure(#[case] input: u16) {
+        let err = BigSyncTimeout::try_new(input);
+        assert_eq!(err, Err(Error::InvalidBigSyncTimeout(input)));
+    }
+
+    #[test]
+    fn test_big_sync_timeout_default() {
+        assert_eq!(BigSyncTimeout::default().value(), 0x00C8);
+    }
+
+    #[rstest]
+    #[case::unencrypted(
+        BigCreateSyncParameters::try_new(
+            BigHandle::try_new(0x01).unwrap(), SyncHandle::try_new(0x0001).unwrap(), None, 0,
+            BigSyncTimeout::default(), &[1, 2]
+        ).unwrap(),
+        &[0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC8, 0x00, 0x02, 0x01, 0x02]
+    )]
+    #[case::encrypted(
+        BigCreateSyncParameters::try_new(
+            BigHandle::try_new(0x02).unwrap(), SyncHandle::try_new(0x0E00).unwrap(),
+            Some(BroadcastCode::new([0x55; 16])), 0x1F, BigSyncTimeout::try_new(0x0064).unwrap(), &[3]
+        ).unwrap(),
+        &[0x02, 0x00, 0x0E, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+            0x55, 0x55, 0x55, 0x55, 0x55, 0x1F, 0x64, 0x00, 0x01, 0x03]
+    )]
+    fn test_big_create_sync_parameters_success(
+        #[case] parameters: BigCreateSyncParameters,
+        #[case] encoded_data: &[u8],
+    ) -> Result<(), UtilsError> {
+        let mut buffer = Buffer::<64>::default();
+        assert_eq!(parameters.encoded_size(), encoded_data.len());
+        parameters.encode(&mut buffer)?;
+        assert_eq!(buffer.data(), encoded_data);
+        assert_eq!(
+            big_create_sync_parameters(encoded_data),
+            Ok((&[] as &[u8], parameters.clone()))
+        );
+        assert_eq!(parameters.big_handle().value(), encoded_data[0]);
+        assert_eq!(
+            parameters.sync_handle().value(),
+            u16::from_le_bytes([encoded_data[1], encoded_data[2]])
+        );
+        assert_eq!(parameters.broadcast_code().is_some(), encoded_data[3] == 1);
+        assert_eq!(parameters.mse(), encoded_data[20]);
+        assert_eq!(
+            parameters.big_sync_timeout().value(),
+            u16::from_le_bytes([encoded_data[21], encoded_data[22]])
+        );
+        assert_eq!(parameters.bis(), &encoded_data[24..]);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::mse_too_big(0x20, &[1], Error::InvalidMse(0x20))]
+    #[case::no_bis(0, &[], Error::InvalidNumBis(0))]
+    #[case::too_many_bis(0, &[1; 32], Error::InvalidNumBis(32))]
+    #[case::bis_index_zero(0, &[1, 0], Error::InvalidBisIndex(0))]
+    #[case::bis_index_too_big(0, &[0x20], Error::InvalidBisIndex(0x20))]
+    fn test_big_create_sync_parameters_failure(
+        #[case] mse: u8,
+        #[case] bis: &[u8],
+        #[case] expected_error: Error,
+    ) {
+        let err = BigCreateSyncParameters::try_new(
+            BigHandle::default(),
+            SyncHandle::default(),
+            None,
+            mse,
+            BigSyncTimeout::default(),
+            bis,
+        );
+        assert_eq!(err, Err(expected_error));
+    }
+
+    #[rstest]
+    #[case::missing_bis(
+        &[0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC8, 0x00, 0x02, 0x01]
+    )]
+    #[case::remaining_data(
+        &[0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC8, 0x00, 0x01, 0x01, 0x02]
+    )]
+    #[case::invalid_big_sync_timeout(
+        &[0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x01]
+    )]
+    fn test_big_create_sync_parameters_parsing_failure(#[case] input: &[u8]) {
+        assert!(big_create_sync_parameters(input).is_err());
+    }
+}
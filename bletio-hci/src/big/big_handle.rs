@@ -0,0 +1,87 @@
+use bletio_utils::{BufferOps, EncodeToBuffer};
+
+use crate::Error;
+
+/// Identifier of a Broadcast Isochronous Group (BIG).
+///
+/// Range: 0x00 to 0xEF
+///
+/// See [Core Specification 6.0, Vol.4, Part E, 7.8.103](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host-controller-interface/host-controller-interface-functional-specification.html).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BigHandle {
+    value: u8,
+}
+
+impl BigHandle {
+    pub const fn try_new(value: u8) -> Result<Self, Error> {
+        if value <= 0xEF {
+            Ok(Self { value })
+        } else {
+            Err(Error::InvalidBigHandle(value))
+        }
+    }
+
+    pub const fn value(&self) -> u8 {
+        self.value
+    }
+}
+
+impl TryFrom<u8> for BigHandle {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::try_new(value)
+    }
+}
+
+impl EncodeToBuffer for BigHandle {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        buffer.try_push(self.value)
+    }
+
+    fn encoded_size(&self) -> usize {
+        size_of::<u8>()
+    }
+}
+
+pub(crate) mod parser {
+    use nom::{combinator::map_res, number::complete::le_u8, IResult, Parser};
+
+    use super::*;
+
+    pub(crate) fn big_handle(input: &[u8]) -> IResult<&[u8], BigHandle> {
+        map_res(le_u8, TryInto::try_into).parse(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bletio_utils::Buffer;
+    use rstest::rstest;
+
+    use super::{parser::*, *};
+
+    #[rstest]
+    #[case(0x00, &[0x00])]
+    #[case(0x12, &[0x12])]
+    #[case(0xEF, &[0xEF])]
+    fn test_big_handle_success(#[case] input: u8, #[case] encoded_data: &[u8]) {
+        let handle = BigHandle::try_new(input).unwrap();
+        assert_eq!(handle.value(), input);
+        let mut buffer = Buffer::<1>::default();
+        assert_eq!(handle.encoded_size(), encoded_data.len());
+        handle.encode(&mut buffer).unwrap();
+        assert_eq!(buffer.data(), encoded_data);
+        assert_eq!(big_handle(encoded_data), Ok((&[] as &[u8], handle)));
+    }
+
+    #[rstest]
+    #[case(0xF0)]
+    #[case(0xFF)]
+    fn test_big_handle_failure(#[case] input: u8) {
+        let err = BigHandle::try_new(input);
+        assert_eq!(err, Err(Error::InvalidBigHandle(input)));
+        assert!(big_handle(&[input]).is_err());
+    }
+}
@@ -0,0 +1,581 @@
+use bletio_utils::Error as UtilsError;
+use bletio_utils::{BufferOps, EncodeToBuffer};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+use crate::{AdvertisingHandle, BigHandle, Error, Phy};
+
+/// Interval, in microseconds, of periodic SDUs.
+///
+/// Here are the characteristics of this SDU interval:
+///  - Range: 0x0000FF to 0x0FFFFF
+///  - Time = N µs
+///  - Time Range: 255 µs to 1.048575 s
+///
+/// See [Core Specification 6.0, Vol.4, Part E, 7.8.103](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host-controller-interface/host-controller-interface-functional-specification.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SduInterval {
+    value: u32,
+}
+
+impl SduInterval {
+    /// Create a valid SDU interval.
+    pub const fn try_new(value: u32) -> Result<Self, Error> {
+        if (value >= 0x0000FF) && (value <= 0x0FFFFF) {
+            Ok(Self { value })
+        } else {
+            Err(Error::InvalidSduInterval(value))
+        }
+    }
+
+    pub const fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+impl Default for SduInterval {
+    fn default() -> Self {
+        // 10 ms, the most common SDU interval for audio streams.
+        Self { value: 10_000 }
+    }
+}
+
+impl TryFrom<u32> for SduInterval {
+    type Error = Error;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Self::try_new(value)
+    }
+}
+
+impl EncodeToBuffer for SduInterval {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, UtilsError> {
+        buffer.copy_from_slice(&self.value.to_le_bytes()[..3])
+    }
+
+    fn encoded_size(&self) -> usize {
+        3
+    }
+}
+
+/// Preferred method of arranging subevents of multiple BISes.
+///
+/// See [Core Specification 6.0, Vol.4, Part E, 7.8.103](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host-controller-interface/host-controller-interface-functional-specification.html).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, IntoPrimitive, TryFromPrimitive)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[num_enum(error_type(name = Error, constructor = Error::InvalidPacking))]
+#[repr(u8)]
+#[non_exhaustive]
+pub enum Packing {
+    /// Sequential (default).
+    #[default]
+    Sequential = 0x00,
+    /// Interleaved.
+    Interleaved = 0x01,
+}
+
+impl EncodeToBuffer for Packing {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, UtilsError> {
+        buffer.try_push((*self).into())
+    }
+
+    fn encoded_size(&self) -> usize {
+        size_of::<Packing>()
+    }
+}
+
+/// Format of the BIS Data PDUs.
+///
+/// See [Core Specification 6.0, Vol.4, Part E, 7.8.103](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host-controller-interface/host-controller-interface-functional-specification.html).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, IntoPrimitive, TryFromPrimitive)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[num_enum(error_type(name = Error, constructor = Error::InvalidFraming))]
+#[repr(u8)]
+#[non_exhaustive]
+pub enum Framing {
+    /// Unframed (default).
+    #[default]
+    Unframed = 0x00,
+    /// Framed.
+    Framed = 0x01,
+}
+
+impl EncodeToBuffer for Framing {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, UtilsError> {
+        buffer.try_push((*self).into())
+    }
+
+    fn encoded_size(&self) -> usize {
+        size_of::<Framing>()
+    }
+}
+
+/// Code used to derive the session key that is used to encrypt and decrypt BIS payloads.
+///
+/// See [Core Specification 6.0, Vol.3, Part C, 3.2.6](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host/generic-access-profile.html).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BroadcastCode {
+    value: [u8; 16],
+}
+
+impl BroadcastCode {
+    pub const fn new(value: [u8; 16]) -> Self {
+        Self { value }
+    }
+
+    pub const fn value(&self) -> &[u8; 16] {
+        &self.value
+    }
+}
+
+impl From<[u8; 16]> for BroadcastCode {
+    fn from(value: [u8; 16]) -> Self {
+        Self::new(value)
+    }
+}
+
+impl EncodeToBuffer for BroadcastCode {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, UtilsError> {
+        buffer.copy_from_slice(&self.value)
+    }
+
+    fn encoded_size(&self) -> usize {
+        self.value.len()
+    }
+}
+
+/// Encode the Encryption and Broadcast_Code parameters of the BIG commands.
+pub(crate) fn encode_encryption<B: BufferOps>(
+    broadcast_code: &Option<BroadcastCode>,
+    buffer: &mut B,
+) -> Result<usize, UtilsError> {
+    buffer.try_push(broadcast_code.is_some() as u8)?;
+    broadcast_code.unwrap_or_default().encode(buffer)?;
+    Ok(1 + size_of::<BroadcastCode>())
+}
+
+/// Parameters for the creation of a Broadcast Isochronous Group (BIG).
+///
+/// The BIG is associated with an existing periodic advertising train, identified by its
+/// advertising handle, whose BIGInfo will describe the BIG to the synchronized receivers.
+///
+/// See [Core Specification 6.0, Vol.4, Part E, 7.8.103](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host-controller-interface/host-controller-interface-functional-specification.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BigParameters {
+    big_handle: BigHandle,
+    advertising_handle: AdvertisingHandle,
+    num_bis: u8,
+    sdu_interval: SduInterval,
+    max_sdu: u16,
+    max_transport_latency: u16,
+    rtn: u8,
+    phy: Phy,
+    packing: Packing,
+    framing: Framing,
+    broadcast_code: Option<BroadcastCode>,
+}
+
+impl BigParameters {
+    /// Create valid BIG parameters.
+    ///
+    /// The number of BISes must be between 1 and 31, the maximum SDU size between 1 and 4095 bytes,
+    /// the maximum transport latency between 5 and 4000 ms and the retransmission number between 0 and 30.
+    /// The BIG is encrypted if a broadcast code is given.
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_new(
+        big_handle: BigHandle,
+        advertising_handle: AdvertisingHandle,
+        num_bis: u8,
+        sdu_interval: SduInterval,
+        max_sdu: u16,
+        max_transport_latency: u16,
+        rtn: u8,
+        phy: Phy,
+        packing: Packing,
+        framing: Framing,
+        broadcast_code: Option<BroadcastCode>,
+    ) -> Result<Self, Error> {
+        if !(0x01..=0x1F).contains(&num_bis) {
+            Err(Error::InvalidNumBis(num_bis))
+        } else if !(0x0001..=0x0FFF).contains(&max_sdu) {
+            Err(Error::InvalidMaxSdu(max_sdu))
+        } else if !(0x0005..=0x0FA0).contains(&max_transport_latency) {
+            Err(Error::InvalidMaxTransportLatency(max_transport_latency))
+        } else if rtn > 0x1E {
+            Err(Error::InvalidRetransmissionNumber(rtn))
+        } else {
+            Ok(Self {
+                big_handle,
+                advertising_handle,
+                num_bis,
+                sdu_interval,
+                max_sdu,
+                max_transport_latency,
+                rtn,
+                phy,
+                packing,
+                framing,
+                broadcast_code,
+            })
+        }
+    }
+
+    pub fn big_handle(&self) -> BigHandle {
+        self.big_handle
+    }
+
+    pub fn advertising_handle(&self) -> AdvertisingHandle {
+        self.advertising_handle
+    }
+
+    pub fn num_bis(&self) -> u8 {
+        self.num_bis
+    }
+
+    pub fn sdu_interval(&self) -> SduInterval {
+        self.sdu_interval
+    }
+
+    pub fn max_sdu(&self) -> u16 {
+        self.max_sdu
+    }
+
+    pub fn max_transport_latency(&self) -> u16 {
+        self.max_transport_latency
+    }
+
+    pub fn rtn(&self) -> u8 {
+        self.rtn
+    }
+
+    pub fn phy(&self) -> Phy {
+        self.phy
+    }
+
+    pub fn packing(&self) -> Packing {
+        self.packing
+    }
+
+    pub fn framing(&self) -> Framing {
+        self.framing
+    }
+
+    pub fn broadcast_code(&self) -> Option<&BroadcastCode> {
+        self.broadcast_code.as_ref()
+    }
+}
+
+impl EncodeToBuffer for BigParameters {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, UtilsError> {
+        self.big_handle.encode(buffer)?;
+        self.advertising_handle.encode(buffer)?;
+        buffer.try_push(self.num_bis)?;
+        self.sdu_interval.encode(buffer)?;
+        buffer.encode_le_u16(self.max_sdu)?;
+        buffer.encode_le_u16(self.max_transport_latency)?;
+        buffer.try_push(self.rtn)?;
+        buffer.try_push(self.phy.bit())?;
+        self.packing.encode(buffer)?;
+        self.framing.encode(buffer)?;
+        encode_encryption(&self.broadcast_code, buffer)?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        self.big_handle.encoded_size()
+            + self.advertising_handle.encoded_size()
+            + size_of::<u8>()
+            + self.sdu_interval.encoded_size()
+            + size_of::<u16>()
+            + size_of::<u16>()
+            + size_of::<u8>()
+            + size_of::<u8>()
+            + self.packing.encoded_size()
+            + self.framing.encoded_size()
+            + 1
+            + size_of::<BroadcastCode>()
+    }
+}
+
+pub(crate) mod parser {
+    use nom::{
+        bytes::take,
+        combinator::{all_consuming, map, map_res},
+        number::complete::{le_u16, le_u24, le_u8},
+        IResult, Parser,
+    };
+
+    use crate::advertising::advertising_handle::parser::advertising_handle;
+    use crate::big::big_handle::parser::big_handle;
+    use crate::common::phy::parser::phy_bit;
+
+    use super::*;
+
+    pub(crate) fn sdu_interval(input: &[u8]) -> IResult<&[u8], SduInterval> {
+        map_res(le_u24, TryInto::try_into).parse(input)
+    }
+
+    fn packing(input: &[u8]) -> IResult<&[u8], Packing> {
+        map_res(le_u8, TryInto::try_into).parse(input)
+    }
+
+    pub(crate) fn framing(input: &[u8]) -> IResult<&[u8], Framing> {
+        map_res(le_u8, TryInto::try_into).parse(input)
+    }
+
+    pub(crate) fn encryption(input: &[u8]) -> IResult<&[u8], bool> {
+        map_res(le_u8, |v| match v {
+            0x00 => Ok(false),
+            0x01 => Ok(true),
+            _ => Err(Error::InvalidEncryption(v)),
+        })
+        .parse(input)
+    }
+
+    fn broadcast_code(input: &[u8]) -> IResult<&[u8], BroadcastCode> {
+        map(
+            map_res(take(16u8), TryInto::<[u8; 16]>::try_into),
+            BroadcastCode::new,
+        )
+        .parse(input)
+    }
+
+    pub(crate) fn encryption_and_broadcast_code(
+        input: &[u8],
+    ) -> IResult<&[u8], Option<BroadcastCode>> {
+        map(
+            (encryption, broadcast_code),
+            |(encryption, broadcast_code)| encryption.then_some(broadcast_code),
+        )
+        .parse(input)
+    }
+
+    pub(crate) fn big_parameters(input: &[u8]) -> IResult<&[u8], BigParameters> {
+        all_consuming(map_res(
+            (
+                big_handle,
+                advertising_handle,
+                le_u8,
+                sdu_interval,
+                le_u16,
+                le_u16,
+                le_u8,
+                phy_bit,
+                packing,
+                framing,
+                encryption_and_broadcast_code,
+            ),
+            |(
+                big_handle,
+                advertising_handle,
+                num_bis,
+                sdu_interval,
+                max_sdu,
+                max_transport_latency,
+                rtn,
+                phy,
+                packing,
+                framing,
+                broadcast_code,
+            )| {
+                BigParameters::try_new(
+                    big_handle,
+                    advertising_handle,
+                    num_bis,
+                    sdu_interval,
+                    max_sdu,
+                    max_transport_latency,
+                    rtn,
+                    phy,
+                    packing,
+                    framing,
+                    broadcast_code,
+                )
+            },
+        ))
+        .parse(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bletio_utils::Buffer;
+    use rstest::rstest;
+
+    use super::{parser::*, *};
+
+    #[rstest]
+    #[case(0x0000FF, &[0xFF, 0x00, 0x00])]
+    #[case(0x002710, &[0x10, 0x27, 0x00])]
+    #[case(0x0FFFFF, &[0xFF, 0xFF, 0x0F])]
+    fn test_sdu_interval_success(
+        #[case] input: u32,
+        #[case] encoded_data: &[u8],
+    ) -> Result<(), UtilsError> {
+        let value = SduInterval::try_new(input).unwrap();
+        assert_eq!(value.value(), input);
+        let mut buffer = Buffer::<3>::default();
+        assert_eq!(value.encoded_size(), encoded_data.len());
+        value.encode(&mut buffer)?;
+        assert_eq!(buffer.data(), encoded_data);
+        assert_eq!(sdu_interval(encoded_data), Ok((&[] as &[u8], value)));
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(0x000000)]
+    #[case(0x0000FE)]
+    #[case(0x100000)]
+    fn test_sdu_interval_failure(#[case] input: u32) {
+        let err = SduInterval::try_new(input);
+        assert_eq!(err, Err(Error::InvalidSduInterval(input)));
+    }
+
+    #[test]
+    fn test_sdu_interval_default() {
+        assert_eq!(SduInterval::default().value(), 10_000);
+    }
+
+    #[test]
+    fn test_broadcast_code() -> Result<(), UtilsError> {
+        let code: BroadcastCode = [
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E,
+            0x0F, 0x10,
+        ]
+        .into();
+        assert_eq!(code.value()[0], 0x01);
+        let mut buffer = Buffer::<16>::default();
+        assert_eq!(code.encode(&mut buffer)?, 16);
+        assert_eq!(buffer.data(), code.value());
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::unencrypted(
+        BigParameters::try_new(
+            BigHandle::try_new(0x01).unwrap(), AdvertisingHandle::try_new(0x00).unwrap(), 2,
+            SduInterval::default(), 100, 20, 2, Phy::Le2M, Packing::Sequential, Framing::Unframed, None
+        ).unwrap(),
+        &[0x01, 0x00, 0x02, 0x10, 0x27, 0x00, 0x64, 0x00, 0x14, 0x00, 0x02, 0x02, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+    )]
+    #[case::encrypted(
+        BigParameters::try_new(
+            BigHandle::try_new(0x02).unwrap(), AdvertisingHandle::try_new(0x01).unwrap(), 1,
+            SduInterval::try_new(7500).unwrap(), 40, 10, 4, Phy::LeCoded, Packing::Interleaved, Framing::Framed,
+            Some(BroadcastCode::new([0xAA; 16]))
+        ).unwrap(),
+        &[0x02, 0x01, 0x01, 0x4C, 0x1D, 0x00, 0x28, 0x00, 0x0A, 0x00, 0x04, 0x04, 0x01, 0x01, 0x01,
+            0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA]
+    )]
+    fn test_big_parameters_success(
+        #[case] parameters: BigParameters,
+        #[case] encoded_data: &[u8],
+    ) -> Result<(), UtilsError> {
+        let mut buffer = Buffer::<31>::default();
+        assert_eq!(parameters.encoded_size(), encoded_data.len());
+        parameters.encode(&mut buffer)?;
+        assert_eq!(buffer.data(), encoded_data);
+        assert_eq!(
+            big_parameters(encoded_data),
+            Ok((&[] as &[u8], parameters.clone()))
+        );
+        assert_eq!(
+            parameters.packing(),
+            Packing::try_from(encoded_data[12]).unwrap()
+        );
+        assert_eq!(
+            parameters.framing(),
+            Framing::try_from(encoded_data[13]).unwrap()
+        );
+        assert_eq!(parameters.broadcast_code().is_some(), encoded_data[14] == 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_big_parameters_getters() {
+        let big_handle = BigHandle::try_new(0x01).unwrap();
+        let advertising_handle = AdvertisingHandle::try_new(0x03).unwrap();
+        let sdu_interval = SduInterval::try_new(10_000).unwrap();
+        let parameters = BigParameters::try_new(
+            big_handle,
+            advertising_handle,
+            2,
+            sdu_interval,
+            120,
+            20,
+            2,
+            Phy::Le2M,
+            Packing::Sequential,
+            Framing::Unframed,
+            None,
+        )
+        .unwrap();
+        assert_eq!(parameters.big_handle(), big_handle);
+        assert_eq!(parameters.advertising_handle(), advertising_handle);
+        assert_eq!(parameters.num_bis(), 2);
+        assert_eq!(parameters.sdu_interval(), sdu_interval);
+        assert_eq!(parameters.max_sdu(), 120);
+        assert_eq!(parameters.max_transport_latency(), 20);
+        assert_eq!(parameters.rtn(), 2);
+        assert_eq!(parameters.phy(), Phy::Le2M);
+        assert_eq!(parameters.broadcast_code(), None);
+    }
+
+    #[rstest]
+    #[case::num_bis_zero(0, 100, 20, 2, Error::InvalidNumBis(0))]
+    #[case::num_bis_too_big(0x20, 100, 20, 2, Error::InvalidNumBis(0x20))]
+    #[case::max_sdu_zero(1, 0, 20, 2, Error::InvalidMaxSdu(0))]
+    #[case::max_sdu_too_big(1, 0x1000, 20, 2, Error::InvalidMaxSdu(0x1000))]
+    #[case::max_transport_latency_too_small(1, 100, 4, 2, Error::InvalidMaxTransportLatency(4))]
+    #[case::max_transport_latency_too_big(
+        1,
+        100,
+        0x0FA1,
+        2,
+        Error::InvalidMaxTransportLatency(0x0FA1)
+    )]
+    #[case::rtn_too_big(1, 100, 20, 0x1F, Error::InvalidRetransmissionNumber(0x1F))]
+    fn test_big_parameters_failure(
+        #[case] num_bis: u8,
+        #[case] max_sdu: u16,
+        #[case] max_transport_latency: u16,
+        #[case] rtn: u8,
+        #[case] expected_error: Error,
+    ) {
+        let err = BigParameters::try_new(
+            BigHandle::default(),
+            AdvertisingHandle::default(),
+            num_bis,
+            SduInterval::default(),
+            max_sdu,
+            max_transport_latency,
+            rtn,
+            Phy::default(),
+            Packing::default(),
+            Framing::default(),
+            None,
+        );
+        assert_eq!(err, Err(expected_error));
+    }
+
+    #[rstest]
+    #[case::invalid_encryption(
+        &[0x01, 0x00, 0x02, 0x10, 0x27, 0x00, 0x64, 0x00, 0x14, 0x00, 0x02, 0x02, 0x00, 0x00, 0x02,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+    )]
+    #[case::invalid_phy(
+        &[0x01, 0x00, 0x02, 0x10, 0x27, 0x00, 0x64, 0x00, 0x14, 0x00, 0x02, 0x03, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+    )]
+    #[case::invalid_num_bis(
+        &[0x01, 0x00, 0x00, 0x10, 0x27, 0x00, 0x64, 0x00, 0x14, 0x00, 0x02, 0x02, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+    )]
+    #[case::not_enough_data(&[0x01, 0x00, 0x02])]
+    fn test_big_parameters_parsing_failure(#[case] input: &[u8]) {
+        assert!(big_parameters(input).is_err());
+    }
+}
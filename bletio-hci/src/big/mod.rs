@@ -0,0 +1,3 @@
+pub(crate) mod big_create_sync_parameters;
+pub(crate) mod big_handle;
+pub(crate) mod big_parameters;
@@ -0,0 +1,412 @@
+extern crate std;
+
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::vec::{self, Vec};
+
+use crate::capture::{HciTracer, PacketDirection, ReplayHciDriver};
+use crate::PacketType;
+
+const BTSNOOP_IDENTIFICATION_PATTERN: &[u8; 8] = b"btsnoop\0";
+const BTSNOOP_VERSION: u32 = 1;
+const BTSNOOP_DATALINK_HCI_UART: u32 = 1002;
+
+// Microseconds between the BTSnoop epoch (midnight, January 1st, 0 AD) and the Unix epoch.
+const BTSNOOP_UNIX_EPOCH_OFFSET: i64 = 0x00DC_DDB3_0F2F_8000;
+
+const BTSNOOP_HEADER_SIZE: usize = 16;
+const BTSNOOP_RECORD_HEADER_SIZE: usize = 24;
+
+const PACKET_FLAG_RECEIVED: u32 = 1 << 0;
+const PACKET_FLAG_COMMAND_OR_EVENT: u32 = 1 << 1;
+
+fn invalid_data(message: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Writer of HCI captures in the BTSnoop format, with the HCI UART (H4) datalink.
+///
+/// The generated files can be opened in Wireshark. When used as an [`HciTracer`], the packets
+/// are timestamped with the system time, and the first write error is kept and returned by
+/// [`BtSnoopWriter::finish`].
+///
+/// See [RFC 1761](https://www.rfc-editor.org/rfc/rfc1761) for the snoop format it derives from.
+#[derive(Debug)]
+pub struct BtSnoopWriter<W>
+where
+    W: Write,
+{
+    writer: W,
+    error: Option<io::Error>,
+}
+
+impl BtSnoopWriter<BufWriter<File>> {
+    /// Create a BTSnoop file at the given path, replacing it if it already exists.
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::new(BufWriter::new(File::create(path)?))
+    }
+}
+
+impl<W> BtSnoopWriter<W>
+where
+    W: Write,
+{
+    /// Start a BTSnoop capture by writing its file header.
+    pub fn new(mut writer: W) -> io::Result<Self> {
+        writer.write_all(BTSNOOP_IDENTIFICATION_PATTERN)?;
+        writer.write_all(&BTSNOOP_VERSION.to_be_bytes())?;
+        writer.write_all(&BTSNOOP_DATALINK_HCI_UART.to_be_bytes())?;
+        Ok(Self {
+            writer,
+            error: None,
+        })
+    }
+
+    /// Write a packet record, the packet including its HCI packet indicator.
+    pub fn write_packet(
+        &mut self,
+        direction: PacketDirection,
+        packet: &[u8],
+        timestamp: SystemTime,
+    ) -> io::Result<()> {
+        let length = u32::try_from(packet.len())
+            .map_err(|_| io::Error::from(io::ErrorKind::InvalidInput))?;
+        let mut flags = match direction {
+            PacketDirection::HostToController => 0,
+            PacketDirection::ControllerToHost => PACKET_FLAG_RECEIVED,
+        };
+        if matches!(
+            packet.first().copied(),
+            Some(t) if t == PacketType::Command as u8 || t == PacketType::Event as u8
+        ) {
+            flags |= PACKET_FLAG_COMMAND_OR_EVENT;
+        }
+        let timestamp = match timestamp.duration_since(UNIX_EPOCH) {
+            Ok(duration) => BTSNOOP_UNIX_EPOCH_OFFSET + duration.as_micros() as i64,
+            Err(err) => BTSNOOP_UNIX_EPOCH_OFFSET - err.duration().as_micros() as i64,
+        };
+
+        // Original length, included length, flags, cumulative drops and timestamp
+        self.writer.write_all(&length.to_be_bytes())?;
+        self.writer.write_all(&length.to_be_bytes())?;
+        self.writer.write_all(&flags.to_be_bytes())?;
+        self.writer.write_all(&0u32.to_be_bytes())?;
+        self.writer.write_all(&timestamp.to_be_bytes())?;
+        self.writer.write_all(packet)
+    }
+
+    /// Flush the capture and give back the underlying writer.
+    ///
+    /// Fails with the first error that occurred while tracing packets, if any.
+    pub fn finish(mut self) -> io::Result<W> {
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+impl<W> HciTracer for BtSnoopWriter<W>
+where
+    W: Write,
+{
+    fn trace(&mut self, direction: PacketDirection, packet: &[u8]) {
+        if self.error.is_none() {
+            if let Err(err) = self.write_packet(direction, packet, SystemTime::now()) {
+                self.error = Some(err);
+            }
+        }
+    }
+}
+
+/// Packet record read from a BTSnoop capture.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BtSnoopRecord {
+    pub direction: PacketDirection,
+    pub timestamp: SystemTime,
+    /// The packet, including its HCI packet indicator.
+    pub packet: Vec<u8>,
+}
+
+/// Reader of HCI captures in the BTSnoop format, with the HCI UART (H4) datalink.
+///
+/// The packet records are read one at a time by iterating over the reader.
+#[derive(Debug)]
+pub struct BtSnoopReader<R>
+where
+    R: Read,
+{
+    reader: R,
+}
+
+impl BtSnoopReader<BufReader<File>> {
+    /// Open the BTSnoop file at the given path.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::new(BufReader::new(File::open(path)?))
+    }
+}
+
+impl<R> BtSnoopReader<R>
+where
+    R: Read,
+{
+    /// Start reading a BTSnoop capture by checking its file header.
+    pub fn new(mut reader: R) -> io::Result<Self> {
+        let mut header = [0; BTSNOOP_HEADER_SIZE];
+        reader.read_exact(&mut header)?;
+        if &header[..8] != BTSNOOP_IDENTIFICATION_PATTERN {
+            return Err(invalid_data("not a BTSnoop file"));
+        }
+        if header[8..12] != BTSNOOP_VERSION.to_be_bytes() {
+            return Err(invalid_data("unsupported BTSnoop version"));
+        }
+        if header[12..16] != BTSNOOP_DATALINK_HCI_UART.to_be_bytes() {
+            return Err(invalid_data(
+                "unsupported BTSnoop datalink, only HCI UART is supported",
+            ));
+        }
+        Ok(Self { reader })
+    }
+
+    /// Read the next packet record, `None` being returned at the end of the capture.
+    pub fn read_record(&mut self) -> io::Result<Option<BtSnoopRecord>> {
+        let mut header = [0; BTSNOOP_RECORD_HEADER_SIZE];
+        let mut filled = 0;
+        while filled < header.len() {
+            match self.reader.read(&mut header[filled..]) {
+                Ok(0) if filled == 0 => return Ok(None),
+                Ok(0) => return Err(io::ErrorKind::UnexpectedEof.into()),
+                Ok(n) => filled += n,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => return Err(err),
+            }
+        }
+
+        // INVARIANT: The slices have the size of the integers they are converted to.
+        let included_length = u32::from_be_bytes(header[4..8].try_into().unwrap());
+        let flags = u32::from_be_bytes(header[8..12].try_into().unwrap());
+        let timestamp = i64::from_be_bytes(header[16..24].try_into().unwrap());
+
+        let direction = if flags & PACKET_FLAG_RECEIVED != 0 {
+            PacketDirection::ControllerToHost
+        } else {
+            PacketDirection::HostToController
+        };
+        let timestamp = timestamp - BTSNOOP_UNIX_EPOCH_OFFSET;
+        let timestamp = if timestamp >= 0 {
+            UNIX_EPOCH + Duration::from_micros(timestamp as u64)
+        } else {
+            UNIX_EPOCH - Duration::from_micros(timestamp.unsigned_abs())
+        };
+        let mut packet = std::vec![0; included_length as usize];
+        self.reader.read_exact(&mut packet)?;
+        Ok(Some(BtSnoopRecord {
+            direction,
+            timestamp,
+            packet,
+        }))
+    }
+}
+
+impl<R> Iterator for BtSnoopReader<R>
+where
+    R: Read,
+{
+    type Item = io::Result<BtSnoopRecord>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_record().transpose()
+    }
+}
+
+impl ReplayHciDriver<vec::IntoIter<(PacketDirection, Vec<u8>)>, Vec<u8>> {
+    /// Create a driver replaying a whole BTSnoop capture.
+    pub fn from_btsnoop(reader: impl Read) -> io::Result<Self> {
+        let steps = BtSnoopReader::new(reader)?
+            .map(|record| record.map(|record| (record.direction, record.packet)))
+            .collect::<io::Result<Vec<_>>>()?;
+        Ok(Self::new(steps))
+    }
+
+    /// Create a driver replaying the BTSnoop file at the given path.
+    pub fn open_btsnoop(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::from_btsnoop(BufReader::new(File::open(path)?))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::capture::TracedHciDriver;
+    use crate::test::TokioHciDriver;
+    use crate::Hci;
+
+    #[test]
+    fn test_btsnoop_header() {
+        let writer = BtSnoopWriter::new(Vec::new()).unwrap();
+        assert_eq!(
+            writer.finish().unwrap(),
+            &[b'b', b't', b's', b'n', b'o', b'o', b'p', 0, 0, 0, 0, 1, 0, 0, 0x03, 0xEA]
+        );
+    }
+
+    #[test]
+    fn test_btsnoop_packet_records() {
+        let timestamp = UNIX_EPOCH + Duration::from_micros(1);
+        let mut writer = BtSnoopWriter::new(Vec::new()).unwrap();
+        writer
+            .write_packet(
+                PacketDirection::HostToController,
+                &[0x01, 0x03, 0x0C, 0x00],
+                timestamp,
+            )
+            .unwrap();
+        writer
+            .write_packet(
+                PacketDirection::ControllerToHost,
+                &[0x02, 0x01, 0x20, 0x00, 0x00],
+                timestamp,
+            )
+            .unwrap();
+        let data = writer.finish().unwrap();
+        assert_eq!(
+            &data[16..44],
+            &[
+                0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 2, 0, 0, 0, 0, 0x00, 0xDC, 0xDD, 0xB3, 0x0F, 0x2F,
+                0x80, 0x01, 0x01, 0x03, 0x0C, 0x00
+            ]
+        );
+        assert_eq!(
+            &data[44..],
+            &[
+                0, 0, 0, 5, 0, 0, 0, 5, 0, 0, 0, 1, 0, 0, 0, 0, 0x00, 0xDC, 0xDD, 0xB3, 0x0F, 0x2F,
+                0x80, 0x01, 0x02, 0x01, 0x20, 0x00, 0x00
+            ]
+        );
+    }
+
+    #[test]
+    fn test_btsnoop_tracer() {
+        let mut writer = BtSnoopWriter::new(Vec::new()).unwrap();
+        writer.trace(
+            PacketDirection::ControllerToHost,
+            &[0x04, 0x0E, 0x04, 0x01, 0x03, 0x0C, 0x00],
+        );
+        let data = writer.finish().unwrap();
+        assert_eq!(data.len(), 16 + 24 + 7);
+        // Received event
+        assert_eq!(&data[24..28], &[0, 0, 0, 3]);
+        assert_eq!(&data[40..], &[0x04, 0x0E, 0x04, 0x01, 0x03, 0x0C, 0x00]);
+    }
+
+    #[test]
+    fn test_btsnoop_tracer_keeps_first_error() {
+        #[derive(Debug)]
+        struct FailingWriter(usize);
+        impl Write for FailingWriter {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                if self.0 < buf.len() {
+                    return Err(io::Error::from(io::ErrorKind::StorageFull));
+                }
+                self.0 -= buf.len();
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut writer = BtSnoopWriter::new(FailingWriter(16)).unwrap();
+        writer.trace(PacketDirection::HostToController, &[0x01, 0x03, 0x0C, 0x00]);
+        writer.trace(PacketDirection::HostToController, &[0x01, 0x03, 0x0C, 0x00]);
+        assert_eq!(
+            writer.finish().unwrap_err().kind(),
+            io::ErrorKind::StorageFull
+        );
+    }
+
+    #[test]
+    fn test_btsnoop_reader() {
+        let timestamp = UNIX_EPOCH + Duration::from_micros(1_234_567);
+        let mut writer = BtSnoopWriter::new(Vec::new()).unwrap();
+        writer
+            .write_packet(
+                PacketDirection::HostToController,
+                &[0x01, 0x03, 0x0C, 0x00],
+                timestamp,
+            )
+            .unwrap();
+        writer
+            .write_packet(
+                PacketDirection::ControllerToHost,
+                &[0x04, 0x0E, 0x04, 0x01, 0x03, 0x0C, 0x00],
+                timestamp,
+            )
+            .unwrap();
+        let data = writer.finish().unwrap();
+
+        let records = BtSnoopReader::new(data.as_slice())
+            .unwrap()
+            .collect::<io::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(
+            records,
+            &[
+                BtSnoopRecord {
+                    direction: PacketDirection::HostToController,
+                    timestamp,
+                    packet: std::vec![0x01, 0x03, 0x0C, 0x00],
+                },
+                BtSnoopRecord {
+                    direction: PacketDirection::ControllerToHost,
+                    timestamp,
+                    packet: std::vec![0x04, 0x0E, 0x04, 0x01, 0x03, 0x0C, 0x00],
+                },
+            ]
+        );
+
+        // Truncated record
+        let mut reader = BtSnoopReader::new(&data[..data.len() - 1]).unwrap();
+        assert!(reader.next().unwrap().is_ok());
+        assert_eq!(
+            reader.next().unwrap().unwrap_err().kind(),
+            io::ErrorKind::UnexpectedEof
+        );
+    }
+
+    #[test]
+    fn test_btsnoop_reader_invalid_header() {
+        let data = BtSnoopWriter::new(Vec::new()).unwrap().finish().unwrap();
+        let mut invalid_pattern = data.clone();
+        invalid_pattern[0] = b'B';
+        let mut invalid_datalink = data.clone();
+        invalid_datalink[15] = 0xE9;
+        for data in [&invalid_pattern[..], &invalid_datalink[..], &data[..8]] {
+            assert!(BtSnoopReader::new(data).is_err());
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_btsnoop_capture_and_replay() {
+        let mock = tokio_test::io::Builder::new()
+            .write(&[1, 3, 12, 0])
+            .read(&[4, 14, 4, 1, 3, 12, 0])
+            .build();
+        let mut driver = TracedHciDriver::new(
+            TokioHciDriver { hci: mock },
+            BtSnoopWriter::new(Vec::new()).unwrap(),
+        );
+        let mut hci = Hci::new(&mut driver);
+        assert_eq!(hci.cmd_reset().await, Ok(()));
+        let capture = driver.into_inner().1.finish().unwrap();
+
+        let mut driver = ReplayHciDriver::from_btsnoop(capture.as_slice()).unwrap();
+        let mut hci = Hci::new(&mut driver);
+        assert_eq!(hci.cmd_reset().await, Ok(()));
+        assert_eq!(driver.check(), Ok(()));
+    }
+}
@@ -0,0 +1,131 @@
+//! Capture of the HCI traffic.
+//!
+//! Every HCI packet sent or received by [`Hci`](crate::Hci) is handed to
+//! [`HciDriver::trace`](crate::HciDriver::trace). Wrapping a driver in a [`TracedHciDriver`]
+//! forwards these packets to an [`HciTracer`], that can for example log them over RTT or defmt
+//! on an embedded target, or write them to a BTSnoop or pcap file with the `std` feature.
+//!
+//! Conversely, a [`ReplayHciDriver`] plays a recorded trace back to the Host, acting as the
+//! Controller, to write deterministic regression tests.
+
+use crate::{HciDriver, HciDriverError};
+
+#[cfg(feature = "std")]
+mod btsnoop;
+#[cfg(feature = "std")]
+mod pcap;
+mod replay;
+
+#[cfg(feature = "std")]
+pub use btsnoop::{BtSnoopReader, BtSnoopRecord, BtSnoopWriter};
+#[cfg(feature = "std")]
+pub use pcap::PcapWriter;
+pub use replay::{ReplayDivergence, ReplayHciDriver, ReportedPacket};
+
+/// Direction of a captured HCI packet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PacketDirection {
+    /// Packet sent by the Host to the Controller, ie. a command or outgoing data.
+    HostToController,
+    /// Packet sent by the Controller to the Host, ie. an event or incoming data.
+    ControllerToHost,
+}
+
+/// Receiver of the captured HCI packets.
+///
+/// The packets are given with their HCI packet indicator, as they are transmitted on an H4 UART
+/// transport. Timestamping the packets is left to the implementation.
+pub trait HciTracer {
+    fn trace(&mut self, direction: PacketDirection, packet: &[u8]);
+}
+
+impl<T> HciTracer for &mut T
+where
+    T: HciTracer + ?Sized,
+{
+    fn trace(&mut self, direction: PacketDirection, packet: &[u8]) {
+        (**self).trace(direction, packet);
+    }
+}
+
+/// HCI driver forwarding all the traffic of another driver to an [`HciTracer`].
+#[derive(Debug)]
+pub struct TracedHciDriver<H, T>
+where
+    H: HciDriver,
+    T: HciTracer,
+{
+    driver: H,
+    tracer: T,
+}
+
+impl<H, T> TracedHciDriver<H, T>
+where
+    H: HciDriver,
+    T: HciTracer,
+{
+    pub fn new(driver: H, tracer: T) -> Self {
+        Self { driver, tracer }
+    }
+
+    pub fn tracer(&self) -> &T {
+        &self.tracer
+    }
+
+    pub fn into_inner(self) -> (H, T) {
+        (self.driver, self.tracer)
+    }
+}
+
+impl<H, T> HciDriver for TracedHciDriver<H, T>
+where
+    H: HciDriver,
+    T: HciTracer,
+{
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, HciDriverError> {
+        self.driver.read(buf).await
+    }
+
+    async fn write(&mut self, buf: &[u8]) -> Result<usize, HciDriverError> {
+        self.driver.write(buf).await
+    }
+
+    fn trace(&mut self, direction: PacketDirection, packet: &[u8]) {
+        self.driver.trace(direction, packet);
+        self.tracer.trace(direction, packet);
+    }
+}
+
+/// Tracer logging every HCI packet with defmt, at the trace level.
+#[cfg(feature = "defmt")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DefmtHciTracer;
+
+#[cfg(feature = "defmt")]
+impl HciTracer for DefmtHciTracer {
+    fn trace(&mut self, direction: PacketDirection, packet: &[u8]) {
+        defmt::trace!("HCI {}: {=[u8]:02x}", direction, packet);
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use heapless::Vec;
+
+    use super::*;
+
+    /// Tracer keeping the captured packets in memory.
+    #[derive(Debug, Default)]
+    pub(crate) struct MemoryTracer {
+        pub(crate) packets: Vec<(PacketDirection, Vec<u8, 64>), 16>,
+    }
+
+    impl HciTracer for MemoryTracer {
+        fn trace(&mut self, direction: PacketDirection, packet: &[u8]) {
+            self.packets
+                .push((direction, Vec::from_slice(packet).unwrap()))
+                .unwrap();
+        }
+    }
+}
@@ -0,0 +1,174 @@
+extern crate std;
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::capture::{HciTracer, PacketDirection};
+
+const PCAP_MAGIC_NUMBER: u32 = 0xA1B2_C3D4;
+const PCAP_VERSION_MAJOR: u16 = 2;
+const PCAP_VERSION_MINOR: u16 = 4;
+const PCAP_SNAPLEN: u32 = 65535;
+const LINKTYPE_BLUETOOTH_HCI_H4_WITH_PHDR: u32 = 201;
+
+// Size of the pseudo-header containing the direction of the packet
+const PSEUDO_HEADER_SIZE: usize = 4;
+
+/// Writer of HCI captures in the pcap format, with the `LINKTYPE_BLUETOOTH_HCI_H4_WITH_PHDR`
+/// link type.
+///
+/// The generated files can be opened in Wireshark or tcpdump. When used as an [`HciTracer`], the
+/// packets are timestamped with the system time, and the first write error is kept and returned by
+/// [`PcapWriter::finish`].
+///
+/// See [the link type description](https://www.tcpdump.org/linktypes/LINKTYPE_BLUETOOTH_HCI_H4_WITH_PHDR.html).
+#[derive(Debug)]
+pub struct PcapWriter<W>
+where
+    W: Write,
+{
+    writer: W,
+    error: Option<io::Error>,
+}
+
+impl PcapWriter<BufWriter<File>> {
+    /// Create a pcap file at the given path, replacing it if it already exists.
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::new(BufWriter::new(File::create(path)?))
+    }
+}
+
+impl<W> PcapWriter<W>
+where
+    W: Write,
+{
+    /// Start a pcap capture by writing its global header.
+    pub fn new(mut writer: W) -> io::Result<Self> {
+        writer.write_all(&PCAP_MAGIC_NUMBER.to_le_bytes())?;
+        writer.write_all(&PCAP_VERSION_MAJOR.to_le_bytes())?;
+        writer.write_all(&PCAP_VERSION_MINOR.to_le_bytes())?;
+        // Time zone offset and timestamps accuracy
+        writer.write_all(&0i32.to_le_bytes())?;
+        writer.write_all(&0u32.to_le_bytes())?;
+        writer.write_all(&PCAP_SNAPLEN.to_le_bytes())?;
+        writer.write_all(&LINKTYPE_BLUETOOTH_HCI_H4_WITH_PHDR.to_le_bytes())?;
+        Ok(Self {
+            writer,
+            error: None,
+        })
+    }
+
+    /// Write a packet record, the packet including its HCI packet indicator.
+    pub fn write_packet(
+        &mut self,
+        direction: PacketDirection,
+        packet: &[u8],
+        timestamp: SystemTime,
+    ) -> io::Result<()> {
+        let length = u32::try_from(PSEUDO_HEADER_SIZE + packet.len())
+            .map_err(|_| io::Error::from(io::ErrorKind::InvalidInput))?;
+        let direction: u32 = match direction {
+            PacketDirection::HostToController => 0,
+            PacketDirection::ControllerToHost => 1,
+        };
+        let timestamp = timestamp.duration_since(UNIX_EPOCH).unwrap_or_default();
+
+        // Timestamp seconds and microseconds, included length and original length
+        self.writer
+            .write_all(&(timestamp.as_secs() as u32).to_le_bytes())?;
+        self.writer
+            .write_all(&timestamp.subsec_micros().to_le_bytes())?;
+        self.writer.write_all(&length.to_le_bytes())?;
+        self.writer.write_all(&length.to_le_bytes())?;
+        // The pseudo-header is in network byte order
+        self.writer.write_all(&direction.to_be_bytes())?;
+        self.writer.write_all(packet)
+    }
+
+    /// Flush the capture and give back the underlying writer.
+    ///
+    /// Fails with the first error that occurred while tracing packets, if any.
+    pub fn finish(mut self) -> io::Result<W> {
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+impl<W> HciTracer for PcapWriter<W>
+where
+    W: Write,
+{
+    fn trace(&mut self, direction: PacketDirection, packet: &[u8]) {
+        if self.error.is_none() {
+            if let Err(err) = self.write_packet(direction, packet, SystemTime::now()) {
+                self.error = Some(err);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+    use std::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn test_pcap_header() {
+        let writer = PcapWriter::new(Vec::new()).unwrap();
+        assert_eq!(
+            writer.finish().unwrap(),
+            &[
+                0xD4, 0xC3, 0xB2, 0xA1, 2, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xFF, 0xFF, 0, 0, 201,
+                0, 0, 0
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pcap_packet_records() {
+        let timestamp = UNIX_EPOCH + Duration::from_micros(2_000_003);
+        let mut writer = PcapWriter::new(Vec::new()).unwrap();
+        writer
+            .write_packet(
+                PacketDirection::HostToController,
+                &[0x01, 0x03, 0x0C, 0x00],
+                timestamp,
+            )
+            .unwrap();
+        writer
+            .write_packet(
+                PacketDirection::ControllerToHost,
+                &[0x04, 0x0E, 0x04, 0x01, 0x03, 0x0C, 0x00],
+                timestamp,
+            )
+            .unwrap();
+        let data = writer.finish().unwrap();
+        assert_eq!(
+            &data[24..48],
+            &[2, 0, 0, 0, 3, 0, 0, 0, 8, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0x01, 0x03, 0x0C, 0x00]
+        );
+        assert_eq!(
+            &data[48..],
+            &[
+                2, 0, 0, 0, 3, 0, 0, 0, 11, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 1, 0x04, 0x0E, 0x04,
+                0x01, 0x03, 0x0C, 0x00
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pcap_tracer() {
+        let mut writer = PcapWriter::new(Vec::new()).unwrap();
+        writer.trace(PacketDirection::HostToController, &[0x01, 0x03, 0x0C, 0x00]);
+        let data = writer.finish().unwrap();
+        assert_eq!(data.len(), 24 + 16 + 4 + 4);
+        assert_eq!(&data[40..], &[0, 0, 0, 0, 0x01, 0x03, 0x0C, 0x00]);
+    }
+}
@@ -0,0 +1,344 @@
+use core::fmt;
+use core::iter::Peekable;
+
+use heapless::Vec;
+
+use crate::capture::PacketDirection;
+use crate::{HciDriver, HciDriverError};
+
+// Packet Type (1) + HCI command or event packet
+const REPORTED_PACKET_MAX_SIZE: usize = 259;
+
+/// Copy of a packet kept in a [`ReplayDivergence`], truncated if it is too long.
+pub type ReportedPacket = Vec<u8, REPORTED_PACKET_MAX_SIZE>;
+
+fn reported_packet(packet: &[u8]) -> ReportedPacket {
+    let len = packet.len().min(REPORTED_PACKET_MAX_SIZE);
+    // INVARIANT: The length has been limited to the capacity of the vector.
+    Vec::from_slice(&packet[..len]).unwrap()
+}
+
+/// Description of the first difference between the replayed trace and the behaviour of the Host.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::large_enum_variant)]
+pub enum ReplayDivergence {
+    /// The Host wrote a packet that is not the one of the trace.
+    UnexpectedWrite {
+        /// Index of the diverging step in the trace.
+        step: usize,
+        /// Packet expected to be written, `None` if a packet was expected to be read instead or
+        /// if the trace was over.
+        expected: Option<ReportedPacket>,
+        /// Packet written by the Host.
+        actual: ReportedPacket,
+    },
+    /// The Host waited for a packet while the trace expected it to write one.
+    UnexpectedRead {
+        /// Index of the diverging step in the trace.
+        step: usize,
+        /// Packet expected to be written.
+        expected: ReportedPacket,
+    },
+    /// The Host stopped before the end of the trace.
+    Unfinished {
+        /// Index of the first step that has not been replayed.
+        step: usize,
+        /// Direction of the first packet that has not been replayed.
+        direction: PacketDirection,
+        /// First packet that has not been replayed.
+        expected: ReportedPacket,
+    },
+}
+
+struct HexPacket<'a>(&'a [u8]);
+
+impl fmt::Display for HexPacket<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, byte) in self.0.iter().enumerate() {
+            if i != 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for ReplayDivergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedWrite {
+                step,
+                expected: Some(expected),
+                actual,
+            } => {
+                let offset = expected
+                    .iter()
+                    .zip(actual.iter())
+                    .position(|(expected, actual)| expected != actual)
+                    .unwrap_or(expected.len().min(actual.len()));
+                writeln!(f, "step {step}: the host wrote an unexpected packet")?;
+                writeln!(f, "  expected: {}", HexPacket(expected))?;
+                writeln!(f, "  actual:   {}", HexPacket(actual))?;
+                write!(
+                    f,
+                    "            {:>width$} first difference at byte {offset}",
+                    "^^",
+                    width = 3 * offset + 2
+                )
+            }
+            Self::UnexpectedWrite {
+                step,
+                expected: None,
+                actual,
+            } => write!(
+                f,
+                "step {step}: the host wrote {} while a packet from the controller or the end of the trace was expected",
+                HexPacket(actual)
+            ),
+            Self::UnexpectedRead { step, expected } => write!(
+                f,
+                "step {step}: the host waited for the controller while it was expected to write {}",
+                HexPacket(expected)
+            ),
+            Self::Unfinished {
+                step,
+                direction,
+                expected,
+            } => write!(
+                f,
+                "step {step}: the trace is not over, next packet is {} ({direction:?})",
+                HexPacket(expected)
+            ),
+        }
+    }
+}
+
+/// HCI driver replaying a recorded trace, acting as the Controller.
+///
+/// Each packet written by the Host is checked against the next packet of the trace, and the
+/// packets of the trace coming from the Controller are delivered to the Host when it reads, one
+/// packet at a time. The trace can be a scripted sequence of packets, or a BTSnoop capture with
+/// the `std` feature.
+///
+/// On the first divergence, reads and writes fail and the divergence is kept to be reported by
+/// [`ReplayHciDriver::check`]. When the whole trace has been replayed, reads fail as if the
+/// transport had been closed.
+#[derive(Debug)]
+pub struct ReplayHciDriver<I, P>
+where
+    I: Iterator<Item = (PacketDirection, P)>,
+    P: AsRef<[u8]>,
+{
+    steps: Peekable<I>,
+    step: usize,
+    read_offset: usize,
+    divergence: Option<ReplayDivergence>,
+}
+
+impl<I, P> ReplayHciDriver<I, P>
+where
+    I: Iterator<Item = (PacketDirection, P)>,
+    P: AsRef<[u8]>,
+{
+    pub fn new(steps: impl IntoIterator<IntoIter = I>) -> Self {
+        Self {
+            steps: steps.into_iter().peekable(),
+            step: 0,
+            read_offset: 0,
+            divergence: None,
+        }
+    }
+
+    /// Get the divergence that occurred, if any.
+    pub fn divergence(&self) -> Option<&ReplayDivergence> {
+        self.divergence.as_ref()
+    }
+
+    /// Check that the Host behaved as recorded in the whole trace.
+    ///
+    /// Stopping before the end of the trace is a divergence as well.
+    pub fn check(&mut self) -> Result<(), &ReplayDivergence> {
+        if self.divergence.is_none() {
+            if let Some((direction, packet)) = self.steps.peek() {
+                let divergence = ReplayDivergence::Unfinished {
+                    step: self.step,
+                    direction: *direction,
+                    expected: reported_packet(packet.as_ref()),
+                };
+                self.diverge(divergence);
+            }
+        }
+        match &self.divergence {
+            Some(divergence) => Err(divergence),
+            None => Ok(()),
+        }
+    }
+
+    fn diverge(&mut self, divergence: ReplayDivergence) {
+        #[cfg(feature = "defmt")]
+        defmt::error!("Replay divergence at step {}", self.step);
+        self.divergence = Some(divergence);
+    }
+
+    fn next_step(&mut self) {
+        self.steps.next();
+        self.step += 1;
+        self.read_offset = 0;
+    }
+}
+
+impl<I, P> HciDriver for ReplayHciDriver<I, P>
+where
+    I: Iterator<Item = (PacketDirection, P)>,
+    P: AsRef<[u8]>,
+{
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, HciDriverError> {
+        if self.divergence.is_some() {
+            return Err(HciDriverError::ReadFailure);
+        }
+        let (len, complete) = match self.steps.peek() {
+            Some((PacketDirection::ControllerToHost, packet)) => {
+                let remaining = &packet.as_ref()[self.read_offset..];
+                let len = remaining.len().min(buf.len());
+                buf[..len].copy_from_slice(&remaining[..len]);
+                (len, len == remaining.len())
+            }
+            Some((PacketDirection::HostToController, packet)) => {
+                let divergence = ReplayDivergence::UnexpectedRead {
+                    step: self.step,
+                    expected: reported_packet(packet.as_ref()),
+                };
+                self.diverge(divergence);
+                return Err(HciDriverError::ReadFailure);
+            }
+            None => return Err(HciDriverError::ReadFailure),
+        };
+        if complete {
+            self.next_step();
+        } else {
+            self.read_offset += len;
+        }
+        Ok(len)
+    }
+
+    async fn write(&mut self, buf: &[u8]) -> Result<usize, HciDriverError> {
+        if self.divergence.is_some() {
+            return Err(HciDriverError::WriteFailure);
+        }
+        let expected = match self.steps.peek() {
+            Some((PacketDirection::HostToController, packet)) if packet.as_ref() == buf => {
+                self.next_step();
+                return Ok(buf.len());
+            }
+            Some((PacketDirection::HostToController, packet)) => {
+                Some(reported_packet(packet.as_ref()))
+            }
+            _ => None,
+        };
+        let divergence = ReplayDivergence::UnexpectedWrite {
+            step: self.step,
+            expected,
+            actual: reported_packet(buf),
+        };
+        self.diverge(divergence);
+        Err(HciDriverError::WriteFailure)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use std::format;
+
+    use super::*;
+    use crate::{Error, Hci};
+
+    const RESET_SCRIPT: &[(PacketDirection, &[u8])] = &[
+        (PacketDirection::HostToController, &[1, 3, 12, 0]),
+        (PacketDirection::ControllerToHost, &[4, 14, 4, 1, 3, 12, 0]),
+    ];
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_replay_success() {
+        let mut driver = ReplayHciDriver::new(RESET_SCRIPT.iter().copied());
+        let mut hci = Hci::new(&mut driver);
+        assert_eq!(hci.cmd_reset().await, Ok(()));
+        assert_eq!(
+            hci.wait_for_event().await,
+            Err(Error::HciDriver(HciDriverError::ReadFailure))
+        );
+        assert_eq!(driver.check(), Ok(()));
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_replay_unexpected_write() {
+        let mut driver = ReplayHciDriver::new(RESET_SCRIPT.iter().copied());
+        let mut hci = Hci::new(&mut driver);
+        assert_eq!(
+            hci.cmd_read_bd_addr().await,
+            Err(Error::HciDriver(HciDriverError::WriteFailure))
+        );
+        let divergence = driver.check().unwrap_err();
+        assert_eq!(
+            divergence,
+            &ReplayDivergence::UnexpectedWrite {
+                step: 0,
+                expected: Some(reported_packet(&[1, 3, 12, 0])),
+                actual: reported_packet(&[1, 9, 16, 0]),
+            }
+        );
+        assert_eq!(
+            format!("{divergence}"),
+            "step 0: the host wrote an unexpected packet\n  expected: 01 03 0c 00\n  actual:   01 09 10 00\n               ^^ first difference at byte 1"
+        );
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_replay_unexpected_read() {
+        let mut driver = ReplayHciDriver::new(RESET_SCRIPT.iter().copied());
+        let mut hci = Hci::new(&mut driver);
+        assert!(hci.wait_for_event().await.is_err());
+        let divergence = driver.check().unwrap_err().clone();
+        assert_eq!(
+            format!("{divergence}"),
+            "step 0: the host waited for the controller while it was expected to write 01 03 0c 00"
+        );
+        // The divergence is sticky
+        assert_eq!(
+            driver.write(&[1, 3, 12, 0]).await,
+            Err(HciDriverError::WriteFailure)
+        );
+        assert_eq!(driver.divergence(), Some(&divergence));
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_replay_write_after_end() {
+        let mut driver = ReplayHciDriver::new(RESET_SCRIPT.iter().copied());
+        let mut hci = Hci::new(&mut driver);
+        assert_eq!(hci.cmd_reset().await, Ok(()));
+        assert!(hci.cmd_reset().await.is_err());
+        assert_eq!(
+            format!("{}", driver.check().unwrap_err()),
+            "step 2: the host wrote 01 03 0c 00 while a packet from the controller or the end of the trace was expected"
+        );
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_replay_unfinished() {
+        let mut driver = ReplayHciDriver::new(RESET_SCRIPT.iter().copied());
+        assert_eq!(driver.write(&[1, 3, 12, 0]).await, Ok(4));
+        let mut buf = [0; 4];
+        assert_eq!(driver.read(&mut buf).await, Ok(4));
+        assert_eq!(buf, [4, 14, 4, 1]);
+        assert_eq!(
+            driver.check(),
+            Err(&ReplayDivergence::Unfinished {
+                step: 1,
+                direction: PacketDirection::ControllerToHost,
+                expected: reported_packet(&[4, 14, 4, 1, 3, 12, 0]),
+            })
+        );
+    }
+}
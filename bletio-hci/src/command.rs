@@ -2,10 +2,10 @@ use bletio_utils::{Buffer, BufferOps, EncodeToBuffer};
 use num_enum::{FromPrimitive, IntoPrimitive};
 
 use crate::{
-    AdvertisingData, AdvertisingEnable, AdvertisingParameters, ConnectionHandle,
-    ConnectionParameters, ConnectionUpdateParameters, Error, EventMask, FilterDuplicates,
-    LeEventMask, LeFilterAcceptListAddress, PacketType, RandomStaticDeviceAddress, Reason,
-    ScanEnable, ScanParameters,
+    AdvertisingData, AdvertisingEnable, AdvertisingParameters, BigCreateSyncParameters, BigHandle,
+    BigParameters, ConnectionHandle, ConnectionParameters, ConnectionUpdateParameters, Error,
+    EventMask, FilterDuplicates, LeEventMask, LeFilterAcceptListAddress, PacketType,
+    RandomStaticDeviceAddress, Reason, ScanEnable, ScanParameters,
 };
 
 const NOP_OGF: u16 = 0x00;
@@ -21,7 +21,7 @@ const fn opcode(ogf: u16, ocf: u16) -> u16 {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, IntoPrimitive, FromPrimitive)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u16)]
-pub(crate) enum CommandOpCode {
+pub enum CommandOpCode {
     Nop = opcode(NOP_OGF, 0x0000),
     Disconnect = opcode(LINK_CONTROL_OGF, 0x0006),
     SetEventMask = opcode(CONTROLLER_AND_BASEBAND_OGF, 0x0001),
@@ -51,16 +51,34 @@ pub(crate) enum CommandOpCode {
     // LeEncrypt = opcode(LE_CONTROLLER_OGF, 0x0017),
     LeRand = opcode(LE_CONTROLLER_OGF, 0x0018),
     LeReadSupportedStates = opcode(LE_CONTROLLER_OGF, 0x001C),
+    LeCreateBig = opcode(LE_CONTROLLER_OGF, 0x0068),
+    LeTerminateBig = opcode(LE_CONTROLLER_OGF, 0x006A),
+    LeBigCreateSync = opcode(LE_CONTROLLER_OGF, 0x006B),
+    LeBigTerminateSync = opcode(LE_CONTROLLER_OGF, 0x006C),
     #[num_enum(catch_all)]
     Unsupported(u16),
 }
 
+impl EncodeToBuffer for CommandOpCode {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        buffer.encode_le_u16((*self).into())
+    }
+
+    fn encoded_size(&self) -> usize {
+        size_of::<u16>()
+    }
+}
+
+/// HCI command sent by the Host to the Controller.
 #[derive(Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub(crate) enum Command {
+pub enum Command {
     Disconnect(ConnectionHandle, Reason),
     LeAddDeviceToFilterAcceptList(LeFilterAcceptListAddress),
+    LeBigCreateSync(BigCreateSyncParameters),
+    LeBigTerminateSync(BigHandle),
     LeClearFilterAcceptList,
+    LeCreateBig(BigParameters),
     LeCreateConnection(ConnectionParameters),
     LeCreateConnectionCancel,
     LeConnectionUpdate(ConnectionUpdateParameters),
@@ -80,6 +98,7 @@ pub(crate) enum Command {
     LeSetScanEnable(ScanEnable, FilterDuplicates),
     LeSetScanParameters(ScanParameters),
     LeSetScanResponseData(AdvertisingData),
+    LeTerminateBig(BigHandle, Reason),
     Nop,
     ReadBdAddr,
     ReadBufferSize,
@@ -114,9 +133,18 @@ impl Command {
             | Command::LeRemoveDeviceFromFilterAcceptList(address) => {
                 CommandPacket::new(self.opcode()).encode(address)?
             }
+            Command::LeBigCreateSync(parameters) => {
+                CommandPacket::new(self.opcode()).encode(parameters)?
+            }
+            Command::LeBigTerminateSync(big_handle) => {
+                CommandPacket::new(self.opcode()).encode(big_handle)?
+            }
             Command::LeConnectionUpdate(parameters) => {
                 CommandPacket::new(self.opcode()).encode(parameters)?
             }
+            Command::LeCreateBig(parameters) => {
+                CommandPacket::new(self.opcode()).encode(parameters)?
+            }
             Command::LeCreateConnection(parameters) => {
                 CommandPacket::new(self.opcode()).encode(parameters)?
             }
@@ -146,6 +174,9 @@ impl Command {
             Command::LeSetScanResponseData(data) => {
                 CommandPacket::new(self.opcode()).encode(data)?
             }
+            Command::LeTerminateBig(big_handle, reason) => CommandPacket::new(self.opcode())
+                .encode(big_handle)?
+                .encode(reason)?,
             Command::SetEventMask(event_mask) => {
                 CommandPacket::new(self.opcode()).encode(event_mask)?
             }
@@ -153,12 +184,15 @@ impl Command {
         })
     }
 
-    pub(crate) const fn opcode(&self) -> CommandOpCode {
+    pub const fn opcode(&self) -> CommandOpCode {
         match self {
             Self::Disconnect(_, _) => CommandOpCode::Disconnect,
             Self::LeAddDeviceToFilterAcceptList(_) => CommandOpCode::LeAddDeviceToFilterAcceptList,
+            Self::LeBigCreateSync(_) => CommandOpCode::LeBigCreateSync,
+            Self::LeBigTerminateSync(_) => CommandOpCode::LeBigTerminateSync,
             Self::LeClearFilterAcceptList => CommandOpCode::LeClearFilterAcceptList,
             Self::LeConnectionUpdate(_) => CommandOpCode::LeConnectionUpdate,
+            Self::LeCreateBig(_) => CommandOpCode::LeCreateBig,
             Self::LeCreateConnection(_) => CommandOpCode::LeCreateConnection,
             Self::LeCreateConnectionCancel => CommandOpCode::LeCreateConnectionCancel,
             Self::LeRand => CommandOpCode::LeRand,
@@ -180,6 +214,7 @@ impl Command {
             Self::LeSetScanEnable(_, _) => CommandOpCode::LeSetScanEnable,
             Self::LeSetScanParameters(_) => CommandOpCode::LeSetScanParameters,
             Self::LeSetScanResponseData(_) => CommandOpCode::LeSetScanResponseData,
+            Self::LeTerminateBig(_, _) => CommandOpCode::LeTerminateBig,
             Self::Nop => CommandOpCode::Nop,
             Self::ReadBdAddr => CommandOpCode::ReadBdAddr,
             Self::ReadBufferSize => CommandOpCode::ReadBufferSize,
@@ -240,6 +275,10 @@ pub(crate) mod parser {
         advertising_data::parser::advertising_data, advertising_enable::parser::advertising_enable,
         advertising_parameters::parser::advertising_parameters,
     };
+    use crate::big::{
+        big_create_sync_parameters::parser::big_create_sync_parameters,
+        big_handle::parser::big_handle, big_parameters::parser::big_parameters,
+    };
     use crate::common::{
         device_address::parser::random_address, event_mask::parser::event_mask,
         le_event_mask::parser::le_event_mask,
@@ -253,7 +292,7 @@ pub(crate) mod parser {
     use crate::scanning::{
         scan_enable::parser::scan_enable_parameters, scan_parameters::parser::scan_parameters,
     };
-    use crate::{Command, CommandOpCode, ConnectionHandle, Packet, Reason};
+    use crate::{BigHandle, Command, CommandOpCode, ConnectionHandle, Packet, Reason};
 
     pub(crate) fn command_opcode(input: &[u8]) -> IResult<&[u8], CommandOpCode> {
         map(le_u16, CommandOpCode::from).parse(input)
@@ -263,7 +302,13 @@ pub(crate) mod parser {
         (connection_handle, reason).parse(input)
     }
 
-    pub(crate) fn command(input: &[u8]) -> IResult<&[u8], Packet> {
+    fn le_terminate_big(input: &[u8]) -> IResult<&[u8], (BigHandle, Reason)> {
+        (big_handle, reason).parse(input)
+    }
+
+    pub(crate) fn command<const ACL_DATA_SIZE: usize>(
+        input: &[u8],
+    ) -> IResult<&[u8], Packet<ACL_DATA_SIZE>> {
         let (input, (command_opcode, parameter_total_length)) =
             pair(command_opcode, parameter_total_length).parse(input)?;
         let (input, parameters) = take(parameter_total_length).parse(input)?;
@@ -279,12 +324,24 @@ pub(crate) mod parser {
                         le_filter_accept_list_address(parameters)?;
                     Command::LeAddDeviceToFilterAcceptList(le_filter_accept_list_address)
                 }
+                CommandOpCode::LeBigCreateSync => {
+                    let (_, big_create_sync_parameters) = big_create_sync_parameters(parameters)?;
+                    Command::LeBigCreateSync(big_create_sync_parameters)
+                }
+                CommandOpCode::LeBigTerminateSync => {
+                    let (_, big_handle) = big_handle(parameters)?;
+                    Command::LeBigTerminateSync(big_handle)
+                }
                 CommandOpCode::LeClearFilterAcceptList => Command::LeClearFilterAcceptList,
                 CommandOpCode::LeConnectionUpdate => {
                     let (_, connection_update_parameters) =
                         connection_update_parameters(parameters)?;
                     Command::LeConnectionUpdate(connection_update_parameters)
                 }
+                CommandOpCode::LeCreateBig => {
+                    let (_, big_parameters) = big_parameters(parameters)?;
+                    Command::LeCreateBig(big_parameters)
+                }
                 CommandOpCode::LeCreateConnection => {
                     let (_, connection_parameters) = connection_parameters(parameters)?;
                     Command::LeCreateConnection(connection_parameters)
@@ -337,6 +394,10 @@ pub(crate) mod parser {
                     let (_, scan_response_data) = advertising_data(parameters)?;
                     Command::LeSetScanResponseData(scan_response_data)
                 }
+                CommandOpCode::LeTerminateBig => {
+                    let (_, (big_handle, reason)) = le_terminate_big(parameters)?;
+                    Command::LeTerminateBig(big_handle, reason)
+                }
                 CommandOpCode::Nop => Command::Nop,
                 CommandOpCode::ReadBdAddr => Command::ReadBdAddr,
                 CommandOpCode::ReadBufferSize => Command::ReadBufferSize,
@@ -356,9 +417,10 @@ pub(crate) mod parser {
 #[cfg(test)]
 mod test {
     use crate::{
-        packet::parser::packet, AdvertisingChannelMap, AdvertisingFilterPolicy,
-        AdvertisingIntervalRange, AdvertisingType, DeviceAddress, OwnAddressType, Packet,
-        PublicDeviceAddress, RandomAddress,
+        packet::parser::packet, AdvertisingChannelMap, AdvertisingFilterPolicy, AdvertisingHandle,
+        AdvertisingIntervalRange, AdvertisingType, BigSyncTimeout, DeviceAddress, Framing,
+        OwnAddressType, Packet, Packing, Phy, PublicDeviceAddress, RandomAddress, SduInterval,
+        SyncHandle,
     };
 
     use super::*;
@@ -401,6 +463,9 @@ mod test {
         CommandOpCode::LeAddDeviceToFilterAcceptList,
         &[1, 17, 32, 7, 0, 0x38, 0x5E, 0x43, 0xCA, 0x4C, 0x40]
     )]
+    #[case::le_big_terminate_sync(
+        Command::LeBigTerminateSync(BigHandle::try_new(0x02).unwrap()), CommandOpCode::LeBigTerminateSync, &[1, 108, 32, 1, 2]
+    )]
     #[case::le_clear_filter_accept_list(Command::LeClearFilterAcceptList, CommandOpCode::LeClearFilterAcceptList, &[1, 16, 32, 0])]
     #[case::le_connection_update(
         Command::LeConnectionUpdate(ConnectionUpdateParameters::default()),
@@ -464,6 +529,11 @@ mod test {
         CommandOpCode::LeSetScanResponseData,
         &[1, 9, 32, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
     )]
+    #[case::le_terminate_big(
+        Command::LeTerminateBig(BigHandle::try_new(0x01).unwrap(), Reason::RemoteUserTerminatedConnection),
+        CommandOpCode::LeTerminateBig,
+        &[1, 106, 32, 2, 1, 19]
+    )]
     #[case::nop(Command::Nop, CommandOpCode::Nop, &[1, 0, 0, 0])]
     #[case::read_bd_addr(Command::ReadBdAddr, CommandOpCode::ReadBdAddr, &[1, 9, 16, 0])]
     #[case::read_buffer_size(Command::ReadBufferSize, CommandOpCode::ReadBufferSize, &[1, 5, 16, 0])]
@@ -540,11 +610,39 @@ mod test {
         Command::LeAddDeviceToFilterAcceptList(PublicDeviceAddress::from([0x38, 0x5E, 0x43, 0xCA, 0x4C, 0x40]).into()),
         &[1, 17, 32, 7, 0, 0x38, 0x5E, 0x43, 0xCA, 0x4C, 0x40]
     )]
+    #[case::le_big_create_sync(
+        Command::LeBigCreateSync(BigCreateSyncParameters::try_new(
+            BigHandle::try_new(1).unwrap(),
+            SyncHandle::try_new(1).unwrap(),
+            None,
+            0,
+            BigSyncTimeout::default(),
+            &[1, 2],
+        ).unwrap()),
+        &[1, 107, 32, 26, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 200, 0, 2, 1, 2]
+    )]
+    #[case::le_big_terminate_sync(Command::LeBigTerminateSync(BigHandle::try_new(0x02).unwrap()), &[1, 108, 32, 1, 2])]
     #[case::le_clear_filter_accept_list(Command::LeClearFilterAcceptList, &[1, 16, 32, 0])]
     #[case::le_connection_update(
         Command::LeConnectionUpdate(ConnectionUpdateParameters::default()),
         &[1, 19, 32, 14, 0, 0, 64, 0, 64, 0, 0, 0, 32, 0, 0, 0, 0, 0]
     )]
+    #[case::le_create_big(
+        Command::LeCreateBig(BigParameters::try_new(
+            BigHandle::try_new(1).unwrap(),
+            AdvertisingHandle::try_new(0).unwrap(),
+            2,
+            SduInterval::default(),
+            100,
+            20,
+            2,
+            Phy::Le2M,
+            Packing::Sequential,
+            Framing::Unframed,
+            None,
+        ).unwrap()),
+        &[1, 104, 32, 31, 1, 0, 2, 16, 39, 0, 100, 0, 20, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+    )]
     #[case::le_create_connection(
         Command::LeCreateConnection(ConnectionParameters::default()),
         &[1, 13, 32, 25, 16, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0, 64, 0, 0, 0, 32, 0, 0, 0, 0, 0]
@@ -602,6 +700,10 @@ mod test {
         Command::LeSetScanResponseData(AdvertisingData::default()),
         &[1, 9, 32, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
     )]
+    #[case::le_terminate_big(
+        Command::LeTerminateBig(BigHandle::try_new(0x01).unwrap(), Reason::RemoteUserTerminatedConnection),
+        &[1, 106, 32, 2, 1, 19]
+    )]
     #[case::nop(Command::Nop, &[1, 0, 0, 0])]
     #[case::read_bd_addr(Command::ReadBdAddr, &[1, 9, 16, 0])]
     #[case::read_buffer_size(Command::ReadBufferSize, &[1, 5, 16, 0])]
@@ -613,6 +715,7 @@ mod test {
         &[1, 1, 12, 8, 0, 128, 0, 2, 0, 0, 0, 0]
     )]
     fn test_supported_command_parsing(#[case] command: Command, #[case] input: &[u8]) {
+        assert_eq!(command.encode().unwrap().data(), input);
         let (rest, hci_packet) = packet(input).unwrap();
         assert_eq!(hci_packet, Packet::Command(command));
         assert!(rest.is_empty());
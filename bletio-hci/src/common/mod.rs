@@ -6,6 +6,7 @@ pub(crate) mod le_filter_accept_list_address;
 pub(crate) mod le_states;
 pub(crate) mod own_address_type;
 pub(crate) mod peer_address_type;
+pub(crate) mod phy;
 pub(crate) mod rssi;
 pub(crate) mod supported_commands;
 pub(crate) mod supported_features;
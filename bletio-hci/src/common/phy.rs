@@ -0,0 +1,97 @@
+use bletio_utils::{BufferOps, EncodeToBuffer};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+use crate::Error;
+
+/// PHY used to transmit or receive packets.
+///
+/// See [Core Specification 6.0, Vol.4, Part E, 7.7.65.27](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host-controller-interface/host-controller-interface-functional-specification.html).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, IntoPrimitive, TryFromPrimitive)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[num_enum(error_type(name = Error, constructor = Error::InvalidPhy))]
+#[repr(u8)]
+#[non_exhaustive]
+pub enum Phy {
+    /// LE 1M PHY (default).
+    #[default]
+    Le1M = 0x01,
+    /// LE 2M PHY.
+    Le2M = 0x02,
+    /// LE Coded PHY.
+    LeCoded = 0x03,
+}
+
+impl Phy {
+    /// Get the bit corresponding to this PHY in a PHY bit field, as used in commands where
+    /// the Host indicates its preferred PHYs.
+    pub(crate) const fn bit(&self) -> u8 {
+        1 << ((*self as u8) - 1)
+    }
+}
+
+impl EncodeToBuffer for Phy {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        buffer.try_push((*self).into())
+    }
+
+    fn encoded_size(&self) -> usize {
+        size_of::<Phy>()
+    }
+}
+
+pub(crate) mod parser {
+    use nom::{combinator::map_res, number::complete::le_u8, IResult, Parser};
+
+    use super::*;
+
+    pub(crate) fn phy(input: &[u8]) -> IResult<&[u8], Phy> {
+        map_res(le_u8, TryInto::try_into).parse(input)
+    }
+
+    pub(crate) fn phy_bit(input: &[u8]) -> IResult<&[u8], Phy> {
+        map_res(le_u8, |v| match v {
+            0b001 => Ok(Phy::Le1M),
+            0b010 => Ok(Phy::Le2M),
+            0b100 => Ok(Phy::LeCoded),
+            _ => Err(Error::InvalidPhy(v)),
+        })
+        .parse(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bletio_utils::Buffer;
+    use rstest::rstest;
+
+    use super::{parser::*, *};
+
+    #[rstest]
+    #[case(Phy::Le1M, 0x01, 0b001)]
+    #[case(Phy::Le2M, 0x02, 0b010)]
+    #[case(Phy::LeCoded, 0x03, 0b100)]
+    fn test_phy_success(
+        #[case] value: Phy,
+        #[case] encoded: u8,
+        #[case] bit: u8,
+    ) -> Result<(), bletio_utils::Error> {
+        let mut buffer = Buffer::<1>::default();
+        assert_eq!(value.encoded_size(), 1);
+        value.encode(&mut buffer)?;
+        assert_eq!(buffer.data(), &[encoded]);
+        assert_eq!(value.bit(), bit);
+        assert_eq!(phy(&[encoded]), Ok((&[] as &[u8], value)));
+        assert_eq!(phy_bit(&[bit]), Ok((&[] as &[u8], value)));
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(0x00)]
+    #[case(0x04)]
+    fn test_phy_failure(#[case] input: u8) {
+        let err: Result<Phy, Error> = input.try_into();
+        assert_eq!(err, Err(Error::InvalidPhy(input)));
+        assert!(phy(&[input]).is_err());
+        assert!(phy_bit(&[0b011]).is_err());
+    }
+}
@@ -1,3 +1,5 @@
+use bletio_utils::{BufferOps, EncodeToBuffer};
+
 use crate::{LeCombinedState, LeSingleState, LeState};
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
@@ -12,6 +14,16 @@ impl From<u64> for SupportedLeStates {
     }
 }
 
+impl EncodeToBuffer for SupportedLeStates {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        buffer.encode_le_u64(self.value)
+    }
+
+    fn encoded_size(&self) -> usize {
+        size_of::<u64>()
+    }
+}
+
 impl SupportedLeStates {
     pub fn is_supported(&self, state: impl Into<LeState>) -> bool {
         self.is_supported_internal(state.into())
@@ -10,20 +10,34 @@ pub enum Error {
     DataWillNotFitAclDataPacket,
     /// The provided data is too big to fit in an HCI command packet.
     DataWillNotFitCommandPacket,
+    /// The provided data is too big to fit in an HCI event packet.
+    DataWillNotFitEventPacket,
+    /// The provided data is too big to fit in an ISO data packet.
+    DataWillNotFitIsoDataPacket,
     /// HCI error code.
     ErrorCode(ErrorCode),
+    /// The event queue has been full, some events received from the Controller have been dropped.
+    EventQueueOverflow,
     /// An error coming from the HCI driver.
     HciDriver(HciDriverError),
     /// The provided advertising enable value is invalid.
     InvalidAdvertisingEnableValue(u8),
     /// The provided advertising filter policy is invalid.
     InvalidAdvertisingFilterPolicy(u8),
+    /// The provided advertising handle is invalid, it needs to be between 0x00 and 0xEF.
+    InvalidAdvertisingHandle(u8),
     /// The provided advertising interval value is invalid, it needs to be between 0x0020 and 0x4000.
     InvalidAdvertisingInterval(u16),
     /// The advertising interval range is invalid, the first value must be smaller or equal to the second one.
     InvalidAdvertisingIntervalRange,
     /// The provided advertising type is invalid.
     InvalidAdvertisingType(u8),
+    /// The provided BIG handle is invalid, it needs to be between 0x00 and 0xEF.
+    InvalidBigHandle(u8),
+    /// The provided BIG sync timeout is invalid, it needs to be between 0x000A and 0x4000.
+    InvalidBigSyncTimeout(u16),
+    /// The provided BIS index is invalid, it needs to be between 0x01 and 0x1F.
+    InvalidBisIndex(u8),
     /// The provided broadcast flag is invalid.
     InvalidBroadcastFlag(u8),
     /// The provided central clock accuracy is invalid.
@@ -40,14 +54,22 @@ pub enum Error {
     InvalidConnectionIntervalValue(u16),
     /// The connection peer address type value is invalid.
     InvalidConnectionPeerAddressType(u8),
+    /// The provided encryption value is invalid.
+    InvalidEncryption(u8),
     /// Invalid or unhandled HCI error code.
     InvalidErrorCode(u8),
     /// Invalid HCI event packet.
     InvalidEventPacket,
     /// The provided filter duplicates value is invalid.
     InvalidFilterDuplicatesValue(u8),
+    /// The provided framing value is invalid.
+    InvalidFraming(u8),
     /// The provided initiator filter policy is invalid.
     InvalidInitiatorFilterPolicy(u8),
+    /// The provided ISO packet boundary flag is invalid.
+    InvalidIsoPacketBoundaryFlag(u8),
+    /// The provided ISO packet status flag is invalid.
+    InvalidIsoPacketStatusFlag(u8),
     /// The provided LE advertising report event type is invalid.
     InvalidLeAdvertisingReportEventType(u8),
     /// The provided LE advertising report num reports is invalid.
@@ -56,6 +78,14 @@ pub enum Error {
     InvalidLeFilterAcceptListAddressType(u8),
     /// The provided max latency is invalid.
     InvalidLatency(u16),
+    /// The provided maximum SDU size is invalid, it needs to be between 0x0001 and 0x0FFF.
+    InvalidMaxSdu(u16),
+    /// The provided maximum transport latency is invalid, it needs to be between 0x0005 and 0x0FA0.
+    InvalidMaxTransportLatency(u16),
+    /// The provided maximum number of subevents is invalid, it needs to be between 0x00 and 0x1F.
+    InvalidMse(u8),
+    /// The provided number of BIS is invalid, it needs to be between 0x01 and 0x1F.
+    InvalidNumBis(u8),
     /// The provided own address type is invalid.
     InvalidOwnAddressType(u8),
     /// Invalid HCI packet, either malformed or not expected (e.g. Command received by the Host).
@@ -64,8 +94,12 @@ pub enum Error {
     InvalidPacketBoundaryFlag(u8),
     /// Invalid or unhandled HCI packet type.
     InvalidPacketType(u8),
+    /// The provided packing value is invalid.
+    InvalidPacking(u8),
     /// The provided peer address type is invalid.
     InvalidPeerAddressType(u8),
+    /// The provided PHY is invalid.
+    InvalidPhy(u8),
     /// The provided public device address is invalid.
     InvalidPublicDeviceAddress,
     /// The provided random address is invalid.
@@ -78,6 +112,8 @@ pub enum Error {
     InvalidRandomStaticDeviceAddress,
     /// The provided reason is invalid.
     InvalidReason(u8),
+    /// The provided retransmission number is invalid, it needs to be between 0x00 and 0x1E.
+    InvalidRetransmissionNumber(u8),
     /// The provided role is invalid.
     InvalidRole(u8),
     /// The provided RSSI value is invalid.
@@ -92,15 +128,27 @@ pub enum Error {
     InvalidScanWindow(u16),
     /// The provided scanning filter policy is invalid.
     InvalidScanningFilterPolicy(u8),
+    /// The provided SDU interval is invalid, it needs to be between 0x0000FF and 0x0FFFFF.
+    InvalidSduInterval(u32),
     /// The provided supervision timeout is invalid.
     InvalidSupervisionTimeout(u16),
+    /// The provided sync handle is invalid, it needs to be between 0x0000 and 0x0EFF.
+    InvalidSyncHandle(u16),
     /// The provided TX power level value is invalid.
     InvalidTxPowerLevelValue(i8),
+    /// The ISO data queue has been full, some ISO data packets received from the Controller have
+    /// been dropped.
+    IsoDataQueueOverflow,
+    /// No command with this opcode is waiting for its response.
+    NoPendingCommand(u16),
     /// The scan window must be smaller or equal to the scan interval.
     ScanWindowMustBeSmallerOrEqualToScanInterval,
     /// The Supervision_Timeout in milliseconds shall be larger than (1 + Max_Latency) ×
     /// Connection_Interval_Max × 2, where Connection_Interval_Max is given in milliseconds.
     SupervisionTimeoutIsNotBigEnough,
+    /// Too many commands are waiting for their response, some responses need to be retrieved
+    /// before sending another command.
+    TooManyPendingCommands,
     /// The controller sent an unexpected event in response to a command.
     UnexpectedEvent,
 }
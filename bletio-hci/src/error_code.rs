@@ -1,10 +1,11 @@
-use num_enum::TryFromPrimitive;
+use bletio_utils::{BufferOps, EncodeToBuffer};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
 
 use crate::Error;
 
 /// HCI error codes as defined in
 /// [Core Specification 6.0, Vol.1, Part F](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/architecture,-change-history,-and-conventions/controller-error-codes.html).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive, IntoPrimitive)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[num_enum(error_type(name = Error, constructor = Error::InvalidErrorCode))]
 #[repr(u8)]
@@ -194,8 +195,20 @@ impl ErrorCode {
     }
 }
 
+impl EncodeToBuffer for ErrorCode {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        buffer.try_push((*self).into())?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        size_of::<ErrorCode>()
+    }
+}
+
 #[cfg(test)]
 mod test {
+    use bletio_utils::Buffer;
     use rstest::rstest;
 
     use super::*;
@@ -221,6 +234,19 @@ mod test {
         assert_eq!(err, Err(Error::InvalidErrorCode(input)));
     }
 
+    #[rstest]
+    #[case(ErrorCode::Success, &[0x00])]
+    #[case(ErrorCode::ConnectionTerminatedByLocalHost, &[0x16])]
+    fn test_hci_error_code_encoding(
+        #[case] error_code: ErrorCode,
+        #[case] expected: &[u8],
+    ) -> Result<(), bletio_utils::Error> {
+        let mut buffer = Buffer::<1>::default();
+        assert_eq!(error_code.encode(&mut buffer)?, expected.len());
+        assert_eq!(buffer.data(), expected);
+        Ok(())
+    }
+
     #[test]
     fn test_hci_error_code_is_success() {
         assert!(ErrorCode::Success.is_success());
@@ -1,7 +1,10 @@
 use core::num::{NonZeroU16, NonZeroU8};
 
+use bitflags::Flags;
+use bletio_utils::{BufferOps, EncodeToBuffer};
+
 use crate::{
-    CommandOpCode, ErrorCode, PublicDeviceAddress, SupportedCommands, SupportedFeatures,
+    BigHandle, CommandOpCode, ErrorCode, PublicDeviceAddress, SupportedCommands, SupportedFeatures,
     SupportedLeFeatures, SupportedLeStates, TxPowerLevel,
 };
 
@@ -15,7 +18,7 @@ pub struct CommandCompleteEvent {
 }
 
 impl CommandCompleteEvent {
-    pub(crate) fn new(
+    pub fn new(
         num_hci_command_packets: u8,
         opcode: CommandOpCode,
         status: ErrorCode,
@@ -28,12 +31,57 @@ impl CommandCompleteEvent {
             parameter: parameter.map(Into::into),
         }
     }
+
+    pub fn num_hci_command_packets(&self) -> u8 {
+        self.num_hci_command_packets
+    }
+
+    pub fn opcode(&self) -> CommandOpCode {
+        self.opcode
+    }
+
+    pub fn status(&self) -> ErrorCode {
+        self.status
+    }
+
+    fn encoded_parameter(&self) -> Option<&EventParameter> {
+        // Only the status is returned when a command fails, except for the BIG handle that is always present.
+        self.parameter.as_ref().filter(|parameter| {
+            self.status.is_success() || matches!(parameter, EventParameter::BigHandle(_))
+        })
+    }
+}
+
+impl EncodeToBuffer for CommandCompleteEvent {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        buffer.try_push(self.num_hci_command_packets)?;
+        self.opcode.encode(buffer)?;
+        if self.opcode != CommandOpCode::Nop {
+            self.status.encode(buffer)?;
+        }
+        if let Some(parameter) = self.encoded_parameter() {
+            parameter.encode(buffer)?;
+        }
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        let mut size = size_of::<u8>() + self.opcode.encoded_size();
+        if self.opcode != CommandOpCode::Nop {
+            size += self.status.encoded_size();
+        }
+        size + self
+            .encoded_parameter()
+            .map(EncodeToBuffer::encoded_size)
+            .unwrap_or_default()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub(crate) enum EventParameter {
+pub enum EventParameter {
     BdAddr(BdAddrEventParameter),
+    BigHandle(BigHandleEventParameter),
     BufferSize(BufferSizeEventParameter),
     LeBufferSize(LeBufferSizeEventParameter),
     RandomNumber(RandomNumberEventParameter),
@@ -45,12 +93,62 @@ pub(crate) enum EventParameter {
     FilterAcceptListSize(FilterAcceptListSizeEventParameter),
 }
 
+impl EncodeToBuffer for EventParameter {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        match self {
+            Self::BdAddr(parameter) => parameter.encode(buffer),
+            Self::BigHandle(parameter) => parameter.encode(buffer),
+            Self::BufferSize(parameter) => parameter.encode(buffer),
+            Self::LeBufferSize(parameter) => parameter.encode(buffer),
+            Self::RandomNumber(parameter) => parameter.encode(buffer),
+            Self::SupportedCommands(parameter) => parameter.encode(buffer),
+            Self::SupportedFeatures(parameter) => parameter.encode(buffer),
+            Self::SupportedLeFeatures(parameter) => parameter.encode(buffer),
+            Self::SupportedLeStates(parameter) => parameter.encode(buffer),
+            Self::TxPowerLevel(parameter) => parameter.encode(buffer),
+            Self::FilterAcceptListSize(parameter) => parameter.encode(buffer),
+        }
+    }
+
+    fn encoded_size(&self) -> usize {
+        match self {
+            Self::BdAddr(parameter) => parameter.encoded_size(),
+            Self::BigHandle(parameter) => parameter.encoded_size(),
+            Self::BufferSize(parameter) => parameter.encoded_size(),
+            Self::LeBufferSize(parameter) => parameter.encoded_size(),
+            Self::RandomNumber(parameter) => parameter.encoded_size(),
+            Self::SupportedCommands(parameter) => parameter.encoded_size(),
+            Self::SupportedFeatures(parameter) => parameter.encoded_size(),
+            Self::SupportedLeFeatures(parameter) => parameter.encoded_size(),
+            Self::SupportedLeStates(parameter) => parameter.encoded_size(),
+            Self::TxPowerLevel(parameter) => parameter.encoded_size(),
+            Self::FilterAcceptListSize(parameter) => parameter.encoded_size(),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub(crate) struct BdAddrEventParameter {
+pub struct BdAddrEventParameter {
     pub(crate) bd_addr: PublicDeviceAddress,
 }
 
+impl BdAddrEventParameter {
+    pub fn new(bd_addr: PublicDeviceAddress) -> Self {
+        Self { bd_addr }
+    }
+}
+
+impl EncodeToBuffer for BdAddrEventParameter {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        self.bd_addr.encode(buffer)
+    }
+
+    fn encoded_size(&self) -> usize {
+        self.bd_addr.encoded_size()
+    }
+}
+
 impl From<BdAddrEventParameter> for EventParameter {
     fn from(value: BdAddrEventParameter) -> Self {
         Self::BdAddr(value)
@@ -59,13 +157,71 @@ impl From<BdAddrEventParameter> for EventParameter {
 
 #[derive(Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub(crate) struct BufferSizeEventParameter {
+pub struct BigHandleEventParameter {
+    pub(crate) big_handle: BigHandle,
+}
+
+impl BigHandleEventParameter {
+    pub fn new(big_handle: BigHandle) -> Self {
+        Self { big_handle }
+    }
+}
+
+impl EncodeToBuffer for BigHandleEventParameter {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        self.big_handle.encode(buffer)
+    }
+
+    fn encoded_size(&self) -> usize {
+        self.big_handle.encoded_size()
+    }
+}
+
+impl From<BigHandleEventParameter> for EventParameter {
+    fn from(value: BigHandleEventParameter) -> Self {
+        Self::BigHandle(value)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BufferSizeEventParameter {
     pub(crate) acl_data_packet_length: NonZeroU16,
     pub(crate) synchronous_data_packet_length: NonZeroU8,
     pub(crate) total_num_acl_data_packets: NonZeroU16,
     pub(crate) total_num_synchronous_packets: u16,
 }
 
+impl BufferSizeEventParameter {
+    pub fn new(
+        acl_data_packet_length: NonZeroU16,
+        synchronous_data_packet_length: NonZeroU8,
+        total_num_acl_data_packets: NonZeroU16,
+        total_num_synchronous_packets: u16,
+    ) -> Self {
+        Self {
+            acl_data_packet_length,
+            synchronous_data_packet_length,
+            total_num_acl_data_packets,
+            total_num_synchronous_packets,
+        }
+    }
+}
+
+impl EncodeToBuffer for BufferSizeEventParameter {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        buffer.encode_le_u16(self.acl_data_packet_length.get())?;
+        buffer.try_push(self.synchronous_data_packet_length.get())?;
+        buffer.encode_le_u16(self.total_num_acl_data_packets.get())?;
+        buffer.encode_le_u16(self.total_num_synchronous_packets)?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        3 * size_of::<u16>() + size_of::<u8>()
+    }
+}
+
 impl From<BufferSizeEventParameter> for EventParameter {
     fn from(value: BufferSizeEventParameter) -> Self {
         Self::BufferSize(value)
@@ -74,11 +230,32 @@ impl From<BufferSizeEventParameter> for EventParameter {
 
 #[derive(Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub(crate) struct LeBufferSizeEventParameter {
+pub struct LeBufferSizeEventParameter {
     pub(crate) le_acl_data_packet_length: u16,
     pub(crate) total_num_le_acl_data_packets: u8,
 }
 
+impl LeBufferSizeEventParameter {
+    pub fn new(le_acl_data_packet_length: u16, total_num_le_acl_data_packets: u8) -> Self {
+        Self {
+            le_acl_data_packet_length,
+            total_num_le_acl_data_packets,
+        }
+    }
+}
+
+impl EncodeToBuffer for LeBufferSizeEventParameter {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        buffer.encode_le_u16(self.le_acl_data_packet_length)?;
+        buffer.try_push(self.total_num_le_acl_data_packets)?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        size_of::<u16>() + size_of::<u8>()
+    }
+}
+
 impl From<LeBufferSizeEventParameter> for EventParameter {
     fn from(value: LeBufferSizeEventParameter) -> Self {
         Self::LeBufferSize(value)
@@ -87,10 +264,26 @@ impl From<LeBufferSizeEventParameter> for EventParameter {
 
 #[derive(Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub(crate) struct RandomNumberEventParameter {
+pub struct RandomNumberEventParameter {
     pub(crate) random_number: [u8; 8],
 }
 
+impl RandomNumberEventParameter {
+    pub fn new(random_number: [u8; 8]) -> Self {
+        Self { random_number }
+    }
+}
+
+impl EncodeToBuffer for RandomNumberEventParameter {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        buffer.copy_from_slice(&self.random_number)
+    }
+
+    fn encoded_size(&self) -> usize {
+        self.random_number.len()
+    }
+}
+
 impl From<RandomNumberEventParameter> for EventParameter {
     fn from(value: RandomNumberEventParameter) -> Self {
         Self::RandomNumber(value)
@@ -99,10 +292,26 @@ impl From<RandomNumberEventParameter> for EventParameter {
 
 #[derive(Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub(crate) struct SupportedCommandsEventParameter {
+pub struct SupportedCommandsEventParameter {
     pub(crate) supported_commands: SupportedCommands,
 }
 
+impl SupportedCommandsEventParameter {
+    pub fn new(supported_commands: SupportedCommands) -> Self {
+        Self { supported_commands }
+    }
+}
+
+impl EncodeToBuffer for SupportedCommandsEventParameter {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        buffer.copy_from_slice(&self.supported_commands.bits().0)
+    }
+
+    fn encoded_size(&self) -> usize {
+        64
+    }
+}
+
 impl From<SupportedCommandsEventParameter> for EventParameter {
     fn from(value: SupportedCommandsEventParameter) -> Self {
         Self::SupportedCommands(value)
@@ -111,10 +320,26 @@ impl From<SupportedCommandsEventParameter> for EventParameter {
 
 #[derive(Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub(crate) struct SupportedFeaturesEventParameter {
+pub struct SupportedFeaturesEventParameter {
     pub(crate) supported_features: SupportedFeatures,
 }
 
+impl SupportedFeaturesEventParameter {
+    pub fn new(supported_features: SupportedFeatures) -> Self {
+        Self { supported_features }
+    }
+}
+
+impl EncodeToBuffer for SupportedFeaturesEventParameter {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        buffer.encode_le_u64(self.supported_features.bits())
+    }
+
+    fn encoded_size(&self) -> usize {
+        size_of::<u64>()
+    }
+}
+
 impl From<SupportedFeaturesEventParameter> for EventParameter {
     fn from(value: SupportedFeaturesEventParameter) -> Self {
         Self::SupportedFeatures(value)
@@ -123,10 +348,29 @@ impl From<SupportedFeaturesEventParameter> for EventParameter {
 
 #[derive(Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub(crate) struct SupportedLeFeaturesEventParameter {
+pub struct SupportedLeFeaturesEventParameter {
     pub(crate) supported_le_features: SupportedLeFeatures,
 }
 
+impl SupportedLeFeaturesEventParameter {
+    pub fn new(supported_le_features: SupportedLeFeatures) -> Self {
+        Self {
+            supported_le_features,
+        }
+    }
+}
+
+impl EncodeToBuffer for SupportedLeFeaturesEventParameter {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        // Only the page 0 of the LE features is returned
+        buffer.copy_from_slice(&self.supported_le_features.bits().0[..8])
+    }
+
+    fn encoded_size(&self) -> usize {
+        8
+    }
+}
+
 impl From<SupportedLeFeaturesEventParameter> for EventParameter {
     fn from(value: SupportedLeFeaturesEventParameter) -> Self {
         Self::SupportedLeFeatures(value)
@@ -135,10 +379,28 @@ impl From<SupportedLeFeaturesEventParameter> for EventParameter {
 
 #[derive(Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub(crate) struct SupportedLeStatesEventParameter {
+pub struct SupportedLeStatesEventParameter {
     pub(crate) supported_le_states: SupportedLeStates,
 }
 
+impl SupportedLeStatesEventParameter {
+    pub fn new(supported_le_states: SupportedLeStates) -> Self {
+        Self {
+            supported_le_states,
+        }
+    }
+}
+
+impl EncodeToBuffer for SupportedLeStatesEventParameter {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        self.supported_le_states.encode(buffer)
+    }
+
+    fn encoded_size(&self) -> usize {
+        self.supported_le_states.encoded_size()
+    }
+}
+
 impl From<SupportedLeStatesEventParameter> for EventParameter {
     fn from(value: SupportedLeStatesEventParameter) -> Self {
         Self::SupportedLeStates(value)
@@ -147,10 +409,26 @@ impl From<SupportedLeStatesEventParameter> for EventParameter {
 
 #[derive(Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub(crate) struct TxPowerLevelEventParameter {
+pub struct TxPowerLevelEventParameter {
     pub(crate) tx_power_level: TxPowerLevel,
 }
 
+impl TxPowerLevelEventParameter {
+    pub fn new(tx_power_level: TxPowerLevel) -> Self {
+        Self { tx_power_level }
+    }
+}
+
+impl EncodeToBuffer for TxPowerLevelEventParameter {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        buffer.try_push(self.tx_power_level.value() as u8)
+    }
+
+    fn encoded_size(&self) -> usize {
+        size_of::<i8>()
+    }
+}
+
 impl From<TxPowerLevelEventParameter> for EventParameter {
     fn from(value: TxPowerLevelEventParameter) -> Self {
         Self::TxPowerLevel(value)
@@ -159,10 +437,28 @@ impl From<TxPowerLevelEventParameter> for EventParameter {
 
 #[derive(Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub(crate) struct FilterAcceptListSizeEventParameter {
+pub struct FilterAcceptListSizeEventParameter {
     pub(crate) filter_accept_list_size: usize,
 }
 
+impl FilterAcceptListSizeEventParameter {
+    pub fn new(filter_accept_list_size: usize) -> Self {
+        Self {
+            filter_accept_list_size,
+        }
+    }
+}
+
+impl EncodeToBuffer for FilterAcceptListSizeEventParameter {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        buffer.try_push(self.filter_accept_list_size as u8)
+    }
+
+    fn encoded_size(&self) -> usize {
+        size_of::<u8>()
+    }
+}
+
 impl From<FilterAcceptListSizeEventParameter> for EventParameter {
     fn from(value: FilterAcceptListSizeEventParameter) -> Self {
         Self::FilterAcceptListSize(value)
@@ -170,7 +466,6 @@ impl From<FilterAcceptListSizeEventParameter> for EventParameter {
 }
 
 pub(crate) mod parser {
-    use bitflags::Flags;
     use nom::{
         bytes::take,
         combinator::{eof, map, map_res},
@@ -179,6 +474,7 @@ pub(crate) mod parser {
         IResult, Parser,
     };
 
+    use crate::big::big_handle::parser::big_handle;
     use crate::command::parser::command_opcode;
     use crate::event::parser::{hci_error_code, num_hci_command_packets};
 
@@ -263,6 +559,12 @@ pub(crate) mod parser {
                 eof(rest)?;
                 (status, None::<EventParameter>)
             }
+            CommandOpCode::LeBigTerminateSync => {
+                let (rest, (status, big_handle)) =
+                    (hci_error_code, big_handle).parse(return_parameters)?;
+                eof(rest)?;
+                (status, Some(BigHandleEventParameter { big_handle }.into()))
+            }
             CommandOpCode::LeRand => {
                 let (rest, status) = hci_error_code(return_parameters)?;
                 let (rest, random_number) = if status.is_success() {
@@ -429,8 +731,11 @@ pub(crate) mod parser {
                 )
             }
             CommandOpCode::Disconnect
+            | CommandOpCode::LeBigCreateSync
             | CommandOpCode::LeConnectionUpdate
+            | CommandOpCode::LeCreateBig
             | CommandOpCode::LeCreateConnection
+            | CommandOpCode::LeTerminateBig
             | CommandOpCode::Unsupported(_) => {
                 return Err(nom::Err::Failure(nom::error::Error::new(
                     return_parameters,
@@ -454,7 +759,9 @@ pub(crate) mod parser {
 mod test {
     use rstest::rstest;
 
-    use crate::{packet::parser::packet, Event, Packet};
+    use bletio_utils::BufferOps;
+
+    use crate::{packet::parser::packet, test::event_packet, Event, Packet};
 
     use super::*;
 
@@ -462,6 +769,10 @@ mod test {
     #[case::le_add_device_to_filter_accept_list(CommandCompleteEvent::new(
             1, CommandOpCode::LeAddDeviceToFilterAcceptList, ErrorCode::Success, None::<EventParameter>
         ), &[4, 14, 4, 1, 17, 32, 0])]
+    #[case::le_big_terminate_sync(CommandCompleteEvent::new(
+            1, CommandOpCode::LeBigTerminateSync, ErrorCode::Success,
+            Some(BigHandleEventParameter { big_handle: BigHandle::try_new(2).unwrap() })
+        ), &[4, 14, 5, 1, 108, 32, 0, 2])]
     #[case::le_create_connection_cancel(CommandCompleteEvent::new(
             1, CommandOpCode::LeCreateConnectionCancel, ErrorCode::Success, None::<EventParameter>
         ), &[4, 14, 4, 1, 14, 32, 0])]
@@ -568,8 +879,10 @@ mod test {
         #[case] event: CommandCompleteEvent,
         #[case] input: &[u8],
     ) {
+        let event = Event::CommandComplete(event);
+        assert_eq!(event_packet(&event).data(), input);
         let (rest, packet) = packet(input).unwrap();
-        assert_eq!(packet, Packet::Event(Event::CommandComplete(event)));
+        assert_eq!(packet, Packet::Event(event));
         assert!(rest.is_empty());
     }
 
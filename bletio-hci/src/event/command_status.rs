@@ -1,3 +1,5 @@
+use bletio_utils::{BufferOps, EncodeToBuffer};
+
 use crate::command::CommandOpCode;
 use crate::ErrorCode;
 
@@ -10,17 +12,38 @@ pub struct CommandStatusEvent {
 }
 
 impl CommandStatusEvent {
-    pub(crate) fn new(
-        status: ErrorCode,
-        num_hci_command_packets: u8,
-        opcode: CommandOpCode,
-    ) -> Self {
+    pub fn new(status: ErrorCode, num_hci_command_packets: u8, opcode: CommandOpCode) -> Self {
         Self {
             status,
             num_hci_command_packets,
             opcode,
         }
     }
+
+    pub fn num_hci_command_packets(&self) -> u8 {
+        self.num_hci_command_packets
+    }
+
+    pub fn opcode(&self) -> CommandOpCode {
+        self.opcode
+    }
+
+    pub fn status(&self) -> ErrorCode {
+        self.status
+    }
+}
+
+impl EncodeToBuffer for CommandStatusEvent {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        self.status.encode(buffer)?;
+        buffer.try_push(self.num_hci_command_packets)?;
+        self.opcode.encode(buffer)?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        self.status.encoded_size() + size_of::<u8>() + self.opcode.encoded_size()
+    }
 }
 
 pub(crate) mod parser {
@@ -51,6 +74,7 @@ mod test {
     use super::*;
     use crate::{
         packet::{parser::packet, Packet},
+        test::event_packet,
         Event,
     };
 
@@ -63,8 +87,10 @@ mod test {
         #[case] event: CommandStatusEvent,
         #[case] input: &[u8],
     ) {
+        let event = Event::CommandStatus(event);
+        assert_eq!(event_packet(&event).data(), input);
         let (rest, packet) = packet(input).unwrap();
-        assert_eq!(packet, Packet::Event(Event::CommandStatus(event)));
+        assert_eq!(packet, Packet::Event(event));
         assert!(rest.is_empty());
     }
 
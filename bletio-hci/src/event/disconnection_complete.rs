@@ -1,6 +1,8 @@
+use bletio_utils::{BufferOps, EncodeToBuffer};
+
 use crate::{ConnectionHandle, ErrorCode};
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct DisconnectionCompleteEvent {
     pub(crate) status: ErrorCode,
@@ -8,6 +10,43 @@ pub struct DisconnectionCompleteEvent {
     pub(crate) reason: ErrorCode,
 }
 
+impl DisconnectionCompleteEvent {
+    pub fn new(status: ErrorCode, connection_handle: ConnectionHandle, reason: ErrorCode) -> Self {
+        Self {
+            status,
+            connection_handle,
+            reason,
+        }
+    }
+
+    pub fn connection_handle(&self) -> ConnectionHandle {
+        self.connection_handle
+    }
+
+    pub fn reason(&self) -> ErrorCode {
+        self.reason
+    }
+
+    pub fn status(&self) -> ErrorCode {
+        self.status
+    }
+}
+
+impl EncodeToBuffer for DisconnectionCompleteEvent {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        self.status.encode(buffer)?;
+        self.connection_handle.encode(buffer)?;
+        self.reason.encode(buffer)?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        self.status.encoded_size()
+            + self.connection_handle.encoded_size()
+            + self.reason.encoded_size()
+    }
+}
+
 pub(crate) mod parser {
     use nom::{combinator::map, IResult, Parser};
 
@@ -36,6 +75,7 @@ mod tests {
 
     use crate::packet::parser::packet;
     use crate::packet::Packet;
+    use crate::test::event_packet;
     use crate::Event;
 
     use super::*;
@@ -61,11 +101,10 @@ mod tests {
         #[case] input: &[u8],
         #[case] expected: DisconnectionCompleteEvent,
     ) {
+        let expected = Event::DisconnectionComplete(expected);
+        assert_eq!(event_packet(&expected).data(), input);
         let (rest, packet) = packet(input).unwrap();
-        assert_eq!(
-            packet,
-            Packet::Event(Event::DisconnectionComplete(expected))
-        );
+        assert_eq!(packet, Packet::Event(expected));
         assert!(rest.is_empty());
     }
 
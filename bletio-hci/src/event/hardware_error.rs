@@ -0,0 +1,78 @@
+use bletio_utils::{BufferOps, EncodeToBuffer};
+
+/// Hardware Error Event.
+///
+/// Sent by the Controller when a hardware failure has occurred, the Host is then expected to
+/// reset the Controller.
+///
+/// See [Core Specification 6.0, Vol.4, Part E, 7.7.16](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host-controller-interface/host-controller-interface-functional-specification.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct HardwareErrorEvent {
+    pub(crate) hardware_code: u8,
+}
+
+impl HardwareErrorEvent {
+    pub fn new(hardware_code: u8) -> Self {
+        Self { hardware_code }
+    }
+
+    /// Implementation specific code identifying the hardware failure.
+    pub fn hardware_code(&self) -> u8 {
+        self.hardware_code
+    }
+}
+
+impl EncodeToBuffer for HardwareErrorEvent {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        buffer.try_push(self.hardware_code)?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        size_of::<u8>()
+    }
+}
+
+pub(crate) mod parser {
+    use nom::{
+        combinator::{all_consuming, map},
+        number::complete::le_u8,
+        IResult, Parser,
+    };
+
+    use super::*;
+
+    pub(crate) fn hardware_error_event(input: &[u8]) -> IResult<&[u8], HardwareErrorEvent> {
+        map(all_consuming(le_u8), |hardware_code| HardwareErrorEvent {
+            hardware_code,
+        })
+        .parse(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{packet::parser::packet, test::event_packet, Event, Packet};
+
+    #[test]
+    fn test_hardware_error_event_parsing() {
+        let input = &[0x04, 0x10, 0x01, 0x2A];
+        let expected = HardwareErrorEvent {
+            hardware_code: 0x2A,
+        };
+        assert_eq!(expected.hardware_code(), 0x2A);
+        let expected = Event::HardwareError(expected);
+        assert_eq!(event_packet(&expected).data(), input);
+        let (rest, packet) = packet(input).unwrap();
+        assert_eq!(packet, Packet::Event(expected));
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn test_hardware_error_event_invalid_length() {
+        assert!(packet(&[0x04, 0x10, 0x00]).is_err());
+        assert!(packet(&[0x04, 0x10, 0x02, 0x2A, 0x00]).is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use bletio_utils::{Buffer, BufferOps};
+use bletio_utils::{Buffer, BufferOps, EncodeToBuffer};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 
 use crate::{
@@ -65,26 +65,53 @@ pub enum LeAdvertisingReportEventType {
 
 /// Data contained in a LE Advertising Report event.
 ///
+/// It references the bytes of the event directly, without copying them, and is at most 31
+/// bytes long.
+///
 /// See [Core Specification 6.0, Vol.4, Part E, 7.7.65.2](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host-controller-interface/host-controller-interface-functional-specification.html#UUID-bacd71f4-fabc-238d-72ee-f9aaaf5cbf22).
-pub type LeAdvertisingReportData = Buffer<ADVERTISING_DATA_SIZE>;
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeAdvertisingReportData<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> LeAdvertisingReportData<'a> {
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for LeAdvertisingReportData<'a> {
+    type Error = bletio_utils::Error;
+
+    fn try_from(value: &'a [u8]) -> Result<Self, Self::Error> {
+        if value.len() > ADVERTISING_DATA_SIZE {
+            Err(bletio_utils::Error::BufferTooSmall)
+        } else {
+            Ok(Self { data: value })
+        }
+    }
+}
 
 /// A single report contained in a LE Advertising Report Event.
 ///
+/// It borrows its data from the [`LeAdvertisingReportList`] it comes from.
+///
 /// See [Core Specification 6.0, Vol.4, Part E, 7.7.65.2](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host-controller-interface/host-controller-interface-functional-specification.html#UUID-bacd71f4-fabc-238d-72ee-f9aaaf5cbf22).
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub struct LeAdvertisingReport {
+pub struct LeAdvertisingReport<'a> {
     event_type: LeAdvertisingReportEventType,
     address: ConnectionPeerAddress,
-    data: LeAdvertisingReportData,
+    data: LeAdvertisingReportData<'a>,
     rssi: Option<Rssi>,
 }
 
-impl LeAdvertisingReport {
-    fn new(
+impl<'a> LeAdvertisingReport<'a> {
+    pub fn new(
         event_type: LeAdvertisingReportEventType,
         address: ConnectionPeerAddress,
-        data: LeAdvertisingReportData,
+        data: LeAdvertisingReportData<'a>,
         rssi: Option<Rssi>,
     ) -> Self {
         Self {
@@ -99,8 +126,8 @@ impl LeAdvertisingReport {
         &self.address
     }
 
-    pub fn data(&self) -> &LeAdvertisingReportData {
-        &self.data
+    pub fn data(&self) -> LeAdvertisingReportData<'a> {
+        self.data
     }
 
     pub fn event_type(&self) -> LeAdvertisingReportEventType {
@@ -112,6 +139,25 @@ impl LeAdvertisingReport {
     }
 }
 
+impl EncodeToBuffer for LeAdvertisingReport<'_> {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        buffer.try_push(self.event_type.into())?;
+        self.address.encode(buffer)?;
+        buffer.try_push(self.data.data.len() as u8)?;
+        buffer.copy_from_slice(self.data.data)?;
+        buffer.try_push(self.rssi.map(|rssi| rssi.value() as u8).unwrap_or(0x7F))?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        size_of::<LeAdvertisingReportEventType>()
+            + self.address.encoded_size()
+            + size_of::<u8>()
+            + self.data.data.len()
+            + size_of::<u8>()
+    }
+}
+
 /// List of all the reports contained in a LE Advertising Report Event.
 ///
 /// See [Core Specification 6.0, Vol.4, Part E, 7.7.65.2](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host-controller-interface/host-controller-interface-functional-specification.html#UUID-bacd71f4-fabc-238d-72ee-f9aaaf5cbf22).
@@ -134,8 +180,8 @@ pub struct LeAdvertisingReportListIterator<'a> {
     next_index: usize,
 }
 
-impl Iterator for LeAdvertisingReportListIterator<'_> {
-    type Item = LeAdvertisingReport;
+impl<'a> Iterator for LeAdvertisingReportListIterator<'a> {
+    type Item = LeAdvertisingReport<'a>;
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.next_index >= self.data.len() {
@@ -158,7 +204,7 @@ impl LeAdvertisingReportList {
         }
     }
 
-    pub fn iter(&self) -> LeAdvertisingReportListIterator {
+    pub fn iter(&self) -> LeAdvertisingReportListIterator<'_> {
         LeAdvertisingReportListIterator {
             data: self.data.data(),
             next_index: 0,
@@ -166,6 +212,33 @@ impl LeAdvertisingReportList {
     }
 }
 
+impl TryFrom<&[LeAdvertisingReport<'_>]> for LeAdvertisingReportList {
+    type Error = Error;
+
+    fn try_from(reports: &[LeAdvertisingReport<'_>]) -> Result<Self, Self::Error> {
+        let num_reports = u8::try_from(reports.len()).unwrap_or(u8::MAX).try_into()?;
+        let mut data = Buffer::default();
+        for report in reports {
+            report
+                .encode(&mut data)
+                .map_err(|_| Error::DataWillNotFitEventPacket)?;
+        }
+        Ok(Self { data, num_reports })
+    }
+}
+
+impl EncodeToBuffer for LeAdvertisingReportList {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        buffer.try_push(self.num_reports.value())?;
+        buffer.copy_from_slice(self.data.data())?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        size_of::<u8>() + self.data.len()
+    }
+}
+
 pub(crate) mod parser {
     use nom::{
         bytes::take,
@@ -194,7 +267,7 @@ pub(crate) mod parser {
         le_u8.parse(input)
     }
 
-    fn le_advertising_report_data(input: &[u8]) -> IResult<&[u8], LeAdvertisingReportData> {
+    fn le_advertising_report_data(input: &[u8]) -> IResult<&[u8], LeAdvertisingReportData<'_>> {
         let (rest, data_length) = le_advertising_report_data_length(input)?;
         map_res(take(data_length), TryInto::try_into).parse(rest)
     }
@@ -209,7 +282,7 @@ pub(crate) mod parser {
 
     pub(crate) fn le_advertising_report(
         input: &[u8],
-    ) -> IResult<&[u8], (usize, LeAdvertisingReport)> {
+    ) -> IResult<&[u8], (usize, LeAdvertisingReport<'_>)> {
         map(
             consumed((
                 le_advertising_report_event_type,
@@ -250,8 +323,8 @@ mod test {
     use rstest::rstest;
 
     use crate::{
-        packet::parser::packet, AdvertisingData, Event, LeMetaEvent, Packet, PublicDeviceAddress,
-        RandomAddress,
+        packet::parser::packet, test::event_packet, AdvertisingData, Event, LeMetaEvent, Packet,
+        PublicDeviceAddress, RandomAddress,
     };
 
     use super::*;
@@ -293,13 +366,13 @@ mod test {
         let report = LeAdvertisingReport::new(
             event_type,
             le_advertising_report_address.clone(),
-            data.clone(),
+            data,
             rssi,
         );
         assert_eq!(report.event_type(), event_type);
         assert_eq!(report.address(), &le_advertising_report_address);
         assert_eq!(report.address().value(), address.value());
-        assert_eq!(report.data(), &data);
+        assert_eq!(report.data(), data);
         assert_eq!(report.rssi(), rssi);
     }
 
@@ -322,13 +395,13 @@ mod test {
         let report = LeAdvertisingReport::new(
             event_type,
             le_advertising_report_address.clone(),
-            data.clone(),
+            data,
             rssi,
         );
         assert_eq!(report.event_type(), event_type);
         assert_eq!(report.address(), &le_advertising_report_address);
         assert_eq!(report.address().value(), address.value());
-        assert_eq!(report.data(), &data);
+        assert_eq!(report.data(), data);
         assert_eq!(report.rssi(), rssi);
     }
 
@@ -401,13 +474,10 @@ mod test {
         #[case] le_advertising_report_list: LeAdvertisingReportList,
         #[case] input: &[u8],
     ) {
+        let event = Event::LeMeta(LeMetaEvent::LeAdvertisingReport(le_advertising_report_list));
+        assert_eq!(event_packet(&event).data(), input);
         let (rest, packet) = packet(input).unwrap();
-        assert_eq!(
-            packet,
-            Packet::Event(Event::LeMeta(LeMetaEvent::LeAdvertisingReport(
-                le_advertising_report_list
-            )))
-        );
+        assert_eq!(packet, Packet::Event(event));
         assert!(rest.is_empty());
     }
 
@@ -425,13 +495,69 @@ mod test {
     #[test]
     fn test_advertising_data_from_le_advertising_report_data() {
         let data = [25; 16];
-        let mut buffer: Buffer<31> = Buffer::default();
-        buffer.copy_from_slice(&data[..]).unwrap();
-        let data = LeAdvertisingReportData::from(buffer);
-        let adv_data: AdvertisingData = (&data).into();
+        let data = LeAdvertisingReportData::try_from(&data[..]).unwrap();
+        assert_eq!(data.data(), &[25; 16]);
+        let adv_data: AdvertisingData = data.into();
         assert_eq!(
             adv_data.data(),
             &[16, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25]
         );
     }
+
+    #[test]
+    fn test_le_advertising_report_data_too_long() {
+        assert_eq!(
+            LeAdvertisingReportData::try_from(&[0; 32][..]),
+            Err(bletio_utils::Error::BufferTooSmall)
+        );
+    }
+
+    #[test]
+    fn test_le_advertising_report_borrows_event_data() {
+        let report_list = LeAdvertisingReportList::new(
+            1.try_into().unwrap(),
+            &[0, 1, 160, 215, 105, 192, 58, 123, 3, 2, 1, 6, 204],
+        );
+        let report = report_list.iter().next().unwrap();
+        let data = report.data().data();
+        assert_eq!(data, &[2, 1, 6]);
+        assert!(report_list
+            .data
+            .data()
+            .as_ptr_range()
+            .contains(&data.as_ptr()));
+    }
+
+    #[test]
+    fn test_le_advertising_report_list_from_reports() {
+        let input = [
+            0, 1, 160, 215, 105, 192, 58, 123, 3, 2, 1, 6, 204, 4, 0, 1, 2, 3, 4, 5, 6, 0, 127,
+        ];
+        let report_list = LeAdvertisingReportList::new(2.try_into().unwrap(), &input);
+        let reports: heapless::Vec<_, 2> = report_list.iter().collect();
+        assert_eq!(reports[1].rssi(), None);
+        assert_eq!(
+            LeAdvertisingReportList::try_from(&reports[..]),
+            Ok(report_list.clone())
+        );
+    }
+
+    #[rstest]
+    #[case::no_report(0, Error::InvalidLeAdvertisingReportNumReports(0))]
+    #[case::too_many_reports(26, Error::InvalidLeAdvertisingReportNumReports(26))]
+    #[case::too_much_data(7, Error::DataWillNotFitEventPacket)]
+    fn test_le_advertising_report_list_from_reports_failure(
+        #[case] num_reports: usize,
+        #[case] error: Error,
+    ) {
+        let data = [0; 31];
+        let report = LeAdvertisingReport::new(
+            LeAdvertisingReportEventType::NonConnectableUndirected,
+            ConnectionPeerAddress::default(),
+            LeAdvertisingReportData::try_from(&data[..]).unwrap(),
+            None,
+        );
+        let reports: heapless::Vec<_, 26> = core::iter::repeat_n(report, num_reports).collect();
+        assert_eq!(LeAdvertisingReportList::try_from(&reports[..]), Err(error));
+    }
 }
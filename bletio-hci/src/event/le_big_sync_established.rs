@@ -0,0 +1,264 @@
+use bletio_utils::{BufferOps, EncodeToBuffer};
+use heapless::Vec;
+
+use crate::event::le_create_big_complete::{encode_bis_handles, encode_le_u24, BIS_HANDLES_NB_MAX};
+use crate::{BigHandle, ConnectionHandle, ErrorCode};
+
+/// LE BIG Sync Established Event.
+///
+/// If the status is not a success, all the parameters except the BIG handle shall be ignored.
+///
+/// See [Core Specification 6.0, Vol.4, Part E, 7.7.65.29](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host-controller-interface/host-controller-interface-functional-specification.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeBigSyncEstablishedEvent {
+    pub(crate) status: ErrorCode,
+    pub(crate) big_handle: BigHandle,
+    pub(crate) transport_latency_big: u32,
+    pub(crate) nse: u8,
+    pub(crate) bn: u8,
+    pub(crate) pto: u8,
+    pub(crate) irc: u8,
+    pub(crate) max_pdu: u16,
+    pub(crate) iso_interval: u16,
+    pub(crate) bis_handles: Vec<ConnectionHandle, BIS_HANDLES_NB_MAX>,
+}
+
+impl LeBigSyncEstablishedEvent {
+    pub fn big_handle(&self) -> BigHandle {
+        self.big_handle
+    }
+
+    /// Get the connection handles of the synchronized BISes.
+    pub fn bis_handles(&self) -> &[ConnectionHandle] {
+        self.bis_handles.as_slice()
+    }
+
+    /// Get the burst number.
+    pub fn bn(&self) -> u8 {
+        self.bn
+    }
+
+    /// Get the immediate repetition count.
+    pub fn irc(&self) -> u8 {
+        self.irc
+    }
+
+    /// Get the time between two consecutive BIG anchor points, in units of 1.25 ms.
+    pub fn iso_interval(&self) -> u16 {
+        self.iso_interval
+    }
+
+    /// Get the maximum size of an isochronous PDU.
+    pub fn max_pdu(&self) -> u16 {
+        self.max_pdu
+    }
+
+    /// Get the number of subevents in each BIS event in the BIG.
+    pub fn nse(&self) -> u8 {
+        self.nse
+    }
+
+    /// Get the pre-transmission offset.
+    pub fn pto(&self) -> u8 {
+        self.pto
+    }
+
+    pub fn status(&self) -> ErrorCode {
+        self.status
+    }
+
+    /// Get the actual transport latency in microseconds.
+    pub fn transport_latency_big(&self) -> u32 {
+        self.transport_latency_big
+    }
+}
+
+/// Size of the parameters following the BIG handle when no BIS has been synchronized.
+const EMPTY_PARAMETERS_SIZE: usize = 3 + 4 + 2 * size_of::<u16>() + 1;
+
+impl EncodeToBuffer for LeBigSyncEstablishedEvent {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        self.status.encode(buffer)?;
+        self.big_handle.encode(buffer)?;
+        if self.status.is_success() {
+            encode_le_u24(self.transport_latency_big, buffer)?;
+            buffer.copy_from_slice(&[self.nse, self.bn, self.pto, self.irc])?;
+            buffer.encode_le_u16(self.max_pdu)?;
+            buffer.encode_le_u16(self.iso_interval)?;
+            encode_bis_handles(&self.bis_handles, buffer)?;
+        } else {
+            // On failure, the controller zero-fills the remaining parameters.
+            buffer.copy_from_slice(&[0; EMPTY_PARAMETERS_SIZE])?;
+        }
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        let size = self.status.encoded_size() + self.big_handle.encoded_size();
+        if self.status.is_success() {
+            size + 3
+                + 4 * size_of::<u8>()
+                + 2 * size_of::<u16>()
+                + size_of::<u8>()
+                + self.bis_handles.len() * size_of::<u16>()
+        } else {
+            size + EMPTY_PARAMETERS_SIZE
+        }
+    }
+}
+
+pub(crate) mod parser {
+    use nom::{
+        combinator::all_consuming,
+        number::complete::{le_u16, le_u24, le_u8},
+        IResult, Parser,
+    };
+
+    use super::*;
+    use crate::big::big_handle::parser::big_handle;
+    use crate::event::le_create_big_complete::parser::bis_handles;
+    use crate::event::parser::hci_error_code;
+    use crate::LeMetaEvent;
+
+    pub(crate) fn le_big_sync_established_event(input: &[u8]) -> IResult<&[u8], LeMetaEvent> {
+        let (rest, (status, big_handle)) = (hci_error_code, big_handle).parse(input)?;
+        if !status.is_success() {
+            return Ok((
+                &[],
+                LeMetaEvent::LeBigSyncEstablished(LeBigSyncEstablishedEvent {
+                    status,
+                    big_handle,
+                    transport_latency_big: 0,
+                    nse: 0,
+                    bn: 0,
+                    pto: 0,
+                    irc: 0,
+                    max_pdu: 0,
+                    iso_interval: 0,
+                    bis_handles: Vec::new(),
+                }),
+            ));
+        }
+        let (rest, (transport_latency_big, nse, bn, pto, irc, max_pdu, iso_interval, bis_handles)) =
+            all_consuming((
+                le_u24,
+                le_u8,
+                le_u8,
+                le_u8,
+                le_u8,
+                le_u16,
+                le_u16,
+                bis_handles,
+            ))
+            .parse(rest)?;
+        Ok((
+            rest,
+            LeMetaEvent::LeBigSyncEstablished(LeBigSyncEstablishedEvent {
+                status,
+                big_handle,
+                transport_latency_big,
+                nse,
+                bn,
+                pto,
+                irc,
+                max_pdu,
+                iso_interval,
+                bis_handles,
+            }),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{packet::parser::packet, test::event_packet, Event, LeMetaEvent, Packet};
+
+    #[rstest]
+    #[case::success(
+        &[0x04, 0x3E, 0x13, 0x1D, 0x00, 0x02, 0x20, 0x4E, 0x00, 0x04, 0x01, 0x00, 0x02, 0x64, 0x00,
+            0x08, 0x00, 0x02, 0x30, 0x00, 0x31, 0x00],
+        LeBigSyncEstablishedEvent {
+            status: ErrorCode::Success,
+            big_handle: BigHandle::try_new(0x02).unwrap(),
+            transport_latency_big: 20_000,
+            nse: 4,
+            bn: 1,
+            pto: 0,
+            irc: 2,
+            max_pdu: 100,
+            iso_interval: 8,
+            bis_handles: Vec::from_slice(&[
+                ConnectionHandle::try_new(0x0030).unwrap(),
+                ConnectionHandle::try_new(0x0031).unwrap(),
+            ]).unwrap(),
+        }
+    )]
+    #[case::failure(
+        &[0x04, 0x3E, 0x0F, 0x1D, 0x3E, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00],
+        LeBigSyncEstablishedEvent {
+            status: ErrorCode::ConnectionFailedToBeEstablished,
+            big_handle: BigHandle::try_new(0x02).unwrap(),
+            transport_latency_big: 0,
+            nse: 0,
+            bn: 0,
+            pto: 0,
+            irc: 0,
+            max_pdu: 0,
+            iso_interval: 0,
+            bis_handles: Vec::new(),
+        }
+    )]
+    fn test_le_big_sync_established_event_parsing_success(
+        #[case] input: &[u8],
+        #[case] expected: LeBigSyncEstablishedEvent,
+    ) {
+        let expected = Event::LeMeta(LeMetaEvent::LeBigSyncEstablished(expected));
+        assert_eq!(event_packet(&expected).data(), input);
+        let (rest, packet) = packet(input).unwrap();
+        assert_eq!(packet, Packet::Event(expected));
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn test_le_big_sync_established_event_parsing_failure() {
+        // Missing the second BIS handle
+        assert!(packet(&[
+            0x04, 0x3E, 0x11, 0x1D, 0x00, 0x02, 0x20, 0x4E, 0x00, 0x04, 0x01, 0x00, 0x02, 0x64,
+            0x00, 0x08, 0x00, 0x02, 0x30, 0x00
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_le_big_sync_established_event_getters() {
+        let big_handle = BigHandle::try_new(0x02).unwrap();
+        let bis_handle = ConnectionHandle::try_new(0x0040).unwrap();
+        let event = LeBigSyncEstablishedEvent {
+            status: ErrorCode::Success,
+            big_handle,
+            transport_latency_big: 2_000,
+            nse: 3,
+            bn: 2,
+            pto: 1,
+            irc: 4,
+            max_pdu: 251,
+            iso_interval: 16,
+            bis_handles: Vec::from_slice(&[bis_handle]).unwrap(),
+        };
+        assert_eq!(event.status(), ErrorCode::Success);
+        assert_eq!(event.big_handle(), big_handle);
+        assert_eq!(event.transport_latency_big(), 2_000);
+        assert_eq!(event.nse(), 3);
+        assert_eq!(event.bn(), 2);
+        assert_eq!(event.pto(), 1);
+        assert_eq!(event.irc(), 4);
+        assert_eq!(event.max_pdu(), 251);
+        assert_eq!(event.iso_interval(), 16);
+        assert_eq!(event.bis_handles(), &[bis_handle]);
+    }
+}
@@ -0,0 +1,96 @@
+use bletio_utils::{BufferOps, EncodeToBuffer};
+
+use crate::{BigHandle, ErrorCode};
+
+/// LE BIG Sync Lost Event.
+///
+/// See [Core Specification 6.0, Vol.4, Part E, 7.7.65.30](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host-controller-interface/host-controller-interface-functional-specification.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeBigSyncLostEvent {
+    pub(crate) big_handle: BigHandle,
+    pub(crate) reason: ErrorCode,
+}
+
+impl LeBigSyncLostEvent {
+    pub fn big_handle(&self) -> BigHandle {
+        self.big_handle
+    }
+
+    pub fn reason(&self) -> ErrorCode {
+        self.reason
+    }
+}
+
+impl EncodeToBuffer for LeBigSyncLostEvent {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        self.big_handle.encode(buffer)?;
+        self.reason.encode(buffer)?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        self.big_handle.encoded_size() + self.reason.encoded_size()
+    }
+}
+
+pub(crate) mod parser {
+    use nom::{
+        combinator::{all_consuming, map},
+        IResult, Parser,
+    };
+
+    use super::*;
+    use crate::big::big_handle::parser::big_handle;
+    use crate::event::parser::hci_error_code;
+    use crate::LeMetaEvent;
+
+    pub(crate) fn le_big_sync_lost_event(input: &[u8]) -> IResult<&[u8], LeMetaEvent> {
+        map(
+            all_consuming((big_handle, hci_error_code)),
+            |(big_handle, reason)| {
+                LeMetaEvent::LeBigSyncLost(LeBigSyncLostEvent { big_handle, reason })
+            },
+        )
+        .parse(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{packet::parser::packet, test::event_packet, Event, LeMetaEvent, Packet};
+
+    #[test]
+    fn test_le_big_sync_lost_event_parsing() {
+        let (rest, packet) = packet(&[0x04, 0x3E, 0x03, 0x1E, 0x01, 0x08]).unwrap();
+        let expected = LeBigSyncLostEvent {
+            big_handle: BigHandle::try_new(0x01).unwrap(),
+            reason: ErrorCode::ConnectionTimeout,
+        };
+        assert_eq!(expected.big_handle(), BigHandle::try_new(0x01).unwrap());
+        assert_eq!(expected.reason(), ErrorCode::ConnectionTimeout);
+        assert_eq!(
+            packet,
+            Packet::Event(Event::LeMeta(LeMetaEvent::LeBigSyncLost(expected)))
+        );
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn test_le_big_sync_lost_event_encoding() {
+        let event = Event::LeMeta(LeMetaEvent::LeBigSyncLost(LeBigSyncLostEvent {
+            big_handle: BigHandle::try_new(0x01).unwrap(),
+            reason: ErrorCode::ConnectionTimeout,
+        }));
+        assert_eq!(
+            event_packet(&event).data(),
+            &[0x04, 0x3E, 0x03, 0x1E, 0x01, 0x08]
+        );
+    }
+
+    #[test]
+    fn test_le_big_sync_lost_event_invalid_big_handle() {
+        assert!(packet(&[0x04, 0x3E, 0x03, 0x1E, 0xF0, 0x08]).is_err());
+    }
+}
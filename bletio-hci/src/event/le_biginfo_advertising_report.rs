@@ -0,0 +1,251 @@
+use bletio_utils::{BufferOps, EncodeToBuffer};
+
+use crate::{Framing, Phy, SduInterval, SyncHandle};
+
+/// LE BIGInfo Advertising Report Event.
+///
+/// Reports the BIGInfo of a BIG received on a synchronized periodic advertising train.
+/// This is what a Host needs to know to decide whether to synchronize to the BIG.
+///
+/// See [Core Specification 6.0, Vol.4, Part E, 7.7.65.34](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host-controller-interface/host-controller-interface-functional-specification.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeBiginfoAdvertisingReportEvent {
+    pub(crate) sync_handle: SyncHandle,
+    pub(crate) num_bis: u8,
+    pub(crate) nse: u8,
+    pub(crate) iso_interval: u16,
+    pub(crate) bn: u8,
+    pub(crate) pto: u8,
+    pub(crate) irc: u8,
+    pub(crate) max_pdu: u16,
+    pub(crate) sdu_interval: SduInterval,
+    pub(crate) max_sdu: u16,
+    pub(crate) phy: Phy,
+    pub(crate) framing: Framing,
+    pub(crate) encryption: bool,
+}
+
+impl LeBiginfoAdvertisingReportEvent {
+    /// Get the burst number.
+    pub fn bn(&self) -> u8 {
+        self.bn
+    }
+
+    /// Tell whether the BIG is encrypted.
+    pub fn encryption(&self) -> bool {
+        self.encryption
+    }
+
+    pub fn framing(&self) -> Framing {
+        self.framing
+    }
+
+    /// Get the immediate repetition count.
+    pub fn irc(&self) -> u8 {
+        self.irc
+    }
+
+    /// Get the time between two consecutive BIG anchor points, in units of 1.25 ms.
+    pub fn iso_interval(&self) -> u16 {
+        self.iso_interval
+    }
+
+    /// Get the maximum size of an isochronous PDU.
+    pub fn max_pdu(&self) -> u16 {
+        self.max_pdu
+    }
+
+    /// Get the maximum size of an SDU.
+    pub fn max_sdu(&self) -> u16 {
+        self.max_sdu
+    }
+
+    /// Get the number of subevents in each BIS event in the BIG.
+    pub fn nse(&self) -> u8 {
+        self.nse
+    }
+
+    /// Get the number of BISes in the BIG.
+    pub fn num_bis(&self) -> u8 {
+        self.num_bis
+    }
+
+    pub fn phy(&self) -> Phy {
+        self.phy
+    }
+
+    /// Get the pre-transmission offset.
+    pub fn pto(&self) -> u8 {
+        self.pto
+    }
+
+    pub fn sdu_interval(&self) -> SduInterval {
+        self.sdu_interval
+    }
+
+    pub fn sync_handle(&self) -> SyncHandle {
+        self.sync_handle
+    }
+}
+
+impl EncodeToBuffer for LeBiginfoAdvertisingReportEvent {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        self.sync_handle.encode(buffer)?;
+        buffer.try_push(self.num_bis)?;
+        buffer.try_push(self.nse)?;
+        buffer.encode_le_u16(self.iso_interval)?;
+        buffer.copy_from_slice(&[self.bn, self.pto, self.irc])?;
+        buffer.encode_le_u16(self.max_pdu)?;
+        self.sdu_interval.encode(buffer)?;
+        buffer.encode_le_u16(self.max_sdu)?;
+        self.phy.encode(buffer)?;
+        self.framing.encode(buffer)?;
+        buffer.try_push(self.encryption as u8)?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        self.sync_handle.encoded_size()
+            + 5 * size_of::<u8>()
+            + 3 * size_of::<u16>()
+            + self.sdu_interval.encoded_size()
+            + self.phy.encoded_size()
+            + self.framing.encoded_size()
+            + size_of::<u8>()
+    }
+}
+
+pub(crate) mod parser {
+    use nom::{
+        combinator::{all_consuming, map},
+        number::complete::{le_u16, le_u8},
+        IResult, Parser,
+    };
+
+    use super::*;
+    use crate::big::big_parameters::parser::{encryption, framing, sdu_interval};
+    use crate::common::phy::parser::phy;
+    use crate::scanning::sync_handle::parser::sync_handle;
+    use crate::LeMetaEvent;
+
+    pub(crate) fn le_biginfo_advertising_report_event(input: &[u8]) -> IResult<&[u8], LeMetaEvent> {
+        map(
+            all_consuming((
+                sync_handle,
+                le_u8,
+                le_u8,
+                le_u16,
+                le_u8,
+                le_u8,
+                le_u8,
+                le_u16,
+                sdu_interval,
+                le_u16,
+                phy,
+                framing,
+                encryption,
+            )),
+            |(
+                sync_handle,
+                num_bis,
+                nse,
+                iso_interval,
+                bn,
+                pto,
+                irc,
+                max_pdu,
+                sdu_interval,
+                max_sdu,
+                phy,
+                framing,
+                encryption,
+            )| {
+                LeMetaEvent::LeBiginfoAdvertisingReport(LeBiginfoAdvertisingReportEvent {
+                    sync_handle,
+                    num_bis,
+                    nse,
+                    iso_interval,
+                    bn,
+                    pto,
+                    irc,
+                    max_pdu,
+                    sdu_interval,
+                    max_sdu,
+                    phy,
+                    framing,
+                    encryption,
+                })
+            },
+        )
+        .parse(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{packet::parser::packet, test::event_packet, Event, LeMetaEvent, Packet};
+
+    fn expected_event() -> LeBiginfoAdvertisingReportEvent {
+        LeBiginfoAdvertisingReportEvent {
+            sync_handle: SyncHandle::try_new(0x0001).unwrap(),
+            num_bis: 2,
+            nse: 4,
+            iso_interval: 8,
+            bn: 1,
+            pto: 0,
+            irc: 2,
+            max_pdu: 100,
+            sdu_interval: SduInterval::try_new(10_000).unwrap(),
+            max_sdu: 100,
+            phy: Phy::Le2M,
+            framing: Framing::Unframed,
+            encryption: true,
+        }
+    }
+
+    #[test]
+    fn test_le_biginfo_advertising_report_event_parsing_success() {
+        let input = &[
+            0x04, 0x3E, 0x14, 0x22, 0x01, 0x00, 0x02, 0x04, 0x08, 0x00, 0x01, 0x00, 0x02, 0x64,
+            0x00, 0x10, 0x27, 0x00, 0x64, 0x00, 0x02, 0x00, 0x01,
+        ];
+        let event = Event::LeMeta(LeMetaEvent::LeBiginfoAdvertisingReport(expected_event()));
+        assert_eq!(event_packet(&event).data(), input);
+        let (rest, packet) = packet(input).unwrap();
+        assert_eq!(packet, Packet::Event(event));
+        assert!(rest.is_empty());
+    }
+
+    #[rstest]
+    #[case::invalid_phy(&[0x04, 0x3E, 0x14, 0x22, 0x01, 0x00, 0x02, 0x04, 0x08, 0x00, 0x01, 0x00, 0x02,
+        0x64, 0x00, 0x10, 0x27, 0x00, 0x64, 0x00, 0x04, 0x00, 0x01])]
+    #[case::invalid_encryption(&[0x04, 0x3E, 0x14, 0x22, 0x01, 0x00, 0x02, 0x04, 0x08, 0x00, 0x01, 0x00,
+        0x02, 0x64, 0x00, 0x10, 0x27, 0x00, 0x64, 0x00, 0x02, 0x00, 0x02])]
+    #[case::missing_encryption(&[0x04, 0x3E, 0x13, 0x22, 0x01, 0x00, 0x02, 0x04, 0x08, 0x00, 0x01, 0x00,
+        0x02, 0x64, 0x00, 0x10, 0x27, 0x00, 0x64, 0x00, 0x02, 0x00])]
+    fn test_le_biginfo_advertising_report_event_parsing_failure(#[case] input: &[u8]) {
+        assert!(packet(input).is_err());
+    }
+
+    #[test]
+    fn test_le_biginfo_advertising_report_event_getters() {
+        let event = expected_event();
+        assert_eq!(event.sync_handle(), SyncHandle::try_new(0x0001).unwrap());
+        assert_eq!(event.num_bis(), 2);
+        assert_eq!(event.nse(), 4);
+        assert_eq!(event.iso_interval(), 8);
+        assert_eq!(event.bn(), 1);
+        assert_eq!(event.pto(), 0);
+        assert_eq!(event.irc(), 2);
+        assert_eq!(event.max_pdu(), 100);
+        assert_eq!(event.sdu_interval(), SduInterval::try_new(10_000).unwrap());
+        assert_eq!(event.max_sdu(), 100);
+        assert_eq!(event.phy(), Phy::Le2M);
+        assert_eq!(event.framing(), Framing::Unframed);
+        assert!(event.encryption());
+    }
+}
@@ -1,14 +1,15 @@
-use num_enum::TryFromPrimitive;
+use bletio_utils::{BufferOps, EncodeToBuffer};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
 
 use crate::{
     ConnectionHandle, ConnectionInterval, DeviceAddress, Error, ErrorCode, Latency,
-    SupervisionTimeout,
+    PeerAddressType, SupervisionTimeout,
 };
 
 /// Role in a connection.
 ///
 /// See [Core Specification 6.0, Vol. 4, Part E, 7.7.65.1](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host-controller-interface/host-controller-interface-functional-specification.html#UUID-bacd71f4-fabc-238d-72ee-f9aaaf5cbf22).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoPrimitive, TryFromPrimitive)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[num_enum(error_type(name = Error, constructor = Error::InvalidRole))]
 #[repr(u8)]
@@ -20,10 +21,21 @@ pub enum Role {
     Peripheral = 0x01,
 }
 
+impl EncodeToBuffer for Role {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        buffer.try_push((*self).into())?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        size_of::<Role>()
+    }
+}
+
 /// Central clock accuracy in a connection.
 ///
 /// See [Core Specification 6.0, Vol. 4, Part E, 7.7.65.1](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host-controller-interface/host-controller-interface-functional-specification.html#UUID-bacd71f4-fabc-238d-72ee-f9aaaf5cbf22).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoPrimitive, TryFromPrimitive)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[num_enum(error_type(name = Error, constructor = Error::InvalidCentralClockAccuracy))]
 #[repr(u8)]
@@ -47,6 +59,17 @@ pub enum CentralClockAccuracy {
     Ppm20 = 0x07,
 }
 
+impl EncodeToBuffer for CentralClockAccuracy {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        buffer.try_push((*self).into())?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        size_of::<CentralClockAccuracy>()
+    }
+}
+
 /// LE Connection Complete Event.
 ///
 /// See [Core Specification 6.0, Vol.4, Part E, 7.7.65.1](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host-controller-interface/host-controller-interface-functional-specification.html#UUID-bacd71f4-fabc-238d-72ee-f9aaaf5cbf22).
@@ -64,6 +87,29 @@ pub struct LeConnectionCompleteEvent {
 }
 
 impl LeConnectionCompleteEvent {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        status: ErrorCode,
+        connection_handle: ConnectionHandle,
+        role: Role,
+        peer_address: DeviceAddress,
+        connection_interval: ConnectionInterval,
+        peripheral_latency: Latency,
+        supervision_timeout: SupervisionTimeout,
+        central_clock_accuracy: CentralClockAccuracy,
+    ) -> Self {
+        Self {
+            status,
+            connection_handle,
+            role,
+            peer_address,
+            connection_interval,
+            peripheral_latency,
+            supervision_timeout,
+            central_clock_accuracy,
+        }
+    }
+
     pub fn central_clock_accuracy(&self) -> CentralClockAccuracy {
         self.central_clock_accuracy
     }
@@ -97,6 +143,33 @@ impl LeConnectionCompleteEvent {
     }
 }
 
+impl EncodeToBuffer for LeConnectionCompleteEvent {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        self.status.encode(buffer)?;
+        self.connection_handle.encode(buffer)?;
+        self.role.encode(buffer)?;
+        PeerAddressType::from(&self.peer_address).encode(buffer)?;
+        self.peer_address.encode(buffer)?;
+        self.connection_interval.encode(buffer)?;
+        self.peripheral_latency.encode(buffer)?;
+        self.supervision_timeout.encode(buffer)?;
+        self.central_clock_accuracy.encode(buffer)?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        self.status.encoded_size()
+            + self.connection_handle.encoded_size()
+            + self.role.encoded_size()
+            + PeerAddressType::from(&self.peer_address).encoded_size()
+            + self.peer_address.encoded_size()
+            + self.connection_interval.encoded_size()
+            + self.peripheral_latency.encoded_size()
+            + self.supervision_timeout.encoded_size()
+            + self.central_clock_accuracy.encoded_size()
+    }
+}
+
 pub(crate) mod parser {
     use nom::{
         combinator::{all_consuming, map, map_res},
@@ -162,11 +235,53 @@ pub(crate) mod parser {
 
 #[cfg(test)]
 mod tests {
+    use rstest::rstest;
+
     use super::*;
     use crate::{
-        connection_interval, latency, supervision_timeout, RandomResolvablePrivateAddress,
+        connection_interval, latency, packet::parser::packet, supervision_timeout,
+        test::event_packet, Event, LeMetaEvent, Packet, PublicDeviceAddress,
+        RandomResolvablePrivateAddress,
     };
 
+    #[rstest]
+    #[case::central_with_random_peer(
+        LeConnectionCompleteEvent {
+            status: ErrorCode::Success,
+            connection_handle: ConnectionHandle::try_new(0).unwrap(),
+            role: Role::Central,
+            peer_address: RandomResolvablePrivateAddress::try_new([83, 251, 125, 93, 119, 88]).unwrap().into(),
+            connection_interval: connection_interval!(64),
+            peripheral_latency: latency!(0),
+            supervision_timeout: supervision_timeout!(32),
+            central_clock_accuracy: CentralClockAccuracy::Ppm150,
+        },
+        &[4, 62, 19, 1, 0, 0, 0, 0, 1, 83, 251, 125, 93, 119, 88, 64, 0, 0, 0, 32, 0, 2]
+    )]
+    #[case::peripheral_with_public_peer(
+        LeConnectionCompleteEvent {
+            status: ErrorCode::Success,
+            connection_handle: ConnectionHandle::try_new(1).unwrap(),
+            role: Role::Peripheral,
+            peer_address: PublicDeviceAddress::new([0xCD, 0x2E, 0x0B, 0x04, 0x32, 0x56]).into(),
+            connection_interval: connection_interval!(24),
+            peripheral_latency: latency!(4),
+            supervision_timeout: supervision_timeout!(400),
+            central_clock_accuracy: CentralClockAccuracy::Ppm500,
+        },
+        &[4, 62, 19, 1, 0, 1, 0, 1, 0, 0xCD, 0x2E, 0x0B, 0x04, 0x32, 0x56, 24, 0, 4, 0, 144, 1, 0]
+    )]
+    fn test_le_connection_complete_event_round_trip(
+        #[case] event: LeConnectionCompleteEvent,
+        #[case] input: &[u8],
+    ) {
+        let event = Event::LeMeta(LeMetaEvent::LeConnectionComplete(event));
+        assert_eq!(event_packet(&event).data(), input);
+        let (rest, packet) = packet(input).unwrap();
+        assert_eq!(packet, Packet::Event(event));
+        assert!(rest.is_empty());
+    }
+
     #[test]
     fn test_le_connection_complete_event() {
         let central_clock_accuracy = CentralClockAccuracy::Ppm150;
@@ -1,3 +1,5 @@
+use bletio_utils::{BufferOps, EncodeToBuffer};
+
 use crate::{ConnectionHandle, ConnectionInterval, ErrorCode, Latency, SupervisionTimeout};
 
 /// LE Connection Update Complete Event.
@@ -14,6 +16,22 @@ pub struct LeConnectionUpdateCompleteEvent {
 }
 
 impl LeConnectionUpdateCompleteEvent {
+    pub fn new(
+        status: ErrorCode,
+        connection_handle: ConnectionHandle,
+        connection_interval: ConnectionInterval,
+        peripheral_latency: Latency,
+        supervision_timeout: SupervisionTimeout,
+    ) -> Self {
+        Self {
+            status,
+            connection_handle,
+            connection_interval,
+            peripheral_latency,
+            supervision_timeout,
+        }
+    }
+
     pub fn connection_handle(&self) -> ConnectionHandle {
         self.connection_handle
     }
@@ -35,6 +53,25 @@ impl LeConnectionUpdateCompleteEvent {
     }
 }
 
+impl EncodeToBuffer for LeConnectionUpdateCompleteEvent {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        self.status.encode(buffer)?;
+        self.connection_handle.encode(buffer)?;
+        self.connection_interval.encode(buffer)?;
+        self.peripheral_latency.encode(buffer)?;
+        self.supervision_timeout.encode(buffer)?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        self.status.encoded_size()
+            + self.connection_handle.encoded_size()
+            + self.connection_interval.encoded_size()
+            + self.peripheral_latency.encoded_size()
+            + self.supervision_timeout.encoded_size()
+    }
+}
+
 pub(crate) mod parser {
     use nom::{
         combinator::{all_consuming, map},
@@ -82,7 +119,28 @@ pub(crate) mod parser {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{connection_interval, latency, supervision_timeout};
+    use crate::{
+        connection_interval, latency, packet::parser::packet, supervision_timeout,
+        test::event_packet, Event, LeMetaEvent, Packet,
+    };
+
+    #[test]
+    fn test_le_connection_update_complete_event_round_trip() {
+        let input = &[4, 62, 10, 3, 0, 1, 0, 40, 0, 2, 0, 200, 0];
+        let event = Event::LeMeta(LeMetaEvent::LeConnectionUpdateComplete(
+            LeConnectionUpdateCompleteEvent {
+                status: ErrorCode::Success,
+                connection_handle: ConnectionHandle::try_new(1).unwrap(),
+                connection_interval: connection_interval!(40),
+                peripheral_latency: latency!(2),
+                supervision_timeout: supervision_timeout!(200),
+            },
+        ));
+        assert_eq!(event_packet(&event).data(), input);
+        let (rest, packet) = packet(input).unwrap();
+        assert_eq!(packet, Packet::Event(event));
+        assert!(rest.is_empty());
+    }
 
     #[test]
     fn test_le_connection_update_complete_event() {
@@ -0,0 +1,344 @@
+use bletio_utils::{BufferOps, EncodeToBuffer};
+use heapless::Vec;
+
+use crate::{BigHandle, ConnectionHandle, ErrorCode, Phy};
+
+pub(crate) const BIS_HANDLES_NB_MAX: usize = 31;
+
+/// Encode the Num_BIS and Connection_Handle parameters of the BIG events.
+pub(crate) fn encode_bis_handles<B: BufferOps>(
+    bis_handles: &[ConnectionHandle],
+    buffer: &mut B,
+) -> Result<usize, bletio_utils::Error> {
+    buffer.try_push(bis_handles.len() as u8)?;
+    for handle in bis_handles {
+        handle.encode(buffer)?;
+    }
+    Ok(size_of::<u8>() + bis_handles.len() * size_of::<u16>())
+}
+
+/// Encode a 24-bit value, as used for the latencies and delays of the BIG events.
+pub(crate) fn encode_le_u24<B: BufferOps>(
+    value: u32,
+    buffer: &mut B,
+) -> Result<usize, bletio_utils::Error> {
+    buffer.copy_from_slice(&value.to_le_bytes()[..3])
+}
+
+/// LE Create BIG Complete Event.
+///
+/// If the status is not a success, all the parameters except the BIG handle shall be ignored.
+///
+/// See [Core Specification 6.0, Vol.4, Part E, 7.7.65.27](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host-controller-interface/host-controller-interface-functional-specification.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeCreateBigCompleteEvent {
+    pub(crate) status: ErrorCode,
+    pub(crate) big_handle: BigHandle,
+    pub(crate) big_sync_delay: u32,
+    pub(crate) transport_latency_big: u32,
+    pub(crate) phy: Phy,
+    pub(crate) nse: u8,
+    pub(crate) bn: u8,
+    pub(crate) pto: u8,
+    pub(crate) irc: u8,
+    pub(crate) max_pdu: u16,
+    pub(crate) iso_interval: u16,
+    pub(crate) bis_handles: Vec<ConnectionHandle, BIS_HANDLES_NB_MAX>,
+}
+
+impl LeCreateBigCompleteEvent {
+    /// Get the maximum time in microseconds for transmission of PDUs of all BISes in a BIG event.
+    pub fn big_sync_delay(&self) -> u32 {
+        self.big_sync_delay
+    }
+
+    pub fn big_handle(&self) -> BigHandle {
+        self.big_handle
+    }
+
+    /// Get the connection handles of the BISes in the BIG.
+    pub fn bis_handles(&self) -> &[ConnectionHandle] {
+        self.bis_handles.as_slice()
+    }
+
+    /// Get the burst number.
+    pub fn bn(&self) -> u8 {
+        self.bn
+    }
+
+    /// Get the immediate repetition count.
+    pub fn irc(&self) -> u8 {
+        self.irc
+    }
+
+    /// Get the time between two consecutive BIG anchor points, in units of 1.25 ms.
+    pub fn iso_interval(&self) -> u16 {
+        self.iso_interval
+    }
+
+    /// Get the maximum size of an isochronous PDU.
+    pub fn max_pdu(&self) -> u16 {
+        self.max_pdu
+    }
+
+    /// Get the number of subevents in each BIS event in the BIG.
+    pub fn nse(&self) -> u8 {
+        self.nse
+    }
+
+    pub fn phy(&self) -> Phy {
+        self.phy
+    }
+
+    /// Get the pre-transmission offset.
+    pub fn pto(&self) -> u8 {
+        self.pto
+    }
+
+    pub fn status(&self) -> ErrorCode {
+        self.status
+    }
+
+    /// Get the actual transport latency in microseconds.
+    pub fn transport_latency_big(&self) -> u32 {
+        self.transport_latency_big
+    }
+}
+
+/// Size of the parameters following the BIG handle when no BIS has been created.
+const EMPTY_PARAMETERS_SIZE: usize = 2 * 3 + 1 + 4 + 2 * size_of::<u16>() + 1;
+
+impl EncodeToBuffer for LeCreateBigCompleteEvent {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        self.status.encode(buffer)?;
+        self.big_handle.encode(buffer)?;
+        if self.status.is_success() {
+            encode_le_u24(self.big_sync_delay, buffer)?;
+            encode_le_u24(self.transport_latency_big, buffer)?;
+            self.phy.encode(buffer)?;
+            buffer.copy_from_slice(&[self.nse, self.bn, self.pto, self.irc])?;
+            buffer.encode_le_u16(self.max_pdu)?;
+            buffer.encode_le_u16(self.iso_interval)?;
+            encode_bis_handles(&self.bis_handles, buffer)?;
+        } else {
+            // On failure, the controller zero-fills the remaining parameters.
+            buffer.copy_from_slice(&[0; EMPTY_PARAMETERS_SIZE])?;
+        }
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        let size = self.status.encoded_size() + self.big_handle.encoded_size();
+        if self.status.is_success() {
+            size + 2 * 3
+                + self.phy.encoded_size()
+                + 4 * size_of::<u8>()
+                + 2 * size_of::<u16>()
+                + size_of::<u8>()
+                + self.bis_handles.len() * size_of::<u16>()
+        } else {
+            size + EMPTY_PARAMETERS_SIZE
+        }
+    }
+}
+
+pub(crate) mod parser {
+    use nom::{
+        combinator::all_consuming,
+        number::complete::{le_u16, le_u24, le_u8},
+        IResult, Parser,
+    };
+
+    use super::*;
+    use crate::big::big_handle::parser::big_handle;
+    use crate::common::phy::parser::phy;
+    use crate::connection::connection_handle::parser::connection_handle;
+    use crate::event::parser::hci_error_code;
+    use crate::LeMetaEvent;
+
+    pub(crate) fn bis_handles(
+        input: &[u8],
+    ) -> IResult<&[u8], Vec<ConnectionHandle, BIS_HANDLES_NB_MAX>> {
+        let (mut rest, num_bis) = le_u8(input)?;
+        let mut handles = Vec::new();
+        for _ in 0..num_bis {
+            let (r, handle) = connection_handle(rest)?;
+            handles.push(handle).map_err(|_| {
+                nom::Err::Failure(nom::error::Error::new(
+                    rest,
+                    nom::error::ErrorKind::TooLarge,
+                ))
+            })?;
+            rest = r;
+        }
+        Ok((rest, handles))
+    }
+
+    pub(crate) fn le_create_big_complete_event(input: &[u8]) -> IResult<&[u8], LeMetaEvent> {
+        let (rest, (status, big_handle)) = (hci_error_code, big_handle).parse(input)?;
+        if !status.is_success() {
+            return Ok((
+                &[],
+                LeMetaEvent::LeCreateBigComplete(LeCreateBigCompleteEvent {
+                    status,
+                    big_handle,
+                    big_sync_delay: 0,
+                    transport_latency_big: 0,
+                    phy: Phy::default(),
+                    nse: 0,
+                    bn: 0,
+                    pto: 0,
+                    irc: 0,
+                    max_pdu: 0,
+                    iso_interval: 0,
+                    bis_handles: Vec::new(),
+                }),
+            ));
+        }
+        let (
+            rest,
+            (
+                big_sync_delay,
+                transport_latency_big,
+                phy,
+                nse,
+                bn,
+                pto,
+                irc,
+                max_pdu,
+                iso_interval,
+                bis_handles,
+            ),
+        ) = all_consuming((
+            le_u24,
+            le_u24,
+            phy,
+            le_u8,
+            le_u8,
+            le_u8,
+            le_u8,
+            le_u16,
+            le_u16,
+            bis_handles,
+        ))
+        .parse(rest)?;
+        Ok((
+            rest,
+            LeMetaEvent::LeCreateBigComplete(LeCreateBigCompleteEvent {
+                status,
+                big_handle,
+                big_sync_delay,
+                transport_latency_big,
+                phy,
+                nse,
+                bn,
+                pto,
+                irc,
+                max_pdu,
+                iso_interval,
+                bis_handles,
+            }),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{packet::parser::packet, test::event_packet, Event, LeMetaEvent, Packet};
+
+    #[rstest]
+    #[case::success(
+        &[0x04, 0x3E, 0x17, 0x1B, 0x00, 0x01, 0x10, 0x27, 0x00, 0x20, 0x4E, 0x00, 0x02, 0x04, 0x01,
+            0x00, 0x02, 0x64, 0x00, 0x08, 0x00, 0x02, 0x20, 0x00, 0x21, 0x00],
+        LeCreateBigCompleteEvent {
+            status: ErrorCode::Success,
+            big_handle: BigHandle::try_new(0x01).unwrap(),
+            big_sync_delay: 10_000,
+            transport_latency_big: 20_000,
+            phy: Phy::Le2M,
+            nse: 4,
+            bn: 1,
+            pto: 0,
+            irc: 2,
+            max_pdu: 100,
+            iso_interval: 8,
+            bis_handles: Vec::from_slice(&[
+                ConnectionHandle::try_new(0x0020).unwrap(),
+                ConnectionHandle::try_new(0x0021).unwrap(),
+            ]).unwrap(),
+        }
+    )]
+    #[case::failure(
+        &[0x04, 0x3E, 0x13, 0x1B, 0x0C, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+        LeCreateBigCompleteEvent {
+            status: ErrorCode::CommandDisallowed,
+            big_handle: BigHandle::try_new(0x01).unwrap(),
+            big_sync_delay: 0,
+            transport_latency_big: 0,
+            phy: Phy::Le1M,
+            nse: 0,
+            bn: 0,
+            pto: 0,
+            irc: 0,
+            max_pdu: 0,
+            iso_interval: 0,
+            bis_handles: Vec::new(),
+        }
+    )]
+    fn test_le_create_big_complete_event_parsing_success(
+        #[case] input: &[u8],
+        #[case] expected: LeCreateBigCompleteEvent,
+    ) {
+        let expected = Event::LeMeta(LeMetaEvent::LeCreateBigComplete(expected));
+        assert_eq!(event_packet(&expected).data(), input);
+        let (rest, packet) = packet(input).unwrap();
+        assert_eq!(packet, Packet::Event(expected));
+        assert!(rest.is_empty());
+    }
+
+    #[rstest]
+    #[case::missing_bis_handle(&[0x04, 0x3E, 0x15, 0x1B, 0x00, 0x01, 0x10, 0x27, 0x00, 0x20, 0x4E, 0x00,
+        0x02, 0x04, 0x01, 0x00, 0x02, 0x64, 0x00, 0x08, 0x00, 0x02, 0x20, 0x00])]
+    #[case::invalid_phy(&[0x04, 0x3E, 0x15, 0x1B, 0x00, 0x01, 0x10, 0x27, 0x00, 0x20, 0x4E, 0x00,
+        0x04, 0x04, 0x01, 0x00, 0x02, 0x64, 0x00, 0x08, 0x00, 0x01, 0x20, 0x00])]
+    fn test_le_create_big_complete_event_parsing_failure(#[case] input: &[u8]) {
+        assert!(packet(input).is_err());
+    }
+
+    #[test]
+    fn test_le_create_big_complete_event_getters() {
+        let big_handle = BigHandle::try_new(0x02).unwrap();
+        let bis_handle = ConnectionHandle::try_new(0x0040).unwrap();
+        let event = LeCreateBigCompleteEvent {
+            status: ErrorCode::Success,
+            big_handle,
+            big_sync_delay: 1_000,
+            transport_latency_big: 2_000,
+            phy: Phy::LeCoded,
+            nse: 3,
+            bn: 2,
+            pto: 1,
+            irc: 4,
+            max_pdu: 251,
+            iso_interval: 16,
+            bis_handles: Vec::from_slice(&[bis_handle]).unwrap(),
+        };
+        assert_eq!(event.status(), ErrorCode::Success);
+        assert_eq!(event.big_handle(), big_handle);
+        assert_eq!(event.big_sync_delay(), 1_000);
+        assert_eq!(event.transport_latency_big(), 2_000);
+        assert_eq!(event.phy(), Phy::LeCoded);
+        assert_eq!(event.nse(), 3);
+        assert_eq!(event.bn(), 2);
+        assert_eq!(event.pto(), 1);
+        assert_eq!(event.irc(), 4);
+        assert_eq!(event.max_pdu(), 251);
+        assert_eq!(event.iso_interval(), 16);
+        assert_eq!(event.bis_handles(), &[bis_handle]);
+    }
+}
@@ -1,6 +1,11 @@
+use bletio_utils::{BufferOps, EncodeToBuffer};
 use num_enum::{FromPrimitive, IntoPrimitive};
 
-use crate::{LeAdvertisingReportList, LeConnectionCompleteEvent, LeConnectionUpdateCompleteEvent};
+use crate::{
+    LeAdvertisingReportList, LeBigSyncEstablishedEvent, LeBigSyncLostEvent,
+    LeBiginfoAdvertisingReportEvent, LeConnectionCompleteEvent, LeConnectionUpdateCompleteEvent,
+    LeCreateBigCompleteEvent, LeTerminateBigCompleteEvent,
+};
 
 #[derive(Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
@@ -9,9 +14,64 @@ pub enum LeMetaEvent {
     LeConnectionComplete(LeConnectionCompleteEvent),
     LeAdvertisingReport(LeAdvertisingReportList),
     LeConnectionUpdateComplete(LeConnectionUpdateCompleteEvent),
+    LeCreateBigComplete(LeCreateBigCompleteEvent),
+    LeTerminateBigComplete(LeTerminateBigCompleteEvent),
+    LeBigSyncEstablished(LeBigSyncEstablishedEvent),
+    LeBigSyncLost(LeBigSyncLostEvent),
+    LeBiginfoAdvertisingReport(LeBiginfoAdvertisingReportEvent),
     Unsupported(u8),
 }
 
+impl LeMetaEvent {
+    fn code(&self) -> LeMetaEventCode {
+        match self {
+            Self::LeConnectionComplete(_) => LeMetaEventCode::LeConnectionComplete,
+            Self::LeAdvertisingReport(_) => LeMetaEventCode::LeAdvertisingReport,
+            Self::LeConnectionUpdateComplete(_) => LeMetaEventCode::LeConnectionUpdateComplete,
+            Self::LeCreateBigComplete(_) => LeMetaEventCode::LeCreateBigComplete,
+            Self::LeTerminateBigComplete(_) => LeMetaEventCode::LeTerminateBigComplete,
+            Self::LeBigSyncEstablished(_) => LeMetaEventCode::LeBigSyncEstablished,
+            Self::LeBigSyncLost(_) => LeMetaEventCode::LeBigSyncLost,
+            Self::LeBiginfoAdvertisingReport(_) => LeMetaEventCode::LeBiginfoAdvertisingReport,
+            Self::Unsupported(code) => LeMetaEventCode::Unsupported(*code),
+        }
+    }
+}
+
+impl EncodeToBuffer for LeMetaEvent {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        buffer.try_push(self.code().into())?;
+        match self {
+            Self::LeConnectionComplete(event) => event.encode(buffer)?,
+            Self::LeAdvertisingReport(event) => event.encode(buffer)?,
+            Self::LeConnectionUpdateComplete(event) => event.encode(buffer)?,
+            Self::LeCreateBigComplete(event) => event.encode(buffer)?,
+            Self::LeTerminateBigComplete(event) => event.encode(buffer)?,
+            Self::LeBigSyncEstablished(event) => event.encode(buffer)?,
+            Self::LeBigSyncLost(event) => event.encode(buffer)?,
+            Self::LeBiginfoAdvertisingReport(event) => event.encode(buffer)?,
+            // The parameters of an unsupported event are not kept.
+            Self::Unsupported(_) => 0,
+        };
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        size_of::<u8>()
+            + match self {
+                Self::LeConnectionComplete(event) => event.encoded_size(),
+                Self::LeAdvertisingReport(event) => event.encoded_size(),
+                Self::LeConnectionUpdateComplete(event) => event.encoded_size(),
+                Self::LeCreateBigComplete(event) => event.encoded_size(),
+                Self::LeTerminateBigComplete(event) => event.encoded_size(),
+                Self::LeBigSyncEstablished(event) => event.encoded_size(),
+                Self::LeBigSyncLost(event) => event.encoded_size(),
+                Self::LeBiginfoAdvertisingReport(event) => event.encoded_size(),
+                Self::Unsupported(_) => 0,
+            }
+    }
+}
+
 #[derive(Debug, IntoPrimitive, FromPrimitive)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
@@ -19,6 +79,11 @@ enum LeMetaEventCode {
     LeConnectionComplete = 0x01,
     LeAdvertisingReport = 0x02,
     LeConnectionUpdateComplete = 0x03,
+    LeCreateBigComplete = 0x1B,
+    LeTerminateBigComplete = 0x1C,
+    LeBigSyncEstablished = 0x1D,
+    LeBigSyncLost = 0x1E,
+    LeBiginfoAdvertisingReport = 0x22,
     #[num_enum(catch_all)]
     Unsupported(u8),
 }
@@ -28,8 +93,13 @@ pub(crate) mod parser {
 
     use super::*;
     use crate::event::le_advertising_report::parser::le_advertising_report_event;
+    use crate::event::le_big_sync_established::parser::le_big_sync_established_event;
+    use crate::event::le_big_sync_lost::parser::le_big_sync_lost_event;
+    use crate::event::le_biginfo_advertising_report::parser::le_biginfo_advertising_report_event;
     use crate::event::le_connection_complete::parser::le_connection_complete_event;
     use crate::event::le_connection_update_complete::parser::le_connection_update_complete_event;
+    use crate::event::le_create_big_complete::parser::le_create_big_complete_event;
+    use crate::event::le_terminate_big_complete::parser::le_terminate_big_complete_event;
 
     fn le_meta_event_code(input: &[u8]) -> IResult<&[u8], LeMetaEventCode> {
         map_res(le_u8, LeMetaEventCode::try_from).parse(input)
@@ -43,6 +113,13 @@ pub(crate) mod parser {
             LeMetaEventCode::LeConnectionUpdateComplete => {
                 le_connection_update_complete_event(parameters)
             }
+            LeMetaEventCode::LeCreateBigComplete => le_create_big_complete_event(parameters),
+            LeMetaEventCode::LeTerminateBigComplete => le_terminate_big_complete_event(parameters),
+            LeMetaEventCode::LeBigSyncEstablished => le_big_sync_established_event(parameters),
+            LeMetaEventCode::LeBigSyncLost => le_big_sync_lost_event(parameters),
+            LeMetaEventCode::LeBiginfoAdvertisingReport => {
+                le_biginfo_advertising_report_event(parameters)
+            }
             LeMetaEventCode::Unsupported(event_code) => {
                 Ok((&[], LeMetaEvent::Unsupported(event_code)))
             }
@@ -52,7 +129,9 @@ pub(crate) mod parser {
 
 #[cfg(test)]
 mod test {
-    use crate::{packet::parser::packet, Event, LeMetaEvent, Packet};
+    use bletio_utils::BufferOps;
+
+    use crate::{packet::parser::packet, test::event_packet, Event, LeMetaEvent, Packet};
 
     #[test]
     fn test_unsupported_le_meta_event_parsing() {
@@ -67,4 +146,10 @@ mod test {
         ));
         assert!(rest.is_empty());
     }
+
+    #[test]
+    fn test_unsupported_le_meta_event_encoding() {
+        let event = Event::LeMeta(LeMetaEvent::Unsupported(0x34));
+        assert_eq!(event_packet(&event).data(), &[0x04, 0x3E, 0x01, 0x34]);
+    }
 }
@@ -0,0 +1,104 @@
+use bletio_utils::{BufferOps, EncodeToBuffer};
+
+use crate::{BigHandle, ErrorCode};
+
+/// LE Terminate BIG Complete Event.
+///
+/// See [Core Specification 6.0, Vol.4, Part E, 7.7.65.28](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host-controller-interface/host-controller-interface-functional-specification.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeTerminateBigCompleteEvent {
+    pub(crate) big_handle: BigHandle,
+    pub(crate) reason: ErrorCode,
+}
+
+impl LeTerminateBigCompleteEvent {
+    pub fn big_handle(&self) -> BigHandle {
+        self.big_handle
+    }
+
+    pub fn reason(&self) -> ErrorCode {
+        self.reason
+    }
+}
+
+impl EncodeToBuffer for LeTerminateBigCompleteEvent {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        self.big_handle.encode(buffer)?;
+        self.reason.encode(buffer)?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        self.big_handle.encoded_size() + self.reason.encoded_size()
+    }
+}
+
+pub(crate) mod parser {
+    use nom::{
+        combinator::{all_consuming, map},
+        IResult, Parser,
+    };
+
+    use super::*;
+    use crate::big::big_handle::parser::big_handle;
+    use crate::event::parser::hci_error_code;
+    use crate::LeMetaEvent;
+
+    pub(crate) fn le_terminate_big_complete_event(input: &[u8]) -> IResult<&[u8], LeMetaEvent> {
+        map(
+            all_consuming((big_handle, hci_error_code)),
+            |(big_handle, reason)| {
+                LeMetaEvent::LeTerminateBigComplete(LeTerminateBigCompleteEvent {
+                    big_handle,
+                    reason,
+                })
+            },
+        )
+        .parse(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{packet::parser::packet, test::event_packet, Event, LeMetaEvent, Packet};
+
+    #[test]
+    fn test_le_terminate_big_complete_event_parsing() {
+        let (rest, packet) = packet(&[0x04, 0x3E, 0x03, 0x1C, 0x01, 0x16]).unwrap();
+        let expected = LeTerminateBigCompleteEvent {
+            big_handle: BigHandle::try_new(0x01).unwrap(),
+            reason: ErrorCode::ConnectionTerminatedByLocalHost,
+        };
+        assert_eq!(expected.big_handle(), BigHandle::try_new(0x01).unwrap());
+        assert_eq!(
+            expected.reason(),
+            ErrorCode::ConnectionTerminatedByLocalHost
+        );
+        assert_eq!(
+            packet,
+            Packet::Event(Event::LeMeta(LeMetaEvent::LeTerminateBigComplete(expected)))
+        );
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn test_le_terminate_big_complete_event_encoding() {
+        let event = Event::LeMeta(LeMetaEvent::LeTerminateBigComplete(
+            LeTerminateBigCompleteEvent {
+                big_handle: BigHandle::try_new(0x01).unwrap(),
+                reason: ErrorCode::ConnectionTerminatedByLocalHost,
+            },
+        ));
+        assert_eq!(
+            event_packet(&event).data(),
+            &[0x04, 0x3E, 0x03, 0x1C, 0x01, 0x16]
+        );
+    }
+
+    #[test]
+    fn test_le_terminate_big_complete_event_invalid_big_handle() {
+        assert!(packet(&[0x04, 0x3E, 0x03, 0x1C, 0xF0, 0x16]).is_err());
+    }
+}
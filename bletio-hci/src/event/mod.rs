@@ -1,20 +1,32 @@
 use core::ops::{Deref, DerefMut};
 
+use bletio_utils::{BufferOps, EncodeToBuffer};
 use heapless::Vec;
 use num_enum::{FromPrimitive, IntoPrimitive};
 
 use crate::event::command_status::CommandStatusEvent;
-use crate::{CommandCompleteEvent, DisconnectionCompleteEvent, LeMetaEvent};
+use crate::{
+    CommandCompleteEvent, DisconnectionCompleteEvent, HardwareErrorEvent, LeMetaEvent,
+    NumberOfCompletedPacketsEvent,
+};
 
 pub(crate) mod command_complete;
 pub(crate) mod command_status;
 pub(crate) mod disconnection_complete;
+pub(crate) mod hardware_error;
 pub(crate) mod le_advertising_report;
+pub(crate) mod le_big_sync_established;
+pub(crate) mod le_big_sync_lost;
+pub(crate) mod le_biginfo_advertising_report;
 pub(crate) mod le_connection_complete;
 pub(crate) mod le_connection_update_complete;
+pub(crate) mod le_create_big_complete;
 pub(crate) mod le_meta;
+pub(crate) mod le_terminate_big_complete;
+pub(crate) mod number_of_completed_packets;
 
-const EVENT_LIST_NB_EVENTS: usize = 4;
+/// Default maximum number of events returned at once by [`Hci::wait_for_event`](crate::Hci::wait_for_event).
+pub const EVENT_LIST_NB_EVENTS: usize = 4;
 
 #[derive(Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
@@ -23,25 +35,81 @@ pub enum Event {
     DisconnectionComplete(DisconnectionCompleteEvent),
     CommandComplete(CommandCompleteEvent),
     CommandStatus(CommandStatusEvent),
+    HardwareError(HardwareErrorEvent),
+    NumberOfCompletedPackets(NumberOfCompletedPacketsEvent),
     LeMeta(LeMetaEvent),
     Unsupported(u8),
 }
 
+impl Event {
+    fn code(&self) -> EventCode {
+        match self {
+            Self::DisconnectionComplete(_) => EventCode::DisconnectionComplete,
+            Self::CommandComplete(_) => EventCode::CommandComplete,
+            Self::CommandStatus(_) => EventCode::CommandStatus,
+            Self::HardwareError(_) => EventCode::HardwareError,
+            Self::NumberOfCompletedPackets(_) => EventCode::NumberOfCompletedPackets,
+            Self::LeMeta(_) => EventCode::LeMeta,
+            Self::Unsupported(code) => EventCode::Unsupported(*code),
+        }
+    }
+
+    fn parameter_total_length(&self) -> usize {
+        match self {
+            Self::DisconnectionComplete(event) => event.encoded_size(),
+            Self::CommandComplete(event) => event.encoded_size(),
+            Self::CommandStatus(event) => event.encoded_size(),
+            Self::HardwareError(event) => event.encoded_size(),
+            Self::NumberOfCompletedPackets(event) => event.encoded_size(),
+            Self::LeMeta(event) => event.encoded_size(),
+            // The parameters of an unsupported event are not kept.
+            Self::Unsupported(_) => 0,
+        }
+    }
+}
+
+/// Encode the event as sent by a controller, without the HCI packet indicator.
+impl EncodeToBuffer for Event {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        let parameter_total_length: u8 = self
+            .parameter_total_length()
+            .try_into()
+            .map_err(|_| bletio_utils::Error::CannotEncode)?;
+        buffer.try_push(self.code().into())?;
+        buffer.try_push(parameter_total_length)?;
+        match self {
+            Self::DisconnectionComplete(event) => event.encode(buffer)?,
+            Self::CommandComplete(event) => event.encode(buffer)?,
+            Self::CommandStatus(event) => event.encode(buffer)?,
+            Self::HardwareError(event) => event.encode(buffer)?,
+            Self::NumberOfCompletedPackets(event) => event.encode(buffer)?,
+            Self::LeMeta(event) => event.encode(buffer)?,
+            Self::Unsupported(_) => 0,
+        };
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        2 * size_of::<u8>() + self.parameter_total_length()
+    }
+}
+
+/// List of events, holding up to `NB_EVENTS` events.
 #[derive(Debug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub struct EventList {
-    events: Vec<Event, EVENT_LIST_NB_EVENTS>,
+pub struct EventList<const NB_EVENTS: usize = EVENT_LIST_NB_EVENTS> {
+    events: Vec<Event, NB_EVENTS>,
 }
 
-impl Deref for EventList {
-    type Target = Vec<Event, EVENT_LIST_NB_EVENTS>;
+impl<const NB_EVENTS: usize> Deref for EventList<NB_EVENTS> {
+    type Target = Vec<Event, NB_EVENTS>;
 
     fn deref(&self) -> &Self::Target {
         &self.events
     }
 }
 
-impl DerefMut for EventList {
+impl<const NB_EVENTS: usize> DerefMut for EventList<NB_EVENTS> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.events
     }
@@ -54,6 +122,8 @@ enum EventCode {
     DisconnectionComplete = 0x05,
     CommandComplete = 0x0E,
     CommandStatus = 0x0F,
+    HardwareError = 0x10,
+    NumberOfCompletedPackets = 0x13,
     LeMeta = 0x3E,
     #[num_enum(catch_all)]
     Unsupported(u8),
@@ -67,6 +137,8 @@ pub(crate) mod parser {
     use super::*;
     use crate::event::command_status::parser::command_status_event;
     use crate::event::disconnection_complete::parser::disconnection_complete_event;
+    use crate::event::hardware_error::parser::hardware_error_event;
+    use crate::event::number_of_completed_packets::parser::number_of_completed_packets_event;
     use crate::{
         event::{
             command_complete::parser::command_complete_event, le_meta::parser::le_meta_event,
@@ -88,7 +160,9 @@ pub(crate) mod parser {
         map_res(le_u8, ErrorCode::try_from).parse(input)
     }
 
-    pub(crate) fn event(input: &[u8]) -> IResult<&[u8], Packet> {
+    pub(crate) fn event<const ACL_DATA_SIZE: usize>(
+        input: &[u8],
+    ) -> IResult<&[u8], Packet<ACL_DATA_SIZE>> {
         let (input, (event_code, parameter_total_length)) =
             pair(event_code, parameter_total_length).parse(input)?;
         let (input, parameters) = take(parameter_total_length).parse(input)?;
@@ -107,6 +181,14 @@ pub(crate) mod parser {
                     let (_, event) = command_status_event(parameters)?;
                     Event::CommandStatus(event)
                 }
+                EventCode::HardwareError => {
+                    let (_, event) = hardware_error_event(parameters)?;
+                    Event::HardwareError(event)
+                }
+                EventCode::NumberOfCompletedPackets => {
+                    let (_, event) = number_of_completed_packets_event(parameters)?;
+                    Event::NumberOfCompletedPackets(event)
+                }
                 EventCode::LeMeta => {
                     let (_, event) = le_meta_event(parameters)?;
                     Event::LeMeta(event)
@@ -119,7 +201,7 @@ pub(crate) mod parser {
 
 #[cfg(test)]
 mod test {
-    use crate::{packet::parser::packet, Packet};
+    use crate::{packet::parser::packet, test::event_packet, Packet};
 
     use super::*;
 
@@ -134,8 +216,17 @@ mod test {
 
     #[test]
     fn test_event_list() {
-        let event_list = EventList::default();
+        let event_list: EventList = EventList::default();
         assert_eq!(event_list.deref(), &event_list.events);
+        assert_eq!(event_list.capacity(), EVENT_LIST_NB_EVENTS);
+    }
+
+    #[test]
+    fn test_event_list_custom_capacity() {
+        let mut event_list: EventList<1> = EventList::default();
+        assert_eq!(event_list.capacity(), 1);
+        assert!(event_list.push(Event::Unsupported(1)).is_ok());
+        assert!(event_list.push(Event::Unsupported(2)).is_err());
     }
 
     #[test]
@@ -145,4 +236,22 @@ mod test {
         assert!(matches!(packet, Packet::Event(Event::Unsupported(1))));
         assert!(rest.is_empty());
     }
+
+    #[test]
+    fn test_unsupported_event_encoding() {
+        assert_eq!(
+            event_packet(&Event::Unsupported(1)).data(),
+            &[0x04, 0x01, 0x00]
+        );
+    }
+
+    #[test]
+    fn test_event_encoding_too_long() {
+        let mut buffer = bletio_utils::Buffer::<2>::default();
+        assert!(Event::Unsupported(1).encode(&mut buffer).is_ok());
+        assert_eq!(
+            Event::Unsupported(1).encode(&mut buffer),
+            Err(bletio_utils::Error::BufferTooSmall)
+        );
+    }
 }
@@ -0,0 +1,187 @@
+use bletio_utils::{BufferOps, EncodeToBuffer};
+use heapless::Vec;
+
+use crate::{ConnectionHandle, Error};
+
+// The parameters of an event are at most 255 bytes long, with 1 byte for the number of handles
+// and 4 bytes per handle.
+const NUMBER_OF_COMPLETED_PACKETS_MAX_HANDLES: usize = 63;
+
+/// Number of packets that have been completed for a connection handle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CompletedPackets {
+    connection_handle: ConnectionHandle,
+    num_completed_packets: u16,
+}
+
+impl CompletedPackets {
+    pub fn new(connection_handle: ConnectionHandle, num_completed_packets: u16) -> Self {
+        Self {
+            connection_handle,
+            num_completed_packets,
+        }
+    }
+
+    pub fn connection_handle(&self) -> ConnectionHandle {
+        self.connection_handle
+    }
+
+    pub fn num_completed_packets(&self) -> u16 {
+        self.num_completed_packets
+    }
+}
+
+/// Number Of Completed Packets Event.
+///
+/// Sent by the Controller to tell how many ACL data packets have been completed (transmitted
+/// or flushed) for each connection handle since the previous event.
+///
+/// See [Core Specification 6.0, Vol.4, Part E, 7.7.19](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host-controller-interface/host-controller-interface-functional-specification.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct NumberOfCompletedPacketsEvent {
+    completed_packets: Vec<CompletedPackets, NUMBER_OF_COMPLETED_PACKETS_MAX_HANDLES>,
+}
+
+impl NumberOfCompletedPacketsEvent {
+    pub fn try_new(completed_packets: &[CompletedPackets]) -> Result<Self, Error> {
+        Ok(Self {
+            completed_packets: Vec::from_slice(completed_packets)
+                .map_err(|_| Error::DataWillNotFitEventPacket)?,
+        })
+    }
+
+    pub fn completed_packets(&self) -> &[CompletedPackets] {
+        &self.completed_packets
+    }
+}
+
+impl EncodeToBuffer for NumberOfCompletedPacketsEvent {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        buffer.try_push(self.completed_packets.len() as u8)?;
+        for completed_packets in &self.completed_packets {
+            completed_packets.connection_handle.encode(buffer)?;
+            buffer.encode_le_u16(completed_packets.num_completed_packets)?;
+        }
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        size_of::<u8>() + self.completed_packets.len() * 2 * size_of::<u16>()
+    }
+}
+
+pub(crate) mod parser {
+    use nom::{
+        combinator::{eof, map},
+        number::complete::{le_u16, le_u8},
+        IResult, Parser,
+    };
+
+    use super::*;
+    use crate::connection::connection_handle::parser::connection_handle;
+
+    fn num_handles(input: &[u8]) -> IResult<&[u8], u8> {
+        le_u8.parse(input)
+    }
+
+    fn completed_packets(input: &[u8]) -> IResult<&[u8], CompletedPackets> {
+        map(
+            (connection_handle, le_u16),
+            |(connection_handle, num_completed_packets)| CompletedPackets {
+                connection_handle,
+                num_completed_packets,
+            },
+        )
+        .parse(input)
+    }
+
+    pub(crate) fn number_of_completed_packets_event(
+        input: &[u8],
+    ) -> IResult<&[u8], NumberOfCompletedPacketsEvent> {
+        let (mut rest, num_handles) = num_handles(input)?;
+        let mut completed_packets_list = Vec::new();
+        for _ in 0..num_handles {
+            let (r, completed_packets) = completed_packets(rest)?;
+            completed_packets_list
+                .push(completed_packets)
+                .map_err(|_| {
+                    nom::Err::Failure(nom::error::Error::new(
+                        rest,
+                        nom::error::ErrorKind::TooLarge,
+                    ))
+                })?;
+            rest = r;
+        }
+        let (rest, _) = eof(rest)?;
+        Ok((
+            rest,
+            NumberOfCompletedPacketsEvent {
+                completed_packets: completed_packets_list,
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{packet::parser::packet, test::event_packet, Event, Packet};
+
+    #[rstest]
+    #[case::one_handle(
+        &[CompletedPackets {
+            connection_handle: ConnectionHandle::try_new(0x0001).unwrap(),
+            num_completed_packets: 2,
+        }],
+        &[0x04, 0x13, 0x05, 0x01, 0x01, 0x00, 0x02, 0x00]
+    )]
+    #[case::two_handles(
+        &[
+            CompletedPackets {
+                connection_handle: ConnectionHandle::try_new(0x0001).unwrap(),
+                num_completed_packets: 2,
+            },
+            CompletedPackets {
+                connection_handle: ConnectionHandle::try_new(0x0EFF).unwrap(),
+                num_completed_packets: 0x0102,
+            },
+        ],
+        &[0x04, 0x13, 0x09, 0x02, 0x01, 0x00, 0x02, 0x00, 0xFF, 0x0E, 0x02, 0x01]
+    )]
+    fn test_number_of_completed_packets_event_parsing(
+        #[case] completed_packets: &[CompletedPackets],
+        #[case] input: &[u8],
+    ) {
+        let event = NumberOfCompletedPacketsEvent::try_new(completed_packets).unwrap();
+        assert_eq!(event.completed_packets(), completed_packets);
+        let event = Event::NumberOfCompletedPackets(event);
+        assert_eq!(event_packet(&event).data(), input);
+        let (rest, packet) = packet(input).unwrap();
+        assert_eq!(packet, Packet::Event(event));
+        assert!(rest.is_empty());
+    }
+
+    #[rstest]
+    #[case::missing_handle(&[0x04, 0x13, 0x05, 0x02, 0x01, 0x00, 0x02, 0x00])]
+    #[case::extra_data(&[0x04, 0x13, 0x06, 0x01, 0x01, 0x00, 0x02, 0x00, 0x00])]
+    #[case::invalid_handle(&[0x04, 0x13, 0x05, 0x01, 0x00, 0x0F, 0x02, 0x00])]
+    fn test_number_of_completed_packets_event_parsing_failure(#[case] input: &[u8]) {
+        assert!(packet(input).is_err());
+    }
+
+    #[test]
+    fn test_number_of_completed_packets_event_too_many_handles() {
+        let completed_packets = [CompletedPackets {
+            connection_handle: ConnectionHandle::default(),
+            num_completed_packets: 1,
+        }; NUMBER_OF_COMPLETED_PACKETS_MAX_HANDLES + 1];
+        assert_eq!(
+            NumberOfCompletedPacketsEvent::try_new(&completed_packets),
+            Err(Error::DataWillNotFitEventPacket)
+        );
+    }
+}
@@ -3,41 +3,175 @@ use core::{
     time::Duration,
 };
 
+use bletio_utils::{Buffer, BufferOps, EncodeToBuffer};
+use heapless::{Deque, Vec};
+
+use crate::capture::PacketDirection;
 use crate::{
-    AdvertisingData, AdvertisingEnable, AdvertisingParameters, Command, ConnectionHandle,
-    ConnectionParameters, ConnectionUpdateParameters, Error, ErrorCode, Event, EventList,
-    EventMask, EventParameter, FilterDuplicates, HciBuffer, HciDriver, LeEventMask,
-    LeFilterAcceptListAddress, Packet, PublicDeviceAddress, RandomStaticDeviceAddress, Reason,
-    ScanEnable, ScanParameters, SupportedCommands, SupportedFeatures, SupportedLeFeatures,
-    SupportedLeStates, TxPowerLevel, WithTimeout,
+    AdvertisingData, AdvertisingEnable, AdvertisingParameters, BigCreateSyncParameters, BigHandle,
+    BigParameters, Command, CommandOpCode, ConnectionHandle, ConnectionParameters,
+    ConnectionUpdateParameters, Error, ErrorCode, Event, EventList, EventMask, EventParameter,
+    FilterDuplicates, HciBuffer, HciDriver, IsoData, LeEventMask, LeFilterAcceptListAddress,
+    Packet, PacketType, PublicDeviceAddress, RandomStaticDeviceAddress, Reason, ScanEnable,
+    ScanParameters, SupportedCommands, SupportedFeatures, SupportedLeFeatures, SupportedLeStates,
+    TimeSource, TxPowerLevel, WithTimeout, ACL_DATA_MAX_SIZE, EVENT_LIST_NB_EVENTS,
+    HCI_MAX_READ_BUFFER_SIZE,
 };
 
 const HCI_COMMAND_TIMEOUT: Duration = Duration::from_millis(1000);
 
+/// Default number of events that can be queued while waiting for command responses.
+pub const HCI_DEFAULT_EVENT_QUEUE_SIZE: usize = 8;
+
+/// Maximum number of commands that can be waiting for their response at the same time.
+const HCI_MAX_PENDING_COMMANDS: usize = 4;
+
+// Packet Type (1) + ISO data packet
+const HCI_ISO_DATA_MAX_SIZE: usize = 259;
+
+/// Default number of received ISO data packets that can be queued until they are retrieved.
+pub const HCI_DEFAULT_ISO_DATA_QUEUE_SIZE: usize = 4;
+
+/// Time source used by [`Hci`] when none is given.
+///
+/// It is the tokio time source if the `tokio` feature is enabled, otherwise the embassy one if
+/// the `embassy` feature is enabled.
+#[cfg(feature = "tokio")]
+pub type DefaultTimeSource = crate::TokioTimeSource;
+/// Time source used by [`Hci`] when none is given.
+///
+/// It is the tokio time source if the `tokio` feature is enabled, otherwise the embassy one if
+/// the `embassy` feature is enabled.
+#[cfg(all(feature = "embassy", not(feature = "tokio")))]
+pub type DefaultTimeSource = crate::EmbassyTimeSource;
+/// Placeholder for the default time source of [`Hci`] when neither the `tokio` nor the
+/// `embassy` feature is enabled.
+///
+/// It does not implement [`TimeSource`], so a time source needs to be given explicitly with
+/// [`Hci::with_time_source`].
+#[cfg(not(any(feature = "embassy", feature = "tokio")))]
 #[derive(Debug)]
-pub struct Hci<H>
-where
+pub enum DefaultTimeSource {}
+
+/// Host side of the HCI, sending commands to the Controller and receiving its events.
+///
+/// Several commands can be in flight at the same time, up to the number of command packets
+/// the Controller is able to accept. The events received while waiting for the responses of
+/// the commands are kept in a queue of `EVENT_QUEUE_SIZE` events until they are retrieved with
+/// [`Hci::wait_for_event`], that returns up to `EVENT_LIST_SIZE` events at once. The command
+/// timeouts are measured with the `T` [`TimeSource`].
+///
+/// The packets from the Controller are read in a buffer of `READ_BUFFER_SIZE` bytes, and the
+/// received ACL data packets are able to hold up to `ACL_DATA_SIZE` bytes of data. The received
+/// ISO data packets are kept in a queue of `ISO_DATA_QUEUE_SIZE` packets until they are retrieved
+/// with [`Hci::take_iso_data`].
+#[derive(Debug)]
+pub struct Hci<
+    H,
+    T = DefaultTimeSource,
+    const EVENT_QUEUE_SIZE: usize = HCI_DEFAULT_EVENT_QUEUE_SIZE,
+    const READ_BUFFER_SIZE: usize = HCI_MAX_READ_BUFFER_SIZE,
+    const EVENT_LIST_SIZE: usize = EVENT_LIST_NB_EVENTS,
+    const ACL_DATA_SIZE: usize = ACL_DATA_MAX_SIZE,
+    const ISO_DATA_QUEUE_SIZE: usize = HCI_DEFAULT_ISO_DATA_QUEUE_SIZE,
+> where
     H: HciDriver,
 {
     driver: H,
+    time_source: T,
     num_hci_command_packets: u8,
-    read_buffer: HciBuffer,
-    event_list: EventList,
+    read_buffer: HciBuffer<READ_BUFFER_SIZE>,
+    pending_commands: Vec<CommandOpCode, HCI_MAX_PENDING_COMMANDS>,
+    command_responses: Vec<Event, HCI_MAX_PENDING_COMMANDS>,
+    event_queue: Deque<Event, EVENT_QUEUE_SIZE>,
+    event_queue_overflowed: bool,
+    iso_data_queue: Deque<IsoData, ISO_DATA_QUEUE_SIZE>,
+    iso_data_queue_overflowed: bool,
 }
 
+#[cfg(any(feature = "embassy", feature = "tokio"))]
 impl<H> Hci<H>
 where
     H: HciDriver,
 {
     pub fn new(hci_driver: H) -> Self {
+        Self::with_time_source(hci_driver, DefaultTimeSource::default())
+    }
+}
+
+impl<H, T> Hci<H, T>
+where
+    H: HciDriver,
+    T: TimeSource,
+{
+    /// Create an HCI measuring its timeouts with the given time source.
+    pub fn with_time_source(hci_driver: H, time_source: T) -> Self {
+        Self::with_event_queue(hci_driver, time_source)
+    }
+}
+
+impl<
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >
+    Hci<
+        H,
+        T,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
+where
+    H: HciDriver,
+    T: TimeSource,
+{
+    /// Create an HCI whose capacities are given by the const generic parameters of its type,
+    /// e.g. `let hci: Hci<_, _, 32> = Hci::with_event_queue(driver, TokioTimeSource);` for an
+    /// event queue able to hold 32 events.
+    pub fn with_event_queue(hci_driver: H, time_source: T) -> Self {
         Self {
             driver: hci_driver,
-            num_hci_command_packets: 0,
+            time_source,
+            // The Controller is able to accept one command after power on, like after a reset.
+            num_hci_command_packets: 1,
             read_buffer: Default::default(),
-            event_list: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
         }
     }
 
+    /// Get the time source measuring the timeouts of the HCI.
+    pub fn time_source(&self) -> &T {
+        &self.time_source
+    }
+
+    /// Forget the pending commands, the queued events and ISO data and any partially received
+    /// packet, e.g. after a failure of the Controller.
+    ///
+    /// As after a reset of the Controller, the Host then assumes it is allowed to send a single
+    /// command.
+    pub fn reset_state(&mut self) {
+        self.num_hci_command_packets = 1;
+        self.read_buffer.clear();
+        self.pending_commands.clear();
+        self.command_responses.clear();
+        self.event_queue.clear();
+        self.event_queue_overflowed = false;
+        self.iso_data_queue.clear();
+        self.iso_data_queue_overflowed = false;
+    }
+
     pub async fn cmd_disconnect(
         &mut self,
         connection_handle: ConnectionHandle,
@@ -60,6 +194,28 @@ where
         .await
     }
 
+    pub async fn cmd_le_big_create_sync(
+        &mut self,
+        parameters: BigCreateSyncParameters,
+    ) -> Result<(), Error> {
+        self.execute_command_with_command_status_response(Command::LeBigCreateSync(parameters))
+            .await
+    }
+
+    pub async fn cmd_le_big_terminate_sync(
+        &mut self,
+        big_handle: BigHandle,
+    ) -> Result<BigHandle, Error> {
+        let (status, param) = self
+            .execute_command_with_command_complete_response(Command::LeBigTerminateSync(big_handle))
+            .await?;
+        if let (ErrorCode::Success, Some(EventParameter::BigHandle(param))) = (status, param) {
+            Ok(param.big_handle)
+        } else {
+            Err(Error::ErrorCode(status))
+        }
+    }
+
     pub async fn cmd_le_clear_filter_accept_list(&mut self) -> Result<(), Error> {
         self.cmd_with_command_complete_response_without_parameter(Command::LeClearFilterAcceptList)
             .await
@@ -75,6 +231,11 @@ where
         .await
     }
 
+    pub async fn cmd_le_create_big(&mut self, parameters: BigParameters) -> Result<(), Error> {
+        self.execute_command_with_command_status_response(Command::LeCreateBig(parameters))
+            .await
+    }
+
     pub async fn cmd_le_create_connection(
         &mut self,
         connection_parameters: ConnectionParameters,
@@ -260,6 +421,17 @@ where
             .await
     }
 
+    pub async fn cmd_le_terminate_big(
+        &mut self,
+        big_handle: BigHandle,
+        reason: Reason,
+    ) -> Result<(), Error> {
+        self.execute_command_with_command_status_response(Command::LeTerminateBig(
+            big_handle, reason,
+        ))
+        .await
+    }
+
     pub async fn cmd_read_bd_addr(&mut self) -> Result<PublicDeviceAddress, Error> {
         let (status, param) = self
             .execute_command_with_command_complete_response(Command::ReadBdAddr)
@@ -325,50 +497,123 @@ where
             .await
     }
 
-    pub async fn wait_for_event(&mut self) -> Result<EventList, Error> {
-        let mut event_list = core::mem::take(&mut self.event_list);
+    pub async fn send_iso_data(&mut self, iso_data: &IsoData) -> Result<(), Error> {
+        let mut buffer = Buffer::<HCI_ISO_DATA_MAX_SIZE>::default();
+        buffer
+            .try_push(PacketType::IsoData as u8)
+            .map_err(|_| Error::DataWillNotFitIsoDataPacket)?;
+        iso_data
+            .encode(&mut buffer)
+            .map_err(|_| Error::DataWillNotFitIsoDataPacket)?;
+        self.driver.write(buffer.data()).await?;
+        self.driver
+            .trace(PacketDirection::HostToController, buffer.data());
+        Ok(())
+    }
 
-        loop {
-            if (self.read_buffer.is_empty() && !event_list.is_empty()) || event_list.is_full() {
-                return Ok(event_list);
+    /// Send a command to the Controller without waiting for its response.
+    ///
+    /// The command is sent as soon as the Controller is able to accept it, according to the
+    /// Num_HCI_Command_Packets it has last reported, so several commands can be in flight at the
+    /// same time. The response of the command must then be retrieved with
+    /// [`Hci::wait_command_response`]; events received in the meantime are queued and returned
+    /// by [`Hci::wait_for_event`].
+    pub async fn send_command(&mut self, command: Command) -> Result<(), Error> {
+        if self.pending_commands.len() + self.command_responses.len() >= HCI_MAX_PENDING_COMMANDS {
+            return Err(Error::TooManyPendingCommands);
+        }
+        if self.num_hci_command_packets == 0 {
+            let timeout = self.time_source.delay(HCI_COMMAND_TIMEOUT);
+            async {
+                while self.num_hci_command_packets == 0 {
+                    self.read_and_dispatch_packet().await?;
+                }
+                Ok::<_, Error>(())
             }
+            .with_timeout(timeout)
+            .await??;
+        }
 
-            match Self::hci_read_and_parse_packet(&mut self.driver, &mut self.read_buffer).await {
-                Ok((remaining, packet)) => {
-                    match packet {
-                        Packet::Command(_) => {
-                            // The Host is not supposed to receive commands, ignore it!
-                            #[cfg(feature = "defmt")]
-                            defmt::warn!("Received command while waiting for event, ignore it!");
-                        }
-                        Packet::AclData(_data) => {
-                            #[cfg(feature = "defmt")]
-                            defmt::debug!("Received ACL data packet with data: {:?}", _data);
-                            // TODO
-                        }
-                        Packet::Event(event) => {
-                            Self::update_num_hci_command_packets(
-                                &mut self.num_hci_command_packets,
-                                &event,
-                            );
-
-                            // INVARIANT: The event list is known to be able to hold this event,
-                            // otherwise we would have returned at the beginning of the loop.
-                            event_list.push(event).unwrap();
-                        }
-                    }
+        let command_packet = command.encode()?;
+        self.driver.write(command_packet.data()).await?;
+        self.driver
+            .trace(PacketDirection::HostToController, command_packet.data());
+        self.num_hci_command_packets -= 1;
+        // INVARIANT: The number of pending commands has been checked at the beginning.
+        self.pending_commands.push(command.opcode()).unwrap();
+        Ok(())
+    }
 
-                    // INVARIANT: The remaining is known to be shorter than the buffer.
-                    self.read_buffer = remaining.try_into().unwrap();
+    /// Wait for the response of a command previously sent with [`Hci::send_command`].
+    ///
+    /// The response is either a Command Complete event or a Command Status event, depending on
+    /// the command. If several commands with the same opcode are in flight, their responses
+    /// are returned in the order they have been received.
+    pub async fn wait_command_response(&mut self, opcode: CommandOpCode) -> Result<Event, Error> {
+        let timeout = self.time_source.delay(HCI_COMMAND_TIMEOUT);
+        match self
+            .read_command_response(opcode)
+            .with_timeout(timeout)
+            .await
+        {
+            Ok(result) => result,
+            Err(e) => {
+                if let Some(index) = self.pending_commands.iter().position(|op| *op == opcode) {
+                    self.pending_commands.remove(index);
+                    // The Controller will not answer this command anymore, consider its command
+                    // slot free again so that the Host is still able to recover, e.g. by
+                    // resetting it.
+                    self.num_hci_command_packets = self.num_hci_command_packets.saturating_add(1);
                 }
-                Err(e) => {
-                    self.read_buffer.clear();
-                    return Err(e);
+                Err(e.into())
+            }
+        }
+    }
+
+    /// Wait for the next events received from the Controller, the command responses excepted.
+    ///
+    /// It also returns when ISO data has been received, so that it can be retrieved with
+    /// [`Hci::take_iso_data`]. The list of events is then empty if no event has been received
+    /// along with the ISO data. As long as ISO data remains queued, this returns immediately, so
+    /// the ISO data needs to be taken after each call.
+    ///
+    /// If events have been dropped because the event queue was full, this is reported once with
+    /// [`Error::EventQueueOverflow`], the events still queued are returned by the next call. The
+    /// same goes for the ISO data packets dropped because the ISO data queue was full, reported
+    /// with [`Error::IsoDataQueueOverflow`].
+    pub async fn wait_for_event(&mut self) -> Result<EventList<EVENT_LIST_SIZE>, Error> {
+        if core::mem::take(&mut self.event_queue_overflowed) {
+            return Err(Error::EventQueueOverflow);
+        }
+        if core::mem::take(&mut self.iso_data_queue_overflowed) {
+            return Err(Error::IsoDataQueueOverflow);
+        }
+        let mut event_list = EventList::default();
+
+        loop {
+            if (self.read_buffer.is_empty()
+                && (!self.event_queue.is_empty() || !self.iso_data_queue.is_empty()))
+                || self.event_queue.len() >= event_list.capacity()
+            {
+                while !event_list.is_full() {
+                    match self.event_queue.pop_front() {
+                        // INVARIANT: The event list is known not to be full.
+                        Some(event) => event_list.push(event).unwrap(),
+                        None => break,
+                    }
                 }
+                return Ok(event_list);
             }
+
+            self.read_and_dispatch_packet().await?;
         }
     }
 
+    /// Take the oldest ISO data packet received from the Controller, if any.
+    pub fn take_iso_data(&mut self) -> Option<IsoData> {
+        self.iso_data_queue.pop_front()
+    }
+
     async fn cmd_with_command_complete_response_without_parameter(
         &mut self,
         command: Command,
@@ -384,14 +629,9 @@ where
     }
 
     async fn execute_command(&mut self, command: Command) -> Result<Event, Error> {
-        if self.num_hci_command_packets == 0 {
-            self.wait_controller_ready().await?;
-        }
-        let event = self
-            .send_command_and_wait_response(command)
-            .with_timeout(HCI_COMMAND_TIMEOUT)
-            .await??;
-        Ok(event)
+        let opcode = command.opcode();
+        self.send_command(command).await?;
+        self.wait_command_response(opcode).await
     }
 
     async fn execute_command_with_command_complete_response(
@@ -412,132 +652,121 @@ where
         match self.execute_command(command).await? {
             Event::CommandStatus(event) if event.status.is_success() => Ok(()),
             Event::CommandStatus(event) => Err(Error::ErrorCode(event.status)),
-            _ => Err(Error::UnexpectedEvent), // Actually, not reachable
+            _ => Err(Error::UnexpectedEvent),
         }
     }
 
-    async fn send_command_and_wait_response(&mut self, command: Command) -> Result<Event, Error> {
-        let command_packet = command.encode()?;
-        self.driver.write(command_packet.data()).await?;
+    async fn read_command_response(&mut self, opcode: CommandOpCode) -> Result<Event, Error> {
         loop {
+            if let Some(index) = self
+                .command_responses
+                .iter()
+                .position(|event| command_response_opcode(event) == Some(opcode))
+            {
+                return Ok(self.command_responses.remove(index));
+            }
+            if !self.pending_commands.contains(&opcode) {
+                return Err(Error::NoPendingCommand(opcode.into()));
+            }
+
+            self.read_and_dispatch_packet().await?;
+        }
+    }
+
+    async fn read_and_dispatch_packet(&mut self) -> Result<(), Error> {
+        let packet =
             match Self::hci_read_and_parse_packet(&mut self.driver, &mut self.read_buffer).await {
                 Ok((remaining, packet)) => {
-                    let result = match packet {
-                        Packet::Command(_) => {
-                            // The Host is not supposed to receive commands!
-                            Some(Err(Error::InvalidPacket))
-                        }
-                        Packet::AclData(_) => {
-                            todo!()
-                        }
-                        Packet::Event(event) => {
-                            Self::update_num_hci_command_packets(
-                                &mut self.num_hci_command_packets,
-                                &event,
-                            );
-
-                            match &event {
-                                Event::CommandComplete(command_complete_event)
-                                    if command_complete_event.opcode == command.opcode() =>
-                                {
-                                    Some(Ok(event))
-                                }
-                                Event::CommandStatus(command_status_event)
-                                    if command_status_event.opcode == command.opcode() =>
-                                {
-                                    Some(Ok(event))
-                                }
-                                Event::Unsupported(_) => {
-                                    // Ignore unsupported event
-                                    None
-                                }
-                                _ => {
-                                    // Other events will be handled higher in the stack
-                                    if self.event_list.push(event).is_err() {
-                                        #[cfg(feature = "defmt")]
-                                        defmt::warn!("HCI event list is full, cannot add more!");
-                                    }
-                                    None
-                                }
-                            }
-                        }
-                    };
-
                     // INVARIANT: The remaining is known to be shorter than the buffer.
                     self.read_buffer = remaining.try_into().unwrap();
-
-                    if let Some(result) = result {
-                        return result;
-                    }
+                    packet
                 }
                 Err(e) => {
                     self.read_buffer.clear();
                     return Err(e);
                 }
+            };
+
+        match packet {
+            Packet::Command(_) => {
+                // The Host is not supposed to receive commands!
+                return Err(Error::InvalidPacket);
+            }
+            Packet::AclData(_data) => {
+                #[cfg(feature = "defmt")]
+                defmt::debug!("Received ACL data packet with data: {:?}", _data);
+                // TODO
             }
+            Packet::IsoData(data) => {
+                #[cfg(feature = "defmt")]
+                defmt::debug!("Received ISO data packet with data: {:?}", data);
+                if self.iso_data_queue.push_back(data).is_err() {
+                    #[cfg(feature = "defmt")]
+                    defmt::warn!("HCI ISO data queue is full, dropping ISO data!");
+                    self.iso_data_queue_overflowed = true;
+                }
+            }
+            Packet::Event(event) => self.dispatch_event(event),
         }
+        Ok(())
     }
 
-    async fn wait_controller_ready(&mut self) -> Result<(), Error> {
-        while self.num_hci_command_packets == 0 {
-            match Self::hci_read_and_parse_packet(&mut self.driver, &mut self.read_buffer).await {
-                Ok((remaining, packet)) => {
-                    match packet {
-                        Packet::Command(_) => {
-                            // The Host is not supposed to receive commands!
-                            return Err(Error::InvalidPacket);
-                        }
-                        Packet::AclData(_) => {
-                            // The host is not expecting to receive ACL data now!
-                            return Err(Error::InvalidPacket);
-                        }
-                        Packet::Event(event) => {
-                            Self::update_num_hci_command_packets(
-                                &mut self.num_hci_command_packets,
-                                &event,
-                            );
-
-                            // INVARIANT: The remaining is known to be shorter than the buffer.
-                            self.read_buffer = remaining.try_into().unwrap();
-                        }
-                    }
-                }
-                Err(e) => {
-                    self.read_buffer.clear();
-                    return Err(e);
-                }
+    fn dispatch_event(&mut self, event: Event) {
+        let num_hci_command_packets = match &event {
+            Event::CommandComplete(event) => Some(event.num_hci_command_packets),
+            Event::CommandStatus(event) => Some(event.num_hci_command_packets),
+            _ => None,
+        };
+        if let Some(num_hci_command_packets) = num_hci_command_packets {
+            self.num_hci_command_packets = num_hci_command_packets;
+            if let Some(index) = self
+                .pending_commands
+                .iter()
+                .position(|opcode| command_response_opcode(&event) == Some(*opcode))
+            {
+                self.pending_commands.remove(index);
+                // INVARIANT: The responses and the pending commands share the same capacity.
+                self.command_responses.push(event).unwrap();
             }
+            // Otherwise, this only gives command credits (e.g. with the NOP opcode).
+        } else if matches!(event, Event::Unsupported(_)) {
+            // Ignore unsupported event
+        } else if matches!(event, Event::NumberOfCompletedPackets(_)) {
+            // Ignore the ACL data flow control, no ACL data is sent to the Controller yet
+        } else if self.event_queue.push_back(event).is_err() {
+            // Other events will be handled higher in the stack
+            #[cfg(feature = "defmt")]
+            defmt::warn!("HCI event queue is full, dropping event!");
+            self.event_queue_overflowed = true;
         }
-        Ok(())
     }
 
     async fn hci_read_and_parse_packet<'a>(
         driver: &mut H,
-        read_buffer: &'a mut HciBuffer,
-    ) -> Result<(&'a [u8], Packet), Error>
+        read_buffer: &'a mut HciBuffer<READ_BUFFER_SIZE>,
+    ) -> Result<(&'a [u8], Packet<ACL_DATA_SIZE>), Error>
     where
         H: HciDriver,
     {
         if read_buffer.is_empty() {
             read_buffer.read(driver).await?;
         }
+        let data = read_buffer.data();
         let (remaining, hci_packet) =
-            crate::packet::parser::packet(read_buffer.data()).map_err(|_| Error::InvalidPacket)?;
+            crate::packet::parser::sized_packet(data).map_err(|_| Error::InvalidPacket)?;
+        driver.trace(
+            PacketDirection::ControllerToHost,
+            &data[..data.len() - remaining.len()],
+        );
         Ok((remaining, hci_packet))
     }
+}
 
-    fn update_num_hci_command_packets(num_hci_command_packets: &mut u8, event: &Event) {
-        match event {
-            Event::CommandComplete(event) => {
-                *num_hci_command_packets = event.num_hci_command_packets;
-            }
-            Event::CommandStatus(event) => {
-                *num_hci_command_packets = event.num_hci_command_packets;
-            }
-            _ => {
-                // Ignore other events
-            }
-        }
+fn command_response_opcode(event: &Event) -> Option<CommandOpCode> {
+    match event {
+        Event::CommandComplete(event) => Some(event.opcode),
+        Event::CommandStatus(event) => Some(event.opcode),
+        _ => None,
     }
 }
 
@@ -549,14 +778,17 @@ mod test {
     use tokio_test::io::Mock;
 
     use super::*;
+    use crate::capture::{test::MemoryTracer, TracedHciDriver};
     use crate::test::*;
     use crate::{
         connection_event_length_range, connection_interval, latency, supervision_timeout,
-        CentralClockAccuracy, ConnectionHandle, ConnectionIntervalRange, ConnectionPeerAddress,
-        DeviceAddress, DisconnectionCompleteEvent, ErrorCode, HciDriverError,
-        InitiatorFilterPolicy, Latency, LeConnectionCompleteEvent, LeConnectionUpdateCompleteEvent,
-        LeMetaEvent, OwnAddressType, RandomResolvablePrivateAddress, Role, ScanInterval,
-        ScanWindow, SupervisionTimeout,
+        AdvertisingHandle, BigSyncTimeout, CentralClockAccuracy, ConnectionHandle,
+        ConnectionIntervalRange, ConnectionPeerAddress, DeviceAddress, DisconnectionCompleteEvent,
+        ErrorCode, Framing, HciDriverError, InitiatorFilterPolicy, Latency,
+        LeBigSyncEstablishedEvent, LeConnectionCompleteEvent, LeConnectionUpdateCompleteEvent,
+        LeCreateBigCompleteEvent, LeMetaEvent, LeTerminateBigCompleteEvent, OwnAddressType,
+        Packing, Phy, RandomResolvablePrivateAddress, Role, ScanInterval, ScanWindow, SduInterval,
+        SupervisionTimeout, SyncHandle, TokioTimeSource,
     };
 
     fn mock_cmd_disconnect_success() -> Mock {
@@ -598,11 +830,17 @@ mod test {
         #[case] expected_event: Option<Event>,
     ) {
         let hci_driver = TokioHciDriver { hci: mock };
-        let mut hci = Hci {
+        let mut hci: Hci<_> = Hci {
             driver: hci_driver,
+            time_source: TokioTimeSource,
             num_hci_command_packets: 1,
             read_buffer: Default::default(),
-            event_list: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
         };
         assert_eq!(
             hci.cmd_disconnect(
@@ -662,11 +900,17 @@ mod test {
         #[case] expected: Result<(), Error>,
     ) {
         let hci_driver = TokioHciDriver { hci: mock };
-        let mut hci = Hci {
+        let mut hci: Hci<_> = Hci {
             driver: hci_driver,
+            time_source: TokioTimeSource,
             num_hci_command_packets: 1,
             read_buffer: Default::default(),
-            event_list: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
         };
         assert_eq!(
             hci.cmd_le_add_device_to_filter_accept_list(DeviceAddress::Random(
@@ -679,6 +923,158 @@ mod test {
         );
     }
 
+    #[fixture]
+    fn mock_cmd_le_big_create_sync_success() -> Mock {
+        tokio_test::io::Builder::new()
+            .write(&[
+                1, 107, 32, 26, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 200,
+                0, 2, 1, 2,
+            ])
+            .read(&[4, 15, 4, 0, 1, 107, 32])
+            .wait(Duration::from_millis(10))
+            .read(&[
+                4, 62, 19, 29, 0, 1, 32, 78, 0, 4, 1, 0, 2, 100, 0, 8, 0, 2, 48, 0, 49, 0,
+            ])
+            .build()
+    }
+
+    #[fixture]
+    fn mock_cmd_le_big_create_sync_command_disallowed() -> Mock {
+        tokio_test::io::Builder::new()
+            .write(&[
+                1, 107, 32, 26, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 200,
+                0, 2, 1, 2,
+            ])
+            .read(&[4, 15, 4, 12, 1, 107, 32])
+            .build()
+    }
+
+    #[rstest]
+    #[case::success(
+        mock_cmd_le_big_create_sync_success(),
+        Ok(()),
+        Some(Event::LeMeta(LeMetaEvent::LeBigSyncEstablished(LeBigSyncEstablishedEvent {
+            status: ErrorCode::Success,
+            big_handle: BigHandle::try_new(1).unwrap(),
+            transport_latency_big: 20_000,
+            nse: 4,
+            bn: 1,
+            pto: 0,
+            irc: 2,
+            max_pdu: 100,
+            iso_interval: 8,
+            bis_handles: heapless::Vec::from_slice(&[
+                ConnectionHandle::try_new(0x0030).unwrap(),
+                ConnectionHandle::try_new(0x0031).unwrap(),
+            ]).unwrap(),
+        })))
+    )]
+    #[case::command_disallowed(
+        mock_cmd_le_big_create_sync_command_disallowed(),
+        Err(Error::ErrorCode(ErrorCode::CommandDisallowed)),
+        None
+    )]
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_cmd_le_big_create_sync(
+        #[case] mock: Mock,
+        #[case] expected_cmd_result: Result<(), Error>,
+        #[case] expected_event: Option<Event>,
+    ) {
+        let hci_driver = TokioHciDriver { hci: mock };
+        let mut hci: Hci<_> = Hci {
+            driver: hci_driver,
+            time_source: TokioTimeSource,
+            num_hci_command_packets: 1,
+            read_buffer: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
+        };
+        let parameters = BigCreateSyncParameters::try_new(
+            BigHandle::try_new(1).unwrap(),
+            SyncHandle::try_new(1).unwrap(),
+            None,
+            0,
+            BigSyncTimeout::default(),
+            &[1, 2],
+        )
+        .unwrap();
+        assert_eq!(
+            hci.cmd_le_big_create_sync(parameters).await,
+            expected_cmd_result
+        );
+        if expected_event.is_some() {
+            let mut event_list = hci.wait_for_event().await.unwrap();
+            assert_eq!(event_list.len(), 1);
+            assert_eq!(event_list.pop(), expected_event);
+        }
+    }
+
+    #[fixture]
+    fn mock_cmd_le_big_terminate_sync_success() -> Mock {
+        tokio_test::io::Builder::new()
+            .write(&[1, 108, 32, 1, 1])
+            .read(&[4, 14, 5, 1, 108, 32, 0, 1])
+            .build()
+    }
+
+    #[fixture]
+    fn mock_cmd_le_big_terminate_sync_unknown_advertising_identifier() -> Mock {
+        tokio_test::io::Builder::new()
+            .write(&[1, 108, 32, 1, 1])
+            .read(&[4, 14, 5, 1, 108, 32, 0x42, 1])
+            .build()
+    }
+
+    #[fixture]
+    fn mock_cmd_le_big_terminate_sync_invalid_event_packet() -> Mock {
+        tokio_test::io::Builder::new()
+            .write(&[1, 108, 32, 1, 1])
+            .read(&[4, 14, 4, 1, 108, 32, 0])
+            .build()
+    }
+
+    #[rstest]
+    #[case::success(
+        mock_cmd_le_big_terminate_sync_success(),
+        Ok(BigHandle::try_new(1).unwrap())
+    )]
+    #[case::unknown_advertising_identifier(
+        mock_cmd_le_big_terminate_sync_unknown_advertising_identifier(),
+        Err(Error::ErrorCode(ErrorCode::UnknownAdvertisingIdentifier))
+    )]
+    #[case::invalid_event_packet(
+        mock_cmd_le_big_terminate_sync_invalid_event_packet(),
+        Err(Error::InvalidPacket)
+    )]
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_cmd_le_big_terminate_sync(
+        #[case] mock: Mock,
+        #[case] expected: Result<BigHandle, Error>,
+    ) {
+        let hci_driver = TokioHciDriver { hci: mock };
+        let mut hci: Hci<_> = Hci {
+            driver: hci_driver,
+            time_source: TokioTimeSource,
+            num_hci_command_packets: 1,
+            read_buffer: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
+        };
+        assert_eq!(
+            hci.cmd_le_big_terminate_sync(BigHandle::try_new(1).unwrap())
+                .await,
+            expected
+        );
+    }
+
     #[fixture]
     fn mock_cmd_le_clear_filter_accept_list_success() -> Mock {
         tokio_test::io::Builder::new()
@@ -719,11 +1115,17 @@ mod test {
         #[case] expected: Result<(), Error>,
     ) {
         let hci_driver = TokioHciDriver { hci: mock };
-        let mut hci = Hci {
+        let mut hci: Hci<_> = Hci {
             driver: hci_driver,
+            time_source: TokioTimeSource,
             num_hci_command_packets: 1,
             read_buffer: Default::default(),
-            event_list: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
         };
         assert_eq!(hci.cmd_le_clear_filter_accept_list().await, expected);
     }
@@ -774,11 +1176,17 @@ mod test {
         #[case] expected_event: Option<Event>,
     ) {
         let hci_driver = TokioHciDriver { hci: mock };
-        let mut hci = Hci {
+        let mut hci: Hci<_> = Hci {
             driver: hci_driver,
+            time_source: TokioTimeSource,
             num_hci_command_packets: 1,
             read_buffer: Default::default(),
-            event_list: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
         };
         let connection_update_params = ConnectionUpdateParameters::try_new(
             ConnectionHandle::default(),
@@ -799,6 +1207,101 @@ mod test {
         }
     }
 
+    #[fixture]
+    fn mock_cmd_le_create_big_success() -> Mock {
+        tokio_test::io::Builder::new()
+            .write(&[
+                1, 104, 32, 31, 1, 0, 2, 16, 39, 0, 100, 0, 20, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            ])
+            .read(&[4, 15, 4, 0, 1, 104, 32])
+            .wait(Duration::from_millis(10))
+            .read(&[
+                4, 62, 23, 27, 0, 1, 16, 39, 0, 32, 78, 0, 2, 4, 1, 0, 2, 100, 0, 8, 0, 2, 32, 0,
+                33, 0,
+            ])
+            .build()
+    }
+
+    #[fixture]
+    fn mock_cmd_le_create_big_command_disallowed() -> Mock {
+        tokio_test::io::Builder::new()
+            .write(&[
+                1, 104, 32, 31, 1, 0, 2, 16, 39, 0, 100, 0, 20, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            ])
+            .read(&[4, 15, 4, 12, 1, 104, 32])
+            .build()
+    }
+
+    #[rstest]
+    #[case::success(
+        mock_cmd_le_create_big_success(),
+        Ok(()),
+        Some(Event::LeMeta(LeMetaEvent::LeCreateBigComplete(LeCreateBigCompleteEvent {
+            status: ErrorCode::Success,
+            big_handle: BigHandle::try_new(1).unwrap(),
+            big_sync_delay: 10_000,
+            transport_latency_big: 20_000,
+            phy: Phy::Le2M,
+            nse: 4,
+            bn: 1,
+            pto: 0,
+            irc: 2,
+            max_pdu: 100,
+            iso_interval: 8,
+            bis_handles: heapless::Vec::from_slice(&[
+                ConnectionHandle::try_new(0x0020).unwrap(),
+                ConnectionHandle::try_new(0x0021).unwrap(),
+            ]).unwrap(),
+        })))
+    )]
+    #[case::command_disallowed(
+        mock_cmd_le_create_big_command_disallowed(),
+        Err(Error::ErrorCode(ErrorCode::CommandDisallowed)),
+        None
+    )]
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_cmd_le_create_big(
+        #[case] mock: Mock,
+        #[case] expected_cmd_result: Result<(), Error>,
+        #[case] expected_event: Option<Event>,
+    ) {
+        let hci_driver = TokioHciDriver { hci: mock };
+        let mut hci: Hci<_> = Hci {
+            driver: hci_driver,
+            time_source: TokioTimeSource,
+            num_hci_command_packets: 1,
+            read_buffer: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
+        };
+        let parameters = BigParameters::try_new(
+            BigHandle::try_new(1).unwrap(),
+            AdvertisingHandle::try_new(0).unwrap(),
+            2,
+            SduInterval::default(),
+            100,
+            20,
+            2,
+            Phy::Le2M,
+            Packing::Sequential,
+            Framing::Unframed,
+            None,
+        )
+        .unwrap();
+        assert_eq!(hci.cmd_le_create_big(parameters).await, expected_cmd_result);
+        if expected_event.is_some() {
+            let mut event_list = hci.wait_for_event().await.unwrap();
+            assert_eq!(event_list.len(), 1);
+            assert_eq!(event_list.pop(), expected_event);
+        }
+    }
+
     #[fixture]
     fn mock_cmd_le_create_connection_success() -> Mock {
         tokio_test::io::Builder::new()
@@ -852,11 +1355,17 @@ mod test {
         #[case] expected_event: Option<Event>,
     ) {
         let hci_driver = TokioHciDriver { hci: mock };
-        let mut hci = Hci {
+        let mut hci: Hci<_> = Hci {
             driver: hci_driver,
+            time_source: TokioTimeSource,
             num_hci_command_packets: 1,
             read_buffer: Default::default(),
-            event_list: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
         };
         let connection_params = ConnectionParameters::try_new(
             ScanInterval::default(),
@@ -925,11 +1434,17 @@ mod test {
         #[case] expected: Result<(), Error>,
     ) {
         let hci_driver = TokioHciDriver { hci: mock };
-        let mut hci = Hci {
+        let mut hci: Hci<_> = Hci {
             driver: hci_driver,
+            time_source: TokioTimeSource,
             num_hci_command_packets: 1,
             read_buffer: Default::default(),
-            event_list: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
         };
         assert_eq!(hci.cmd_le_create_connection_cancel().await, expected);
     }
@@ -974,11 +1489,17 @@ mod test {
     #[tokio::test(flavor = "current_thread", start_paused = true)]
     async fn test_cmd_le_rand(#[case] mock: Mock, #[case] expected: Result<[u8; 8], Error>) {
         let hci_driver = TokioHciDriver { hci: mock };
-        let mut hci = Hci {
+        let mut hci: Hci<_> = Hci {
             driver: hci_driver,
+            time_source: TokioTimeSource,
             num_hci_command_packets: 1,
             read_buffer: Default::default(),
-            event_list: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
         };
         assert_eq!(hci.cmd_le_rand().await, expected);
     }
@@ -1026,11 +1547,17 @@ mod test {
         #[case] expected: Result<TxPowerLevel, Error>,
     ) {
         let hci_driver = TokioHciDriver { hci: mock };
-        let mut hci = Hci {
+        let mut hci: Hci<_> = Hci {
             driver: hci_driver,
+            time_source: TokioTimeSource,
             num_hci_command_packets: 1,
             read_buffer: Default::default(),
-            event_list: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
         };
         assert_eq!(
             hci.cmd_le_read_advertising_channel_tx_power().await,
@@ -1081,11 +1608,17 @@ mod test {
         #[case] expected: Result<(u16, u16), Error>,
     ) {
         let hci_driver = TokioHciDriver { hci: mock };
-        let mut hci = Hci {
+        let mut hci: Hci<_> = Hci {
             driver: hci_driver,
+            time_source: TokioTimeSource,
             num_hci_command_packets: 1,
             read_buffer: Default::default(),
-            event_list: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
         };
         assert_eq!(hci.cmd_le_read_buffer_size().await, expected);
     }
@@ -1130,11 +1663,17 @@ mod test {
         #[case] expected: Result<usize, Error>,
     ) {
         let hci_driver = TokioHciDriver { hci: mock };
-        let mut hci = Hci {
+        let mut hci: Hci<_> = Hci {
             driver: hci_driver,
+            time_source: TokioTimeSource,
             num_hci_command_packets: 1,
             read_buffer: Default::default(),
-            event_list: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
         };
         assert_eq!(hci.cmd_le_read_filter_accept_list_size().await, expected);
     }
@@ -1182,11 +1721,17 @@ mod test {
         #[case] expected: Result<SupportedLeFeatures, Error>,
     ) {
         let hci_driver = TokioHciDriver { hci: mock };
-        let mut hci = Hci {
+        let mut hci: Hci<_> = Hci {
             driver: hci_driver,
+            time_source: TokioTimeSource,
             num_hci_command_packets: 1,
             read_buffer: Default::default(),
-            event_list: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
         };
         assert_eq!(
             hci.cmd_le_read_local_supported_features_page_0().await,
@@ -1237,11 +1782,17 @@ mod test {
         #[case] expected: Result<SupportedLeStates, Error>,
     ) {
         let hci_driver = TokioHciDriver { hci: mock };
-        let mut hci = Hci {
+        let mut hci: Hci<_> = Hci {
             driver: hci_driver,
+            time_source: TokioTimeSource,
             num_hci_command_packets: 1,
             read_buffer: Default::default(),
-            event_list: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
         };
         assert_eq!(hci.cmd_le_read_supported_states().await, expected);
     }
@@ -1289,11 +1840,17 @@ mod test {
         #[case] expected: Result<(), Error>,
     ) {
         let hci_driver = TokioHciDriver { hci: mock };
-        let mut hci = Hci {
+        let mut hci: Hci<_> = Hci {
             driver: hci_driver,
+            time_source: TokioTimeSource,
             num_hci_command_packets: 1,
             read_buffer: Default::default(),
-            event_list: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
         };
         assert_eq!(
             hci.cmd_le_remove_device_from_filter_accept_list(DeviceAddress::Random(
@@ -1340,11 +1897,17 @@ mod test {
         #[case] expected: Result<(), Error>,
     ) {
         let hci_driver = TokioHciDriver { hci: mock };
-        let mut hci = Hci {
+        let mut hci: Hci<_> = Hci {
             driver: hci_driver,
+            time_source: TokioTimeSource,
             num_hci_command_packets: 1,
             read_buffer: Default::default(),
-            event_list: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
         };
         assert_eq!(
             hci.cmd_le_set_advertising_data(AdvertisingData::default())
@@ -1381,11 +1944,17 @@ mod test {
         #[case] expected: Result<(), Error>,
     ) {
         let hci_driver = TokioHciDriver { hci: mock };
-        let mut hci = Hci {
+        let mut hci: Hci<_> = Hci {
             driver: hci_driver,
+            time_source: TokioTimeSource,
             num_hci_command_packets: 1,
             read_buffer: Default::default(),
-            event_list: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
         };
         assert_eq!(
             hci.cmd_le_set_advertising_enable(AdvertisingEnable::Enabled)
@@ -1422,11 +1991,17 @@ mod test {
         #[case] expected: Result<(), Error>,
     ) {
         let hci_driver = TokioHciDriver { hci: mock };
-        let mut hci = Hci {
+        let mut hci: Hci<_> = Hci {
             driver: hci_driver,
+            time_source: TokioTimeSource,
             num_hci_command_packets: 1,
             read_buffer: Default::default(),
-            event_list: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
         };
         assert_eq!(
             hci.cmd_le_set_advertising_parameters(AdvertisingParameters::default())
@@ -1460,11 +2035,17 @@ mod test {
     #[tokio::test(flavor = "current_thread", start_paused = true)]
     async fn test_cmd_le_set_event_mask(#[case] mock: Mock, #[case] expected: Result<(), Error>) {
         let hci_driver = TokioHciDriver { hci: mock };
-        let mut hci = Hci {
+        let mut hci: Hci<_> = Hci {
             driver: hci_driver,
+            time_source: TokioTimeSource,
             num_hci_command_packets: 1,
             read_buffer: Default::default(),
-            event_list: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
         };
         assert_eq!(
             hci.cmd_le_set_event_mask(LeEventMask::default()).await,
@@ -1515,11 +2096,17 @@ mod test {
         #[case] expected: Result<(), Error>,
     ) {
         let hci_driver = TokioHciDriver { hci: mock };
-        let mut hci = Hci {
+        let mut hci: Hci<_> = Hci {
             driver: hci_driver,
+            time_source: TokioTimeSource,
             num_hci_command_packets: 1,
             read_buffer: Default::default(),
-            event_list: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
         };
         assert_eq!(
             hci.cmd_le_set_random_address(
@@ -1555,11 +2142,17 @@ mod test {
     #[tokio::test(flavor = "current_thread", start_paused = true)]
     async fn test_cmd_le_set_scan_enable(#[case] mock: Mock, #[case] expected: Result<(), Error>) {
         let hci_driver = TokioHciDriver { hci: mock };
-        let mut hci = Hci {
+        let mut hci: Hci<_> = Hci {
             driver: hci_driver,
+            time_source: TokioTimeSource,
             num_hci_command_packets: 1,
             read_buffer: Default::default(),
-            event_list: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
         };
         assert_eq!(
             hci.cmd_le_set_scan_enable(ScanEnable::Enabled, FilterDuplicates::Disabled)
@@ -1596,11 +2189,17 @@ mod test {
         #[case] expected: Result<(), Error>,
     ) {
         let hci_driver = TokioHciDriver { hci: mock };
-        let mut hci = Hci {
+        let mut hci: Hci<_> = Hci {
             driver: hci_driver,
+            time_source: TokioTimeSource,
             num_hci_command_packets: 1,
             read_buffer: Default::default(),
-            event_list: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
         };
         assert_eq!(
             hci.cmd_le_set_scan_parameters(ScanParameters::default())
@@ -1643,11 +2242,17 @@ mod test {
         #[case] expected: Result<(), Error>,
     ) {
         let hci_driver = TokioHciDriver { hci: mock };
-        let mut hci = Hci {
+        let mut hci: Hci<_> = Hci {
             driver: hci_driver,
+            time_source: TokioTimeSource,
             num_hci_command_packets: 1,
             read_buffer: Default::default(),
-            event_list: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
         };
         assert_eq!(
             hci.cmd_le_set_scan_response_data(AdvertisingData::default())
@@ -1656,6 +2261,72 @@ mod test {
         );
     }
 
+    #[fixture]
+    fn mock_cmd_le_terminate_big_success() -> Mock {
+        tokio_test::io::Builder::new()
+            .write(&[1, 106, 32, 2, 1, 19])
+            .read(&[4, 15, 4, 0, 1, 106, 32])
+            .wait(Duration::from_millis(10))
+            .read(&[4, 62, 3, 28, 1, 22])
+            .build()
+    }
+
+    #[fixture]
+    fn mock_cmd_le_terminate_big_unknown_advertising_identifier() -> Mock {
+        tokio_test::io::Builder::new()
+            .write(&[1, 106, 32, 2, 1, 19])
+            .read(&[4, 15, 4, 0x42, 1, 106, 32])
+            .build()
+    }
+
+    #[rstest]
+    #[case::success(
+        mock_cmd_le_terminate_big_success(),
+        Ok(()),
+        Some(Event::LeMeta(LeMetaEvent::LeTerminateBigComplete(LeTerminateBigCompleteEvent {
+            big_handle: BigHandle::try_new(1).unwrap(),
+            reason: ErrorCode::ConnectionTerminatedByLocalHost,
+        })))
+    )]
+    #[case::unknown_advertising_identifier(
+        mock_cmd_le_terminate_big_unknown_advertising_identifier(),
+        Err(Error::ErrorCode(ErrorCode::UnknownAdvertisingIdentifier)),
+        None
+    )]
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_cmd_le_terminate_big(
+        #[case] mock: Mock,
+        #[case] expected_cmd_result: Result<(), Error>,
+        #[case] expected_event: Option<Event>,
+    ) {
+        let hci_driver = TokioHciDriver { hci: mock };
+        let mut hci: Hci<_> = Hci {
+            driver: hci_driver,
+            time_source: TokioTimeSource,
+            num_hci_command_packets: 1,
+            read_buffer: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
+        };
+        assert_eq!(
+            hci.cmd_le_terminate_big(
+                BigHandle::try_new(1).unwrap(),
+                Reason::RemoteUserTerminatedConnection
+            )
+            .await,
+            expected_cmd_result
+        );
+        if expected_event.is_some() {
+            let mut event_list = hci.wait_for_event().await.unwrap();
+            assert_eq!(event_list.len(), 1);
+            assert_eq!(event_list.pop(), expected_event);
+        }
+    }
+
     #[fixture]
     fn mock_cmd_read_bd_addr_success() -> Mock {
         tokio_test::io::Builder::new()
@@ -1699,11 +2370,17 @@ mod test {
         #[case] expected: Result<PublicDeviceAddress, Error>,
     ) {
         let hci_driver = TokioHciDriver { hci: mock };
-        let mut hci = Hci {
+        let mut hci: Hci<_> = Hci {
             driver: hci_driver,
+            time_source: TokioTimeSource,
             num_hci_command_packets: 1,
             read_buffer: Default::default(),
-            event_list: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
         };
         assert_eq!(hci.cmd_read_bd_addr().await, expected);
     }
@@ -1756,11 +2433,17 @@ mod test {
         #[case] expected: Result<(NonZeroU16, NonZeroU8, NonZeroU16, u16), Error>,
     ) {
         let hci_driver = TokioHciDriver { hci: mock };
-        let mut hci = Hci {
+        let mut hci: Hci<_> = Hci {
             driver: hci_driver,
+            time_source: TokioTimeSource,
             num_hci_command_packets: 1,
             read_buffer: Default::default(),
-            event_list: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
         };
         assert_eq!(hci.cmd_read_buffer_size().await, expected);
     }
@@ -1812,11 +2495,17 @@ mod test {
         #[case] expected: Result<SupportedCommands, Error>,
     ) {
         let hci_driver = TokioHciDriver { hci: mock };
-        let mut hci = Hci {
+        let mut hci: Hci<_> = Hci {
             driver: hci_driver,
+            time_source: TokioTimeSource,
             num_hci_command_packets: 1,
             read_buffer: Default::default(),
-            event_list: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
         };
         assert_eq!(hci.cmd_read_local_supported_commands().await, expected);
     }
@@ -1864,11 +2553,17 @@ mod test {
         #[case] expected: Result<SupportedFeatures, Error>,
     ) {
         let hci_driver = TokioHciDriver { hci: mock };
-        let mut hci = Hci {
+        let mut hci: Hci<_> = Hci {
             driver: hci_driver,
+            time_source: TokioTimeSource,
             num_hci_command_packets: 1,
             read_buffer: Default::default(),
-            event_list: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
         };
         assert_eq!(hci.cmd_read_local_supported_features().await, expected);
     }
@@ -1959,6 +2654,15 @@ mod test {
             .build()
     }
 
+    fn mock_cmd_reset_receive_acl_data() -> Mock {
+        tokio_test::io::Builder::new()
+            .read(&[4, 14, 3, 1, 0, 0])
+            .write(&[1, 3, 12, 0])
+            .read(&[2, 1, 32, 2, 0, 170, 187])
+            .read(&[4, 14, 4, 1, 3, 12, 0])
+            .build()
+    }
+
     #[rstest]
     #[case::success(mock_cmd_reset_success(), Ok(()))]
     #[case::hardware_failure(
@@ -1997,10 +2701,13 @@ mod test {
         mock_cmd_reset_receive_unhandled_event(),
         Ok(())
     )]
+    #[case::receive_acl_data(mock_cmd_reset_receive_acl_data(), Ok(()))]
     #[tokio::test(flavor = "current_thread", start_paused = true)]
     async fn test_cmd_reset(#[case] mock: Mock, #[case] expected: Result<(), Error>) {
         let hci_driver = TokioHciDriver { hci: mock };
         let mut hci = Hci::new(hci_driver);
+        // Wait for the Controller to report that it is ready with a NOP Command Complete.
+        hci.num_hci_command_packets = 0;
         assert_eq!(hci.cmd_reset().await, expected);
     }
 
@@ -2029,11 +2736,17 @@ mod test {
     #[tokio::test(flavor = "current_thread", start_paused = true)]
     async fn test_cmd_set_event_mask(#[case] mock: Mock, #[case] expected: Result<(), Error>) {
         let hci_driver = TokioHciDriver { hci: mock };
-        let mut hci = Hci {
+        let mut hci: Hci<_> = Hci {
             driver: hci_driver,
+            time_source: TokioTimeSource,
             num_hci_command_packets: 1,
             read_buffer: Default::default(),
-            event_list: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
         };
         assert_eq!(
             hci.cmd_set_event_mask(EventMask::HARDWARE_ERROR | EventMask::DATA_BUFFER_OVERFLOW)
@@ -2041,4 +2754,408 @@ mod test {
             expected
         );
     }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_send_iso_data() {
+        let mock = tokio_test::io::Builder::new()
+            .write(&[5, 32, 32, 7, 0, 2, 1, 3, 0, 0xAA, 0xBB, 0xCC])
+            .build();
+        let hci_driver = TokioHciDriver { hci: mock };
+        let mut hci: Hci<_> = Hci {
+            driver: hci_driver,
+            time_source: TokioTimeSource,
+            num_hci_command_packets: 1,
+            read_buffer: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
+        };
+        let iso_data = IsoData::try_new_complete_sdu(
+            ConnectionHandle::try_new(0x0020).unwrap(),
+            None,
+            0x0102,
+            &[0xAA, 0xBB, 0xCC],
+        )
+        .unwrap();
+        assert_eq!(hci.send_iso_data(&iso_data).await, Ok(()));
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_receive_iso_data() {
+        let mock = tokio_test::io::Builder::new()
+            .read(&[5, 32, 32, 7, 0, 2, 1, 3, 0, 0xAA, 0xBB, 0xCC])
+            .read(&[4, 5, 4, 0, 0, 0, 22])
+            .build();
+        let hci_driver = TokioHciDriver { hci: mock };
+        let mut hci = Hci::new(hci_driver);
+        assert!(hci.take_iso_data().is_none());
+
+        assert!(hci.wait_for_event().await.unwrap().is_empty());
+        let iso_data = IsoData::try_new_complete_sdu(
+            ConnectionHandle::try_new(0x0020).unwrap(),
+            None,
+            0x0102,
+            &[0xAA, 0xBB, 0xCC],
+        )
+        .unwrap();
+        assert_eq!(hci.take_iso_data(), Some(iso_data));
+        assert!(hci.take_iso_data().is_none());
+
+        assert_eq!(hci.wait_for_event().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_command_pipelining() {
+        let mock = tokio_test::io::Builder::new()
+            .write(&[1, 3, 12, 0])
+            .write(&[1, 1, 32, 8, 31, 0, 0, 0, 0, 0, 0, 0])
+            .read(&[4, 14, 4, 1, 1, 32, 0])
+            .read(&[4, 5, 4, 0, 0, 0, 22])
+            .read(&[4, 14, 4, 2, 3, 12, 0])
+            .build();
+        let hci_driver = TokioHciDriver { hci: mock };
+        let mut hci: Hci<_> = Hci {
+            driver: hci_driver,
+            time_source: TokioTimeSource,
+            num_hci_command_packets: 2,
+            read_buffer: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
+        };
+        assert_eq!(hci.send_command(Command::Reset).await, Ok(()));
+        assert_eq!(
+            hci.send_command(Command::LeSetEventMask(LeEventMask::default()))
+                .await,
+            Ok(())
+        );
+        assert_eq!(hci.num_hci_command_packets, 0);
+
+        let response = hci.wait_command_response(CommandOpCode::Reset).await;
+        assert!(
+            matches!(response, Ok(Event::CommandComplete(event)) if event.opcode == CommandOpCode::Reset)
+        );
+        assert_eq!(hci.num_hci_command_packets, 2);
+        let response = hci
+            .wait_command_response(CommandOpCode::LeSetEventMask)
+            .await;
+        assert!(
+            matches!(response, Ok(Event::CommandComplete(event)) if event.opcode == CommandOpCode::LeSetEventMask)
+        );
+        assert_eq!(
+            hci.wait_command_response(CommandOpCode::Reset).await,
+            Err(Error::NoPendingCommand(0x0C03))
+        );
+
+        let mut event_list = hci.wait_for_event().await.unwrap();
+        assert_eq!(event_list.len(), 1);
+        assert!(matches!(
+            event_list.pop(),
+            Some(Event::DisconnectionComplete(_))
+        ));
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_first_command_sent_without_waiting_for_credits() {
+        let mock = tokio_test::io::Builder::new()
+            .write(&[1, 3, 12, 0])
+            .read(&[4, 14, 4, 1, 3, 12, 0])
+            .build();
+        let hci_driver = TokioHciDriver { hci: mock };
+        let mut hci = Hci::new(hci_driver);
+        assert_eq!(hci.cmd_reset().await, Ok(()));
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_command_waiting_for_credits() {
+        let mock = tokio_test::io::Builder::new()
+            .read(&[4, 5, 4, 0, 0, 0, 22])
+            .read(&[4, 14, 3, 1, 0, 0])
+            .write(&[1, 3, 12, 0])
+            .read(&[4, 14, 4, 1, 3, 12, 0])
+            .build();
+        let hci_driver = TokioHciDriver { hci: mock };
+        let mut hci = Hci::new(hci_driver);
+        hci.num_hci_command_packets = 0;
+        assert_eq!(hci.cmd_reset().await, Ok(()));
+        let event_list = hci.wait_for_event().await.unwrap();
+        assert_eq!(event_list.len(), 1);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_command_waiting_for_credits_timeout() {
+        let mock = tokio_test::io::Builder::new()
+            .read(&[4, 5, 4, 0, 0, 0, 22])
+            .wait(Duration::from_secs(2))
+            .build();
+        let hci_driver = TokioHciDriver { hci: mock };
+        let mut hci = Hci::new(hci_driver);
+        hci.num_hci_command_packets = 0;
+        let start = tokio::time::Instant::now();
+        assert_eq!(
+            hci.cmd_reset().await,
+            Err(Error::HciDriver(HciDriverError::Timeout))
+        );
+        assert_eq!(start.elapsed(), HCI_COMMAND_TIMEOUT);
+        assert!(hci.pending_commands.is_empty());
+        assert_eq!(hci.wait_for_event().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_too_many_pending_commands() {
+        let mock = tokio_test::io::Builder::new()
+            .write(&[1, 3, 12, 0])
+            .write(&[1, 3, 12, 0])
+            .write(&[1, 3, 12, 0])
+            .write(&[1, 3, 12, 0])
+            .build();
+        let hci_driver = TokioHciDriver { hci: mock };
+        let mut hci: Hci<_> = Hci {
+            driver: hci_driver,
+            time_source: TokioTimeSource,
+            num_hci_command_packets: 8,
+            read_buffer: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
+        };
+        for _ in 0..HCI_MAX_PENDING_COMMANDS {
+            assert_eq!(hci.send_command(Command::Reset).await, Ok(()));
+        }
+        assert_eq!(
+            hci.send_command(Command::Reset).await,
+            Err(Error::TooManyPendingCommands)
+        );
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_command_timeout_frees_command_slot() {
+        let mock = tokio_test::io::Builder::new()
+            .write(&[1, 3, 12, 0])
+            .wait(Duration::from_secs(2))
+            .build();
+        let hci_driver = TokioHciDriver { hci: mock };
+        let mut hci: Hci<_> = Hci {
+            driver: hci_driver,
+            time_source: TokioTimeSource,
+            num_hci_command_packets: 1,
+            read_buffer: Default::default(),
+            pending_commands: Default::default(),
+            command_responses: Default::default(),
+            event_queue: Default::default(),
+            event_queue_overflowed: false,
+            iso_data_queue: Default::default(),
+            iso_data_queue_overflowed: false,
+        };
+        assert_eq!(
+            hci.cmd_reset().await,
+            Err(Error::HciDriver(HciDriverError::Timeout))
+        );
+        assert_eq!(hci.num_hci_command_packets, 1);
+        assert!(hci.pending_commands.is_empty());
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_reset_state() {
+        let mock = tokio_test::io::Builder::new()
+            .write(&[1, 3, 12, 0])
+            .read(&[4, 5, 4, 0, 0, 0, 22])
+            .write(&[1, 3, 12, 0])
+            .read(&[4, 14, 4, 1, 3, 12, 0])
+            .build();
+        let hci_driver = TokioHciDriver { hci: mock };
+        let mut hci = Hci::new(hci_driver);
+        hci.send_command(Command::Reset).await.unwrap();
+        hci.read_and_dispatch_packet().await.unwrap();
+        assert_eq!(hci.pending_commands.len(), 1);
+        assert_eq!(hci.event_queue.len(), 1);
+
+        hci.reset_state();
+        assert_eq!(hci.num_hci_command_packets, 1);
+        assert!(hci.pending_commands.is_empty());
+        assert!(hci.event_queue.is_empty());
+        assert_eq!(hci.cmd_reset().await, Ok(()));
+    }
+
+    #[derive(Debug, Default)]
+    struct ElapsedTimeSource;
+
+    impl TimeSource for ElapsedTimeSource {
+        type Instant = ();
+        type Delay = core::future::Ready<()>;
+
+        fn now(&self) -> Self::Instant {}
+
+        fn elapsed(&self, _instant: Self::Instant) -> Duration {
+            Duration::MAX
+        }
+
+        fn delay(&self, _duration: Duration) -> Self::Delay {
+            core::future::ready(())
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_command_timeout_with_custom_time_source() {
+        let mock = tokio_test::io::Builder::new()
+            .write(&[1, 3, 12, 0])
+            .write(&[1, 3, 12, 0])
+            .wait(Duration::from_millis(10))
+            .read(&[4, 14, 4, 1, 3, 12, 0])
+            .build();
+        let hci_driver = TokioHciDriver { hci: mock };
+        let mut hci = Hci::with_time_source(hci_driver, ElapsedTimeSource);
+        hci.num_hci_command_packets = 2;
+        let start = tokio::time::Instant::now();
+        assert_eq!(
+            hci.cmd_reset().await,
+            Err(Error::HciDriver(HciDriverError::Timeout))
+        );
+        assert_eq!(start.elapsed(), Duration::ZERO);
+
+        let mut hci = Hci::with_time_source(hci.driver, TokioTimeSource);
+        assert_eq!(hci.cmd_reset().await, Ok(()));
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_event_queue_larger_than_event_list() {
+        let mut builder = tokio_test::io::Builder::new();
+        builder.write(&[1, 3, 12, 0]);
+        for handle in 0..6 {
+            builder.read(&[4, 5, 4, 0, handle, 0, 22]);
+        }
+        builder.read(&[4, 14, 4, 1, 3, 12, 0]);
+        let hci_driver = TokioHciDriver {
+            hci: builder.build(),
+        };
+        let mut hci: Hci<_, _, 6> = Hci::with_event_queue(hci_driver, TokioTimeSource);
+        assert_eq!(hci.cmd_reset().await, Ok(()));
+        assert_eq!(hci.wait_for_event().await.unwrap().len(), 4);
+        assert_eq!(hci.wait_for_event().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_event_queue_overflow() {
+        let mut builder = tokio_test::io::Builder::new();
+        builder.write(&[1, 3, 12, 0]);
+        for handle in 0..3 {
+            builder.read(&[4, 5, 4, 0, handle, 0, 22]);
+        }
+        builder.read(&[4, 14, 4, 1, 3, 12, 0]);
+        let hci_driver = TokioHciDriver {
+            hci: builder.build(),
+        };
+        let mut hci: Hci<_, _, 2> = Hci::with_event_queue(hci_driver, TokioTimeSource);
+        assert_eq!(hci.cmd_reset().await, Ok(()));
+        assert_eq!(hci.wait_for_event().await, Err(Error::EventQueueOverflow));
+        assert_eq!(hci.wait_for_event().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_iso_data_queue_overflow() {
+        let mock = tokio_test::io::Builder::new()
+            .read(&[
+                5, 32, 32, 7, 0, 2, 1, 3, 0, 0xAA, 0xBB, 0xCC, 5, 32, 32, 7, 0, 3, 1, 3, 0, 0xDD,
+                0xEE, 0xFF,
+            ])
+            .read(&[4, 5, 4, 0, 0, 0, 22])
+            .build();
+        let hci_driver = TokioHciDriver { hci: mock };
+        let mut hci: Hci<
+            _,
+            _,
+            HCI_DEFAULT_EVENT_QUEUE_SIZE,
+            HCI_MAX_READ_BUFFER_SIZE,
+            EVENT_LIST_NB_EVENTS,
+            ACL_DATA_MAX_SIZE,
+            1,
+        > = Hci::with_event_queue(hci_driver, TokioTimeSource);
+        assert!(hci.wait_for_event().await.unwrap().is_empty());
+        assert_eq!(
+            hci.take_iso_data()
+                .map(|iso_data| iso_data.packet_sequence_number()),
+            Some(Some(0x0102))
+        );
+        assert!(hci.take_iso_data().is_none());
+        assert_eq!(hci.wait_for_event().await, Err(Error::IsoDataQueueOverflow));
+        assert_eq!(hci.wait_for_event().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_custom_event_list_size() {
+        let mut builder = tokio_test::io::Builder::new();
+        builder.write(&[1, 3, 12, 0]);
+        for handle in 0..6 {
+            builder.read(&[4, 5, 4, 0, handle, 0, 22]);
+        }
+        builder.read(&[4, 14, 4, 1, 3, 12, 0]);
+        let hci_driver = TokioHciDriver {
+            hci: builder.build(),
+        };
+        let mut hci: Hci<_, _, 8, HCI_MAX_READ_BUFFER_SIZE, 8> =
+            Hci::with_event_queue(hci_driver, TokioTimeSource);
+        assert_eq!(hci.cmd_reset().await, Ok(()));
+        assert_eq!(hci.wait_for_event().await.unwrap().len(), 6);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_large_acl_data_packet() {
+        let mut acl_data_packet = [0; 105];
+        acl_data_packet[..5].copy_from_slice(&[2, 0, 32, 100, 0]);
+        let mock = tokio_test::io::Builder::new()
+            .read(&acl_data_packet)
+            .build();
+        let mut hci: Hci<_> = Hci::new(TokioHciDriver { hci: mock });
+        assert_eq!(hci.wait_for_event().await, Err(Error::InvalidPacket));
+
+        let mock = tokio_test::io::Builder::new()
+            .read(&acl_data_packet)
+            .read(&[4, 5, 4, 0, 0, 0, 22])
+            .build();
+        let mut hci: Hci<_, _, 8, HCI_MAX_READ_BUFFER_SIZE, 4, 251> =
+            Hci::with_event_queue(TokioHciDriver { hci: mock }, TokioTimeSource);
+        assert_eq!(hci.wait_for_event().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_traced_hci_driver() {
+        let mock = tokio_test::io::Builder::new()
+            .write(&[1, 3, 12, 0])
+            .read(&[4, 14, 4, 1, 3, 12, 0, 4, 5, 4, 0, 0, 0, 22])
+            .build();
+        let mut tracer = MemoryTracer::default();
+        let mut hci = Hci::new(TracedHciDriver::new(
+            TokioHciDriver { hci: mock },
+            &mut tracer,
+        ));
+        assert_eq!(hci.cmd_reset().await, Ok(()));
+        assert!(hci.wait_for_event().await.is_ok());
+        assert_eq!(
+            tracer
+                .packets
+                .iter()
+                .map(|(direction, packet)| (*direction, packet.as_slice()))
+                .collect::<heapless::Vec<_, 3>>(),
+            &[
+                (PacketDirection::HostToController, &[1, 3, 12, 0][..]),
+                (
+                    PacketDirection::ControllerToHost,
+                    &[4, 14, 4, 1, 3, 12, 0][..]
+                ),
+                (
+                    PacketDirection::ControllerToHost,
+                    &[4, 5, 4, 0, 0, 0, 22][..]
+                ),
+            ]
+        );
+    }
 }
@@ -2,15 +2,17 @@ use bletio_utils::{Buffer, BufferOps, Error as UtilsError};
 
 use crate::{HciDriver, HciDriverError};
 
-const HCI_MAX_READ_BUFFER_SIZE: usize = 259;
+/// Default size of the buffer in which the packets from the Controller are read, big enough to
+/// hold an event with the maximum parameters length.
+pub const HCI_MAX_READ_BUFFER_SIZE: usize = 259;
 
 #[derive(Debug, Clone, Default)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub(crate) struct HciBuffer {
-    buffer: Buffer<HCI_MAX_READ_BUFFER_SIZE>,
+pub(crate) struct HciBuffer<const SIZE: usize = HCI_MAX_READ_BUFFER_SIZE> {
+    buffer: Buffer<SIZE>,
 }
 
-impl HciBuffer {
+impl<const SIZE: usize> HciBuffer<SIZE> {
     pub(crate) fn clear(&mut self) {
         self.buffer.clear();
     }
@@ -38,7 +40,7 @@ impl HciBuffer {
     }
 }
 
-impl TryFrom<&[u8]> for HciBuffer {
+impl<const SIZE: usize> TryFrom<&[u8]> for HciBuffer<SIZE> {
     type Error = UtilsError;
 
     fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
@@ -72,4 +74,26 @@ mod test {
 
         Ok(())
     }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_hci_buffer_custom_size() -> Result<(), bletio_utils::Error> {
+        let read_data: &[u8] = &[0x01, 0x02, 0x03, 0x04, 0x05];
+
+        let mock = tokio_test::io::Builder::new()
+            .read(&read_data[..3])
+            .read(&read_data[3..])
+            .build();
+        let mut hci_driver = TokioHciDriver { hci: mock };
+
+        let mut buffer: HciBuffer<3> = Default::default();
+        assert_eq!(buffer.read(&mut hci_driver).await.unwrap(), 3);
+        assert_eq!(buffer.data(), &read_data[..3]);
+        assert!(HciBuffer::<3>::try_from(read_data).is_err());
+
+        buffer.clear();
+        assert_eq!(buffer.read(&mut hci_driver).await.unwrap(), 2);
+        assert_eq!(buffer.data(), &read_data[3..]);
+
+        Ok(())
+    }
 }
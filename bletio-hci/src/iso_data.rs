@@ -0,0 +1,350 @@
+use bletio_utils::{Buffer, BufferOps, EncodeToBuffer, Error as UtilsError};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+use crate::{ConnectionHandle, Error};
+
+// Packet Type (1) + Header (4) + Time_Stamp (4) + Packet_Sequence_Number (2) + ISO_SDU_Length (2)
+// + ISO SDU fragment, so that an ISO data packet always fits in the HCI read buffer.
+const ISO_DATA_MAX_SIZE: usize = 246;
+
+const ISO_DATA_TIMESTAMP_FLAG: u16 = 1 << 14;
+
+/// Packet boundary flag of an ISO data packet.
+///
+/// See [Core Specification 6.0, Vol. 4, Part E, 5.4.5](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host-controller-interface/host-controller-interface-functional-specification.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoPrimitive, TryFromPrimitive)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[num_enum(error_type(name = Error, constructor = Error::InvalidIsoPacketBoundaryFlag))]
+#[repr(u8)]
+#[non_exhaustive]
+pub enum IsoPacketBoundaryFlag {
+    /// The ISO data load contains the first fragment of a fragmented SDU.
+    FirstFragment = 0b00,
+    /// The ISO data load contains a continuation fragment of an SDU.
+    ContinuationFragment = 0b01,
+    /// The ISO data load contains a complete SDU.
+    CompleteSdu = 0b10,
+    /// The ISO data load contains the last fragment of an SDU.
+    LastFragment = 0b11,
+}
+
+impl IsoPacketBoundaryFlag {
+    const fn has_sdu_header(&self) -> bool {
+        matches!(self, Self::FirstFragment | Self::CompleteSdu)
+    }
+}
+
+/// Packet status flag of an ISO data packet, only meaningful for data received from the Controller.
+///
+/// See [Core Specification 6.0, Vol. 4, Part E, 5.4.5](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host-controller-interface/host-controller-interface-functional-specification.html).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, IntoPrimitive, TryFromPrimitive)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[num_enum(error_type(name = Error, constructor = Error::InvalidIsoPacketStatusFlag))]
+#[repr(u8)]
+#[non_exhaustive]
+pub enum IsoPacketStatusFlag {
+    /// Valid data. The complete SDU was received correctly.
+    #[default]
+    ValidData = 0b00,
+    /// Possibly invalid data. The contents of the SDU may contain errors or part of the SDU may
+    /// be missing.
+    PossiblyInvalidData = 0b01,
+    /// Part(s) of the SDU were not received correctly.
+    LostData = 0b10,
+}
+
+/// ISO data packet.
+///
+/// See [Core Specification 6.0, Vol. 4, Part E, 5.4.5](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host-controller-interface/host-controller-interface-functional-specification.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct IsoData {
+    handle: ConnectionHandle,
+    packet_boundary_flag: IsoPacketBoundaryFlag,
+    timestamp: Option<u32>,
+    packet_sequence_number: u16,
+    iso_sdu_length: u16,
+    packet_status_flag: IsoPacketStatusFlag,
+    data: Buffer<ISO_DATA_MAX_SIZE>,
+}
+
+impl IsoData {
+    /// Create an ISO data packet.
+    ///
+    /// The packet sequence number, the ISO SDU length and the packet status flag are only
+    /// sent when the packet boundary flag indicates a first fragment or a complete SDU.
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_new(
+        handle: ConnectionHandle,
+        packet_boundary_flag: IsoPacketBoundaryFlag,
+        timestamp: Option<u32>,
+        packet_sequence_number: u16,
+        iso_sdu_length: u16,
+        packet_status_flag: IsoPacketStatusFlag,
+        data: &[u8],
+    ) -> Result<Self, Error> {
+        if iso_sdu_length > 0x0FFF {
+            return Err(Error::InvalidMaxSdu(iso_sdu_length));
+        }
+        let mut s = Self {
+            handle,
+            packet_boundary_flag,
+            timestamp,
+            packet_sequence_number,
+            iso_sdu_length,
+            packet_status_flag,
+            data: Buffer::default(),
+        };
+        s.data
+            .copy_from_slice(data)
+            .map_err(|_| Error::DataWillNotFitIsoDataPacket)?;
+        Ok(s)
+    }
+
+    /// Create an ISO data packet containing a complete SDU.
+    pub fn try_new_complete_sdu(
+        handle: ConnectionHandle,
+        timestamp: Option<u32>,
+        packet_sequence_number: u16,
+        sdu: &[u8],
+    ) -> Result<Self, Error> {
+        Self::try_new(
+            handle,
+            IsoPacketBoundaryFlag::CompleteSdu,
+            timestamp,
+            packet_sequence_number,
+            sdu.len() as u16,
+            IsoPacketStatusFlag::ValidData,
+            sdu,
+        )
+    }
+
+    pub fn handle(&self) -> ConnectionHandle {
+        self.handle
+    }
+
+    pub fn packet_boundary_flag(&self) -> IsoPacketBoundaryFlag {
+        self.packet_boundary_flag
+    }
+
+    pub fn timestamp(&self) -> Option<u32> {
+        self.timestamp
+    }
+
+    pub fn packet_sequence_number(&self) -> Option<u16> {
+        self.packet_boundary_flag
+            .has_sdu_header()
+            .then_some(self.packet_sequence_number)
+    }
+
+    pub fn iso_sdu_length(&self) -> Option<u16> {
+        self.packet_boundary_flag
+            .has_sdu_header()
+            .then_some(self.iso_sdu_length)
+    }
+
+    pub fn packet_status_flag(&self) -> IsoPacketStatusFlag {
+        self.packet_status_flag
+    }
+
+    pub fn data(&self) -> &[u8] {
+        self.data.data()
+    }
+
+    fn data_load_length(&self) -> usize {
+        let mut len = self.data.len();
+        if self.timestamp.is_some() {
+            len += size_of::<u32>();
+        }
+        if self.packet_boundary_flag.has_sdu_header() {
+            len += 2 * size_of::<u16>();
+        }
+        len
+    }
+}
+
+impl EncodeToBuffer for IsoData {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, UtilsError> {
+        let mut handle_and_flags =
+            self.handle.value() | ((u8::from(self.packet_boundary_flag) as u16) << 12);
+        if self.timestamp.is_some() {
+            handle_and_flags |= ISO_DATA_TIMESTAMP_FLAG;
+        }
+        buffer.encode_le_u16(handle_and_flags)?;
+        buffer.encode_le_u16(self.data_load_length() as u16)?;
+        if let Some(timestamp) = self.timestamp {
+            buffer.encode_le_u32(timestamp)?;
+        }
+        if self.packet_boundary_flag.has_sdu_header() {
+            buffer.encode_le_u16(self.packet_sequence_number)?;
+            buffer.encode_le_u16(
+                self.iso_sdu_length | ((u8::from(self.packet_status_flag) as u16) << 14),
+            )?;
+        }
+        buffer.copy_from_slice(self.data.data())?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        2 * size_of::<u16>() + self.data_load_length()
+    }
+}
+
+pub(crate) mod parser {
+    use nom::{
+        bytes::take,
+        combinator::{cond, map_res},
+        number::complete::{le_u16, le_u32},
+        IResult, Parser,
+    };
+
+    use super::*;
+    use crate::packet::Packet;
+
+    fn connection_handle_and_flags(
+        input: &[u8],
+    ) -> IResult<&[u8], (ConnectionHandle, IsoPacketBoundaryFlag, bool)> {
+        map_res(le_u16, |v| {
+            let connection_handle = ConnectionHandle::try_new(v & 0x0FFF)?;
+            let packet_boundary_flag: IsoPacketBoundaryFlag =
+                (((v >> 12) & 0b0011) as u8).try_into()?;
+            let timestamp_flag = (v & ISO_DATA_TIMESTAMP_FLAG) != 0;
+            Ok::<_, Error>((connection_handle, packet_boundary_flag, timestamp_flag))
+        })
+        .parse(input)
+    }
+
+    fn data_load_length(input: &[u8]) -> IResult<&[u8], u16> {
+        map_res(le_u16, |v| Ok::<_, Error>(v & 0x3FFF)).parse(input)
+    }
+
+    fn iso_sdu_length_and_packet_status_flag(
+        input: &[u8],
+    ) -> IResult<&[u8], (u16, IsoPacketStatusFlag)> {
+        map_res(le_u16, |v| {
+            let packet_status_flag: IsoPacketStatusFlag = ((v >> 14) as u8).try_into()?;
+            Ok::<_, Error>((v & 0x0FFF, packet_status_flag))
+        })
+        .parse(input)
+    }
+
+    pub(crate) fn iso_data<const ACL_DATA_SIZE: usize>(
+        input: &[u8],
+    ) -> IResult<&[u8], Packet<ACL_DATA_SIZE>> {
+        let (rest, ((connection_handle, packet_boundary_flag, timestamp_flag), data_load_length)) =
+            (connection_handle_and_flags, data_load_length).parse(input)?;
+        let (rest, data_load) = take(data_load_length).parse(rest)?;
+        let (data, (timestamp, sdu_header)) = (
+            cond(timestamp_flag, le_u32),
+            cond(
+                packet_boundary_flag.has_sdu_header(),
+                (le_u16, iso_sdu_length_and_packet_status_flag),
+            ),
+        )
+            .parse(data_load)?;
+        let (packet_sequence_number, (iso_sdu_length, packet_status_flag)) =
+            sdu_header.unwrap_or_default();
+        let iso_data = IsoData::try_new(
+            connection_handle,
+            packet_boundary_flag,
+            timestamp,
+            packet_sequence_number,
+            iso_sdu_length,
+            packet_status_flag,
+            data,
+        )
+        .map_err(|_| {
+            nom::Err::Failure(nom::error::Error::new(data, nom::error::ErrorKind::Fail))
+        })?;
+        Ok((rest, Packet::IsoData(iso_data)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::packet::parser::packet;
+    use crate::Packet;
+
+    #[rstest]
+    #[case::complete_sdu_without_timestamp(
+        IsoData::try_new_complete_sdu(ConnectionHandle::try_new(0x0020).unwrap(), None, 0x0102, &[0xAA, 0xBB, 0xCC]).unwrap(),
+        &[0x05, 0x20, 0x20, 0x07, 0x00, 0x02, 0x01, 0x03, 0x00, 0xAA, 0xBB, 0xCC]
+    )]
+    #[case::complete_sdu_with_timestamp(
+        IsoData::try_new_complete_sdu(ConnectionHandle::try_new(0x0021).unwrap(), Some(0x12345678), 0x0003, &[0x01, 0x02]).unwrap(),
+        &[0x05, 0x21, 0x60, 0x0A, 0x00, 0x78, 0x56, 0x34, 0x12, 0x03, 0x00, 0x02, 0x00, 0x01, 0x02]
+    )]
+    #[case::continuation_fragment(
+        IsoData::try_new(
+            ConnectionHandle::try_new(0x0020).unwrap(), IsoPacketBoundaryFlag::ContinuationFragment,
+            None, 0, 0, IsoPacketStatusFlag::ValidData, &[0x10, 0x11]
+        ).unwrap(),
+        &[0x05, 0x20, 0x10, 0x02, 0x00, 0x10, 0x11]
+    )]
+    #[case::first_fragment_lost_data(
+        IsoData::try_new(
+            ConnectionHandle::try_new(0x0020).unwrap(), IsoPacketBoundaryFlag::FirstFragment,
+            None, 0x0010, 0x0004, IsoPacketStatusFlag::LostData, &[0x10, 0x11]
+        ).unwrap(),
+        &[0x05, 0x20, 0x00, 0x06, 0x00, 0x10, 0x00, 0x04, 0x80, 0x10, 0x11]
+    )]
+    fn test_iso_data_encoding_and_parsing(#[case] iso_data: IsoData, #[case] input: &[u8]) {
+        let mut buffer = Buffer::<259>::default();
+        buffer.try_push(0x05).unwrap();
+        let len = iso_data.encode(&mut buffer).unwrap();
+        assert_eq!(len, iso_data.encoded_size());
+        assert_eq!(buffer.data(), input);
+        assert_eq!(packet(input), Ok((&[] as &[u8], Packet::IsoData(iso_data))));
+    }
+
+    #[test]
+    fn test_iso_data_getters() {
+        let handle = ConnectionHandle::try_new(0x0020).unwrap();
+        let iso_data = IsoData::try_new_complete_sdu(handle, Some(10), 5, &[0x01]).unwrap();
+        assert_eq!(iso_data.handle(), handle);
+        assert_eq!(
+            iso_data.packet_boundary_flag(),
+            IsoPacketBoundaryFlag::CompleteSdu
+        );
+        assert_eq!(iso_data.timestamp(), Some(10));
+        assert_eq!(iso_data.packet_sequence_number(), Some(5));
+        assert_eq!(iso_data.iso_sdu_length(), Some(1));
+        assert_eq!(
+            iso_data.packet_status_flag(),
+            IsoPacketStatusFlag::ValidData
+        );
+        assert_eq!(iso_data.data(), &[0x01]);
+
+        let iso_data = IsoData::try_new(
+            handle,
+            IsoPacketBoundaryFlag::LastFragment,
+            None,
+            5,
+            1,
+            IsoPacketStatusFlag::ValidData,
+            &[0x01],
+        )
+        .unwrap();
+        assert_eq!(iso_data.packet_sequence_number(), None);
+        assert_eq!(iso_data.iso_sdu_length(), None);
+    }
+
+    #[test]
+    fn test_iso_data_too_big() {
+        let handle = ConnectionHandle::try_new(0x0020).unwrap();
+        assert_eq!(
+            IsoData::try_new_complete_sdu(handle, None, 0, &[0u8; 247]),
+            Err(Error::DataWillNotFitIsoDataPacket)
+        );
+    }
+
+    #[rstest]
+    #[case::invalid_packet_status_flag(&[0x05, 0x20, 0x20, 0x05, 0x00, 0x02, 0x01, 0x01, 0xC0, 0xAA])]
+    #[case::missing_data(&[0x05, 0x20, 0x20, 0x07, 0x00, 0x02, 0x01, 0x03, 0x00, 0xAA])]
+    fn test_iso_data_parsing_failure(#[case] input: &[u8]) {
+        assert!(packet(input).is_err());
+    }
+}
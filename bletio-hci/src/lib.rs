@@ -1,9 +1,29 @@
+//! HCI handling for the bletio BLE stack.
+//!
+//! # Features
+//!
+//! - `tokio` (default): tokio time source, used as [`DefaultTimeSource`].
+//! - `embassy`: embassy time source, used as [`DefaultTimeSource`] unless `tokio` is also enabled.
+//! - `std-time`: standard library time source, usable with any executor.
+//! - `std`: standard library drivers and captures, including the Linux HCI user channel driver,
+//!   implies `std-time` and `tokio`.
+//! - `embedded-io-async`: serial port adapter for the `embedded-io-async` traits.
+//! - `defmt`: `defmt::Format` implementations.
+//!
+//! Both `tokio` and `embassy` can be enabled at the same time, e.g. through the unification of
+//! the features of several dependencies, in which case tokio is preferred wherever a default
+//! is picked. The embassy time source can still be given explicitly, e.g. with
+//! [`Hci::with_time_source`].
+
 #![no_std]
 
+pub mod capture;
 pub mod common;
+pub mod transport;
 
 mod acl_data;
 mod advertising;
+mod big;
 mod command;
 mod connection;
 mod error;
@@ -11,33 +31,38 @@ mod error_code;
 mod event;
 mod hci;
 mod hci_buffer;
+mod iso_data;
 mod packet;
 mod scanning;
 mod traits;
 
-#[cfg(all(feature = "embassy", feature = "tokio"))]
-compile_error!("feature \"embassy\" and feature \"tokio\" cannot be enabled at the same time");
-
 #[cfg(feature = "embassy")]
-mod timeout_embassy;
+mod time_embassy;
+#[cfg(feature = "std-time")]
+mod time_std;
 #[cfg(feature = "tokio")]
-mod timeout_tokio;
+mod time_tokio;
 
-pub(crate) use command::{Command, CommandOpCode};
 pub(crate) use common::peer_address_type::PeerAddressType;
-pub(crate) use event::command_complete::EventParameter;
 pub(crate) use hci_buffer::HciBuffer;
-pub(crate) use packet::{Packet, PacketType};
+pub(crate) use packet::PacketType;
 
-pub use acl_data::{AclData, BroadcastFlag, PacketBoundaryFlag};
+pub use acl_data::{AclData, BroadcastFlag, PacketBoundaryFlag, ACL_DATA_MAX_SIZE};
 pub use advertising::{
     advertising_data::AdvertisingData,
     advertising_enable::AdvertisingEnable,
+    advertising_handle::AdvertisingHandle,
     advertising_parameters::{
         advertising_interval_range, AdvertisingChannelMap, AdvertisingFilterPolicy,
         AdvertisingInterval, AdvertisingIntervalRange, AdvertisingParameters, AdvertisingType,
     },
 };
+pub use big::{
+    big_create_sync_parameters::{BigCreateSyncParameters, BigSyncTimeout},
+    big_handle::BigHandle,
+    big_parameters::{BigParameters, BroadcastCode, Framing, Packing, SduInterval},
+};
+pub use command::{Command, CommandOpCode};
 pub use common::{
     device_address::{
         DeviceAddress, PublicDeviceAddress, RandomAddress, RandomNonResolvablePrivateAddress,
@@ -48,6 +73,7 @@ pub use common::{
     le_filter_accept_list_address::LeFilterAcceptListAddress,
     le_states::{LeCombinedState, LeSingleState, LeState},
     own_address_type::OwnAddressType,
+    phy::Phy,
     rssi::Rssi,
     supported_commands::SupportedCommands,
     supported_features::SupportedFeatures,
@@ -73,32 +99,80 @@ pub use connection::{
 pub use error::Error;
 pub use error_code::ErrorCode;
 pub use event::{
-    command_complete::CommandCompleteEvent,
+    command_complete::{
+        BdAddrEventParameter, BigHandleEventParameter, BufferSizeEventParameter,
+        CommandCompleteEvent, EventParameter, FilterAcceptListSizeEventParameter,
+        LeBufferSizeEventParameter, RandomNumberEventParameter, SupportedCommandsEventParameter,
+        SupportedFeaturesEventParameter, SupportedLeFeaturesEventParameter,
+        SupportedLeStatesEventParameter, TxPowerLevelEventParameter,
+    },
+    command_status::CommandStatusEvent,
     disconnection_complete::DisconnectionCompleteEvent,
+    hardware_error::HardwareErrorEvent,
     le_advertising_report::{
         LeAdvertisingReport, LeAdvertisingReportData, LeAdvertisingReportEventType,
         LeAdvertisingReportList,
     },
+    le_big_sync_established::LeBigSyncEstablishedEvent,
+    le_big_sync_lost::LeBigSyncLostEvent,
+    le_biginfo_advertising_report::LeBiginfoAdvertisingReportEvent,
     le_connection_complete::{CentralClockAccuracy, LeConnectionCompleteEvent, Role},
     le_connection_update_complete::LeConnectionUpdateCompleteEvent,
+    le_create_big_complete::LeCreateBigCompleteEvent,
     le_meta::LeMetaEvent,
-    Event, EventList,
+    le_terminate_big_complete::LeTerminateBigCompleteEvent,
+    number_of_completed_packets::{CompletedPackets, NumberOfCompletedPacketsEvent},
+    Event, EventList, EVENT_LIST_NB_EVENTS,
+};
+pub use hci::{
+    DefaultTimeSource, Hci, HCI_DEFAULT_EVENT_QUEUE_SIZE, HCI_DEFAULT_ISO_DATA_QUEUE_SIZE,
 };
-pub use hci::Hci;
+pub use hci_buffer::HCI_MAX_READ_BUFFER_SIZE;
+pub use iso_data::{IsoData, IsoPacketBoundaryFlag, IsoPacketStatusFlag};
+pub use packet::Packet;
 pub use scanning::{
     scan_enable::{FilterDuplicates, ScanEnable},
     scan_interval::{scan_interval, ScanInterval},
     scan_parameters::{ScanParameters, ScanType, ScanningFilterPolicy},
     scan_window::{scan_window, ScanWindow},
+    sync_handle::SyncHandle,
+};
+#[cfg(feature = "embassy")]
+pub use time_embassy::EmbassyTimeSource;
+#[cfg(feature = "std-time")]
+pub use time_std::{StdDelay, StdTimeSource};
+#[cfg(feature = "tokio")]
+pub use time_tokio::TokioTimeSource;
+pub use traits::{HciDriver, HciDriverError, TimeSource, WithTimeout};
+#[cfg(feature = "embedded-io-async")]
+pub use transport::EmbeddedIoSerialPort;
+#[cfg(all(feature = "std", target_os = "linux"))]
+pub use transport::HciUserChannelDriver;
+#[cfg(feature = "std")]
+pub use transport::StreamHciDriver;
+#[cfg(feature = "tokio")]
+pub use transport::TokioSerialPort;
+pub use transport::{
+    h4::H4Transport,
+    h5::{H5Config, H5Transport},
+    SerialPort,
 };
-pub use traits::{HciDriver, HciDriverError, WithTimeout};
 
 #[cfg(test)]
 mod test {
+    use bletio_utils::{Buffer, BufferOps, EncodeToBuffer};
     use tokio::io::{AsyncReadExt, AsyncWriteExt};
 
     use super::*;
 
+    /// Encode an event as a whole HCI event packet, as it is sent by a controller.
+    pub(crate) fn event_packet(event: &Event) -> Buffer<259> {
+        let mut buffer = Buffer::default();
+        buffer.try_push(PacketType::Event as u8).unwrap();
+        event.encode(&mut buffer).unwrap();
+        buffer
+    }
+
     pub(crate) struct TokioHciDriver<H>
     where
         H: tokio::io::AsyncRead + tokio::io::AsyncWrite,
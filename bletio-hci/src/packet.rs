@@ -1,6 +1,7 @@
+use bletio_utils::{BufferOps, EncodeToBuffer};
 use num_enum::TryFromPrimitive;
 
-use crate::{AclData, Command, Error, Event};
+use crate::{AclData, Command, Error, Event, IsoData, ACL_DATA_MAX_SIZE};
 
 /// HCI packet type.
 ///
@@ -22,21 +23,73 @@ pub(crate) enum PacketType {
     IsoData = 0x05,
 }
 
+/// HCI packet, as exchanged between the Host and the Controller.
+///
+/// The ACL data packets are able to hold up to `ACL_DATA_SIZE` bytes of data.
 #[derive(Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[allow(clippy::large_enum_variant)]
-pub(crate) enum Packet {
+pub enum Packet<const ACL_DATA_SIZE: usize = ACL_DATA_MAX_SIZE> {
     Command(Command),
-    AclData(AclData),
+    AclData(AclData<ACL_DATA_SIZE>),
     Event(Event),
+    IsoData(IsoData),
+}
+
+impl Packet {
+    /// Parse an HCI packet preceded by its HCI packet indicator, as transmitted on an H4 UART
+    /// transport, returning the remaining bytes along with the packet.
+    pub fn parse(input: &[u8]) -> Result<(&[u8], Self), Error> {
+        parser::packet(input).map_err(|_| Error::InvalidPacket)
+    }
+}
+
+/// Encode the packet preceded by its HCI packet indicator, as transmitted on an H4 UART
+/// transport.
+impl<const ACL_DATA_SIZE: usize> EncodeToBuffer for Packet<ACL_DATA_SIZE> {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        match self {
+            Self::Command(command) => {
+                let command_packet = command
+                    .encode()
+                    .map_err(|_| bletio_utils::Error::CannotEncode)?;
+                buffer.copy_from_slice(command_packet.data())?;
+            }
+            Self::AclData(acl_data) => {
+                buffer.try_push(PacketType::AclData as u8)?;
+                acl_data.encode(buffer)?;
+            }
+            Self::Event(event) => {
+                buffer.try_push(PacketType::Event as u8)?;
+                event.encode(buffer)?;
+            }
+            Self::IsoData(iso_data) => {
+                buffer.try_push(PacketType::IsoData as u8)?;
+                iso_data.encode(buffer)?;
+            }
+        }
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        match self {
+            Self::Command(command) => command
+                .encode()
+                .map(|command_packet| command_packet.data().len())
+                .unwrap_or_default(),
+            Self::AclData(acl_data) => size_of::<u8>() + acl_data.encoded_size(),
+            Self::Event(event) => size_of::<u8>() + event.encoded_size(),
+            Self::IsoData(iso_data) => size_of::<u8>() + iso_data.encoded_size(),
+        }
+    }
 }
 
 pub(crate) mod parser {
     use nom::{combinator::map_res, number::complete::le_u8, IResult, Parser};
 
     use crate::{
-        acl_data::parser::acl_data, command::parser::command, event::parser::event, Packet,
-        PacketType,
+        acl_data::parser::acl_data, command::parser::command, event::parser::event,
+        iso_data::parser::iso_data, Packet, PacketType,
     };
 
     pub(crate) fn parameter_total_length(input: &[u8]) -> IResult<&[u8], u8> {
@@ -44,6 +97,12 @@ pub(crate) mod parser {
     }
 
     pub(crate) fn packet(input: &[u8]) -> IResult<&[u8], Packet> {
+        sized_packet(input)
+    }
+
+    pub(crate) fn sized_packet<const ACL_DATA_SIZE: usize>(
+        input: &[u8],
+    ) -> IResult<&[u8], Packet<ACL_DATA_SIZE>> {
         let (input, packet_type) = map_res(le_u8, PacketType::try_from).parse(input)?;
         match packet_type {
             PacketType::Command => command.parse(input),
@@ -56,19 +115,14 @@ pub(crate) mod parser {
                 )))
             }
             PacketType::Event => event.parse(input),
-            PacketType::IsoData => {
-                // TODO: Handle ISO data parsing.
-                Err(nom::Err::Failure(nom::error::Error::new(
-                    input,
-                    nom::error::ErrorKind::Fail,
-                )))
-            }
+            PacketType::IsoData => iso_data.parse(input),
         }
     }
 }
 
 #[cfg(test)]
 mod test {
+    use bletio_utils::Buffer;
     use claims::assert_err;
     use rstest::rstest;
 
@@ -87,6 +141,21 @@ mod test {
         assert!(matches!(err, Err(Error::InvalidPacketType(_))));
     }
 
+    #[rstest]
+    #[case::command(&[0x01, 0x03, 0x0C, 0x00])]
+    #[case::command_with_parameters(&[0x01, 0x0C, 0x20, 0x02, 0x01, 0x00])]
+    #[case::acl_data(&[0x02, 0x01, 0x20, 0x03, 0x00, 0x01, 0x02, 0x03])]
+    #[case::event(&[0x04, 0x0E, 0x04, 0x01, 0x03, 0x0C, 0x00])]
+    #[case::iso_data(&[0x05, 0x01, 0x20, 0x07, 0x00, 0x01, 0x00, 0x03, 0x00, 0x01, 0x02, 0x03])]
+    fn test_packet_encoding(#[case] input: &[u8]) {
+        let (rest, packet) = Packet::parse(input).unwrap();
+        assert!(rest.is_empty());
+        let mut buffer = Buffer::<32>::default();
+        assert_eq!(packet.encoded_size(), input.len());
+        assert_eq!(packet.encode(&mut buffer), Ok(input.len()));
+        assert_eq!(buffer.data(), input);
+    }
+
     #[rstest]
     #[case(&[0x03])]
     #[case(&[0x05])]
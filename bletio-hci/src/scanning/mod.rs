@@ -3,3 +3,4 @@ pub mod scan_window;
 
 pub(crate) mod scan_enable;
 pub(crate) mod scan_parameters;
+pub(crate) mod sync_handle;
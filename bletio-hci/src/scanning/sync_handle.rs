@@ -0,0 +1,87 @@
+use bletio_utils::{BufferOps, EncodeToBuffer};
+
+use crate::Error;
+
+/// Identifier of a periodic advertising train the Controller is synchronized to.
+///
+/// Range: 0x0000 to 0x0EFF
+///
+/// See [Core Specification 6.0, Vol.4, Part E, 7.7.65.14](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host-controller-interface/host-controller-interface-functional-specification.html).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SyncHandle {
+    value: u16,
+}
+
+impl SyncHandle {
+    pub const fn try_new(value: u16) -> Result<Self, Error> {
+        if value <= 0x0EFF {
+            Ok(Self { value })
+        } else {
+            Err(Error::InvalidSyncHandle(value))
+        }
+    }
+
+    pub const fn value(&self) -> u16 {
+        self.value
+    }
+}
+
+impl TryFrom<u16> for SyncHandle {
+    type Error = Error;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        Self::try_new(value)
+    }
+}
+
+impl EncodeToBuffer for SyncHandle {
+    fn encode<B: BufferOps>(&self, buffer: &mut B) -> Result<usize, bletio_utils::Error> {
+        buffer.encode_le_u16(self.value)
+    }
+
+    fn encoded_size(&self) -> usize {
+        size_of::<u16>()
+    }
+}
+
+pub(crate) mod parser {
+    use nom::{combinator::map_res, number::complete::le_u16, IResult, Parser};
+
+    use super::*;
+
+    pub(crate) fn sync_handle(input: &[u8]) -> IResult<&[u8], SyncHandle> {
+        map_res(le_u16, TryInto::try_into).parse(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bletio_utils::Buffer;
+    use rstest::rstest;
+
+    use super::{parser::*, *};
+
+    #[rstest]
+    #[case(0x0000, &[0x00, 0x00])]
+    #[case(0x0102, &[0x02, 0x01])]
+    #[case(0x0EFF, &[0xFF, 0x0E])]
+    fn test_sync_handle_success(#[case] input: u16, #[case] encoded_data: &[u8]) {
+        let handle = SyncHandle::try_new(input).unwrap();
+        assert_eq!(handle.value(), input);
+        let mut buffer = Buffer::<2>::default();
+        assert_eq!(handle.encoded_size(), encoded_data.len());
+        handle.encode(&mut buffer).unwrap();
+        assert_eq!(buffer.data(), encoded_data);
+        assert_eq!(sync_handle(encoded_data), Ok((&[] as &[u8], handle)));
+    }
+
+    #[rstest]
+    #[case(0x0F00)]
+    #[case(0xFFFF)]
+    fn test_sync_handle_failure(#[case] input: u16) {
+        let err = SyncHandle::try_new(input);
+        assert_eq!(err, Err(Error::InvalidSyncHandle(input)));
+        assert!(sync_handle(&input.to_le_bytes()).is_err());
+    }
+}
@@ -0,0 +1,33 @@
+use core::time::Duration;
+
+use embassy_time::{Instant, Timer};
+
+use crate::TimeSource;
+
+/// Time source based on the embassy timers.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct EmbassyTimeSource;
+
+impl TimeSource for EmbassyTimeSource {
+    type Instant = Instant;
+    type Delay = Timer;
+
+    fn now(&self) -> Self::Instant {
+        Instant::now()
+    }
+
+    fn elapsed(&self, instant: Self::Instant) -> Duration {
+        Duration::from_micros(
+            Instant::now()
+                .saturating_duration_since(instant)
+                .as_micros(),
+        )
+    }
+
+    fn delay(&self, duration: Duration) -> Self::Delay {
+        Timer::after(embassy_time::Duration::from_micros(
+            duration.as_micros() as u64
+        ))
+    }
+}
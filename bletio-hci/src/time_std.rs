@@ -0,0 +1,184 @@
+extern crate std;
+
+use core::future::Future;
+use core::pin::Pin;
+use core::sync::atomic::{AtomicU64, Ordering};
+use core::task::{Context, Poll, Waker};
+use core::time::Duration;
+use std::sync::{Condvar, Mutex, Once};
+use std::time::Instant;
+use std::vec::Vec;
+
+use crate::TimeSource;
+
+/// Time source based on the standard library threads, usable with any executor.
+///
+/// The pending delays are handled by a single timer thread, started with the first delay,
+/// sleeping until the earliest deadline and then waking the tasks waiting for the elapsed
+/// delays.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct StdTimeSource;
+
+impl TimeSource for StdTimeSource {
+    type Instant = Instant;
+    type Delay = StdDelay;
+
+    fn now(&self) -> Self::Instant {
+        Instant::now()
+    }
+
+    fn elapsed(&self, instant: Self::Instant) -> Duration {
+        Instant::now().saturating_duration_since(instant)
+    }
+
+    fn delay(&self, duration: Duration) -> Self::Delay {
+        StdDelay {
+            deadline: Instant::now() + duration,
+            id: None,
+        }
+    }
+}
+
+/// Delay created by [`StdTimeSource`].
+#[derive(Debug)]
+pub struct StdDelay {
+    deadline: Instant,
+    // Identifier of the delay in the timer, once it has been registered.
+    id: Option<u64>,
+}
+
+impl Future for StdDelay {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if Instant::now() >= self.deadline {
+            return Poll::Ready(());
+        }
+        let id = *self
+            .id
+            .get_or_insert_with(|| NEXT_DELAY_ID.fetch_add(1, Ordering::Relaxed));
+        TIMER.register(id, self.deadline, cx.waker());
+        Poll::Pending
+    }
+}
+
+impl Drop for StdDelay {
+    fn drop(&mut self) {
+        if let Some(id) = self.id {
+            TIMER.unregister(id);
+        }
+    }
+}
+
+static NEXT_DELAY_ID: AtomicU64 = AtomicU64::new(0);
+static TIMER: Timer = Timer {
+    entries: Mutex::new(Vec::new()),
+    condvar: Condvar::new(),
+    thread: Once::new(),
+};
+
+/// Timer shared by all the [`StdDelay`]s.
+struct Timer {
+    entries: Mutex<Vec<TimerEntry>>,
+    // Notified when a delay is registered, as it may expire before the ones already waited for.
+    condvar: Condvar,
+    thread: Once,
+}
+
+struct TimerEntry {
+    id: u64,
+    deadline: Instant,
+    waker: Waker,
+}
+
+impl Timer {
+    fn register(&'static self, id: u64, deadline: Instant, waker: &Waker) {
+        self.thread.call_once(|| {
+            std::thread::spawn(|| self.run());
+        });
+        let mut entries = self.entries.lock().unwrap();
+        match entries.iter_mut().find(|entry| entry.id == id) {
+            Some(entry) => entry.waker.clone_from(waker),
+            None => {
+                entries.push(TimerEntry {
+                    id,
+                    deadline,
+                    waker: waker.clone(),
+                });
+                self.condvar.notify_one();
+            }
+        }
+    }
+
+    fn unregister(&self, id: u64) {
+        self.entries.lock().unwrap().retain(|entry| entry.id != id);
+    }
+
+    fn run(&self) {
+        let mut entries = self.entries.lock().unwrap();
+        loop {
+            let now = Instant::now();
+            entries.retain(|entry| {
+                let expired = entry.deadline <= now;
+                if expired {
+                    entry.waker.wake_by_ref();
+                }
+                !expired
+            });
+            entries = match entries.iter().map(|entry| entry.deadline).min() {
+                Some(deadline) => {
+                    self.condvar
+                        .wait_timeout(entries, deadline - now)
+                        .unwrap()
+                        .0
+                }
+                None => self.condvar.wait(entries).unwrap(),
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{HciDriverError, WithTimeout};
+
+    use super::*;
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_std_delay() {
+        let start = Instant::now();
+        StdTimeSource.delay(Duration::from_millis(20)).await;
+        assert!(start.elapsed() >= Duration::from_millis(20));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_with_std_timeout_triggered() {
+        let err = core::future::pending::<()>()
+            .with_timeout(StdTimeSource.delay(Duration::from_millis(20)))
+            .await;
+        assert!(matches!(err, Err(HciDriverError::Timeout)));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_std_delays_share_the_timer() {
+        let start = Instant::now();
+        let long = StdTimeSource.delay(Duration::from_millis(60));
+        // A delay dropped before completing does not prevent the other ones from completing.
+        let _ = core::future::pending::<()>()
+            .with_timeout(StdTimeSource.delay(Duration::from_millis(10)))
+            .await;
+        StdTimeSource.delay(Duration::from_millis(20)).await;
+        assert!(start.elapsed() < Duration::from_millis(60));
+        long.await;
+        assert!(start.elapsed() >= Duration::from_millis(60));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_std_elapsed() {
+        let start = StdTimeSource.now();
+        StdTimeSource.delay(Duration::from_millis(20)).await;
+        assert!(StdTimeSource.elapsed(start) >= Duration::from_millis(20));
+        let future = start + Duration::from_secs(60);
+        assert_eq!(StdTimeSource.elapsed(future), Duration::ZERO);
+    }
+}
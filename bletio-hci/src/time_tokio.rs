@@ -0,0 +1,60 @@
+use core::time::Duration;
+
+use tokio::time::{sleep, Instant, Sleep};
+
+use crate::TimeSource;
+
+/// Time source based on the tokio timers.
+///
+/// When the tokio time is paused, e.g. in tests, the timeouts follow the virtual tokio clock.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TokioTimeSource;
+
+impl TimeSource for TokioTimeSource {
+    type Instant = Instant;
+    type Delay = Sleep;
+
+    fn now(&self) -> Self::Instant {
+        Instant::now()
+    }
+
+    fn elapsed(&self, instant: Self::Instant) -> Duration {
+        Instant::now().saturating_duration_since(instant)
+    }
+
+    fn delay(&self, duration: Duration) -> Self::Delay {
+        sleep(duration)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{HciDriverError, WithTimeout};
+
+    use super::*;
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_with_timeout_not_triggered() {
+        assert!(sleep(Duration::from_millis(500))
+            .with_timeout(TokioTimeSource.delay(Duration::from_millis(1000)))
+            .await
+            .is_ok())
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_with_timeout_triggered() {
+        let err = sleep(Duration::from_millis(1000))
+            .with_timeout(TokioTimeSource.delay(Duration::from_millis(500)))
+            .await;
+        assert!(matches!(err, Err(HciDriverError::Timeout)));
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_elapsed() {
+        let start = TokioTimeSource.now();
+        TokioTimeSource.delay(Duration::from_millis(300)).await;
+        assert_eq!(TokioTimeSource.elapsed(start), Duration::from_millis(300));
+        let future = start + Duration::from_secs(1);
+        assert_eq!(TokioTimeSource.elapsed(future), Duration::ZERO);
+    }
+}
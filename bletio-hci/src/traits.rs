@@ -1,6 +1,10 @@
-use core::future::Future;
+use core::future::{poll_fn, Future};
+use core::pin::pin;
+use core::task::Poll;
 use core::time::Duration;
 
+use crate::capture::PacketDirection;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum HciDriverError {
@@ -12,13 +16,104 @@ pub enum HciDriverError {
 pub trait HciDriver {
     fn read(&mut self, buf: &mut [u8]) -> impl Future<Output = Result<usize, HciDriverError>>;
     fn write(&mut self, buf: &[u8]) -> impl Future<Output = Result<usize, HciDriverError>>;
+
+    /// Called with every complete HCI packet that has been written or read, including its HCI
+    /// packet indicator.
+    ///
+    /// Does nothing by default, see [`TracedHciDriver`](crate::capture::TracedHciDriver) to
+    /// capture the HCI traffic.
+    fn trace(&mut self, _direction: PacketDirection, _packet: &[u8]) {}
 }
 
-pub trait WithTimeout {
-    type Output;
+impl<H> HciDriver for &mut H
+where
+    H: HciDriver,
+{
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, HciDriverError> {
+        (**self).read(buf).await
+    }
+
+    async fn write(&mut self, buf: &[u8]) -> Result<usize, HciDriverError> {
+        (**self).write(buf).await
+    }
+
+    fn trace(&mut self, direction: PacketDirection, packet: &[u8]) {
+        (**self).trace(direction, packet);
+    }
+}
 
-    fn with_timeout(
+/// Source of time used by the HCI and its transports to apply timeouts, and by the host to
+/// measure how long ago something happened.
+///
+/// Implementations are provided for tokio (`TokioTimeSource`), embassy (`EmbassyTimeSource`)
+/// and the standard library threads (`StdTimeSource`) with the corresponding features. It can
+/// also be implemented to use the timer service of an RTOS or a virtual clock in tests.
+pub trait TimeSource {
+    /// Point in time, as measured by the time source.
+    type Instant: Copy;
+    /// Future completing once a delay has elapsed.
+    type Delay: Future<Output = ()>;
+
+    /// Get the current point in time.
+    fn now(&self) -> Self::Instant;
+
+    /// Get the time elapsed since `instant`, or zero if it is in the future.
+    fn elapsed(&self, instant: Self::Instant) -> Duration;
+
+    /// Create a future completing after `duration`.
+    fn delay(&self, duration: Duration) -> Self::Delay;
+}
+
+impl<T> TimeSource for &T
+where
+    T: TimeSource,
+{
+    type Instant = T::Instant;
+    type Delay = T::Delay;
+
+    fn now(&self) -> Self::Instant {
+        (**self).now()
+    }
+
+    fn elapsed(&self, instant: Self::Instant) -> Duration {
+        (**self).elapsed(instant)
+    }
+
+    fn delay(&self, duration: Duration) -> Self::Delay {
+        (**self).delay(duration)
+    }
+}
+
+pub trait WithTimeout: Future + Sized {
+    /// Wait for the future to complete, failing with [`HciDriverError::Timeout`] if `delay`
+    /// completes first.
+    fn with_timeout<D>(
         self,
-        timeout_duration: Duration,
-    ) -> impl Future<Output = Result<Self::Output, HciDriverError>>;
+        delay: D,
+    ) -> impl Future<Output = Result<Self::Output, HciDriverError>>
+    where
+        D: Future<Output = ()>;
+}
+
+impl<F> WithTimeout for F
+where
+    F: Future,
+{
+    async fn with_timeout<D>(self, delay: D) -> Result<Self::Output, HciDriverError>
+    where
+        D: Future<Output = ()>,
+    {
+        let mut future = pin!(self);
+        let mut delay = pin!(delay);
+        poll_fn(|cx| {
+            if let Poll::Ready(output) = future.as_mut().poll(cx) {
+                return Poll::Ready(Ok(output));
+            }
+            delay
+                .as_mut()
+                .poll(cx)
+                .map(|()| Err(HciDriverError::Timeout))
+        })
+        .await
+    }
 }
@@ -0,0 +1,348 @@
+use crate::{HciDriver, HciDriverError, PacketType, SerialPort, HCI_MAX_READ_BUFFER_SIZE};
+
+// Packet Type (1) + ACL/ISO data header (4)
+const H4_HEADER_MAX_SIZE: usize = 5;
+
+/// HCI UART Transport Layer, also known as H4.
+///
+/// Each HCI packet is preceded by its packet indicator. The packets are read one at a time, using
+/// the length field of their header. When garbage is received on the serial port, the bytes are
+/// discarded one by one until a valid packet header is found. Command packets are only sent by the
+/// Host, so they are considered as garbage when read.
+///
+/// The packet being received is kept in a buffer of `BUFFER_SIZE` bytes, so that reading is
+/// cancellation-safe: when a read is dropped in the middle of a packet, the next one resumes it.
+/// A valid packet that does not fit in this buffer or in the buffer given to the read is skipped.
+///
+/// See [Core Specification 6.0, Vol.4, Part A](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host-controller-interface/uart-transport-layer.html).
+#[derive(Debug)]
+pub struct H4Transport<S, const BUFFER_SIZE: usize = HCI_MAX_READ_BUFFER_SIZE>
+where
+    S: SerialPort,
+{
+    serial_port: S,
+    pending: [u8; BUFFER_SIZE],
+    pending_len: usize,
+    skip_len: usize,
+}
+
+impl<S, const BUFFER_SIZE: usize> H4Transport<S, BUFFER_SIZE>
+where
+    S: SerialPort,
+{
+    pub fn new(serial_port: S) -> Self {
+        const { assert!(BUFFER_SIZE >= H4_HEADER_MAX_SIZE) };
+        Self {
+            serial_port,
+            pending: [0; BUFFER_SIZE],
+            pending_len: 0,
+            skip_len: 0,
+        }
+    }
+
+    pub fn into_inner(self) -> S {
+        self.serial_port
+    }
+
+    fn discard_first_pending_byte(&mut self) {
+        #[cfg(feature = "defmt")]
+        defmt::debug!("H4: discarding byte {:x}", self.pending[0]);
+        self.consume_pending(1);
+    }
+
+    fn consume_pending(&mut self, len: usize) {
+        self.pending.copy_within(len..self.pending_len, 0);
+        self.pending_len -= len;
+    }
+
+    fn skip_packet(&mut self, packet_len: usize) {
+        #[cfg(feature = "defmt")]
+        defmt::warn!(
+            "H4: skipping packet of {} bytes that is too long",
+            packet_len
+        );
+        if packet_len <= self.pending_len {
+            self.consume_pending(packet_len);
+        } else {
+            self.skip_len = packet_len - self.pending_len;
+            self.pending_len = 0;
+        }
+    }
+
+    // Read until the first `len` bytes of the packet are pending. The pending length is updated
+    // after each read, so no byte is lost if the future is dropped.
+    async fn fill_pending(&mut self, len: usize) -> Result<(), HciDriverError> {
+        while self.pending_len < len {
+            match self
+                .serial_port
+                .read(&mut self.pending[self.pending_len..len])
+                .await?
+            {
+                0 => return Err(HciDriverError::ReadFailure),
+                n => self.pending_len += n,
+            }
+        }
+        Ok(())
+    }
+
+    async fn skip_pending_packet(&mut self) -> Result<(), HciDriverError> {
+        while self.skip_len > 0 {
+            let len = self.skip_len.min(BUFFER_SIZE);
+            match self.serial_port.read(&mut self.pending[..len]).await? {
+                0 => return Err(HciDriverError::ReadFailure),
+                n => self.skip_len -= n,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<S, const BUFFER_SIZE: usize> HciDriver for H4Transport<S, BUFFER_SIZE>
+where
+    S: SerialPort,
+{
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, HciDriverError> {
+        loop {
+            self.skip_pending_packet().await?;
+            self.fill_pending(1).await?;
+
+            let Some(header_size) = header_size(self.pending[0]) else {
+                self.discard_first_pending_byte();
+                continue;
+            };
+            self.fill_pending(header_size).await?;
+
+            let Some(payload_length) = payload_length(&self.pending[..header_size]) else {
+                self.discard_first_pending_byte();
+                continue;
+            };
+            let packet_len = header_size + payload_length;
+            if packet_len > buf.len() || packet_len > BUFFER_SIZE {
+                self.skip_packet(packet_len);
+                continue;
+            }
+            self.fill_pending(packet_len).await?;
+
+            buf[..packet_len].copy_from_slice(&self.pending[..packet_len]);
+            self.consume_pending(packet_len);
+            return Ok(packet_len);
+        }
+    }
+
+    async fn write(&mut self, buf: &[u8]) -> Result<usize, HciDriverError> {
+        self.serial_port.write_all(buf).await?;
+        Ok(buf.len())
+    }
+}
+
+fn header_size(packet_indicator: u8) -> Option<usize> {
+    match PacketType::try_from(packet_indicator).ok()? {
+        PacketType::Command => None,
+        PacketType::AclData => Some(5),
+        PacketType::SynchronousData => Some(4),
+        PacketType::Event => Some(3),
+        PacketType::IsoData => Some(5),
+    }
+}
+
+// Get the payload length from a full packet header, or `None` if the header is not valid.
+fn payload_length(header: &[u8]) -> Option<usize> {
+    const HANDLE_MAX: u16 = 0x0EFF;
+    let handle = || u16::from_le_bytes([header[1], header[2]]);
+    match PacketType::try_from(header[0]).ok()? {
+        PacketType::Command => None,
+        PacketType::AclData => {
+            ((handle() & 0x0FFF) <= HANDLE_MAX).then_some(())?;
+            Some(u16::from_le_bytes([header[3], header[4]]) as usize)
+        }
+        PacketType::SynchronousData => {
+            ((handle() & 0x0FFF) <= HANDLE_MAX).then_some(())?;
+            Some(header[3] as usize)
+        }
+        PacketType::Event => (header[1] != 0).then_some(header[2] as usize),
+        PacketType::IsoData => {
+            let length = u16::from_le_bytes([header[3], header[4]]);
+            // The RFU bits of the handle and length fields must be 0.
+            ((handle() & 0x0FFF) <= HANDLE_MAX && (handle() & 0x8000) == 0).then_some(())?;
+            ((length & 0xC000) == 0).then_some(length as usize)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "tokio"))]
+mod test {
+    use core::time::Duration;
+
+    use rstest::rstest;
+    use tokio_test::io::Mock;
+
+    use super::*;
+    use crate::TokioSerialPort;
+
+    fn h4_transport(mock: Mock) -> H4Transport<TokioSerialPort<Mock>> {
+        H4Transport::new(TokioSerialPort::new(mock))
+    }
+
+    #[rstest]
+    #[case::event(&[0x04, 0x0E, 0x04, 0x01, 0x03, 0x0C, 0x00])]
+    #[case::event_without_parameters(&[0x04, 0xFF, 0x00])]
+    #[case::acl_data(&[0x02, 0x01, 0x20, 0x03, 0x00, 0x01, 0x02, 0x03])]
+    #[case::iso_data(&[0x05, 0x20, 0x20, 0x06, 0x00, 0x00, 0x00, 0x02, 0x00, 0x01, 0x02])]
+    #[case::synchronous_data(&[0x03, 0x01, 0x00, 0x02, 0x01, 0x02])]
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_h4_read_one_packet(#[case] input: &[u8]) {
+        let mock = tokio_test::io::Builder::new().read(input).build();
+        let mut transport = h4_transport(mock);
+        let mut buf = [0u8; 259];
+        let len = transport.read(&mut buf).await.unwrap();
+        assert_eq!(&buf[..len], input);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_h4_read_packets_one_at_a_time() {
+        let mock = tokio_test::io::Builder::new()
+            .read(&[
+                0x04, 0x0E, 0x04, 0x01, 0x03, 0x0C, 0x00, 0x04, 0x0F, 0x04, 0x00, 0x01, 0x06, 0x04,
+            ])
+            .build();
+        let mut transport = h4_transport(mock);
+        let mut buf = [0u8; 259];
+        let len = transport.read(&mut buf).await.unwrap();
+        assert_eq!(&buf[..len], &[0x04, 0x0E, 0x04, 0x01, 0x03, 0x0C, 0x00]);
+        let len = transport.read(&mut buf).await.unwrap();
+        assert_eq!(&buf[..len], &[0x04, 0x0F, 0x04, 0x00, 0x01, 0x06, 0x04]);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_h4_read_fragmented_packet() {
+        let mock = tokio_test::io::Builder::new()
+            .read(&[0x04])
+            .read(&[0x0E, 0x04, 0x01])
+            .read(&[0x03])
+            .read(&[0x0C, 0x00])
+            .build();
+        let mut transport = h4_transport(mock);
+        let mut buf = [0u8; 259];
+        let len = transport.read(&mut buf).await.unwrap();
+        assert_eq!(&buf[..len], &[0x04, 0x0E, 0x04, 0x01, 0x03, 0x0C, 0x00]);
+    }
+
+    #[rstest]
+    #[case::invalid_packet_indicators(&[0x00, 0xFF, 0x42])]
+    #[case::command(&[0x01, 0x01, 0x10, 0x00])]
+    #[case::invalid_event_code(&[0x04, 0x00])]
+    #[case::invalid_acl_handle(&[0x02, 0xFF, 0x0F, 0x00, 0x00])]
+    #[case::invalid_iso_length_rfu_bits(&[0x05, 0x20, 0x00, 0x00, 0x40])]
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_h4_read_resynchronization(#[case] garbage: &[u8]) {
+        let packet = &[0x04, 0x0E, 0x04, 0x01, 0x03, 0x0C, 0x00];
+        let mock = tokio_test::io::Builder::new()
+            .read(garbage)
+            .read(packet)
+            .build();
+        let mut transport = h4_transport(mock);
+        let mut buf = [0u8; 259];
+        let len = transport.read(&mut buf).await.unwrap();
+        assert_eq!(&buf[..len], packet);
+    }
+
+    // The payload of the too long packet looks like event packets, it must not be parsed.
+    fn mock_too_long_packet_then_event() -> Mock {
+        let mut too_long_packet = [0x04u8; 105];
+        too_long_packet[..5].copy_from_slice(&[0x02, 0x01, 0x00, 0x64, 0x00]);
+        tokio_test::io::Builder::new()
+            .read(&too_long_packet[..40])
+            .read(&too_long_packet[40..])
+            .read(&[0x04, 0x0E, 0x04, 0x01, 0x03, 0x0C, 0x00])
+            .build()
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_h4_read_skips_packet_longer_than_read_buffer() {
+        let mut transport = h4_transport(mock_too_long_packet_then_event());
+        let mut buf = [0u8; 16];
+        let len = transport.read(&mut buf).await.unwrap();
+        assert_eq!(&buf[..len], &[0x04, 0x0E, 0x04, 0x01, 0x03, 0x0C, 0x00]);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_h4_read_skips_packet_longer_than_transport_buffer() {
+        let mut transport: H4Transport<_, 64> =
+            H4Transport::new(TokioSerialPort::new(mock_too_long_packet_then_event()));
+        let mut buf = [0u8; 259];
+        let len = transport.read(&mut buf).await.unwrap();
+        assert_eq!(&buf[..len], &[0x04, 0x0E, 0x04, 0x01, 0x03, 0x0C, 0x00]);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_h4_read_cancelled_in_the_middle_of_a_packet() {
+        let mock = tokio_test::io::Builder::new()
+            .read(&[0x04, 0x0E, 0x04, 0x01])
+            .wait(Duration::from_millis(100))
+            .read(&[0x03, 0x0C, 0x00, 0x04, 0x0F])
+            .wait(Duration::from_millis(100))
+            .read(&[0x04, 0x00, 0x01, 0x06, 0x04])
+            .build();
+        let mut transport = h4_transport(mock);
+        let mut buf = [0u8; 259];
+        assert!(
+            tokio::time::timeout(Duration::from_millis(10), transport.read(&mut buf))
+                .await
+                .is_err()
+        );
+        let len = transport.read(&mut buf).await.unwrap();
+        assert_eq!(&buf[..len], &[0x04, 0x0E, 0x04, 0x01, 0x03, 0x0C, 0x00]);
+        assert!(
+            tokio::time::timeout(Duration::from_millis(10), transport.read(&mut buf))
+                .await
+                .is_err()
+        );
+        let len = transport.read(&mut buf).await.unwrap();
+        assert_eq!(&buf[..len], &[0x04, 0x0F, 0x04, 0x00, 0x01, 0x06, 0x04]);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_h4_read_resynchronization_inside_header() {
+        // The invalid ISO header contains the beginning of a valid event packet.
+        let mock = tokio_test::io::Builder::new()
+            .read(&[0x05, 0x00, 0x80, 0x04, 0x0E, 0x00])
+            .build();
+        let mut transport = h4_transport(mock);
+        let mut buf = [0u8; 259];
+        let len = transport.read(&mut buf).await.unwrap();
+        assert_eq!(&buf[..len], &[0x04, 0x0E, 0x00]);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_h4_read_keeps_bytes_of_next_packet() {
+        // After resynchronization, the header buffer holds more than the first packet.
+        let mock = tokio_test::io::Builder::new()
+            .read(&[0x02, 0x04, 0xFF, 0x00, 0x04])
+            .read(&[0x0E, 0x00])
+            .build();
+        let mut transport = h4_transport(mock);
+        let mut buf = [0u8; 259];
+        let len = transport.read(&mut buf).await.unwrap();
+        assert_eq!(&buf[..len], &[0x04, 0xFF, 0x00]);
+        let len = transport.read(&mut buf).await.unwrap();
+        assert_eq!(&buf[..len], &[0x04, 0x0E, 0x00]);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_h4_read_closed_serial_port() {
+        let mock = tokio_test::io::Builder::new().read(&[0x04, 0x0E]).build();
+        let mut transport = h4_transport(mock);
+        let mut buf = [0u8; 259];
+        let err = transport.read(&mut buf).await.unwrap_err();
+        assert_eq!(err, HciDriverError::ReadFailure);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_h4_write() {
+        let packet = &[0x01, 0x03, 0x0C, 0x00];
+        let mock = tokio_test::io::Builder::new().write(packet).build();
+        let mut transport = h4_transport(mock);
+        assert_eq!(transport.write(packet).await.unwrap(), packet.len());
+        let _ = transport.into_inner().into_inner();
+    }
+}
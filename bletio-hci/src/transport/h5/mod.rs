@@ -0,0 +1,753 @@
+use core::time::Duration;
+
+use bletio_utils::{Buffer, BufferOps};
+use heapless::Deque;
+
+use crate::transport::h5::packet::{
+    data_integrity_check, next_seq, H5Header, H5LinkConfig, H5PacketType, LinkControlMessage,
+    H5_CRC_SIZE, H5_HEADER_SIZE,
+};
+use crate::transport::h5::slip::{SlipDecoder, SlipEvent};
+use crate::{DefaultTimeSource, HciDriver, HciDriverError, SerialPort, TimeSource, WithTimeout};
+
+mod packet;
+mod slip;
+
+// Packet Type (1) + ISO data packet
+const HCI_PACKET_MAX_SIZE: usize = 259;
+// Header (4) + HCI packet without its packet type + Data integrity check (2)
+const H5_PACKET_MAX_SIZE: usize = H5_HEADER_SIZE + HCI_PACKET_MAX_SIZE - 1 + H5_CRC_SIZE;
+// Delimiters (2) + every byte escaped
+const H5_FRAME_MAX_SIZE: usize = 2 + 2 * H5_PACKET_MAX_SIZE;
+// Delimiters (2) + every byte escaped of the longest link control packet with its data integrity check
+const H5_CONTROL_FRAME_MAX_SIZE: usize = 2 + 2 * (H5_HEADER_SIZE + 3 + H5_CRC_SIZE);
+const H5_RECEIVED_PACKETS_MAX: usize = 2;
+const H5_READ_CHUNK_SIZE: usize = 32;
+
+/// Configuration of the [`H5Transport`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct H5Config {
+    data_integrity_check: bool,
+    link_establishment_interval: Duration,
+    max_retransmissions: u8,
+    out_of_frame_flow_control: bool,
+    retransmission_timeout: Duration,
+}
+
+impl H5Config {
+    /// Request the use of the data integrity check (CRC) on the packets sent by both ends of the link.
+    pub fn with_data_integrity_check(mut self, enabled: bool) -> Self {
+        self.data_integrity_check = enabled;
+        self
+    }
+
+    /// Set the interval between the SYNC or CONFIG messages sent during the link establishment.
+    pub fn with_link_establishment_interval(mut self, interval: Duration) -> Self {
+        self.link_establishment_interval = interval;
+        self
+    }
+
+    /// Set the number of times a reliable packet is retransmitted before giving up.
+    pub fn with_max_retransmissions(mut self, max_retransmissions: u8) -> Self {
+        self.max_retransmissions = max_retransmissions;
+        self
+    }
+
+    /// Request the use of the out-of-frame software flow control (XON/XOFF).
+    pub fn with_out_of_frame_flow_control(mut self, enabled: bool) -> Self {
+        self.out_of_frame_flow_control = enabled;
+        self
+    }
+
+    /// Set the time to wait for the acknowledgment of a reliable packet before retransmitting it.
+    pub fn with_retransmission_timeout(mut self, timeout: Duration) -> Self {
+        self.retransmission_timeout = timeout;
+        self
+    }
+
+    fn link_config(&self) -> H5LinkConfig {
+        H5LinkConfig {
+            sliding_window_size: 1,
+            out_of_frame_flow_control: self.out_of_frame_flow_control,
+            data_integrity_check: self.data_integrity_check,
+        }
+    }
+}
+
+impl Default for H5Config {
+    fn default() -> Self {
+        Self {
+            data_integrity_check: false,
+            link_establishment_interval: Duration::from_millis(250),
+            max_retransmissions: 10,
+            out_of_frame_flow_control: false,
+            retransmission_timeout: Duration::from_millis(250),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+enum LinkState {
+    Uninitialized,
+    Initialized,
+    Active,
+}
+
+/// HCI Three-Wire UART Transport Layer, also known as H5.
+///
+/// The HCI packets are sent as reliable packets in SLIP frames and are retransmitted until they are
+/// acknowledged by the peer. The link is established (SYNC/CONFIG messages) on the first read or
+/// write. A single reliable packet is sent at a time, so the sliding window size is 1.
+///
+/// See [Core Specification 6.0, Vol.4, Part D](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host-controller-interface/three-wire-uart-transport-layer.html).
+#[derive(Debug)]
+pub struct H5Transport<S, T = DefaultTimeSource>
+where
+    S: SerialPort,
+{
+    serial_port: S,
+    time_source: T,
+    config: H5Config,
+    link_state: LinkState,
+    link_config: H5LinkConfig,
+    // Sequence number of the next reliable packet to send.
+    tx_seq: u8,
+    // Whether the last sent reliable packet is waiting for an acknowledgment.
+    tx_pending: bool,
+    tx_frame: Buffer<H5_FRAME_MAX_SIZE>,
+    // Sequence number of the next reliable packet expected from the peer.
+    rx_ack: u8,
+    rx_decoder: SlipDecoder<H5_PACKET_MAX_SIZE>,
+    rx_chunk: [u8; H5_READ_CHUNK_SIZE],
+    rx_chunk_pos: usize,
+    rx_chunk_len: usize,
+    rx_packets: Deque<Buffer<HCI_PACKET_MAX_SIZE>, H5_RECEIVED_PACKETS_MAX>,
+    peer_xoff: bool,
+}
+
+#[cfg(any(feature = "embassy", feature = "tokio"))]
+impl<S> H5Transport<S>
+where
+    S: SerialPort,
+{
+    pub fn new(serial_port: S) -> Self {
+        Self::with_config(serial_port, H5Config::default())
+    }
+
+    pub fn with_config(serial_port: S, config: H5Config) -> Self {
+        Self::with_time_source(serial_port, config, DefaultTimeSource::default())
+    }
+}
+
+impl<S, T> H5Transport<S, T>
+where
+    S: SerialPort,
+    T: TimeSource,
+{
+    /// Create an H5 transport measuring its link establishment and retransmission timeouts
+    /// with the given time source.
+    pub fn with_time_source(serial_port: S, config: H5Config, time_source: T) -> Self {
+        Self {
+            serial_port,
+            time_source,
+            config,
+            link_state: LinkState::Uninitialized,
+            link_config: H5LinkConfig::default(),
+            tx_seq: 0,
+            tx_pending: false,
+            tx_frame: Buffer::default(),
+            rx_ack: 0,
+            rx_decoder: SlipDecoder::default(),
+            rx_chunk: [0; H5_READ_CHUNK_SIZE],
+            rx_chunk_pos: 0,
+            rx_chunk_len: 0,
+            rx_packets: Deque::new(),
+            peer_xoff: false,
+        }
+    }
+
+    pub fn into_inner(self) -> S {
+        self.serial_port
+    }
+
+    /// Tell whether the link has been established with the peer.
+    pub fn is_active(&self) -> bool {
+        self.link_state == LinkState::Active
+    }
+
+    async fn establish_link(&mut self) -> Result<(), HciDriverError> {
+        while self.link_state != LinkState::Active {
+            let state = self.link_state;
+            match state {
+                LinkState::Uninitialized => {
+                    self.send_link_control(LinkControlMessage::Sync).await?
+                }
+                _ => {
+                    self.send_link_control(LinkControlMessage::Config(self.config.link_config()))
+                        .await?
+                }
+            }
+            let timeout = self
+                .time_source
+                .delay(self.config.link_establishment_interval);
+            let wait_state_change = async {
+                while self.link_state == state {
+                    self.process_incoming().await?;
+                }
+                Ok::<(), HciDriverError>(())
+            };
+            match wait_state_change.with_timeout(timeout).await {
+                Ok(res) => res?,
+                Err(HciDriverError::Timeout) => (),
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+
+    fn reset_link(&mut self) {
+        #[cfg(feature = "defmt")]
+        defmt::warn!("H5: peer has been reset, resetting the link");
+        self.link_state = LinkState::Uninitialized;
+        self.link_config = H5LinkConfig::default();
+        self.tx_seq = 0;
+        self.tx_pending = false;
+        self.rx_ack = 0;
+        self.rx_packets.clear();
+        self.peer_xoff = false;
+    }
+
+    // Read from the serial port until a frame has been handled or the flow control state has changed.
+    async fn process_incoming(&mut self) -> Result<(), HciDriverError> {
+        loop {
+            if self.rx_chunk_pos == self.rx_chunk_len {
+                self.rx_chunk_len = match self.serial_port.read(&mut self.rx_chunk).await? {
+                    0 => return Err(HciDriverError::ReadFailure),
+                    n => n,
+                };
+                self.rx_chunk_pos = 0;
+            }
+            let byte = self.rx_chunk[self.rx_chunk_pos];
+            self.rx_chunk_pos += 1;
+            match self
+                .rx_decoder
+                .push(byte, self.link_config.out_of_frame_flow_control)
+            {
+                Some(SlipEvent::Frame) => return self.handle_frame().await,
+                Some(SlipEvent::Xon) => {
+                    self.peer_xoff = false;
+                    return Ok(());
+                }
+                Some(SlipEvent::Xoff) => {
+                    self.peer_xoff = true;
+                    return Ok(());
+                }
+                None => (),
+            }
+        }
+    }
+
+    async fn handle_frame(&mut self) -> Result<(), HciDriverError> {
+        let frame = self.rx_decoder.frame();
+        let Some(header) = H5Header::decode(frame) else {
+            #[cfg(feature = "defmt")]
+            defmt::debug!("H5: dropping packet with invalid header");
+            return Ok(());
+        };
+        let payload_end = H5_HEADER_SIZE + header.payload_length as usize;
+        let expected_len = if header.data_integrity_check_present {
+            payload_end + H5_CRC_SIZE
+        } else {
+            payload_end
+        };
+        if frame.len() != expected_len {
+            #[cfg(feature = "defmt")]
+            defmt::debug!("H5: dropping packet with invalid length");
+            return Ok(());
+        }
+        if header.data_integrity_check_present {
+            let crc = u16::from_be_bytes([frame[payload_end], frame[payload_end + 1]]);
+            if data_integrity_check(&frame[..payload_end]) != crc {
+                #[cfg(feature = "defmt")]
+                defmt::debug!("H5: dropping packet with invalid data integrity check");
+                return Ok(());
+            }
+        }
+
+        if header.packet_type == H5PacketType::LinkControl {
+            return match LinkControlMessage::decode(&frame[H5_HEADER_SIZE..payload_end]) {
+                Some(message) => self.handle_link_control(message).await,
+                None => Ok(()),
+            };
+        }
+        if self.link_state != LinkState::Active {
+            // Only link control packets are allowed before the link is established.
+            return Ok(());
+        }
+
+        if self.tx_pending && header.ack == next_seq(self.tx_seq) {
+            self.tx_pending = false;
+            self.tx_seq = next_seq(self.tx_seq);
+        }
+
+        let packet_type = match header.packet_type {
+            H5PacketType::Ack | H5PacketType::VendorSpecific | H5PacketType::LinkControl => {
+                return Ok(())
+            }
+            H5PacketType::Command => crate::PacketType::Command,
+            H5PacketType::AclData => crate::PacketType::AclData,
+            H5PacketType::SynchronousData => crate::PacketType::SynchronousData,
+            H5PacketType::Event => crate::PacketType::Event,
+            H5PacketType::IsoData => crate::PacketType::IsoData,
+        };
+        if header.reliable && header.seq != self.rx_ack {
+            // Out of order or duplicate packet, acknowledge again the last received packet.
+            return self.send_ack().await;
+        }
+        if !self.rx_packets.is_full() {
+            let mut packet = Buffer::<HCI_PACKET_MAX_SIZE>::default();
+            let payload = &self.rx_decoder.frame()[H5_HEADER_SIZE..payload_end];
+            if packet.try_push(packet_type as u8).is_ok() && packet.copy_from_slice(payload).is_ok()
+            {
+                // INVARIANT: The deque has been checked as not being full.
+                self.rx_packets.push_back(packet).unwrap();
+                if header.reliable {
+                    self.rx_ack = next_seq(self.rx_ack);
+                }
+            }
+        }
+        if header.reliable {
+            // When the packet could not be stored, the acknowledgment number has not been
+            // incremented so the peer will retransmit it later.
+            self.send_ack().await?;
+        }
+        Ok(())
+    }
+
+    async fn handle_link_control(
+        &mut self,
+        message: LinkControlMessage,
+    ) -> Result<(), HciDriverError> {
+        match (self.link_state, message) {
+            (LinkState::Active, LinkControlMessage::Sync) => {
+                self.reset_link();
+                self.send_link_control(LinkControlMessage::SyncResponse)
+                    .await
+            }
+            (_, LinkControlMessage::Sync) => {
+                self.send_link_control(LinkControlMessage::SyncResponse)
+                    .await
+            }
+            (LinkState::Uninitialized, LinkControlMessage::SyncResponse) => {
+                self.link_state = LinkState::Initialized;
+                Ok(())
+            }
+            (LinkState::Initialized | LinkState::Active, LinkControlMessage::Config(config)) => {
+                let config = self.config.link_config().negotiate(&config);
+                self.send_link_control(LinkControlMessage::ConfigResponse(config))
+                    .await
+            }
+            (LinkState::Initialized, LinkControlMessage::ConfigResponse(config)) => {
+                self.link_config = self.config.link_config().negotiate(&config);
+                self.link_state = LinkState::Active;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    async fn send_ack(&mut self) -> Result<(), HciDriverError> {
+        self.send_unreliable(H5PacketType::Ack, &[]).await
+    }
+
+    async fn send_link_control(
+        &mut self,
+        message: LinkControlMessage,
+    ) -> Result<(), HciDriverError> {
+        let (payload, len) = message.encode();
+        self.send_unreliable(H5PacketType::LinkControl, &payload[..len])
+            .await
+    }
+
+    async fn send_unreliable(
+        &mut self,
+        packet_type: H5PacketType,
+        payload: &[u8],
+    ) -> Result<(), HciDriverError> {
+        let mut frame = Buffer::<H5_CONTROL_FRAME_MAX_SIZE>::default();
+        self.encode_frame(packet_type, 0, false, payload, &mut frame)?;
+        self.serial_port.write_all(frame.data()).await
+    }
+
+    fn encode_frame<B: BufferOps>(
+        &self,
+        packet_type: H5PacketType,
+        seq: u8,
+        reliable: bool,
+        payload: &[u8],
+        frame: &mut B,
+    ) -> Result<(), HciDriverError> {
+        let mut packet = Buffer::<H5_PACKET_MAX_SIZE>::default();
+        let data_integrity_check_present = self.link_config.data_integrity_check;
+        let header = H5Header {
+            seq,
+            ack: self.rx_ack,
+            data_integrity_check_present,
+            reliable,
+            packet_type,
+            payload_length: payload.len() as u16,
+        };
+        packet
+            .copy_from_slice(&header.encode())
+            .and_then(|_| packet.copy_from_slice(payload))
+            .map_err(|_| HciDriverError::WriteFailure)?;
+        if data_integrity_check_present {
+            let crc = data_integrity_check(packet.data());
+            packet
+                .copy_from_slice(&crc.to_be_bytes())
+                .map_err(|_| HciDriverError::WriteFailure)?;
+        }
+        slip::encode(
+            packet.data(),
+            self.link_config.out_of_frame_flow_control,
+            frame,
+        )
+        .map_err(|_| HciDriverError::WriteFailure)?;
+        Ok(())
+    }
+}
+
+impl<S, T> HciDriver for H5Transport<S, T>
+where
+    S: SerialPort,
+    T: TimeSource,
+{
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, HciDriverError> {
+        loop {
+            if let Some(packet) = self.rx_packets.pop_front() {
+                let len = packet.len();
+                buf.get_mut(..len)
+                    .ok_or(HciDriverError::ReadFailure)?
+                    .copy_from_slice(packet.data());
+                return Ok(len);
+            }
+            self.establish_link().await?;
+            self.process_incoming().await?;
+        }
+    }
+
+    async fn write(&mut self, buf: &[u8]) -> Result<usize, HciDriverError> {
+        let packet_type = match buf.first().map(|v| crate::PacketType::try_from(*v)) {
+            Some(Ok(crate::PacketType::Command)) => H5PacketType::Command,
+            Some(Ok(crate::PacketType::AclData)) => H5PacketType::AclData,
+            Some(Ok(crate::PacketType::SynchronousData)) => H5PacketType::SynchronousData,
+            Some(Ok(crate::PacketType::Event)) => H5PacketType::Event,
+            Some(Ok(crate::PacketType::IsoData)) => H5PacketType::IsoData,
+            _ => return Err(HciDriverError::WriteFailure),
+        };
+
+        self.establish_link().await?;
+        while self.peer_xoff {
+            self.process_incoming().await?;
+        }
+
+        let mut frame = core::mem::take(&mut self.tx_frame);
+        frame.clear();
+        let res = self.encode_frame(packet_type, self.tx_seq, true, &buf[1..], &mut frame);
+        self.tx_frame = frame;
+        res?;
+        self.tx_pending = true;
+
+        let retransmission_timeout = self.config.retransmission_timeout;
+        for _ in 0..=self.config.max_retransmissions {
+            self.serial_port.write_all(self.tx_frame.data()).await?;
+            let timeout = self.time_source.delay(retransmission_timeout);
+            let wait_ack = async {
+                while self.tx_pending {
+                    self.process_incoming().await?;
+                }
+                Ok::<(), HciDriverError>(())
+            };
+            match wait_ack.with_timeout(timeout).await {
+                Ok(res) => {
+                    res?;
+                    return if self.link_state == LinkState::Active {
+                        Ok(buf.len())
+                    } else {
+                        // The link has been reset while waiting for the acknowledgment.
+                        Err(HciDriverError::WriteFailure)
+                    };
+                }
+                Err(HciDriverError::Timeout) => {
+                    #[cfg(feature = "defmt")]
+                    defmt::debug!("H5: retransmitting packet {}", self.tx_seq);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        self.tx_pending = false;
+        Err(HciDriverError::Timeout)
+    }
+}
+
+#[cfg(all(test, feature = "tokio"))]
+mod test {
+    use tokio::io::DuplexStream;
+    use tokio::time::Instant;
+    use tokio_test::io::Mock;
+
+    use super::*;
+    use crate::transport::h5::slip::{XOFF, XON};
+    use crate::TokioSerialPort;
+
+    const RESET_COMMAND: &[u8] = &[0x01, 0x03, 0x0C, 0x00];
+    const RESET_COMMAND_COMPLETE_EVENT: &[u8] = &[0x04, 0x0E, 0x04, 0x01, 0x03, 0x0C, 0x00];
+
+    const SYNC_FRAME: &[u8] = &[0xC0, 0x00, 0x2F, 0x00, 0xD0, 0x01, 0x7E, 0xC0];
+    const SYNC_RESPONSE_FRAME: &[u8] = &[0xC0, 0x00, 0x2F, 0x00, 0xD0, 0x02, 0x7D, 0xC0];
+    const CONFIG_FRAME: &[u8] = &[0xC0, 0x00, 0x3F, 0x00, 0xDB, 0xDC, 0x03, 0xFC, 0x01, 0xC0];
+    const CONFIG_RESPONSE_FRAME: &[u8] =
+        &[0xC0, 0x00, 0x3F, 0x00, 0xDB, 0xDC, 0x04, 0x7B, 0x01, 0xC0];
+    const ACK_1_FRAME: &[u8] = &[0xC0, 0x08, 0x00, 0x00, 0xF7, 0xC0];
+
+    type DuplexH5Transport = H5Transport<TokioSerialPort<DuplexStream>>;
+
+    fn loopback(
+        host_config: H5Config,
+        controller_config: H5Config,
+    ) -> (DuplexH5Transport, DuplexH5Transport) {
+        let (host_stream, controller_stream) = tokio::io::duplex(4096);
+        (
+            H5Transport::with_config(TokioSerialPort::new(host_stream), host_config),
+            H5Transport::with_config(TokioSerialPort::new(controller_stream), controller_config),
+        )
+    }
+
+    async fn exchange_reset<S1: SerialPort, S2: SerialPort>(
+        host: &mut H5Transport<S1>,
+        controller: &mut H5Transport<S2>,
+    ) {
+        let mut buf = [0u8; 259];
+        let (written, read) = tokio::join!(host.write(RESET_COMMAND), controller.read(&mut buf));
+        assert_eq!(written.unwrap(), RESET_COMMAND.len());
+        assert_eq!(&buf[..read.unwrap()], RESET_COMMAND);
+
+        let (written, read) = tokio::join!(
+            controller.write(RESET_COMMAND_COMPLETE_EVENT),
+            host.read(&mut buf)
+        );
+        assert_eq!(written.unwrap(), RESET_COMMAND_COMPLETE_EVENT.len());
+        assert_eq!(&buf[..read.unwrap()], RESET_COMMAND_COMPLETE_EVENT);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_h5_wire_format() {
+        let mock: Mock = tokio_test::io::Builder::new()
+            .write(SYNC_FRAME)
+            .read(SYNC_RESPONSE_FRAME)
+            .write(CONFIG_FRAME)
+            .read(CONFIG_RESPONSE_FRAME)
+            .write(&[0xC0, 0x80, 0x31, 0x00, 0x4E, 0x03, 0x0C, 0x00, 0xC0])
+            .read(ACK_1_FRAME)
+            .read(&[
+                // Garbage
+                0x42, 0x43, // Invalid header checksum
+                0xC0, 0x88, 0x64, 0x00, 0x14, 0x0E, 0x04, 0x01, 0x03, 0x0C, 0x00, 0xC0,
+                // Invalid payload length
+                0xC0, 0x88, 0x64, 0x00, 0x13, 0x0E, 0x04, 0x01, 0xC0, // Valid event
+                0xC0, 0x88, 0x64, 0x00, 0x13, 0x0E, 0x04, 0x01, 0x03, 0x0C, 0x00, 0xC0,
+            ])
+            .write(ACK_1_FRAME)
+            .build();
+        let mut transport = H5Transport::new(TokioSerialPort::new(mock));
+        assert!(!transport.is_active());
+        assert_eq!(
+            transport.write(RESET_COMMAND).await.unwrap(),
+            RESET_COMMAND.len()
+        );
+        assert!(transport.is_active());
+        let mut buf = [0u8; 259];
+        let len = transport.read(&mut buf).await.unwrap();
+        assert_eq!(&buf[..len], RESET_COMMAND_COMPLETE_EVENT);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_h5_loopback() {
+        let (mut host, mut controller) = loopback(H5Config::default(), H5Config::default());
+        exchange_reset(&mut host, &mut controller).await;
+        assert!(host.is_active());
+        assert!(controller.is_active());
+        assert_eq!(host.link_config, H5LinkConfig::default());
+        // Sequence numbers keep on incrementing
+        exchange_reset(&mut host, &mut controller).await;
+        assert_eq!(host.tx_seq, 2);
+        assert_eq!(host.rx_ack, 2);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_h5_loopback_sequence_number_wraps() {
+        let (mut host, mut controller) = loopback(H5Config::default(), H5Config::default());
+        for _ in 0..10 {
+            exchange_reset(&mut host, &mut controller).await;
+        }
+        assert_eq!(host.tx_seq, 2);
+        assert_eq!(controller.tx_seq, 2);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_h5_loopback_with_data_integrity_check_and_flow_control() {
+        let config = H5Config::default()
+            .with_data_integrity_check(true)
+            .with_out_of_frame_flow_control(true);
+        let (mut host, mut controller) = loopback(config, config);
+        exchange_reset(&mut host, &mut controller).await;
+        assert_eq!(
+            host.link_config,
+            H5LinkConfig {
+                sliding_window_size: 1,
+                out_of_frame_flow_control: true,
+                data_integrity_check: true,
+            }
+        );
+
+        // Data containing bytes that need to be escaped
+        let acl_data = &[0x02, 0x01, 0x00, 0x04, 0x00, 0xC0, 0xDB, XON, XOFF];
+        let mut buf = [0u8; 259];
+        let (written, read) = tokio::join!(host.write(acl_data), controller.read(&mut buf));
+        assert_eq!(written.unwrap(), acl_data.len());
+        assert_eq!(&buf[..read.unwrap()], acl_data);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_h5_loopback_negotiation() {
+        let (mut host, mut controller) = loopback(
+            H5Config::default()
+                .with_data_integrity_check(true)
+                .with_out_of_frame_flow_control(true),
+            H5Config::default().with_data_integrity_check(true),
+        );
+        exchange_reset(&mut host, &mut controller).await;
+        assert_eq!(
+            host.link_config,
+            H5LinkConfig {
+                sliding_window_size: 1,
+                out_of_frame_flow_control: false,
+                data_integrity_check: true,
+            }
+        );
+        assert_eq!(controller.link_config, host.link_config);
+    }
+
+    struct LossySerialPort<S: SerialPort> {
+        inner: S,
+        reliable_frames_to_drop: usize,
+    }
+
+    impl<S: SerialPort> SerialPort for LossySerialPort<S> {
+        async fn read(&mut self, buf: &mut [u8]) -> Result<usize, HciDriverError> {
+            self.inner.read(buf).await
+        }
+
+        async fn write_all(&mut self, buf: &[u8]) -> Result<(), HciDriverError> {
+            if self.reliable_frames_to_drop > 0 && (buf[1] & 0x80) != 0 {
+                self.reliable_frames_to_drop -= 1;
+                return Ok(());
+            }
+            self.inner.write_all(buf).await
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_h5_retransmission() {
+        let (host_stream, controller_stream) = tokio::io::duplex(4096);
+        let mut host = H5Transport::new(LossySerialPort {
+            inner: TokioSerialPort::new(host_stream),
+            reliable_frames_to_drop: 2,
+        });
+        let mut controller = H5Transport::new(TokioSerialPort::new(controller_stream));
+        let start = Instant::now();
+        exchange_reset(&mut host, &mut controller).await;
+        assert!(start.elapsed() >= 2 * H5Config::default().retransmission_timeout);
+        assert_eq!(host.into_inner().reliable_frames_to_drop, 0);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_h5_retransmission_failure() {
+        let (mut host, mut controller) = loopback(
+            H5Config::default().with_max_retransmissions(3),
+            H5Config::default(),
+        );
+        let (host_res, controller_res) =
+            tokio::join!(host.establish_link(), controller.establish_link());
+        assert!(host_res.is_ok());
+        assert!(controller_res.is_ok());
+
+        // The controller is not reading anymore, so it never acknowledges the packet
+        let start = Instant::now();
+        assert_eq!(
+            host.write(RESET_COMMAND).await,
+            Err(HciDriverError::Timeout)
+        );
+        assert_eq!(
+            start.elapsed(),
+            4 * H5Config::default().retransmission_timeout
+        );
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_h5_out_of_frame_flow_control() {
+        let config = H5Config::default().with_out_of_frame_flow_control(true);
+        let (mut host, mut controller) = loopback(config, config);
+        exchange_reset(&mut host, &mut controller).await;
+
+        controller.serial_port.write_all(&[XOFF]).await.unwrap();
+        let start = Instant::now();
+        let mut buf = [0u8; 259];
+        let (written, read) = tokio::join!(host.write(RESET_COMMAND), async {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            controller.serial_port.write_all(&[XON]).await.unwrap();
+            controller.read(&mut buf).await
+        });
+        assert_eq!(written.unwrap(), RESET_COMMAND.len());
+        assert_eq!(&buf[..read.unwrap()], RESET_COMMAND);
+        assert!(start.elapsed() >= Duration::from_millis(100));
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_h5_peer_reset() {
+        let (mut host, mut controller) = loopback(H5Config::default(), H5Config::default());
+        exchange_reset(&mut host, &mut controller).await;
+
+        // The controller restarts and establishes the link again
+        let mut controller = H5Transport::new(controller.into_inner());
+        let mut buf = [0u8; 259];
+        let (written, read) = tokio::join!(
+            controller.write(RESET_COMMAND_COMPLETE_EVENT),
+            host.read(&mut buf)
+        );
+        assert_eq!(written.unwrap(), RESET_COMMAND_COMPLETE_EVENT.len());
+        assert_eq!(&buf[..read.unwrap()], RESET_COMMAND_COMPLETE_EVENT);
+        assert!(host.is_active());
+        assert_eq!(host.rx_ack, 1);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_h5_write_invalid_packet_type() {
+        let (mut host, _controller) = loopback(H5Config::default(), H5Config::default());
+        assert_eq!(
+            host.write(&[0x00, 0x01]).await,
+            Err(HciDriverError::WriteFailure)
+        );
+        assert_eq!(host.write(&[]).await, Err(HciDriverError::WriteFailure));
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_h5_closed_serial_port() {
+        let (mut host, controller) = loopback(H5Config::default(), H5Config::default());
+        drop(controller);
+        let mut buf = [0u8; 259];
+        assert!(host.read(&mut buf).await.is_err());
+    }
+}
@@ -0,0 +1,320 @@
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+pub(crate) const H5_HEADER_SIZE: usize = 4;
+pub(crate) const H5_CRC_SIZE: usize = 2;
+
+const SEQ_MASK: u8 = 0x07;
+const DATA_INTEGRITY_CHECK_PRESENT: u8 = 0x40;
+const RELIABLE_PACKET: u8 = 0x80;
+const PAYLOAD_LENGTH_MAX: u16 = 0x0FFF;
+
+/// Type of the packet carried by a Three-Wire UART packet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoPrimitive, TryFromPrimitive)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(u8)]
+pub(crate) enum H5PacketType {
+    Ack = 0x00,
+    Command = 0x01,
+    AclData = 0x02,
+    SynchronousData = 0x03,
+    Event = 0x04,
+    IsoData = 0x05,
+    VendorSpecific = 0x0E,
+    LinkControl = 0x0F,
+}
+
+/// Header of a Three-Wire UART packet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub(crate) struct H5Header {
+    pub(crate) seq: u8,
+    pub(crate) ack: u8,
+    pub(crate) data_integrity_check_present: bool,
+    pub(crate) reliable: bool,
+    pub(crate) packet_type: H5PacketType,
+    pub(crate) payload_length: u16,
+}
+
+impl H5Header {
+    pub(crate) fn encode(&self) -> [u8; H5_HEADER_SIZE] {
+        let mut flags = (self.seq & SEQ_MASK) | ((self.ack & SEQ_MASK) << 3);
+        if self.data_integrity_check_present {
+            flags |= DATA_INTEGRITY_CHECK_PRESENT;
+        }
+        if self.reliable {
+            flags |= RELIABLE_PACKET;
+        }
+        let packet_type: u8 = self.packet_type.into();
+        let length = self.payload_length & PAYLOAD_LENGTH_MAX;
+        let mut header = [
+            flags,
+            packet_type | (((length & 0x0F) as u8) << 4),
+            (length >> 4) as u8,
+            0,
+        ];
+        header[3] = header_checksum(&header[..3]);
+        header
+    }
+
+    /// Decode a packet header, returning `None` if its checksum is wrong or its packet type unknown.
+    pub(crate) fn decode(header: &[u8]) -> Option<Self> {
+        if header.len() < H5_HEADER_SIZE || header_checksum(&header[..3]) != header[3] {
+            return None;
+        }
+        Some(Self {
+            seq: header[0] & SEQ_MASK,
+            ack: (header[0] >> 3) & SEQ_MASK,
+            data_integrity_check_present: (header[0] & DATA_INTEGRITY_CHECK_PRESENT) != 0,
+            reliable: (header[0] & RELIABLE_PACKET) != 0,
+            packet_type: H5PacketType::try_from(header[1] & 0x0F).ok()?,
+            payload_length: ((header[1] >> 4) as u16) | ((header[2] as u16) << 4),
+        })
+    }
+}
+
+fn header_checksum(header: &[u8]) -> u8 {
+    0xFF - header.iter().fold(0u8, |acc, b| acc.wrapping_add(*b))
+}
+
+/// Compute the data integrity check of a packet.
+///
+/// This is a CRC-CCITT computed on the bytes in the order they are sent on the UART, that is least
+/// significant bit first. The result is bit-reversed so that it can be sent most significant byte
+/// first.
+pub(crate) fn data_integrity_check(data: &[u8]) -> u16 {
+    data.iter()
+        .fold(0xFFFFu16, |crc, byte| {
+            (0..8).fold(crc ^ (*byte as u16), |crc, _| {
+                if (crc & 0x0001) != 0 {
+                    (crc >> 1) ^ 0x8408
+                } else {
+                    crc >> 1
+                }
+            })
+        })
+        .reverse_bits()
+}
+
+pub(crate) fn next_seq(seq: u8) -> u8 {
+    (seq + 1) & SEQ_MASK
+}
+
+/// Link control messages used for the link establishment.
+///
+/// See [Core Specification 6.0, Vol.4, Part D, 8](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host-controller-interface/three-wire-uart-transport-layer.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub(crate) enum LinkControlMessage {
+    Sync,
+    SyncResponse,
+    Config(H5LinkConfig),
+    ConfigResponse(H5LinkConfig),
+    Wakeup,
+    Woken,
+    Sleep,
+}
+
+const SYNC: [u8; 2] = [0x01, 0x7E];
+const SYNC_RESPONSE: [u8; 2] = [0x02, 0x7D];
+const CONFIG: [u8; 2] = [0x03, 0xFC];
+const CONFIG_RESPONSE: [u8; 2] = [0x04, 0x7B];
+const WAKEUP: [u8; 2] = [0x05, 0xFA];
+const WOKEN: [u8; 2] = [0x06, 0xF9];
+const SLEEP: [u8; 2] = [0x07, 0x78];
+
+impl LinkControlMessage {
+    pub(crate) fn encode(&self) -> ([u8; 3], usize) {
+        let (message, config) = match self {
+            Self::Sync => (SYNC, None),
+            Self::SyncResponse => (SYNC_RESPONSE, None),
+            Self::Config(config) => (CONFIG, Some(config)),
+            Self::ConfigResponse(config) => (CONFIG_RESPONSE, Some(config)),
+            Self::Wakeup => (WAKEUP, None),
+            Self::Woken => (WOKEN, None),
+            Self::Sleep => (SLEEP, None),
+        };
+        match config {
+            Some(config) => ([message[0], message[1], config.encode()], 3),
+            None => ([message[0], message[1], 0], 2),
+        }
+    }
+
+    pub(crate) fn decode(payload: &[u8]) -> Option<Self> {
+        if payload.len() < 2 {
+            return None;
+        }
+        // A configuration message without configuration field is using the default configuration.
+        let config = || {
+            payload
+                .get(2)
+                .map(|v| H5LinkConfig::decode(*v))
+                .unwrap_or_default()
+        };
+        match [payload[0], payload[1]] {
+            SYNC => Some(Self::Sync),
+            SYNC_RESPONSE => Some(Self::SyncResponse),
+            CONFIG => Some(Self::Config(config())),
+            CONFIG_RESPONSE => Some(Self::ConfigResponse(config())),
+            WAKEUP => Some(Self::Wakeup),
+            WOKEN => Some(Self::Woken),
+            SLEEP => Some(Self::Sleep),
+            _ => None,
+        }
+    }
+}
+
+/// Configuration field of the CONFIG and CONFIG RESPONSE link control messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub(crate) struct H5LinkConfig {
+    pub(crate) sliding_window_size: u8,
+    pub(crate) out_of_frame_flow_control: bool,
+    pub(crate) data_integrity_check: bool,
+}
+
+impl H5LinkConfig {
+    fn encode(&self) -> u8 {
+        let mut value = self.sliding_window_size & SEQ_MASK;
+        if self.out_of_frame_flow_control {
+            value |= 0x08;
+        }
+        if self.data_integrity_check {
+            value |= 0x10;
+        }
+        value
+    }
+
+    fn decode(value: u8) -> Self {
+        Self {
+            sliding_window_size: (value & SEQ_MASK).max(1),
+            out_of_frame_flow_control: (value & 0x08) != 0,
+            data_integrity_check: (value & 0x10) != 0,
+        }
+    }
+
+    /// Get the configuration supported by both ends of the link.
+    pub(crate) fn negotiate(&self, other: &Self) -> Self {
+        Self {
+            sliding_window_size: self.sliding_window_size.min(other.sliding_window_size),
+            out_of_frame_flow_control: self.out_of_frame_flow_control
+                && other.out_of_frame_flow_control,
+            data_integrity_check: self.data_integrity_check && other.data_integrity_check,
+        }
+    }
+}
+
+impl Default for H5LinkConfig {
+    fn default() -> Self {
+        Self {
+            sliding_window_size: 1,
+            out_of_frame_flow_control: false,
+            data_integrity_check: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::sync(
+        H5Header { seq: 0, ack: 0, data_integrity_check_present: false, reliable: false, packet_type: H5PacketType::LinkControl, payload_length: 2 },
+        [0x00, 0x2F, 0x00, 0xD0]
+    )]
+    #[case::ack(
+        H5Header { seq: 0, ack: 1, data_integrity_check_present: false, reliable: false, packet_type: H5PacketType::Ack, payload_length: 0 },
+        [0x08, 0x00, 0x00, 0xF7]
+    )]
+    #[case::reliable_command(
+        H5Header { seq: 0, ack: 0, data_integrity_check_present: false, reliable: true, packet_type: H5PacketType::Command, payload_length: 3 },
+        [0x80, 0x31, 0x00, 0x4E]
+    )]
+    #[case::long_event_with_crc(
+        H5Header { seq: 7, ack: 5, data_integrity_check_present: true, reliable: true, packet_type: H5PacketType::Event, payload_length: 257 },
+        [0xEF, 0x14, 0x10, 0xEC]
+    )]
+    fn test_h5_header_encode_decode(#[case] header: H5Header, #[case] encoded: [u8; 4]) {
+        assert_eq!(header.encode(), encoded);
+        assert_eq!(H5Header::decode(&encoded), Some(header));
+    }
+
+    #[rstest]
+    #[case::invalid_checksum(&[0x00, 0x2F, 0x00, 0xD1])]
+    #[case::invalid_packet_type(&[0x00, 0x26, 0x00, 0xD9])]
+    #[case::too_short(&[0x00, 0x2F, 0x00])]
+    fn test_h5_header_decode_failure(#[case] input: &[u8]) {
+        assert_eq!(H5Header::decode(input), None);
+    }
+
+    #[test]
+    fn test_data_integrity_check() {
+        assert_eq!(data_integrity_check(b"123456789"), 0x89F6);
+    }
+
+    #[test]
+    fn test_next_seq() {
+        assert_eq!(next_seq(0), 1);
+        assert_eq!(next_seq(6), 7);
+        assert_eq!(next_seq(7), 0);
+    }
+
+    #[rstest]
+    #[case::sync(LinkControlMessage::Sync, &[0x01, 0x7E])]
+    #[case::sync_response(LinkControlMessage::SyncResponse, &[0x02, 0x7D])]
+    #[case::config(LinkControlMessage::Config(H5LinkConfig::default()), &[0x03, 0xFC, 0x01])]
+    #[case::config_response(
+        LinkControlMessage::ConfigResponse(H5LinkConfig { sliding_window_size: 4, out_of_frame_flow_control: true, data_integrity_check: true }),
+        &[0x04, 0x7B, 0x1C]
+    )]
+    #[case::wakeup(LinkControlMessage::Wakeup, &[0x05, 0xFA])]
+    #[case::woken(LinkControlMessage::Woken, &[0x06, 0xF9])]
+    #[case::sleep(LinkControlMessage::Sleep, &[0x07, 0x78])]
+    fn test_link_control_message_encode_decode(
+        #[case] message: LinkControlMessage,
+        #[case] encoded: &[u8],
+    ) {
+        let (data, len) = message.encode();
+        assert_eq!(&data[..len], encoded);
+        assert_eq!(LinkControlMessage::decode(encoded), Some(message));
+    }
+
+    #[test]
+    fn test_link_control_message_config_without_field() {
+        assert_eq!(
+            LinkControlMessage::decode(&[0x03, 0xFC]),
+            Some(LinkControlMessage::Config(H5LinkConfig::default()))
+        );
+    }
+
+    #[rstest]
+    #[case::unknown(&[0x01, 0x7F])]
+    #[case::too_short(&[0x01])]
+    fn test_link_control_message_decode_failure(#[case] input: &[u8]) {
+        assert_eq!(LinkControlMessage::decode(input), None);
+    }
+
+    #[test]
+    fn test_h5_link_config_negotiate() {
+        let local = H5LinkConfig {
+            sliding_window_size: 4,
+            out_of_frame_flow_control: true,
+            data_integrity_check: false,
+        };
+        let remote = H5LinkConfig {
+            sliding_window_size: 2,
+            out_of_frame_flow_control: true,
+            data_integrity_check: true,
+        };
+        assert_eq!(
+            local.negotiate(&remote),
+            H5LinkConfig {
+                sliding_window_size: 2,
+                out_of_frame_flow_control: true,
+                data_integrity_check: false,
+            }
+        );
+    }
+}
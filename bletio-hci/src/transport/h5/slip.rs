@@ -0,0 +1,213 @@
+use bletio_utils::{Buffer, BufferOps, Error as UtilsError};
+
+const SLIP_DELIMITER: u8 = 0xC0;
+const SLIP_ESCAPE: u8 = 0xDB;
+const SLIP_ESCAPED_DELIMITER: u8 = 0xDC;
+const SLIP_ESCAPED_ESCAPE: u8 = 0xDD;
+const SLIP_ESCAPED_XON: u8 = 0xDE;
+const SLIP_ESCAPED_XOFF: u8 = 0xDF;
+
+pub(crate) const XON: u8 = 0x11;
+pub(crate) const XOFF: u8 = 0x13;
+
+/// Encode a packet in a SLIP frame.
+///
+/// The XON and XOFF bytes are escaped when out-of-frame software flow control is in use.
+pub(crate) fn encode<B: BufferOps>(
+    packet: &[u8],
+    out_of_frame_flow_control: bool,
+    buffer: &mut B,
+) -> Result<usize, UtilsError> {
+    let mut len = buffer.try_push(SLIP_DELIMITER)?;
+    for &byte in packet {
+        len += match byte {
+            SLIP_DELIMITER => buffer.copy_from_slice(&[SLIP_ESCAPE, SLIP_ESCAPED_DELIMITER])?,
+            SLIP_ESCAPE => buffer.copy_from_slice(&[SLIP_ESCAPE, SLIP_ESCAPED_ESCAPE])?,
+            XON if out_of_frame_flow_control => {
+                buffer.copy_from_slice(&[SLIP_ESCAPE, SLIP_ESCAPED_XON])?
+            }
+            XOFF if out_of_frame_flow_control => {
+                buffer.copy_from_slice(&[SLIP_ESCAPE, SLIP_ESCAPED_XOFF])?
+            }
+            _ => buffer.try_push(byte)?,
+        };
+    }
+    len += buffer.try_push(SLIP_DELIMITER)?;
+    Ok(len)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub(crate) enum SlipEvent {
+    /// A complete frame has been received, it is available with [`SlipDecoder::frame`].
+    Frame,
+    /// An out-of-frame XON byte has been received.
+    Xon,
+    /// An out-of-frame XOFF byte has been received.
+    Xoff,
+}
+
+/// Incremental SLIP frame decoder.
+///
+/// Invalid frames (bad escape sequence or too long) are silently dropped.
+#[derive(Debug, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub(crate) struct SlipDecoder<const CAP: usize> {
+    frame: Buffer<CAP>,
+    in_frame: bool,
+    escaping: bool,
+    invalid: bool,
+}
+
+impl<const CAP: usize> SlipDecoder<CAP> {
+    pub(crate) fn frame(&self) -> &[u8] {
+        self.frame.data()
+    }
+
+    pub(crate) fn push(&mut self, byte: u8, out_of_frame_flow_control: bool) -> Option<SlipEvent> {
+        if !self.in_frame {
+            return match byte {
+                SLIP_DELIMITER => {
+                    self.start_frame();
+                    None
+                }
+                XON if out_of_frame_flow_control => Some(SlipEvent::Xon),
+                XOFF if out_of_frame_flow_control => Some(SlipEvent::Xoff),
+                _ => None,
+            };
+        }
+
+        if byte == SLIP_DELIMITER {
+            if self.frame.is_empty() && !self.escaping {
+                // Consecutive delimiters, consider the last one as the start of the frame.
+                self.start_frame();
+                return None;
+            }
+            self.in_frame = false;
+            return (!self.invalid && !self.escaping).then_some(SlipEvent::Frame);
+        }
+
+        let byte = if self.escaping {
+            self.escaping = false;
+            match byte {
+                SLIP_ESCAPED_DELIMITER => SLIP_DELIMITER,
+                SLIP_ESCAPED_ESCAPE => SLIP_ESCAPE,
+                SLIP_ESCAPED_XON => XON,
+                SLIP_ESCAPED_XOFF => XOFF,
+                _ => {
+                    self.invalid = true;
+                    return None;
+                }
+            }
+        } else if byte == SLIP_ESCAPE {
+            self.escaping = true;
+            return None;
+        } else {
+            byte
+        };
+        if self.frame.try_push(byte).is_err() {
+            self.invalid = true;
+        }
+        None
+    }
+
+    fn start_frame(&mut self) {
+        self.frame.clear();
+        self.in_frame = true;
+        self.escaping = false;
+        self.invalid = false;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn decode<const CAP: usize>(
+        decoder: &mut SlipDecoder<CAP>,
+        input: &[u8],
+        out_of_frame_flow_control: bool,
+    ) -> Option<SlipEvent> {
+        input
+            .iter()
+            .filter_map(|b| decoder.push(*b, out_of_frame_flow_control))
+            .last()
+    }
+
+    #[rstest]
+    #[case::plain(&[0x01, 0x7E], false, &[0xC0, 0x01, 0x7E, 0xC0])]
+    #[case::escape_delimiter_and_escape(&[0xC0, 0x01, 0xDB], false, &[0xC0, 0xDB, 0xDC, 0x01, 0xDB, 0xDD, 0xC0])]
+    #[case::no_flow_control(&[0x11, 0x13], false, &[0xC0, 0x11, 0x13, 0xC0])]
+    #[case::flow_control(&[0x11, 0x13], true, &[0xC0, 0xDB, 0xDE, 0xDB, 0xDF, 0xC0])]
+    fn test_slip_encode_decode(
+        #[case] packet: &[u8],
+        #[case] out_of_frame_flow_control: bool,
+        #[case] encoded: &[u8],
+    ) {
+        let mut buffer = Buffer::<16>::default();
+        let len = encode(packet, out_of_frame_flow_control, &mut buffer).unwrap();
+        assert_eq!(len, encoded.len());
+        assert_eq!(buffer.data(), encoded);
+
+        let mut decoder = SlipDecoder::<16>::default();
+        assert_eq!(
+            decode(&mut decoder, encoded, out_of_frame_flow_control),
+            Some(SlipEvent::Frame)
+        );
+        assert_eq!(decoder.frame(), packet);
+    }
+
+    #[test]
+    fn test_slip_encode_buffer_too_small() {
+        let mut buffer = Buffer::<4>::default();
+        assert_eq!(
+            encode(&[0xC0, 0xC0], false, &mut buffer),
+            Err(UtilsError::BufferTooSmall)
+        );
+    }
+
+    #[test]
+    fn test_slip_decode_skips_garbage_and_consecutive_delimiters() {
+        let mut decoder = SlipDecoder::<16>::default();
+        assert_eq!(
+            decode(
+                &mut decoder,
+                &[0x42, 0x43, 0xC0, 0xC0, 0x01, 0x02, 0xC0],
+                false
+            ),
+            Some(SlipEvent::Frame)
+        );
+        assert_eq!(decoder.frame(), &[0x01, 0x02]);
+    }
+
+    #[rstest]
+    #[case::invalid_escape(&[0xC0, 0x01, 0xDB, 0x01, 0xC0])]
+    #[case::unfinished_escape(&[0xC0, 0x01, 0xDB, 0xC0])]
+    #[case::too_long(&[0xC0, 0x01, 0x02, 0x03, 0x04, 0x05, 0xC0])]
+    fn test_slip_decode_invalid_frame(#[case] input: &[u8]) {
+        let mut decoder = SlipDecoder::<4>::default();
+        assert_eq!(decode(&mut decoder, input, false), None);
+        // The decoder recovers on the next frame
+        assert_eq!(
+            decode(&mut decoder, &[0xC0, 0x01, 0xC0], false),
+            Some(SlipEvent::Frame)
+        );
+        assert_eq!(decoder.frame(), &[0x01]);
+    }
+
+    #[rstest]
+    #[case::xon(XON, true, Some(SlipEvent::Xon))]
+    #[case::xoff(XOFF, true, Some(SlipEvent::Xoff))]
+    #[case::xon_without_flow_control(XON, false, None)]
+    #[case::xoff_without_flow_control(XOFF, false, None)]
+    fn test_slip_decode_out_of_frame_flow_control(
+        #[case] byte: u8,
+        #[case] out_of_frame_flow_control: bool,
+        #[case] expected: Option<SlipEvent>,
+    ) {
+        let mut decoder = SlipDecoder::<16>::default();
+        assert_eq!(decoder.push(byte, out_of_frame_flow_control), expected);
+    }
+}
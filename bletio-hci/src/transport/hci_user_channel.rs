@@ -0,0 +1,141 @@
+extern crate std;
+
+use std::io;
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+
+use tokio::io::unix::AsyncFd;
+
+use crate::{HciDriver, HciDriverError};
+
+const BTPROTO_HCI: libc::c_int = 1;
+const HCI_CHANNEL_USER: u16 = 1;
+
+#[repr(C)]
+struct SockaddrHci {
+    hci_family: libc::sa_family_t,
+    hci_dev: u16,
+    hci_channel: u16,
+}
+
+/// HCI driver using a Linux Bluetooth socket bound to the user channel of a local adapter.
+///
+/// The user channel gives an exclusive access to the adapter, bypassing the Linux Bluetooth stack.
+/// The adapter needs to be down (e.g. `hciconfig hci0 down` or `btmgmt --index 0 power off`) and
+/// the process needs the `CAP_NET_ADMIN` capability. Each read or write on the socket is a single
+/// HCI packet preceded by its packet indicator.
+#[derive(Debug)]
+pub struct HciUserChannelDriver {
+    fd: AsyncFd<OwnedFd>,
+}
+
+impl HciUserChannelDriver {
+    /// Open the user channel of the adapter with the given index, e.g. 0 for `hci0`.
+    ///
+    /// This needs to be called from within a tokio runtime.
+    pub fn open(dev_id: u16) -> io::Result<Self> {
+        // SAFETY: Plain socket creation, the returned file descriptor is checked before use.
+        let fd = unsafe {
+            libc::socket(
+                libc::AF_BLUETOOTH,
+                libc::SOCK_RAW | libc::SOCK_CLOEXEC | libc::SOCK_NONBLOCK,
+                BTPROTO_HCI,
+            )
+        };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        // SAFETY: The file descriptor has just been created and is owned by nobody else.
+        let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+
+        let addr = SockaddrHci {
+            hci_family: libc::AF_BLUETOOTH as libc::sa_family_t,
+            hci_dev: dev_id,
+            hci_channel: HCI_CHANNEL_USER,
+        };
+        // SAFETY: The address is a valid `sockaddr_hci` structure whose size is given.
+        let res = unsafe {
+            libc::bind(
+                fd.as_raw_fd(),
+                &addr as *const SockaddrHci as *const libc::sockaddr,
+                size_of::<SockaddrHci>() as libc::socklen_t,
+            )
+        };
+        if res < 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        // SAFETY: The file descriptor is owned by the `AsyncFd` and is neither replaced nor closed
+        // while registered.
+        let fd = unsafe { AsyncFd::register(fd) }.map_err(io::Error::from)?;
+        Ok(Self { fd })
+    }
+}
+
+impl HciDriver for HciUserChannelDriver {
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, HciDriverError> {
+        loop {
+            let mut guard = self
+                .fd
+                .readable()
+                .await
+                .map_err(|_| HciDriverError::ReadFailure)?;
+            match guard.try_io(|fd| {
+                // SAFETY: The buffer is valid for writes of its length.
+                let res = unsafe {
+                    libc::read(
+                        fd.as_raw_fd(),
+                        buf.as_mut_ptr() as *mut libc::c_void,
+                        buf.len(),
+                    )
+                };
+                if res < 0 {
+                    Err(io::Error::last_os_error())
+                } else {
+                    Ok(res as usize)
+                }
+            }) {
+                Ok(res) => return res.map_err(|_| HciDriverError::ReadFailure),
+                Err(_would_block) => continue,
+            }
+        }
+    }
+
+    async fn write(&mut self, buf: &[u8]) -> Result<usize, HciDriverError> {
+        loop {
+            let mut guard = self
+                .fd
+                .writable()
+                .await
+                .map_err(|_| HciDriverError::WriteFailure)?;
+            match guard.try_io(|fd| {
+                // SAFETY: The buffer is valid for reads of its length.
+                let res = unsafe {
+                    libc::write(
+                        fd.as_raw_fd(),
+                        buf.as_ptr() as *const libc::c_void,
+                        buf.len(),
+                    )
+                };
+                if res < 0 {
+                    Err(io::Error::last_os_error())
+                } else {
+                    Ok(res as usize)
+                }
+            }) {
+                Ok(res) => return res.map_err(|_| HciDriverError::WriteFailure),
+                Err(_would_block) => continue,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_hci_user_channel_open_unknown_adapter() {
+        // Either Bluetooth sockets are not available or the adapter does not exist.
+        assert!(HciUserChannelDriver::open(0xFFFE).is_err());
+    }
+}
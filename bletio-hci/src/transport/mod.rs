@@ -0,0 +1,43 @@
+//! HCI transport layers.
+//!
+//! A transport layer implements the [`HciDriver`](crate::HciDriver) trait on top of a
+//! [`SerialPort`], taking care of the framing of the HCI packets.
+
+use core::future::Future;
+
+use crate::HciDriverError;
+
+pub(crate) mod h4;
+pub(crate) mod h5;
+
+#[cfg(all(feature = "std", target_os = "linux"))]
+mod hci_user_channel;
+#[cfg(feature = "embedded-io-async")]
+mod serial_port_embedded_io;
+#[cfg(feature = "tokio")]
+mod serial_port_tokio;
+#[cfg(feature = "std")]
+mod stream;
+
+#[cfg(all(feature = "std", target_os = "linux"))]
+pub use hci_user_channel::HciUserChannelDriver;
+#[cfg(feature = "embedded-io-async")]
+pub use serial_port_embedded_io::EmbeddedIoSerialPort;
+#[cfg(feature = "tokio")]
+pub use serial_port_tokio::TokioSerialPort;
+#[cfg(feature = "std")]
+pub use stream::StreamHciDriver;
+
+/// Byte stream on which an HCI transport layer is running, typically an UART.
+pub trait SerialPort {
+    /// Read some bytes, returning the number of bytes that have been read.
+    ///
+    /// Reading 0 bytes means that the serial port has been closed.
+    ///
+    /// The read must be cancellation-safe: if its future is dropped before completion, no byte
+    /// has been consumed from the serial port.
+    fn read(&mut self, buf: &mut [u8]) -> impl Future<Output = Result<usize, HciDriverError>>;
+
+    /// Write all the given bytes.
+    fn write_all(&mut self, buf: &[u8]) -> impl Future<Output = Result<(), HciDriverError>>;
+}
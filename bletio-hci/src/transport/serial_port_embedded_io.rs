@@ -0,0 +1,48 @@
+use embedded_io_async::{Read, Write};
+
+use crate::{HciDriverError, SerialPort};
+
+/// [`SerialPort`] adapter for `embedded-io-async` byte streams, typically an UART peripheral.
+#[derive(Debug)]
+pub struct EmbeddedIoSerialPort<T>
+where
+    T: Read + Write,
+{
+    inner: T,
+}
+
+impl<T> EmbeddedIoSerialPort<T>
+where
+    T: Read + Write,
+{
+    pub fn new(inner: T) -> Self {
+        Self { inner }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T> SerialPort for EmbeddedIoSerialPort<T>
+where
+    T: Read + Write,
+{
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, HciDriverError> {
+        self.inner
+            .read(buf)
+            .await
+            .map_err(|_| HciDriverError::ReadFailure)
+    }
+
+    async fn write_all(&mut self, buf: &[u8]) -> Result<(), HciDriverError> {
+        self.inner
+            .write_all(buf)
+            .await
+            .map_err(|_| HciDriverError::WriteFailure)?;
+        self.inner
+            .flush()
+            .await
+            .map_err(|_| HciDriverError::WriteFailure)
+    }
+}
@@ -0,0 +1,48 @@
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+use crate::{HciDriverError, SerialPort};
+
+/// [`SerialPort`] adapter for tokio `AsyncRead + AsyncWrite` byte streams.
+#[derive(Debug)]
+pub struct TokioSerialPort<T>
+where
+    T: AsyncRead + AsyncWrite + Unpin,
+{
+    inner: T,
+}
+
+impl<T> TokioSerialPort<T>
+where
+    T: AsyncRead + AsyncWrite + Unpin,
+{
+    pub fn new(inner: T) -> Self {
+        Self { inner }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T> SerialPort for TokioSerialPort<T>
+where
+    T: AsyncRead + AsyncWrite + Unpin,
+{
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, HciDriverError> {
+        self.inner
+            .read(buf)
+            .await
+            .map_err(|_| HciDriverError::ReadFailure)
+    }
+
+    async fn write_all(&mut self, buf: &[u8]) -> Result<(), HciDriverError> {
+        self.inner
+            .write_all(buf)
+            .await
+            .map_err(|_| HciDriverError::WriteFailure)?;
+        self.inner
+            .flush()
+            .await
+            .map_err(|_| HciDriverError::WriteFailure)
+    }
+}
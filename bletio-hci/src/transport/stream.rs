@@ -0,0 +1,102 @@
+extern crate std;
+
+use std::io;
+
+#[cfg(unix)]
+use std::path::Path;
+#[cfg(unix)]
+use tokio::net::UnixStream;
+use tokio::net::{TcpStream, ToSocketAddrs};
+
+use crate::{H4Transport, TokioSerialPort};
+
+/// HCI driver using the H4 framing over a tokio byte stream.
+///
+/// This is typically used to talk to a virtual controller, such as a local emulator process,
+/// listening on a TCP port or a Unix socket.
+pub type StreamHciDriver<T> = H4Transport<TokioSerialPort<T>>;
+
+impl StreamHciDriver<TcpStream> {
+    /// Connect to a controller listening on a TCP endpoint.
+    pub async fn connect_tcp(addr: impl ToSocketAddrs) -> io::Result<Self> {
+        let stream = TcpStream::connect(addr).await?;
+        stream.set_nodelay(true)?;
+        Ok(H4Transport::new(TokioSerialPort::new(stream)))
+    }
+}
+
+#[cfg(unix)]
+impl StreamHciDriver<UnixStream> {
+    /// Connect to a controller listening on a Unix socket.
+    pub async fn connect_unix(path: impl AsRef<Path>) -> io::Result<Self> {
+        let stream = UnixStream::connect(path).await?;
+        Ok(H4Transport::new(TokioSerialPort::new(stream)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    use super::*;
+    use crate::HciDriver;
+
+    const RESET_COMMAND: &[u8] = &[0x01, 0x03, 0x0C, 0x00];
+    const RESET_COMMAND_COMPLETE_EVENT: &[u8] = &[0x04, 0x0E, 0x04, 0x01, 0x03, 0x0C, 0x00];
+
+    async fn check_exchange<D, S>(driver: &mut D, controller: &mut S)
+    where
+        D: HciDriver,
+        S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
+    {
+        assert_eq!(
+            driver.write(RESET_COMMAND).await.unwrap(),
+            RESET_COMMAND.len()
+        );
+        let mut command = [0u8; 4];
+        controller.read_exact(&mut command).await.unwrap();
+        assert_eq!(&command, RESET_COMMAND);
+
+        controller
+            .write_all(RESET_COMMAND_COMPLETE_EVENT)
+            .await
+            .unwrap();
+        let mut buf = [0u8; 259];
+        let len = driver.read(&mut buf).await.unwrap();
+        assert_eq!(&buf[..len], RESET_COMMAND_COMPLETE_EVENT);
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_stream_hci_driver_tcp() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (driver, accepted) =
+            tokio::join!(StreamHciDriver::connect_tcp(addr), listener.accept());
+        let mut driver = driver.unwrap();
+        let (mut controller, _) = accepted.unwrap();
+        check_exchange(&mut driver, &mut controller).await;
+    }
+
+    #[cfg(unix)]
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_stream_hci_driver_unix() {
+        let path =
+            std::env::temp_dir().join(std::format!("bletio-hci-test-{}.sock", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let listener = tokio::net::UnixListener::bind(&path).unwrap();
+        let (driver, accepted) =
+            tokio::join!(StreamHciDriver::connect_unix(&path), listener.accept());
+        let mut driver = driver.unwrap();
+        let (mut controller, _) = accepted.unwrap();
+        check_exchange(&mut driver, &mut controller).await;
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_stream_hci_driver_connection_refused() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        drop(listener);
+        assert!(StreamHciDriver::connect_tcp(addr).await.is_err());
+    }
+}
@@ -4,6 +4,7 @@ use bletio_utils::EncodeToBuffer;
 use crate::assigned_numbers::AdType;
 
 const ADVERTISING_INTERVAL_AD_STRUCT_SIZE: usize = 3;
+const ADVERTISING_INTERVAL_LONG_3_BYTES_MAX: u32 = 0x00FF_FFFF;
 
 /// The advertising interval.
 ///
@@ -43,10 +44,56 @@ impl EncodeToBuffer for AdvertisingIntervalAdStruct {
     }
 }
 
+/// The advertising interval, for intervals that do not fit in an
+/// [AdvertisingIntervalAdStruct].
+///
+/// The value is in units of 0.625 ms and is encoded on 3 octets, or on 4 octets if it does not
+/// fit on 3 octets. The long advertising interval is defined in
+/// [Supplement to the Bluetooth Core Specification, Part A, 1.15](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/CSS_v12/CSS/out/en/supplement-to-the-bluetooth-core-specification/data-types-specification.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AdvertisingIntervalLongAdStruct {
+    interval: u32,
+}
+
+impl AdvertisingIntervalLongAdStruct {
+    pub(crate) const fn new(interval: u32) -> Self {
+        Self { interval }
+    }
+
+    /// Get the advertising interval, in units of 0.625 ms.
+    pub fn value(&self) -> u32 {
+        self.interval
+    }
+}
+
+impl EncodeToBuffer for AdvertisingIntervalLongAdStruct {
+    fn encode<B: bletio_utils::BufferOps>(
+        &self,
+        buffer: &mut B,
+    ) -> Result<usize, bletio_utils::Error> {
+        let size = self.encoded_size();
+        buffer.try_push((size - 1) as u8)?;
+        buffer.try_push(AdType::AdvertisingIntervalLong as u8)?;
+        buffer.copy_from_slice(&self.interval.to_le_bytes()[..size - 2])?;
+        Ok(size)
+    }
+
+    fn encoded_size(&self) -> usize {
+        if self.interval > ADVERTISING_INTERVAL_LONG_3_BYTES_MAX {
+            6
+        } else {
+            5
+        }
+    }
+}
+
 pub(crate) mod parser {
     use nom::{
-        combinator::{map, map_res},
-        number::complete::le_u16,
+        branch::alt,
+        combinator::{eof, map, map_res, verify},
+        number::complete::{le_u16, le_u24, le_u32},
+        sequence::terminated,
         IResult, Parser,
     };
 
@@ -60,6 +107,21 @@ pub(crate) mod parser {
         })
         .parse(input)
     }
+
+    pub(crate) fn advertising_interval_long_ad_struct(input: &[u8]) -> IResult<&[u8], AdStruct> {
+        map(
+            alt((
+                terminated(le_u24, eof),
+                verify(terminated(le_u32, eof), |interval| {
+                    *interval > ADVERTISING_INTERVAL_LONG_3_BYTES_MAX
+                }),
+            )),
+            |interval| {
+                AdStruct::AdvertisingIntervalLong(AdvertisingIntervalLongAdStruct::new(interval))
+            },
+        )
+        .parse(input)
+    }
 }
 
 #[cfg(test)]
@@ -109,4 +171,33 @@ mod test {
     fn test_advertising_interval_ad_struct_parsing_failure(#[case] input: &[u8]) {
         assert!(advertising_interval_ad_struct(input).is_err());
     }
+
+    #[rstest]
+    #[case(0x0000_0020, &[0x04, 0x2F, 0x20, 0x00, 0x00])]
+    #[case(0x00FF_FFFF, &[0x04, 0x2F, 0xFF, 0xFF, 0xFF])]
+    #[case(0x0100_0000, &[0x05, 0x2F, 0x00, 0x00, 0x00, 0x01])]
+    fn test_advertising_interval_long_ad_struct(
+        #[case] interval: u32,
+        #[case] encoded_data: &[u8],
+    ) -> Result<(), bletio_utils::Error> {
+        let mut buffer = Buffer::<6>::default();
+        let ad_struct = AdvertisingIntervalLongAdStruct::new(interval);
+        ad_struct.encode(&mut buffer)?;
+        assert_eq!(buffer.data(), encoded_data);
+        assert_eq!(ad_struct.encoded_size(), encoded_data.len());
+        assert_eq!(ad_struct.value(), interval);
+        assert_eq!(
+            advertising_interval_long_ad_struct(&encoded_data[2..]),
+            Ok((&[] as &[u8], AdStruct::AdvertisingIntervalLong(ad_struct)))
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(&[0x20, 0x00])]
+    #[case(&[0x20, 0x00, 0x00, 0x00])]
+    #[case(&[0x20, 0x00, 0x00, 0x00, 0x00])]
+    fn test_advertising_interval_long_ad_struct_parsing_failure(#[case] input: &[u8]) {
+        assert!(advertising_interval_long_ad_struct(input).is_err());
+    }
 }
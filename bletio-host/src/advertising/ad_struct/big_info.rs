@@ -0,0 +1,284 @@
+use bletio_hci::{Framing, Phy};
+use bletio_utils::EncodeToBuffer;
+use heapless::Vec;
+
+use crate::assigned_numbers::AdType;
+
+const BIG_INFO_UNENCRYPTED_LENGTH: usize = 33;
+const BIG_INFO_ENCRYPTED_LENGTH: usize = 57;
+const GIV_LENGTH: usize = 8;
+const GSKD_LENGTH: usize = 16;
+
+/// The information needed to synchronize to a Broadcast Isochronous Group.
+///
+/// The BIGInfo is defined in
+/// [Core Specification 6.0, Vol. 6, Part B, 4.4.6.11](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/low-energy-controller/link-layer-specification.html).
+///
+/// This Advertising Structure is meant to be used in the Additional Controller Advertising Data
+/// of a periodic advertising train. It is 33 octets long, or 57 octets long when the Broadcast
+/// Isochronous Group is encrypted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BigInfoAdStruct {
+    data: Vec<u8, BIG_INFO_ENCRYPTED_LENGTH>,
+}
+
+impl BigInfoAdStruct {
+    fn bits(&self, start: usize, len: usize) -> u64 {
+        (0..len).fold(0u64, |value, i| {
+            let bit = start + i;
+            value | ((((self.data[bit / 8] >> (bit % 8)) & 1) as u64) << i)
+        })
+    }
+
+    /// Get the offset from the periodic advertising packet to the first BIS anchor point,
+    /// in units of [`big_offset_units`](Self::big_offset_units).
+    pub fn big_offset(&self) -> u16 {
+        self.bits(0, 14) as u16
+    }
+
+    /// Get the units of the [`big_offset`](Self::big_offset), in microseconds.
+    pub fn big_offset_units(&self) -> u16 {
+        if self.bits(14, 1) == 0 {
+            30
+        } else {
+            300
+        }
+    }
+
+    /// Get the ISO interval, in units of 1.25 ms.
+    pub fn iso_interval(&self) -> u16 {
+        self.bits(15, 12) as u16
+    }
+
+    /// Get the number of BISes in the BIG.
+    pub fn num_bis(&self) -> u8 {
+        self.bits(27, 5) as u8
+    }
+
+    /// Get the number of subevents per BIS in each BIG event.
+    pub fn nse(&self) -> u8 {
+        self.bits(32, 5) as u8
+    }
+
+    /// Get the burst number.
+    pub fn bn(&self) -> u8 {
+        self.bits(37, 3) as u8
+    }
+
+    /// Get the interval between the start of consecutive subevents, in microseconds.
+    pub fn sub_interval(&self) -> u32 {
+        self.bits(40, 20) as u32
+    }
+
+    /// Get the pre-transmission offset.
+    pub fn pto(&self) -> u8 {
+        self.bits(60, 4) as u8
+    }
+
+    /// Get the interval between the start of consecutive BISes, in microseconds.
+    pub fn bis_spacing(&self) -> u32 {
+        self.bits(64, 20) as u32
+    }
+
+    /// Get the immediate repetition count.
+    pub fn irc(&self) -> u8 {
+        self.bits(84, 4) as u8
+    }
+
+    /// Get the maximum number of data octets that can be carried in each BIS Data PDU.
+    pub fn max_pdu(&self) -> u8 {
+        self.bits(88, 8) as u8
+    }
+
+    /// Get the seed for the Access Addresses of the BISes.
+    pub fn seed_access_address(&self) -> u32 {
+        self.bits(104, 32) as u32
+    }
+
+    /// Get the SDU interval, in microseconds.
+    pub fn sdu_interval(&self) -> u32 {
+        self.bits(136, 20) as u32
+    }
+
+    /// Get the maximum size of an SDU.
+    pub fn max_sdu(&self) -> u16 {
+        self.bits(156, 12) as u16
+    }
+
+    /// Get the base CRC initialization value of the BISes.
+    pub fn base_crc_init(&self) -> u16 {
+        self.bits(168, 16) as u16
+    }
+
+    /// Get the channel map used by the BIG, one bit per channel.
+    pub fn channel_map(&self) -> u64 {
+        self.bits(184, 37)
+    }
+
+    /// Get the PHY used by the BIG.
+    pub fn phy(&self) -> Phy {
+        match self.bits(221, 3) {
+            0 => Phy::Le1M,
+            1 => Phy::Le2M,
+            // The PHY value is checked when parsing.
+            _ => Phy::LeCoded,
+        }
+    }
+
+    /// Get the payload count of the first BIS PDU of the BIG event.
+    pub fn bis_payload_count(&self) -> u64 {
+        self.bits(224, 39)
+    }
+
+    /// Get the framing mode of the BIG.
+    pub fn framing(&self) -> Framing {
+        if self.bits(263, 1) == 0 {
+            Framing::Unframed
+        } else {
+            Framing::Framed
+        }
+    }
+
+    /// Tell whether the BIG is encrypted.
+    pub fn is_encrypted(&self) -> bool {
+        self.data.len() == BIG_INFO_ENCRYPTED_LENGTH
+    }
+
+    /// Get the group initialization vector, if the BIG is encrypted.
+    pub fn giv(&self) -> Option<&[u8]> {
+        self.is_encrypted().then(|| {
+            &self.data[BIG_INFO_UNENCRYPTED_LENGTH..BIG_INFO_UNENCRYPTED_LENGTH + GIV_LENGTH]
+        })
+    }
+
+    /// Get the group session key diversifier, if the BIG is encrypted.
+    pub fn gskd(&self) -> Option<&[u8]> {
+        self.is_encrypted().then(|| {
+            &self.data[BIG_INFO_UNENCRYPTED_LENGTH + GIV_LENGTH
+                ..BIG_INFO_UNENCRYPTED_LENGTH + GIV_LENGTH + GSKD_LENGTH]
+        })
+    }
+}
+
+impl EncodeToBuffer for BigInfoAdStruct {
+    fn encode<B: bletio_utils::BufferOps>(
+        &self,
+        buffer: &mut B,
+    ) -> Result<usize, bletio_utils::Error> {
+        buffer.try_push((self.encoded_size() - 1) as u8)?;
+        buffer.try_push(AdType::BigInfo as u8)?;
+        buffer.copy_from_slice(self.data.as_slice())?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        self.data.len() + 2
+    }
+}
+
+pub(crate) mod parser {
+    use nom::{
+        bytes::take,
+        combinator::{map, verify},
+        IResult, Parser,
+    };
+
+    use crate::advertising::ad_struct::AdStruct;
+
+    use super::*;
+
+    pub(crate) fn big_info_ad_struct(input: &[u8]) -> IResult<&[u8], AdStruct> {
+        map(
+            verify(take(input.len()), |data: &[u8]| {
+                (data.len() == BIG_INFO_UNENCRYPTED_LENGTH
+                    || data.len() == BIG_INFO_ENCRYPTED_LENGTH)
+                    && (data[27] >> 5) <= 2
+            }),
+            |data: &[u8]| {
+                AdStruct::BigInfo(BigInfoAdStruct {
+                    // The length has been verified above.
+                    data: Vec::from_slice(data).unwrap(),
+                })
+            },
+        )
+        .parse(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bletio_utils::{Buffer, BufferOps};
+    use rstest::rstest;
+
+    use crate::advertising::ad_struct::AdStruct;
+
+    use super::{parser::*, *};
+
+    const BIG_INFO: [u8; 33] = [
+        0x64, 0x40, 0x03, 0x10, 0x02, 0xE8, 0x13, 0x00, 0x20, 0x4E, 0x10, 0x78, 0x00, 0xD6, 0xBE,
+        0x89, 0x8E, 0x10, 0x27, 0x80, 0x07, 0x34, 0x12, 0xFF, 0xFF, 0xFF, 0xFF, 0x3F, 0x05, 0x00,
+        0x00, 0x00, 0x80,
+    ];
+
+    fn parse(data: &[u8]) -> BigInfoAdStruct {
+        match big_info_ad_struct(data) {
+            Ok((&[], AdStruct::BigInfo(big_info))) => big_info,
+            _ => panic!("invalid BIGInfo"),
+        }
+    }
+
+    #[test]
+    fn test_big_info_ad_struct_unencrypted() -> Result<(), bletio_utils::Error> {
+        let big_info = parse(&BIG_INFO);
+        assert_eq!(big_info.big_offset(), 100);
+        assert_eq!(big_info.big_offset_units(), 300);
+        assert_eq!(big_info.iso_interval(), 6);
+        assert_eq!(big_info.num_bis(), 2);
+        assert_eq!(big_info.nse(), 2);
+        assert_eq!(big_info.bn(), 0);
+        assert_eq!(big_info.sub_interval(), 5_096);
+        assert_eq!(big_info.pto(), 0);
+        assert_eq!(big_info.bis_spacing(), 20_000);
+        assert_eq!(big_info.irc(), 1);
+        assert_eq!(big_info.max_pdu(), 120);
+        assert_eq!(big_info.seed_access_address(), 0x8E89BED6);
+        assert_eq!(big_info.sdu_interval(), 10_000);
+        assert_eq!(big_info.max_sdu(), 120);
+        assert_eq!(big_info.base_crc_init(), 0x1234);
+        assert_eq!(big_info.channel_map(), 0x1F_FFFF_FFFF);
+        assert_eq!(big_info.phy(), Phy::Le2M);
+        assert_eq!(big_info.bis_payload_count(), 5);
+        assert_eq!(big_info.framing(), Framing::Framed);
+        assert!(!big_info.is_encrypted());
+        assert_eq!(big_info.giv(), None);
+        assert_eq!(big_info.gskd(), None);
+
+        let mut buffer = Buffer::<35>::default();
+        big_info.encode(&mut buffer)?;
+        assert_eq!(&buffer.data()[..2], &[0x22, 0x2C]);
+        assert_eq!(&buffer.data()[2..], &BIG_INFO);
+        Ok(())
+    }
+
+    #[test]
+    fn test_big_info_ad_struct_encrypted() {
+        let mut data = [0u8; 57];
+        data[..33].copy_from_slice(&BIG_INFO);
+        data[33..41].copy_from_slice(&[0x01; 8]);
+        data[41..].copy_from_slice(&[0x02; 16]);
+        let big_info = parse(&data);
+        assert!(big_info.is_encrypted());
+        assert_eq!(big_info.giv(), Some([0x01; 8].as_slice()));
+        assert_eq!(big_info.gskd(), Some([0x02; 16].as_slice()));
+        assert_eq!(big_info.encoded_size(), 59);
+    }
+
+    #[rstest]
+    #[case(&BIG_INFO[..32])]
+    #[case(&[0u8; 34])]
+    #[case(&[0xFFu8; 33])]
+    fn test_big_info_ad_struct_parsing_failure(#[case] input: &[u8]) {
+        assert!(big_info_ad_struct(input).is_err());
+    }
+}
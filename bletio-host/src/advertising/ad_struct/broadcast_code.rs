@@ -0,0 +1,104 @@
+use bletio_hci::BroadcastCode;
+use bletio_utils::EncodeToBuffer;
+
+use crate::assigned_numbers::AdType;
+
+const BROADCAST_CODE_AD_STRUCT_SIZE: usize = 17;
+
+/// The Broadcast Code used to encrypt the Broadcast Isochronous Streams of a Broadcast
+/// Isochronous Group.
+///
+/// This Advertising Structure shall only be used in an Out-of-Band data block, as defined in
+/// [Supplement to the Bluetooth Core Specification, Part A, 1.22](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/CSS_v12/CSS/out/en/supplement-to-the-bluetooth-core-specification/data-types-specification.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BroadcastCodeAdStruct {
+    code: BroadcastCode,
+}
+
+impl BroadcastCodeAdStruct {
+    pub(crate) const fn new(code: BroadcastCode) -> Self {
+        Self { code }
+    }
+
+    pub fn value(&self) -> &BroadcastCode {
+        &self.code
+    }
+}
+
+impl EncodeToBuffer for BroadcastCodeAdStruct {
+    fn encode<B: bletio_utils::BufferOps>(
+        &self,
+        buffer: &mut B,
+    ) -> Result<usize, bletio_utils::Error> {
+        buffer.try_push(BROADCAST_CODE_AD_STRUCT_SIZE as u8)?;
+        buffer.try_push(AdType::BroadcastCode as u8)?;
+        self.code.encode(buffer)?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        BROADCAST_CODE_AD_STRUCT_SIZE + 1
+    }
+}
+
+pub(crate) mod parser {
+    use nom::{
+        bytes::take,
+        combinator::{map, map_res},
+        IResult, Parser,
+    };
+
+    use crate::advertising::ad_struct::AdStruct;
+
+    use super::*;
+
+    fn broadcast_code(input: &[u8]) -> IResult<&[u8], BroadcastCode> {
+        map(
+            map_res(take(16u8), TryInto::<[u8; 16]>::try_into),
+            BroadcastCode::new,
+        )
+        .parse(input)
+    }
+
+    pub(crate) fn broadcast_code_ad_struct(input: &[u8]) -> IResult<&[u8], AdStruct> {
+        map(broadcast_code, |code| {
+            AdStruct::BroadcastCode(BroadcastCodeAdStruct::new(code))
+        })
+        .parse(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bletio_utils::{Buffer, BufferOps};
+
+    use crate::advertising::ad_struct::AdStruct;
+
+    use super::{parser::*, *};
+
+    const CODE: [u8; 16] = *b"bletio-broadcast";
+
+    #[test]
+    fn test_broadcast_code_ad_struct() -> Result<(), bletio_utils::Error> {
+        let mut buffer = Buffer::<18>::default();
+        let ad_struct = BroadcastCodeAdStruct::new(BroadcastCode::new(CODE));
+        ad_struct.encode(&mut buffer)?;
+        assert_eq!(&buffer.data()[..2], &[0x11, 0x2D]);
+        assert_eq!(&buffer.data()[2..], &CODE);
+        assert_eq!(ad_struct.value().value(), &CODE);
+        Ok(())
+    }
+
+    #[test]
+    fn test_broadcast_code_ad_struct_parsing() {
+        assert_eq!(
+            broadcast_code_ad_struct(&CODE),
+            Ok((
+                &[] as &[u8],
+                AdStruct::BroadcastCode(BroadcastCodeAdStruct::new(BroadcastCode::new(CODE)))
+            ))
+        );
+        assert!(broadcast_code_ad_struct(&CODE[..8]).is_err());
+    }
+}
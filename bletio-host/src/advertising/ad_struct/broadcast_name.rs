@@ -0,0 +1,121 @@
+use bletio_utils::EncodeToBuffer;
+use heapless::String;
+
+use crate::{advertising::AdvertisingError, assigned_numbers::AdType};
+
+const BROADCAST_NAME_MIN_LENGTH: usize = 4;
+const BROADCAST_NAME_MAX_LENGTH: usize = 29;
+
+/// The name of a broadcast source, as defined in the Public Broadcast Profile specification.
+///
+/// It is a UTF-8 string of at least 4 octets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BroadcastNameAdStruct {
+    name: String<BROADCAST_NAME_MAX_LENGTH>,
+}
+
+impl BroadcastNameAdStruct {
+    pub(crate) fn try_new(name: &str) -> Result<Self, AdvertisingError> {
+        if name.len() < BROADCAST_NAME_MIN_LENGTH {
+            return Err(AdvertisingError::InvalidBroadcastName);
+        }
+        Ok(Self {
+            name: name
+                .try_into()
+                .map_err(|_| AdvertisingError::AdvertisingDataWillNotFitAdvertisingPacket)?,
+        })
+    }
+
+    pub fn value(&self) -> &str {
+        self.name.as_str()
+    }
+}
+
+impl EncodeToBuffer for BroadcastNameAdStruct {
+    fn encode<B: bletio_utils::BufferOps>(
+        &self,
+        buffer: &mut B,
+    ) -> Result<usize, bletio_utils::Error> {
+        buffer.try_push((self.encoded_size() - 1) as u8)?;
+        buffer.try_push(AdType::BroadcastName as u8)?;
+        buffer.copy_from_slice(self.name.as_bytes())?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        self.name.len() + 2
+    }
+}
+
+pub(crate) mod parser {
+    use nom::{
+        bytes::take,
+        combinator::{map, map_res},
+        IResult, Parser,
+    };
+
+    use crate::advertising::ad_struct::AdStruct;
+
+    use super::*;
+
+    pub(crate) fn broadcast_name_ad_struct(input: &[u8]) -> IResult<&[u8], AdStruct> {
+        map(
+            map_res(
+                map_res(take(input.len()), core::str::from_utf8),
+                BroadcastNameAdStruct::try_new,
+            ),
+            AdStruct::BroadcastName,
+        )
+        .parse(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bletio_utils::{Buffer, BufferOps};
+    use rstest::rstest;
+
+    use crate::advertising::ad_struct::AdStruct;
+
+    use super::{parser::*, *};
+
+    #[rstest]
+    #[case("Gate", &[0x05, 0x30, b'G', b'a', b't', b'e'])]
+    #[case("Café 42", &[0x09, 0x30, b'C', b'a', b'f', 0xC3, 0xA9, b' ', b'4', b'2'])]
+    fn test_broadcast_name_ad_struct_success(
+        #[case] name: &str,
+        #[case] encoded_data: &[u8],
+    ) -> Result<(), bletio_utils::Error> {
+        let mut buffer = Buffer::<31>::default();
+        let ad_struct = BroadcastNameAdStruct::try_new(name).unwrap();
+        ad_struct.encode(&mut buffer)?;
+        assert_eq!(buffer.data(), encoded_data);
+        assert_eq!(ad_struct.value(), name);
+        assert_eq!(
+            broadcast_name_ad_struct(&encoded_data[2..]),
+            Ok((&[] as &[u8], AdStruct::BroadcastName(ad_struct)))
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("abc", AdvertisingError::InvalidBroadcastName)]
+    #[case(
+        "A broadcast name that is too long",
+        AdvertisingError::AdvertisingDataWillNotFitAdvertisingPacket
+    )]
+    fn test_broadcast_name_ad_struct_failure(
+        #[case] name: &str,
+        #[case] expected_error: AdvertisingError,
+    ) {
+        assert_eq!(BroadcastNameAdStruct::try_new(name), Err(expected_error));
+    }
+
+    #[rstest]
+    #[case(b"abc")]
+    #[case(&[b'a', b'b', b'c', 0xFF])]
+    fn test_broadcast_name_ad_struct_parsing_failure(#[case] input: &[u8]) {
+        assert!(broadcast_name_ad_struct(input).is_err());
+    }
+}
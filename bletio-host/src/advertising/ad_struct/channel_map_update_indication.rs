@@ -0,0 +1,143 @@
+use bletio_utils::EncodeToBuffer;
+
+use crate::advertising::AdvertisingError;
+use crate::assigned_numbers::AdType;
+
+const CHANNEL_MAP_UPDATE_INDICATION_AD_STRUCT_SIZE: usize = 8;
+const CHANNEL_MAP_MASK: u64 = 0x1F_FFFF_FFFF;
+
+/// The indication of an update of the channel map used by a periodic advertising train or a
+/// Broadcast Isochronous Group.
+///
+/// This Advertising Structure is meant to be used in the Additional Controller Advertising Data,
+/// as defined in
+/// [Supplement to the Bluetooth Core Specification, Part A, 1.20](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/CSS_v12/CSS/out/en/supplement-to-the-bluetooth-core-specification/data-types-specification.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ChannelMapUpdateIndicationAdStruct {
+    channel_map: u64,
+    instant: u16,
+}
+
+impl ChannelMapUpdateIndicationAdStruct {
+    pub(crate) const fn try_new(channel_map: u64, instant: u16) -> Result<Self, AdvertisingError> {
+        if channel_map & !CHANNEL_MAP_MASK != 0 {
+            return Err(AdvertisingError::InvalidChannelMap);
+        }
+        Ok(Self {
+            channel_map,
+            instant,
+        })
+    }
+
+    /// Get the new channel map, one bit for each of the 37 data channels.
+    pub fn channel_map(&self) -> u64 {
+        self.channel_map
+    }
+
+    /// Get the event counter value at which the new channel map will be used.
+    pub fn instant(&self) -> u16 {
+        self.instant
+    }
+}
+
+impl EncodeToBuffer for ChannelMapUpdateIndicationAdStruct {
+    fn encode<B: bletio_utils::BufferOps>(
+        &self,
+        buffer: &mut B,
+    ) -> Result<usize, bletio_utils::Error> {
+        buffer.try_push(CHANNEL_MAP_UPDATE_INDICATION_AD_STRUCT_SIZE as u8)?;
+        buffer.try_push(AdType::ChannelMapUpdateIndication as u8)?;
+        buffer.copy_from_slice(&self.channel_map.to_le_bytes()[..5])?;
+        buffer.encode_le_u16(self.instant)?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        CHANNEL_MAP_UPDATE_INDICATION_AD_STRUCT_SIZE + 1
+    }
+}
+
+pub(crate) mod parser {
+    use nom::{
+        bytes::take,
+        combinator::{map, map_res},
+        number::complete::le_u16,
+        IResult, Parser,
+    };
+
+    use crate::advertising::ad_struct::AdStruct;
+
+    use super::*;
+
+    fn channel_map(input: &[u8]) -> IResult<&[u8], u64> {
+        map(take(5u8), |bytes: &[u8]| {
+            bytes
+                .iter()
+                .rev()
+                .fold(0, |acc, byte| (acc << 8) | *byte as u64)
+        })
+        .parse(input)
+    }
+
+    pub(crate) fn channel_map_update_indication_ad_struct(
+        input: &[u8],
+    ) -> IResult<&[u8], AdStruct> {
+        map(
+            map_res((channel_map, le_u16), |(channel_map, instant)| {
+                ChannelMapUpdateIndicationAdStruct::try_new(channel_map, instant)
+            }),
+            AdStruct::ChannelMapUpdateIndication,
+        )
+        .parse(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bletio_utils::{Buffer, BufferOps};
+    use rstest::rstest;
+
+    use crate::advertising::ad_struct::AdStruct;
+
+    use super::{parser::*, *};
+
+    #[rstest]
+    #[case(0x1F_FFFF_FFFF, 0x0010, &[0x08, 0x28, 0xFF, 0xFF, 0xFF, 0xFF, 0x1F, 0x10, 0x00])]
+    #[case(0x00_0F00_00FF, 0xABCD, &[0x08, 0x28, 0xFF, 0x00, 0x00, 0x0F, 0x00, 0xCD, 0xAB])]
+    fn test_channel_map_update_indication_ad_struct_success(
+        #[case] channel_map: u64,
+        #[case] instant: u16,
+        #[case] encoded_data: &[u8],
+    ) -> Result<(), bletio_utils::Error> {
+        let mut buffer = Buffer::<9>::default();
+        let ad_struct = ChannelMapUpdateIndicationAdStruct::try_new(channel_map, instant).unwrap();
+        ad_struct.encode(&mut buffer)?;
+        assert_eq!(buffer.data(), encoded_data);
+        assert_eq!(ad_struct.channel_map(), channel_map);
+        assert_eq!(ad_struct.instant(), instant);
+        assert_eq!(
+            channel_map_update_indication_ad_struct(&encoded_data[2..]),
+            Ok((
+                &[] as &[u8],
+                AdStruct::ChannelMapUpdateIndication(ad_struct)
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_channel_map_update_indication_ad_struct_failure() {
+        assert_eq!(
+            ChannelMapUpdateIndicationAdStruct::try_new(0x20_0000_0000, 0),
+            Err(AdvertisingError::InvalidChannelMap)
+        );
+    }
+
+    #[rstest]
+    #[case(&[0xFF, 0xFF, 0xFF, 0xFF, 0x20, 0x10, 0x00])]
+    #[case(&[0xFF, 0xFF, 0xFF, 0xFF, 0x1F, 0x10])]
+    fn test_channel_map_update_indication_ad_struct_parsing_failure(#[case] input: &[u8]) {
+        assert!(channel_map_update_indication_ad_struct(input).is_err());
+    }
+}
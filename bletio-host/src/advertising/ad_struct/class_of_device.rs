@@ -0,0 +1,167 @@
+use bletio_utils::EncodeToBuffer;
+
+use crate::advertising::AdvertisingError;
+use crate::assigned_numbers::AdType;
+
+const CLASS_OF_DEVICE_AD_STRUCT_SIZE: usize = 4;
+const CLASS_OF_DEVICE_MAX_VALUE: u32 = 0x00FF_FFFF;
+
+/// The Class of Device of a BR/EDR device.
+///
+/// It is a 24-bit value made of the major service classes, the major device class and the minor
+/// device class, as defined in the Assigned Numbers, Section 2.8.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ClassOfDevice {
+    value: u32,
+}
+
+impl ClassOfDevice {
+    /// Create a Class of Device.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` — The 24-bit Class of Device value.
+    pub const fn try_new(value: u32) -> Result<Self, AdvertisingError> {
+        if value <= CLASS_OF_DEVICE_MAX_VALUE {
+            Ok(Self { value })
+        } else {
+            Err(AdvertisingError::InvalidClassOfDeviceValue(value))
+        }
+    }
+
+    pub const fn value(&self) -> u32 {
+        self.value
+    }
+
+    /// Get the major service classes bit field (bits 13 to 23 of the value).
+    pub const fn major_service_classes(&self) -> u16 {
+        (self.value >> 13) as u16
+    }
+
+    /// Get the major device class (bits 8 to 12 of the value).
+    pub const fn major_device_class(&self) -> u8 {
+        ((self.value >> 8) & 0x1F) as u8
+    }
+
+    /// Get the minor device class (bits 2 to 7 of the value).
+    pub const fn minor_device_class(&self) -> u8 {
+        ((self.value >> 2) & 0x3F) as u8
+    }
+}
+
+impl TryFrom<u32> for ClassOfDevice {
+    type Error = AdvertisingError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Self::try_new(value)
+    }
+}
+
+/// The Class of Device of the device.
+///
+/// This Advertising Structure is meant to be used in an Out-of-Band data block, as defined in
+/// [Supplement to the Bluetooth Core Specification, Part A, 1.6](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/CSS_v12/CSS/out/en/supplement-to-the-bluetooth-core-specification/data-types-specification.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ClassOfDeviceAdStruct {
+    class_of_device: ClassOfDevice,
+}
+
+impl ClassOfDeviceAdStruct {
+    pub(crate) const fn new(class_of_device: ClassOfDevice) -> Self {
+        Self { class_of_device }
+    }
+
+    pub fn value(&self) -> ClassOfDevice {
+        self.class_of_device
+    }
+}
+
+impl EncodeToBuffer for ClassOfDeviceAdStruct {
+    fn encode<B: bletio_utils::BufferOps>(
+        &self,
+        buffer: &mut B,
+    ) -> Result<usize, bletio_utils::Error> {
+        buffer.try_push(CLASS_OF_DEVICE_AD_STRUCT_SIZE as u8)?;
+        buffer.try_push(AdType::ClassOfDevice as u8)?;
+        buffer.copy_from_slice(&self.class_of_device.value().to_le_bytes()[..3])?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        CLASS_OF_DEVICE_AD_STRUCT_SIZE + 1
+    }
+}
+
+pub(crate) mod parser {
+    use nom::{
+        combinator::{map, map_res},
+        number::complete::le_u24,
+        IResult, Parser,
+    };
+
+    use crate::advertising::ad_struct::AdStruct;
+
+    use super::*;
+
+    pub(crate) fn class_of_device_ad_struct(input: &[u8]) -> IResult<&[u8], AdStruct> {
+        map(map_res(le_u24, TryFrom::try_from), |class_of_device| {
+            AdStruct::ClassOfDevice(ClassOfDeviceAdStruct::new(class_of_device))
+        })
+        .parse(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bletio_utils::{Buffer, BufferOps};
+    use rstest::rstest;
+
+    use crate::advertising::ad_struct::AdStruct;
+
+    use super::{parser::*, *};
+
+    #[test]
+    fn test_class_of_device() {
+        let class_of_device = ClassOfDevice::try_new(0x5A020C).unwrap();
+        assert_eq!(class_of_device.value(), 0x5A020C);
+        assert_eq!(class_of_device.major_service_classes(), 0x2D0);
+        assert_eq!(class_of_device.major_device_class(), 0x02);
+        assert_eq!(class_of_device.minor_device_class(), 0x03);
+        assert_eq!(
+            ClassOfDevice::try_new(0x0100_0000),
+            Err(AdvertisingError::InvalidClassOfDeviceValue(0x0100_0000))
+        );
+    }
+
+    #[rstest]
+    #[case(0x5A020C, &[0x04, 0x0D, 0x0C, 0x02, 0x5A])]
+    #[case(0x000000, &[0x04, 0x0D, 0x00, 0x00, 0x00])]
+    fn test_class_of_device_ad_struct(
+        #[case] value: u32,
+        #[case] encoded_data: &[u8],
+    ) -> Result<(), bletio_utils::Error> {
+        let class_of_device = ClassOfDevice::try_new(value).unwrap();
+        let mut buffer = Buffer::<5>::default();
+        let ad_struct = ClassOfDeviceAdStruct::new(class_of_device);
+        ad_struct.encode(&mut buffer)?;
+        assert_eq!(buffer.data(), encoded_data);
+        assert_eq!(ad_struct.value(), class_of_device);
+        Ok(())
+    }
+
+    #[test]
+    fn test_class_of_device_ad_struct_parsing() {
+        assert_eq!(
+            class_of_device_ad_struct(&[0x0C, 0x02, 0x5A]),
+            Ok((
+                &[] as &[u8],
+                AdStruct::ClassOfDevice(ClassOfDeviceAdStruct::new(
+                    ClassOfDevice::try_new(0x5A020C).unwrap()
+                ))
+            ))
+        );
+        assert!(class_of_device_ad_struct(&[0x0C, 0x02]).is_err());
+    }
+}
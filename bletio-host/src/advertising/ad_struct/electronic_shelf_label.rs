@@ -0,0 +1,152 @@
+use bletio_utils::EncodeToBuffer;
+use heapless::Vec;
+
+use crate::advertising::AdvertisingError;
+use crate::assigned_numbers::AdType;
+
+const RANDOMIZER_LENGTH: usize = 5;
+const MIC_LENGTH: usize = 4;
+const ELECTRONIC_SHELF_LABEL_PAYLOAD_MAX_LENGTH: usize = 20;
+
+/// Encrypted data sent by an Access Point to Electronic Shelf Labels.
+///
+/// Its format is defined in the Electronic Shelf Label Profile specification. The payload is
+/// encrypted with the key material shared by the Access Point and the Electronic Shelf Labels.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ElectronicShelfLabelAdStruct {
+    randomizer: [u8; RANDOMIZER_LENGTH],
+    payload: Vec<u8, ELECTRONIC_SHELF_LABEL_PAYLOAD_MAX_LENGTH>,
+    mic: [u8; MIC_LENGTH],
+}
+
+impl ElectronicShelfLabelAdStruct {
+    pub(crate) fn try_new(
+        randomizer: [u8; RANDOMIZER_LENGTH],
+        payload: &[u8],
+        mic: [u8; MIC_LENGTH],
+    ) -> Result<Self, AdvertisingError> {
+        Ok(Self {
+            randomizer,
+            payload: payload
+                .try_into()
+                .map_err(|_| AdvertisingError::AdvertisingDataWillNotFitAdvertisingPacket)?,
+            mic,
+        })
+    }
+
+    /// Get the randomizer used for the encryption of the payload.
+    pub fn randomizer(&self) -> &[u8; RANDOMIZER_LENGTH] {
+        &self.randomizer
+    }
+
+    /// Get the encrypted payload.
+    pub fn payload(&self) -> &[u8] {
+        self.payload.as_slice()
+    }
+
+    /// Get the Message Integrity Check of the encrypted payload.
+    pub fn mic(&self) -> &[u8; MIC_LENGTH] {
+        &self.mic
+    }
+}
+
+impl EncodeToBuffer for ElectronicShelfLabelAdStruct {
+    fn encode<B: bletio_utils::BufferOps>(
+        &self,
+        buffer: &mut B,
+    ) -> Result<usize, bletio_utils::Error> {
+        buffer.try_push((self.encoded_size() - 1) as u8)?;
+        buffer.try_push(AdType::ElectronicShelfLabel as u8)?;
+        buffer.copy_from_slice(&self.randomizer)?;
+        buffer.copy_from_slice(self.payload.as_slice())?;
+        buffer.copy_from_slice(&self.mic)?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        RANDOMIZER_LENGTH + self.payload.len() + MIC_LENGTH + 2
+    }
+}
+
+pub(crate) mod parser {
+    use nom::{
+        bytes::take,
+        combinator::{fail, map_res},
+        IResult, Parser,
+    };
+
+    use crate::advertising::ad_struct::AdStruct;
+
+    use super::*;
+
+    pub(crate) fn electronic_shelf_label_ad_struct(input: &[u8]) -> IResult<&[u8], AdStruct> {
+        let Some(payload_len) = input.len().checked_sub(RANDOMIZER_LENGTH + MIC_LENGTH) else {
+            return fail().parse(input);
+        };
+        map_res(
+            (
+                map_res(take(RANDOMIZER_LENGTH), TryInto::try_into),
+                take(payload_len),
+                map_res(take(MIC_LENGTH), TryInto::try_into),
+            ),
+            |(randomizer, payload, mic)| {
+                ElectronicShelfLabelAdStruct::try_new(randomizer, payload, mic)
+                    .map(AdStruct::ElectronicShelfLabel)
+            },
+        )
+        .parse(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bletio_utils::{Buffer, BufferOps};
+    use rstest::rstest;
+
+    use crate::advertising::ad_struct::AdStruct;
+
+    use super::{parser::*, *};
+
+    #[rstest]
+    #[case(
+        [0x01, 0x02, 0x03, 0x04, 0x05], &[], [0xA1, 0xA2, 0xA3, 0xA4],
+        &[0x0A, 0x34, 0x01, 0x02, 0x03, 0x04, 0x05, 0xA1, 0xA2, 0xA3, 0xA4]
+    )]
+    #[case(
+        [0x01, 0x02, 0x03, 0x04, 0x05], &[0x10, 0x20, 0x30], [0xA1, 0xA2, 0xA3, 0xA4],
+        &[0x0D, 0x34, 0x01, 0x02, 0x03, 0x04, 0x05, 0x10, 0x20, 0x30, 0xA1, 0xA2, 0xA3, 0xA4]
+    )]
+    fn test_electronic_shelf_label_ad_struct_success(
+        #[case] randomizer: [u8; RANDOMIZER_LENGTH],
+        #[case] payload: &[u8],
+        #[case] mic: [u8; MIC_LENGTH],
+        #[case] encoded_data: &[u8],
+    ) -> Result<(), bletio_utils::Error> {
+        let mut buffer = Buffer::<31>::default();
+        let ad_struct = ElectronicShelfLabelAdStruct::try_new(randomizer, payload, mic).unwrap();
+        ad_struct.encode(&mut buffer)?;
+        assert_eq!(buffer.data(), encoded_data);
+        assert_eq!(ad_struct.randomizer(), &randomizer);
+        assert_eq!(ad_struct.payload(), payload);
+        assert_eq!(ad_struct.mic(), &mic);
+        assert_eq!(
+            electronic_shelf_label_ad_struct(&encoded_data[2..]),
+            Ok((&[] as &[u8], AdStruct::ElectronicShelfLabel(ad_struct)))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_electronic_shelf_label_ad_struct_failure() {
+        assert_eq!(
+            ElectronicShelfLabelAdStruct::try_new([0; 5], &[0; 21], [0; 4]),
+            Err(AdvertisingError::AdvertisingDataWillNotFitAdvertisingPacket)
+        );
+    }
+
+    #[test]
+    fn test_electronic_shelf_label_ad_struct_parsing_failure() {
+        assert!(electronic_shelf_label_ad_struct(&[0x01, 0x02, 0x03, 0x04, 0x05, 0xA1]).is_err());
+    }
+}
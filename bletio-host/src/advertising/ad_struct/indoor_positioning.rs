@@ -0,0 +1,382 @@
+use bletio_utils::EncodeToBuffer;
+
+use crate::assigned_numbers::AdType;
+
+const COORDINATES_PRESENT: u8 = 1 << 0;
+const LOCAL_COORDINATE_SYSTEM: u8 = 1 << 1;
+const TX_POWER_PRESENT: u8 = 1 << 2;
+const ALTITUDE_PRESENT: u8 = 1 << 3;
+const FLOOR_NUMBER_PRESENT: u8 = 1 << 4;
+const UNCERTAINTY_PRESENT: u8 = 1 << 5;
+const LOCATION_NAME_AVAILABLE: u8 = 1 << 6;
+
+/// Coordinates of the device, in the coordinate system it uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum IndoorPositioningCoordinates {
+    /// WGS84 coordinates, in units of 10^-7 degrees.
+    Wgs84 { latitude: i32, longitude: i32 },
+    /// Local coordinates, in units of 10 cm.
+    Local { north: i16, east: i16 },
+}
+
+impl IndoorPositioningCoordinates {
+    const fn len(&self) -> usize {
+        match self {
+            Self::Wgs84 { .. } => 8,
+            Self::Local { .. } => 4,
+        }
+    }
+}
+
+/// Indoor positioning information of the device, as defined in the Indoor Positioning Service
+/// specification.
+///
+/// All the fields are optional, only the ones that are set are included in the Indoor
+/// Positioning Advertising Structure.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct IndoorPositioning {
+    coordinates: Option<IndoorPositioningCoordinates>,
+    tx_power: Option<i8>,
+    floor_number: Option<u8>,
+    altitude: Option<u16>,
+    uncertainty: Option<u8>,
+    location_name_available: bool,
+}
+
+impl IndoorPositioning {
+    /// Create an empty indoor positioning information.
+    pub const fn new() -> Self {
+        Self {
+            coordinates: None,
+            tx_power: None,
+            floor_number: None,
+            altitude: None,
+            uncertainty: None,
+            location_name_available: false,
+        }
+    }
+
+    /// Set the coordinates of the device.
+    pub const fn with_coordinates(mut self, coordinates: IndoorPositioningCoordinates) -> Self {
+        self.coordinates = Some(coordinates);
+        self
+    }
+
+    /// Set the TX power of the device, in dBm.
+    pub const fn with_tx_power(mut self, tx_power: i8) -> Self {
+        self.tx_power = Some(tx_power);
+        self
+    }
+
+    /// Set the floor number, as the actual floor number plus 20.
+    pub const fn with_floor_number(mut self, floor_number: u8) -> Self {
+        self.floor_number = Some(floor_number);
+        self
+    }
+
+    /// Set the altitude, in decimeters plus 1000 decimeters.
+    pub const fn with_altitude(mut self, altitude: u16) -> Self {
+        self.altitude = Some(altitude);
+        self
+    }
+
+    /// Set the uncertainty of the location information, as defined by the Indoor Positioning
+    /// Service specification.
+    pub const fn with_uncertainty(mut self, uncertainty: u8) -> Self {
+        self.uncertainty = Some(uncertainty);
+        self
+    }
+
+    /// Tell whether the location name is available in the GATT database of the device.
+    pub const fn with_location_name_available(mut self, available: bool) -> Self {
+        self.location_name_available = available;
+        self
+    }
+
+    pub const fn coordinates(&self) -> Option<IndoorPositioningCoordinates> {
+        self.coordinates
+    }
+
+    pub const fn tx_power(&self) -> Option<i8> {
+        self.tx_power
+    }
+
+    pub const fn floor_number(&self) -> Option<u8> {
+        self.floor_number
+    }
+
+    pub const fn altitude(&self) -> Option<u16> {
+        self.altitude
+    }
+
+    pub const fn uncertainty(&self) -> Option<u8> {
+        self.uncertainty
+    }
+
+    pub const fn location_name_available(&self) -> bool {
+        self.location_name_available
+    }
+
+    fn configuration(&self) -> u8 {
+        let mut configuration = match self.coordinates {
+            Some(IndoorPositioningCoordinates::Wgs84 { .. }) => COORDINATES_PRESENT,
+            Some(IndoorPositioningCoordinates::Local { .. }) => {
+                COORDINATES_PRESENT | LOCAL_COORDINATE_SYSTEM
+            }
+            None => 0,
+        };
+        if self.tx_power.is_some() {
+            configuration |= TX_POWER_PRESENT;
+        }
+        if self.altitude.is_some() {
+            configuration |= ALTITUDE_PRESENT;
+        }
+        if self.floor_number.is_some() {
+            configuration |= FLOOR_NUMBER_PRESENT;
+        }
+        if self.uncertainty.is_some() {
+            configuration |= UNCERTAINTY_PRESENT;
+        }
+        if self.location_name_available {
+            configuration |= LOCATION_NAME_AVAILABLE;
+        }
+        configuration
+    }
+}
+
+impl EncodeToBuffer for IndoorPositioning {
+    fn encode<B: bletio_utils::BufferOps>(
+        &self,
+        buffer: &mut B,
+    ) -> Result<usize, bletio_utils::Error> {
+        buffer.try_push(self.configuration())?;
+        match self.coordinates {
+            Some(IndoorPositioningCoordinates::Wgs84 {
+                latitude,
+                longitude,
+            }) => {
+                buffer.encode_le_u32(latitude as u32)?;
+                buffer.encode_le_u32(longitude as u32)?;
+            }
+            Some(IndoorPositioningCoordinates::Local { north, east }) => {
+                buffer.encode_le_u16(north as u16)?;
+                buffer.encode_le_u16(east as u16)?;
+            }
+            None => {}
+        }
+        if let Some(tx_power) = self.tx_power {
+            buffer.try_push(tx_power as u8)?;
+        }
+        if let Some(floor_number) = self.floor_number {
+            buffer.try_push(floor_number)?;
+        }
+        if let Some(altitude) = self.altitude {
+            buffer.encode_le_u16(altitude)?;
+        }
+        if let Some(uncertainty) = self.uncertainty {
+            buffer.try_push(uncertainty)?;
+        }
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        1 + self.coordinates.map_or(0, |c| c.len())
+            + self.tx_power.map_or(0, |_| 1)
+            + self.floor_number.map_or(0, |_| 1)
+            + self.altitude.map_or(0, |_| 2)
+            + self.uncertainty.map_or(0, |_| 1)
+    }
+}
+
+/// Indoor positioning information of the device.
+///
+/// Its format is defined in the Indoor Positioning Service specification, see [`IndoorPositioning`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct IndoorPositioningAdStruct {
+    indoor_positioning: IndoorPositioning,
+}
+
+impl IndoorPositioningAdStruct {
+    pub(crate) const fn new(indoor_positioning: IndoorPositioning) -> Self {
+        Self { indoor_positioning }
+    }
+
+    pub fn value(&self) -> &IndoorPositioning {
+        &self.indoor_positioning
+    }
+}
+
+impl EncodeToBuffer for IndoorPositioningAdStruct {
+    fn encode<B: bletio_utils::BufferOps>(
+        &self,
+        buffer: &mut B,
+    ) -> Result<usize, bletio_utils::Error> {
+        buffer.try_push((self.encoded_size() - 1) as u8)?;
+        buffer.try_push(AdType::IndoorPositioning as u8)?;
+        self.indoor_positioning.encode(buffer)?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        self.indoor_positioning.encoded_size() + 2
+    }
+}
+
+pub(crate) mod parser {
+    use nom::{
+        combinator::{cond, eof, map},
+        number::complete::{le_i16, le_i32, le_i8, le_u16, le_u8},
+        IResult, Parser,
+    };
+
+    use crate::advertising::ad_struct::AdStruct;
+
+    use super::*;
+
+    fn coordinates(
+        configuration: u8,
+    ) -> impl FnMut(&[u8]) -> IResult<&[u8], Option<IndoorPositioningCoordinates>> {
+        move |input| {
+            if configuration & COORDINATES_PRESENT == 0 {
+                Ok((input, None))
+            } else if configuration & LOCAL_COORDINATE_SYSTEM == 0 {
+                map((le_i32, le_i32), |(latitude, longitude)| {
+                    Some(IndoorPositioningCoordinates::Wgs84 {
+                        latitude,
+                        longitude,
+                    })
+                })
+                .parse(input)
+            } else {
+                map((le_i16, le_i16), |(north, east)| {
+                    Some(IndoorPositioningCoordinates::Local { north, east })
+                })
+                .parse(input)
+            }
+        }
+    }
+
+    fn indoor_positioning(input: &[u8]) -> IResult<&[u8], IndoorPositioning> {
+        // The Advertising Structure may be empty, only telling that the Indoor Positioning
+        // Service is supported.
+        if input.is_empty() {
+            return Ok((input, IndoorPositioning::default()));
+        }
+        let (rest, configuration) = le_u8(input)?;
+        let (rest, (coordinates, tx_power, floor_number, altitude, uncertainty, _)) = (
+            coordinates(configuration),
+            cond(configuration & TX_POWER_PRESENT != 0, le_i8),
+            cond(configuration & FLOOR_NUMBER_PRESENT != 0, le_u8),
+            cond(configuration & ALTITUDE_PRESENT != 0, le_u16),
+            cond(configuration & UNCERTAINTY_PRESENT != 0, le_u8),
+            eof,
+        )
+            .parse(rest)?;
+        Ok((
+            rest,
+            IndoorPositioning {
+                coordinates,
+                tx_power,
+                floor_number,
+                altitude,
+                uncertainty,
+                location_name_available: configuration & LOCATION_NAME_AVAILABLE != 0,
+            },
+        ))
+    }
+
+    pub(crate) fn indoor_positioning_ad_struct(input: &[u8]) -> IResult<&[u8], AdStruct> {
+        map(indoor_positioning, |indoor_positioning| {
+            AdStruct::IndoorPositioning(IndoorPositioningAdStruct::new(indoor_positioning))
+        })
+        .parse(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bletio_utils::{Buffer, BufferOps};
+    use rstest::rstest;
+
+    use crate::advertising::ad_struct::AdStruct;
+
+    use super::{parser::*, *};
+
+    #[rstest]
+    #[case(IndoorPositioning::new(), &[0x02, 0x25, 0x00])]
+    #[case(
+        IndoorPositioning::new()
+            .with_coordinates(IndoorPositioningCoordinates::Wgs84 { latitude: 488_583_701, longitude: 22_944_813 })
+            .with_floor_number(21)
+            .with_location_name_available(true),
+        &[0x0B, 0x25, 0x51, 0x15, 0x32, 0x1F, 0x1D, 0x2D, 0x1C, 0x5E, 0x01, 0x15]
+    )]
+    #[case(
+        IndoorPositioning::new()
+            .with_coordinates(IndoorPositioningCoordinates::Local { north: -10, east: 25 })
+            .with_tx_power(-4)
+            .with_floor_number(20)
+            .with_altitude(1010)
+            .with_uncertainty(0x52),
+        &[0x0B, 0x25, 0x3F, 0xF6, 0xFF, 0x19, 0x00, 0xFC, 0x14, 0xF2, 0x03, 0x52]
+    )]
+    fn test_indoor_positioning_ad_struct(
+        #[case] indoor_positioning: IndoorPositioning,
+        #[case] encoded_data: &[u8],
+    ) -> Result<(), bletio_utils::Error> {
+        let mut buffer = Buffer::<31>::default();
+        let ad_struct = IndoorPositioningAdStruct::new(indoor_positioning);
+        ad_struct.encode(&mut buffer)?;
+        assert_eq!(buffer.data(), encoded_data);
+        assert_eq!(ad_struct.value(), &indoor_positioning);
+        assert_eq!(
+            indoor_positioning_ad_struct(&encoded_data[2..]),
+            Ok((&[] as &[u8], AdStruct::IndoorPositioning(ad_struct)))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_indoor_positioning_accessors() {
+        let indoor_positioning = IndoorPositioning::new()
+            .with_coordinates(IndoorPositioningCoordinates::Local { north: 1, east: 2 })
+            .with_tx_power(-4)
+            .with_floor_number(20)
+            .with_altitude(1010)
+            .with_uncertainty(0x52)
+            .with_location_name_available(true);
+        assert_eq!(
+            indoor_positioning.coordinates(),
+            Some(IndoorPositioningCoordinates::Local { north: 1, east: 2 })
+        );
+        assert_eq!(indoor_positioning.tx_power(), Some(-4));
+        assert_eq!(indoor_positioning.floor_number(), Some(20));
+        assert_eq!(indoor_positioning.altitude(), Some(1010));
+        assert_eq!(indoor_positioning.uncertainty(), Some(0x52));
+        assert!(indoor_positioning.location_name_available());
+    }
+
+    #[test]
+    fn test_indoor_positioning_ad_struct_parsing_empty() {
+        assert_eq!(
+            indoor_positioning_ad_struct(&[]),
+            Ok((
+                &[] as &[u8],
+                AdStruct::IndoorPositioning(IndoorPositioningAdStruct::new(
+                    IndoorPositioning::default()
+                ))
+            ))
+        );
+    }
+
+    #[rstest]
+    #[case(&[0x01, 0x51, 0x15, 0x3C, 0x83])]
+    #[case(&[0x04])]
+    #[case(&[0x00, 0x01])]
+    fn test_indoor_positioning_ad_struct_parsing_failure(#[case] input: &[u8]) {
+        assert!(indoor_positioning_ad_struct(input).is_err());
+    }
+}
@@ -0,0 +1,143 @@
+use bletio_hci::DeviceAddress;
+use bletio_utils::EncodeToBuffer;
+
+use crate::assigned_numbers::AdType;
+
+const LE_BLUETOOTH_DEVICE_ADDRESS_AD_STRUCT_SIZE: usize = 8;
+const RANDOM_ADDRESS_TYPE: u8 = 0x01;
+
+/// The LE device address of the device.
+///
+/// This Advertising Structure is meant to be used in an Out-of-Band data block, as defined in
+/// [Supplement to the Bluetooth Core Specification, Part A, 1.16](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/CSS_v12/CSS/out/en/supplement-to-the-bluetooth-core-specification/data-types-specification.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeBluetoothDeviceAddressAdStruct {
+    address: DeviceAddress,
+}
+
+impl LeBluetoothDeviceAddressAdStruct {
+    pub(crate) const fn new(address: DeviceAddress) -> Self {
+        Self { address }
+    }
+
+    pub fn value(&self) -> &DeviceAddress {
+        &self.address
+    }
+}
+
+impl EncodeToBuffer for LeBluetoothDeviceAddressAdStruct {
+    fn encode<B: bletio_utils::BufferOps>(
+        &self,
+        buffer: &mut B,
+    ) -> Result<usize, bletio_utils::Error> {
+        buffer.try_push(LE_BLUETOOTH_DEVICE_ADDRESS_AD_STRUCT_SIZE as u8)?;
+        buffer.try_push(AdType::LeBluetoothDeviceAddress as u8)?;
+        self.address.encode(buffer)?;
+        buffer.try_push(match self.address {
+            DeviceAddress::Public(_) => 0x00,
+            DeviceAddress::Random(_) => RANDOM_ADDRESS_TYPE,
+        })?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        LE_BLUETOOTH_DEVICE_ADDRESS_AD_STRUCT_SIZE + 1
+    }
+}
+
+pub(crate) mod parser {
+    use bletio_hci::{common::device_address::parser::address, PublicDeviceAddress};
+    use nom::{
+        combinator::{map, map_res},
+        number::complete::le_u8,
+        IResult, Parser,
+    };
+
+    use crate::advertising::ad_struct::AdStruct;
+
+    use super::*;
+
+    fn le_bluetooth_device_address(input: &[u8]) -> IResult<&[u8], DeviceAddress> {
+        map_res(
+            (address, le_u8),
+            |(address, address_type)| -> Result<DeviceAddress, bletio_hci::Error> {
+                // Only the least significant bit of the address type is meaningful.
+                if address_type & RANDOM_ADDRESS_TYPE == 0 {
+                    Ok(PublicDeviceAddress::new(address).into())
+                } else {
+                    Ok(DeviceAddress::Random(address.try_into()?))
+                }
+            },
+        )
+        .parse(input)
+    }
+
+    pub(crate) fn le_bluetooth_device_address_ad_struct(input: &[u8]) -> IResult<&[u8], AdStruct> {
+        map(le_bluetooth_device_address, |address| {
+            AdStruct::LeBluetoothDeviceAddress(LeBluetoothDeviceAddressAdStruct::new(address))
+        })
+        .parse(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bletio_hci::{PublicDeviceAddress, RandomStaticDeviceAddress};
+    use bletio_utils::{Buffer, BufferOps};
+    use rstest::rstest;
+
+    use crate::advertising::ad_struct::AdStruct;
+
+    use super::{parser::*, *};
+
+    #[rstest]
+    #[case(
+        PublicDeviceAddress::new([0xCD, 0x2E, 0x0B, 0x04, 0x32, 0x56]).into(),
+        &[0x08, 0x1B, 0xCD, 0x2E, 0x0B, 0x04, 0x32, 0x56, 0x00]
+    )]
+    #[case(
+        RandomStaticDeviceAddress::try_new([0x28, 0xC8, 0xE9, 0x7D, 0x6A, 0xF7]).unwrap().into(),
+        &[0x08, 0x1B, 0x28, 0xC8, 0xE9, 0x7D, 0x6A, 0xF7, 0x01]
+    )]
+    fn test_le_bluetooth_device_address_ad_struct(
+        #[case] address: DeviceAddress,
+        #[case] encoded_data: &[u8],
+    ) -> Result<(), bletio_utils::Error> {
+        let mut buffer = Buffer::<9>::default();
+        let ad_struct = LeBluetoothDeviceAddressAdStruct::new(address.clone());
+        ad_struct.encode(&mut buffer)?;
+        assert_eq!(buffer.data(), encoded_data);
+        assert_eq!(ad_struct.value(), &address);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(
+        &[0xCD, 0x2E, 0x0B, 0x04, 0x32, 0x56, 0x00],
+        PublicDeviceAddress::new([0xCD, 0x2E, 0x0B, 0x04, 0x32, 0x56]).into()
+    )]
+    #[case(
+        &[0x28, 0xC8, 0xE9, 0x7D, 0x6A, 0xF7, 0xFF],
+        RandomStaticDeviceAddress::try_new([0x28, 0xC8, 0xE9, 0x7D, 0x6A, 0xF7]).unwrap().into()
+    )]
+    fn test_le_bluetooth_device_address_ad_struct_parsing_success(
+        #[case] input: &[u8],
+        #[case] address: DeviceAddress,
+    ) {
+        assert_eq!(
+            le_bluetooth_device_address_ad_struct(input),
+            Ok((
+                &[] as &[u8],
+                AdStruct::LeBluetoothDeviceAddress(LeBluetoothDeviceAddressAdStruct::new(address))
+            ))
+        );
+    }
+
+    #[rstest]
+    #[case(&[0xCD, 0x2E, 0x0B, 0x04, 0x32, 0x56])]
+    #[case(&[0x28, 0xC8, 0xE9, 0x7D, 0x6A, 0xB7, 0x01])]
+    fn test_le_bluetooth_device_address_ad_struct_parsing_failure(#[case] input: &[u8]) {
+        assert!(le_bluetooth_device_address_ad_struct(input).is_err());
+    }
+}
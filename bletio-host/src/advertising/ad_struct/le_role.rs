@@ -0,0 +1,126 @@
+use bletio_utils::EncodeToBuffer;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+use crate::advertising::AdvertisingError;
+use crate::assigned_numbers::AdType;
+
+const LE_ROLE_AD_STRUCT_SIZE: usize = 2;
+
+/// The LE roles supported by the device and its preferred role.
+///
+/// The values are defined in
+/// [Supplement to the Bluetooth Core Specification, Part A, 1.17](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/CSS_v12/CSS/out/en/supplement-to-the-bluetooth-core-specification/data-types-specification.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoPrimitive, TryFromPrimitive)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[num_enum(error_type(name = AdvertisingError, constructor = AdvertisingError::InvalidLeRoleValue))]
+#[repr(u8)]
+#[non_exhaustive]
+pub enum LeRole {
+    /// Only Peripheral role supported.
+    OnlyPeripheral = 0x00,
+    /// Only Central role supported.
+    OnlyCentral = 0x01,
+    /// Peripheral and Central roles supported, Peripheral role preferred for connection establishment.
+    PeripheralPreferred = 0x02,
+    /// Peripheral and Central roles supported, Central role preferred for connection establishment.
+    CentralPreferred = 0x03,
+}
+
+/// The LE role capabilities of the device.
+///
+/// This Advertising Structure is meant to be used in an Out-of-Band data block, as defined in
+/// [Supplement to the Bluetooth Core Specification, Part A, 1.17](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/CSS_v12/CSS/out/en/supplement-to-the-bluetooth-core-specification/data-types-specification.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeRoleAdStruct {
+    role: LeRole,
+}
+
+impl LeRoleAdStruct {
+    pub(crate) const fn new(role: LeRole) -> Self {
+        Self { role }
+    }
+
+    pub fn value(&self) -> LeRole {
+        self.role
+    }
+}
+
+impl EncodeToBuffer for LeRoleAdStruct {
+    fn encode<B: bletio_utils::BufferOps>(
+        &self,
+        buffer: &mut B,
+    ) -> Result<usize, bletio_utils::Error> {
+        buffer.try_push(LE_ROLE_AD_STRUCT_SIZE as u8)?;
+        buffer.try_push(AdType::LeRole as u8)?;
+        buffer.try_push(self.role.into())?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        LE_ROLE_AD_STRUCT_SIZE + 1
+    }
+}
+
+pub(crate) mod parser {
+    use nom::{
+        combinator::{map, map_res},
+        number::complete::le_u8,
+        IResult, Parser,
+    };
+
+    use crate::advertising::ad_struct::AdStruct;
+
+    use super::*;
+
+    pub(crate) fn le_role_ad_struct(input: &[u8]) -> IResult<&[u8], AdStruct> {
+        map(map_res(le_u8, TryFrom::try_from), |role| {
+            AdStruct::LeRole(LeRoleAdStruct::new(role))
+        })
+        .parse(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bletio_utils::{Buffer, BufferOps};
+    use rstest::rstest;
+
+    use crate::advertising::ad_struct::AdStruct;
+
+    use super::{parser::*, *};
+
+    #[rstest]
+    #[case(LeRole::OnlyPeripheral, &[0x02, 0x1C, 0x00])]
+    #[case(LeRole::CentralPreferred, &[0x02, 0x1C, 0x03])]
+    fn test_le_role_ad_struct(
+        #[case] role: LeRole,
+        #[case] encoded_data: &[u8],
+    ) -> Result<(), bletio_utils::Error> {
+        let mut buffer = Buffer::<3>::default();
+        let ad_struct = LeRoleAdStruct::new(role);
+        ad_struct.encode(&mut buffer)?;
+        assert_eq!(buffer.data(), encoded_data);
+        assert_eq!(ad_struct.value(), role);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(&[0x01], LeRole::OnlyCentral)]
+    #[case(&[0x02], LeRole::PeripheralPreferred)]
+    fn test_le_role_ad_struct_parsing_success(#[case] input: &[u8], #[case] role: LeRole) {
+        assert_eq!(
+            le_role_ad_struct(input),
+            Ok((&[] as &[u8], AdStruct::LeRole(LeRoleAdStruct::new(role))))
+        );
+    }
+
+    #[test]
+    fn test_le_role_ad_struct_parsing_failure() {
+        assert!(le_role_ad_struct(&[0x04]).is_err());
+        assert_eq!(
+            LeRole::try_from(0x04),
+            Err(AdvertisingError::InvalidLeRoleValue(0x04))
+        );
+    }
+}
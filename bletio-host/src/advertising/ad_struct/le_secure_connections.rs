@@ -0,0 +1,168 @@
+use bletio_utils::EncodeToBuffer;
+
+use crate::assigned_numbers::AdType;
+
+const LE_SECURE_CONNECTIONS_VALUE_AD_STRUCT_SIZE: usize = 17;
+
+/// The LE Secure Connections Confirmation Value, used for Out-of-Band pairing.
+///
+/// This Advertising Structure shall only be used in an Out-of-Band data block, as defined in
+/// [Supplement to the Bluetooth Core Specification, Part A, 1.6](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/CSS_v12/CSS/out/en/supplement-to-the-bluetooth-core-specification/data-types-specification.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeSecureConnectionsConfirmationValueAdStruct {
+    value: u128,
+}
+
+impl LeSecureConnectionsConfirmationValueAdStruct {
+    pub(crate) const fn new(value: u128) -> Self {
+        Self { value }
+    }
+
+    pub fn value(&self) -> u128 {
+        self.value
+    }
+}
+
+impl EncodeToBuffer for LeSecureConnectionsConfirmationValueAdStruct {
+    fn encode<B: bletio_utils::BufferOps>(
+        &self,
+        buffer: &mut B,
+    ) -> Result<usize, bletio_utils::Error> {
+        buffer.try_push(LE_SECURE_CONNECTIONS_VALUE_AD_STRUCT_SIZE as u8)?;
+        buffer.try_push(AdType::LeSecureConnectionsConfirmationValue as u8)?;
+        buffer.encode_le_u128(self.value)?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        LE_SECURE_CONNECTIONS_VALUE_AD_STRUCT_SIZE + 1
+    }
+}
+
+/// The LE Secure Connections Random Value, used for Out-of-Band pairing.
+///
+/// This Advertising Structure shall only be used in an Out-of-Band data block, as defined in
+/// [Supplement to the Bluetooth Core Specification, Part A, 1.6](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/CSS_v12/CSS/out/en/supplement-to-the-bluetooth-core-specification/data-types-specification.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeSecureConnectionsRandomValueAdStruct {
+    value: u128,
+}
+
+impl LeSecureConnectionsRandomValueAdStruct {
+    pub(crate) const fn new(value: u128) -> Self {
+        Self { value }
+    }
+
+    pub fn value(&self) -> u128 {
+        self.value
+    }
+}
+
+impl EncodeToBuffer for LeSecureConnectionsRandomValueAdStruct {
+    fn encode<B: bletio_utils::BufferOps>(
+        &self,
+        buffer: &mut B,
+    ) -> Result<usize, bletio_utils::Error> {
+        buffer.try_push(LE_SECURE_CONNECTIONS_VALUE_AD_STRUCT_SIZE as u8)?;
+        buffer.try_push(AdType::LeSecureConnectionsRandomValue as u8)?;
+        buffer.encode_le_u128(self.value)?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        LE_SECURE_CONNECTIONS_VALUE_AD_STRUCT_SIZE + 1
+    }
+}
+
+pub(crate) mod parser {
+    use nom::{combinator::map, number::complete::le_u128, IResult, Parser};
+
+    use crate::advertising::ad_struct::AdStruct;
+
+    use super::*;
+
+    pub(crate) fn le_secure_connections_confirmation_value_ad_struct(
+        input: &[u8],
+    ) -> IResult<&[u8], AdStruct> {
+        map(le_u128, |value| {
+            AdStruct::LeSecureConnectionsConfirmationValue(
+                LeSecureConnectionsConfirmationValueAdStruct::new(value),
+            )
+        })
+        .parse(input)
+    }
+
+    pub(crate) fn le_secure_connections_random_value_ad_struct(
+        input: &[u8],
+    ) -> IResult<&[u8], AdStruct> {
+        map(le_u128, |value| {
+            AdStruct::LeSecureConnectionsRandomValue(LeSecureConnectionsRandomValueAdStruct::new(
+                value,
+            ))
+        })
+        .parse(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bletio_utils::{Buffer, BufferOps};
+
+    use crate::advertising::ad_struct::AdStruct;
+
+    use super::{parser::*, *};
+
+    const VALUE: u128 = 0x0F0E0D0C_0B0A0908_07060504_03020100;
+    const ENCODED_VALUE: [u8; 16] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E,
+        0x0F,
+    ];
+
+    #[test]
+    fn test_le_secure_connections_confirmation_value_ad_struct() -> Result<(), bletio_utils::Error>
+    {
+        let mut buffer = Buffer::<18>::default();
+        let ad_struct = LeSecureConnectionsConfirmationValueAdStruct::new(VALUE);
+        ad_struct.encode(&mut buffer)?;
+        assert_eq!(&buffer.data()[..2], &[0x11, 0x22]);
+        assert_eq!(&buffer.data()[2..], &ENCODED_VALUE);
+        assert_eq!(ad_struct.value(), VALUE);
+        Ok(())
+    }
+
+    #[test]
+    fn test_le_secure_connections_random_value_ad_struct() -> Result<(), bletio_utils::Error> {
+        let mut buffer = Buffer::<18>::default();
+        let ad_struct = LeSecureConnectionsRandomValueAdStruct::new(VALUE);
+        ad_struct.encode(&mut buffer)?;
+        assert_eq!(&buffer.data()[..2], &[0x11, 0x23]);
+        assert_eq!(&buffer.data()[2..], &ENCODED_VALUE);
+        assert_eq!(ad_struct.value(), VALUE);
+        Ok(())
+    }
+
+    #[test]
+    fn test_le_secure_connections_ad_struct_parsing() {
+        assert_eq!(
+            le_secure_connections_confirmation_value_ad_struct(&ENCODED_VALUE),
+            Ok((
+                &[] as &[u8],
+                AdStruct::LeSecureConnectionsConfirmationValue(
+                    LeSecureConnectionsConfirmationValueAdStruct::new(VALUE)
+                )
+            ))
+        );
+        assert_eq!(
+            le_secure_connections_random_value_ad_struct(&ENCODED_VALUE),
+            Ok((
+                &[] as &[u8],
+                AdStruct::LeSecureConnectionsRandomValue(
+                    LeSecureConnectionsRandomValueAdStruct::new(VALUE)
+                )
+            ))
+        );
+        assert!(le_secure_connections_random_value_ad_struct(&ENCODED_VALUE[..15]).is_err());
+    }
+}
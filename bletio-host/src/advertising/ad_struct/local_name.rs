@@ -3,7 +3,8 @@ use heapless::String;
 
 use crate::{advertising::AdvertisingError, assigned_numbers::AdType};
 
-const LOCAL_NAME_MAX_LENGTH: usize = 29;
+/// Maximum length of a local name fitting in an Advertising Data or Scan Response Data packet.
+pub const LOCAL_NAME_MAX_LENGTH: usize = 29;
 
 /// Whether the local name is complete or shortened.
 ///
@@ -39,6 +40,11 @@ impl LocalNameAdStruct {
                 LocalNameComplete::Complete => local_name.try_into(),
                 LocalNameComplete::Shortened(len) => {
                     if local_name.len() > len {
+                        // Do not cut the local name in the middle of a multi-byte character.
+                        let len = (0..=len)
+                            .rev()
+                            .find(|&index| local_name.is_char_boundary(index))
+                            .unwrap_or_default();
                         (&local_name[..len]).try_into()
                     } else {
                         let mut local_name_str: String<LOCAL_NAME_MAX_LENGTH> =
@@ -141,6 +147,8 @@ mod test {
     #[case("bletio", LocalNameComplete::Shortened(3), "ble", &[0x04, 0x08, b'b', b'l', b'e'])]
     #[case("bletio", LocalNameComplete::Shortened(5), "bleti", &[0x06, 0x08, b'b', b'l', b'e', b't', b'i'])]
     #[case("bletio", LocalNameComplete::Shortened(8), "bletio  ", &[0x09, 0x08, b'b', b'l', b'e', b't', b'i', b'o', b' ', b' '])]
+    #[case("Café", LocalNameComplete::Shortened(4), "Caf", &[0x04, 0x08, b'C', b'a', b'f'])]
+    #[case("Café", LocalNameComplete::Shortened(5), "Café", &[0x06, 0x08, b'C', b'a', b'f', 0xC3, 0xA9])]
     fn test_local_name_ad_struct_success(
         #[case] local_name: &str,
         #[case] complete: LocalNameComplete,
@@ -10,29 +10,64 @@
 
 pub(crate) mod advertising_interval;
 pub(crate) mod appearance;
+pub(crate) mod big_info;
+pub(crate) mod broadcast_code;
+pub(crate) mod broadcast_name;
+pub(crate) mod channel_map_update_indication;
+pub(crate) mod class_of_device;
+pub(crate) mod electronic_shelf_label;
 pub(crate) mod flags;
+pub(crate) mod indoor_positioning;
+pub(crate) mod le_bluetooth_device_address;
+pub(crate) mod le_role;
+pub(crate) mod le_secure_connections;
 pub(crate) mod le_supported_features;
 pub(crate) mod local_name;
 pub(crate) mod manufacturer_specific_data;
+pub(crate) mod periodic_advertising_response_timing_information;
 pub(crate) mod peripheral_connection_interval_range;
 pub(crate) mod public_target_address;
 pub(crate) mod random_target_address;
+pub(crate) mod resolvable_set_identifier;
+pub(crate) mod security_manager;
 pub(crate) mod service_data;
 pub(crate) mod service_solicitation;
 pub(crate) mod service_uuid;
+pub(crate) mod three_dimensional_information;
+pub(crate) mod transport_discovery_data;
 pub(crate) mod tx_power_level;
+pub(crate) mod unhandled;
 pub(crate) mod uri;
 
-pub(crate) use advertising_interval::AdvertisingIntervalAdStruct;
+pub(crate) use advertising_interval::{
+    AdvertisingIntervalAdStruct, AdvertisingIntervalLongAdStruct,
+};
 pub(crate) use appearance::AppearanceAdStruct;
+pub(crate) use big_info::BigInfoAdStruct;
 use bletio_utils::EncodeToBuffer;
+pub(crate) use broadcast_code::BroadcastCodeAdStruct;
+pub(crate) use broadcast_name::BroadcastNameAdStruct;
+pub(crate) use channel_map_update_indication::ChannelMapUpdateIndicationAdStruct;
+pub(crate) use class_of_device::ClassOfDeviceAdStruct;
+pub(crate) use electronic_shelf_label::ElectronicShelfLabelAdStruct;
 pub(crate) use flags::FlagsAdStruct;
+pub(crate) use indoor_positioning::IndoorPositioningAdStruct;
+pub(crate) use le_bluetooth_device_address::LeBluetoothDeviceAddressAdStruct;
+pub(crate) use le_role::LeRoleAdStruct;
+pub(crate) use le_secure_connections::{
+    LeSecureConnectionsConfirmationValueAdStruct, LeSecureConnectionsRandomValueAdStruct,
+};
 pub(crate) use le_supported_features::LeSupportedFeaturesAdStruct;
 pub(crate) use local_name::LocalNameAdStruct;
 pub(crate) use manufacturer_specific_data::ManufacturerSpecificDataAdStruct;
+pub(crate) use periodic_advertising_response_timing_information::PeriodicAdvertisingResponseTimingInformationAdStruct;
 pub(crate) use peripheral_connection_interval_range::PeripheralConnectionIntervalRangeAdStruct;
 pub(crate) use public_target_address::PublicTargetAddressAdStruct;
 pub(crate) use random_target_address::RandomTargetAddressAdStruct;
+pub(crate) use resolvable_set_identifier::ResolvableSetIdentifierAdStruct;
+pub(crate) use security_manager::{
+    SecurityManagerOutOfBandFlagsAdStruct, SecurityManagerTkValueAdStruct,
+};
 pub(crate) use service_data::{
     ServiceDataUuid128AdStruct, ServiceDataUuid16AdStruct, ServiceDataUuid32AdStruct,
 };
@@ -43,21 +78,42 @@ pub(crate) use service_solicitation::{
 pub(crate) use service_uuid::{
     ServiceUuid128AdStruct, ServiceUuid16AdStruct, ServiceUuid32AdStruct,
 };
+pub(crate) use three_dimensional_information::ThreeDimensionalInformationAdStruct;
+pub(crate) use transport_discovery_data::TransportDiscoveryDataAdStruct;
 pub(crate) use tx_power_level::TxPowerLevelAdStruct;
+pub(crate) use unhandled::UnhandledAdStruct;
 pub(crate) use uri::UriAdStruct;
 
 #[derive(Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum AdStruct {
     AdvertisingInterval(AdvertisingIntervalAdStruct),
+    AdvertisingIntervalLong(AdvertisingIntervalLongAdStruct),
     Appearance(AppearanceAdStruct),
+    BigInfo(BigInfoAdStruct),
+    BroadcastCode(BroadcastCodeAdStruct),
+    BroadcastName(BroadcastNameAdStruct),
+    ChannelMapUpdateIndication(ChannelMapUpdateIndicationAdStruct),
+    ClassOfDevice(ClassOfDeviceAdStruct),
+    ElectronicShelfLabel(ElectronicShelfLabelAdStruct),
     Flags(FlagsAdStruct),
+    IndoorPositioning(IndoorPositioningAdStruct),
+    LeBluetoothDeviceAddress(LeBluetoothDeviceAddressAdStruct),
+    LeRole(LeRoleAdStruct),
+    LeSecureConnectionsConfirmationValue(LeSecureConnectionsConfirmationValueAdStruct),
+    LeSecureConnectionsRandomValue(LeSecureConnectionsRandomValueAdStruct),
     LeSupportedFeatures(LeSupportedFeaturesAdStruct),
     LocalName(LocalNameAdStruct),
     ManufacturerSpecificData(ManufacturerSpecificDataAdStruct),
+    PeriodicAdvertisingResponseTimingInformation(
+        PeriodicAdvertisingResponseTimingInformationAdStruct,
+    ),
     PeripheralConnectionIntervalRange(PeripheralConnectionIntervalRangeAdStruct),
     PublicTargetAddress(PublicTargetAddressAdStruct),
     RandomTargetAddress(RandomTargetAddressAdStruct),
+    ResolvableSetIdentifier(ResolvableSetIdentifierAdStruct),
+    SecurityManagerOutOfBandFlags(SecurityManagerOutOfBandFlagsAdStruct),
+    SecurityManagerTkValue(SecurityManagerTkValueAdStruct),
     ServiceDataUuid16(ServiceDataUuid16AdStruct),
     ServiceDataUuid32(ServiceDataUuid32AdStruct),
     ServiceDataUuid128(ServiceDataUuid128AdStruct),
@@ -67,9 +123,11 @@ pub enum AdStruct {
     ServiceUuid16(ServiceUuid16AdStruct),
     ServiceUuid32(ServiceUuid32AdStruct),
     ServiceUuid128(ServiceUuid128AdStruct),
+    ThreeDimensionalInformation(ThreeDimensionalInformationAdStruct),
+    TransportDiscoveryData(TransportDiscoveryDataAdStruct),
     TxPowerLevel(TxPowerLevelAdStruct),
     Uri(UriAdStruct),
-    Unhandled(u8),
+    Unhandled(UnhandledAdStruct),
 }
 
 impl EncodeToBuffer for AdStruct {
@@ -79,14 +137,30 @@ impl EncodeToBuffer for AdStruct {
     ) -> Result<usize, bletio_utils::Error> {
         match self {
             AdStruct::AdvertisingInterval(v) => v.encode(buffer),
+            AdStruct::AdvertisingIntervalLong(v) => v.encode(buffer),
             AdStruct::Appearance(v) => v.encode(buffer),
+            AdStruct::BigInfo(v) => v.encode(buffer),
+            AdStruct::BroadcastCode(v) => v.encode(buffer),
+            AdStruct::BroadcastName(v) => v.encode(buffer),
+            AdStruct::ChannelMapUpdateIndication(v) => v.encode(buffer),
+            AdStruct::ClassOfDevice(v) => v.encode(buffer),
+            AdStruct::ElectronicShelfLabel(v) => v.encode(buffer),
             AdStruct::Flags(v) => v.encode(buffer),
+            AdStruct::IndoorPositioning(v) => v.encode(buffer),
+            AdStruct::LeBluetoothDeviceAddress(v) => v.encode(buffer),
+            AdStruct::LeRole(v) => v.encode(buffer),
+            AdStruct::LeSecureConnectionsConfirmationValue(v) => v.encode(buffer),
+            AdStruct::LeSecureConnectionsRandomValue(v) => v.encode(buffer),
             AdStruct::LeSupportedFeatures(v) => v.encode(buffer),
             AdStruct::LocalName(v) => v.encode(buffer),
             AdStruct::ManufacturerSpecificData(v) => v.encode(buffer),
+            AdStruct::PeriodicAdvertisingResponseTimingInformation(v) => v.encode(buffer),
             AdStruct::PeripheralConnectionIntervalRange(v) => v.encode(buffer),
             AdStruct::PublicTargetAddress(v) => v.encode(buffer),
             AdStruct::RandomTargetAddress(v) => v.encode(buffer),
+            AdStruct::ResolvableSetIdentifier(v) => v.encode(buffer),
+            AdStruct::SecurityManagerOutOfBandFlags(v) => v.encode(buffer),
+            AdStruct::SecurityManagerTkValue(v) => v.encode(buffer),
             AdStruct::ServiceDataUuid16(v) => v.encode(buffer),
             AdStruct::ServiceDataUuid32(v) => v.encode(buffer),
             AdStruct::ServiceDataUuid128(v) => v.encode(buffer),
@@ -96,23 +170,41 @@ impl EncodeToBuffer for AdStruct {
             AdStruct::ServiceUuid16(v) => v.encode(buffer),
             AdStruct::ServiceUuid32(v) => v.encode(buffer),
             AdStruct::ServiceUuid128(v) => v.encode(buffer),
+            AdStruct::ThreeDimensionalInformation(v) => v.encode(buffer),
+            AdStruct::TransportDiscoveryData(v) => v.encode(buffer),
             AdStruct::TxPowerLevel(v) => v.encode(buffer),
             AdStruct::Uri(v) => v.encode(buffer),
-            AdStruct::Unhandled(_) => Err(bletio_utils::Error::CannotEncode),
+            AdStruct::Unhandled(v) => v.encode(buffer),
         }
     }
 
     fn encoded_size(&self) -> usize {
         match self {
             AdStruct::AdvertisingInterval(v) => v.encoded_size(),
+            AdStruct::AdvertisingIntervalLong(v) => v.encoded_size(),
             AdStruct::Appearance(v) => v.encoded_size(),
+            AdStruct::BigInfo(v) => v.encoded_size(),
+            AdStruct::BroadcastCode(v) => v.encoded_size(),
+            AdStruct::BroadcastName(v) => v.encoded_size(),
+            AdStruct::ChannelMapUpdateIndication(v) => v.encoded_size(),
+            AdStruct::ClassOfDevice(v) => v.encoded_size(),
+            AdStruct::ElectronicShelfLabel(v) => v.encoded_size(),
             AdStruct::Flags(v) => v.encoded_size(),
+            AdStruct::IndoorPositioning(v) => v.encoded_size(),
+            AdStruct::LeBluetoothDeviceAddress(v) => v.encoded_size(),
+            AdStruct::LeRole(v) => v.encoded_size(),
+            AdStruct::LeSecureConnectionsConfirmationValue(v) => v.encoded_size(),
+            AdStruct::LeSecureConnectionsRandomValue(v) => v.encoded_size(),
             AdStruct::LeSupportedFeatures(v) => v.encoded_size(),
             AdStruct::LocalName(v) => v.encoded_size(),
             AdStruct::ManufacturerSpecificData(v) => v.encoded_size(),
+            AdStruct::PeriodicAdvertisingResponseTimingInformation(v) => v.encoded_size(),
             AdStruct::PeripheralConnectionIntervalRange(v) => v.encoded_size(),
             AdStruct::PublicTargetAddress(v) => v.encoded_size(),
             AdStruct::RandomTargetAddress(v) => v.encoded_size(),
+            AdStruct::ResolvableSetIdentifier(v) => v.encoded_size(),
+            AdStruct::SecurityManagerOutOfBandFlags(v) => v.encoded_size(),
+            AdStruct::SecurityManagerTkValue(v) => v.encoded_size(),
             AdStruct::ServiceDataUuid16(v) => v.encoded_size(),
             AdStruct::ServiceDataUuid32(v) => v.encoded_size(),
             AdStruct::ServiceDataUuid128(v) => v.encoded_size(),
@@ -122,9 +214,11 @@ impl EncodeToBuffer for AdStruct {
             AdStruct::ServiceUuid16(v) => v.encoded_size(),
             AdStruct::ServiceUuid32(v) => v.encoded_size(),
             AdStruct::ServiceUuid128(v) => v.encoded_size(),
+            AdStruct::ThreeDimensionalInformation(v) => v.encoded_size(),
+            AdStruct::TransportDiscoveryData(v) => v.encoded_size(),
             AdStruct::TxPowerLevel(v) => v.encoded_size(),
             AdStruct::Uri(v) => v.encoded_size(),
-            AdStruct::Unhandled(_) => 0,
+            AdStruct::Unhandled(v) => v.encoded_size(),
         }
     }
 }
@@ -132,15 +226,17 @@ impl EncodeToBuffer for AdStruct {
 #[cfg(test)]
 mod test {
     use bletio_hci::{
-        AdvertisingInterval, PublicDeviceAddress, RandomStaticDeviceAddress, SupportedLeFeatures,
-        TxPowerLevel,
+        AdvertisingInterval, BroadcastCode, PublicDeviceAddress, RandomStaticDeviceAddress,
+        SupportedLeFeatures, TxPowerLevel,
     };
     use bletio_utils::{Buffer, BufferOps};
     use rstest::rstest;
 
     use crate::{
         advertising::{
-            Flags, LocalNameComplete, PeripheralConnectionIntervalRange, ServiceListComplete, Uri,
+            ClassOfDevice, Flags, IndoorPositioning, LeRole, LocalNameComplete,
+            PeripheralConnectionIntervalRange, SecurityManagerOutOfBandFlags, ServiceListComplete,
+            ThreeDimensionalInformationFlags, TransportBlock, Uri,
         },
         assigned_numbers::{AppearanceValue, CompanyIdentifier, ProvisionedUriScheme, ServiceUuid},
         uuid::{Uuid128, Uuid32},
@@ -150,7 +246,36 @@ mod test {
 
     #[rstest]
     #[case(AdStruct::AdvertisingInterval(AdvertisingIntervalAdStruct::new(AdvertisingInterval::default())), &[0x03, 0x1A, 0x00, 0x08])]
+    #[case(AdStruct::AdvertisingIntervalLong(AdvertisingIntervalLongAdStruct::new(0x0100_0000)), &[0x05, 0x2F, 0x00, 0x00, 0x00, 0x01])]
     #[case(AdStruct::Appearance(AppearanceAdStruct::new(AppearanceValue::StandmountedSpeaker)), &[0x03, 0x19, 0x44, 0x08])]
+    #[case(
+        AdStruct::BroadcastCode(BroadcastCodeAdStruct::new(BroadcastCode::new(*b"bletio-broadcast"))),
+        &[0x11, 0x2D, b'b', b'l', b'e', b't', b'i', b'o', b'-', b'b', b'r', b'o', b'a', b'd', b'c', b'a', b's', b't']
+    )]
+    #[case(AdStruct::BroadcastName(BroadcastNameAdStruct::try_new("Gate").unwrap()), &[0x05, 0x30, b'G', b'a', b't', b'e'])]
+    #[case(
+        AdStruct::ChannelMapUpdateIndication(ChannelMapUpdateIndicationAdStruct::try_new(0x1F_FFFF_FFFF, 0x0100).unwrap()),
+        &[0x08, 0x28, 0xFF, 0xFF, 0xFF, 0xFF, 0x1F, 0x00, 0x01]
+    )]
+    #[case(AdStruct::ClassOfDevice(ClassOfDeviceAdStruct::new(ClassOfDevice::try_new(0x24_0404).unwrap())), &[0x04, 0x0D, 0x04, 0x04, 0x24])]
+    #[case(
+        AdStruct::ElectronicShelfLabel(ElectronicShelfLabelAdStruct::try_new([0x01, 0x02, 0x03, 0x04, 0x05], &[0x10], [0xA1, 0xA2, 0xA3, 0xA4]).unwrap()),
+        &[0x0B, 0x34, 0x01, 0x02, 0x03, 0x04, 0x05, 0x10, 0xA1, 0xA2, 0xA3, 0xA4]
+    )]
+    #[case(AdStruct::IndoorPositioning(IndoorPositioningAdStruct::new(IndoorPositioning::new().with_floor_number(22))), &[0x03, 0x25, 0x10, 0x16])]
+    #[case(
+        AdStruct::LeBluetoothDeviceAddress(LeBluetoothDeviceAddressAdStruct::new(PublicDeviceAddress::new([0xCD, 0x2E, 0x0B, 0x04, 0x32, 0x56]).into())),
+        &[0x08, 0x1B, 0xCD, 0x2E, 0x0B, 0x04, 0x32, 0x56, 0x00]
+    )]
+    #[case(AdStruct::LeRole(LeRoleAdStruct::new(LeRole::PeripheralPreferred)), &[0x02, 0x1C, 0x02])]
+    #[case(
+        AdStruct::LeSecureConnectionsConfirmationValue(LeSecureConnectionsConfirmationValueAdStruct::new(0x0F0E0D0C_0B0A0908_07060504_03020100)),
+        &[0x11, 0x22, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F]
+    )]
+    #[case(
+        AdStruct::LeSecureConnectionsRandomValue(LeSecureConnectionsRandomValueAdStruct::new(0x0F0E0D0C_0B0A0908_07060504_03020100)),
+        &[0x11, 0x23, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F]
+    )]
     #[case(AdStruct::Flags(FlagsAdStruct::new(Flags::LE_GENERAL_DISCOVERABLE_MODE | Flags::BREDR_NOT_SUPPORTED)), &[0x02, 0x01, 0x06])]
     #[case(AdStruct::LeSupportedFeatures(LeSupportedFeaturesAdStruct::new(SupportedLeFeatures::default())), &[0x01, 0x27])]
     #[case(
@@ -167,6 +292,10 @@ mod test {
         &[0x1E, 0xFF, 0x4C, 0x00, 0x12, 0x19, 0x00, 0x9A, 0x9A, 0xE9, 0x80, 0x96, 0x3C, 0xA0, 0x14, 0xFB, 0xE2,
             0x14, 0x41, 0x88, 0xF5, 0xDA, 0xB6, 0x07, 0x99, 0xD3, 0x15, 0x57, 0x6C, 0x01, 0x00]
     )]
+    #[case(
+        AdStruct::PeriodicAdvertisingResponseTimingInformation(PeriodicAdvertisingResponseTimingInformationAdStruct::new(0x8E89BED6, 4, 24, 2, 10)),
+        &[0x09, 0x32, 0xD6, 0xBE, 0x89, 0x8E, 0x04, 0x18, 0x02, 0x0A]
+    )]
     #[case(
         AdStruct::PeripheralConnectionIntervalRange(
             PeripheralConnectionIntervalRangeAdStruct::new(PeripheralConnectionIntervalRange::try_new(0x0006.try_into().unwrap(), 0x0C80.try_into().unwrap()).unwrap())
@@ -185,6 +314,20 @@ mod test {
         ),
         &[0x07, 0x18, 0x28, 0xC8, 0xE9, 0x7D, 0x6A, 0xF7]
     )]
+    #[case(
+        AdStruct::ResolvableSetIdentifier(ResolvableSetIdentifierAdStruct::new([0x01, 0x02, 0x03, 0x04, 0x05, 0x06])),
+        &[0x07, 0x2E, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06]
+    )]
+    #[case(
+        AdStruct::SecurityManagerOutOfBandFlags(SecurityManagerOutOfBandFlagsAdStruct::new(
+            SecurityManagerOutOfBandFlags::OOB_DATA_PRESENT | SecurityManagerOutOfBandFlags::LE_SUPPORTED_HOST
+        )),
+        &[0x02, 0x11, 0x03]
+    )]
+    #[case(
+        AdStruct::SecurityManagerTkValue(SecurityManagerTkValueAdStruct::new(0x0F0E0D0C_0B0A0908_07060504_03020100)),
+        &[0x11, 0x10, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F]
+    )]
     #[case(
         AdStruct::ServiceDataUuid16(
             ServiceDataUuid16AdStruct::try_new(ServiceUuid::LinkLoss, &[0x01, 0x14]).unwrap()
@@ -242,6 +385,14 @@ mod test {
         ).unwrap()),
         &[0x11, 0x06, 0x40, 0xD6, 0x6E, 0xFD, 0xD0, 0x11, 0x2C, 0xAD, 0x9E, 0x4C, 0x7D, 0x22, 0x7E, 0x28, 0xA1, 0xF5]
     )]
+    #[case(
+        AdStruct::ThreeDimensionalInformation(ThreeDimensionalInformationAdStruct::new(ThreeDimensionalInformationFlags::ASSOCIATION_NOTIFICATION, 70)),
+        &[0x03, 0x3D, 0x01, 0x46]
+    )]
+    #[case(
+        AdStruct::TransportDiscoveryData(TransportDiscoveryDataAdStruct::try_new(&[TransportBlock::new(0x01, 0x02, &[0xAA])]).unwrap()),
+        &[0x05, 0x26, 0x01, 0x02, 0x01, 0xAA]
+    )]
     #[case(AdStruct::TxPowerLevel(TxPowerLevelAdStruct::new(TxPowerLevel::try_new(20).unwrap())), &[0x02, 0x0A, 0x14])]
     #[case(
         AdStruct::Uri(UriAdStruct::new(Uri::try_new(ProvisionedUriScheme::Http, "//example.org/").unwrap())),
@@ -256,13 +407,12 @@ mod test {
     }
 
     #[test]
-    fn test_ad_struct_encode_failure() {
-        let ad_struct = AdStruct::Unhandled(0x30);
+    fn test_ad_struct_encode_unhandled() {
+        let ad_struct =
+            AdStruct::Unhandled(UnhandledAdStruct::try_new(0x29, &[0x00, 0x01]).unwrap());
         let mut buffer: Buffer<32> = Buffer::default();
-        assert_eq!(
-            ad_struct.encode(&mut buffer),
-            Err(bletio_utils::Error::CannotEncode)
-        );
-        assert_eq!(ad_struct.encoded_size(), 0);
+        assert_eq!(ad_struct.encode(&mut buffer), Ok(4));
+        assert_eq!(ad_struct.encoded_size(), 4);
+        assert_eq!(buffer.data(), &[0x03, 0x29, 0x00, 0x01]);
     }
 }
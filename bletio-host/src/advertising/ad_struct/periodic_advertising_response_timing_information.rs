@@ -0,0 +1,165 @@
+use bletio_utils::EncodeToBuffer;
+
+use crate::assigned_numbers::AdType;
+
+const PERIODIC_ADVERTISING_RESPONSE_TIMING_INFORMATION_AD_STRUCT_SIZE: usize = 9;
+
+/// The timing information of the response slots of a Periodic Advertising with Responses train.
+///
+/// This Advertising Structure is meant to be used in the Additional Controller Advertising Data,
+/// as defined in
+/// [Supplement to the Bluetooth Core Specification, Part A, 1.24](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/CSS_v12/CSS/out/en/supplement-to-the-bluetooth-core-specification/data-types-specification.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PeriodicAdvertisingResponseTimingInformationAdStruct {
+    response_access_address: u32,
+    num_subevents: u8,
+    subevent_interval: u8,
+    response_slot_delay: u8,
+    response_slot_spacing: u8,
+}
+
+impl PeriodicAdvertisingResponseTimingInformationAdStruct {
+    pub(crate) const fn new(
+        response_access_address: u32,
+        num_subevents: u8,
+        subevent_interval: u8,
+        response_slot_delay: u8,
+        response_slot_spacing: u8,
+    ) -> Self {
+        Self {
+            response_access_address,
+            num_subevents,
+            subevent_interval,
+            response_slot_delay,
+            response_slot_spacing,
+        }
+    }
+
+    /// Get the Access Address to use for the responses.
+    pub fn response_access_address(&self) -> u32 {
+        self.response_access_address
+    }
+
+    /// Get the number of subevents.
+    pub fn num_subevents(&self) -> u8 {
+        self.num_subevents
+    }
+
+    /// Get the interval between subevents, in units of 1.25 ms.
+    pub fn subevent_interval(&self) -> u8 {
+        self.subevent_interval
+    }
+
+    /// Get the time between the advertising packet of a subevent and the first response slot,
+    /// in units of 1.25 ms.
+    pub fn response_slot_delay(&self) -> u8 {
+        self.response_slot_delay
+    }
+
+    /// Get the time between response slots, in units of 0.125 ms.
+    pub fn response_slot_spacing(&self) -> u8 {
+        self.response_slot_spacing
+    }
+}
+
+impl EncodeToBuffer for PeriodicAdvertisingResponseTimingInformationAdStruct {
+    fn encode<B: bletio_utils::BufferOps>(
+        &self,
+        buffer: &mut B,
+    ) -> Result<usize, bletio_utils::Error> {
+        buffer.try_push(PERIODIC_ADVERTISING_RESPONSE_TIMING_INFORMATION_AD_STRUCT_SIZE as u8)?;
+        buffer.try_push(AdType::PeriodicAdvertisingResponseTimingInformation as u8)?;
+        buffer.encode_le_u32(self.response_access_address)?;
+        buffer.try_push(self.num_subevents)?;
+        buffer.try_push(self.subevent_interval)?;
+        buffer.try_push(self.response_slot_delay)?;
+        buffer.try_push(self.response_slot_spacing)?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        PERIODIC_ADVERTISING_RESPONSE_TIMING_INFORMATION_AD_STRUCT_SIZE + 1
+    }
+}
+
+pub(crate) mod parser {
+    use nom::{
+        combinator::map,
+        number::complete::{le_u32, le_u8},
+        IResult, Parser,
+    };
+
+    use crate::advertising::ad_struct::AdStruct;
+
+    use super::*;
+
+    pub(crate) fn periodic_advertising_response_timing_information_ad_struct(
+        input: &[u8],
+    ) -> IResult<&[u8], AdStruct> {
+        map(
+            (le_u32, le_u8, le_u8, le_u8, le_u8),
+            |(
+                response_access_address,
+                num_subevents,
+                subevent_interval,
+                response_slot_delay,
+                response_slot_spacing,
+            )| {
+                AdStruct::PeriodicAdvertisingResponseTimingInformation(
+                    PeriodicAdvertisingResponseTimingInformationAdStruct::new(
+                        response_access_address,
+                        num_subevents,
+                        subevent_interval,
+                        response_slot_delay,
+                        response_slot_spacing,
+                    ),
+                )
+            },
+        )
+        .parse(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bletio_utils::{Buffer, BufferOps};
+
+    use crate::advertising::ad_struct::AdStruct;
+
+    use super::{parser::*, *};
+
+    #[test]
+    fn test_periodic_advertising_response_timing_information_ad_struct(
+    ) -> Result<(), bletio_utils::Error> {
+        let mut buffer = Buffer::<10>::default();
+        let ad_struct =
+            PeriodicAdvertisingResponseTimingInformationAdStruct::new(0x8E89BED6, 4, 24, 2, 10);
+        ad_struct.encode(&mut buffer)?;
+        let encoded_data = &[0x09, 0x32, 0xD6, 0xBE, 0x89, 0x8E, 0x04, 0x18, 0x02, 0x0A];
+        assert_eq!(buffer.data(), encoded_data);
+        assert_eq!(ad_struct.response_access_address(), 0x8E89BED6);
+        assert_eq!(ad_struct.num_subevents(), 4);
+        assert_eq!(ad_struct.subevent_interval(), 24);
+        assert_eq!(ad_struct.response_slot_delay(), 2);
+        assert_eq!(ad_struct.response_slot_spacing(), 10);
+        assert_eq!(
+            periodic_advertising_response_timing_information_ad_struct(&encoded_data[2..]),
+            Ok((
+                &[] as &[u8],
+                AdStruct::PeriodicAdvertisingResponseTimingInformation(ad_struct)
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_periodic_advertising_response_timing_information_ad_struct_parsing_failure() {
+        assert!(
+            periodic_advertising_response_timing_information_ad_struct(&[
+                0xD6, 0xBE, 0x89, 0x8E, 0x04, 0x18, 0x02
+            ])
+            .is_err()
+        );
+    }
+}
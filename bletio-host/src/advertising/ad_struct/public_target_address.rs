@@ -75,7 +75,7 @@ pub(crate) mod parser {
 
     pub(crate) fn public_target_address_ad_struct(mut input: &[u8]) -> IResult<&[u8], AdStruct> {
         let len = input.len() / 6;
-        if (len > PUBLIC_TARGET_ADDRESS_NB_MAX_ADDRESSES) || ((input.len() % 6) != 0) {
+        if (len > PUBLIC_TARGET_ADDRESS_NB_MAX_ADDRESSES) || !input.len().is_multiple_of(6) {
             fail::<_, &[u8], _>().parse(input)?;
         }
         let mut ad_struct = PublicTargetAddressAdStruct {
@@ -0,0 +1,97 @@
+use bletio_utils::EncodeToBuffer;
+
+use crate::assigned_numbers::AdType;
+
+const RESOLVABLE_SET_IDENTIFIER_AD_STRUCT_SIZE: usize = 7;
+
+/// The Resolvable Set Identifier (RSI) of the device.
+///
+/// It allows the members of a Coordinated Set to be discovered, as defined in the Coordinated
+/// Set Identification Profile specification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ResolvableSetIdentifierAdStruct {
+    rsi: [u8; 6],
+}
+
+impl ResolvableSetIdentifierAdStruct {
+    pub(crate) const fn new(rsi: [u8; 6]) -> Self {
+        Self { rsi }
+    }
+
+    pub fn value(&self) -> &[u8; 6] {
+        &self.rsi
+    }
+}
+
+impl EncodeToBuffer for ResolvableSetIdentifierAdStruct {
+    fn encode<B: bletio_utils::BufferOps>(
+        &self,
+        buffer: &mut B,
+    ) -> Result<usize, bletio_utils::Error> {
+        buffer.try_push(RESOLVABLE_SET_IDENTIFIER_AD_STRUCT_SIZE as u8)?;
+        buffer.try_push(AdType::ResolvableSetIdentifier as u8)?;
+        buffer.copy_from_slice(&self.rsi)?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        RESOLVABLE_SET_IDENTIFIER_AD_STRUCT_SIZE + 1
+    }
+}
+
+pub(crate) mod parser {
+    use nom::{
+        bytes::take,
+        combinator::{map, map_res},
+        IResult, Parser,
+    };
+
+    use crate::advertising::ad_struct::AdStruct;
+
+    use super::*;
+
+    pub(crate) fn resolvable_set_identifier_ad_struct(input: &[u8]) -> IResult<&[u8], AdStruct> {
+        map(map_res(take(6u8), TryInto::try_into), |rsi| {
+            AdStruct::ResolvableSetIdentifier(ResolvableSetIdentifierAdStruct::new(rsi))
+        })
+        .parse(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bletio_utils::{Buffer, BufferOps};
+
+    use crate::advertising::ad_struct::AdStruct;
+
+    use super::{parser::*, *};
+
+    #[test]
+    fn test_resolvable_set_identifier_ad_struct() -> Result<(), bletio_utils::Error> {
+        let rsi = [0x9B, 0x7A, 0x2C, 0x12, 0x34, 0x56];
+        let mut buffer = Buffer::<8>::default();
+        let ad_struct = ResolvableSetIdentifierAdStruct::new(rsi);
+        ad_struct.encode(&mut buffer)?;
+        assert_eq!(
+            buffer.data(),
+            &[0x07, 0x2E, 0x9B, 0x7A, 0x2C, 0x12, 0x34, 0x56]
+        );
+        assert_eq!(ad_struct.value(), &rsi);
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolvable_set_identifier_ad_struct_parsing() {
+        assert_eq!(
+            resolvable_set_identifier_ad_struct(&[0x9B, 0x7A, 0x2C, 0x12, 0x34, 0x56]),
+            Ok((
+                &[] as &[u8],
+                AdStruct::ResolvableSetIdentifier(ResolvableSetIdentifierAdStruct::new([
+                    0x9B, 0x7A, 0x2C, 0x12, 0x34, 0x56
+                ]))
+            ))
+        );
+        assert!(resolvable_set_identifier_ad_struct(&[0x9B, 0x7A, 0x2C]).is_err());
+    }
+}
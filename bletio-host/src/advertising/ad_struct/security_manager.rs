@@ -0,0 +1,217 @@
+#[cfg(not(feature = "defmt"))]
+use bitflags::bitflags;
+#[cfg(feature = "defmt")]
+use defmt::bitflags;
+
+use bletio_utils::EncodeToBuffer;
+
+use crate::assigned_numbers::AdType;
+
+const SECURITY_MANAGER_TK_VALUE_AD_STRUCT_SIZE: usize = 17;
+const SECURITY_MANAGER_OUT_OF_BAND_FLAGS_AD_STRUCT_SIZE: usize = 2;
+
+/// The Temporary Key (TK) value of the Security Manager, used for LE legacy pairing.
+///
+/// This Advertising Structure shall only be used in an Out-of-Band data block, as defined in
+/// [Supplement to the Bluetooth Core Specification, Part A, 1.8](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/CSS_v12/CSS/out/en/supplement-to-the-bluetooth-core-specification/data-types-specification.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SecurityManagerTkValueAdStruct {
+    value: u128,
+}
+
+impl SecurityManagerTkValueAdStruct {
+    pub(crate) const fn new(value: u128) -> Self {
+        Self { value }
+    }
+
+    pub fn value(&self) -> u128 {
+        self.value
+    }
+}
+
+impl EncodeToBuffer for SecurityManagerTkValueAdStruct {
+    fn encode<B: bletio_utils::BufferOps>(
+        &self,
+        buffer: &mut B,
+    ) -> Result<usize, bletio_utils::Error> {
+        buffer.try_push(SECURITY_MANAGER_TK_VALUE_AD_STRUCT_SIZE as u8)?;
+        buffer.try_push(AdType::SecurityManagerTkValue as u8)?;
+        buffer.encode_le_u128(self.value)?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        SECURITY_MANAGER_TK_VALUE_AD_STRUCT_SIZE + 1
+    }
+}
+
+/// The Out-of-Band flags of the Security Manager.
+///
+/// This Advertising Structure shall only be used in an Out-of-Band data block, as defined in
+/// [Supplement to the Bluetooth Core Specification, Part A, 1.7](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/CSS_v12/CSS/out/en/supplement-to-the-bluetooth-core-specification/data-types-specification.html).
+///
+/// See [`SecurityManagerOutOfBandFlags`] for more information about each of the flags.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SecurityManagerOutOfBandFlagsAdStruct {
+    flags: SecurityManagerOutOfBandFlags,
+}
+
+impl SecurityManagerOutOfBandFlagsAdStruct {
+    pub(crate) const fn new(flags: SecurityManagerOutOfBandFlags) -> Self {
+        Self { flags }
+    }
+
+    pub fn value(&self) -> SecurityManagerOutOfBandFlags {
+        self.flags
+    }
+}
+
+impl EncodeToBuffer for SecurityManagerOutOfBandFlagsAdStruct {
+    fn encode<B: bletio_utils::BufferOps>(
+        &self,
+        buffer: &mut B,
+    ) -> Result<usize, bletio_utils::Error> {
+        buffer.try_push(SECURITY_MANAGER_OUT_OF_BAND_FLAGS_AD_STRUCT_SIZE as u8)?;
+        buffer.try_push(AdType::SecurityManagerOutOfBandFlags as u8)?;
+        buffer.try_push(self.flags.bits())?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        SECURITY_MANAGER_OUT_OF_BAND_FLAGS_AD_STRUCT_SIZE + 1
+    }
+}
+
+bitflags! {
+    /// Flags to be used in a
+    /// [SecurityManagerOutOfBandFlagsAdStruct](crate::advertising::ad_struct::SecurityManagerOutOfBandFlagsAdStruct)
+    /// Advertising Structure, as defined in
+    /// [Supplement to the Bluetooth Core Specification, Part A, 1.7](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/CSS_v12/CSS/out/en/supplement-to-the-bluetooth-core-specification/data-types-specification.html).
+    #[cfg_attr(not(feature = "defmt"), derive(Debug, Clone, Copy, PartialEq, Eq))]
+    pub struct SecurityManagerOutOfBandFlags: u8 {
+        /// Out-of-Band data present.
+        const OOB_DATA_PRESENT = 1 << 0;
+        /// LE supported (Host).
+        const LE_SUPPORTED_HOST = 1 << 1;
+        /// Simultaneous LE and BR/EDR to Same Device Capable (Host).
+        const SIMULTANEOUS_LE_AND_BREDR_TO_SAME_DEVICE_CAPABLE_HOST = 1 << 2;
+        /// The address sent in the Out-of-Band data block is a random address (public otherwise).
+        const RANDOM_ADDRESS = 1 << 3;
+    }
+}
+
+impl From<u8> for SecurityManagerOutOfBandFlags {
+    fn from(value: u8) -> Self {
+        Self::from_bits_truncate(value)
+    }
+}
+
+pub(crate) mod parser {
+    use nom::{
+        combinator::map,
+        number::complete::{le_u128, le_u8},
+        IResult, Parser,
+    };
+
+    use crate::advertising::ad_struct::AdStruct;
+
+    use super::*;
+
+    pub(crate) fn security_manager_tk_value_ad_struct(input: &[u8]) -> IResult<&[u8], AdStruct> {
+        map(le_u128, |value| {
+            AdStruct::SecurityManagerTkValue(SecurityManagerTkValueAdStruct::new(value))
+        })
+        .parse(input)
+    }
+
+    pub(crate) fn security_manager_out_of_band_flags_ad_struct(
+        input: &[u8],
+    ) -> IResult<&[u8], AdStruct> {
+        map(le_u8, |flags| {
+            AdStruct::SecurityManagerOutOfBandFlags(SecurityManagerOutOfBandFlagsAdStruct::new(
+                flags.into(),
+            ))
+        })
+        .parse(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bletio_utils::{Buffer, BufferOps};
+    use rstest::rstest;
+
+    use crate::advertising::ad_struct::AdStruct;
+
+    use super::{parser::*, *};
+
+    #[test]
+    fn test_security_manager_tk_value_ad_struct() -> Result<(), bletio_utils::Error> {
+        let mut buffer = Buffer::<18>::default();
+        let ad_struct = SecurityManagerTkValueAdStruct::new(0x000F_423F);
+        ad_struct.encode(&mut buffer)?;
+        assert_eq!(
+            buffer.data(),
+            &[
+                0x11, 0x10, 0x3F, 0x42, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00
+            ]
+        );
+        assert_eq!(ad_struct.value(), 0x000F_423F);
+        Ok(())
+    }
+
+    #[test]
+    fn test_security_manager_tk_value_ad_struct_parsing() {
+        assert_eq!(
+            security_manager_tk_value_ad_struct(&[
+                0x3F, 0x42, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00
+            ]),
+            Ok((
+                &[] as &[u8],
+                AdStruct::SecurityManagerTkValue(SecurityManagerTkValueAdStruct::new(0x000F_423F))
+            ))
+        );
+        assert!(security_manager_tk_value_ad_struct(&[0x3F, 0x42, 0x0F]).is_err());
+    }
+
+    #[rstest]
+    #[case(SecurityManagerOutOfBandFlags::empty(), &[0x02, 0x11, 0x00])]
+    #[case(
+        SecurityManagerOutOfBandFlags::OOB_DATA_PRESENT | SecurityManagerOutOfBandFlags::LE_SUPPORTED_HOST
+            | SecurityManagerOutOfBandFlags::RANDOM_ADDRESS,
+        &[0x02, 0x11, 0x0B]
+    )]
+    fn test_security_manager_out_of_band_flags_ad_struct(
+        #[case] flags: SecurityManagerOutOfBandFlags,
+        #[case] encoded_data: &[u8],
+    ) -> Result<(), bletio_utils::Error> {
+        let mut buffer = Buffer::<3>::default();
+        let ad_struct = SecurityManagerOutOfBandFlagsAdStruct::new(flags);
+        ad_struct.encode(&mut buffer)?;
+        assert_eq!(buffer.data(), encoded_data);
+        assert_eq!(ad_struct.value(), flags);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(&[0x03], SecurityManagerOutOfBandFlags::OOB_DATA_PRESENT | SecurityManagerOutOfBandFlags::LE_SUPPORTED_HOST)]
+    #[case(&[0xFF], SecurityManagerOutOfBandFlags::all())]
+    fn test_security_manager_out_of_band_flags_ad_struct_parsing(
+        #[case] input: &[u8],
+        #[case] flags: SecurityManagerOutOfBandFlags,
+    ) {
+        assert_eq!(
+            security_manager_out_of_band_flags_ad_struct(input),
+            Ok((
+                &[] as &[u8],
+                AdStruct::SecurityManagerOutOfBandFlags(
+                    SecurityManagerOutOfBandFlagsAdStruct::new(flags)
+                )
+            ))
+        );
+    }
+}
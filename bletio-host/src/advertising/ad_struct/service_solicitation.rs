@@ -178,7 +178,7 @@ pub(crate) mod parser {
         mut input: &[u8],
     ) -> IResult<&[u8], AdStruct> {
         let len = input.len() / 2;
-        if (len > SERVICE_SOLICITATION_UUID16_NB_MAX) || ((input.len() % 2) != 0) {
+        if (len > SERVICE_SOLICITATION_UUID16_NB_MAX) || !input.len().is_multiple_of(2) {
             fail::<_, &[u8], _>().parse(input)?;
         }
         let mut ad_struct = ServiceSolicitationUuid16AdStruct {
@@ -198,7 +198,7 @@ pub(crate) mod parser {
         mut input: &[u8],
     ) -> IResult<&[u8], AdStruct> {
         let len = input.len() / 4;
-        if (len > SERVICE_SOLICITATION_UUID32_NB_MAX) || ((input.len() % 4) != 0) {
+        if (len > SERVICE_SOLICITATION_UUID32_NB_MAX) || !input.len().is_multiple_of(4) {
             fail::<_, &[u8], _>().parse(input)?;
         }
         let mut ad_struct = ServiceSolicitationUuid32AdStruct {
@@ -217,7 +217,7 @@ pub(crate) mod parser {
         mut input: &[u8],
     ) -> IResult<&[u8], AdStruct> {
         let len = input.len() / 16;
-        if (len > SERVICE_SOLICITATION_UUID128_NB_MAX) || ((input.len() % 16) != 0) {
+        if (len > SERVICE_SOLICITATION_UUID128_NB_MAX) || !input.len().is_multiple_of(16) {
             fail::<_, &[u8], _>().parse(input)?;
         }
         let mut ad_struct = ServiceSolicitationUuid128AdStruct {
@@ -229,7 +229,7 @@ pub(crate) mod parser {
         mut input: &[u8],
     ) -> IResult<&[u8], AdStruct> {
         let len = input.len() / 2;
-        if (len > SERVICE_UUID16_NB_MAX) || ((input.len() % 2) != 0) {
+        if (len > SERVICE_UUID16_NB_MAX) || !input.len().is_multiple_of(2) {
             fail::<_, &[u8], _>().parse(input)?;
         }
         let mut ad_struct = ServiceUuid16AdStruct {
@@ -250,7 +250,7 @@ pub(crate) mod parser {
         mut input: &[u8],
     ) -> IResult<&[u8], AdStruct> {
         let len = input.len() / 2;
-        if !(1..=SERVICE_UUID16_NB_MAX).contains(&len) || ((input.len() % 2) != 0) {
+        if !(1..=SERVICE_UUID16_NB_MAX).contains(&len) || !input.len().is_multiple_of(2) {
             fail::<_, &[u8], _>().parse(input)?;
         }
         let mut ad_struct = ServiceUuid16AdStruct {
@@ -271,7 +271,7 @@ pub(crate) mod parser {
         mut input: &[u8],
     ) -> IResult<&[u8], AdStruct> {
         let len = input.len() / 4;
-        if (len > SERVICE_UUID32_NB_MAX) || ((input.len() % 4) != 0) {
+        if (len > SERVICE_UUID32_NB_MAX) || !input.len().is_multiple_of(4) {
             fail::<_, &[u8], _>().parse(input)?;
         }
         let mut ad_struct = ServiceUuid32AdStruct {
@@ -291,7 +291,7 @@ pub(crate) mod parser {
         mut input: &[u8],
     ) -> IResult<&[u8], AdStruct> {
         let len = input.len() / 4;
-        if !(1..=SERVICE_UUID32_NB_MAX).contains(&len) || ((input.len() % 4) != 0) {
+        if !(1..=SERVICE_UUID32_NB_MAX).contains(&len) || !input.len().is_multiple_of(4) {
             fail::<_, &[u8], _>().parse(input)?;
         }
         let mut ad_struct = ServiceUuid32AdStruct {
@@ -311,7 +311,7 @@ pub(crate) mod parser {
         mut input: &[u8],
     ) -> IResult<&[u8], AdStruct> {
         let len = input.len() / 16;
-        if (len > SERVICE_UUID128_NB_MAX) || ((input.len() % 16) != 0) {
+        if (len > SERVICE_UUID128_NB_MAX) || !input.len().is_multiple_of(16) {
             fail::<_, &[u8], _>().parse(input)?;
         }
         let mut ad_struct = ServiceUuid128AdStruct {
@@ -331,7 +331,7 @@ pub(crate) mod parser {
         mut input: &[u8],
     ) -> IResult<&[u8], AdStruct> {
         let len = input.len() / 16;
-        if !(1..=SERVICE_UUID128_NB_MAX).contains(&len) || ((input.len() % 16) != 0) {
+        if !(1..=SERVICE_UUID128_NB_MAX).contains(&len) || !input.len().is_multiple_of(16) {
             fail::<_, &[u8], _>().parse(input)?;
         }
         let mut ad_struct = ServiceUuid128AdStruct {
@@ -0,0 +1,158 @@
+#[cfg(not(feature = "defmt"))]
+use bitflags::bitflags;
+#[cfg(feature = "defmt")]
+use defmt::bitflags;
+
+use bletio_utils::EncodeToBuffer;
+
+use crate::assigned_numbers::AdType;
+
+const THREE_DIMENSIONAL_INFORMATION_AD_STRUCT_SIZE: usize = 3;
+
+/// Information sent by a 3D Display to 3D Glasses, as defined in the 3D Synchronization Profile
+/// specification.
+///
+/// See [`ThreeDimensionalInformationFlags`] for more information about each of the flags.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ThreeDimensionalInformationAdStruct {
+    flags: ThreeDimensionalInformationFlags,
+    path_loss_threshold: u8,
+}
+
+impl ThreeDimensionalInformationAdStruct {
+    pub(crate) const fn new(
+        flags: ThreeDimensionalInformationFlags,
+        path_loss_threshold: u8,
+    ) -> Self {
+        Self {
+            flags,
+            path_loss_threshold,
+        }
+    }
+
+    pub fn flags(&self) -> ThreeDimensionalInformationFlags {
+        self.flags
+    }
+
+    /// Get the path loss threshold, in dB, under which the 3D Glasses may associate with the
+    /// 3D Display.
+    pub fn path_loss_threshold(&self) -> u8 {
+        self.path_loss_threshold
+    }
+}
+
+impl EncodeToBuffer for ThreeDimensionalInformationAdStruct {
+    fn encode<B: bletio_utils::BufferOps>(
+        &self,
+        buffer: &mut B,
+    ) -> Result<usize, bletio_utils::Error> {
+        buffer.try_push(THREE_DIMENSIONAL_INFORMATION_AD_STRUCT_SIZE as u8)?;
+        buffer.try_push(AdType::ThreeDimensionalInformationData as u8)?;
+        buffer.try_push(self.flags.bits())?;
+        buffer.try_push(self.path_loss_threshold)?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        THREE_DIMENSIONAL_INFORMATION_AD_STRUCT_SIZE + 1
+    }
+}
+
+bitflags! {
+    /// Flags to be used in a
+    /// [ThreeDimensionalInformationAdStruct](crate::advertising::ad_struct::ThreeDimensionalInformationAdStruct)
+    /// Advertising Structure, as defined in the 3D Synchronization Profile specification.
+    #[cfg_attr(not(feature = "defmt"), derive(Debug, Clone, Copy, PartialEq, Eq))]
+    pub struct ThreeDimensionalInformationFlags: u8 {
+        /// The 3D Display supports the Association Notification.
+        const ASSOCIATION_NOTIFICATION = 1 << 0;
+        /// The 3D Display supports the Battery Level Reporting.
+        const BATTERY_LEVEL_REPORTING = 1 << 1;
+        /// The 3D Display requests the 3D Glasses to send a battery level report at start-up
+        /// synchronization.
+        const SEND_BATTERY_LEVEL_REPORT_ON_STARTUP = 1 << 2;
+        /// The 3D Display is in factory test mode.
+        const FACTORY_TEST_MODE = 1 << 7;
+    }
+}
+
+impl From<u8> for ThreeDimensionalInformationFlags {
+    fn from(value: u8) -> Self {
+        Self::from_bits_truncate(value)
+    }
+}
+
+pub(crate) mod parser {
+    use nom::{combinator::map, number::complete::le_u8, IResult, Parser};
+
+    use crate::advertising::ad_struct::AdStruct;
+
+    use super::*;
+
+    pub(crate) fn three_dimensional_information_ad_struct(
+        input: &[u8],
+    ) -> IResult<&[u8], AdStruct> {
+        map((le_u8, le_u8), |(flags, path_loss_threshold)| {
+            AdStruct::ThreeDimensionalInformation(ThreeDimensionalInformationAdStruct::new(
+                flags.into(),
+                path_loss_threshold,
+            ))
+        })
+        .parse(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bletio_utils::{Buffer, BufferOps};
+    use rstest::rstest;
+
+    use crate::advertising::ad_struct::AdStruct;
+
+    use super::{parser::*, *};
+
+    #[rstest]
+    #[case(ThreeDimensionalInformationFlags::empty(), 0, &[0x03, 0x3D, 0x00, 0x00])]
+    #[case(
+        ThreeDimensionalInformationFlags::ASSOCIATION_NOTIFICATION
+            | ThreeDimensionalInformationFlags::BATTERY_LEVEL_REPORTING,
+        70,
+        &[0x03, 0x3D, 0x03, 0x46]
+    )]
+    fn test_three_dimensional_information_ad_struct(
+        #[case] flags: ThreeDimensionalInformationFlags,
+        #[case] path_loss_threshold: u8,
+        #[case] encoded_data: &[u8],
+    ) -> Result<(), bletio_utils::Error> {
+        let mut buffer = Buffer::<4>::default();
+        let ad_struct = ThreeDimensionalInformationAdStruct::new(flags, path_loss_threshold);
+        ad_struct.encode(&mut buffer)?;
+        assert_eq!(buffer.data(), encoded_data);
+        assert_eq!(ad_struct.flags(), flags);
+        assert_eq!(ad_struct.path_loss_threshold(), path_loss_threshold);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(&[0x84, 0x46], ThreeDimensionalInformationFlags::SEND_BATTERY_LEVEL_REPORT_ON_STARTUP
+        | ThreeDimensionalInformationFlags::FACTORY_TEST_MODE, 70)]
+    #[case(&[0xFF, 0x00], ThreeDimensionalInformationFlags::all(), 0)]
+    fn test_three_dimensional_information_ad_struct_parsing(
+        #[case] input: &[u8],
+        #[case] flags: ThreeDimensionalInformationFlags,
+        #[case] path_loss_threshold: u8,
+    ) {
+        assert_eq!(
+            three_dimensional_information_ad_struct(input),
+            Ok((
+                &[] as &[u8],
+                AdStruct::ThreeDimensionalInformation(ThreeDimensionalInformationAdStruct::new(
+                    flags,
+                    path_loss_threshold
+                ))
+            ))
+        );
+        assert!(three_dimensional_information_ad_struct(&input[..1]).is_err());
+    }
+}
@@ -0,0 +1,210 @@
+use bletio_utils::EncodeToBuffer;
+use heapless::Vec;
+
+use crate::advertising::AdvertisingError;
+use crate::assigned_numbers::AdType;
+
+const TRANSPORT_DISCOVERY_DATA_MAX_LENGTH: usize = 29;
+
+/// A Transport Block of a Transport Discovery Data Advertising Structure.
+///
+/// Each Transport Block describes a transport provided by an organization, as defined in the
+/// Transport Discovery Service specification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TransportBlock<'a> {
+    organization_id: u8,
+    flags: u8,
+    data: &'a [u8],
+}
+
+impl<'a> TransportBlock<'a> {
+    /// Create a Transport Block.
+    ///
+    /// # Arguments
+    ///
+    /// * `organization_id` — The Organization ID, from the Bluetooth SIG assigned numbers.
+    /// * `flags` — The TDS flags, containing the role, the transport data incomplete flag and
+    ///   the transport state.
+    /// * `data` — The organization specific transport data.
+    pub const fn new(organization_id: u8, flags: u8, data: &'a [u8]) -> Self {
+        Self {
+            organization_id,
+            flags,
+            data,
+        }
+    }
+
+    pub const fn organization_id(&self) -> u8 {
+        self.organization_id
+    }
+
+    pub const fn flags(&self) -> u8 {
+        self.flags
+    }
+
+    pub const fn data(&self) -> &'a [u8] {
+        self.data
+    }
+}
+
+/// The transports that can be discovered on the device.
+///
+/// Its format is defined in the Transport Discovery Service specification. It contains a list
+/// of [`TransportBlock`]s.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TransportDiscoveryDataAdStruct {
+    blocks: Vec<u8, TRANSPORT_DISCOVERY_DATA_MAX_LENGTH>,
+}
+
+impl TransportDiscoveryDataAdStruct {
+    pub(crate) fn try_new(blocks: &[TransportBlock]) -> Result<Self, AdvertisingError> {
+        let mut ad_struct = Self { blocks: Vec::new() };
+        for block in blocks {
+            let data_len: u8 = block
+                .data
+                .len()
+                .try_into()
+                .map_err(|_| AdvertisingError::AdvertisingDataWillNotFitAdvertisingPacket)?;
+            ad_struct
+                .blocks
+                .extend_from_slice(&[block.organization_id, block.flags, data_len])
+                .and_then(|_| ad_struct.blocks.extend_from_slice(block.data))
+                .map_err(|_| AdvertisingError::AdvertisingDataWillNotFitAdvertisingPacket)?;
+        }
+        Ok(ad_struct)
+    }
+
+    /// Get an iterator over the Transport Blocks.
+    pub fn iter(&self) -> TransportBlockIterator<'_> {
+        TransportBlockIterator {
+            data: self.blocks.as_slice(),
+        }
+    }
+}
+
+impl EncodeToBuffer for TransportDiscoveryDataAdStruct {
+    fn encode<B: bletio_utils::BufferOps>(
+        &self,
+        buffer: &mut B,
+    ) -> Result<usize, bletio_utils::Error> {
+        buffer.try_push((self.encoded_size() - 1) as u8)?;
+        buffer.try_push(AdType::TransportDiscoveryData as u8)?;
+        buffer.copy_from_slice(self.blocks.as_slice())?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        self.blocks.len() + 2
+    }
+}
+
+/// Iterator over the Transport Blocks of a Transport Discovery Data Advertising Structure.
+#[derive(Debug, Clone)]
+pub struct TransportBlockIterator<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Iterator for TransportBlockIterator<'a> {
+    type Item = TransportBlock<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // INVARIANT: The blocks have been validated when creating or parsing the Advertising Structure.
+        let (rest, block) = parser::transport_block(self.data).ok()?;
+        self.data = rest;
+        Some(block)
+    }
+}
+
+pub(crate) mod parser {
+    use nom::{
+        bytes::take,
+        combinator::{map, verify},
+        multi::{length_data, many1_count},
+        number::complete::le_u8,
+        IResult, Parser,
+    };
+
+    use crate::advertising::ad_struct::AdStruct;
+
+    use super::*;
+
+    pub(crate) fn transport_block(input: &[u8]) -> IResult<&[u8], TransportBlock<'_>> {
+        map(
+            (le_u8, le_u8, length_data(le_u8)),
+            |(organization_id, flags, data)| TransportBlock::new(organization_id, flags, data),
+        )
+        .parse(input)
+    }
+
+    pub(crate) fn transport_discovery_data_ad_struct(input: &[u8]) -> IResult<&[u8], AdStruct> {
+        let (rest, blocks) = verify(take(input.len()), |blocks: &[u8]| {
+            blocks.len() <= TRANSPORT_DISCOVERY_DATA_MAX_LENGTH
+                && many1_count(transport_block)
+                    .parse(blocks)
+                    .is_ok_and(|(rest, _)| rest.is_empty())
+        })
+        .parse(input)?;
+        Ok((
+            rest,
+            AdStruct::TransportDiscoveryData(TransportDiscoveryDataAdStruct {
+                // INVARIANT: The length of the blocks has been checked above.
+                blocks: blocks.try_into().unwrap(),
+            }),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bletio_utils::{Buffer, BufferOps};
+    use rstest::rstest;
+
+    use crate::advertising::ad_struct::AdStruct;
+
+    use super::{parser::*, *};
+
+    #[rstest]
+    #[case(&[TransportBlock::new(0x01, 0x09, &[])], &[0x04, 0x26, 0x01, 0x09, 0x00])]
+    #[case(
+        &[TransportBlock::new(0x01, 0x0A, &[0x12, 0x34]), TransportBlock::new(0x02, 0x01, &[0x56])],
+        &[0x0A, 0x26, 0x01, 0x0A, 0x02, 0x12, 0x34, 0x02, 0x01, 0x01, 0x56]
+    )]
+    fn test_transport_discovery_data_ad_struct_success(
+        #[case] blocks: &[TransportBlock],
+        #[case] encoded_data: &[u8],
+    ) -> Result<(), bletio_utils::Error> {
+        let mut buffer = Buffer::<31>::default();
+        let ad_struct = TransportDiscoveryDataAdStruct::try_new(blocks).unwrap();
+        ad_struct.encode(&mut buffer)?;
+        assert_eq!(buffer.data(), encoded_data);
+        assert!(ad_struct.iter().eq(blocks.iter().copied()));
+        assert_eq!(
+            transport_discovery_data_ad_struct(&encoded_data[2..]),
+            Ok((&[] as &[u8], AdStruct::TransportDiscoveryData(ad_struct)))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_transport_discovery_data_ad_struct_failure() {
+        let err = TransportDiscoveryDataAdStruct::try_new(&[
+            TransportBlock::new(0x01, 0x00, &[0; 20]),
+            TransportBlock::new(0x02, 0x00, &[0; 4]),
+        ]);
+        assert_eq!(
+            err,
+            Err(AdvertisingError::AdvertisingDataWillNotFitAdvertisingPacket)
+        );
+    }
+
+    #[rstest]
+    #[case(&[])]
+    #[case(&[0x01, 0x0A])]
+    #[case(&[0x01, 0x0A, 0x02, 0x12])]
+    #[case(&[0x01, 0x0A, 0x01, 0x12, 0x34])]
+    fn test_transport_discovery_data_ad_struct_parsing_failure(#[case] input: &[u8]) {
+        assert!(transport_discovery_data_ad_struct(input).is_err());
+    }
+}
@@ -0,0 +1,122 @@
+use bletio_utils::EncodeToBuffer;
+use heapless::Vec;
+
+use crate::advertising::AdvertisingError;
+
+const UNHANDLED_DATA_MAX_LENGTH: usize = 29;
+
+/// Advertising Structure whose type is not decoded.
+///
+/// Its AD type and its raw data are kept as is, so that they can be interpreted by the
+/// application, for example for the Mesh or the Encrypted Advertising Data types, or for types
+/// that have been assigned after this version of the stack.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct UnhandledAdStruct {
+    ad_type: u8,
+    data: Vec<u8, UNHANDLED_DATA_MAX_LENGTH>,
+}
+
+impl UnhandledAdStruct {
+    pub(crate) fn try_new(ad_type: u8, data: &[u8]) -> Result<Self, AdvertisingError> {
+        Ok(Self {
+            ad_type,
+            data: data
+                .try_into()
+                .map_err(|_| AdvertisingError::AdvertisingDataWillNotFitAdvertisingPacket)?,
+        })
+    }
+
+    pub fn ad_type(&self) -> u8 {
+        self.ad_type
+    }
+
+    pub fn data(&self) -> &[u8] {
+        self.data.as_slice()
+    }
+}
+
+impl EncodeToBuffer for UnhandledAdStruct {
+    fn encode<B: bletio_utils::BufferOps>(
+        &self,
+        buffer: &mut B,
+    ) -> Result<usize, bletio_utils::Error> {
+        buffer.try_push((self.encoded_size() - 1) as u8)?;
+        buffer.try_push(self.ad_type)?;
+        buffer.copy_from_slice(self.data.as_slice())?;
+        Ok(self.encoded_size())
+    }
+
+    fn encoded_size(&self) -> usize {
+        self.data.len() + 2
+    }
+}
+
+pub(crate) mod parser {
+    use nom::{combinator::fail, IResult, Parser};
+
+    use crate::advertising::ad_struct::AdStruct;
+
+    use super::*;
+
+    pub(crate) fn unhandled_ad_struct(ad_type: u8, input: &[u8]) -> IResult<&[u8], AdStruct> {
+        match UnhandledAdStruct::try_new(ad_type, input) {
+            Ok(ad_struct) => Ok((&[], AdStruct::Unhandled(ad_struct))),
+            Err(_) => fail().parse(input),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bletio_utils::{Buffer, BufferOps};
+    use rstest::rstest;
+
+    use crate::advertising::ad_struct::AdStruct;
+
+    use super::{parser::*, *};
+
+    #[rstest]
+    #[case(0x2A, &[], &[0x01, 0x2A])]
+    #[case(0x50, &[0x01, 0x02, 0x03], &[0x04, 0x50, 0x01, 0x02, 0x03])]
+    fn test_unhandled_ad_struct_success(
+        #[case] ad_type: u8,
+        #[case] data: &[u8],
+        #[case] encoded_data: &[u8],
+    ) -> Result<(), bletio_utils::Error> {
+        let mut buffer = Buffer::<31>::default();
+        let ad_struct = UnhandledAdStruct::try_new(ad_type, data).unwrap();
+        ad_struct.encode(&mut buffer)?;
+        assert_eq!(buffer.data(), encoded_data);
+        assert_eq!(ad_struct.ad_type(), ad_type);
+        assert_eq!(ad_struct.data(), data);
+        Ok(())
+    }
+
+    #[test]
+    fn test_unhandled_ad_struct_failure() {
+        let err = UnhandledAdStruct::try_new(0x50, &[0; 30]);
+        assert_eq!(
+            err,
+            Err(AdvertisingError::AdvertisingDataWillNotFitAdvertisingPacket)
+        );
+    }
+
+    #[rstest]
+    #[case(0x29, &[0x00, 0x01])]
+    #[case(0x50, &[])]
+    fn test_unhandled_ad_struct_parsing_success(#[case] ad_type: u8, #[case] input: &[u8]) {
+        assert_eq!(
+            unhandled_ad_struct(ad_type, input),
+            Ok((
+                &[] as &[u8],
+                AdStruct::Unhandled(UnhandledAdStruct::try_new(ad_type, input).unwrap())
+            ))
+        );
+    }
+
+    #[test]
+    fn test_unhandled_ad_struct_parsing_failure() {
+        assert!(unhandled_ad_struct(0x50, &[0; 30]).is_err());
+    }
+}
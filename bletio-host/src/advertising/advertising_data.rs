@@ -1,28 +1,40 @@
 use core::marker::PhantomData;
 
 use bletio_hci::{
-    AdvertisingInterval, LeAdvertisingReportData, PublicDeviceAddress, RandomAddress,
-    SupportedLeFeatures, TxPowerLevel,
+    AdvertisingInterval, BroadcastCode, DeviceAddress, LeAdvertisingReportData,
+    PublicDeviceAddress, RandomAddress, SupportedLeFeatures, TxPowerLevel,
 };
-use bletio_utils::{BufferOps, EncodeToBuffer};
+use bletio_utils::{Buffer, BufferOps, EncodeToBuffer};
 
+use crate::advertising::ad_struct::local_name::LOCAL_NAME_MAX_LENGTH;
 use crate::advertising::ad_struct::{
-    AdStruct, AdvertisingIntervalAdStruct, AppearanceAdStruct, FlagsAdStruct,
-    LeSupportedFeaturesAdStruct, LocalNameAdStruct, ManufacturerSpecificDataAdStruct,
+    AdStruct, AdvertisingIntervalAdStruct, AdvertisingIntervalLongAdStruct, AppearanceAdStruct,
+    BroadcastCodeAdStruct, BroadcastNameAdStruct, ChannelMapUpdateIndicationAdStruct,
+    ClassOfDeviceAdStruct, ElectronicShelfLabelAdStruct, FlagsAdStruct, IndoorPositioningAdStruct,
+    LeBluetoothDeviceAddressAdStruct, LeRoleAdStruct, LeSecureConnectionsConfirmationValueAdStruct,
+    LeSecureConnectionsRandomValueAdStruct, LeSupportedFeaturesAdStruct, LocalNameAdStruct,
+    ManufacturerSpecificDataAdStruct, PeriodicAdvertisingResponseTimingInformationAdStruct,
     PeripheralConnectionIntervalRangeAdStruct, PublicTargetAddressAdStruct,
-    RandomTargetAddressAdStruct, ServiceDataUuid128AdStruct, ServiceDataUuid16AdStruct,
-    ServiceDataUuid32AdStruct, ServiceSolicitationUuid128AdStruct,
-    ServiceSolicitationUuid16AdStruct, ServiceSolicitationUuid32AdStruct, ServiceUuid128AdStruct,
-    ServiceUuid16AdStruct, ServiceUuid32AdStruct, TxPowerLevelAdStruct, UriAdStruct,
+    RandomTargetAddressAdStruct, ResolvableSetIdentifierAdStruct,
+    SecurityManagerOutOfBandFlagsAdStruct, SecurityManagerTkValueAdStruct,
+    ServiceDataUuid128AdStruct, ServiceDataUuid16AdStruct, ServiceDataUuid32AdStruct,
+    ServiceSolicitationUuid128AdStruct, ServiceSolicitationUuid16AdStruct,
+    ServiceSolicitationUuid32AdStruct, ServiceUuid128AdStruct, ServiceUuid16AdStruct,
+    ServiceUuid32AdStruct, ThreeDimensionalInformationAdStruct, TransportDiscoveryDataAdStruct,
+    TxPowerLevelAdStruct, UriAdStruct,
 };
 use crate::advertising::{
-    AdvertisingError, Flags, LocalNameComplete, PeripheralConnectionIntervalRange,
-    ServiceListComplete, Uri,
+    AdvertisingError, ClassOfDevice, Flags, IndoorPositioning, LeRole, LocalNameComplete,
+    PeripheralConnectionIntervalRange, SecurityManagerOutOfBandFlags, ServiceListComplete,
+    ThreeDimensionalInformationFlags, TransportBlock, Uri,
 };
 use crate::assigned_numbers::{AppearanceValue, CompanyIdentifier, ServiceUuid};
 use crate::uuid::{Uuid128, Uuid32};
 use crate::{DeviceInformation, Error};
 
+const ADVERTISING_DATA_MAX_SIZE: usize = 31;
+const AD_STRUCT_LIST_MAX_SIZE: usize = 3 * ADVERTISING_DATA_MAX_SIZE;
+
 /// Marker trait for Advertising Data types.
 pub trait IsAdvertisingData {}
 
@@ -62,9 +74,9 @@ where
         self.iter().any(func)
     }
 
-    pub fn iter(&self) -> AdvertisingDataIterator {
+    pub fn iter(&self) -> AdvertisingDataIterator<'_> {
         AdvertisingDataIterator {
-            data: self.data.data(),
+            data: &self.data.data()[1..],
             next_index: 0,
         }
     }
@@ -96,6 +108,31 @@ where
     }
 }
 
+impl GenericAdvertisingData<AdvertisingDataType> {
+    /// Get a copy of the Advertising Data whose Flags are replaced by the ones returned by `func`
+    /// from the current ones, the Flags being put first.
+    pub(crate) fn with_updated_flags(
+        &self,
+        func: impl FnOnce(Option<Flags>) -> Option<Flags>,
+    ) -> Result<Self, AdvertisingError> {
+        let flags = self.iter().find_map(|ad_struct| match ad_struct {
+            AdStruct::Flags(flags) => Some(flags.value()),
+            _ => None,
+        });
+        let mut builder = Self::builder();
+        if let Some(flags) = func(flags) {
+            builder = builder.add_ad_struct(FlagsAdStruct::new(flags))?;
+        }
+        for ad_struct in self
+            .iter()
+            .filter(|ad_struct| !matches!(ad_struct, AdStruct::Flags(_)))
+        {
+            builder = builder.add_ad_struct(ad_struct)?;
+        }
+        Ok(builder.build())
+    }
+}
+
 impl<T> EncodeToBuffer for GenericAdvertisingData<T>
 where
     T: IsAdvertisingData,
@@ -118,6 +155,18 @@ where
     }
 }
 
+impl<T> From<bletio_hci::AdvertisingData> for GenericAdvertisingData<T>
+where
+    T: IsAdvertisingData,
+{
+    fn from(value: bletio_hci::AdvertisingData) -> Self {
+        Self {
+            data: value,
+            _marker: PhantomData,
+        }
+    }
+}
+
 /// Advertising Data sent when advertising.
 ///
 /// The packet format for the Advertising Data is defined in
@@ -129,8 +178,8 @@ where
 /// Use the [`AdvertisingDataBuilder`] to instantiate it.
 pub type AdvertisingData = GenericAdvertisingData<AdvertisingDataType>;
 
-impl From<&LeAdvertisingReportData> for AdvertisingData {
-    fn from(value: &LeAdvertisingReportData) -> Self {
+impl From<LeAdvertisingReportData<'_>> for AdvertisingData {
+    fn from(value: LeAdvertisingReportData<'_>) -> Self {
         Self {
             data: value.into(),
             _marker: PhantomData,
@@ -138,6 +187,15 @@ impl From<&LeAdvertisingReportData> for AdvertisingData {
     }
 }
 
+impl From<AdvertisingDataView<'_>> for AdvertisingData {
+    fn from(value: AdvertisingDataView<'_>) -> Self {
+        Self {
+            data: value.data.into(),
+            _marker: PhantomData,
+        }
+    }
+}
+
 /// Scan Response Data that can be sent when the advertising is scannable.
 ///
 /// The packet format for the Scan Response Data is defined in
@@ -149,8 +207,8 @@ impl From<&LeAdvertisingReportData> for AdvertisingData {
 /// Use the [`ScanResponseDataBuilder`] to instantiate it.
 pub type ScanResponseData = GenericAdvertisingData<ScanResponseDataType>;
 
-impl From<&LeAdvertisingReportData> for ScanResponseData {
-    fn from(value: &LeAdvertisingReportData) -> Self {
+impl From<LeAdvertisingReportData<'_>> for ScanResponseData {
+    fn from(value: LeAdvertisingReportData<'_>) -> Self {
         Self {
             data: value.into(),
             _marker: PhantomData,
@@ -158,6 +216,61 @@ impl From<&LeAdvertisingReportData> for ScanResponseData {
     }
 }
 
+impl From<AdvertisingDataView<'_>> for ScanResponseData {
+    fn from(value: AdvertisingDataView<'_>) -> Self {
+        Self {
+            data: value.data.into(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Borrowed view over the Advertising Data or Scan Response Data of a received advertising report.
+///
+/// It references the bytes of the received HCI event instead of copying them. Convert it to an
+/// [`AdvertisingData`] or a [`ScanResponseData`] to keep it after the event has been handled.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AdvertisingDataView<'a> {
+    data: LeAdvertisingReportData<'a>,
+}
+
+impl<'a> AdvertisingDataView<'a> {
+    /// Get the raw bytes of the Advertising Structures.
+    pub fn data(&self) -> &'a [u8] {
+        self.data.data()
+    }
+
+    pub fn has_ad_struct(&self, func: impl FnMut(AdStruct) -> bool) -> bool {
+        self.iter().any(func)
+    }
+
+    pub fn iter(&self) -> AdvertisingDataIterator<'a> {
+        AdvertisingDataIterator {
+            data: self.data.data(),
+            next_index: 0,
+        }
+    }
+}
+
+impl<'a, T> From<&'a GenericAdvertisingData<T>> for AdvertisingDataView<'a>
+where
+    T: IsAdvertisingData,
+{
+    fn from(value: &'a GenericAdvertisingData<T>) -> Self {
+        Self {
+            // INVARIANT: The advertising data is known to be at most 31 bytes long.
+            data: value.data.data()[1..].try_into().unwrap(),
+        }
+    }
+}
+
+impl<'a> From<LeAdvertisingReportData<'a>> for AdvertisingDataView<'a> {
+    fn from(value: LeAdvertisingReportData<'a>) -> Self {
+        Self { data: value }
+    }
+}
+
 /// Iterator over the Advertising Structures of an Advertising Data or Scan Response Data.
 pub struct AdvertisingDataIterator<'a> {
     data: &'a [u8],
@@ -172,12 +285,6 @@ impl Iterator for AdvertisingDataIterator<'_> {
             if self.next_index >= self.data.len() {
                 return None;
             }
-            if self.next_index == 0 {
-                match parser::advertising_data_length(self.data) {
-                    Ok(_) => self.next_index += 1,
-                    Err(_) => unreachable!("That means data is empty and so we already returned"),
-                }
-            }
 
             match parser::ad_struct(&self.data[self.next_index..]) {
                 Ok((_, (len, ad_struct))) => {
@@ -232,13 +339,36 @@ where
         self,
         interval: AdvertisingInterval,
     ) -> Result<Self, AdvertisingError> {
-        if self.has_ad_struct(|ad_struct| matches!(ad_struct, AdStruct::AdvertisingInterval(_))) {
+        if self.has_ad_struct(|ad_struct| {
+            matches!(
+                ad_struct,
+                AdStruct::AdvertisingInterval(_) | AdStruct::AdvertisingIntervalLong(_)
+            )
+        }) {
             Err(AdvertisingError::OnlyOneAdvertisingIntervalAllowedInAdvertisingDataOrScanResponseData)
         } else {
             self.add_ad_struct(AdvertisingIntervalAdStruct::new(interval))
         }
     }
 
+    /// Add an Advertising Interval Long Advertising Structure to the Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` — The advertising interval, in units of 0.625 ms, to put in the added Advertising Interval Long Advertising Structure.
+    pub fn with_advertising_interval_long(self, interval: u32) -> Result<Self, AdvertisingError> {
+        if self.has_ad_struct(|ad_struct| {
+            matches!(
+                ad_struct,
+                AdStruct::AdvertisingInterval(_) | AdStruct::AdvertisingIntervalLong(_)
+            )
+        }) {
+            Err(AdvertisingError::OnlyOneAdvertisingIntervalAllowedInAdvertisingDataOrScanResponseData)
+        } else {
+            self.add_ad_struct(AdvertisingIntervalLongAdStruct::new(interval))
+        }
+    }
+
     /// Add an Appearance Advertising Structure to the Advertising Data.
     pub fn with_appearance(self) -> Result<Self, AdvertisingError> {
         if self.has_ad_struct(|ad_struct| matches!(ad_struct, AdStruct::Appearance(_))) {
@@ -248,6 +378,146 @@ where
         }
     }
 
+    /// Add a Broadcast Code Advertising Structure to the Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` — The Broadcast Code to put in the added Broadcast Code Advertising Structure.
+    pub fn with_broadcast_code(self, code: BroadcastCode) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(BroadcastCodeAdStruct::new(code))
+    }
+
+    /// Add a Broadcast Name Advertising Structure to the Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` — The name to put in the added Broadcast Name Advertising Structure, at least 4 octets long.
+    pub fn with_broadcast_name(self, name: &str) -> Result<Self, AdvertisingError> {
+        if self.has_ad_struct(|ad_struct| matches!(ad_struct, AdStruct::BroadcastName(_))) {
+            Err(AdvertisingError::OnlyOneBroadcastNameAllowedInAdvertisingDataOrScanResponseData)
+        } else {
+            self.add_ad_struct(BroadcastNameAdStruct::try_new(name)?)
+        }
+    }
+
+    /// Add a Channel Map Update Indication Advertising Structure to the Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `channel_map` — The new channel map, one bit for each of the 37 data channels.
+    /// * `instant` — The event counter value at which the new channel map will be used.
+    pub fn with_channel_map_update_indication(
+        self,
+        channel_map: u64,
+        instant: u16,
+    ) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(ChannelMapUpdateIndicationAdStruct::try_new(
+            channel_map,
+            instant,
+        )?)
+    }
+
+    /// Add a Class of Device Advertising Structure to the Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `class_of_device` — The Class of Device to put in the added Class of Device Advertising Structure.
+    pub fn with_class_of_device(
+        self,
+        class_of_device: ClassOfDevice,
+    ) -> Result<Self, AdvertisingError> {
+        if self.has_ad_struct(|ad_struct| matches!(ad_struct, AdStruct::ClassOfDevice(_))) {
+            Err(AdvertisingError::OnlyOneClassOfDeviceAllowedInAdvertisingDataOrScanResponseData)
+        } else {
+            self.add_ad_struct(ClassOfDeviceAdStruct::new(class_of_device))
+        }
+    }
+
+    /// Add an Electronic Shelf Label Advertising Structure to the Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `randomizer` — The randomizer used to encrypt the payload.
+    /// * `payload` — The encrypted payload to put in the added Electronic Shelf Label Advertising Structure.
+    /// * `mic` — The Message Integrity Check of the encrypted payload.
+    pub fn with_electronic_shelf_label(
+        self,
+        randomizer: [u8; 5],
+        payload: &[u8],
+        mic: [u8; 4],
+    ) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(ElectronicShelfLabelAdStruct::try_new(
+            randomizer, payload, mic,
+        )?)
+    }
+
+    /// Add an Indoor Positioning Advertising Structure to the Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `indoor_positioning` — The indoor positioning information to put in the added Indoor Positioning Advertising Structure.
+    pub fn with_indoor_positioning(
+        self,
+        indoor_positioning: IndoorPositioning,
+    ) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(IndoorPositioningAdStruct::new(indoor_positioning))
+    }
+
+    /// Add a LE Bluetooth Device Address Advertising Structure to the Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` — The device address to put in the added LE Bluetooth Device Address Advertising Structure.
+    pub fn with_le_bluetooth_device_address(
+        self,
+        address: DeviceAddress,
+    ) -> Result<Self, AdvertisingError> {
+        if self
+            .has_ad_struct(|ad_struct| matches!(ad_struct, AdStruct::LeBluetoothDeviceAddress(_)))
+        {
+            Err(AdvertisingError::OnlyOneLeBluetoothDeviceAddressAllowedInAdvertisingDataOrScanResponseData)
+        } else {
+            self.add_ad_struct(LeBluetoothDeviceAddressAdStruct::new(address))
+        }
+    }
+
+    /// Add a LE Role Advertising Structure to the Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `role` — The LE Role to put in the added LE Role Advertising Structure.
+    pub fn with_le_role(self, role: LeRole) -> Result<Self, AdvertisingError> {
+        if self.has_ad_struct(|ad_struct| matches!(ad_struct, AdStruct::LeRole(_))) {
+            Err(AdvertisingError::OnlyOneLeRoleAllowedInAdvertisingDataOrScanResponseData)
+        } else {
+            self.add_ad_struct(LeRoleAdStruct::new(role))
+        }
+    }
+
+    /// Add a LE Secure Connections Confirmation Value Advertising Structure to the Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` — The confirmation value to put in the added LE Secure Connections Confirmation Value Advertising Structure.
+    pub fn with_le_secure_connections_confirmation_value(
+        self,
+        value: u128,
+    ) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(LeSecureConnectionsConfirmationValueAdStruct::new(value))
+    }
+
+    /// Add a LE Secure Connections Random Value Advertising Structure to the Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` — The random value to put in the added LE Secure Connections Random Value Advertising Structure.
+    pub fn with_le_secure_connections_random_value(
+        self,
+        value: u128,
+    ) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(LeSecureConnectionsRandomValueAdStruct::new(value))
+    }
+
     /// Add a LE Supported Features Advertising Structure to the Advertising Data.
     pub fn with_le_supported_features(self) -> Result<Self, AdvertisingError> {
         if self.has_ad_struct(|ad_struct| matches!(ad_struct, AdStruct::LeSupportedFeatures(_))) {
@@ -289,6 +559,32 @@ where
         )?)
     }
 
+    /// Add a Periodic Advertising Response Timing Information Advertising Structure to the Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `response_access_address` — The Access Address to use for the responses.
+    /// * `num_subevents` — The number of subevents.
+    /// * `subevent_interval` — The interval between subevents, in units of 1.25 ms.
+    /// * `response_slot_delay` — The time between the advertising packet of a subevent and the first response slot, in units of 1.25 ms.
+    /// * `response_slot_spacing` — The time between response slots, in units of 0.125 ms.
+    pub fn with_periodic_advertising_response_timing_information(
+        self,
+        response_access_address: u32,
+        num_subevents: u8,
+        subevent_interval: u8,
+        response_slot_delay: u8,
+        response_slot_spacing: u8,
+    ) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(PeriodicAdvertisingResponseTimingInformationAdStruct::new(
+            response_access_address,
+            num_subevents,
+            subevent_interval,
+            response_slot_delay,
+            response_slot_spacing,
+        ))
+    }
+
     /// Add a Peripheral Connection Interval Range Advertising Structure to the Advertising Data.
     ///
     /// # Arguments
@@ -333,6 +629,36 @@ where
         }
     }
 
+    /// Add a Resolvable Set Identifier Advertising Structure to the Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `rsi` — The Resolvable Set Identifier to put in the added Resolvable Set Identifier Advertising Structure.
+    pub fn with_resolvable_set_identifier(self, rsi: [u8; 6]) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(ResolvableSetIdentifierAdStruct::new(rsi))
+    }
+
+    /// Add a Security Manager Out of Band Flags Advertising Structure to the Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `flags` — The flags to put in the added Security Manager Out of Band Flags Advertising Structure.
+    pub fn with_security_manager_out_of_band_flags(
+        self,
+        flags: SecurityManagerOutOfBandFlags,
+    ) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(SecurityManagerOutOfBandFlagsAdStruct::new(flags))
+    }
+
+    /// Add a Security Manager TK Value Advertising Structure to the Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` — The Temporary Key to put in the added Security Manager TK Value Advertising Structure.
+    pub fn with_security_manager_tk_value(self, value: u128) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(SecurityManagerTkValueAdStruct::new(value))
+    }
+
     /// Add a Service Data for a 16-bit Service UUID Advertising Structure to the Advertising Data.
     ///
     /// # Arguments
@@ -453,6 +779,35 @@ where
         self.add_ad_struct(ServiceUuid128AdStruct::try_new(uuids, complete)?)
     }
 
+    /// Add a 3D Information Data Advertising Structure to the Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `flags` — The flags to put in the added 3D Information Data Advertising Structure.
+    /// * `path_loss_threshold` — The path loss threshold, in dB, to put in the added 3D Information Data Advertising Structure.
+    pub fn with_three_dimensional_information(
+        self,
+        flags: ThreeDimensionalInformationFlags,
+        path_loss_threshold: u8,
+    ) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(ThreeDimensionalInformationAdStruct::new(
+            flags,
+            path_loss_threshold,
+        ))
+    }
+
+    /// Add a Transport Discovery Data Advertising Structure to the Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `blocks` — The list of transport blocks to put in the added Transport Discovery Data Advertising Structure.
+    pub fn with_transport_discovery_data(
+        self,
+        blocks: &[TransportBlock],
+    ) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(TransportDiscoveryDataAdStruct::try_new(blocks)?)
+    }
+
     /// Add a TX Power Level Advertising Structure to the Advertising Data.
     pub fn with_tx_power_level(self) -> Result<Self, AdvertisingError> {
         self.add_ad_struct(TxPowerLevelAdStruct::new(TxPowerLevel::default()))
@@ -475,91 +830,782 @@ where
         Ok(self)
     }
 
-    fn has_ad_struct(&self, func: impl FnMut(AdStruct) -> bool) -> bool {
-        self.data.has_ad_struct(func)
+    fn has_ad_struct(&self, func: impl FnMut(AdStruct) -> bool) -> bool {
+        self.data.has_ad_struct(func)
+    }
+
+    fn remaining_len(&self) -> usize {
+        ADVERTISING_DATA_MAX_SIZE - self.data.data.data()[0] as usize
+    }
+}
+
+impl GenericAdvertisingDataBuilder<AdvertisingDataType> {
+    /// Add a Flags Advertising Structure to the Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `flags` — The Flags value to put in the added Flags Advertising Structure.
+    pub fn with_flags(self, flags: Flags) -> Result<Self, AdvertisingError> {
+        if self.has_ad_struct(|ad_struct| matches!(ad_struct, AdStruct::Flags(_))) {
+            Err(AdvertisingError::OnlyOneFlagsAllowedInAdvertisingData)
+        } else {
+            self.add_ad_struct(FlagsAdStruct::new(flags))
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct FullAdvertisingData {
+    pub(crate) adv_data: AdvertisingData,
+    pub(crate) scanresp_data: Option<ScanResponseData>,
+}
+
+impl FullAdvertisingData {
+    pub fn builder() -> FullAdvertisingDataBuilder {
+        FullAdvertisingDataBuilder::new()
+    }
+
+    pub fn try_new(
+        adv_data: AdvertisingData,
+        scanresp_data: impl Into<Option<ScanResponseData>>,
+    ) -> Result<Self, Error> {
+        let scanresp_data = scanresp_data.into();
+        if let Some(scanresp_data) = &scanresp_data {
+            if adv_data.has_ad_struct(|ad_struct| matches!(ad_struct, AdStruct::Appearance(_)))
+                && scanresp_data
+                    .has_ad_struct(|ad_struct| matches!(ad_struct, AdStruct::Appearance(_)))
+            {
+                return Err(
+                    AdvertisingError::AppearanceNotAllowedInBothAdvertisingDataAndScanResponseData,
+                )?;
+            }
+        }
+        Ok(Self {
+            adv_data,
+            scanresp_data,
+        })
+    }
+
+    pub fn advertising_data(&self) -> &AdvertisingData {
+        &self.adv_data
+    }
+
+    pub fn iter(
+        &self,
+    ) -> core::iter::Chain<AdvertisingDataIterator<'_>, AdvertisingDataIterator<'_>> {
+        const EMPTY_ADVERTISING_DATA_ITERATOR: AdvertisingDataIterator = AdvertisingDataIterator {
+            data: &[],
+            next_index: 0,
+        };
+
+        if let Some(scanresp_data) = self.scanresp_data.as_ref() {
+            self.adv_data.iter().chain(scanresp_data.iter())
+        } else {
+            self.adv_data.iter().chain(EMPTY_ADVERTISING_DATA_ITERATOR)
+        }
+    }
+
+    pub fn scan_response_data(&self) -> Option<&ScanResponseData> {
+        self.scanresp_data.as_ref()
+    }
+
+    /// Get the Scan Response Data to send to the Controller, empty if there is none.
+    pub(crate) fn scan_response_hci_data(&self) -> bletio_hci::AdvertisingData {
+        self.scanresp_data
+            .as_ref()
+            .map(Into::into)
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn fill_automatic_data(
+        &self,
+        device_information: &DeviceInformation,
+    ) -> Result<Self, Error> {
+        let adv_data = self.adv_data.fill_automatic_data(device_information)?;
+
+        let scanresp_data = if let Some(scanresp_data) = self.scanresp_data.as_ref() {
+            Some(scanresp_data.fill_automatic_data(device_information)?)
+        } else {
+            None
+        };
+
+        FullAdvertisingData::try_new(adv_data, scanresp_data)
+    }
+}
+
+impl From<FullAdvertisingDataView<'_>> for FullAdvertisingData {
+    fn from(value: FullAdvertisingDataView<'_>) -> Self {
+        // The received data is kept as is, even if it does not comply with the rules checked
+        // when building the data to advertise.
+        Self {
+            adv_data: value.adv_data.into(),
+            scanresp_data: value.scanresp_data.map(Into::into),
+        }
+    }
+}
+
+/// Builder to create a [`FullAdvertisingData`] from a prioritized list of Advertising Structures.
+///
+/// The Advertising Structures are placed in the Advertising Data, or in the Scan Response Data
+/// when they do not fit, in the order in which they have been added. The Flags are always put
+/// first in the Advertising Data. When an Advertising Structure does not fit in either of them,
+/// the local name is shortened and the service UUID lists are truncated and marked incomplete.
+///
+/// The Scan Response Data is only sent when the advertising is scannable.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FullAdvertisingDataBuilder {
+    ad_structs: Buffer<AD_STRUCT_LIST_MAX_SIZE>,
+}
+
+impl FullAdvertisingDataBuilder {
+    /// Create a Full Advertising Data builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the Full Advertising Data, placing all the Advertising Structures that have been added.
+    pub fn build(self) -> Result<FullAdvertisingData, Error> {
+        let mut adv_data = AdvertisingData::builder();
+        let mut scanresp_data = ScanResponseData::builder();
+        if let Some(flags) = self
+            .iter()
+            .find(|ad_struct| matches!(ad_struct, AdStruct::Flags(_)))
+        {
+            adv_data = adv_data.add_ad_struct(flags)?;
+        }
+        for ad_struct in self
+            .iter()
+            .filter(|ad_struct| !matches!(ad_struct, AdStruct::Flags(_)))
+        {
+            if ad_struct.encoded_size() <= adv_data.remaining_len() {
+                adv_data = adv_data.add_ad_struct(ad_struct)?;
+            } else if ad_struct.encoded_size() <= scanresp_data.remaining_len() {
+                scanresp_data = scanresp_data.add_ad_struct(ad_struct)?;
+            } else {
+                let in_adv_data = adv_data.remaining_len() >= scanresp_data.remaining_len();
+                let len = if in_adv_data {
+                    adv_data.remaining_len()
+                } else {
+                    scanresp_data.remaining_len()
+                };
+                let ad_struct = Self::shrink(ad_struct, len)
+                    .ok_or(AdvertisingError::AdvertisingDataWillNotFitAdvertisingPacket)?;
+                if in_adv_data {
+                    adv_data = adv_data.add_ad_struct(ad_struct)?;
+                } else {
+                    scanresp_data = scanresp_data.add_ad_struct(ad_struct)?;
+                }
+            }
+        }
+        let scanresp_data = (scanresp_data.remaining_len() < ADVERTISING_DATA_MAX_SIZE)
+            .then(|| scanresp_data.build());
+        FullAdvertisingData::try_new(adv_data.build(), scanresp_data)
+    }
+
+    /// Add an Advertising Interval Advertising Structure to the Full Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` — The Advertising Interval value to put in the added Advertising Interval Advertising Structure.
+    pub fn with_advertising_interval(
+        self,
+        interval: AdvertisingInterval,
+    ) -> Result<Self, AdvertisingError> {
+        if self.has_ad_struct(|ad_struct| {
+            matches!(
+                ad_struct,
+                AdStruct::AdvertisingInterval(_) | AdStruct::AdvertisingIntervalLong(_)
+            )
+        }) {
+            Err(AdvertisingError::OnlyOneAdvertisingIntervalAllowedInAdvertisingDataOrScanResponseData)
+        } else {
+            self.add_ad_struct(AdvertisingIntervalAdStruct::new(interval))
+        }
+    }
+
+    /// Add an Advertising Interval Long Advertising Structure to the Full Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` — The advertising interval, in units of 0.625 ms, to put in the added Advertising Interval Long Advertising Structure.
+    pub fn with_advertising_interval_long(self, interval: u32) -> Result<Self, AdvertisingError> {
+        if self.has_ad_struct(|ad_struct| {
+            matches!(
+                ad_struct,
+                AdStruct::AdvertisingInterval(_) | AdStruct::AdvertisingIntervalLong(_)
+            )
+        }) {
+            Err(AdvertisingError::OnlyOneAdvertisingIntervalAllowedInAdvertisingDataOrScanResponseData)
+        } else {
+            self.add_ad_struct(AdvertisingIntervalLongAdStruct::new(interval))
+        }
+    }
+
+    /// Add an Appearance Advertising Structure to the Full Advertising Data.
+    pub fn with_appearance(self) -> Result<Self, AdvertisingError> {
+        if self.has_ad_struct(|ad_struct| matches!(ad_struct, AdStruct::Appearance(_))) {
+            Err(AdvertisingError::OnlyOneAppearanceAllowedInAdvertisingDataOrScanResponseData)
+        } else {
+            self.add_ad_struct(AppearanceAdStruct::new(AppearanceValue::GenericUnknown))
+        }
+    }
+
+    /// Add a Broadcast Code Advertising Structure to the Full Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` — The Broadcast Code to put in the added Broadcast Code Advertising Structure.
+    pub fn with_broadcast_code(self, code: BroadcastCode) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(BroadcastCodeAdStruct::new(code))
+    }
+
+    /// Add a Broadcast Name Advertising Structure to the Full Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` — The name to put in the added Broadcast Name Advertising Structure, at least 4 octets long.
+    pub fn with_broadcast_name(self, name: &str) -> Result<Self, AdvertisingError> {
+        if self.has_ad_struct(|ad_struct| matches!(ad_struct, AdStruct::BroadcastName(_))) {
+            Err(AdvertisingError::OnlyOneBroadcastNameAllowedInAdvertisingDataOrScanResponseData)
+        } else {
+            self.add_ad_struct(BroadcastNameAdStruct::try_new(name)?)
+        }
+    }
+
+    /// Add a Channel Map Update Indication Advertising Structure to the Full Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `channel_map` — The new channel map, one bit for each of the 37 data channels.
+    /// * `instant` — The event counter value at which the new channel map will be used.
+    pub fn with_channel_map_update_indication(
+        self,
+        channel_map: u64,
+        instant: u16,
+    ) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(ChannelMapUpdateIndicationAdStruct::try_new(
+            channel_map,
+            instant,
+        )?)
+    }
+
+    /// Add a Class of Device Advertising Structure to the Full Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `class_of_device` — The Class of Device to put in the added Class of Device Advertising Structure.
+    pub fn with_class_of_device(
+        self,
+        class_of_device: ClassOfDevice,
+    ) -> Result<Self, AdvertisingError> {
+        if self.has_ad_struct(|ad_struct| matches!(ad_struct, AdStruct::ClassOfDevice(_))) {
+            Err(AdvertisingError::OnlyOneClassOfDeviceAllowedInAdvertisingDataOrScanResponseData)
+        } else {
+            self.add_ad_struct(ClassOfDeviceAdStruct::new(class_of_device))
+        }
+    }
+
+    /// Add an Electronic Shelf Label Advertising Structure to the Full Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `randomizer` — The randomizer used to encrypt the payload.
+    /// * `payload` — The encrypted payload to put in the added Electronic Shelf Label Advertising Structure.
+    /// * `mic` — The Message Integrity Check of the encrypted payload.
+    pub fn with_electronic_shelf_label(
+        self,
+        randomizer: [u8; 5],
+        payload: &[u8],
+        mic: [u8; 4],
+    ) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(ElectronicShelfLabelAdStruct::try_new(
+            randomizer, payload, mic,
+        )?)
+    }
+
+    /// Add a Flags Advertising Structure to the Full Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `flags` — The Flags value to put in the added Flags Advertising Structure.
+    pub fn with_flags(self, flags: Flags) -> Result<Self, AdvertisingError> {
+        if self.has_ad_struct(|ad_struct| matches!(ad_struct, AdStruct::Flags(_))) {
+            Err(AdvertisingError::OnlyOneFlagsAllowedInAdvertisingData)
+        } else {
+            self.add_ad_struct(FlagsAdStruct::new(flags))
+        }
+    }
+
+    /// Add an Indoor Positioning Advertising Structure to the Full Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `indoor_positioning` — The indoor positioning information to put in the added Indoor Positioning Advertising Structure.
+    pub fn with_indoor_positioning(
+        self,
+        indoor_positioning: IndoorPositioning,
+    ) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(IndoorPositioningAdStruct::new(indoor_positioning))
+    }
+
+    /// Add a LE Bluetooth Device Address Advertising Structure to the Full Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` — The device address to put in the added LE Bluetooth Device Address Advertising Structure.
+    pub fn with_le_bluetooth_device_address(
+        self,
+        address: DeviceAddress,
+    ) -> Result<Self, AdvertisingError> {
+        if self
+            .has_ad_struct(|ad_struct| matches!(ad_struct, AdStruct::LeBluetoothDeviceAddress(_)))
+        {
+            Err(AdvertisingError::OnlyOneLeBluetoothDeviceAddressAllowedInAdvertisingDataOrScanResponseData)
+        } else {
+            self.add_ad_struct(LeBluetoothDeviceAddressAdStruct::new(address))
+        }
+    }
+
+    /// Add a LE Role Advertising Structure to the Full Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `role` — The LE Role to put in the added LE Role Advertising Structure.
+    pub fn with_le_role(self, role: LeRole) -> Result<Self, AdvertisingError> {
+        if self.has_ad_struct(|ad_struct| matches!(ad_struct, AdStruct::LeRole(_))) {
+            Err(AdvertisingError::OnlyOneLeRoleAllowedInAdvertisingDataOrScanResponseData)
+        } else {
+            self.add_ad_struct(LeRoleAdStruct::new(role))
+        }
+    }
+
+    /// Add a LE Secure Connections Confirmation Value Advertising Structure to the Full Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` — The confirmation value to put in the added LE Secure Connections Confirmation Value Advertising Structure.
+    pub fn with_le_secure_connections_confirmation_value(
+        self,
+        value: u128,
+    ) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(LeSecureConnectionsConfirmationValueAdStruct::new(value))
+    }
+
+    /// Add a LE Secure Connections Random Value Advertising Structure to the Full Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` — The random value to put in the added LE Secure Connections Random Value Advertising Structure.
+    pub fn with_le_secure_connections_random_value(
+        self,
+        value: u128,
+    ) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(LeSecureConnectionsRandomValueAdStruct::new(value))
+    }
+
+    /// Add a LE Supported Features Advertising Structure to the Full Advertising Data.
+    pub fn with_le_supported_features(self) -> Result<Self, AdvertisingError> {
+        if self.has_ad_struct(|ad_struct| matches!(ad_struct, AdStruct::LeSupportedFeatures(_))) {
+            Err(AdvertisingError::OnlyOneLeSupportedFeaturesAllowedInAdvertisingDataOrScanResponseData)
+        } else {
+            self.add_ad_struct(LeSupportedFeaturesAdStruct::new(
+                SupportedLeFeatures::default(),
+            ))
+        }
+    }
+
+    /// Add a Local Name Advertising Structure to the Full Advertising Data.
+    ///
+    /// The local name is put complete if it fits, and shortened otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `local_name` — The local name of the device, needed to know whether it fits complete or needs to be shortened.
+    pub fn with_local_name(self, local_name: &str) -> Result<Self, AdvertisingError> {
+        if self.has_ad_struct(|ad_struct| matches!(ad_struct, AdStruct::LocalName(_))) {
+            Err(AdvertisingError::OnlyOneLocalNameAllowedInAdvertisingDataOrScanResponseData)
+        } else {
+            let complete = if local_name.len() > LOCAL_NAME_MAX_LENGTH {
+                LocalNameComplete::Shortened(LOCAL_NAME_MAX_LENGTH)
+            } else {
+                LocalNameComplete::Complete
+            };
+            self.add_ad_struct(LocalNameAdStruct::try_new(local_name, complete)?)
+        }
+    }
+
+    /// Add a Manufacturer Specific Data Advertising Structure to the Full Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `manufacturer` — The `CompanyIdentifier` to put in the added Manufacturer Specific Data Advertising Structure.
+    /// * `data` — The data to put in the added Manufacturer Specific Data Advertising Structure.
+    pub fn with_manufacturer_specific_data(
+        self,
+        manufacturer: CompanyIdentifier,
+        data: &[u8],
+    ) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(ManufacturerSpecificDataAdStruct::try_new(
+            manufacturer,
+            data,
+        )?)
+    }
+
+    /// Add a Periodic Advertising Response Timing Information Advertising Structure to the Full Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `response_access_address` — The Access Address to use for the responses.
+    /// * `num_subevents` — The number of subevents.
+    /// * `subevent_interval` — The interval between subevents, in units of 1.25 ms.
+    /// * `response_slot_delay` — The time between the advertising packet of a subevent and the first response slot, in units of 1.25 ms.
+    /// * `response_slot_spacing` — The time between response slots, in units of 0.125 ms.
+    pub fn with_periodic_advertising_response_timing_information(
+        self,
+        response_access_address: u32,
+        num_subevents: u8,
+        subevent_interval: u8,
+        response_slot_delay: u8,
+        response_slot_spacing: u8,
+    ) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(PeriodicAdvertisingResponseTimingInformationAdStruct::new(
+            response_access_address,
+            num_subevents,
+            subevent_interval,
+            response_slot_delay,
+            response_slot_spacing,
+        ))
+    }
+
+    /// Add a Peripheral Connection Interval Range Advertising Structure to the Full Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` — The Peripheral Connection Interval range to put in the added Peripheral Connection Range Advertising Structure.
+    pub fn with_peripheral_connection_interval_range(
+        self,
+        range: PeripheralConnectionIntervalRange,
+    ) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(PeripheralConnectionIntervalRangeAdStruct::new(range))
+    }
+
+    /// Add a Public Target Address Advertising Structure to the Full Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `addresses` — The list of public device addresses to put in the added Public Target Address Advertising Structure.
+    pub fn with_public_target_address(
+        self,
+        addresses: &[PublicDeviceAddress],
+    ) -> Result<Self, AdvertisingError> {
+        if self.has_ad_struct(|ad_struct| matches!(ad_struct, AdStruct::PublicTargetAddress(_))) {
+            Err(AdvertisingError::OnlyOnePublicTargetAddressAllowedInAdvertisingDataOrScanResponseData)
+        } else {
+            self.add_ad_struct(PublicTargetAddressAdStruct::try_new(addresses)?)
+        }
+    }
+
+    /// Add a Random Target Address Advertising Structure to the Full Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `addresses` — The list of random addresses to put in the added Random Target Address Advertising Structure.
+    pub fn with_random_target_address(
+        self,
+        addresses: &[RandomAddress],
+    ) -> Result<Self, AdvertisingError> {
+        if self.has_ad_struct(|ad_struct| matches!(ad_struct, AdStruct::RandomTargetAddress(_))) {
+            Err(AdvertisingError::OnlyOneRandomTargetAddressAllowedInAdvertisingDataOrScanResponseData)
+        } else {
+            self.add_ad_struct(RandomTargetAddressAdStruct::try_new(addresses)?)
+        }
+    }
+
+    /// Add a Resolvable Set Identifier Advertising Structure to the Full Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `rsi` — The Resolvable Set Identifier to put in the added Resolvable Set Identifier Advertising Structure.
+    pub fn with_resolvable_set_identifier(self, rsi: [u8; 6]) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(ResolvableSetIdentifierAdStruct::new(rsi))
+    }
+
+    /// Add a Security Manager Out of Band Flags Advertising Structure to the Full Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `flags` — The flags to put in the added Security Manager Out of Band Flags Advertising Structure.
+    pub fn with_security_manager_out_of_band_flags(
+        self,
+        flags: SecurityManagerOutOfBandFlags,
+    ) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(SecurityManagerOutOfBandFlagsAdStruct::new(flags))
+    }
+
+    /// Add a Security Manager TK Value Advertising Structure to the Full Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` — The Temporary Key to put in the added Security Manager TK Value Advertising Structure.
+    pub fn with_security_manager_tk_value(self, value: u128) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(SecurityManagerTkValueAdStruct::new(value))
+    }
+
+    /// Add a Service Data for a 16-bit Service UUID Advertising Structure to the Full Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `uuid` — The 16-bit Service UUID to put in the added Service Data Advertising Structure.
+    /// * `data` — The data to put in the added Service Data Advertising Structure.
+    pub fn with_service_data_uuid16(
+        self,
+        uuid: ServiceUuid,
+        data: &[u8],
+    ) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(ServiceDataUuid16AdStruct::try_new(uuid, data)?)
+    }
+
+    /// Add a Service Data for a 32-bit Service UUID Advertising Structure to the Full Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `uuid` — The 32-bit Service UUID to put in the added Service Data Advertising Structure.
+    /// * `data` — The data to put in the added Service Data Advertising Structure.
+    pub fn with_service_data_uuid32(
+        self,
+        uuid: Uuid32,
+        data: &[u8],
+    ) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(ServiceDataUuid32AdStruct::try_new(uuid, data)?)
+    }
+
+    /// Add a Service Data for a 128-bit Service UUID Advertising Structure to the Full Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `uuid` — The 128-bit Service UUID to put in the added Service Data Advertising Structure.
+    /// * `data` — The data to put in the added Service Data Advertising Structure.
+    pub fn with_service_data_uuid128(
+        self,
+        uuid: Uuid128,
+        data: &[u8],
+    ) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(ServiceDataUuid128AdStruct::try_new(uuid, data)?)
+    }
+
+    /// Add a list of 16-bit Service Solicitation UUIDs Advertising Structure to the Full Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `uuids` — The list of 16-bit Service UUIDs to put in the added Service Solicitation UUID16 Advertising Structure.
+    pub fn with_service_solicitation_uuid16(
+        self,
+        uuids: &[ServiceUuid],
+    ) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(ServiceSolicitationUuid16AdStruct::try_new(uuids)?)
+    }
+
+    /// Add a list of 32-bit Service Solicitation UUIDs Advertising Structure to the Full Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `uuids` — The list of 32-bit Service UUIDs to put in the added Service Solicitation UUID32 Advertising Structure.
+    pub fn with_service_solicitation_uuid32(
+        self,
+        uuids: &[Uuid32],
+    ) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(ServiceSolicitationUuid32AdStruct::try_new(uuids)?)
+    }
+
+    /// Add a list of 128-bit Service Solicitation UUIDs Advertising Structure to the Full Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `uuids` — The list of 128-bit Service UUIDs to put in the added Service Solicitation UUID128 Advertising Structure.
+    pub fn with_service_solicitation_uuid128(
+        self,
+        uuids: &[Uuid128],
+    ) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(ServiceSolicitationUuid128AdStruct::try_new(uuids)?)
+    }
+
+    /// Add a list of 16-bit Service UUIDs Advertising Structure to the Full Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `uuids` — The list of 16-bit Service UUIDs to put in the added Service UUID16 Advertising Structure.
+    /// * `complete` — Whether the provided list is complete or not.
+    pub fn with_service_uuid16(
+        self,
+        uuids: &[ServiceUuid],
+        complete: ServiceListComplete,
+    ) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(ServiceUuid16AdStruct::try_new(uuids, complete)?)
+    }
+
+    /// Add a list of 32-bit Service UUIDs Advertising Structure to the Full Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `uuids` — The list of 32-bit Service UUIDs to put in the added Service UUID32 Advertising Structure.
+    /// * `complete` — Whether the provided list is complete or not.
+    pub fn with_service_uuid32(
+        self,
+        uuids: &[Uuid32],
+        complete: ServiceListComplete,
+    ) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(ServiceUuid32AdStruct::try_new(uuids, complete)?)
+    }
+
+    /// Add a list of 128-bit Service UUIDs Advertising Structure to the Full Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `uuids` — The list of 128-bit Service UUIDs to put in the added Service UUID128 Advertising Structure.
+    /// * `complete` — Whether the provided list is complete or not.
+    pub fn with_service_uuid128(
+        self,
+        uuids: &[Uuid128],
+        complete: ServiceListComplete,
+    ) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(ServiceUuid128AdStruct::try_new(uuids, complete)?)
+    }
+
+    /// Add a 3D Information Data Advertising Structure to the Full Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `flags` — The flags to put in the added 3D Information Data Advertising Structure.
+    /// * `path_loss_threshold` — The path loss threshold, in dB, to put in the added 3D Information Data Advertising Structure.
+    pub fn with_three_dimensional_information(
+        self,
+        flags: ThreeDimensionalInformationFlags,
+        path_loss_threshold: u8,
+    ) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(ThreeDimensionalInformationAdStruct::new(
+            flags,
+            path_loss_threshold,
+        ))
+    }
+
+    /// Add a Transport Discovery Data Advertising Structure to the Full Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `blocks` — The list of transport blocks to put in the added Transport Discovery Data Advertising Structure.
+    pub fn with_transport_discovery_data(
+        self,
+        blocks: &[TransportBlock],
+    ) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(TransportDiscoveryDataAdStruct::try_new(blocks)?)
+    }
+
+    /// Add a TX Power Level Advertising Structure to the Full Advertising Data.
+    pub fn with_tx_power_level(self) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(TxPowerLevelAdStruct::new(TxPowerLevel::default()))
+    }
+
+    /// Add a Uri Advertising Structure to the Full Advertising Data.
+    ///
+    /// # Arguments
+    ///
+    /// * `uri` — The Uri to put in the added Uri Advertising Structure.
+    pub fn with_uri(self, uri: Uri) -> Result<Self, AdvertisingError> {
+        self.add_ad_struct(UriAdStruct::new(uri))
+    }
+
+    fn add_ad_struct(mut self, ad_struct: impl EncodeToBuffer) -> Result<Self, AdvertisingError> {
+        if ad_struct.encoded_size() > self.ad_structs.remaining_len() {
+            return Err(AdvertisingError::AdvertisingDataWillNotFitAdvertisingPacket);
+        }
+        ad_struct
+            .encode(&mut self.ad_structs)
+            .map_err(|_| AdvertisingError::AdvertisingDataWillNotFitAdvertisingPacket)?;
+        Ok(self)
+    }
+
+    fn has_ad_struct(&self, func: impl FnMut(AdStruct) -> bool) -> bool {
+        self.iter().any(func)
+    }
+
+    fn iter(&self) -> AdvertisingDataIterator<'_> {
+        AdvertisingDataIterator {
+            data: self.ad_structs.data(),
+            next_index: 0,
+        }
     }
-}
 
-impl GenericAdvertisingDataBuilder<AdvertisingDataType> {
-    /// Add a Flags Advertising Structure to the Advertising Data.
-    ///
-    /// # Arguments
-    ///
-    /// * `flags` — The Flags value to put in the added Flags Advertising Structure.
-    pub fn with_flags(self, flags: Flags) -> Result<Self, AdvertisingError> {
-        if self.has_ad_struct(|ad_struct| matches!(ad_struct, AdStruct::Flags(_))) {
-            Err(AdvertisingError::OnlyOneFlagsAllowedInAdvertisingData)
-        } else {
-            self.add_ad_struct(FlagsAdStruct::new(flags))
+    /// Shorten the local name or truncate a service UUID list so that it fits in `len` octets.
+    fn shrink(ad_struct: AdStruct, len: usize) -> Option<AdStruct> {
+        const AD_STRUCT_HEADER_SIZE: usize = 2;
+        let len = len.checked_sub(AD_STRUCT_HEADER_SIZE)?;
+        match ad_struct {
+            AdStruct::LocalName(local_name) if len > 0 => {
+                LocalNameAdStruct::try_new(local_name.value(), LocalNameComplete::Shortened(len))
+                    .ok()
+                    .map(AdStruct::LocalName)
+            }
+            AdStruct::ServiceUuid16(uuids) if len >= 2 => {
+                ServiceUuid16AdStruct::try_new(&uuids[..len / 2], ServiceListComplete::Incomplete)
+                    .ok()
+                    .map(AdStruct::ServiceUuid16)
+            }
+            AdStruct::ServiceUuid32(uuids) if len >= 4 => {
+                ServiceUuid32AdStruct::try_new(&uuids[..len / 4], ServiceListComplete::Incomplete)
+                    .ok()
+                    .map(AdStruct::ServiceUuid32)
+            }
+            AdStruct::ServiceUuid128(uuids) if len >= 16 => {
+                ServiceUuid128AdStruct::try_new(&uuids[..len / 16], ServiceListComplete::Incomplete)
+                    .ok()
+                    .map(AdStruct::ServiceUuid128)
+            }
+            _ => None,
         }
     }
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Eq)]
+/// Borrowed view over the Advertising Data and the optional Scan Response Data of a received
+/// advertising report.
+///
+/// Convert it to a [`FullAdvertisingData`] to keep it after the event has been handled.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub struct FullAdvertisingData {
-    pub(crate) adv_data: AdvertisingData,
-    pub(crate) scanresp_data: Option<ScanResponseData>,
+pub struct FullAdvertisingDataView<'a> {
+    adv_data: AdvertisingDataView<'a>,
+    scanresp_data: Option<AdvertisingDataView<'a>>,
 }
 
-impl FullAdvertisingData {
-    pub fn try_new(
-        adv_data: AdvertisingData,
-        scanresp_data: impl Into<Option<ScanResponseData>>,
-    ) -> Result<Self, Error> {
-        let scanresp_data = scanresp_data.into();
-        if let Some(scanresp_data) = &scanresp_data {
-            if adv_data.has_ad_struct(|ad_struct| matches!(ad_struct, AdStruct::Appearance(_)))
-                && scanresp_data
-                    .has_ad_struct(|ad_struct| matches!(ad_struct, AdStruct::Appearance(_)))
-            {
-                return Err(
-                    AdvertisingError::AppearanceNotAllowedInBothAdvertisingDataAndScanResponseData,
-                )?;
-            }
-        }
-        Ok(Self {
+impl<'a> FullAdvertisingDataView<'a> {
+    pub(crate) fn new(
+        adv_data: AdvertisingDataView<'a>,
+        scanresp_data: Option<AdvertisingDataView<'a>>,
+    ) -> Self {
+        Self {
             adv_data,
             scanresp_data,
-        })
-    }
-
-    pub fn advertising_data(&self) -> &AdvertisingData {
-        &self.adv_data
+        }
     }
 
-    pub fn iter(
-        &self,
-    ) -> core::iter::Chain<AdvertisingDataIterator<'_>, AdvertisingDataIterator<'_>> {
-        const EMPTY_ADVERTISING_DATA_ITERATOR: AdvertisingDataIterator = AdvertisingDataIterator {
-            data: &[],
-            next_index: 0,
-        };
-
-        if let Some(scanresp_data) = self.scanresp_data.as_ref() {
-            self.adv_data.iter().chain(scanresp_data.iter())
-        } else {
-            self.adv_data.iter().chain(EMPTY_ADVERTISING_DATA_ITERATOR)
-        }
+    pub fn advertising_data(&self) -> AdvertisingDataView<'a> {
+        self.adv_data
     }
 
-    pub fn scan_response_data(&self) -> Option<&ScanResponseData> {
-        self.scanresp_data.as_ref()
+    pub fn has_ad_struct(&self, func: impl FnMut(AdStruct) -> bool) -> bool {
+        self.iter().any(func)
     }
 
-    pub(crate) fn fill_automatic_data(
+    pub fn iter(
         &self,
-        device_information: &DeviceInformation,
-    ) -> Result<Self, Error> {
-        let adv_data = self.adv_data.fill_automatic_data(device_information)?;
-
-        let scanresp_data = if let Some(scanresp_data) = self.scanresp_data.as_ref() {
-            Some(scanresp_data.fill_automatic_data(device_information)?)
-        } else {
-            None
-        };
+    ) -> core::iter::Chain<AdvertisingDataIterator<'a>, AdvertisingDataIterator<'a>> {
+        self.adv_data
+            .iter()
+            .chain(self.scanresp_data.unwrap_or_default().iter())
+    }
 
-        FullAdvertisingData::try_new(adv_data, scanresp_data)
+    pub fn scan_response_data(&self) -> Option<AdvertisingDataView<'a>> {
+        self.scanresp_data
     }
 }
 
@@ -572,15 +1618,35 @@ pub(crate) mod parser {
 
     use crate::{
         advertising::ad_struct::{
-            advertising_interval::parser::advertising_interval_ad_struct,
+            advertising_interval::parser::{
+                advertising_interval_ad_struct, advertising_interval_long_ad_struct,
+            },
             appearance::parser::appearance_ad_struct,
+            big_info::parser::big_info_ad_struct,
+            broadcast_code::parser::broadcast_code_ad_struct,
+            broadcast_name::parser::broadcast_name_ad_struct,
+            channel_map_update_indication::parser::channel_map_update_indication_ad_struct,
+            class_of_device::parser::class_of_device_ad_struct,
+            electronic_shelf_label::parser::electronic_shelf_label_ad_struct,
             flags::parser::flags_ad_struct,
+            indoor_positioning::parser::indoor_positioning_ad_struct,
+            le_bluetooth_device_address::parser::le_bluetooth_device_address_ad_struct,
+            le_role::parser::le_role_ad_struct,
+            le_secure_connections::parser::{
+                le_secure_connections_confirmation_value_ad_struct,
+                le_secure_connections_random_value_ad_struct,
+            },
             le_supported_features::parser::le_supported_features_ad_struct,
             local_name::parser::{complete_local_name_ad_struct, shortened_local_name_ad_struct},
             manufacturer_specific_data::parser::manufacturer_specific_data_ad_struct,
+            periodic_advertising_response_timing_information::parser::periodic_advertising_response_timing_information_ad_struct,
             peripheral_connection_interval_range::parser::peripheral_connection_interval_range_ad_struct,
             public_target_address::parser::public_target_address_ad_struct,
             random_target_address::parser::random_target_address_ad_struct,
+            resolvable_set_identifier::parser::resolvable_set_identifier_ad_struct,
+            security_manager::parser::{
+                security_manager_out_of_band_flags_ad_struct, security_manager_tk_value_ad_struct,
+            },
             service_data::parser::{
                 service_data_uuid128_ad_struct, service_data_uuid16_ad_struct,
                 service_data_uuid32_ad_struct,
@@ -598,7 +1664,10 @@ pub(crate) mod parser {
                 incomplete_list_of_service_uuid16_ad_struct,
                 incomplete_list_of_service_uuid32_ad_struct,
             },
+            three_dimensional_information::parser::three_dimensional_information_ad_struct,
+            transport_discovery_data::parser::transport_discovery_data_ad_struct,
             tx_power_level::parser::tx_power_level_ad_struct,
+            unhandled::parser::unhandled_ad_struct,
             uri::parser::uri_ad_struct,
             AdStruct,
         },
@@ -606,18 +1675,10 @@ pub(crate) mod parser {
         uuid::{Uuid128, Uuid32},
     };
 
-    pub(crate) fn advertising_data_length(input: &[u8]) -> IResult<&[u8], u8> {
-        le_u8.parse(input)
-    }
-
     pub(crate) fn ad_struct_length(input: &[u8]) -> IResult<&[u8], usize> {
         map(le_u8, |v| v as usize).parse(input)
     }
 
-    fn ad_type(input: &[u8]) -> IResult<&[u8], AdType> {
-        map_res(le_u8, TryInto::try_into).parse(input)
-    }
-
     pub(crate) fn service_uuid(input: &[u8]) -> IResult<&[u8], ServiceUuid> {
         map_res(le_u16, TryFrom::try_from).parse(input)
     }
@@ -631,57 +1692,97 @@ pub(crate) mod parser {
     }
 
     pub(crate) fn ad_struct(input: &[u8]) -> IResult<&[u8], (usize, AdStruct)> {
-        let (rest, (ad_struct_length, ad_type)) = (ad_struct_length, ad_type).parse(input)?;
+        let (rest, (ad_struct_length, ad_type)) = (ad_struct_length, le_u8).parse(input)?;
         let len = ad_struct_length - 1;
         let parameters = &rest[..len];
-        let (_rest, ad_struct) = match ad_type {
-            AdType::Flags => flags_ad_struct.parse(parameters),
-            AdType::IncompleteListOfServiceUuid16 => {
+        let (_rest, ad_struct) = match AdType::try_from(ad_type) {
+            Ok(AdType::Flags) => flags_ad_struct.parse(parameters),
+            Ok(AdType::IncompleteListOfServiceUuid16) => {
                 incomplete_list_of_service_uuid16_ad_struct.parse(parameters)
             }
-            AdType::CompleteListOfServiceUuid16 => {
+            Ok(AdType::CompleteListOfServiceUuid16) => {
                 complete_list_of_service_uuid16_ad_struct.parse(parameters)
             }
-            AdType::IncompleteListOfServiceUuid32 => {
+            Ok(AdType::IncompleteListOfServiceUuid32) => {
                 incomplete_list_of_service_uuid32_ad_struct.parse(parameters)
             }
-            AdType::CompleteListOfServiceUuid32 => {
+            Ok(AdType::CompleteListOfServiceUuid32) => {
                 complete_list_of_service_uuid32_ad_struct.parse(parameters)
             }
-            AdType::IncompleteListOfServiceUuid128 => {
+            Ok(AdType::IncompleteListOfServiceUuid128) => {
                 incomplete_list_of_service_uuid128_ad_struct.parse(parameters)
             }
-            AdType::CompleteListOfServiceUuid128 => {
+            Ok(AdType::CompleteListOfServiceUuid128) => {
                 complete_list_of_service_uuid128_ad_struct.parse(parameters)
             }
-            AdType::ShortenedLocalName => shortened_local_name_ad_struct.parse(parameters),
-            AdType::CompleteLocalName => complete_local_name_ad_struct.parse(parameters),
-            AdType::TxPowerLevel => tx_power_level_ad_struct.parse(parameters),
-            AdType::PeripheralConnectionIntervalRange => {
+            Ok(AdType::ShortenedLocalName) => shortened_local_name_ad_struct.parse(parameters),
+            Ok(AdType::CompleteLocalName) => complete_local_name_ad_struct.parse(parameters),
+            Ok(AdType::TxPowerLevel) => tx_power_level_ad_struct.parse(parameters),
+            Ok(AdType::PeripheralConnectionIntervalRange) => {
                 peripheral_connection_interval_range_ad_struct.parse(parameters)
             }
-            AdType::ListOfSolicitationServiceUuid16 => {
+            Ok(AdType::ListOfSolicitationServiceUuid16) => {
                 list_of_solicitation_service_uuid16_ad_struct.parse(parameters)
             }
-            AdType::ListOfSolicitationServiceUuid128 => {
+            Ok(AdType::ListOfSolicitationServiceUuid128) => {
                 list_of_solicitation_service_uuid128_ad_struct.parse(parameters)
             }
-            AdType::ServiceDataUuid16 => service_data_uuid16_ad_struct.parse(parameters),
-            AdType::PublicTargetAddress => public_target_address_ad_struct.parse(parameters),
-            AdType::RandomTargetAddress => random_target_address_ad_struct.parse(parameters),
-            AdType::Appearance => appearance_ad_struct.parse(parameters),
-            AdType::AdvertisingInterval => advertising_interval_ad_struct.parse(parameters),
-            AdType::ListOfSolicitationServiceUuid32 => {
+            Ok(AdType::ServiceDataUuid16) => service_data_uuid16_ad_struct.parse(parameters),
+            Ok(AdType::PublicTargetAddress) => public_target_address_ad_struct.parse(parameters),
+            Ok(AdType::RandomTargetAddress) => random_target_address_ad_struct.parse(parameters),
+            Ok(AdType::Appearance) => appearance_ad_struct.parse(parameters),
+            Ok(AdType::AdvertisingInterval) => advertising_interval_ad_struct.parse(parameters),
+            Ok(AdType::ListOfSolicitationServiceUuid32) => {
                 list_of_solicitation_service_uuid32_ad_struct.parse(parameters)
             }
-            AdType::ServiceDataUuid32 => service_data_uuid32_ad_struct.parse(parameters),
-            AdType::ServiceDataUuid128 => service_data_uuid128_ad_struct.parse(parameters),
-            AdType::Uri => uri_ad_struct.parse(parameters),
-            AdType::LeSupportedFeatures => le_supported_features_ad_struct.parse(parameters),
-            AdType::ManufacturerSpecificData => {
+            Ok(AdType::ServiceDataUuid32) => service_data_uuid32_ad_struct.parse(parameters),
+            Ok(AdType::ServiceDataUuid128) => service_data_uuid128_ad_struct.parse(parameters),
+            Ok(AdType::Uri) => uri_ad_struct.parse(parameters),
+            Ok(AdType::LeSupportedFeatures) => le_supported_features_ad_struct.parse(parameters),
+            Ok(AdType::ManufacturerSpecificData) => {
                 manufacturer_specific_data_ad_struct.parse(parameters)
             }
-            _ => Ok((&[] as &[u8], AdStruct::Unhandled(ad_type.into()))),
+            Ok(AdType::ClassOfDevice) => class_of_device_ad_struct.parse(parameters),
+            Ok(AdType::SecurityManagerTkValue) => {
+                security_manager_tk_value_ad_struct.parse(parameters)
+            }
+            Ok(AdType::SecurityManagerOutOfBandFlags) => {
+                security_manager_out_of_band_flags_ad_struct.parse(parameters)
+            }
+            Ok(AdType::LeBluetoothDeviceAddress) => {
+                le_bluetooth_device_address_ad_struct.parse(parameters)
+            }
+            Ok(AdType::LeRole) => le_role_ad_struct.parse(parameters),
+            Ok(AdType::LeSecureConnectionsConfirmationValue) => {
+                le_secure_connections_confirmation_value_ad_struct.parse(parameters)
+            }
+            Ok(AdType::LeSecureConnectionsRandomValue) => {
+                le_secure_connections_random_value_ad_struct.parse(parameters)
+            }
+            Ok(AdType::IndoorPositioning) => indoor_positioning_ad_struct.parse(parameters),
+            Ok(AdType::TransportDiscoveryData) => {
+                transport_discovery_data_ad_struct.parse(parameters)
+            }
+            Ok(AdType::ChannelMapUpdateIndication) => {
+                channel_map_update_indication_ad_struct.parse(parameters)
+            }
+            Ok(AdType::BigInfo) => big_info_ad_struct.parse(parameters),
+            Ok(AdType::BroadcastCode) => broadcast_code_ad_struct.parse(parameters),
+            Ok(AdType::ResolvableSetIdentifier) => {
+                resolvable_set_identifier_ad_struct.parse(parameters)
+            }
+            Ok(AdType::AdvertisingIntervalLong) => {
+                advertising_interval_long_ad_struct.parse(parameters)
+            }
+            Ok(AdType::BroadcastName) => broadcast_name_ad_struct.parse(parameters),
+            Ok(AdType::PeriodicAdvertisingResponseTimingInformation) => {
+                periodic_advertising_response_timing_information_ad_struct.parse(parameters)
+            }
+            Ok(AdType::ElectronicShelfLabel) => electronic_shelf_label_ad_struct.parse(parameters),
+            Ok(AdType::ThreeDimensionalInformationData) => {
+                three_dimensional_information_ad_struct.parse(parameters)
+            }
+            _ => unhandled_ad_struct(ad_type, parameters),
         }?;
         Ok((&rest[len..], (ad_struct_length + 1, ad_struct)))
     }
@@ -694,7 +1795,8 @@ mod test {
     use rstest::{fixture, rstest};
 
     use crate::{
-        advertising::advertising_data::parser::ad_struct, assigned_numbers::ProvisionedUriScheme,
+        advertising::{ad_struct::UnhandledAdStruct, advertising_data::parser::ad_struct},
+        assigned_numbers::ProvisionedUriScheme,
     };
 
     use super::*;
@@ -820,6 +1922,27 @@ mod test {
             .build()
     }
 
+    #[fixture]
+    fn advertising_data_builder_le_role() -> AdvertisingData {
+        AdvertisingData::builder()
+            .with_flags(Flags::LE_GENERAL_DISCOVERABLE_MODE | Flags::BREDR_NOT_SUPPORTED)
+            .unwrap()
+            .with_le_role(LeRole::PeripheralPreferred)
+            .unwrap()
+            .with_class_of_device(ClassOfDevice::try_new(0x24_0404).unwrap())
+            .unwrap()
+            .with_broadcast_name("Gate")
+            .unwrap()
+            .with_advertising_interval_long(0x0100_0000)
+            .unwrap()
+            .with_three_dimensional_information(
+                ThreeDimensionalInformationFlags::ASSOCIATION_NOTIFICATION,
+                70,
+            )
+            .unwrap()
+            .build()
+    }
+
     #[rstest]
     #[case::empty(
         advertising_data_builder_empty(),
@@ -861,6 +1984,11 @@ mod test {
         &[0x16, 0x15, 0x21, 0x40, 0xD6, 0x6E, 0xFD, 0xD0, 0x11, 0x2C, 0xAD, 0x9E, 0x4C, 0x7D, 0x22, 0x7E,
             0x28, 0xA1, 0xF5, 0x50, 0x84, 0x91, 0xAF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
     )]
+    #[case::le_role(
+        advertising_data_builder_le_role(),
+        &[0x1B, 0x02, 0x01, 0x06, 0x02, 0x1C, 0x02, 0x04, 0x0D, 0x04, 0x04, 0x24, 0x05, 0x30, b'G', b'a',
+            b't', b'e', 0x05, 0x2F, 0x00, 0x00, 0x00, 0x01, 0x03, 0x3D, 0x01, 0x46, 0x00, 0x00, 0x00, 0x00]
+    )]
     fn test_advertising_data_builder_success(
         #[case] adv_data: AdvertisingData,
         #[case] expected_encoded_data: &[u8],
@@ -907,6 +2035,77 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_advertising_data_builder_unique_advertising_interval_long() {
+        let err = AdvertisingData::builder()
+            .with_advertising_interval(AdvertisingInterval::default())
+            .unwrap()
+            .with_advertising_interval_long(0x0100_0000);
+        assert_eq!(
+            err,
+            Err(AdvertisingError::OnlyOneAdvertisingIntervalAllowedInAdvertisingDataOrScanResponseData)
+        );
+        let err = AdvertisingData::builder()
+            .with_advertising_interval_long(0x0100_0000)
+            .unwrap()
+            .with_advertising_interval(AdvertisingInterval::default());
+        assert_eq!(
+            err,
+            Err(AdvertisingError::OnlyOneAdvertisingIntervalAllowedInAdvertisingDataOrScanResponseData)
+        );
+    }
+
+    #[test]
+    fn test_advertising_data_builder_unique_broadcast_name() {
+        let err = AdvertisingData::builder()
+            .with_broadcast_name("Gate")
+            .unwrap()
+            .with_broadcast_name("Gate");
+        assert_eq!(
+            err,
+            Err(AdvertisingError::OnlyOneBroadcastNameAllowedInAdvertisingDataOrScanResponseData)
+        );
+    }
+
+    #[test]
+    fn test_advertising_data_builder_unique_class_of_device() {
+        let class_of_device = ClassOfDevice::try_new(0x24_0404).unwrap();
+        let err = AdvertisingData::builder()
+            .with_class_of_device(class_of_device)
+            .unwrap()
+            .with_class_of_device(class_of_device);
+        assert_eq!(
+            err,
+            Err(AdvertisingError::OnlyOneClassOfDeviceAllowedInAdvertisingDataOrScanResponseData)
+        );
+    }
+
+    #[test]
+    fn test_advertising_data_builder_unique_le_bluetooth_device_address() {
+        let address: DeviceAddress =
+            PublicDeviceAddress::new([0xCD, 0x2E, 0x0B, 0x04, 0x32, 0x56]).into();
+        let err = AdvertisingData::builder()
+            .with_le_bluetooth_device_address(address.clone())
+            .unwrap()
+            .with_le_bluetooth_device_address(address);
+        assert_eq!(
+            err,
+            Err(AdvertisingError::OnlyOneLeBluetoothDeviceAddressAllowedInAdvertisingDataOrScanResponseData)
+        );
+    }
+
+    #[test]
+    fn test_advertising_data_builder_unique_le_role() {
+        let err = AdvertisingData::builder()
+            .with_le_role(LeRole::OnlyPeripheral)
+            .unwrap()
+            .with_le_role(LeRole::OnlyCentral);
+        assert_eq!(
+            err,
+            Err(AdvertisingError::OnlyOneLeRoleAllowedInAdvertisingDataOrScanResponseData)
+        );
+    }
+
     #[test]
     fn test_advertising_data_builder_unique_appearance() {
         let builder = AdvertisingData::builder();
@@ -993,10 +2192,8 @@ mod test {
     #[test]
     fn test_advertising_data_from_le_advertising_report_data() {
         let data = [25; 16];
-        let mut buffer: Buffer<31> = Buffer::default();
-        buffer.copy_from_slice(&data[..]).unwrap();
-        let data = LeAdvertisingReportData::from(buffer);
-        let adv_data: AdvertisingData = (&data).into();
+        let data = LeAdvertisingReportData::try_from(&data[..]).unwrap();
+        let adv_data: AdvertisingData = data.into();
         assert_eq!(
             adv_data.data.data(),
             &[16, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25]
@@ -1289,10 +2486,8 @@ mod test {
     #[test]
     fn test_scan_response_data_from_le_advertising_report_data() {
         let data = [25; 16];
-        let mut buffer: Buffer<31> = Buffer::default();
-        buffer.copy_from_slice(&data[..]).unwrap();
-        let data = LeAdvertisingReportData::from(buffer);
-        let adv_data: ScanResponseData = (&data).into();
+        let data = LeAdvertisingReportData::try_from(&data[..]).unwrap();
+        let adv_data: ScanResponseData = data.into();
         assert_eq!(
             adv_data.data.data(),
             &[16, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25]
@@ -1370,11 +2565,13 @@ mod test {
         let tx_power_level = TxPowerLevel::try_new(-8)?;
         let supported_le_features =
             SupportedLeFeatures::LE_2M_PHY | SupportedLeFeatures::LE_CODED_PHY;
-        let mut device_information = DeviceInformation::default();
-        device_information.appearance = appearance;
-        device_information.local_name = "bletio";
-        device_information.tx_power_level = tx_power_level;
-        device_information.supported_le_features = supported_le_features;
+        let device_information = DeviceInformation {
+            appearance,
+            local_name: "bletio",
+            tx_power_level,
+            supported_le_features,
+            ..Default::default()
+        };
         let filled_full_adv_data = full_adv_data.fill_automatic_data(&device_information)?;
         assert_eq!(filled_full_adv_data.iter().count(), 6);
         assert_eq!(filled_full_adv_data.advertising_data().iter().count(), 4);
@@ -1451,9 +2648,11 @@ mod test {
         assert_eq!(it.next(), None);
 
         let appearance = AppearanceValue::Thermostat;
-        let mut device_information = DeviceInformation::default();
-        device_information.appearance = appearance;
-        device_information.local_name = "bletio";
+        let device_information = DeviceInformation {
+            appearance,
+            local_name: "bletio",
+            ..Default::default()
+        };
         let filled_full_adv_data = full_adv_data.fill_automatic_data(&device_information)?;
         assert_eq!(filled_full_adv_data.iter().count(), 2);
         assert_eq!(filled_full_adv_data.advertising_data().iter().count(), 2);
@@ -1529,11 +2728,13 @@ mod test {
         let tx_power_level = TxPowerLevel::try_new(-8)?;
         let supported_le_features =
             SupportedLeFeatures::LE_2M_PHY | SupportedLeFeatures::LE_CODED_PHY;
-        let mut device_information = DeviceInformation::default();
-        device_information.appearance = appearance;
-        device_information.local_name = "bletio";
-        device_information.tx_power_level = tx_power_level;
-        device_information.supported_le_features = supported_le_features;
+        let device_information = DeviceInformation {
+            appearance,
+            local_name: "bletio",
+            tx_power_level,
+            supported_le_features,
+            ..Default::default()
+        };
         let filled_full_adv_data = full_adv_data.fill_automatic_data(&device_information)?;
         assert_eq!(filled_full_adv_data.iter().count(), 5);
         assert_eq!(filled_full_adv_data.advertising_data().iter().count(), 1);
@@ -1594,6 +2795,137 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_full_advertising_data_builder_no_scan_response_data() -> Result<(), Error> {
+        let full_adv_data = FullAdvertisingData::builder()
+            .with_local_name("bletio")?
+            .with_flags(Flags::LE_GENERAL_DISCOVERABLE_MODE | Flags::BREDR_NOT_SUPPORTED)?
+            .with_appearance()?
+            .build()?;
+        assert_eq!(
+            full_adv_data.advertising_data().data.data(),
+            &[
+                0x0F, 0x02, 0x01, 0x06, 0x07, 0x09, b'b', b'l', b'e', b't', b'i', b'o', 0x03, 0x19,
+                0x00, 0x00
+            ]
+        );
+        assert_eq!(full_adv_data.scan_response_data(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_full_advertising_data_builder_fill_scan_response_data() -> Result<(), Error> {
+        let full_adv_data = FullAdvertisingData::builder()
+            .with_flags(Flags::LE_GENERAL_DISCOVERABLE_MODE | Flags::BREDR_NOT_SUPPORTED)?
+            .with_manufacturer_specific_data(CompanyIdentifier::StMicroelectronics, &[0x01; 20])?
+            .with_local_name("bletio")?
+            .with_tx_power_level()?
+            .build()?;
+        let mut it = full_adv_data.advertising_data().iter();
+        assert!(matches!(it.next(), Some(AdStruct::Flags(_))));
+        assert!(matches!(
+            it.next(),
+            Some(AdStruct::ManufacturerSpecificData(_))
+        ));
+        assert!(matches!(it.next(), Some(AdStruct::TxPowerLevel(_))));
+        assert_eq!(it.next(), None);
+        let mut it = full_adv_data.scan_response_data().unwrap().iter();
+        assert_eq!(
+            it.next(),
+            Some(AdStruct::LocalName(LocalNameAdStruct::try_new(
+                "bletio",
+                LocalNameComplete::Complete
+            )?))
+        );
+        assert_eq!(it.next(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_full_advertising_data_builder_shorten_local_name() -> Result<(), Error> {
+        let full_adv_data = FullAdvertisingData::builder()
+            .with_manufacturer_specific_data(CompanyIdentifier::StMicroelectronics, &[0x01; 20])?
+            .with_manufacturer_specific_data(CompanyIdentifier::StMicroelectronics, &[0x02; 20])?
+            .with_local_name("A very long local name")?
+            .build()?;
+        let mut it = full_adv_data.advertising_data().iter().skip(1);
+        assert_eq!(
+            it.next(),
+            Some(AdStruct::LocalName(LocalNameAdStruct::try_new(
+                "A very long local name",
+                LocalNameComplete::Shortened(5)
+            )?))
+        );
+        assert_eq!(it.next(), None);
+        assert_eq!(
+            full_adv_data.advertising_data().data.data()[25..],
+            [0x06, 0x08, b'A', b' ', b'v', b'e', b'r']
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_full_advertising_data_builder_truncate_service_uuid_list() -> Result<(), Error> {
+        let uuids = [
+            ServiceUuid::LinkLoss,
+            ServiceUuid::Battery,
+            ServiceUuid::EnvironmentalSensing,
+            ServiceUuid::HeartRate,
+        ];
+        let full_adv_data = FullAdvertisingData::builder()
+            .with_manufacturer_specific_data(CompanyIdentifier::StMicroelectronics, &[0x01; 22])?
+            .with_manufacturer_specific_data(CompanyIdentifier::StMicroelectronics, &[0x02; 22])?
+            .with_service_uuid16(&uuids, ServiceListComplete::Complete)?
+            .build()?;
+        let mut it = full_adv_data.advertising_data().iter().skip(1);
+        assert_eq!(
+            it.next(),
+            Some(AdStruct::ServiceUuid16(ServiceUuid16AdStruct::try_new(
+                &uuids[..1],
+                ServiceListComplete::Incomplete
+            )?))
+        );
+        assert_eq!(it.next(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_full_advertising_data_builder_failure() -> Result<(), Error> {
+        let err = FullAdvertisingData::builder()
+            .with_manufacturer_specific_data(CompanyIdentifier::StMicroelectronics, &[0x01; 20])?
+            .with_manufacturer_specific_data(CompanyIdentifier::StMicroelectronics, &[0x02; 20])?
+            .with_manufacturer_specific_data(CompanyIdentifier::StMicroelectronics, &[0x03; 20])?
+            .build();
+        assert_eq!(
+            err,
+            Err(Error::Advertising(
+                AdvertisingError::AdvertisingDataWillNotFitAdvertisingPacket
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_full_advertising_data_builder_unique_ad_structs() -> Result<(), Error> {
+        let builder = FullAdvertisingData::builder()
+            .with_flags(Flags::LE_GENERAL_DISCOVERABLE_MODE)?
+            .with_local_name("bletio")?
+            .with_appearance()?;
+        assert_eq!(
+            builder.clone().with_flags(Flags::BREDR_NOT_SUPPORTED),
+            Err(AdvertisingError::OnlyOneFlagsAllowedInAdvertisingData)
+        );
+        assert_eq!(
+            builder.clone().with_local_name("bletio"),
+            Err(AdvertisingError::OnlyOneLocalNameAllowedInAdvertisingDataOrScanResponseData)
+        );
+        assert_eq!(
+            builder.with_appearance(),
+            Err(AdvertisingError::OnlyOneAppearanceAllowedInAdvertisingDataOrScanResponseData)
+        );
+        Ok(())
+    }
+
     #[rstest]
     #[case(&[0x02, 0x01, 0x06], AdStruct::Flags(FlagsAdStruct::new(Flags::LE_GENERAL_DISCOVERABLE_MODE | Flags::BREDR_NOT_SUPPORTED))
     )]
@@ -1723,7 +3055,62 @@ mod test {
             ).unwrap()
         )
     )]
-    #[case(&[0x01, 0x30], AdStruct::Unhandled(0x30))]
+    #[case(&[0x04, 0x0D, 0x04, 0x04, 0x24], AdStruct::ClassOfDevice(ClassOfDeviceAdStruct::new(ClassOfDevice::try_new(0x24_0404).unwrap())))]
+    #[case(
+        &[0x11, 0x10, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F],
+        AdStruct::SecurityManagerTkValue(SecurityManagerTkValueAdStruct::new(0x0F0E0D0C_0B0A0908_07060504_03020100))
+    )]
+    #[case(
+        &[0x02, 0x11, 0x01],
+        AdStruct::SecurityManagerOutOfBandFlags(SecurityManagerOutOfBandFlagsAdStruct::new(SecurityManagerOutOfBandFlags::OOB_DATA_PRESENT))
+    )]
+    #[case(
+        &[0x08, 0x1B, 0xCD, 0x2E, 0x0B, 0x04, 0x32, 0x56, 0x00],
+        AdStruct::LeBluetoothDeviceAddress(LeBluetoothDeviceAddressAdStruct::new(PublicDeviceAddress::new([0xCD, 0x2E, 0x0B, 0x04, 0x32, 0x56]).into()))
+    )]
+    #[case(&[0x02, 0x1C, 0x03], AdStruct::LeRole(LeRoleAdStruct::new(LeRole::CentralPreferred)))]
+    #[case(
+        &[0x11, 0x22, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F],
+        AdStruct::LeSecureConnectionsConfirmationValue(LeSecureConnectionsConfirmationValueAdStruct::new(0x0F0E0D0C_0B0A0908_07060504_03020100))
+    )]
+    #[case(
+        &[0x11, 0x23, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F],
+        AdStruct::LeSecureConnectionsRandomValue(LeSecureConnectionsRandomValueAdStruct::new(0x0F0E0D0C_0B0A0908_07060504_03020100))
+    )]
+    #[case(&[0x03, 0x25, 0x10, 0x16], AdStruct::IndoorPositioning(IndoorPositioningAdStruct::new(IndoorPositioning::new().with_floor_number(22))))]
+    #[case(
+        &[0x05, 0x26, 0x01, 0x02, 0x01, 0xAA],
+        AdStruct::TransportDiscoveryData(TransportDiscoveryDataAdStruct::try_new(&[TransportBlock::new(0x01, 0x02, &[0xAA])]).unwrap())
+    )]
+    #[case(
+        &[0x08, 0x28, 0xFF, 0xFF, 0xFF, 0xFF, 0x1F, 0x00, 0x01],
+        AdStruct::ChannelMapUpdateIndication(ChannelMapUpdateIndicationAdStruct::try_new(0x1F_FFFF_FFFF, 0x0100).unwrap())
+    )]
+    #[case(
+        &[0x11, 0x2D, b'b', b'l', b'e', b't', b'i', b'o', b'-', b'b', b'r', b'o', b'a', b'd', b'c', b'a', b's', b't'],
+        AdStruct::BroadcastCode(BroadcastCodeAdStruct::new(BroadcastCode::new(*b"bletio-broadcast")))
+    )]
+    #[case(
+        &[0x07, 0x2E, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06],
+        AdStruct::ResolvableSetIdentifier(ResolvableSetIdentifierAdStruct::new([0x01, 0x02, 0x03, 0x04, 0x05, 0x06]))
+    )]
+    #[case(&[0x04, 0x2F, 0x20, 0x00, 0x00], AdStruct::AdvertisingIntervalLong(AdvertisingIntervalLongAdStruct::new(0x20)))]
+    #[case(&[0x05, 0x30, b'G', b'a', b't', b'e'], AdStruct::BroadcastName(BroadcastNameAdStruct::try_new("Gate").unwrap()))]
+    #[case(
+        &[0x09, 0x32, 0xD6, 0xBE, 0x89, 0x8E, 0x04, 0x18, 0x02, 0x0A],
+        AdStruct::PeriodicAdvertisingResponseTimingInformation(PeriodicAdvertisingResponseTimingInformationAdStruct::new(0x8E89BED6, 4, 24, 2, 10))
+    )]
+    #[case(
+        &[0x0A, 0x34, 0x01, 0x02, 0x03, 0x04, 0x05, 0xA1, 0xA2, 0xA3, 0xA4],
+        AdStruct::ElectronicShelfLabel(ElectronicShelfLabelAdStruct::try_new([0x01, 0x02, 0x03, 0x04, 0x05], &[], [0xA1, 0xA2, 0xA3, 0xA4]).unwrap())
+    )]
+    #[case(
+        &[0x03, 0x3D, 0x01, 0x46],
+        AdStruct::ThreeDimensionalInformation(ThreeDimensionalInformationAdStruct::new(ThreeDimensionalInformationFlags::ASSOCIATION_NOTIFICATION, 70))
+    )]
+    #[case(&[0x03, 0x29, 0x00, 0x01], AdStruct::Unhandled(UnhandledAdStruct::try_new(0x29, &[0x00, 0x01]).unwrap()))]
+    #[case(&[0x02, 0x50, 0xAB], AdStruct::Unhandled(UnhandledAdStruct::try_new(0x50, &[0xAB]).unwrap()))]
+    #[case(&[0x01, 0x50], AdStruct::Unhandled(UnhandledAdStruct::try_new(0x50, &[]).unwrap()))]
     fn test_ad_struct_parsing(#[case] input: &[u8], #[case] expected_ad_struct: AdStruct) {
         assert_eq!(
             ad_struct(input),
@@ -1733,12 +3120,47 @@ mod test {
 
     #[test]
     fn test_advertising_data_iterator() {
-        let data: &[u8] = &[0x06, 0x05, 0x12, 0x80, 0x0C, 0x06, 0x00];
+        let data: &[u8] = &[0x05, 0x12, 0x80, 0x0C, 0x06, 0x00];
         let mut it = AdvertisingDataIterator {
             data,
             next_index: 0,
         };
         assert_eq!(it.next(), None);
-        assert_eq!(it.next_index, 7);
+        assert_eq!(it.next_index, 6);
+    }
+
+    #[test]
+    fn test_advertising_data_view() {
+        let data: &[u8] = &[0x02, 0x01, 0x06, 0x03, 0x19, 0x40, 0x00];
+        let view: AdvertisingDataView = LeAdvertisingReportData::try_from(data).unwrap().into();
+        assert_eq!(view.data(), data);
+        assert!(view.has_ad_struct(|ad_struct| matches!(ad_struct, AdStruct::Flags(_))));
+        assert_eq!(view.iter().count(), 2);
+        let adv_data: AdvertisingData = view.into();
+        assert_eq!(adv_data.data.data()[0], 7);
+        assert_eq!(&adv_data.data.data()[1..], data);
+        assert!(adv_data.iter().eq(view.iter()));
+    }
+
+    #[test]
+    fn test_full_advertising_data_view() {
+        let adv_data: &[u8] = &[0x02, 0x01, 0x06];
+        let scanresp_data: &[u8] = &[0x03, 0x19, 0x40, 0x00];
+        let adv_data: AdvertisingDataView =
+            LeAdvertisingReportData::try_from(adv_data).unwrap().into();
+        let scanresp_data: AdvertisingDataView = LeAdvertisingReportData::try_from(scanresp_data)
+            .unwrap()
+            .into();
+
+        let view = FullAdvertisingDataView::new(adv_data, None);
+        assert_eq!(view.scan_response_data(), None);
+        assert_eq!(view.iter().count(), 1);
+
+        let view = FullAdvertisingDataView::new(adv_data, Some(scanresp_data));
+        assert_eq!(view.advertising_data(), adv_data);
+        assert_eq!(view.scan_response_data(), Some(scanresp_data));
+        assert!(view.has_ad_struct(|ad_struct| matches!(ad_struct, AdStruct::Appearance(_))));
+        let full_adv_data: FullAdvertisingData = view.into();
+        assert!(full_adv_data.iter().eq(view.iter()));
     }
 }
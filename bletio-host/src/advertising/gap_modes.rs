@@ -0,0 +1,292 @@
+//! GAP discoverable and connectable modes.
+//!
+//! The modes are defined in
+//! [Core Specification 6.0, Vol. 3, Part C, 9.2 and 9.3](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host/generic-access-profile.html).
+//! Advertising in given modes sets the [`Flags`], the [`AdvertisingType`] and the
+//! [`AdvertisingFilterPolicy`] accordingly.
+
+use core::time::Duration;
+
+use bletio_hci::{AdvertisingFilterPolicy, AdvertisingType, DeviceAddress};
+
+use crate::advertising::{AdvertisingError, AdvertisingParameters, Flags, FullAdvertisingData};
+use crate::Error;
+
+/// Maximum time a device may stay in the limited discoverable mode, TGAP(lim_adv_timeout).
+///
+/// The host stops advertising and goes back to standby when it elapses.
+pub const LIMITED_DISCOVERABLE_TIMEOUT: Duration = Duration::from_secs(180);
+
+/// GAP discoverable mode, telling whether and how the device can be discovered.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DiscoverableMode {
+    /// The device cannot be discovered by the general or limited discovery procedures (default).
+    #[default]
+    NonDiscoverable,
+    /// The device can be discovered for a limited time, by the general and limited discovery
+    /// procedures.
+    LimitedDiscoverable,
+    /// The device can be discovered by the general discovery procedure.
+    GeneralDiscoverable,
+}
+
+impl DiscoverableMode {
+    fn flags(&self, flags: Option<Flags>) -> Option<Flags> {
+        let discoverable_flags =
+            Flags::LE_LIMITED_DISCOVERABLE_MODE | Flags::LE_GENERAL_DISCOVERABLE_MODE;
+        match self {
+            Self::NonDiscoverable => flags.map(|flags| flags - discoverable_flags),
+            Self::LimitedDiscoverable => Some(
+                (flags.unwrap_or_default() - discoverable_flags)
+                    | Flags::LE_LIMITED_DISCOVERABLE_MODE,
+            ),
+            Self::GeneralDiscoverable => Some(
+                (flags.unwrap_or_default() - discoverable_flags)
+                    | Flags::LE_GENERAL_DISCOVERABLE_MODE,
+            ),
+        }
+    }
+}
+
+/// GAP connectable mode, telling whether and how other devices can connect to the device.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ConnectableMode {
+    /// Other devices cannot connect (default).
+    ///
+    /// The advertising is scannable if there is some Scan Response Data.
+    #[default]
+    NonConnectable,
+    /// Only the given peer device can connect, the advertising not carrying any data.
+    DirectedConnectable {
+        peer_address: DeviceAddress,
+        high_duty_cycle: bool,
+    },
+    /// Any device can connect, subject to the advertising filter policy when non-discoverable.
+    UndirectedConnectable,
+}
+
+/// Get the advertising parameters and data to use to advertise in the given modes.
+///
+/// In a discoverable mode, scan and connection requests are accepted from all devices, so the
+/// advertising filter policy is only kept in the non-discoverable mode.
+pub(crate) fn apply_gap_modes(
+    discoverable_mode: DiscoverableMode,
+    connectable_mode: &ConnectableMode,
+    adv_params: &AdvertisingParameters,
+    full_adv_data: &FullAdvertisingData,
+) -> Result<(AdvertisingParameters, FullAdvertisingData), Error> {
+    let mut peer_address = adv_params.peer_address().clone();
+    let r#type = match connectable_mode {
+        ConnectableMode::DirectedConnectable { .. }
+            if discoverable_mode != DiscoverableMode::NonDiscoverable =>
+        {
+            return Err(
+                AdvertisingError::DiscoverableModeNotAllowedWithDirectedConnectableMode.into(),
+            );
+        }
+        ConnectableMode::DirectedConnectable {
+            peer_address: address,
+            high_duty_cycle,
+        } => {
+            peer_address = address.clone();
+            if *high_duty_cycle {
+                AdvertisingType::ConnectableHighDutyCycleDirected
+            } else {
+                AdvertisingType::ConnectableLowDutyCycleDirected
+            }
+        }
+        ConnectableMode::UndirectedConnectable => AdvertisingType::ConnectableUndirected,
+        ConnectableMode::NonConnectable if full_adv_data.scan_response_data().is_some() => {
+            AdvertisingType::ScannableUndirected
+        }
+        ConnectableMode::NonConnectable => AdvertisingType::NonConnectableUndirected,
+    };
+    let filter_policy = if discoverable_mode == DiscoverableMode::NonDiscoverable {
+        adv_params.filter_policy()
+    } else {
+        AdvertisingFilterPolicy::ScanAllAndConnectionAll
+    };
+    let adv_params = AdvertisingParameters::builder()
+        .with_interval(adv_params.interval())
+        .with_type(r#type)
+        .with_own_address_type(adv_params.own_address_type())
+        .with_peer_address(peer_address)
+        .with_channel_map(adv_params.channel_map())
+        .with_filter_policy(filter_policy)
+        .try_build()?;
+
+    let full_adv_data = apply_discoverable_mode_flags(discoverable_mode, full_adv_data)?;
+
+    Ok((adv_params, full_adv_data))
+}
+
+/// Get the Full Advertising Data with the Flags set according to the given discoverable mode.
+pub(crate) fn apply_discoverable_mode_flags(
+    discoverable_mode: DiscoverableMode,
+    full_adv_data: &FullAdvertisingData,
+) -> Result<FullAdvertisingData, Error> {
+    let adv_data = full_adv_data
+        .advertising_data()
+        .with_updated_flags(|flags| discoverable_mode.flags(flags))?;
+    FullAdvertisingData::try_new(adv_data, full_adv_data.scan_response_data().cloned())
+}
+
+#[cfg(test)]
+mod test {
+    use bletio_hci::{PublicDeviceAddress, RandomAddress, RandomStaticDeviceAddress};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::advertising::{AdStruct, AdvertisingData, ScanResponseData};
+
+    fn full_adv_data(flags: Option<Flags>, scannable: bool) -> FullAdvertisingData {
+        let mut adv_data = AdvertisingData::builder();
+        if let Some(flags) = flags {
+            adv_data = adv_data.with_flags(flags).unwrap();
+        }
+        let adv_data = adv_data.with_tx_power_level().unwrap().build();
+        let scanresp_data = scannable.then(|| {
+            ScanResponseData::builder()
+                .with_appearance()
+                .unwrap()
+                .build()
+        });
+        FullAdvertisingData::try_new(adv_data, scanresp_data).unwrap()
+    }
+
+    fn flags(full_adv_data: &FullAdvertisingData) -> Option<Flags> {
+        full_adv_data
+            .advertising_data()
+            .iter()
+            .find_map(|ad_struct| match ad_struct {
+                AdStruct::Flags(flags) => Some(flags.value()),
+                _ => None,
+            })
+    }
+
+    #[rstest]
+    #[case::non_discoverable_non_connectable(
+        DiscoverableMode::NonDiscoverable,
+        ConnectableMode::NonConnectable,
+        None,
+        false,
+        AdvertisingType::NonConnectableUndirected,
+        None,
+        AdvertisingFilterPolicy::ScanFilterAcceptListAndConnectionFilterAcceptList
+    )]
+    #[case::non_discoverable_scannable(
+        DiscoverableMode::NonDiscoverable, ConnectableMode::NonConnectable,
+        Some(Flags::LE_GENERAL_DISCOVERABLE_MODE | Flags::BREDR_NOT_SUPPORTED), true,
+        AdvertisingType::ScannableUndirected, Some(Flags::BREDR_NOT_SUPPORTED),
+        AdvertisingFilterPolicy::ScanFilterAcceptListAndConnectionFilterAcceptList
+    )]
+    #[case::non_discoverable_undirected_connectable(
+        DiscoverableMode::NonDiscoverable,
+        ConnectableMode::UndirectedConnectable,
+        None,
+        false,
+        AdvertisingType::ConnectableUndirected,
+        None,
+        AdvertisingFilterPolicy::ScanFilterAcceptListAndConnectionFilterAcceptList
+    )]
+    #[case::limited_discoverable_undirected_connectable(
+        DiscoverableMode::LimitedDiscoverable, ConnectableMode::UndirectedConnectable, None, true,
+        AdvertisingType::ConnectableUndirected,
+        Some(Flags::LE_LIMITED_DISCOVERABLE_MODE | Flags::BREDR_NOT_SUPPORTED),
+        AdvertisingFilterPolicy::ScanAllAndConnectionAll
+    )]
+    #[case::general_discoverable_non_connectable(
+        DiscoverableMode::GeneralDiscoverable,
+        ConnectableMode::NonConnectable,
+        Some(Flags::LE_LIMITED_DISCOVERABLE_MODE),
+        false,
+        AdvertisingType::NonConnectableUndirected,
+        Some(Flags::LE_GENERAL_DISCOVERABLE_MODE),
+        AdvertisingFilterPolicy::ScanAllAndConnectionAll
+    )]
+    fn test_apply_gap_modes(
+        #[case] discoverable_mode: DiscoverableMode,
+        #[case] connectable_mode: ConnectableMode,
+        #[case] initial_flags: Option<Flags>,
+        #[case] scannable: bool,
+        #[case] expected_type: AdvertisingType,
+        #[case] expected_flags: Option<Flags>,
+        #[case] expected_filter_policy: AdvertisingFilterPolicy,
+    ) -> Result<(), Error> {
+        let adv_params = AdvertisingParameters::builder()
+            .with_interval(bletio_hci::advertising_interval_range!(0x0100, 0x0200))
+            .with_filter_policy(
+                AdvertisingFilterPolicy::ScanFilterAcceptListAndConnectionFilterAcceptList,
+            )
+            .try_build()?;
+        let (adv_params, full_adv_data) = apply_gap_modes(
+            discoverable_mode,
+            &connectable_mode,
+            &adv_params,
+            &full_adv_data(initial_flags, scannable),
+        )?;
+        assert_eq!(adv_params.r#type(), expected_type);
+        assert_eq!(adv_params.filter_policy(), expected_filter_policy);
+        assert_eq!(
+            adv_params.interval(),
+            bletio_hci::advertising_interval_range!(0x0100, 0x0200)
+        );
+        assert_eq!(flags(&full_adv_data), expected_flags);
+        assert!(matches!(
+            full_adv_data.advertising_data().iter().next(),
+            Some(AdStruct::Flags(_)) | Some(AdStruct::TxPowerLevel(_))
+        ));
+        assert_eq!(full_adv_data.scan_response_data().is_some(), scannable);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(true, AdvertisingType::ConnectableHighDutyCycleDirected)]
+    #[case(false, AdvertisingType::ConnectableLowDutyCycleDirected)]
+    fn test_apply_gap_modes_directed_connectable(
+        #[case] high_duty_cycle: bool,
+        #[case] expected_type: AdvertisingType,
+    ) -> Result<(), Error> {
+        let peer_address: DeviceAddress =
+            RandomAddress::Static(RandomStaticDeviceAddress::try_new([1, 2, 3, 4, 5, 0xC6])?)
+                .into();
+        let connectable_mode = ConnectableMode::DirectedConnectable {
+            peer_address: peer_address.clone(),
+            high_duty_cycle,
+        };
+        let (adv_params, _) = apply_gap_modes(
+            DiscoverableMode::NonDiscoverable,
+            &connectable_mode,
+            &AdvertisingParameters::default(),
+            &FullAdvertisingData::default(),
+        )?;
+        assert_eq!(adv_params.r#type(), expected_type);
+        assert_eq!(adv_params.peer_address(), &peer_address);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(DiscoverableMode::LimitedDiscoverable)]
+    #[case(DiscoverableMode::GeneralDiscoverable)]
+    fn test_apply_gap_modes_directed_connectable_discoverable_failure(
+        #[case] discoverable_mode: DiscoverableMode,
+    ) {
+        let connectable_mode = ConnectableMode::DirectedConnectable {
+            peer_address: PublicDeviceAddress::new([1, 2, 3, 4, 5, 6]).into(),
+            high_duty_cycle: false,
+        };
+        assert_eq!(
+            apply_gap_modes(
+                discoverable_mode,
+                &connectable_mode,
+                &AdvertisingParameters::default(),
+                &FullAdvertisingData::default(),
+            ),
+            Err(Error::Advertising(
+                AdvertisingError::DiscoverableModeNotAllowedWithDirectedConnectableMode
+            ))
+        );
+    }
+}
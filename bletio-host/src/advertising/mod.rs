@@ -2,6 +2,8 @@
 //!
 //! This module gives access to all that is need to start advertising:
 //!  - definition of the [advertising parameters](advertising_parameters)
+//!  - the GAP [discoverable and connectable modes](gap_modes) to advertise in
+//!  - the [scan filter](scan_filter) selecting the advertising reports to be notified when scanning
 //!  - definition of all the [advertising structures](ad_struct) to be used in the [`AdvertisingData`] or [`ScanResponseData`] packets.
 
 pub use bletio_hci::{
@@ -13,22 +15,32 @@ mod ad_struct;
 
 pub mod advertising_data;
 pub mod advertising_parameters;
+pub mod gap_modes;
+pub mod scan_filter;
 pub mod scan_parameters;
 pub mod uri;
 
+pub use ad_struct::class_of_device::ClassOfDevice;
 pub use ad_struct::flags::Flags;
-pub use ad_struct::local_name::LocalNameComplete;
+pub use ad_struct::indoor_positioning::{IndoorPositioning, IndoorPositioningCoordinates};
+pub use ad_struct::le_role::LeRole;
+pub use ad_struct::local_name::{LocalNameComplete, LOCAL_NAME_MAX_LENGTH};
 pub use ad_struct::peripheral_connection_interval_range::{
     peripheral_connection_interval_range, PeripheralConnectionInterval,
     PeripheralConnectionIntervalRange,
 };
+pub use ad_struct::security_manager::SecurityManagerOutOfBandFlags;
 pub use ad_struct::service_uuid::ServiceListComplete;
+pub use ad_struct::three_dimensional_information::ThreeDimensionalInformationFlags;
+pub use ad_struct::transport_discovery_data::{TransportBlock, TransportBlockIterator};
 pub use ad_struct::AdStruct;
 pub use advertising_data::{
-    AdvertisingData, AdvertisingDataBuilder, FullAdvertisingData, ScanResponseData,
-    ScanResponseDataBuilder,
+    AdvertisingData, AdvertisingDataBuilder, AdvertisingDataView, FullAdvertisingData,
+    FullAdvertisingDataBuilder, FullAdvertisingDataView, ScanResponseData, ScanResponseDataBuilder,
 };
 pub use advertising_parameters::{AdvertisingParameters, AdvertisingParametersBuilder};
+pub use gap_modes::{ConnectableMode, DiscoverableMode, LIMITED_DISCOVERABLE_TIMEOUT};
+pub use scan_filter::{ScanFilter, ScanFilterBuilder};
 pub use scan_parameters::{ScanParameters, ScanParametersBuilder};
 pub use uri::{custom_uri_scheme, CustomUriScheme, Uri, UriScheme};
 
@@ -40,14 +52,24 @@ pub enum AdvertisingError {
     AdvertisingDataWillNotFitAdvertisingPacket,
     /// The Appearance Advertising Structure is not allowed to be present in both the Advertising Data and the Scan Response Data.
     AppearanceNotAllowedInBothAdvertisingDataAndScanResponseData,
+    /// A discoverable mode cannot be used along with the directed connectable mode, whose advertising does not carry any data.
+    DiscoverableModeNotAllowedWithDirectedConnectableMode,
     /// An empty service UUID list Advertising Structure needs to be complete.
     EmptyServiceUuidListShallBeComplete,
     /// Only one advertising interval Advertising Structure is allowed in an Advertising Data or Scan Response Data block.
     OnlyOneAdvertisingIntervalAllowedInAdvertisingDataOrScanResponseData,
     /// Only one appearance Advertising Structure is allowed in an Advertising Data or Scan Response Data block.
     OnlyOneAppearanceAllowedInAdvertisingDataOrScanResponseData,
+    /// Only one broadcast name Advertising Structure is allowed in an Advertising Data or Scan Response Data block.
+    OnlyOneBroadcastNameAllowedInAdvertisingDataOrScanResponseData,
+    /// Only one class of device Advertising Structure is allowed in an Advertising Data or Scan Response Data block.
+    OnlyOneClassOfDeviceAllowedInAdvertisingDataOrScanResponseData,
     /// Only one flags Advertising Structure is allowed in an Advertising Data block.
     OnlyOneFlagsAllowedInAdvertisingData,
+    /// Only one LE Bluetooth device address Advertising Structure is allowed in an Advertising Data or Scan Response Data block.
+    OnlyOneLeBluetoothDeviceAddressAllowedInAdvertisingDataOrScanResponseData,
+    /// Only one LE role Advertising Structure is allowed in an Advertising Data or Scan Response Data block.
+    OnlyOneLeRoleAllowedInAdvertisingDataOrScanResponseData,
     /// Only one LE supported features Advertising Structure is allowed in an Advertising Data or Scan Response Data block.
     OnlyOneLeSupportedFeaturesAllowedInAdvertisingDataOrScanResponseData,
     /// Only one local name Advertising Structure is allowed in an Advertising Data or Scan Response Data block.
@@ -60,14 +82,28 @@ pub enum AdvertisingError {
     PublicTargetAddressAdStructMustContainAtLeastOneAddress,
     /// The Random Target Address Advertising Structure must contain at least one address.
     RandomTargetAddressAdStructMustContainAtLeastOneAddress,
+    /// The mask of the data of a scan filter needs to be empty or as long as the data prefix.
+    ScanFilterMaskLengthDiffersFromDataPrefixLength,
+    /// The data, mask or name prefix of the scan filter is too long.
+    ScanFilterPatternTooLong,
+    /// The scan filter cannot contain more addresses.
+    TooManyScanFilterAddresses,
     /// The provided Advertising Type value is invalid.
     InvalidAdTypeValue(u8),
     /// The advertising parameters are not valid, probably because the advertising type is ScannableUndirected or NonConnectableUndirected, and the minimum advertising interval value is less than 0x00A0.
     InvalidAdvertisingParameters,
     /// The provided Appearance value is invalid.
     InvalidAppearanceValue(u16),
+    /// The provided broadcast name is invalid, it needs to be at least 4 octets long.
+    InvalidBroadcastName,
+    /// The provided channel map is invalid, only the 37 data channels can be used.
+    InvalidChannelMap,
+    /// The provided Class of Device value is invalid.
+    InvalidClassOfDeviceValue(u32),
     /// The provided Company Identifier value is invalid.
     InvalidCompanyIdentifierValue(u16),
+    /// The provided LE Role value is invalid.
+    InvalidLeRoleValue(u8),
     /// The peripheral connection interval range is invalid.
     InvalidPeripheralConnectionIntervalRange,
     /// The provided peripheral connection interval value is invalid.
@@ -0,0 +1,481 @@
+//! Filter of the advertising reports received when scanning.
+//!
+//! A [`ScanFilter`] given when starting scanning is evaluated by the host on each advertising
+//! report, before notifying it to the [`BleHostObserver`](crate::BleHostObserver) and to the
+//! [`HostEvents`](crate::HostEvents) stream, e.g.:
+//!
+//! ```ignore
+//! let filter = ScanFilter::builder()
+//!     .with_service_uuid(Uuid16(0x180F))
+//!     .with_min_rssi(Rssi::try_new(-70)?)
+//!     .with_connectable_only()
+//!     .build();
+//! let host = host
+//!     .start_scanning_with_filter(&scan_params, FilterDuplicates::Enabled, &filter)
+//!     .await?;
+//! ```
+
+use bletio_hci::{ConnectionPeerAddress, DeviceAddress, LeAdvertisingReportEventType, Rssi};
+use heapless::{String, Vec};
+
+use crate::advertising::{
+    AdStruct, AdvertisingError, FullAdvertisingDataView, LOCAL_NAME_MAX_LENGTH,
+};
+use crate::assigned_numbers::CompanyIdentifier;
+use crate::ble_host::FILTER_ACCEPT_LIST_MAX_SIZE;
+use crate::uuid::{Uuid128, Uuid16};
+
+/// Maximum length of the data prefix of a Manufacturer Specific Data filter.
+const DATA_PREFIX_MAX_LENGTH: usize = 27;
+
+/// Builder to create a [`ScanFilter`].
+#[derive(Debug, Default)]
+pub struct ScanFilterBuilder {
+    filter: ScanFilter,
+}
+
+impl ScanFilterBuilder {
+    /// Create a builder to instantiate a [`ScanFilter`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the [`ScanFilter`].
+    pub fn build(self) -> ScanFilter {
+        self.filter
+    }
+
+    /// Only accept the advertising reports of the given device, this method can be called
+    /// several times to accept several devices.
+    pub fn with_address(
+        mut self,
+        address: impl Into<DeviceAddress>,
+    ) -> Result<Self, AdvertisingError> {
+        self.filter
+            .addresses
+            .push(address.into())
+            .map_err(|_| AdvertisingError::TooManyScanFilterAddresses)?;
+        Ok(self)
+    }
+
+    /// Only accept the advertising reports of connectable advertising.
+    pub fn with_connectable_only(mut self) -> Self {
+        self.filter.connectable_only = true;
+        self
+    }
+
+    /// Program the addresses of the filter into the Controller filter accept list when starting
+    /// scanning, so that the advertising reports of the other devices are not even sent to the
+    /// host.
+    ///
+    /// The filter accept list is replaced by the addresses, and the scanning filter policy set
+    /// to use it. This has no effect if the filter does not contain any address.
+    pub fn with_filter_accept_list(mut self) -> Self {
+        self.filter.use_filter_accept_list = true;
+        self
+    }
+
+    /// Only accept the advertising reports containing Manufacturer Specific Data of the given
+    /// company, whose data starts with `data_prefix`.
+    ///
+    /// Only the bits set in `mask` are compared, it needs to be as long as `data_prefix`, or
+    /// empty to compare all the bits.
+    pub fn with_manufacturer_data(
+        mut self,
+        company_identifier: CompanyIdentifier,
+        data_prefix: &[u8],
+        mask: &[u8],
+    ) -> Result<Self, AdvertisingError> {
+        if !mask.is_empty() && mask.len() != data_prefix.len() {
+            return Err(AdvertisingError::ScanFilterMaskLengthDiffersFromDataPrefixLength);
+        }
+        self.filter.manufacturer_data = Some(ManufacturerDataFilter {
+            company_identifier,
+            data_prefix: data_prefix
+                .try_into()
+                .map_err(|_| AdvertisingError::ScanFilterPatternTooLong)?,
+            mask: mask
+                .try_into()
+                .map_err(|_| AdvertisingError::ScanFilterPatternTooLong)?,
+        });
+        Ok(self)
+    }
+
+    /// Only accept the advertising reports received with at least the given RSSI.
+    pub fn with_min_rssi(mut self, min_rssi: Rssi) -> Self {
+        self.filter.min_rssi = Some(min_rssi);
+        self
+    }
+
+    /// Only accept the advertising reports containing a complete or shortened local name
+    /// starting with the given prefix.
+    pub fn with_name_prefix(mut self, name_prefix: &str) -> Result<Self, AdvertisingError> {
+        self.filter.name_prefix = Some(
+            name_prefix
+                .try_into()
+                .map_err(|_| AdvertisingError::ScanFilterPatternTooLong)?,
+        );
+        Ok(self)
+    }
+
+    /// Only accept the advertising reports containing Service Data for the given service UUID.
+    pub fn with_service_data_uuid(mut self, uuid: impl Into<Uuid128>) -> Self {
+        self.filter.service_data_uuid = Some(uuid.into());
+        self
+    }
+
+    /// Only accept the advertising reports listing the given service UUID.
+    pub fn with_service_uuid(mut self, uuid: impl Into<Uuid128>) -> Self {
+        self.filter.service_uuid = Some(uuid.into());
+        self
+    }
+}
+
+/// Filter of the advertising reports received when scanning.
+///
+/// An advertising report is accepted if it matches all the criteria of the filter, an empty
+/// filter accepting all the advertising reports.
+///
+/// Use the [`ScanFilterBuilder`] to instantiate it.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ScanFilter {
+    addresses: Vec<DeviceAddress, FILTER_ACCEPT_LIST_MAX_SIZE>,
+    connectable_only: bool,
+    manufacturer_data: Option<ManufacturerDataFilter>,
+    min_rssi: Option<Rssi>,
+    name_prefix: Option<String<LOCAL_NAME_MAX_LENGTH>>,
+    service_data_uuid: Option<Uuid128>,
+    service_uuid: Option<Uuid128>,
+    use_filter_accept_list: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+struct ManufacturerDataFilter {
+    company_identifier: CompanyIdentifier,
+    data_prefix: Vec<u8, DATA_PREFIX_MAX_LENGTH>,
+    mask: Vec<u8, DATA_PREFIX_MAX_LENGTH>,
+}
+
+impl ManufacturerDataFilter {
+    fn matches(&self, company_identifier: CompanyIdentifier, data: &[u8]) -> bool {
+        company_identifier == self.company_identifier
+            && data.len() >= self.data_prefix.len()
+            && self
+                .data_prefix
+                .iter()
+                .zip(data)
+                .enumerate()
+                .all(|(index, (expected, value))| {
+                    let mask = self.mask.get(index).copied().unwrap_or(0xFF);
+                    (expected & mask) == (value & mask)
+                })
+    }
+}
+
+impl ScanFilter {
+    /// Instantiate a builder to create a Scan Filter.
+    pub fn builder() -> ScanFilterBuilder {
+        ScanFilterBuilder::new()
+    }
+
+    /// Tell whether an advertising report is accepted by the filter.
+    pub fn matches(
+        &self,
+        event_type: LeAdvertisingReportEventType,
+        address: &ConnectionPeerAddress,
+        rssi: Option<Rssi>,
+        data: FullAdvertisingDataView<'_>,
+    ) -> bool {
+        (!self.connectable_only
+            || matches!(
+                event_type,
+                LeAdvertisingReportEventType::ConnectableUndirected
+                    | LeAdvertisingReportEventType::ConnectableDirected
+            ))
+            && (self.addresses.is_empty() || self.addresses.contains(&device_address(address)))
+            && self
+                .min_rssi
+                .is_none_or(|min_rssi| rssi.is_some_and(|rssi| rssi.value() >= min_rssi.value()))
+            && self.service_uuid.is_none_or(|uuid| {
+                data.has_ad_struct(|ad_struct| match ad_struct {
+                    AdStruct::ServiceUuid16(uuids) => uuids
+                        .iter()
+                        .any(|service_uuid| Uuid16(*service_uuid as u16) == uuid),
+                    AdStruct::ServiceUuid32(uuids) => uuids.iter().any(|value| *value == uuid),
+                    AdStruct::ServiceUuid128(uuids) => uuids.contains(&uuid),
+                    _ => false,
+                })
+            })
+            && self.service_data_uuid.is_none_or(|uuid| {
+                data.has_ad_struct(|ad_struct| match ad_struct {
+                    AdStruct::ServiceDataUuid16(service_data) => {
+                        Uuid16(service_data.uuid() as u16) == uuid
+                    }
+                    AdStruct::ServiceDataUuid32(service_data) => service_data.uuid() == uuid,
+                    AdStruct::ServiceDataUuid128(service_data) => service_data.uuid() == uuid,
+                    _ => false,
+                })
+            })
+            && self.manufacturer_data.as_ref().is_none_or(|filter| {
+                data.has_ad_struct(|ad_struct| match ad_struct {
+                    AdStruct::ManufacturerSpecificData(manufacturer_data) => {
+                        filter.matches(manufacturer_data.manufacturer(), manufacturer_data.data())
+                    }
+                    _ => false,
+                })
+            })
+            && self.name_prefix.as_ref().is_none_or(|name_prefix| {
+                data.has_ad_struct(|ad_struct| match ad_struct {
+                    AdStruct::LocalName(local_name) => {
+                        local_name.value().starts_with(name_prefix.as_str())
+                    }
+                    _ => false,
+                })
+            })
+    }
+
+    /// Get the addresses to program into the Controller filter accept list, if requested.
+    pub(crate) fn filter_accept_list_addresses(&self) -> Option<&[DeviceAddress]> {
+        (self.use_filter_accept_list && !self.addresses.is_empty())
+            .then_some(self.addresses.as_slice())
+    }
+}
+
+fn device_address(address: &ConnectionPeerAddress) -> DeviceAddress {
+    match address {
+        ConnectionPeerAddress::PublicDevice(address)
+        | ConnectionPeerAddress::PublicIdentity(address) => address.clone().into(),
+        ConnectionPeerAddress::RandomDevice(address)
+        | ConnectionPeerAddress::RandomIdentity(address) => address.clone().into(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bletio_hci::{PublicDeviceAddress, RandomAddress, RandomStaticDeviceAddress};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::advertising::{FullAdvertisingData, ServiceListComplete};
+    use crate::assigned_numbers::ServiceUuid;
+    use crate::uuid::Uuid32;
+
+    const ADDRESS: [u8; 6] = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
+
+    fn full_adv_data() -> FullAdvertisingData {
+        FullAdvertisingData::builder()
+            .with_service_uuid16(&[ServiceUuid::Battery], ServiceListComplete::Complete)
+            .unwrap()
+            .with_service_data_uuid32(Uuid32(0x1234_5678), &[0x01])
+            .unwrap()
+            .with_manufacturer_specific_data(
+                CompanyIdentifier::StMicroelectronics,
+                &[0x12, 0x34, 0x56],
+            )
+            .unwrap()
+            .with_local_name("bletio-sensor")
+            .unwrap()
+            .build()
+            .unwrap()
+    }
+
+    fn matches(filter: &ScanFilter, event_type: LeAdvertisingReportEventType) -> bool {
+        let data = full_adv_data();
+        filter.matches(
+            event_type,
+            &ConnectionPeerAddress::PublicDevice(PublicDeviceAddress::new(ADDRESS)),
+            Some(Rssi::try_new(-60).unwrap()),
+            FullAdvertisingDataView::new(
+                (&data.adv_data).into(),
+                data.scanresp_data.as_ref().map(Into::into),
+            ),
+        )
+    }
+
+    #[rstest]
+    #[case::empty(ScanFilter::default(), true)]
+    #[case::connectable_only(ScanFilter::builder().with_connectable_only().build(), true)]
+    #[case::address(
+        ScanFilter::builder().with_address(PublicDeviceAddress::new(ADDRESS)).unwrap().build(),
+        true
+    )]
+    #[case::other_address(
+        ScanFilter::builder()
+            .with_address(RandomAddress::Static(RandomStaticDeviceAddress::try_new([0x01, 0x02, 0x03, 0x04, 0x05, 0xC6]).unwrap()))
+            .unwrap()
+            .build(),
+        false
+    )]
+    #[case::min_rssi(ScanFilter::builder().with_min_rssi(Rssi::try_new(-60).unwrap()).build(), true)]
+    #[case::min_rssi_too_high(
+        ScanFilter::builder().with_min_rssi(Rssi::try_new(-59).unwrap()).build(),
+        false
+    )]
+    #[case::service_uuid16(ScanFilter::builder().with_service_uuid(Uuid16(0x180F)).build(), true)]
+    #[case::service_uuid128(
+        ScanFilter::builder().with_service_uuid(Uuid128::from(Uuid16(0x180F))).build(),
+        true
+    )]
+    #[case::other_service_uuid(
+        ScanFilter::builder().with_service_uuid(Uuid16(0x180D)).build(),
+        false
+    )]
+    #[case::service_data_uuid(
+        ScanFilter::builder().with_service_data_uuid(Uuid32(0x1234_5678)).build(),
+        true
+    )]
+    #[case::other_service_data_uuid(
+        ScanFilter::builder().with_service_data_uuid(Uuid16(0x180F)).build(),
+        false
+    )]
+    #[case::manufacturer(
+        ScanFilter::builder()
+            .with_manufacturer_data(CompanyIdentifier::StMicroelectronics, &[], &[])
+            .unwrap()
+            .build(),
+        true
+    )]
+    #[case::manufacturer_data_prefix(
+        ScanFilter::builder()
+            .with_manufacturer_data(CompanyIdentifier::StMicroelectronics, &[0x12, 0x34], &[])
+            .unwrap()
+            .build(),
+        true
+    )]
+    #[case::manufacturer_data_prefix_with_mask(
+        ScanFilter::builder()
+            .with_manufacturer_data(
+                CompanyIdentifier::StMicroelectronics,
+                &[0x10, 0xFF, 0x56],
+                &[0xF0, 0x00, 0xFF]
+            )
+            .unwrap()
+            .build(),
+        true
+    )]
+    #[case::other_manufacturer_data(
+        ScanFilter::builder()
+            .with_manufacturer_data(CompanyIdentifier::StMicroelectronics, &[0x12, 0x35], &[])
+            .unwrap()
+            .build(),
+        false
+    )]
+    #[case::manufacturer_data_too_short(
+        ScanFilter::builder()
+            .with_manufacturer_data(
+                CompanyIdentifier::StMicroelectronics,
+                &[0x12, 0x34, 0x56, 0x78],
+                &[]
+            )
+            .unwrap()
+            .build(),
+        false
+    )]
+    #[case::name_prefix(
+        ScanFilter::builder().with_name_prefix("bletio").unwrap().build(),
+        true
+    )]
+    #[case::other_name_prefix(
+        ScanFilter::builder().with_name_prefix("sensor").unwrap().build(),
+        false
+    )]
+    #[case::all_criteria(
+        ScanFilter::builder()
+            .with_address(PublicDeviceAddress::new(ADDRESS))
+            .unwrap()
+            .with_connectable_only()
+            .with_min_rssi(Rssi::try_new(-80).unwrap())
+            .with_name_prefix("bletio-")
+            .unwrap()
+            .with_service_uuid(Uuid16(0x180F))
+            .build(),
+        true
+    )]
+    fn test_scan_filter_matches(#[case] filter: ScanFilter, #[case] expected: bool) {
+        assert_eq!(
+            matches(&filter, LeAdvertisingReportEventType::ConnectableUndirected),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_scan_filter_connectable_only() {
+        let filter = ScanFilter::builder().with_connectable_only().build();
+        assert!(matches(
+            &filter,
+            LeAdvertisingReportEventType::ConnectableDirected
+        ));
+        assert!(!matches(
+            &filter,
+            LeAdvertisingReportEventType::ScannableUndirected
+        ));
+        assert!(!matches(
+            &filter,
+            LeAdvertisingReportEventType::NonConnectableUndirected
+        ));
+    }
+
+    #[test]
+    fn test_scan_filter_builder_failure() {
+        let err = ScanFilter::builder()
+            .with_manufacturer_data(
+                CompanyIdentifier::StMicroelectronics,
+                &[0x12, 0x34],
+                &[0xFF],
+            )
+            .unwrap_err();
+        assert_eq!(
+            err,
+            AdvertisingError::ScanFilterMaskLengthDiffersFromDataPrefixLength
+        );
+        let err = ScanFilter::builder()
+            .with_manufacturer_data(
+                CompanyIdentifier::StMicroelectronics,
+                &[0x12; DATA_PREFIX_MAX_LENGTH + 1],
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(err, AdvertisingError::ScanFilterPatternTooLong);
+        let err = ScanFilter::builder()
+            .with_name_prefix("a name prefix that is too long")
+            .unwrap_err();
+        assert_eq!(err, AdvertisingError::ScanFilterPatternTooLong);
+        let mut builder = ScanFilter::builder();
+        for i in 0..FILTER_ACCEPT_LIST_MAX_SIZE {
+            builder = builder
+                .with_address(PublicDeviceAddress::new([i as u8, 0, 0, 0, 0, 0]))
+                .unwrap();
+        }
+        let err = builder
+            .with_address(PublicDeviceAddress::new(ADDRESS))
+            .unwrap_err();
+        assert_eq!(err, AdvertisingError::TooManyScanFilterAddresses);
+    }
+
+    #[rstest]
+    #[case(ScanFilter::builder().with_filter_accept_list().build(), false)]
+    #[case(
+        ScanFilter::builder().with_address(PublicDeviceAddress::new(ADDRESS)).unwrap().build(),
+        false
+    )]
+    #[case(
+        ScanFilter::builder()
+            .with_address(PublicDeviceAddress::new(ADDRESS))
+            .unwrap()
+            .with_filter_accept_list()
+            .build(),
+        true
+    )]
+    fn test_scan_filter_accept_list_addresses(
+        #[case] filter: ScanFilter,
+        #[case] programmed: bool,
+    ) {
+        let expected: &[DeviceAddress] = &[PublicDeviceAddress::new(ADDRESS).into()];
+        assert_eq!(
+            filter.filter_accept_list_addresses(),
+            programmed.then_some(expected)
+        );
+    }
+}
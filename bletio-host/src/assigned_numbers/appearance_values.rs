@@ -657,3 +657,330 @@ pub enum AppearanceValue {
     /// `Industrial Tools - Torque Screwdriver` Appearance value
     TorqueScrewdriver = 0x14C9,
 }
+
+impl AppearanceValue {
+    /// Get the name of the appearance, as defined in the assigned numbers.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::GenericUnknown => "Generic Unknown",
+            Self::GenericPhone => "Generic Phone",
+            Self::GenericComputer => "Generic Computer",
+            Self::DesktopWorkstation => "Desktop Workstation",
+            Self::ServerClassComputer => "Server-class Computer",
+            Self::Laptop => "Laptop",
+            Self::HandheldPcPdaClamshell => "Handheld PC/PDA (clamshell)",
+            Self::PalmSizePcPda => "Palm-size PC/PDA",
+            Self::WearableComputerWatchSize => "Wearable computer (watch size)",
+            Self::Tablet => "Tablet",
+            Self::DockingStation => "Docking Station",
+            Self::AllInOne => "All in One",
+            Self::BladeServer => "Blade Server",
+            Self::Convertible => "Convertible",
+            Self::Detachable => "Detachable",
+            Self::IoTGateway => "IoT Gateway",
+            Self::MiniPc => "Mini PC",
+            Self::StickPc => "Stick PC",
+            Self::GenericWatch => "Generic Watch",
+            Self::SportsWatch => "Sports Watch",
+            Self::Smartwatch => "Smartwatch",
+            Self::GenericClock => "Generic Clock",
+            Self::GenericDisplay => "Generic Display",
+            Self::GenericRemoteControl => "Generic Remote Control",
+            Self::GenericEyeGlasses => "Generic Eye-glasses",
+            Self::GenericTag => "Generic Tag",
+            Self::GenericKeyring => "Generic Keyring",
+            Self::GenericMediaPlayer => "Generic Media Player",
+            Self::GenericBarcodeScanner => "Generic Barcode Scanner",
+            Self::GenericThermometer => "Generic Thermometer",
+            Self::EarThermometer => "Ear Thermometer",
+            Self::GenericHeartRateSensor => "Generic Heart Rate Sensor",
+            Self::HeartRateBelt => "Heart Rate Belt",
+            Self::GenericBloodPressure => "Generic Blood Pressure",
+            Self::ArmBloodPressure => "Arm Blood Pressure",
+            Self::WristBloodPressure => "Wrist Blood Pressure",
+            Self::GenericHumanInterfaceDevice => "Generic Human Interface Device",
+            Self::Keyboard => "Keyboard",
+            Self::Mouse => "Mouse",
+            Self::Joystick => "Joystick",
+            Self::Gamepad => "Gamepad",
+            Self::DigitizerTablet => "Digitizer Tablet",
+            Self::CardReader => "Card Reader",
+            Self::DigitalPen => "Digital Pen",
+            Self::BarcodeScanner => "Barcode Scanner",
+            Self::Touchpad => "Touchpad",
+            Self::PresentationRemote => "Presentation Remote",
+            Self::GenericGlucoseMeter => "Generic Glucose Meter",
+            Self::GenericRunningWalkingSensor => "Generic Running Walking Sensor",
+            Self::InShoeRunningWalkingSensor => "In-Shoe Running Walking Sensor",
+            Self::OnShoeRunningWalkingSensor => "On-Shoe Running Walking Sensor",
+            Self::OnHipRunningWalkingSensor => "On-Hip Running Walking Sensor",
+            Self::GenericCycling => "Generic Cycling",
+            Self::CyclingComputer => "Cycling Computer",
+            Self::SpeedSensor => "Speed Sensor",
+            Self::CadenceSensor => "Cadence Sensor",
+            Self::PowerSensor => "Power Sensor",
+            Self::SpeedAndCadenceSensor => "Speed and Cadence Sensor",
+            Self::GenericControlDevice => "Generic Control Device",
+            Self::Switch => "Switch",
+            Self::MultiSwitch => "Multi-switch",
+            Self::Button => "Button",
+            Self::Slider => "Slider",
+            Self::RotarySwitch => "Rotary Switch",
+            Self::TouchPanel => "Touch Panel",
+            Self::SingleSwitch => "Single Switch",
+            Self::DoubleSwitch => "Double Switch",
+            Self::TripleSwitch => "Triple Switch",
+            Self::BatterySwitch => "Battery Switch",
+            Self::EnergyHarvestingSwitch => "Energy Harvesting Switch",
+            Self::PushButton => "Push Button",
+            Self::Dial => "Dial",
+            Self::GenericNetworkDevice => "Generic Network Device",
+            Self::AccessPoint => "Access Point",
+            Self::MeshDevice => "Mesh Device",
+            Self::MeshNetworkProxy => "Mesh Network Proxy",
+            Self::GenericSensor => "Generic Sensor",
+            Self::MotionSensor => "Motion Sensor",
+            Self::AirQualitySensor => "Air quality Sensor",
+            Self::TemperatureSensor => "Temperature Sensor",
+            Self::HumiditySensor => "Humidity Sensor",
+            Self::LeakSensor => "Leak Sensor",
+            Self::SmokeSensor => "Smoke Sensor",
+            Self::OccupancySensor => "Occupancy Sensor",
+            Self::ContactSensor => "Contact Sensor",
+            Self::CarbonMonoxideSensor => "Carbon Monoxide Sensor",
+            Self::CarbonDioxideSensor => "Carbon Dioxide Sensor",
+            Self::AmbientLightSensor => "Ambient Light Sensor",
+            Self::EnergySensor => "Energy Sensor",
+            Self::ColorLightSensor => "Color Light Sensor",
+            Self::RainSensor => "Rain Sensor",
+            Self::FireSensor => "Fire Sensor",
+            Self::WindSensor => "Wind Sensor",
+            Self::ProximitySensor => "Proximity Sensor",
+            Self::MultiSensor => "Multi-Sensor",
+            Self::FlushMountedSensor => "Flush Mounted Sensor",
+            Self::CeilingMountedSensor => "Ceiling Mounted Sensor",
+            Self::WallMountedSensor => "Wall Mounted Sensor",
+            Self::Multisensor => "Multisensor",
+            Self::EnergyMeter => "Energy Meter",
+            Self::FlameDetector => "Flame Detector",
+            Self::VehicleTirePressureSensor => "Vehicle Tire Pressure Sensor",
+            Self::GenericLightFixtures => "Generic Light Fixtures",
+            Self::WallLight => "Wall Light",
+            Self::CeilingLight => "Ceiling Light",
+            Self::FloorLight => "Floor Light",
+            Self::CabinetLight => "Cabinet Light",
+            Self::DeskLight => "Desk Light",
+            Self::TrofferLight => "Troffer Light",
+            Self::PendantLight => "Pendant Light",
+            Self::InGroundLight => "In-ground Light",
+            Self::FloodLight => "Flood Light",
+            Self::UnderwaterLight => "Underwater Light",
+            Self::BollardWithLight => "Bollard with Light",
+            Self::PathwayLight => "Pathway Light",
+            Self::GardenLight => "Garden Light",
+            Self::PoleTopLight => "Pole-top Light",
+            Self::Spotlight => "Spotlight",
+            Self::LinearLight => "Linear Light",
+            Self::StreetLight => "Street Light",
+            Self::ShelvesLight => "Shelves Light",
+            Self::BayLight => "Bay Light",
+            Self::EmergencyExitLight => "Emergency Exit Light",
+            Self::LightController => "Light Controller",
+            Self::LightDriver => "Light Driver",
+            Self::Bulb => "Bulb",
+            Self::LowBayLight => "Low-bay Light",
+            Self::HighBayLight => "High-bay Light",
+            Self::GenericFan => "Generic Fan",
+            Self::CeilingFan => "Ceiling Fan",
+            Self::AxialFan => "Axial Fan",
+            Self::ExhaustFan => "Exhaust Fan",
+            Self::PedestalFan => "Pedestal Fan",
+            Self::DeskFan => "Desk Fan",
+            Self::WallFan => "Wall Fan",
+            Self::GenericHvac => "Generic HVAC",
+            Self::Thermostat => "Thermostat",
+            Self::Humidifier => "Humidifier",
+            Self::DeHumidifier => "De-humidifier",
+            Self::Heater => "Heater",
+            Self::HvacRadiator => "Radiator",
+            Self::HvacBoiler => "Boiler",
+            Self::HvacHeatPump => "Heat Pump",
+            Self::HvacInfraredHeater => "Infrared Heater",
+            Self::HvacRadiantPanelHeater => "Radiant Panel Heater",
+            Self::HvacFanHeater => "Fan Heater",
+            Self::HvacAirCurtain => "Air Curtain",
+            Self::GenericAirConditioning => "Generic Air Conditioning",
+            Self::GenericHumidifier => "Generic Humidifier",
+            Self::GenericHeating => "Generic Heating",
+            Self::HeatingRadiator => "Radiator",
+            Self::HeatingBoiler => "Boiler",
+            Self::HeatingHeatPump => "Heat Pump",
+            Self::HeatingInfraredHeater => "Infrared Heater",
+            Self::HeatingRadiantPanelHeater => "Radiant Panel Heater",
+            Self::HeatingFanHeater => "Fan Heater",
+            Self::HeatingAirCurtain => "Air Curtain",
+            Self::GenericAccessControl => "Generic Access Control",
+            Self::AccessDoor => "Access Door",
+            Self::GarageDoor => "Garage Door",
+            Self::EmergencyExitDoor => "Emergency Exit Door",
+            Self::AccessLock => "Access Lock",
+            Self::Elevator => "Elevator",
+            Self::Window => "Window",
+            Self::EntranceGate => "Entrance Gate",
+            Self::DoorLock => "Door Lock",
+            Self::Locker => "Locker",
+            Self::GenericMotorizedDevice => "Generic Motorized Device",
+            Self::MotorizedGate => "Motorized Gate",
+            Self::Awning => "Awning",
+            Self::BlindsOrShades => "Blinds or Shades",
+            Self::Curtains => "Curtains",
+            Self::Screen => "Screen",
+            Self::GenericPowerDevice => "Generic Power Device",
+            Self::PowerOutlet => "Power Outlet",
+            Self::PowerStrip => "Power Strip",
+            Self::Plug => "Plug",
+            Self::PowerSupply => "Power Supply",
+            Self::LedDriver => "LED Driver",
+            Self::FluorescentLampGear => "Fluorescent Lamp Gear",
+            Self::HidLampGear => "HID Lamp Gear",
+            Self::ChargeCase => "Charge Case",
+            Self::PowerBank => "Power Bank",
+            Self::GenericLightSource => "Generic Light Source",
+            Self::IncandescentLightBulb => "Incandescent Light Bulb",
+            Self::LedLamp => "LED Lamp",
+            Self::HidLamp => "HID Lamp",
+            Self::FluorescentLamp => "Fluorescent Lamp",
+            Self::LedArray => "LED Array",
+            Self::MultiColorLedArray => "Multi-Color LED Array",
+            Self::LowVoltageHalogen => "Low voltage halogen",
+            Self::OrganicLightEmittingDiodeOled => "Organic light emitting diode (OLED)",
+            Self::GenericWindowCovering => "Generic Window Covering",
+            Self::WindowShades => "Window Shades",
+            Self::WindowBlinds => "Window Blinds",
+            Self::WindowAwning => "Window Awning",
+            Self::WindowCurtain => "Window Curtain",
+            Self::ExteriorShutter => "Exterior Shutter",
+            Self::ExteriorScreen => "Exterior Screen",
+            Self::GenericAudioSink => "Generic Audio Sink",
+            Self::StandaloneSpeaker => "Standalone Speaker",
+            Self::Soundbar => "Soundbar",
+            Self::BookshelfSpeaker => "Bookshelf Speaker",
+            Self::StandmountedSpeaker => "Standmounted Speaker",
+            Self::Speakerphone => "Speakerphone",
+            Self::GenericAudioSource => "Generic Audio Source",
+            Self::Microphone => "Microphone",
+            Self::Alarm => "Alarm",
+            Self::Bell => "Bell",
+            Self::Horn => "Horn",
+            Self::BroadcastingDevice => "Broadcasting Device",
+            Self::ServiceDesk => "Service Desk",
+            Self::Kiosk => "Kiosk",
+            Self::BroadcastingRoom => "Broadcasting Room",
+            Self::Auditorium => "Auditorium",
+            Self::GenericMotorizedVehicle => "Generic Motorized Vehicle",
+            Self::Car => "Car",
+            Self::LargeGoodsVehicle => "Large Goods Vehicle",
+            Self::_2WheeledVehicle => "2-Wheeled Vehicle",
+            Self::Motorbike => "Motorbike",
+            Self::Scooter => "Scooter",
+            Self::Moped => "Moped",
+            Self::_3WheeledVehicle => "3-Wheeled Vehicle",
+            Self::LightVehicle => "Light Vehicle",
+            Self::QuadBike => "Quad Bike",
+            Self::Minibus => "Minibus",
+            Self::Bus => "Bus",
+            Self::Trolley => "Trolley",
+            Self::AgriculturalVehicle => "Agricultural Vehicle",
+            Self::CamperCaravan => "Camper / Caravan",
+            Self::RecreationalVehicleMotorHome => "Recreational Vehicle / Motor Home",
+            Self::GenericDomesticAppliance => "Generic Domestic Appliance",
+            Self::Refrigerator => "Refrigerator",
+            Self::Freezer => "Freezer",
+            Self::Oven => "Oven",
+            Self::Microwave => "Microwave",
+            Self::Toaster => "Toaster",
+            Self::WashingMachine => "Washing Machine",
+            Self::Dryer => "Dryer",
+            Self::CoffeeMaker => "Coffee maker",
+            Self::ClothesIron => "Clothes iron",
+            Self::CurlingIron => "Curling iron",
+            Self::HairDryer => "Hair dryer",
+            Self::VacuumCleaner => "Vacuum cleaner",
+            Self::RoboticVacuumCleaner => "Robotic vacuum cleaner",
+            Self::RiceCooker => "Rice cooker",
+            Self::ClothesSteamer => "Clothes steamer",
+            Self::GenericWearableAudioDevice => "Generic Wearable Audio Device",
+            Self::Earbud => "Earbud",
+            Self::Headset => "Headset",
+            Self::Headphones => "Headphones",
+            Self::NeckBand => "Neck Band",
+            Self::GenericAircraft => "Generic Aircraft",
+            Self::LightAircraft => "Light Aircraft",
+            Self::Microlight => "Microlight",
+            Self::Paraglider => "Paraglider",
+            Self::LargePassengerAircraft => "Large Passenger Aircraft",
+            Self::GenericAvEquipment => "Generic AV Equipment",
+            Self::Amplifier => "Amplifier",
+            Self::Receiver => "Receiver",
+            Self::Radio => "Radio",
+            Self::Tuner => "Tuner",
+            Self::Turntable => "Turntable",
+            Self::CdPlayer => "CD Player",
+            Self::DvdPlayer => "DVD Player",
+            Self::BlurayPlayer => "Bluray Player",
+            Self::OpticalDiscPlayer => "Optical Disc Player",
+            Self::SetTopBox => "Set-Top Box",
+            Self::GenericDisplayEquipment => "Generic Display Equipment",
+            Self::Television => "Television",
+            Self::Monitor => "Monitor",
+            Self::Projector => "Projector",
+            Self::GenericHearingAid => "Generic Hearing aid",
+            Self::InEarHearingAid => "In-ear hearing aid",
+            Self::BehindEarHearingAid => "Behind-ear hearing aid",
+            Self::CochlearImplant => "Cochlear Implant",
+            Self::GenericGaming => "Generic Gaming",
+            Self::HomeVideoGameConsole => "Home Video Game Console",
+            Self::PortableHandheldConsole => "Portable handheld console",
+            Self::GenericSignage => "Generic Signage",
+            Self::DigitalSignage => "Digital Signage",
+            Self::ElectronicLabel => "Electronic Label",
+            Self::GenericPulseOximeter => "Generic Pulse Oximeter",
+            Self::FingertipPulseOximeter => "Fingertip Pulse Oximeter",
+            Self::WristWornPulseOximeter => "Wrist Worn Pulse Oximeter",
+            Self::GenericWeightScale => "Generic Weight Scale",
+            Self::GenericPersonalMobilityDevice => "Generic Personal Mobility Device",
+            Self::PoweredWheelchair => "Powered Wheelchair",
+            Self::MobilityScooter => "Mobility Scooter",
+            Self::GenericContinuousGlucoseMonitor => "Generic Continuous Glucose Monitor",
+            Self::GenericInsulinPump => "Generic Insulin Pump",
+            Self::InsulinPumpDurablePump => "Insulin Pump, durable pump",
+            Self::InsulinPumpPatchPump => "Insulin Pump, patch pump",
+            Self::InsulinPen => "Insulin Pen",
+            Self::GenericMedicationDelivery => "Generic Medication Delivery",
+            Self::GenericSpirometer => "Generic Spirometer",
+            Self::HandheldSpirometer => "Handheld Spirometer",
+            Self::GenericOutdoorSportsActivity => "Generic Outdoor Sports Activity",
+            Self::LocationDisplay => "Location Display",
+            Self::LocationAndNavigationDisplay => "Location and Navigation Display",
+            Self::LocationPod => "Location Pod",
+            Self::LocationAndNavigationPod => "Location and Navigation Pod",
+            Self::GenericIndustrialMeasurementDevice => "Generic Industrial Measurement Device",
+            Self::TorqueTestingDevice => "Torque Testing Device",
+            Self::Caliper => "Caliper",
+            Self::DialIndicator => "Dial Indicator",
+            Self::Micrometer => "Micrometer",
+            Self::HeightGauge => "Height Gauge",
+            Self::ForceGauge => "Force Gauge",
+            Self::GenericIndustrialTools => "Generic Industrial Tools",
+            Self::MachineToolHolder => "Machine Tool Holder",
+            Self::GenericClampingDevice => "Generic Clamping Device",
+            Self::ClampingJawsJawChuck => "Clamping Jaws/Jaw Chuck",
+            Self::ClampingColletChuck => "Clamping (Collet) Chuck",
+            Self::ClampingMandrel => "Clamping Mandrel",
+            Self::Vise => "Vise",
+            Self::ZeroPointClampingSystem => "Zero-Point Clamping System",
+            Self::TorqueWrench => "Torque Wrench",
+            Self::TorqueScrewdriver => "Torque Screwdriver",
+        }
+    }
+}
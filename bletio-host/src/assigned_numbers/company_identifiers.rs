@@ -7582,3 +7582,4103 @@ pub enum CompanyIdentifier {
     /// Ericsson AB (0x0000)
     EricssonAb = 0x0000,
 }
+
+impl CompanyIdentifier {
+    /// Get the name of the company, as defined in the assigned numbers.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::CodieLlc => "Codie LLC",
+            Self::CanyonBicyclesGmbH => "Canyon Bicycles GmbH",
+            Self::AuthGateBV => "AuthGate B.V.",
+            Self::AlibabaChinaCoLtd => "Alibaba (China) Co., Ltd.",
+            Self::PacificMarineBatteriesPtyLimited => "PACIFIC MARINE BATTERIES PTY. LIMITED",
+            Self::HerschelInfraredLtd => "Herschel Infrared Ltd",
+            Self::HighEntropyLlc => "High Entropy, LLC",
+            Self::Crossdoor => "Crossdoor",
+            Self::WestInxLtd => "WEST inx Ltd.",
+            Self::SchulteSchlagbaumAg => "Schulte-Schlagbaum AG",
+            Self::DeityAcousticTechnologyCo => "Deity Acoustic Technology Co.",
+            Self::TongfangHealthTechnologyBeijingCoLtd => {
+                "Tongfang Health Technology (Beijing) Co., Ltd."
+            }
+            Self::GpAcousticsInternationalLimited => "GP Acoustics International Limited",
+            Self::AsahiDensoCoLtd => "Asahi Denso Co.,Ltd.",
+            Self::ThermyLtd => "THERMY LTD",
+            Self::EgojinCoLtd => "egojin co,.ltd",
+            Self::ParagonId => "PARAGON ID",
+            Self::EmbeddedSolutionsLlc => "Embedded Solutions LLC",
+            Self::ServerProductsInc => "Server Products, Inc.",
+            Self::PreseedJapanCorporation => "Preseed Japan Corporation",
+            Self::BluefinDataLlc => "BLUEFIN DATA, LLC",
+            Self::ZucchettiAxess => "Zucchetti Axess",
+            Self::PradcoOutdoorBrands => "PRADCO Outdoor Brands",
+            Self::WearNexLimited => "WearNex Limited",
+            Self::FactorySense => "FactorySense",
+            Self::UnfoldedCircleApS => "Unfolded Circle ApS",
+            Self::BhClearsMicroelectronicsShanghaiCoLtd => {
+                "BHClears Microelectronics (Shanghai) Co., Ltd."
+            }
+            Self::OptronCoLtd => "OPTRON Co., Ltd.",
+            Self::DynetrexSolutionsInc => "Dynetrex Solutions Inc.",
+            Self::SteyrSportGmbH => "STEYR Sport GmbH",
+            Self::HiveSoundzInc => "Hive Soundz inc.",
+            Self::MakichieCoLtd => "Makichie Co., Ltd.",
+            Self::DongguanTrangjanIndustrialCoLtd => "Dongguan Trangjan Industrial Co., Ltd",
+            Self::BrickXterGmbH => "BrickXter GmbH",
+            Self::AmgLabLlc => "AMG Lab LLC",
+            Self::EasyReachSolutionsPrivateLimited => "EasyReach Solutions Private Limited",
+            Self::BiTechAutomotiveWuhuCoLtd => "BiTECH Automotive (Wuhu) Co.,Ltd",
+            Self::OlisElectronicsLlc => "OLIS ELECTRONICS, LLC",
+            Self::QikconnexLlc => "QIKCONNEX LLC",
+            Self::CulliganInternationalCompany => "Culligan International Company",
+            Self::EnlessWireless => "ENLESS WIRELESS",
+            Self::OwletBabyCareInc => "Owlet Baby Care Inc.",
+            Self::TravelxpIndiaPrivateLimited => "Travelxp India Private Limited",
+            Self::AudinorApS => "Audinor ApS",
+            Self::AndrewsAndArnoldLtd => "Andrews & Arnold Ltd",
+            Self::PanasonicAutomotiveSystemsCoLtd => "Panasonic Automotive Systems Co., Ltd.",
+            Self::ScanbroOu => "Scanbro OU",
+            Self::MediboundInc => "Medibound, Inc.",
+            Self::ChromaticInc => "Chromatic Inc.",
+            Self::KokoromilInc => "kokoromil Inc.",
+            Self::SkeedCoLtd => "Skeed,co,Ltd.",
+            Self::ViaanixInc => "Viaanix, Inc.",
+            Self::Tactrix => "Tactrix",
+            Self::MivElectronicsLtd => "MIV ELECTRONICS, LTD",
+            Self::GlutzAg => "Glutz AG",
+            Self::IdentitaInc => "Identita Inc.",
+            Self::RainMakerSolutionsInc => "RainMaker Solutions, Inc.",
+            Self::AvetosDesignLlc => "Avetos Design LLC",
+            Self::NobestInc => "Nobest Inc",
+            Self::CelebritiesManagementPrivateLimited => "Celebrities Management Private Limited",
+            Self::GopodGroupHoldingLimited => "Gopod Group Holding Limited",
+            Self::AllgonAb => "Allgon AB",
+            Self::TeleRadioILysekilAb => "Tele-Radio i Lysekil AB",
+            Self::Brudden => "Brudden",
+            Self::SkeweredFencingLlc => "Skewered Fencing, LLC",
+            Self::OpenTechAllianceInc => "OpenTech Alliance, Inc.",
+            Self::MercuryMarineADivisionOfBrunswickCorporation => {
+                "Mercury Marine, a division of Brunswick Corporation"
+            }
+            Self::TigerLightInc => "TigerLight, Inc.",
+            Self::TymphanyHkLtd => "Tymphany HK Ltd",
+            Self::SpRiNtusGmbH => "SPRiNTUS GmbH",
+            Self::ChevalierTechLimited => "CHEVALIER TECH LIMITED",
+            Self::GuangdongHengqinXingtongTechnologyCoLtd => {
+                "Guangdong Hengqin Xingtong Technology Co.,ltd."
+            }
+            Self::IqnexxtSolutionsGmbH => "IQNEXXT Solutions GmbH",
+            Self::SzrDevUg => "SZR-Dev UG",
+            Self::ArchonControlsLlc => "Archon Controls LLC",
+            Self::JiangsuXinTongdaElectricTechnologyCoLtd => {
+                "Jiangsu XinTongda Electric Technology Co.,Ltd."
+            }
+            Self::FinalInc => "final Inc.",
+            Self::Circular => "Circular",
+            Self::VivagoOy => "Vivago Oy",
+            Self::NeptuneFirstOu => "Neptune First OU",
+            Self::HongKongCommunicationsCompanyLimited => {
+                "HONG KONG COMMUNICATIONS COMPANY LIMITED"
+            }
+            Self::MobileTechInc => "MOBILE TECH, INC.",
+            Self::GuangdongNanguangPhotoAndVideoSystemsCoLtd => {
+                "Guangdong Nanguang Photo&Video Systems Co., Ltd."
+            }
+            Self::LeTouchShenzhenElectronicsCoLtd => "Le Touch (Shenzhen) Electronics Co., Ltd.",
+            Self::RockyRadiosLlc => "Rocky Radios LLC",
+            Self::AdventuresOfThePersistentlyImpairedAndOtherTalesLimited => {
+                "Adventures of the Persistently Impaired (and other tales) Limited"
+            }
+            Self::TorAiLimited => "TOR.AI LIMITED",
+            Self::EnablewearLlc => "ENABLEWEAR LLC",
+            Self::PowerstickCom => "Powerstick.com",
+            Self::OpConnectInc => "OpConnect, Inc.",
+            Self::IMLabInc => "I.M.LAB Inc",
+            Self::FevosLimited => "FEVOS LIMITED",
+            Self::RighInc => "RIGH, INC.",
+            Self::ShenzhenGoodocomInformationTechnologyCoLtd => {
+                "Shenzhen Goodocom Information Technology Co., Ltd."
+            }
+            Self::HyenaInc => "Hyena Inc.",
+            Self::MegatronixBeijingTechnologyCoLtd => "Megatronix (Beijing) Technology Co., Ltd",
+            Self::EarTexLtd => "EarTex Ltd",
+            Self::NextDevicesLtda => "NEXT DEVICES LTDA",
+            Self::ShenzhenBaseusTechnologyCoLtd => "Shenzhen Baseus Technology Co., Ltd.",
+            Self::DaikinIndustriesLtd => "Daikin Industries, LTD",
+            Self::HuiTongIntelligenceCompanyLimited => "HuiTong intelligence Company Limited",
+            Self::LastLockInc => "LAST LOCK INC.",
+            Self::GokiPtyLtd => "GOKI PTY LTD",
+            Self::QueclinkWirelessSolutionsCoLtd => "Queclink Wireless Solutions Co., Ltd.",
+            Self::AntGroupCoLtd => "Ant Group Co., Ltd.",
+            Self::Ruptela => "Ruptela",
+            Self::ShaperToolsInc => "SHAPER TOOLS, INC.",
+            Self::LTHElectronicsLimited => "L.T.H. Electronics Limited",
+            Self::AmimonLtd => "Amimon Ltd.",
+            Self::WuhuHongjingElectronicCoLtd => "Wuhu Hongjing Electronic Co.,Ltd",
+            Self::OmniWaveMicroelectronicsShanghaiCoLtd => {
+                "OmniWave Microelectronics Shanghai Co., Ltd"
+            }
+            Self::LoeweTechnologyGmbH => "Loewe Technology GmbH",
+            Self::UrbanArmorGearLlc => "Urban Armor Gear, LLC",
+            Self::AltinaInc => "Altina Inc.",
+            Self::IneproMeteringBV => "INEPRO Metering B.V.",
+            Self::NewCosmosElectricCoLtd => "New Cosmos Electric Co., Ltd.",
+            Self::ReliefTechnologiesAs => "Relief Technologies AS",
+            Self::Minirig => "MINIRIG",
+            Self::AquanaLlc => "Aquana, LLC",
+            Self::DyacoInternationalInc => "Dyaco International Inc.",
+            Self::ZhejiangDesmanIntelligentTechnologyCoLtd => {
+                "Zhejiang Desman Intelligent Technology Co., Ltd."
+            }
+            Self::EBetGamingSytemsPtyLimited => "eBet Gaming Sytems Pty Limited",
+            Self::QscLlc => "QSC, LLC",
+            Self::BrookseeInc => "Brooksee, Inc.",
+            Self::LuxsharePrecisionIndustryCoLtd => "Luxshare Precision Industry Co., Ltd.",
+            Self::PudseyDiamondEngineeringLimited => "PUDSEY DIAMOND ENGINEERING LIMITED",
+            Self::NittoDenkoCorporation => "Nitto Denko Corporation",
+            Self::DeoneShanghaiCommunicationAndTechnologyCoLtd => {
+                "Deone (Shanghai) Communication & Technology Co., Ltd"
+            }
+            Self::KarlunaMuhendislikSanayiVeTicaretAnonimSirketi => {
+                "KARLUNA MUHENDISLIK SANAYI VE TICARET ANONIM SIRKETI"
+            }
+            Self::HosidenBessonLimited => "Hosiden Besson Limited",
+            Self::Sounduct => "SOUNDUCT",
+            Self::FiloSrl => "Filo Srl",
+            Self::Quantatec => "QUANTATEC",
+            Self::InnoVisionMedicalTechnologiesLlc => "InnoVision Medical Technologies, LLC",
+            Self::ZOneTechnologyCoLtd => "Z-ONE Technology Co., Ltd.",
+            Self::AsustekComputerInc => "Asustek Computer Inc.",
+            Self::WkdLabsLtd => "WKD Labs Ltd",
+            Self::WiserDevicesLlc => "Wiser Devices, LLC",
+            Self::Vanbox => "VANBOX",
+            Self::WalmartInc => "Walmart Inc.",
+            Self::Viselabs => "Viselabs",
+            Self::SwiftIotTechShenzhenCoLtd => "Swift IOT Tech (Shenzhen) Co., LTD.",
+            Self::OfiveLimited => "OFIVE LIMITED",
+            Self::IresInfrarotEnergieSystemeGmbH => "IRES Infrarot Energie Systeme GmbH",
+            Self::SlocGmbH => "SLOC GmbH",
+            Self::CesysGesellschaftFurAngewandteMikroelektronikMbH => {
+                "CESYS Gesellschaft für angewandte Mikroelektronik mbH"
+            }
+            Self::CousinsAndSearsLlc => "Cousins and Sears LLC",
+            Self::SnappwishLlc => "SNAPPWISH LLC",
+            Self::VermisSoftwareSolutionsLlc => "Vermis, software solutions llc",
+            Self::CrescentNv => "Crescent NV",
+            Self::PacificIndustrialCoLtd => "PACIFIC INDUSTRIAL CO., LTD.",
+            Self::AlphaThetaCorporation => "AlphaTheta Corporation",
+            Self::PrimaxElectronicsLtd => "Primax Electronics Ltd.",
+            Self::Onwi => "ONWI",
+            Self::NihonKohdenCorporation => "NIHON KOHDEN CORPORATION",
+            Self::EcarxHubeiTechCoLtd => "ECARX (Hubei) Tech Co.,Ltd.",
+            Self::_93137263QuebecInc => "9313-7263 Quebec inc.",
+            Self::JeElectronicAS => "JE electronic a/s",
+            Self::HuizhouForyouGeneralElectronicsCoLtd => {
+                "Huizhou Foryou General Electronics Co., Ltd."
+            }
+            Self::FlipperDevicesInc => "Flipper Devices Inc.",
+            Self::PatchRxInc => "PatchRx, Inc.",
+            Self::NextSenseInc => "NextSense, Inc.",
+            Self::LihjoenSpeedMeterCoLtd => "LIHJOEN SPEED METER CO., LTD.",
+            Self::HangzhouHikvisionDigitalTechnologyCoLtd => {
+                "Hangzhou Hikvision Digital Technology Co., Ltd."
+            }
+            Self::AvedisZildjianCo => "Avedis Zildjian Co.",
+            Self::MsKajak7UgLimitedLiability => "MS kajak7 UG (limited liability)",
+            Self::HitoInc => "HITO INC",
+            Self::Fogo => "FOGO",
+            Self::CflabTeknolojiTicaretLimitedSirketi => "CFLAB TEKNOLOJI TICARET LIMITED SIRKETI",
+            Self::BleconLtd => "Blecon Ltd",
+            Self::_95125837QuebecInc => "9512-5837 QUEBEC INC.",
+            Self::CapteBV => "Capte B.V.",
+            Self::ShenzhenDigitechCoLtd => "SHENZHEN DIGITECH CO., LTD",
+            Self::TimeLocationSystemsAs => "Time Location Systems AS",
+            Self::SensovoGmbH => "Sensovo GmbH",
+            Self::HiveZoxInternationalSa => "Hive-Zox International SA",
+            Self::HangzhouMicroimageSoftwareCoLtd => "Hangzhou Microimage Software Co.,Ltd.",
+            Self::AdHocElectronicsLlc => "Ad Hoc Electronics, llc.",
+            Self::XiamenRuiYiDaElectronicTechnologyCoLtd => {
+                "Xiamen RUI YI Da Electronic Technology Co.,Ltd"
+            }
+            Self::EasternPartnerLimited => "Eastern Partner Limited",
+            Self::HeilongjiangTianyouweiElectronicsCoLtd => {
+                "Heilongjiang Tianyouwei Electronics Co.,Ltd."
+            }
+            Self::Asystom => "ASYSTOM",
+            Self::CleverLoggerTechnologiesPtyLimited => "CLEVER LOGGER TECHNOLOGIES PTY LIMITED",
+            Self::WanzlGmbHAndCoKGaA => "Wanzl GmbH & Co. KGaA",
+            Self::EkoHealthInc => "Eko Health, Inc.",
+            Self::SenseWorksTecnologiaLtda => "SenseWorks Tecnologia Ltda.",
+            Self::AlogicCorporationPtyLtd => "ALOGIC CORPORATION PTY LTD",
+            Self::FrontActCoLtd => "FrontAct Co., Ltd.",
+            Self::YuanfengTechnologyCoLtd => "Yuanfeng Technology Co., Ltd.",
+            Self::SoundingAudioIndustrialLtd => "Sounding Audio Industrial Ltd.",
+            Self::PrintInternationalLimited => "PRINT INTERNATIONAL LIMITED",
+            Self::EvolutiveSystemsSl => "Evolutive Systems SL",
+            Self::HeinrichKoppGmbH => "Heinrich Kopp GmbH",
+            Self::PinpointGmbH => "Pinpoint GmbH",
+            Self::IodyneLlc => "iodyne, LLC",
+            Self::SurepulseMedicalLimited => "SUREPULSE MEDICAL LIMITED",
+            Self::DoroAb => "Doro AB",
+            Self::ShenzhenEMeetTechnologyCoLtd => "Shenzhen eMeet technology Co.,Ltd",
+            Self::ProsysDevLimited => "PROSYS DEV LIMITED",
+            Self::WuhuMengboTechnologyCoLtd => "Wuhu Mengbo Technology Co., Ltd.",
+            Self::ShenzhenSoundsoulInformationTechnologyCoLtd => {
+                "SHENZHEN SOUNDSOUL INFORMATION TECHNOLOGY CO.,LTD"
+            }
+            Self::WaveRfCorp => "WaveRF, Corp.",
+            Self::HaptechInc => "Haptech, Inc.",
+            Self::BhTechnologies => "BH Technologies",
+            Self::SojiElectronicsJointStockCompany => "SOJI ELECTRONICS JOINT STOCK COMPANY",
+            Self::BeidouIntelligentConnectedVehicleTechnologyCoLtd => {
+                "Beidou Intelligent Connected Vehicle Technology Co., Ltd."
+            }
+            Self::ShenzhenMatchesIoTTechnologyCoLtd => "Shenzhen Matches IoT Technology Co., Ltd.",
+            Self::BelusunTechnologyLtd => "Belusun Technology Ltd.",
+            Self::ChengduCsctMicroelectronicsCoLtd => "Chengdu CSCT Microelectronics Co., Ltd.",
+            Self::HangzhouZhaotongMicroelectronicsCoLtd => {
+                "Hangzhou Zhaotong Microelectronics Co., Ltd."
+            }
+            Self::MutrackCoLtd => "Mutrack Co., Ltd",
+            Self::DeltaFaucetCompany => "Delta Faucet Company",
+            Self::ReekonToolsInc => "REEKON TOOLS INC.",
+            Self::HDropTechnologiesInc => "hDrop Technologies Inc.",
+            Self::WeberStephenProductsLlc => "Weber-Stephen Products LLC",
+            Self::IFlytekSuzhouTechnologyCoLtd => "iFLYTEK (Suzhou) Technology Co., Ltd.",
+            Self::WoncanHongKongLimited => "Woncan (Hong Kong) Limited",
+            Self::SingSunTechnologyInternationalLimited => {
+                "SING SUN TECHNOLOGY (INTERNATIONAL) LIMITED"
+            }
+            Self::SafetySwimLlc => "Safety Swim LLC",
+            Self::FlextronicGmbH => "Flextronic GmbH",
+            Self::AtegenosPharmaceuticalsInc => "ATEGENOS PHARMACEUTICALS INC",
+            Self::FitzInc => "Fitz Inc.",
+            Self::KathreinSolutionsGmbH => "Kathrein Solutions GmbH",
+            Self::GeneralLaserGmbH => "General Laser GmbH",
+            Self::VivintInc => "Vivint, Inc.",
+            Self::DendroTechnologiesInc => "Dendro Technologies, Inc.",
+            Self::DexatekTechnologyLtd => "DEXATEK Technology LTD",
+            Self::EhongTechnologyCoLtd => "Ehong Technology Co.,Ltd",
+            Self::EmbeintInc => "EMBEINT INC",
+            Self::ShenzhenModsemiCoLtd => "Shenzhen MODSEMI Co., Ltd",
+            Self::TamadicCoLtd => "TAMADIC Co., Ltd.",
+            Self::OutshinyIndiaPrivateLimited => "Outshiny India Private Limited",
+            Self::KnogPtyLtd => "KNOG PTY. LTD.",
+            Self::ShenzhenLunciTechnologyCoLtd => "Shenzhen Lunci Technology Co., Ltd",
+            Self::ShenzhenDnsIndustriesCoLtd => "SHENZHEN DNS INDUSTRIES CO., LTD.",
+            Self::TozoaLlc => "Tozoa LLC",
+            Self::AuthorAlarmRazvojInProdajaAvtomobilskihSistemovProtiKrajiDOO => {
+                "AUTHOR-ALARM, razvoj in prodaja avtomobilskih sistemov proti kraji, d.o.o."
+            }
+            Self::KagaFeiCoLtd => "KAGA FEI Co., Ltd.",
+            Self::MinebeaIntecGmbH => "Minebea Intec GmbH",
+            Self::SchellGmbHAndCoKg => "SCHELL GmbH & Co. KG",
+            Self::GranchipIoTTechnologyGuangzhouCoLtd => {
+                "Granchip IoT Technology (Guangzhou) Co.,Ltd"
+            }
+            Self::XiantTechnologiesInc => "Xiant Technologies, Inc.",
+            Self::ModularMedicalInc => "MODULAR MEDICAL, INC.",
+            Self::Beepings => "BEEPINGS",
+            Self::KoqoonGmbHAndCoKg => "KOQOON GmbH & Co.KG",
+            Self::GlobalSatelliteEngineering => "Global Satellite Engineering",
+            Self::SitecomEuropeBV => "Sitecom Europe B.V.",
+            Self::OrangeMicroLimited => "OrangeMicro Limited",
+            Self::Esnah => "ESNAH",
+            Self::KubuSmartLimited => "KUBU SMART LIMITED",
+            Self::NovafonElectromedicalDevicesLimitedLiabilityCompany => {
+                "NOVAFON - Electromedical devices limited liability company"
+            }
+            Self::AstraLedAg => "Astra LED AG",
+            Self::TrotecGmbH => "Trotec GmbH",
+            Self::GeophLlc => "GEOPH, LLC",
+            Self::AptenerMechatronicsPrivateLimited => "Aptener Mechatronics Private Limited",
+            Self::FarmunitedGmbH => "farmunited GmbH",
+            Self::EtoGruppeTechnologiesGmbH => "ETO GRUPPE TECHNOLOGIES GmbH",
+            Self::MorningstarFxPteLtd => "MORNINGSTAR FX PTE. LTD.",
+            Self::Levita => "Levita",
+            Self::Dhl => "DHL",
+            Self::HangzhouNationalChipScienceAndTechnologyCoLtd => {
+                "Hangzhou NationalChip Science & Technology Co.,Ltd"
+            }
+            Self::GearbacTechnologiesInc => "GEARBAC TECHNOLOGIES INC.",
+            Self::CirrusResearchPlc => "Cirrus Research plc",
+            Self::NachiFujikoshiCorp => "NACHI-FUJIKOSHI CORP.",
+            Self::ShenzhenJahportElectronicTechnologyCoLtd => {
+                "Shenzhen Jahport Electronic Technology Co., Ltd."
+            }
+            Self::HwmWaterLimited => "HWM-Water Limited",
+            Self::HellaGmbHAndCoKGaA => "HELLA GmbH & Co. KGaA",
+            Self::Maatel => "MAATEL",
+            Self::FelionTechnologiesCompanyLimited => "Felion Technologies Company Limited",
+            Self::NexisLinkTechnologyCoLtd => "Nexis Link Technology Co., Ltd.",
+            Self::VeoTechnologiesApS => "Veo Technologies ApS",
+            Self::CompanyDeepLtd => "CompanyDeep Ltd",
+            Self::ShenzhenChuangyuanDigitalTechnologyCoLtd => {
+                "Shenzhen Chuangyuan Digital Technology Co., Ltd"
+            }
+            Self::MorningstarCorporation => "Morningstar Corporation",
+            Self::SafeguardEquipmentInc => "SAFEGUARD EQUIPMENT, INC.",
+            Self::DjupAb => "Djup AB",
+            Self::FranklinControlSystems => "Franklin Control Systems",
+            Self::ShenzhenReflyingElectronicCoLtd => "SHENZHEN REFLYING ELECTRONIC CO., LTD",
+            Self::Whirlpool => "Whirlpool",
+            Self::TeleSystemCommunicationsPteLtd => "TELE System Communications Pte. Ltd.",
+            Self::InvisalertSolutionsInc => "Invisalert Solutions, Inc.",
+            Self::HexagonAuraRealityAg => "Hexagon Aura Reality AG",
+            Self::TitumAudioInc => "TITUM AUDIO, INC.",
+            Self::Linktop => "linktop",
+            Self::ItaltractorItmSPA => "ITALTRACTOR ITM S.P.A.",
+            Self::Efento => "Efento",
+            Self::ShenzhenEbelongTechnologyCoLtd => "Shenzhen EBELONG Technology Co., Ltd.",
+            Self::InventronicsGmbH => "Inventronics GmbH",
+            Self::AirwalletApS => "Airwallet ApS",
+            Self::NovofermTormaticGmbH => "Novoferm tormatic GmbH",
+            Self::GeneracCorporation => "Generac Corporation",
+            Self::PixelaCorporation => "PIXELA CORPORATION",
+            Self::HpTuners => "HP Tuners",
+            Self::AirgraftInc => "Airgraft Inc.",
+            Self::KiwiKiGmbH => "KIWI.KI GmbH",
+            Self::FenSystemsLtd => "Fen Systems Ltd.",
+            Self::SickAg => "SICK AG",
+            Self::MmlUsInc => "MML US, Inc",
+            Self::ImpulseWellnessLlc => "Impulse Wellness LLC",
+            Self::CearInc => "Cear, Inc.",
+            Self::SkytechCreationsLimited => "Skytech Creations Limited",
+            Self::BoxyzInc => "Boxyz, Inc.",
+            Self::YeasoundXiamenHearingTechnologyCoLtd => {
+                "Yeasound (Xiamen) Hearing Technology Co., Ltd"
+            }
+            Self::CaireInc => "Caire Inc.",
+            Self::EFJohnsonCompany => "E.F. Johnson Company",
+            Self::ZhejiangHuanfuTechnologyCoLtd => "Zhejiang Huanfu Technology Co., LTD",
+            Self::NeokohmSistemasEletronicosLtda => "NEOKOHM SISTEMAS ELETRONICOS LTDA",
+            Self::HunterIndustriesIncorporated => "Hunter Industries Incorporated",
+            Self::ShroolyInc => "Shrooly Inc",
+            Self::HagerEnergyGmbH => "HagerEnergy GmbH",
+            Self::TachikawaCorporation => "TACHIKAWA CORPORATION",
+            Self::BeamexOyAb => "Beamex Oy Ab",
+            Self::LaasApS => "LAAS ApS",
+            Self::CanonElectronicsInc => "Canon Electronics Inc.",
+            Self::OptivoltLabsInc => "Optivolt Labs, Inc.",
+            Self::RfElectronicsLimited => "RF Electronics Limited",
+            Self::UltimeaTechnologyShenzhenLimited => "Ultimea Technology (Shenzhen) Limited",
+            Self::SoftwareDevelopmentLlc => "Software Development, LLC",
+            Self::SimplyEmbeddedInc => "Simply Embedded Inc.",
+            Self::NanohexCorp => "Nanohex Corp",
+            Self::GeoceneInc => "Geocene Inc.",
+            Self::QuectelWirelessSolutionsCoLtd => "Quectel Wireless Solutions Co., Ltd.",
+            Self::RockwellAutomationInc => "ROCKWELL AUTOMATION, INC.",
+            Self::SewEurodriveGmbHAndCoKg => "SEW-EURODRIVE GmbH & Co KG",
+            Self::TestoSeAndCoKGaA => "Testo SE & Co. KGaA",
+            Self::AtlanticSocieteFrancaiseDeDeveloppementThermique => {
+                "ATLANTIC SOCIETE FRANCAISE DE DEVELOPPEMENT THERMIQUE"
+            }
+            Self::Velco => "VELCO",
+            Self::BeyerdynamicGmbHAndCoKg => "Beyerdynamic GmbH & Co. KG",
+            Self::GravaaBV => "Gravaa B.V.",
+            Self::Konova => "Konova",
+            Self::DaihatsuMotorCoLtd => "Daihatsu Motor Co., Ltd.",
+            Self::TaikoAudioBV => "Taiko Audio B.V.",
+            Self::BeiJingSmartChipMicroelectronicsTechnologyCoLtd => {
+                "BeiJing SmartChip Microelectronics Technology Co.,Ltd"
+            }
+            Self::MindMazeSa => "MindMaze SA",
+            Self::XiamenIntretechInc => "Xiamen Intretech Inc.",
+            Self::ViviwareJapanInc => "VIVIWARE JAPAN, Inc.",
+            Self::MitachiCoLtd => "MITACHI CO.,LTD.",
+            Self::DualNetworksSa => "DualNetworks SA",
+            Self::IFocusCoLtd => "i-focus Co.,Ltd",
+            Self::IndistinguishableFromMagicInc => "Indistinguishable From Magic, Inc.",
+            Self::AnumeSRO => "ANUME s.r.o.",
+            Self::IotaBiosciencesInc => "iota Biosciences, Inc.",
+            Self::EarfunTechnologyHkLimited => "Earfun Technology (HK) Limited",
+            Self::KiterasInc => "Kiteras Inc.",
+            Self::Kindhome => "Kindhome",
+            Self::ClosedJointStockCompanyNvpBolid => "Closed Joint Stock Company NVP BOLID",
+            Self::LookCycleInternational => "Look Cycle International",
+            Self::DynamoxSA => "DYNAMOX S/A",
+            Self::AmbientIoTPtyLtd => "Ambient IoT Pty Ltd",
+            Self::CranePaymentInnovationsInc => "Crane Payment Innovations, Inc.",
+            Self::HelgeKaiserGmbH => "Helge Kaiser GmbH",
+            Self::AirAromaInternationalPtyLtd => "AIR AROMA INTERNATIONAL PTY LTD",
+            Self::StatusAudioLlc => "Status Audio LLC",
+            Self::BlackBoxNetworkServicesIndiaPrivateLimited => {
+                "BLACK BOX NETWORK SERVICES INDIA PRIVATE LIMITED"
+            }
+            Self::HendricksonUsaLLC => "Hendrickson USA , L.L.C",
+            Self::MolnlyckeHealthCareAb => "Molnlycke Health Care AB",
+            Self::Southco => "Southco",
+            Self::SkfFrance => "SKF France",
+            Self::MebsterSRO => "MEBSTER s.r.o.",
+            Self::FIPFormaturaIniezionePolimeriSPA => {
+                "F.I.P. FORMATURA INIEZIONE POLIMERI - S.P.A."
+            }
+            Self::SmartProductsConnectionSA => "Smart Products Connection, S.A.",
+            Self::SiChuanHommeIntelligentTechnologyCoLtd => {
+                "SiChuan Homme Intelligent Technology co.,Ltd."
+            }
+            Self::PellaCorp => "Pella Corp",
+            Self::StoggerBV => "Stogger B.V.",
+            Self::PisonTechnologyInc => "Pison Technology, Inc.",
+            Self::AxisCommunicationsAb => "Axis Communications AB",
+            Self::GunneboAktiebolag => "Gunnebo Aktiebolag",
+            Self::HyupsungMachineryElectricCoLtd => "HYUPSUNG MACHINERY ELECTRIC CO., LTD.",
+            Self::IfmElectronicGmbh => "ifm electronic gmbh",
+            Self::NanjingXinxiangyuanMicroelectronicsCoLtd => {
+                "Nanjing Xinxiangyuan Microelectronics Co., Ltd."
+            }
+            Self::WellangCoLtd => "Wellang.Co,.Ltd",
+            Self::NoClimbProductsLtd => "NO CLIMB PRODUCTS LTD",
+            Self::IsekiFranceSAS => "ISEKI FRANCE S.A.S",
+            Self::LuxotticaGroupSPA => "Luxottica Group S.p.A",
+            Self::DivanTradingCoLtd => "DIVAN TRADING CO., LTD.",
+            Self::GenetusInc => "Genetus inc.",
+            Self::NingboFotileKitchenwareCoLtd => "NINGBO FOTILE KITCHENWARE CO., LTD.",
+            Self::MovanoInc => "Movano Inc.",
+            Self::NikatSolutionsPrivateLimited => "NIKAT SOLUTIONS PRIVATE LIMITED",
+            Self::OptecLlc => "Optec, LLC",
+            Self::IotGizmoCorporation => "IotGizmo Corporation",
+            Self::SoundwaveHearingLlc => "Soundwave Hearing, LLC",
+            Self::RockpileSolutionsLlc => "Rockpile Solutions, LLC",
+            Self::RefrigeratedTransportElectronicsInc => "Refrigerated Transport Electronics, Inc.",
+            Self::VemconGmbH => "Vemcon GmbH",
+            Self::ShenzhenDokeElectronicCoLtd => "Shenzhen DOKE Electronic Co., Ltd",
+            Self::ThalesSimulationAndTrainingAg => "Thales Simulation & Training AG",
+            Self::OdeonInc => "Odeon, Inc.",
+            Self::ExMakhinaInc => "Ex Makhina Inc.",
+            Self::GosuncnTechnologyGroupCoLtd => "Gosuncn Technology Group Co., Ltd.",
+            Self::TektroTechnologyCorporation => "TEKTRO TECHNOLOGY CORPORATION",
+            Self::CpacSystemsAb => "CPAC Systems AB",
+            Self::SignalFireTelemetryInc => "SignalFire Telemetry, Inc.",
+            Self::VogelsProductsBV => "Vogels Products B.V.",
+            Self::LuminoahInc => "LUMINOAH, INC.",
+            Self::BHapticsInc => "bHaptics Inc.",
+            Self::SironaDentalSystemsGmbH => "SIRONA Dental Systems GmbH",
+            Self::LoneStarMarinePtyLtd => "Lone Star Marine Pty Ltd",
+            Self::FrostSolutionsLlc => "Frost Solutions, LLC",
+            Self::SystemicGamesLlc => "Systemic Games, LLC",
+            Self::CycLock => "CycLock",
+            Self::ZereneInc => "Zerene Inc.",
+            Self::XihaoIntelligengtTechnologyCoLtd => "XIHAO INTELLIGENGT TECHNOLOGY CO., LTD",
+            Self::UniversidadPolitecnicaDeMadrid => "Universidad Politecnica de Madrid",
+            Self::ZilliotTechnologiesPrivateLimited => "ZILLIOT TECHNOLOGIES PRIVATE LIMITED",
+            Self::MicropowerGroupAb => "Micropower Group AB",
+            Self::BadgerMeter => "Badger Meter",
+            Self::SynchronInc => "SYNCHRON, INC.",
+            Self::LaxmiTherapeuticDevicesInc => "Laxmi Therapeutic Devices, Inc.",
+            Self::DeltaDevelopmentTeamInc => "Delta Development Team, Inc",
+            Self::AdvancedElectronicApplicationsInc => "Advanced Electronic Applications, Inc",
+            Self::CoolerProLlc => "Cooler Pro, LLC",
+            Self::SilSystemIntegrationLaboratoryGmbH => "SIL System Integration Laboratory GmbH",
+            Self::SensoryxAg => "Sensoryx AG",
+            Self::PhysioLogicDevicesInc => "PhysioLogic Devices, Inc.",
+            Self::MiyakawaElectricWorksLtd => "MIYAKAWA ELECTRIC WORKS LTD.",
+            Self::FujitsuComponentLimited => "FUJITSU COMPONENT LIMITED",
+            Self::Velocitux => "velocitux",
+            Self::BurkertWerkeGmbHAndCoKg => "Burkert Werke GmbH & Co. KG",
+            Self::SystemEliteHoldingsGroupLimited => "System Elite Holdings Group Limited",
+            Self::JapanDisplayInc => "Japan Display Inc.",
+            Self::GreeElectricAppliancesIncOfZhuhai => "GREE Electric Appliances, Inc. of Zhuhai",
+            Self::CedarwareCorp => "Cedarware, Corp.",
+            Self::CennoxGroupLimited => "Cennox Group Limited",
+            Self::ScienterraLimited => "SCIENTERRA LIMITED",
+            Self::SynkopiInc => "Synkopi, Inc.",
+            Self::FestinaLotusSa => "FESTINA LOTUS SA",
+            Self::Electronics4AllInc => "Electronics4All Inc.",
+            Self::LimboidLlc => "LIMBOID LLC",
+            Self::RachioInc => "RACHIO, INC.",
+            Self::MaturixApS => "Maturix ApS",
+            Self::CGAirSystemesInc => "C.G. Air Systemes Inc.",
+            Self::BiolibertyLtd => "Bioliberty Ltd",
+            Self::AkixSRL => "Akix S.r.l.",
+            Self::NationsTechnologiesInc => "Nations Technologies Inc.",
+            Self::Spark => "Spark",
+            Self::MerryElectronicsSPteLtd => "Merry Electronics (S) Pte Ltd",
+            Self::MerryElectronicsCoLtd => "MERRY ELECTRONICS CO., LTD.",
+            Self::SpartekSystemsInc => "Spartek Systems Inc.",
+            Self::GreatDaneLlc => "Great Dane LLC",
+            Self::JvcKenwoodCorporation => "JVC KENWOOD Corporation",
+            Self::TimebirdsAustraliaPtyLtd => "Timebirds Australia Pty Ltd",
+            Self::PetVoiceCoLtd => "PetVoice Co., Ltd.",
+            Self::CEdSchulteGmbHZylinderschlossfabrik => "C.Ed. Schulte GmbH Zylinderschlossfabrik",
+            Self::PlanmecaOy => "Planmeca Oy",
+            Self::ResearchProductsCorporation => "Research Products Corporation",
+            Self::CateyeCoLtd => "CATEYE Co., Ltd.",
+            Self::LeicaGeosystemsAg => "Leica Geosystems AG",
+            Self::DatalogicUsaInc => "Datalogic USA, Inc.",
+            Self::DatalogicSRL => "Datalogic S.r.l.",
+            Self::DoubleOCoLtd => "doubleO Co., Ltd.",
+            Self::EnergyTechnologyAndControlLimited => "Energy Technology and Control Limited",
+            Self::BartecAutoIdLtd => "Bartec Auto Id Ltd",
+            Self::MakuSafeCorp => "MakuSafe Corp",
+            Self::RockyMountainAtvMcJakeWilson => "Rocky Mountain ATV/MC Jake Wilson",
+            Self::Keepen => "KEEPEN",
+            Self::SparkparkAs => "Sparkpark AS",
+            Self::ErgodrivenInc => "Ergodriven Inc",
+            Self::ThuleGroupAb => "Thule Group AB",
+            Self::WuhanWoncanConstructionTechnologiesCoLtd => {
+                "Wuhan Woncan Construction Technologies Co., Ltd."
+            }
+            Self::ElectronXDesign => "ElectronX design",
+            Self::TyromotionGmbH => "Tyromotion GmbH",
+            Self::ProtectAnimalsWithSatellitesLlc => "Protect Animals With Satellites LLC",
+            Self::TamblueOy => "Tamblue Oy",
+            Self::CoreSensingGmbH => "core sensing GmbH",
+            Self::TvsMotorCompanyLtd => "TVS Motor Company Ltd.",
+            Self::OjElectronicsAS => "OJ Electronics A/S",
+            Self::BosBalanceOfStorageSystemsAg => "BOS Balance of Storage Systems AG",
+            Self::SoluxPtyLtd => "SOLUX PTY LTD",
+            Self::RadioSound => "Radio Sound",
+            Self::BestSensAg => "BestSens AG",
+            Self::Midmark => "Midmark",
+            Self::ThotakaTekhnologiesIndiaPrivateLimited => {
+                "THOTAKA TEKHNOLOGIES INDIA PRIVATE LIMITED"
+            }
+            Self::PogsBV => "POGS B.V.",
+            Self::MadgeTechInc => "MadgeTech, Inc",
+            Self::CvNuriTeknik => "CV. NURI TEKNIK",
+            Self::PacificCoastFisheryServices2003Inc => {
+                "Pacific Coast Fishery Services (2003) Inc."
+            }
+            Self::ShenzhenTingtingTechnologyCoLtd => "Shenzhen Tingting Technology Co. LTD",
+            Self::HaywardIndustriesInc => "HAYWARD INDUSTRIES, INC.",
+            Self::PeagLlcDbaJLabAudio => "PEAG, LLC dba JLab Audio",
+            Self::DongguanYougoElectronicsCoLtd => "Dongguan Yougo Electronics Co.,Ltd.",
+            Self::TagHeuerSa => "TAG HEUER SA",
+            Self::McWongInternationalInc => "McWong International, Inc.",
+            Self::AminaDistributionAs => "Amina Distribution AS",
+            Self::OffHighwayPowertrainServicesGermanyGmbH => {
+                "Off-Highway Powertrain Services Germany GmbH"
+            }
+            Self::TaiwanFuhsing => "Taiwan Fuhsing",
+            Self::CorventMedicalInc => "CORVENT MEDICAL, INC.",
+            Self::RegalBeloitAmericaInc => "Regal Beloit America, Inc.",
+            Self::VodalogicPtyLtd => "VODALOGIC PTY LTD",
+            Self::ShenzhenChenyunElectronicsCoLtd => "SHENZHEN CHENYUN ELECTRONICS  CO., LTD",
+            Self::ResponseTechnologiesLtd => "RESPONSE TECHNOLOGIES, LTD.",
+            Self::AlifSemiconductorInc => "Alif Semiconductor, Inc.",
+            Self::YpsomedAg => "Ypsomed AG",
+            Self::CircusWorldDisplaysLimited => "Circus World Displays Limited",
+            Self::WolfSteelLtd => "Wolf Steel ltd",
+            Self::MinamiAcousticsLimited => "Minami acoustics Limited",
+            Self::SiaMeshGroup => "SIA Mesh Group",
+            Self::MaztechIndustriesLlc => "Maztech Industries, LLC",
+            Self::HhoHangzhouDigitalTechnologyCoLtd => {
+                "HHO (Hangzhou) Digital Technology Co., Ltd."
+            }
+            Self::NumaProductsLlc => "Numa Products, LLC",
+            Self::ReoqooIoTTechnologyCoLtd => "Reoqoo IoT Technology Co., Ltd.",
+            Self::TechSwipe => "TechSwipe",
+            Self::EqomSscBV => "EQOM SSC B.V.",
+            Self::ImagineMarketingLimited => "Imagine Marketing Limited",
+            Self::MooreSiliconSemiconductorTechnologyShanghaiCoLtd => {
+                "MooreSilicon Semiconductor Technology (Shanghai) Co., LTD."
+            }
+            Self::ShenzhenCesiInformationTechnologyCoLtd => {
+                "Shenzhen CESI Information Technology Co., Ltd."
+            }
+            Self::SenospaceLlc => "SENOSPACE LLC",
+            Self::YanFengVisteonChongqingAutomotiveElectronicCoLtd => {
+                "YanFeng Visteon(Chongqing) Automotive Electronic Co.,Ltd"
+            }
+            Self::KordDefencePtyLtd => "Kord Defence Pty Ltd",
+            Self::NothingTechnologyLimited => "NOTHING TECHNOLOGY LIMITED",
+            Self::CyclopsMarineLtd => "Cyclops Marine Ltd",
+            Self::InnocentTechnologyCoLtd => "Innocent Technology Co., Ltd.",
+            Self::TrikThom => "TrikThom",
+            Self::SbCAndSCorp => "SB C&S Corp.",
+            Self::SerialTechnologyCorporation => "Serial Technology Corporation",
+            Self::OpenRoadSolutionsInc => "Open Road Solutions, Inc.",
+            Self::AbusAugustBremickerSoehneKommanditgesellschaft => {
+                "ABUS August Bremicker Soehne Kommanditgesellschaft"
+            }
+            Self::HmdGlobalOy => "HMD Global Oy",
+            Self::AnkerInnovationsLimited => "Anker Innovations Limited",
+            Self::CleioInc => "CLEIO Inc.",
+            Self::GarnetInstrumentsLtd => "Garnet Instruments Ltd.",
+            Self::ForwardThinkingSystemsLlc => "Forward Thinking Systems LLC.",
+            Self::PricerAb => "Pricer AB",
+            Self::TroxGmbH => "TROX GmbH",
+            Self::EmlidTechKft => "Emlid Tech Kft.",
+            Self::AmesoTechOpcPrivateLimited => "Ameso Tech (OPC) Private Limited",
+            Self::SecaGmbHAndCoKg => "seca GmbH & Co. KG",
+            Self::ShanghaiProxyNetworkTechnologyCoLtd => {
+                "Shanghai Proxy Network Technology Co., Ltd."
+            }
+            Self::CucumberLightingControlsLimited => "Cucumber Lighting Controls Limited",
+            Self::TheEelectricMacaronLlc => "THE EELECTRIC MACARON LLC",
+            Self::RacketryDOO => "Racketry, d. o. o.",
+            Self::EberspaecherClimateControlSystemsGmbH => {
+                "Eberspaecher Climate Control Systems GmbH"
+            }
+            Self::JanovaGmbH => "janova GmbH",
+            Self::ShinkawaSensorTechnologyInc => "SHINKAWA Sensor Technology, Inc.",
+            Self::RfCreations => "RF Creations",
+            Self::SwipeSenseInc => "SwipeSense, Inc.",
+            Self::Neurinnov => "NEURINNOV",
+            Self::EvidentCorporation => "Evident Corporation",
+            Self::ShenzhenOpenhearingTechCoLtd => "Shenzhen Openhearing Tech CO., LTD .",
+            Self::ShenzhenShokzCoLtd => "Shenzhen Shokz Co.,Ltd.",
+            Self::HerutuElectronicsCorporation => "HERUTU ELECTRONICS CORPORATION",
+            Self::ShenzhenPoseidonNetworkTechnologyCoLtd => {
+                "Shenzhen Poseidon Network Technology Co., Ltd"
+            }
+            Self::MievoTechnologiesPrivateLimited => "Mievo Technologies Private Limited",
+            Self::SonasInc => "Sonas, Inc.",
+            Self::VerveInfoTecPtyLtd => "Verve InfoTec Pty Ltd",
+            Self::MeggerLtd => "Megger Ltd",
+            Self::PrincessCruiseLinesLtd => "Princess Cruise Lines, Ltd.",
+            Self::MitsubishiElectricLightingCoLtd => "MITSUBISHI ELECTRIC LIGHTING CO, LTD",
+            Self::MaquetGmbH => "MAQUET GmbH",
+            Self::XsenseLtd => "XSENSE LTD",
+            Self::YamahaMotorCoLtd => "YAMAHA MOTOR CO.,LTD.",
+            Self::Bigben => "BIGBEN",
+            Self::DragonflyEnergyCorp => "Dragonfly Energy Corp.",
+            Self::EccelCorporationSas => "ECCEL CORPORATION SAS",
+            Self::RibbiotInc => "Ribbiot, INC.",
+            Self::SunstoneRtlsIpariSzolgaltatoKorlatoltFelelosseguTarsasag => {
+                "Sunstone-RTLS Ipari Szolgaltato Korlatolt Felelossegu Tarsasag"
+            }
+            Self::NttSonorityInc => "NTT sonority, Inc.",
+            Self::AlfInc => "ALF Inc.",
+            Self::VireHealthOy => "Vire Health Oy",
+            Self::MiXTelematicsInternationalPtyLtd => "MiX Telematics International (PTY) LTD",
+            Self::Deako => "Deako",
+            Self::HBHightechGmbH => "H+B Hightech GmbH",
+            Self::GemstoneLightsCanadaLtd => "Gemstone Lights Canada Ltd.",
+            Self::BaxterHealthcareCorporation => "Baxter Healthcare Corporation",
+            Self::MovesenseOy => "Movesense Oy",
+            Self::KessebohmerErgonomietechnikGmbH => "Kesseböhmer Ergonomietechnik GmbH",
+            Self::YashuSystems => "Yashu Systems",
+            Self::WescoAg => "WESCO AG",
+            Self::RadarAutomobileSalesShandongCoLtd => "Radar Automobile Sales(Shandong)Co.,Ltd.",
+            Self::TechnoconEngineeringLtd => "Technocon Engineering Ltd.",
+            Self::ToniesGmbH => "tonies GmbH",
+            Self::TMobileUsa => "T-Mobile USA",
+            Self::HeavysInc => "Heavys Inc",
+            Self::AGlobalCoLtd => "A.GLOBAL co.,Ltd.",
+            Self::AgzzxOptoelectronicsTechnologyCoLtd => {
+                "AGZZX OPTOELECTRONICS TECHNOLOGY CO., LTD"
+            }
+            Self::NextivityInc => "Nextivity Inc.",
+            Self::WeltekTechnologiesCompanyLimited => "Weltek Technologies Company Limited",
+            Self::QingdaoEastsoftCommunicationTechnologyCoLtd => {
+                "Qingdao Eastsoft Communication Technology Co.,Ltd"
+            }
+            Self::AmlogicInc => "Amlogic, Inc.",
+            Self::MaxonIndustriesInc => "MAXON INDUSTRIES, INC.",
+            Self::WatchdogSystemsLlc => "Watchdog Systems LLC",
+            Self::Nacon => "NACON",
+            Self::CarrierCorporation => "Carrier Corporation",
+            Self::CardioidTechnologiesLda => "CARDIOID - TECHNOLOGIES, LDA",
+            Self::RochesterSensorsLlc => "Rochester Sensors, LLC",
+            Self::BoomingOfThings => "BOOMING OF THINGS",
+            Self::_3ALogicsInc => "3ALogics, Inc.",
+            Self::MopekaProductsLlc => "Mopeka Products LLC",
+            Self::PtSadamayaGrahaTeknologi => "PT SADAMAYA GRAHA TEKNOLOGI",
+            Self::TriductorTechnologySuzhouInc => "Triductor Technology (Suzhou), Inc.",
+            Self::ZhuhaiSmartlinkTechnologyCoLtd => "Zhuhai Smartlink Technology Co., Ltd",
+            Self::ChargtronIotPrivateLimited => "CHARGTRON IOT PRIVATE LIMITED",
+            Self::TeacCorporation => "TEAC Corporation",
+            Self::ShenzhenGwellTimesTechnologyCoLtd => "Shenzhen Gwell Times Technology Co. , Ltd",
+            Self::EmbeddedEngineeringSolutionsLlc => "Embedded Engineering Solutions LLC",
+            Self::Yupiteru => "yupiteru",
+            Self::TrumaGeratetechnikGmbHAndCoKg => "Truma Gerätetechnik GmbH & Co. KG",
+            Self::StreetCarOrvLlc => "StreetCar ORV, LLC",
+            Self::BitGreenTechnolabzOpcPrivateLimited => {
+                "BitGreen Technolabz (OPC) Private Limited"
+            }
+            Self::ScarabSolutionsLtd => "SCARAB SOLUTIONS LTD",
+            Self::ParakeyAb => "Parakey AB",
+            Self::SensaLlc => "Sensa LLC",
+            Self::FidureCorp => "Fidure Corp.",
+            Self::SniffLogicLtd => "SNIFF LOGIC LTD",
+            Self::GilsonSas => "GILSON SAS",
+            Self::ConsorcioTrustControlNettel => "CONSORCIO TRUST CONTROL - NETTEL",
+            Self::BlitzElectricMotorsLtd => "BLITZ electric motors. LTD",
+            Self::EmerjaCorporation => "Emerja Corporation",
+            Self::TracktingSRL => "TRACKTING S.R.L.",
+            Self::DenSmartHomeBV => "DEN Smart Home B.V.",
+            Self::WakoCoLtd => "WAKO CO,.LTD",
+            Self::DormakabaHoldingAg => "dormakaba Holding AG",
+            Self::PhgPeterHengstlerGmbHCoKg => "phg Peter Hengstler GmbH + Co. KG",
+            Self::PhiatonCorporation => "Phiaton Corporation",
+            Self::NnoxxInc => "NNOXX, Inc",
+            Self::KebaEnergyAutomationGmbH => "KEBA Energy Automation GmbH",
+            Self::WuxiLinkpowerMicroelectronicsCoLtd => "Wuxi Linkpower Microelectronics Co.,Ltd",
+            Self::BlueIdGmbH => "BlueID GmbH",
+            Self::StepUpSolutionsApS => "StepUp Solutions ApS",
+            Self::MgmWirelesssHoldingsPtyLtd => "MGM WIRELESSS HOLDINGS PTY LTD",
+            Self::AlbanGiacomoSPA => "Alban Giacomo S.P.A.",
+            Self::LockswitchSdnBhd => "Lockswitch Sdn Bhd",
+            Self::CyberdyneInc => "CYBERDYNE Inc.",
+            Self::HyksoInc => "Hykso Inc.",
+            Self::UneegMedicalAS => "UNEEG medical A/S",
+            Self::RheemSalesCompanyInc => "Rheem Sales Company, Inc.",
+            Self::ZintouchBV => "Zintouch B.V.",
+            Self::HiVizLightingInc => "HiViz Lighting, Inc.",
+            Self::TacoInc => "Taco, Inc.",
+            Self::EsceaLimited => "ESCEA LIMITED",
+            Self::InnovaSRL => "INNOVA S.R.L.",
+            Self::ImostarTechnologiesInc => "Imostar Technologies Inc.",
+            Self::SharkNinjaOperatingLlc => "SharkNinja Operating LLC",
+            Self::TactileEngineeringInc => "Tactile Engineering, Inc.",
+            Self::SeekwaveTechnologyCoLtd => "Seekwave Technology Co.,ltd.",
+            Self::OrpyxMedicalTechnologiesInc => "Orpyx Medical Technologies Inc.",
+            Self::AdtranInc => "ADTRAN, Inc.",
+            Self::AtSpiroApS => "atSpiro ApS",
+            Self::TwopoundsGmbh => "twopounds gmbh",
+            Self::ValeoManagementServices => "VALEO MANAGEMENT SERVICES",
+            Self::EpsilonElectronicsLnc => "Epsilon Electronics,lnc",
+            Self::GranwinIoTTechnologyGuangzhouCoLtd => {
+                "Granwin IoT Technology (Guangzhou) Co.,Ltd"
+            }
+            Self::BroseVerwaltungSeBamberg => "Brose Verwaltung SE, Bamberg",
+            Self::OncelabsLlc => "ONCELABS LLC",
+            Self::BerlingerAndCoAg => "Berlinger & Co. AG",
+            Self::HeathConsultantsInc => "Heath Consultants Inc.",
+            Self::ControlSolutionsLlc => "Control Solutions LLC",
+            Self::HoribaLtd => "HORIBA, Ltd.",
+            Self::StingerEquipmentInc => "Stinger Equipment, Inc.",
+            Self::BelldesignInc => "BELLDESIGN Inc.",
+            Self::WrmthCorp => "Wrmth Corp.",
+            Self::ClassifiedCycling => "Classified Cycling",
+            Self::OrbInnovationsLtd => "ORB Innovations Ltd",
+            Self::EquinosisLlc => "Equinosis, LLC",
+            Self::TigerCorporation => "TIGER CORPORATION",
+            Self::NoritzCorporation => "Noritz Corporation.",
+            Self::SignalQuestLlc => "SignalQuest, LLC",
+            Self::CosmicnodeBv => "Cosmicnode BV",
+            Self::ThermokonSensortechnikGmbH => "Thermokon-Sensortechnik GmbH",
+            Self::BydCompanyLimited => "BYD Company Limited",
+            Self::ExegerOperationsAb => "Exeger Operations AB",
+            Self::XianYisuobaoElectronicTechnologyCoLtd => {
+                "Xian Yisuobao Electronic Technology Co., Ltd."
+            }
+            Self::KindooLlp => "KINDOO LLP",
+            Self::McIntoshGroupInc => "McIntosh Group Inc",
+            Self::BeeheroInc => "BEEHERO, INC.",
+            Self::EaseeAs => "Easee AS",
+            Self::OtfProductSourcingLlc => "OTF Product Sourcing, LLC",
+            Self::ZekuTechnologyShanghaiCorpLtd => "Zeku Technology (Shanghai) Corp., Ltd.",
+            Self::GigaDeviceSemiconductorInc => "GigaDevice Semiconductor Inc.",
+            Self::CaresixInc => "Caresix Inc.",
+            Self::DensoAircoolCorporation => "DENSO AIRCOOL CORPORATION",
+            Self::EmbectaCorp => "Embecta Corp.",
+            Self::PalElectronics => "Pal Electronics",
+            Self::PerformanceElectronicsLtd => "Performance Electronics, Ltd.",
+            Self::JuraElektroapparateAg => "JURA Elektroapparate AG",
+            Self::SmartdTechnologiesInc => "SMARTD TECHNOLOGIES INC.",
+            Self::KeytecInc => "KEYTEC,Inc.",
+            Self::GlamoInc => "Glamo Inc.",
+            Self::FoshanViomiElectricalTechnologyCoLtd => {
+                "Foshan Viomi Electrical Technology Co., Ltd"
+            }
+            Self::ComelitGroupSPA => "COMELIT GROUP S.P.A.",
+            Self::LviCo => "LVI Co.",
+            Self::EcSenseCoLtd => "EC sense co., Ltd",
+            Self::OffLineJapanCoLtd => "OFF Line Japan Co., Ltd.",
+            Self::Gemu => "GEMU",
+            Self::RockchipElectronicsCoLtd => "Rockchip Electronics Co., Ltd.",
+            Self::CatapultGroupInternationalLtd => "Catapult Group International Ltd",
+            Self::ArloTechnologiesInc => "Arlo Technologies, Inc.",
+            Self::Corrohm => "CORROHM",
+            Self::SomnoMedLimited => "SomnoMed Limited",
+            Self::TykeePtyLtd => "TYKEE PTY. LTD.",
+            Self::GevaSolBV => "Geva Sol B.V.",
+            Self::FasettoInc => "Fasetto, Inc.",
+            Self::ScandinavianHealthLimited => "Scandinavian Health Limited",
+            Self::IoSa => "IoSA",
+            Self::GordonMurrayDesignLimited => "Gordon Murray Design Limited",
+            Self::CosmedSRL => "Cosmed s.r.l.",
+            Self::Aeterlink => "AETERLINK",
+            Self::AlexDenkoCoLtd => "ALEX DENKO CO.,LTD.",
+            Self::MereltronBv => "Mereltron bv",
+            Self::MendeltronInc => "Mendeltron, Inc.",
+            Self::AconnoGmbH => "aconno GmbH",
+            Self::AutomatedPetCareProductsLlc => "Automated Pet Care Products, LLC",
+            Self::SenicInc => "Senic Inc.",
+            Self::LimitedLiabilityCompanyRed => "limited liability company \"Red\"",
+            Self::ConstruktsInc => "CONSTRUKTS, INC.",
+            Self::LedSmartInc => "LED Smart Inc.",
+            Self::MontageConnectInc => "Montage Connect, Inc.",
+            Self::HappyHealthInc => "Happy Health, Inc.",
+            Self::PuffCorp => "Puff Corp",
+            Self::LoomanetInc => "Loomanet, Inc.",
+            Self::NeowrkSistemasInteligentesSA => "NEOWRK SISTEMAS INTELIGENTES S.A.",
+            Self::MqaLimited => "MQA Limited",
+            Self::RatioElectricBv => "Ratio Electric BV",
+            Self::MediaCartecGmbH => "Media-Cartec GmbH",
+            Self::EsmeSolutions => "Esmé Solutions",
+            Self::TAElektroakustikGmbHAndCoKg => "T+A elektroakustik GmbH & Co.KG",
+            Self::DodamEnersysCoLtd => "Dodam Enersys Co., Ltd",
+            Self::CleanBandsSystemsLtd => "CleanBands Systems Ltd.",
+            Self::AlioInc => "Alio, Inc",
+            Self::InnovacionnyeResheniya => "Innovacionnye Resheniya",
+            Self::WackerNeusonSe => "Wacker Neuson SE",
+            Self::GreenTegAg => "greenTEG AG",
+            Self::T5TekInc => "T5 tek, Inc.",
+            Self::ErLabLlc => "ER Lab LLC",
+            Self::PoneBiometricsAs => "PONE BIOMETRICS AS",
+            Self::AngelMedicalSystemsInc => "Angel Medical Systems, Inc.",
+            Self::SiteIqLlc => "Site IQ LLC",
+            Self::KidoSportsCoLtd => "KIDO SPORTS CO., LTD.",
+            Self::SafetytestGmbH => "Safetytest GmbH",
+            Self::LinksysUsaInc => "LINKSYS USA, INC.",
+            Self::CoralTaiyiCoLtd => "CORAL-TAIYI Co. Ltd.",
+            Self::MiracleEarInc => "Miracle-Ear, Inc.",
+            Self::LunaHealthInc => "Luna Health, Inc.",
+            Self::TwentyFiveSevenProdajaInStoritveDOO => {
+                "Twenty Five Seven, prodaja in storitve, d.o.o."
+            }
+            Self::ShindengenElectricManufacturingCoLtd => {
+                "Shindengen Electric Manufacturing Co., Ltd."
+            }
+            Self::SensormateAg => "Sensormate AG",
+            Self::FresnelTechnologiesInc => "Fresnel Technologies, Inc.",
+            Self::PuratapPtyLtd => "Puratap Pty Ltd",
+            Self::ZwillingJAHenckelsAktiengesellschaft => {
+                "ZWILLING J.A. Henckels Aktiengesellschaft"
+            }
+            Self::DeepfieldConnectGmbH => "Deepfield Connect GmbH",
+            Self::ComtelSystemsLtd => "Comtel Systems Ltd.",
+            Self::OtcEngineering => "OTC engineering",
+            Self::Back40Precision => "Back40 Precision",
+            Self::KoizumiLightingTechnologyCorp => "Koizumi Lighting Technology corp.",
+            Self::WinkeyEnterpriseHongKongLimited => "WINKEY ENTERPRISE (HONG KONG) LIMITED",
+            Self::Yale => "Yale",
+            Self::CorofloLimited => "Coroflo Limited",
+            Self::LedworksSRL => "Ledworks S.r.l.",
+            Self::CharBroilLlc => "CHAR-BROIL, LLC",
+            Self::AardexLtd => "Aardex Ltd.",
+            Self::ElicsBasisLtd => "Elics Basis Ltd.",
+            Self::PuraScentsInc => "PURA SCENTS, INC.",
+            Self::VinfastTradingAndProductionJointStockCompany => {
+                "VINFAST TRADING AND PRODUCTION JOINT STOCK COMPANY"
+            }
+            Self::ShenzhenInjoinicTechnologyCoLtd => "Shenzhen Injoinic Technology Co., Ltd.",
+            Self::SuperBLithiumPowerBV => "Super B Lithium Power B.V.",
+            Self::NddMedizintechnikAg => "ndd Medizintechnik AG",
+            Self::ProconAnalyticsLlc => "Procon Analytics, LLC",
+            Self::Idec => "IDEC",
+            Self::HubeiYuanTimesTechnologyCoLtd => "Hubei Yuan Times Technology Co., Ltd.",
+            Self::DuragGmbH => "Durag GmbH",
+            Self::LlTecGroupLlc => "LL Tec Group LLC",
+            Self::NeurosityInc => "Neurosity, Inc.",
+            Self::AmikoSrl => "Amiko srl",
+            Self::SylvacSa => "Sylvac sa",
+            Self::DivesoftSRO => "Divesoft s.r.o.",
+            Self::PerimeterTechnologiesInc => "Perimeter Technologies, Inc.",
+            Self::NeuvatekInc => "Neuvatek Inc.",
+            Self::OtfDistributionLlc => "OTF Distribution, LLC",
+            Self::SigntleInc => "Signtle Inc.",
+            Self::TclCommunicationEquipmentCoLtd => "TCL COMMUNICATION EQUIPMENT CO.,LTD.",
+            Self::AperiaTechnologiesInc => "Aperia Technologies, Inc.",
+            Self::TechticsEngineeringBV => "TECHTICS ENGINEERING B.V.",
+            Self::McotInc => "MCOT INC.",
+            Self::EntWickCo => "EntWick Co.",
+            Self::MieleAndCieKg => "Miele & Cie. KG",
+            Self::ReadyForSkyLlp => "READY FOR SKY LLP",
+            Self::HimsaIiKS => "HIMSA II K/S",
+            Self::SaabAktiebolag => "SAAB Aktiebolag",
+            Self::EtheoryPtyLtd => "ETHEORY PTY LTD",
+            Self::T2RealitySolutionsPrivateLimited => "T2REALITY SOLUTIONS PRIVATE LIMITED",
+            Self::SwissinnoSolutionsAg => "SWISSINNO SOLUTIONS AG",
+            Self::HusoInc => "Huso, INC",
+            Self::SaluStimGroupOy => "SaluStim Group Oy",
+            Self::InnovagPtyLtd => "INNOVAG PTY. LTD.",
+            Self::IonaTechLlc => "IONA Tech LLC",
+            Self::BuildWithRobotsInc => "Build With Robots Inc.",
+            Self::XirgoTechnologiesLlc => "Xirgo Technologies, LLC",
+            Self::NewCosmosUsaInc => "New Cosmos USA, Inc.",
+            Self::FlenderGmbH => "Flender GmbH",
+            Self::FjordenElectraAs => "Fjorden Electra AS",
+            Self::BeijingRanxinIntelligenceTechnologyCoLtd => {
+                "Beijing ranxin intelligence technology Co.,LTD"
+            }
+            Self::EcolabInc => "Ecolab Inc.",
+            Self::NittoKogyoCorporation => "NITTO KOGYO CORPORATION",
+            Self::SomaLabsLlc => "Soma Labs LLC",
+            Self::RoamboticsInc => "Roambotics, Inc.",
+            Self::MachfuInc => "Machfu Inc.",
+            Self::GrandexInternationalCorporation => "Grandex International Corporation",
+            Self::InfinitegraInc => "Infinitegra, Inc.",
+            Self::AlltercoRoboticsLtd => "Allterco Robotics ltd",
+            Self::GlowforgeInc => "GLOWFORGE INC.",
+            Self::HearXGroupPtyLtd => "hearX Group (Pty) Ltd",
+            Self::NissanMotorCoLtd => "Nissan Motor Co., Ltd.",
+            Self::SonicosEnterprisesLlc => "SONICOS ENTERPRISES, LLC",
+            Self::VerventAudioGroup => "Vervent Audio Group",
+            Self::SonovaConsumerHearingGmbH => "Sonova Consumer Hearing GmbH",
+            Self::TireCheckGmbH => "TireCheck GmbH",
+            Self::BunnOMaticCorporation => "Bunn-O-Matic Corporation",
+            Self::DataSciencesInternational => "Data Sciences International",
+            Self::GroupLotusLimited => "Group Lotus Limited",
+            Self::AudioPartnershipPlc => "Audio Partnership Plc",
+            Self::SensoriaHoldingsLtd => "Sensoria Holdings LTD",
+            Self::KomatsuLtd => "Komatsu Ltd.",
+            Self::Gisman => "GISMAN",
+            Self::BeijingWisepoolInfiniteIntelligenceTechnologyCoLtd => {
+                "Beijing Wisepool Infinite Intelligence Technology Co.,Ltd"
+            }
+            Self::TheGoodyearTireAndRubberCompany => "The Goodyear Tire & Rubber Company",
+            Self::GymstoryBV => "Gymstory B.V.",
+            Self::SilverTreeLabsInc => "SILVER TREE LABS, INC.",
+            Self::TelecomDesign => "Telecom Design",
+            Self::NetwakeGmbH => "Netwake GmbH",
+            Self::DreemSas => "Dreem SAS",
+            Self::HangzhouBroadLinkTechnologyCoLtd => "Hangzhou BroadLink Technology Co., Ltd.",
+            Self::CitisendSolutionsSl => "Citisend Solutions, SL",
+            Self::AlfenIcuBV => "Alfen ICU B.V.",
+            Self::IneosAutomotiveLimited => "Ineos Automotive Limited",
+            Self::SenscommSemiconductorCoLtd => "Senscomm Semiconductor Co., Ltd.",
+            Self::GentleEnergyCorp => "Gentle Energy Corp.",
+            Self::PertechIndustriesInc => "Pertech Industries Inc",
+            Self::Motrex => "MOTREX",
+            Self::AmericanTechnologyComponentsIncorporated => {
+                "American Technology Components, Incorporated"
+            }
+            Self::SeikoInstrumentsInc => "Seiko Instruments Inc.",
+            Self::RotronicAg => "Rotronic AG",
+            Self::MuguangGuangdongIntelligentLightingTechnologyCoLtd => {
+                "Muguang (Guangdong) Intelligent Lighting Technology Co., Ltd"
+            }
+            Self::AmpetronicLtd => "Ampetronic Ltd",
+            Self::TrekBicycle => "Trek Bicycle",
+            Self::VimanaTechPtyLtd => "VIMANA TECH PTY LTD",
+            Self::PresidioMedicalInc => "Presidio Medical, Inc.",
+            Self::TaigaMotorsInc => "Taiga Motors Inc.",
+            Self::MammutSportsGroupAg => "Mammut Sports Group AG",
+            Self::ScmGroup => "SCM Group",
+            Self::Axelife => "AXELIFE",
+            Self::IcuTechGmbH => "ICU tech GmbH",
+            Self::OffcodeOy => "Offcode Oy",
+            Self::FoundersLaneGmbH => "FoundersLane GmbH",
+            Self::ScangripAS => "Scangrip A/S",
+            Self::HardcoderOy => "Hardcoder Oy",
+            Self::ShenzhenKtcTechnologyCoLtd => "Shenzhen KTC Technology Co.,Ltd.",
+            Self::SankyoAirTechCoLtd => "Sankyo Air Tech Co.,Ltd.",
+            Self::FieldDesignInc => "FIELD DESIGN INC.",
+            Self::AixlinkChengduCoLtd => "Aixlink(Chengdu) Co., Ltd.",
+            Self::MaxCoLtd => "MAX-co., ltd",
+            Self::TripleWJapanInc => "Triple W Japan Inc.",
+            Self::Bqn => "BQN",
+            Self::HaradaIndustryCoLtd => "HARADA INDUSTRY CO., LTD.",
+            Self::GeeknetInc => "Geeknet, Inc.",
+            Self::LilbitOdmAs => "lilbit ODM AS",
+            Self::JdrfElectromagEngineeringInc => "JDRF Electromag Engineering Inc",
+            Self::ShenzhenMalideTechnologyCoLtd => "Shenzhen Malide Technology Co.,Ltd",
+            Self::ReactMobile => "React Mobile",
+            Self::SolumCoLtd => "SOLUM CO., LTD",
+            Self::SensitechInc => "Sensitech, Inc.",
+            Self::SamsaraNetworksInc => "Samsara Networks, Inc",
+            Self::Dymo => "Dymo",
+            Self::Addaday => "Addaday",
+            Self::QuhaOy => "Quha oy",
+            Self::CleanSpaceTechnologyPtyLtd => "CleanSpace Technology Pty Ltd",
+            Self::MitsubishiElectricAutomationThailandCompanyLimited => {
+                "MITSUBISHI ELECTRIC AUTOMATION (THAILAND) COMPANY LIMITED"
+            }
+            Self::TheApacheSoftwareFoundation => "The Apache Software Foundation",
+            Self::NingBoKliteElectricManufactureCoLtd => {
+                "NingBo klite Electric Manufacture Co.,LTD"
+            }
+            Self::InnoluxCorporation => "Innolux Corporation",
+            Self::NoveaEnergies => "NOVEA ENERGIES",
+            Self::SentekPtyLtd => "Sentek Pty Ltd",
+            Self::RatocSystemsInc => "RATOC Systems, Inc.",
+            Self::RiviehInc => "Rivieh, Inc.",
+            Self::CellcontrolInc => "CELLCONTROL, INC.",
+            Self::FujianNewlandAutoIdTechCoLtd => "Fujian Newland Auto-ID Tech. Co., Ltd.",
+            Self::ExponentialPowerInc => "Exponential Power, Inc.",
+            Self::ShenzhenImagineVisionTechnologyLimited => {
+                "Shenzhen ImagineVision Technology Limited"
+            }
+            Self::HPShelbyManufacturingLlc => "H.P. Shelby Manufacturing, LLC.",
+            Self::VersaGroupBV => "Versa Group B.V.",
+            Self::TokaiDenshiInc => "TOKAI-DENSHI INC",
+            Self::ConvertronixTechnologiesAndServicesLlp => {
+                "CONVERTRONIX TECHNOLOGIES AND SERVICES LLP"
+            }
+            Self::BoraVertriebsGmbHAndCoKg => "BORA - Vertriebs GmbH & Co KG",
+            Self::HGMAutomotiveElectronicsInc => "H G M Automotive Electronics, Inc.",
+            Self::EmotionFitnessGmbHAndCoKg => "Emotion Fitness GmbH & Co. KG",
+            Self::ShenzhenKaadasIntelligentTechnologyCoLtd => {
+                "SHENZHEN KAADAS INTELLIGENT TECHNOLOGY CO.,Ltd"
+            }
+            Self::ZiipInc => "ZIIP Inc",
+            Self::FunFactoryGmbH => "FUN FACTORY GmbH",
+            Self::MeshSystemsLlc => "Mesh Systems LLC",
+            Self::BreeziIoInc => "Breezi.io, Inc.",
+            Self::IcpSystemsBV => "ICP Systems B.V.",
+            Self::AdamHallGmbH => "Adam Hall GmbH",
+            Self::BiosBobBiz => "BiosBob.Biz",
+            Self::EmsIntegratorsLlc => "EMS Integrators, LLC",
+            Self::NomonoAs => "Nomono AS",
+            Self::SkyHawkeTechnologies => "SkyHawke Technologies",
+            Self::NioUsaInc => "NIO USA, Inc.",
+            Self::GentexCorporation => "Gentex Corporation",
+            Self::BirdRidesInc => "Bird Rides, Inc.",
+            Self::ElectronicSensorsInc => "Electronic Sensors, Inc.",
+            Self::NForeTechnologyCoLtd => "nFore Technology Co., Ltd.",
+            Self::IncitatEnvironnement => "INCITAT ENVIRONNEMENT",
+            Self::Tsi => "TSI",
+            Self::SentraxGmbH => "Sentrax GmbH",
+            Self::HavellsIndiaLimited => "Havells India Limited",
+            Self::MindRhythmInc => "MindRhythm, Inc.",
+            Self::IseoSerratureSPA => "ISEO Serrature S.p.a.",
+            Self::RealtimeidAs => "REALTIMEID AS",
+            Self::DodgeIndustrialInc => "Dodge Industrial, Inc.",
+            Self::AicSemiconductorShanghaiCoLtd => "AIC semiconductor (Shanghai) Co., Ltd.",
+            Self::ImpactBiosystemsInc => "Impact Biosystems, Inc.",
+            Self::Red100LightingCoLtd => "Red 100 Lighting Co., ltd.",
+            Self::WisycomSRL => "WISYCOM S.R.L.",
+            Self::OmnivoltaicEnergySolutionsLimitedCompany => {
+                "Omnivoltaic Energy Solutions Limited Company"
+            }
+            Self::Sintef => "SINTEF",
+            Self::BhSens => "BH SENS",
+            Self::ConzumexIndustriesPrivateLimited => "CONZUMEX INDUSTRIES PRIVATE LIMITED",
+            Self::ArmaturaLlc => "ARMATURA LLC",
+            Self::HalaSystemsInc => "Hala Systems, Inc.",
+            Self::SilverWolfVehiclesInc => "Silver Wolf Vehicles Inc.",
+            Self::ArtSpa => "ART SPA",
+            Self::DukeManufacturingCo => "Duke Manufacturing Co",
+            Self::MocaSystemInc => "MOCA System Inc.",
+            Self::RedarcElectronicsPtyLtd => "REDARC ELECTRONICS PTY LTD",
+            Self::IllumagearInc => "ILLUMAGEAR, Inc.",
+            Self::Mainbot => "MAINBOT",
+            Self::AclAirshopBV => "ACL Airshop B.V.",
+            Self::TechVenomEntertainmentPrivateLimited => {
+                "Tech-Venom Entertainment Private Limited"
+            }
+            Self::Chacon => "CHACON",
+            Self::LumiUnitedTechnologyCoLtd => "Lumi United Technology Co., Ltd",
+            Self::BaracodaDailyHealthtech => "Baracoda Daily Healthtech.",
+            Self::NibrotechLtd => "NIBROTECH LTD",
+            Self::BeiJingZiJieTiaoDongKeJiCoLtd => "BeiJing ZiJie TiaoDong KeJi Co.,Ltd.",
+            Self::IRhythmTechnologiesInc => "iRhythm Technologies, Inc.",
+            Self::HygieneIqLlc => "Hygiene IQ, LLC.",
+            Self::AmsAg => "ams AG",
+            Self::TkhSecurityBV => "TKH Security B.V.",
+            Self::BeijingEswinComputingTechnologyCoLtd => {
+                "Beijing ESWIN Computing Technology Co., Ltd."
+            }
+            Self::PbInc => "PB INC.",
+            Self::AcceleratedSystems => "Accelerated Systems",
+            Self::NanoleqAg => "Nanoleq AG",
+            Self::EnerpacToolGroupCorp => "Enerpac Tool Group Corp.",
+            Self::RocaSanitarioSA => "Roca Sanitario, S.A.",
+            Self::WbsProjectHPtyLtd => "WBS PROJECT H PTY LTD",
+            Self::DecathlonSe => "DECATHLON SE",
+            Self::SigSauerInc => "SIG SAUER, INC.",
+            Self::GuardRfidSolutionsInc => "Guard RFID Solutions Inc.",
+            Self::NaosJapanKK => "NAOS JAPAN K.K.",
+            Self::Olumee => "Olumee",
+            Self::Iotools => "IOTOOLS",
+            Self::ToughBuiltIndustriesLlc => "ToughBuilt Industries LLC",
+            Self::ThermoWorksInc => "ThermoWorks, Inc.",
+            Self::AlfaLavalCorporateAb => "Alfa Laval Corporate AB",
+            Self::BEASA => "B.E.A. S.A.",
+            Self::MinebeaAccessSolutionsInc => "Minebea Access Solutions Inc.",
+            Self::SanyoDenkoCoLtd => "SANYO DENKO Co.,Ltd.",
+            Self::BluPeak => "BluPeak",
+            Self::SanistaalAS => "Sanistaal A/S",
+            Self::BelunTechnologyCompanyLimited => "Belun Technology Company Limited",
+            Self::Soonisys => "soonisys",
+            Self::ShenzhenQianfenyiIntelligentTechnologyCoLtd => {
+                "Shenzhen Qianfenyi Intelligent Technology Co., LTD"
+            }
+            Self::WorkaroundGmbh => "Workaround Gmbh",
+            Self::FazuaGmbH => "FAZUA GmbH",
+            Self::MarquardtGmbH => "Marquardt GmbH",
+            Self::IPercut => "I-PERCUT",
+            Self::PrecisionTriathlonSystemsLimited => "Precision Triathlon Systems Limited",
+            Self::IoraTechnologyDevelopmentLtdSti => "IORA Technology Development Ltd. Sti.",
+            Self::ResideoTechnologiesInc => "RESIDEO TECHNOLOGIES, INC.",
+            Self::FlaircommMicroelectronicsInc => "Flaircomm Microelectronics Inc.",
+            Self::FuseawareLimited => "FUSEAWARE LIMITED",
+            Self::EardaTechnologiesCoLtd => "Earda Technologies Co.,Ltd",
+            Self::WeberSensorsLlc => "Weber Sensors, LLC",
+            Self::CerebrumSensorTechnologiesInc => "Cerebrum Sensor Technologies Inc.",
+            Self::SmtElektronikGmbH => "SMT ELEKTRONIK GmbH",
+            Self::ChengduAmbitTechnologyCoLtd => "Chengdu Ambit Technology Co., Ltd.",
+            Self::UnistoAg => "Unisto AG",
+            Self::FirstDesignSystemInc => "First Design System Inc.",
+            Self::Irdeto => "Irdeto",
+            Self::AmetekInc => "AMETEK, Inc.",
+            Self::UnitechElectronicInc => "Unitech Electronic Inc.",
+            Self::RadioworksMicroelectronicsPtyLtd => "Radioworks Microelectronics PTY LTD",
+            Self::_701XInc => "701x Inc.",
+            Self::ShanghaiAllLinkMicroelectronicsCoLtd => {
+                "Shanghai All Link Microelectronics Co.,Ltd"
+            }
+            Self::CradersCoLtd => "CRADERS,CO.,LTD",
+            Self::LeupoldAndStevensInc => "Leupold & Stevens, Inc.",
+            Self::GlpGermanLightProductsGmbH => "GLP German Light Products GmbH",
+            Self::VelentiumLlc => "Velentium, LLC",
+            Self::SaxonarGmbH => "Saxonar GmbH",
+            Self::FutekAdvancedSensorTechnologyInc => "FUTEK ADVANCED SENSOR TECHNOLOGY, INC",
+            Self::SquareInc => "Square, Inc.",
+            Self::BordaTechnology => "Borda Technology",
+            Self::FlirSystemsAb => "FLIR Systems AB",
+            Self::LevelSRO => "LEVEL, s.r.o.",
+            Self::SunplusTechnologyCoLtd => "Sunplus Technology Co., Ltd.",
+            Self::Hexology => "Hexology",
+            Self::UnuGmbH => "unu GmbH",
+            Self::DaliAlliance => "DALI Alliance",
+            Self::GlobalMed => "GlobalMed",
+            Self::ImatrixSystemsInc => "IMATRIX SYSTEMS, INC.",
+            Self::ChengDuForThinkTechnologyCoLtd => "ChengDu ForThink Technology Co., Ltd.",
+            Self::ViceroyDevicesCorporation => "Viceroy Devices Corporation",
+            Self::DouglasDynamicsLLC => "Douglas Dynamics L.L.C.",
+            Self::VoceraCommunicationsInc => "Vocera Communications, Inc.",
+            Self::BossAudio => "Boss Audio",
+            Self::DuravitAg => "Duravit AG",
+            Self::ReelablesInc => "Reelables, Inc.",
+            Self::CodefabrikGmbH => "Codefabrik GmbH",
+            Self::ShenzhenAimoreCoLtd => "Shenzhen Aimore. Co.,Ltd",
+            Self::FranzKaldeweiGmbHAndCoKg => "Franz Kaldewei GmbH&Co KG",
+            Self::AlKoGeraeteGmbH => "AL-KO Geraete GmbH",
+            Self::NymeaGmbH => "nymea GmbH",
+            Self::StreamitBV => "Streamit B.V.",
+            Self::ZhuhaiPantumElectroniscCoLtd => "Zhuhai Pantum Electronisc Co., Ltd",
+            Self::SsvSoftwareSystemsGmbH => "SSV Software Systems GmbH",
+            Self::LautsprecherTeufelGmbH => "Lautsprecher Teufel GmbH",
+            Self::EagleKingdomTechnologiesLimited => "EAGLE KINGDOM TECHNOLOGIES LIMITED",
+            Self::NordicStrongApS => "Nordic Strong ApS",
+            Self::CaciTechnologies => "CACI Technologies",
+            Self::KobataGaugeMfgCoLtd => "KOBATA GAUGE MFG. CO., LTD.",
+            Self::VisuallexSportInternationalLimited => "Visuallex Sport International Limited",
+            Self::Nuvoton => "Nuvoton",
+            Self::IseIndividuelleSoftwareUndElektronikGmbH => {
+                "ise Individuelle Software und Elektronik GmbH"
+            }
+            Self::ShenzhenCoolKitTechnologyCoLtd => "Shenzhen CoolKit Technology Co., Ltd",
+            Self::SwedlockAb => "Swedlock AB",
+            Self::KeepinCoLtd => "Keepin Co., Ltd.",
+            Self::ChengduAichTechnologyCoLtd => "Chengdu Aich Technology Co.,Ltd",
+            Self::BarnesGroupInc => "Barnes Group Inc.",
+            Self::FlexoptixGmbH => "Flexoptix GmbH",
+            Self::Codium => "CODIUM",
+            Self::KenzenInc => "Kenzen, Inc.",
+            Self::RealMegaMicroelectronicsTechnologyShanghaiCoLtd => {
+                "RealMega Microelectronics technology (Shanghai) Co. Ltd."
+            }
+            Self::ShenzhenJingxunTechnologyCoLtd => "Shenzhen Jingxun Technology Co., Ltd.",
+            Self::OmniIdUsaInc => "Omni-ID USA, INC.",
+            Self::PaulHartmannAg => "PAUL HARTMANN AG",
+            Self::RobkooInformationAndTechnologiesCoLtd => {
+                "Robkoo Information & Technologies Co., Ltd."
+            }
+            Self::InventasAs => "Inventas AS",
+            Self::KccsMobileEngineeringCoLtd => "KCCS Mobile Engineering Co., Ltd.",
+            Self::RDasSRO => "R-DAS, s.r.o.",
+            Self::OpenBionicsLtd => "Open Bionics Ltd.",
+            Self::Stl => "STL",
+            Self::SensAiIncorporated => "Sens.ai Incorporated",
+            Self::LogTagNorthAmericaInc => "LogTag North America Inc.",
+            Self::XenterInc => "Xenter, Inc.",
+            Self::ElstatElectronicsLtd => "Elstat Electronics Ltd.",
+            Self::EllenbyTechnologiesInc => "Ellenby Technologies, Inc.",
+            Self::InnerRangePtyLtd => "INNER RANGE PTY. LTD.",
+            Self::TouchTronicsInc => "TouchTronics, Inc.",
+            Self::InVueSecurityProductsInc => "InVue Security Products Inc",
+            Self::VisiontronicSRO => "Visiontronic s.r.o.",
+            Self::AiaiaiApS => "AIAIAI ApS",
+            Self::PsEngineeringInc => "PS Engineering, Inc.",
+            Self::AdevoConsultingAb => "Adevo Consulting AB",
+            Self::OsmHkLimited => "OSM HK Limited",
+            Self::AnhuiListenaiCo => "Anhui Listenai Co",
+            Self::ComputimeInternationalLtd => "Computime International Ltd",
+            Self::SpintlyInc => "Spintly, Inc.",
+            Self::ZencontrolPtyLtd => "Zencontrol Pty Ltd",
+            Self::UrbanistaAb => "Urbanista AB",
+            Self::RealityworksInc => "Realityworks, inc.",
+            Self::ShenzhenUascentTechnologyCoLtd => "Shenzhen Uascent Technology Co., Ltd",
+            Self::FazeproLlc => "FAZEPRO LLC",
+            Self::DicCorporation => "DIC Corporation",
+            Self::CareBloomLlc => "Care Bloom, LLC",
+            Self::LincognTechnologyCoLimited => "LINCOGN TECHNOLOGY CO. LIMITED",
+            Self::LoyTecElectronicsGmbH => "Loy Tec electronics GmbH",
+            Self::IstaInternationalGmbH => "ista International GmbH",
+            Self::LifePlusInc => "LifePlus, Inc.",
+            Self::CanonFinetechNiscaInc => "Canon Finetech Nisca Inc.",
+            Self::XiAnFengyuInformationTechnologyCoLtd => {
+                "Xi'an Fengyu Information Technology Co., Ltd."
+            }
+            Self::EelloLlc => "Eello LLC",
+            Self::TemkinAssociatesLlc => "TEMKIN ASSOCIATES, LLC",
+            Self::ShanghaiHighFlyingElectronicsTechnologyCoLtd => {
+                "Shanghai high-flying electronics technology Co.,Ltd"
+            }
+            Self::FoilInc => "Foil, Inc.",
+            Self::SensTek => "SensTek",
+            Self::LightricityLtd => "Lightricity Ltd",
+            Self::PamexInc => "Pamex Inc.",
+            Self::OobikInc => "OOBIK Inc.",
+            Self::GiPStechSRL => "GiPStech S.r.l.",
+            Self::CarestreamDentalLlc => "Carestream Dental LLC",
+            Self::MonarchInternationalInc => "Monarch International Inc.",
+            Self::ShenzhenGrandsunElectronicCoLtd => "Shenzhen Grandsun Electronic Co.,Ltd.",
+            Self::TotoLtd => "TOTO LTD.",
+            Self::PerfectCompany => "Perfect Company",
+            Self::JcmTechnologiesSA => "JCM TECHNOLOGIES S.A.",
+            Self::DelpSysSRO => "DelpSys, s.r.o.",
+            Self::SaNlightGmbH => "SANlight GmbH",
+            Self::HainbuchGmbhSpannendeTechnik => "HAINBUCH GMBH SPANNENDE TECHNIK",
+            Self::SesImagotag => "SES-Imagotag",
+            Self::PsaPeugeotCitroen => "PSA Peugeot Citroen",
+            Self::ShanghaiSmartSystemTechnologyCoLtd => "Shanghai Smart System Technology Co., Ltd",
+            Self::AlizentInternational => "ALIZENT International",
+            Self::SnowballTechnologyCoLtd => "Snowball Technology Co., Ltd.",
+            Self::GreennoteInc => "Greennote Inc,",
+            Self::RivataInc => "Rivata, Inc.",
+            Self::Corsair => "Corsair",
+            Self::UniversalBiosensorsPtyLtd => "Universal Biosensors Pty Ltd",
+            Self::CleerLimited => "Cleer Limited",
+            Self::IntuityMedical => "Intuity Medical",
+            Self::KebaHandoverAutomationGmbH => "KEBA Handover Automation GmbH",
+            Self::FreedmanElectronicsPtyLtd => "Freedman Electronics Pty Ltd",
+            Self::Waferlock => "WAFERLOCK",
+            Self::UniqAirOy => "UniqAir Oy",
+            Self::EmlidLimited => "Emlid Limited",
+            Self::WebastoSe => "Webasto SE",
+            Self::ShenzhenSunricherTechnologyLimited => "Shenzhen Sunricher Technology Limited",
+            Self::AxtroPteLtd => "AXTRO PTE. LTD.",
+            Self::SynapticsIncorporated => "Synaptics Incorporated",
+            Self::DeltaCycleCorporation => "Delta Cycle Corporation",
+            Self::MicrosonSA => "MICROSON S.A.",
+            Self::InnohomeOy => "Innohome Oy",
+            Self::JumoGmbHAndCoKg => "Jumo GmbH & Co. KG",
+            Self::SenquipPtyLtd => "Senquip Pty Ltd",
+            Self::Ooma => "Ooma",
+            Self::WarnerBros => "Warner Bros.",
+            Self::PacSaneLimited => "Pac Sane Limited",
+            Self::KuukanjyokinCoLtd => "KUUKANJYOKIN Co.,Ltd.",
+            Self::Pokkels => "Pokkels",
+            Self::OlympicOphthalmicsInc => "Olympic Ophthalmics, Inc.",
+            Self::ScribbleDesignInc => "Scribble Design Inc.",
+            Self::HappiestBabyInc => "HAPPIEST BABY, INC.",
+            Self::FocusIngenieriaSrl => "Focus Ingenieria SRL",
+            Self::BeijingSuperHexaCenturyTechnologyCoLtd => {
+                "Beijing SuperHexa Century Technology CO. Ltd"
+            }
+            Self::JustmorphPteLtd => "JUSTMORPH PTE. LTD.",
+            Self::LytxInc => "Lytx, INC.",
+            Self::GeopalSystemAS => "Geopal system A/S",
+            Self::GremsyJsc => "Gremsy JSC",
+            Self::MokoTechnologyLtd => "MOKO TECHNOLOGY Ltd",
+            Self::SmartParksBV => "Smart Parks B.V.",
+            Self::DatangSemiconductorTechnologyCoLtd => "DATANG SEMICONDUCTOR TECHNOLOGY CO.,LTD",
+            Self::Stryker => "stryker",
+            Self::LaceClipsLlc => "LaceClips llc",
+            Self::MgEnergySystemsBV => "MG Energy Systems B.V.",
+            Self::InnovativeDesignLabsInc => "Innovative Design Labs Inc.",
+            Self::LegicIdentsystemsAg => "LEGIC Identsystems AG",
+            Self::SontheimIndustrieElektronikGmbH => "Sontheim Industrie Elektronik GmbH",
+            Self::TourBuiltLlc => "TourBuilt, LLC",
+            Self::IndigoDiabetes => "Indigo Diabetes",
+            Self::MeizhouGuoWeiElectronicsCoLtd => "Meizhou Guo Wei Electronics Co., Ltd",
+            Self::Ambie => "ambie",
+            Self::InugoSystemsLimited => "Inugo Systems Limited",
+            Self::SqlTechnologiesCorp => "SQL Technologies Corp.",
+            Self::KkmCompanyLimited => "KKM COMPANY LIMITED",
+            Self::FollowSenseEuropeBV => "Follow Sense Europe B.V.",
+            Self::Csiro => "CSIRO",
+            Self::NextscapeInc => "Nextscape Inc.",
+            Self::VanmoofGlobalHoldingBV => "VANMOOF Global Holding B.V.",
+            Self::ToytecCorporation => "Toytec Corporation",
+            Self::LocknTechnologiesPrivateLimited => "Lockn Technologies Private Limited",
+            Self::SiFliTechnologiesShanghaiInc => "SiFli Technologies (shanghai) Inc.",
+            Self::MistyWestEnergyAndTransportLtd => "MistyWest Energy and Transport Ltd.",
+            Self::MapLargeInc => "Map Large, Inc.",
+            Self::VentureResearchInc => "Venture Research Inc.",
+            Self::JrcMobilityInc => "JRC Mobility Inc.",
+            Self::TheWandCompanyLtd => "The Wand Company Ltd",
+            Self::BeijingHcInfiniteTechnologyLimited => "Beijing HC-Infinite Technology Limited",
+            Self::_3SiSecuritySystemsInc => "3SI Security Systems, Inc",
+            Self::NovidanInc => "Novidan, Inc.",
+            Self::BuschSystemsInternationalInc => "Busch Systems International Inc.",
+            Self::MotionalysisInc => "Motionalysis, Inc.",
+            Self::OpexCorporation => "OPEX Corporation",
+            Self::GewissSPA => "GEWISS S.p.A.",
+            Self::ShenzhenYopeakOptoelectronicsTechnologyCoLtd => {
+                "Shenzhen Yopeak Optoelectronics Technology Co., Ltd."
+            }
+            Self::HefeiYunlianSemiconductorCoLtd => "Hefei Yunlian Semiconductor Co., Ltd",
+            Self::Delabie => "DELABIE",
+            Self::SitecoGmbH => "Siteco GmbH",
+            Self::GalileoTechnologyLimited => "Galileo Technology Limited",
+            Self::IncotexCoLtd => "Incotex Co. Ltd.",
+            Self::BlueticketingSrl => "BLUETICKETING SRL",
+            Self::BouffaloLabNanjingLtd => "Bouffalo Lab (Nanjing)., Ltd.",
+            Self::_2587702OntarioInc => "2587702 Ontario Inc.",
+            Self::NgkSparkPlugCoLtd => "NGK SPARK PLUG CO., LTD.",
+            Self::SafectoryGmbH => "safectory GmbH",
+            Self::LuxerCorporation => "Luxer Corporation",
+            Self::WmfAg => "WMF AG",
+            Self::PinnacleTechnologyInc => "Pinnacle Technology, Inc.",
+            Self::NevroCorp => "Nevro Corp.",
+            Self::AirWeigh => "Air-Weigh",
+            Self::InstamicInc => "Instamic, Inc.",
+            Self::ZumaArrayLimited => "Zuma Array Limited",
+            Self::ShenzhenFeasycomTechnologyCoLtd => "Shenzhen Feasycom Technology Co., Ltd.",
+            Self::ShenzhenHAndTIntelligentControlCoLtd => {
+                "Shenzhen H&T Intelligent Control Co., Ltd"
+            }
+            Self::PaceBaitIvs => "PaceBait IVS",
+            Self::YamahaCorporation => "Yamaha Corporation",
+            Self::WorthcloudTechnologyCoLtd => "Worthcloud Technology Co.,Ltd",
+            Self::NanoFlexPowerCorporation => "NanoFlex Power Corporation",
+            Self::AyuDevicesPrivateLimited => "AYU DEVICES PRIVATE LIMITED",
+            Self::LouisVuitton => "Louis Vuitton",
+            Self::EranFinancialServicesLlc => "Eran Financial Services LLC",
+            Self::AtmosicTechnologiesInc => "Atmosic Technologies, Inc.",
+            Self::BixolonCoLtd => "BIXOLON CO.,LTD",
+            Self::DaiichikoshoCoLtd => "DAIICHIKOSHO CO., LTD.",
+            Self::ApollogicSpZOO => "Apollogic Sp. z o.o.",
+            Self::JiangxiInnotechTechnologyCoLtd => "Jiangxi Innotech Technology Co., Ltd",
+            Self::DeVilbissHealthcareLlc => "DeVilbiss Healthcare LLC",
+            Self::CombiQAb => "CombiQ AB",
+            Self::ApiK => "API-K",
+            Self::InpeakSC => "INPEAK S.C.",
+            Self::EmbravaPtyLtd => "Embrava Pty Ltd",
+            Self::LinkLabsInc => "Link Labs, Inc.",
+            Self::MaxellLtd => "Maxell, Ltd.",
+            Self::CambridgeAnimalTechnologiesLtd => "Cambridge Animal Technologies Ltd",
+            Self::PlumeDesignInc => "Plume Design Inc",
+            Self::RideVisionLtd => "RIDE VISION LTD",
+            Self::SyngInc => "Syng Inc",
+            Self::CroxelInc => "CROXEL, INC.",
+            Self::Tec4MedLifeScienceGmbH => "Tec4med LifeScience GmbH",
+            Self::DysonTechnologyLimited => "Dyson Technology Limited",
+            Self::Sensolus => "Sensolus",
+            Self::SubaruCorporation => "SUBARU Corporation",
+            Self::LixilCorporation => "LIXIL Corporation",
+            Self::RolandCorporation => "Roland Corporation",
+            Self::BluePeacockGmbH => "Blue Peacock GmbH",
+            Self::ShanghaiYidianIntelligentTechnologyCoLtd => {
+                "Shanghai Yidian Intelligent Technology Co., Ltd."
+            }
+            Self::SianaSystems => "SIANA Systems",
+            Self::VolanTechnologyInc => "Volan Technology Inc.",
+            Self::Ecct => "ECCT",
+            Self::OrasOy => "Oras Oy",
+            Self::ReflowPtyLtd => "Reflow Pty Ltd",
+            Self::ShanghaiWuqiMicroelectronicsCoLtd => "Shanghai wuqi microelectronics Co.,Ltd",
+            Self::SouthwireCompanyLlc => "Southwire Company, LLC",
+            Self::FlosonicsMedical => "Flosonics Medical",
+            Self::DonutroboticsCoLtd => "donutrobotics Co., Ltd.",
+            Self::AyxonDynamicsGmbH => "Ayxon-Dynamics GmbH",
+            Self::CleveronAs => "Cleveron AS",
+            Self::AmplerBikesOu => "Ampler Bikes OU",
+            Self::Airstar => "AIRSTAR",
+            Self::LichtvisionEngineeringGmbH => "Lichtvision Engineering GmbH",
+            Self::KeepTechnologiesInc => "Keep Technologies, Inc.",
+            Self::Confidex => "Confidex",
+            Self::ToituCoLtd => "TOITU CO., LTD.",
+            Self::ListenTechnologiesCorporation => "Listen Technologies Corporation",
+            Self::HangzhouYaguanTechnologyCoLtd => "Hangzhou Yaguan Technology Co. LTD",
+            Self::ROSRL => "R.O. S.R.L.",
+            Self::SensatecCoLtd => "SENSATEC Co., Ltd.",
+            Self::MobileActionTechnologyInc => "Mobile Action Technology Inc.",
+            Self::OkiElectricIndustryCoLtd => "OKI Electric Industry Co., Ltd",
+            Self::SpectrumTechnologiesInc => "Spectrum Technologies, Inc.",
+            Self::BeijingZeroZeroInfinityTechnologyCoLtd => {
+                "Beijing Zero Zero Infinity Technology Co.,Ltd."
+            }
+            Self::AudearaPtyLtd => "Audeara Pty Ltd",
+            Self::OmDigitalSolutionsCorporation => "OM Digital Solutions Corporation",
+            Self::WatchGasBV => "WatchGas B.V.",
+            Self::SteinelSolutionsAg => "Steinel Solutions AG",
+            Self::OjmarSa => "OJMAR SA",
+            Self::SibelInc => "Sibel Inc.",
+            Self::YukonAdvancedOpticsWorldwideUab => "Yukon advanced optics worldwide, UAB",
+            Self::KeanElectronicsPtyLtd => "KEAN ELECTRONICS PTY LTD",
+            Self::AthlosOy => "Athlos Oy",
+            Self::LumenRadioAb => "LumenRadio AB",
+            Self::MelangeSystemsPvtLtd => "Melange Systems Pvt. Ltd.",
+            Self::KabushikigaishaHaneron => "Kabushikigaisha HANERON",
+            Self::MasoniteCorporation => "Masonite Corporation",
+            Self::Mobilogix => "Mobilogix",
+            Self::CpsAs => "CPS AS",
+            Self::FridayHomeAps => "Friday Home Aps",
+            Self::WuhanLinptechCoLtd => "Wuhan Linptech Co.,Ltd.",
+            Self::TagNTracInc => "Tag-N-Trac Inc",
+            Self::PreddioTechnologiesInc => "Preddio Technologies Inc.",
+            Self::MagnusTechnologySdnBhd => "Magnus Technology Sdn Bhd",
+            Self::JldTechnologySolutionsLlc => "JLD Technology Solutions, LLC",
+            Self::InnowareDevelopmentAb => "Innoware Development AB",
+            Self::Aon2Ltd => "AON2 Ltd.",
+            Self::BionicAvionicsInc => "Bionic Avionics Inc.",
+            Self::NagravisionSa => "Nagravision SA",
+            Self::VivoSensMedicalGmbH => "VivoSensMedical GmbH",
+            Self::SynergyTecnologiaEmSistemasLtda => "Synergy Tecnologia em Sistemas Ltda",
+            Self::Coyotta => "Coyotta",
+            Self::EarTeknikIsitmeVeOdiometriCihazlariSanayiVeTicaretAnonimSirketi => {
+                "EAR TEKNIK ISITME VE ODIOMETRI CIHAZLARI SANAYI VE TICARET ANONIM SIRKETI"
+            }
+            Self::GearRadioElectronicsCorp => "GEAR RADIO ELECTRONICS CORP.",
+            Self::OrbisInc => "ORBIS Inc.",
+            Self::HeartHeroInc => "HeartHero, inc.",
+            Self::TemperatureSensitiveSolutionsSystemsSwedenAb => {
+                "Temperature Sensitive Solutions Systems Sweden AB"
+            }
+            Self::AblepayTechnologiesAs => "ABLEPAY TECHNOLOGIES AS",
+            Self::ChessWiseBV => "Chess Wise B.V.",
+            Self::BlueStreakIoTLlc => "BlueStreak IoT, LLC",
+            Self::JuliusBlumGmbH => "Julius Blum GmbH",
+            Self::Blyott => "Blyott",
+            Self::Senso4SDOO => "Senso4s d.o.o.",
+            Self::HxEngineeringLlc => "Hx Engineering, LLC",
+            Self::Mobitrace => "Mobitrace",
+            Self::CrowdGlowLtd => "CrowdGlow Ltd",
+            Self::Xuntong => "XUNTONG",
+            Self::CombustionLlc => "Combustion, LLC",
+            Self::HonorDeviceCoLtd => "Honor Device Co., Ltd.",
+            Self::HungYiMicroelectronicsCoLtd => "HungYi Microelectronics Co.,Ltd.",
+            Self::Uvisio => "UVISIO",
+            Self::JapanTobaccoInc => "JAPAN TOBACCO INC.",
+            Self::UniversalAudioInc => "Universal Audio, Inc.",
+            Self::Rosewill => "Rosewill",
+            Self::AnotherBrainInc => "AnotherBrain inc.",
+            Self::SpanIoInc => "Span.IO, Inc.",
+            Self::VesselLtd => "Vessel Ltd.",
+            Self::CentreSuisseDElectroniqueEtDeMicrotechniqueSa => {
+                "Centre Suisse d'Electronique et de Microtechnique SA"
+            }
+            Self::AerosensLlc => "Aerosens LLC",
+            Self::SkyStreamCorporation => "SkyStream Corporation",
+            Self::ElimoEngineeringLtd => "Elimo Engineering Ltd",
+            Self::SavoyElectronicLighting => "SAVOY ELECTRONIC LIGHTING",
+            Self::PlayerDataLimited => "PlayerData Limited",
+            Self::BoutLabsLlc => "Bout Labs, LLC",
+            Self::PegasusTechnologiesInc => "Pegasus Technologies, Inc.",
+            Self::AutecGesellschaftFuerAutomationstechnikMbH => {
+                "AUTEC Gesellschaft fuer Automationstechnik mbH"
+            }
+            Self::PentaLockAps => "PentaLock Aps.",
+            Self::BlueXMicroelectronicsCorpLtd => "BlueX Microelectronics Corp Ltd.",
+            Self::Dyphi => "DYPHI",
+            Self::Blinqy => "BLINQY",
+            Self::DeublinCompanyLlc => "Deublin Company, LLC",
+            Self::IfLinkOpenCommunity => "ifLink Open Community",
+            Self::PozyxNv => "Pozyx NV",
+            Self::NarhwallInc => "Narhwall Inc.",
+            Self::Ambiq => "Ambiq",
+            Self::DashLogicInc => "DashLogic, Inc.",
+            Self::PhotodynamicIncorporated => "PHOTODYNAMIC INCORPORATED",
+            Self::NipponCeramicCoLtd => "Nippon Ceramic Co.,Ltd.",
+            Self::KhnSolutionsLlc => "KHN Solutions LLC",
+            Self::PaybuddyApS => "Paybuddy ApS",
+            Self::BeijingElectricVehicleCoLtd => "BEIJING ELECTRIC VEHICLE CO.,LTD",
+            Self::SecurityEnhancementSystemsLlc => "Security Enhancement Systems, LLC",
+            Self::KumhoElectricsInc => "KUMHO ELECTRICS, INC",
+            Self::ArduinoSa => "ARDUINO SA",
+            Self::EngagenowDataSciencesPrivateLimited => "ENGAGENOW DATA SCIENCES PRIVATE LIMITED",
+            Self::VosSystemsLlc => "VOS Systems, LLC",
+            Self::ProofDiagnosticsInc => "Proof Diagnostics, Inc.",
+            Self::KoyaMedicalInc => "Koya Medical, Inc.",
+            Self::StepOneLimited => "Step One Limited",
+            Self::YkkApInc => "YKK AP Inc.",
+            Self::DeisterElectronicGmbH => "deister electronic GmbH",
+            Self::SendumWirelessCorporation => "Sendum Wireless Corporation",
+            Self::NewAudioLlc => "New Audio LLC",
+            Self::ETacticaEhf => "eTactica ehf",
+            Self::PixieDustTechnologiesInc => "Pixie Dust Technologies, Inc.",
+            Self::NextMind => "NextMind",
+            Self::CAndEFeinGmbH => "C. & E. Fein GmbH",
+            Self::BronkhorstHighTechBV => "Bronkhorst High-Tech B.V.",
+            Self::Vt42PtyLtd => "VT42 Pty Ltd",
+            Self::AbsoluteAudioLabsBV => "Absolute Audio Labs B.V.",
+            Self::BigKaiserPrecisionToolingLtd => "Big Kaiser Precision Tooling Ltd",
+            Self::TelenorAsa => "Telenor ASA",
+            Self::AntonPaarGmbH => "Anton Paar GmbH",
+            Self::AktiebolagetRegin => "Aktiebolaget Regin",
+            Self::Adveez => "ADVEEZ",
+            Self::C3WirelessLlc => "C3-WIRELESS, LLC",
+            Self::BGridBV => "bGrid B.V.",
+            Self::MequonicEngineeringSL => "Mequonic Engineering, S.L.",
+            Self::Biovigil => "Biovigil",
+            Self::WikaAlexanderWiegandSeAndCoKg => "WIKA Alexander Wiegand SE & Co.KG",
+            Self::BhmTechProduktionsgesellschaftMBH => "BHM-Tech Produktionsgesellschaft m.b.H",
+            Self::TseBrakesInc => "TSE BRAKES, INC.",
+            Self::CelloHillLlc => "Cello Hill, LLC",
+            Self::LumosHealthInc => "Lumos Health Inc.",
+            Self::TeraTronGmbH => "TeraTron GmbH",
+            Self::FeedbackSportsLlc => "Feedback Sports LLC",
+            Self::ElproBuchsAg => "ELPRO-BUCHS AG",
+            Self::BernardKroneHoldingSeAndCoKg => "Bernard Krone Holding SE & Co.KG",
+            Self::DekraTestingAndCertificationSAU => "DEKRA TESTING AND CERTIFICATION, S.A.U.",
+            Self::IsemarSRL => "ISEMAR S.R.L.",
+            Self::SonicSensoryInc => "SonicSensory Inc",
+            Self::ClbBV => "CLB B.V.",
+            Self::ThorleyIndustriesLlc => "Thorley Industries, LLC",
+            Self::CtekSwedenAb => "CTEK Sweden AB",
+            Self::CoreCorporation => "CORE CORPORATION",
+            Self::BiotronikSeAndCoKg => "BIOTRONIK SE & Co. KG",
+            Self::ZifferEinsGmbHAndCoKg => "ZifferEins GmbH & Co. KG",
+            Self::ToyotaMotorCorporation => "TOYOTA motor corporation",
+            Self::FaunaAudioGmbH => "Fauna Audio GmbH",
+            Self::BlueIotBeijingTechnologyCoLtd => "BlueIOT(Beijing) Technology Co.,Ltd",
+            Self::Abeye => "ABEYE",
+            Self::PopitOy => "Popit Oy",
+            Self::ClosedJointStockCompanyZavodFlometrZavodFlometrCjsc => {
+                "Closed Joint Stock Company \"Zavod Flometr\" (\"Zavod Flometr\" CJSC)"
+            }
+            Self::Ga => "GA",
+            Self::IbaDosimetryGmbH => "IBA Dosimetry GmbH",
+            Self::LundMotionProductsInc => "Lund Motion Products, Inc.",
+            Self::BandIndustriesInc => "Band Industries, inc.",
+            Self::GunwerksLlc => "Gunwerks, LLC",
+            Self::_93747319QuebecInc => "9374-7319 Quebec inc",
+            Self::GuideIdBV => "Guide ID B.V.",
+            Self::DricosInc => "dricos, Inc.",
+            Self::WoanTechnologyShenzhenCoLtd => "Woan Technology (Shenzhen) Co., Ltd.",
+            Self::ActevMotorsInc => "Actev Motors, Inc.",
+            Self::NeoMaterialsAndConsultingInc => "Neo Materials and Consulting Inc.",
+            Self::PointGuardLlc => "PointGuard, LLC",
+            Self::AsahiKaseiCorporation => "Asahi Kasei Corporation",
+            Self::CountrymateTechnologyLimited => "Countrymate Technology Limited",
+            Self::MoonbirdBv => "Moonbird BV",
+            Self::GlSolutionsKK => "GL Solutions K.K.",
+            Self::LinkuraAb => "Linkura AB",
+            Self::SenaTechnologiesInc => "Sena Technologies Inc.",
+            Self::NuanceHearingLtd => "NUANCE HEARING LTD",
+            Self::BioEchoNetInc => "BioEchoNet inc.",
+            Self::ElectronicTheatreControls => "Electronic Theatre Controls",
+            Self::LogiLubeLlc095C => "LogiLube, LLC",
+            Self::LismoreInstrumentsLimited => "Lismore Instruments Limited",
+            Self::SelektBilgisayarLletisimUrunleriLnsaatSanayiVeTicaretLimitedSirketi => {
+                "Selekt Bilgisayar, lletisim Urunleri lnsaat Sanayi ve Ticaret Limited Sirketi"
+            }
+            Self::HerdDoggInc => "HerdDogg, Inc",
+            Self::ZteCorporation => "ZTE Corporation",
+            Self::OhsungElectronics => "Ohsung Electronics",
+            Self::Kerlink => "Kerlink",
+            Self::BrevilleGroup => "Breville Group",
+            Self::Julbo => "Julbo",
+            Self::LogiLubeLlc0953 => "LogiLube, LLC",
+            Self::ApptricityCorporation => "Apptricity Corporation",
+            Self::Pprs => "PPRS",
+            Self::Capetech => "Capetech",
+            Self::LimitedLiabilityCompanyMikrotikls => "Limited Liability Company \"Mikrotikls\"",
+            Self::PassiveBoltInc => "PassiveBolt, Inc.",
+            Self::TkLabsInc => "tkLABS INC.",
+            Self::GimmiSysGmbH => "GimmiSys GmbH",
+            Self::KindevaDrugDeliveryLP => "Kindeva Drug Delivery L.P.",
+            Self::ZwiftInc => "Zwift, Inc.",
+            Self::MetronomHealthEurope => "Metronom Health Europe",
+            Self::WearableLinkLimited => "Wearable Link Limited",
+            Self::FirstLightTechnologiesLtd => "First Light Technologies Ltd.",
+            Self::AmcInternationalAlfaMetalcraftCorporationAg => {
+                "AMC International Alfa Metalcraft Corporation AG"
+            }
+            Self::GlobeJiangsuCoLtd => "Globe (Jiangsu) Co., Ltd",
+            Self::AgitronDOO => "Agitron d.o.o.",
+            Self::TranssionHoldingsLimited => "TRANSSION HOLDINGS LIMITED",
+            Self::RivianAutomotiveLlc => "Rivian Automotive, LLC",
+            Self::HeroWorkoutGmbH => "Hero Workout GmbH",
+            Self::JepicoCorporation => "JEPICO Corporation",
+            Self::CatalyftLabsInc => "Catalyft Labs, Inc.",
+            Self::AdolfWuerthGmbHAndCoKg => "Adolf Wuerth GmbH & Co KG",
+            Self::XenomaInc => "Xenoma Inc.",
+            Self::EnsesoLlc => "ENSESO LLC",
+            Self::LinkedSemiMicroelectronicsXiamenCoLtd => {
+                "LinkedSemi Microelectronics (Xiamen) Co., Ltd"
+            }
+            Self::AstemCoLtd => "ASTEM Co.,Ltd.",
+            Self::HenwayTechnologiesLtd => "Henway Technologies, LTD.",
+            Self::RealThingksGmbH => "RealThingks GmbH",
+            Self::ElekonAg => "Elekon AG",
+            Self::ReconnectInc => "Reconnect, Inc.",
+            Self::KiteSpringInc => "KiteSpring Inc.",
+            Self::Sram => "SRAM",
+            Self::BarVisionLlc => "BarVision, LLC",
+            Self::BreathingsCoLtd => "BREATHINGS Co., Ltd.",
+            Self::JamesWalkerRotaBoltLimited => "James Walker RotaBolt Limited",
+            Self::COBOSpA => "C.O.B.O. SpA",
+            Self::PsGmbH => "PS GmbH",
+            Self::LeggettAndPlattIncorporated => "Leggett & Platt, Incorporated",
+            Self::PciPrivateLimited => "PCI Private Limited",
+            Self::TekHome => "TekHome",
+            Self::SapplVerwaltungsUndBetriebsGmbH => "Sappl Verwaltungs- und Betriebs GmbH",
+            Self::QingdaoHaierTechnologyCoLtd => "Qingdao Haier Technology Co., Ltd.",
+            Self::AiRista => "AiRISTA",
+            Self::RooqGmbH => "ROOQ GmbH",
+            Self::GooligumTechnologiesPtyLtd => "Gooligum Technologies Pty Ltd",
+            Self::YukaiEngineeringInc => "Yukai Engineering Inc.",
+            Self::FundacionTecnaliaResearchAndInnovation => {
+                "Fundacion Tecnalia Research and Innovation"
+            }
+            Self::JsbTechPteLtd => "JSB TECH PTE LTD",
+            Self::ShanghaiMxchipInformationTechnologyCoLtd => {
+                "Shanghai MXCHIP Information Technology Co., Ltd."
+            }
+            Self::KahaPteLtd => "KAHA PTE. LTD.",
+            Self::OmnisenseLimited => "Omnisense Limited",
+            Self::MyzeeTechnology => "Myzee Technology",
+            Self::MelbotStudiosSociedadLimitada => "Melbot Studios, Sociedad Limitada",
+            Self::InnokindInc => "Innokind, Inc.",
+            Self::OblamatikAg => "Oblamatik AG",
+            Self::LuminosticsInc => "Luminostics, Inc.",
+            Self::AlbertronicBv => "Albertronic BV",
+            Self::NoSmdLimited => "NO SMD LIMITED",
+            Self::TechnosphereLabsPvtLtd => "Technosphere Labs Pvt. Ltd.",
+            Self::AsrMicroelectronicsShenZhenCoLtd => "ASR Microelectronics(ShenZhen)Co., Ltd.",
+            Self::AmbientSensorsLlc => "Ambient Sensors LLC",
+            Self::HondaMotorCoLtd => "Honda Motor Co., Ltd.",
+            Self::IneoSense => "INEO-SENSE",
+            Self::BraveheartWirelessInc => "Braveheart Wireless, Inc.",
+            Self::NerbioMedicalSoftwarePlatformsInc => "Nerbio Medical Software Platforms Inc",
+            Self::DouglasLightingControlsInc => "Douglas Lighting Controls Inc.",
+            Self::AsrMicroelectronicsShanghaiCoLtd => "ASR Microelectronics (Shanghai) Co., Ltd.",
+            Self::VcInc => "VC Inc.",
+            Self::OptimusiotTechLlp => "OPTIMUSIOT TECH LLP",
+            Self::IotInventGmbH => "IOT Invent GmbH",
+            Self::RadiawaveTechnologiesCoLtd => "Radiawave Technologies Co.,Ltd.",
+            Self::EmbrLabsInc => "EMBR labs, INC",
+            Self::ZhuhaiHoksiTechnologyCoLtd => "Zhuhai Hoksi Technology CO.,LTD",
+            Self::_70MaiCoLtd => "70mai Co.,Ltd.",
+            Self::PinpointInnovationsLimited => "Pinpoint Innovations Limited",
+            Self::UserHelloLlc => "User Hello, LLC",
+            Self::ScopeLogisticalSolutions => "Scope Logistical Solutions",
+            Self::YandexServicesAg => "Yandex Services AG",
+            Self::Suncorporation => "SUNCORPORATION",
+            Self::DatamarsInc => "DATAMARS, Inc.",
+            Self::TscAutoIdTechnologyCoLtd => "TSC Auto-ID Technology Co., Ltd.",
+            Self::Lucimed => "Lucimed",
+            Self::BeijingZizaiTechnologyCoLtd => "Beijing Zizai Technology Co., LTD.",
+            Self::PlastimoldProductsInc => "Plastimold Products, Inc",
+            Self::KetronixsSdnBhd => "Ketronixs Sdn Bhd",
+            Self::BioIntelliSenseInc => "BioIntelliSense, Inc.",
+            Self::HillRom => "Hill-Rom",
+            Self::DarkglassElectronicsOy => "Darkglass Electronics Oy",
+            Self::TrooCorporation => "Troo Corporation",
+            Self::SpacelabsMedicalInc => "Spacelabs Medical Inc.",
+            Self::InstagridGmbH => "instagrid GmbH",
+            Self::MtdProductsIncAndAffiliates => "MTD Products Inc & Affiliates",
+            Self::DermalPhotonicsCorporation => "Dermal Photonics Corporation",
+            Self::TymtixTechnologiesPrivateLimited => "Tymtix Technologies Private Limited",
+            Self::KodimoTechnologiesCompanyLimited => "Kodimo Technologies Company Limited",
+            Self::PspPauliServicesAndProductsGmbH => "PSP - Pauli Services & Products GmbH",
+            Self::Microoled => "Microoled",
+            Self::TheLSStarrettCompany => "The L.S. Starrett Company",
+            Self::JoovvInc => "Joovv, Inc.",
+            Self::CumulusDigitalSystemsInc => "Cumulus Digital Systems, Inc",
+            Self::AskeyComputerCorp => "Askey Computer Corp.",
+            Self::ImiHydronicEngineeringInternationalSa => {
+                "IMI Hydronic Engineering International SA"
+            }
+            Self::DensoCorporation => "Denso Corporation",
+            Self::BeijingBigMomentTechnologyCoLtd => "Beijing Big Moment Technology Co., Ltd.",
+            Self::CowbellEngineeringCoLtd => "COWBELL ENGINEERING CO.,LTD.",
+            Self::TaiwanIntelligentHomeCorp => "Taiwan Intelligent Home Corp.",
+            Self::Naonext => "Naonext",
+            Self::BarrotTechnologyCoLtd => "Barrot Technology Co.,Ltd.",
+            Self::EnesoTecnologiaDeAdaptacionSL => "Eneso Tecnologia de Adaptacion S.L.",
+            Self::CrowdConnectedLtd => "Crowd Connected Ltd",
+            Self::RashidovLtd => "Rashidov ltd",
+            Self::RepublicWirelessInc => "Republic Wireless, Inc.",
+            Self::ShenzhenSimoTechnologyCoLtd => "Shenzhen Simo Technology co. LTD",
+            Self::KozoKeikakuEngineeringInc => "KOZO KEIKAKU ENGINEERING Inc.",
+            Self::PhiliaTechnology => "Philia Technology",
+            Self::IrisOhyamaCoLtd => "IRIS OHYAMA CO.,LTD.",
+            Self::TeConnectivityCorporation => "TE Connectivity Corporation",
+            Self::CodeQ => "code-Q",
+            Self::ShenzhenAukeyEBusinessCoLtd => "SHENZHEN AUKEY E BUSINESS CO., LTD",
+            Self::TertiumTechnology => "Tertium Technology",
+            Self::MiridiaTechnologyIncorporated => "Miridia Technology Incorporated",
+            Self::PointrLabsLimited => "Pointr Labs Limited",
+            Self::Wares => "WARES",
+            Self::InovonicsCorp => "Inovonics Corp",
+            Self::NomeOy => "Nome Oy",
+            Self::KeYes => "KEYes",
+            Self::AdataTechnologyCoLtd => "ADATA Technology Co., LTD.",
+            Self::NovelBitsLlc => "Novel Bits, LLC",
+            Self::VirscientLimited => "Virscient Limited",
+            Self::SensoviumInc => "Sensovium Inc.",
+            Self::EstomInfotechKft => "ESTOM Infotech Kft.",
+            Self::BetternotstealmybikeUgWithLimitedLiability => {
+                "betternotstealmybike UG (with limited liability)"
+            }
+            Self::ZimiCorporation => "ZIMI CORPORATION",
+            Self::Ifly => "ifly",
+            Self::TgmTechnologyCoLtd => "TGM TECHNOLOGY CO., LTD.",
+            Self::JtInnovationsLimited => "JT INNOVATIONS LIMITED",
+            Self::NubiaTechnologyCoLtd => "Nubia Technology Co.,Ltd.",
+            Self::NoventaAg => "Noventa AG",
+            Self::LiteboxerTechnologiesInc => "Liteboxer Technologies Inc.",
+            Self::MonadnockSystemsLtd => "Monadnock Systems Ltd.",
+            Self::IntegraOpticsInc => "Integra Optics Inc",
+            Self::JWagnerGmbH => "J. Wagner GmbH",
+            Self::CellAssistLlc => "CellAssist, LLC",
+            Self::ChipoloDOO => "CHIPOLO d.o.o.",
+            Self::LindinventAb => "Lindinvent AB",
+            Self::RaydenEarthLtd => "Rayden.Earth LTD",
+            Self::AccentAdvancedSystemsSlu => "Accent Advanced Systems SLU",
+            Self::SircCoLtd => "SIRC Co., Ltd.",
+            Self::UbisysTechnologiesGmbH => "ubisys technologies GmbH",
+            Self::Bf1SystemsLimited => "bf1systems limited",
+            Self::PrevaylLimited => "Prevayl Limited",
+            Self::TokaiRikaCoLtd => "Tokai-rika co.,ltd.",
+            Self::HyperIceInc => "HYPER ICE, INC.",
+            Self::UShinLtd => "U-Shin Ltd.",
+            Self::CheckTechnologySolutionsLlc => "Check Technology Solutions LLC",
+            Self::AbbInc => "ABB Inc",
+            Self::BoehringerIngelheimVetmedicaGmbH => "Boehringer Ingelheim Vetmedica GmbH",
+            Self::TransferFi => "TransferFi",
+            Self::SengledCoLtd => "Sengled Co., Ltd.",
+            Self::IoniqSkincareGmbHAndCoKg => "IONIQ Skincare GmbH & Co. KG",
+            Self::PfSchweisstechnologieGmbh => "PF SCHWEISSTECHNOLOGIE GMBH",
+            Self::CoreVisionBv => "CORE|vision BV",
+            Self::TrivediAdvancedTechnologiesLlc => "Trivedi Advanced Technologies LLC",
+            Self::PolideaSpZOO => "Polidea Sp. z o.o.",
+            Self::MoticonReGoAg => "Moticon ReGo AG",
+            Self::KayamaticsLimited => "Kayamatics Limited",
+            Self::TopreCorporation => "Topre Corporation",
+            Self::CoburnTechnologyLlc => "Coburn Technology, LLC",
+            Self::SzDjiTechnologyCoLtd => "SZ DJI TECHNOLOGY CO.,LTD",
+            Self::FraunhoferIis => "Fraunhofer IIS",
+            Self::ShanghaiKfcubeInc => "Shanghai Kfcube Inc",
+            Self::Tgr1618Limited => "TGR 1.618 Limited",
+            Self::IntelligenceworksInc => "Intelligenceworks Inc.",
+            Self::UmehealLtd => "UMEHEAL Ltd",
+            Self::RealmeChongqingMobileTelecommunicationsCorpLtd => {
+                "Realme Chongqing Mobile Telecommunications Corp., Ltd."
+            }
+            Self::HoffmannSe => "Hoffmann SE",
+            Self::EpicSystemsCoLtd => "Epic Systems Co., Ltd.",
+            Self::ExeoTechCorporation => "EXEO TECH CORPORATION",
+            Self::AclaraTechnologiesLlc => "Aclara Technologies LLC",
+            Self::WitschiElectronicLtd => "Witschi Electronic Ltd",
+            Self::ISensInc => "i-SENS, inc.",
+            Self::JJADEEnterpriseLlc => "J-J.A.D.E. Enterprise LLC",
+            Self::EmbeddedDevicesCoCompany => "Embedded Devices Co. Company",
+            Self::SauconTechnologies => "Saucon Technologies",
+            Self::PrivateLimitedCompanyTeltonika => "Private limited company \"Teltonika\"",
+            Self::SfsUnimarketAg => "SFS unimarket AG",
+            Self::SensiboInc => "Sensibo, Inc.",
+            Self::CurrentLightingSolutionsLlc => "Current Lighting Solutions LLC",
+            Self::NokianRenkaatOyj => "Nokian Renkaat Oyj",
+            Self::GimerMedical => "Gimer medical",
+            Self::Epifit => "EPIFIT",
+            Self::MaytronicsLtd => "Maytronics Ltd",
+            Self::IngenieurbueroBirnfeldUgHaftungsbeschraenkt => {
+                "Ingenieurbuero Birnfeld UG (haftungsbeschraenkt)"
+            }
+            Self::SmartWirelessGmbHAndCoKg => "SmartWireless GmbH & Co. KG",
+            Self::NichieiIntecCoLtd => "NICHIEI INTEC CO., LTD.",
+            Self::TaitInternationalLimited => "Tait International Limited",
+            Self::GigaTmsInc => "GIGA-TMS INC",
+            Self::SolitonSystemsKK => "Soliton Systems K.K.",
+            Self::GbSolutionCoLtd => "GB Solution co.,Ltd",
+            Self::TricorderArraayTechnologiesLlc => "Tricorder Arraay Technologies LLC",
+            Self::SclakSRL => "sclak s.r.l.",
+            Self::Xanthio => "XANTHIO",
+            Self::EnPointeFencingPtyLtd => "EnPointe Fencing Pty Ltd",
+            Self::HydroGearLimitedPartnership => "Hydro-Gear Limited Partnership",
+            Self::MovellaTechnologiesBV => "Movella Technologies B.V.",
+            Self::LevolorInc => "LEVOLOR INC",
+            Self::ControlidIndustriaComercioDeHardwareEServicosDeTecnologiaLtda => {
+                "Controlid Industria, Comercio de Hardware e Servicos de Tecnologia Ltda"
+            }
+            Self::WintersteigerAg => "Wintersteiger AG",
+            Self::PsyonicInc => "PSYONIC, Inc.",
+            Self::Optalert => "Optalert",
+            Self::ImagiLabsAb => "imagiLabs AB",
+            Self::PhillipsConnectTechnologiesLlc => "Phillips Connect Technologies LLC",
+            Self::_1BarNetLimited => "1bar.net Limited",
+            Self::KonftelAb => "Konftel AB",
+            Self::CrosscanGmbH => "Crosscan GmbH",
+            Self::Bystamp => "BYSTAMP",
+            Self::ZrfLlc => "ZRF, LLC",
+            Self::MizunoCorporation => "MIZUNO Corporation",
+            Self::TheChamberlainGroupInc => "The Chamberlain Group, Inc.",
+            Self::TomeInc => "Tome, Inc.",
+            Self::SmartResQApS => "SmartResQ ApS",
+            Self::BernerInternationalLlc => "Berner International LLC",
+            Self::TreegreenLimited => "Treegreen Limited",
+            Self::InnophaseIncorporated => "Innophase Incorporated",
+            Self::_11HealthAndTechnologiesLimited => "11 Health & Technologies Limited",
+            Self::DensionElektronikaiKft => "Dension Elektronikai Kft.",
+            Self::WyzeLabsInc => "Wyze Labs, Inc",
+            Self::TrackunitAS => "Trackunit A/S",
+            Self::VorwerkElektrowerkeGmbHAndCoKg => "Vorwerk Elektrowerke GmbH & Co. KG",
+            Self::BiometrikaDOO => "Biometrika d.o.o.",
+            Self::RevvoTechnologiesInc => "Revvo Technologies, Inc.",
+            Self::PacificTrackLlc => "Pacific Track, LLC",
+            Self::OdicIncorporated => "Odic Incorporated",
+            Self::EvvaSicherheitstechnologieGmbH => "EVVA Sicherheitstechnologie GmbH",
+            Self::WiOsenseGmbHAndCoKg => "WIOsense GmbH & Co. KG",
+            Self::WesternDigitalTechologiesInc => "Western Digital Techologies, Inc.",
+            Self::LaonzCoLtd => "LAONZ Co.,Ltd",
+            Self::EmergencyLightingProductsLimited => "Emergency Lighting Products Limited",
+            Self::Rafaelmicro => "Rafaelmicro",
+            Self::YoTronicsTechnologyCoLtd => "Yo-tronics Technology Co., Ltd.",
+            Self::SmartDrive => "SmartDrive",
+            Self::SmartSensorLabsLtd => "SmartSensor Labs Ltd",
+            Self::AlflexProductsBV => "Alflex Products B.V.",
+            Self::CompegpsTeamSociedadLimitada => "COMPEGPS TEAM,SOCIEDAD LIMITADA",
+            Self::KrogSystemsLlc => "Krog Systems LLC",
+            Self::GuilinZhishenInformationTechnologyCoLtd => {
+                "Guilin Zhishen Information Technology Co.,Ltd."
+            }
+            Self::AcosCoLtd => "ACOS CO.,LTD.",
+            Self::NisshinboMicroDevicesInc => "Nisshinbo Micro Devices Inc.",
+            Self::Dakatech => "DAKATECH",
+            Self::BlueUp => "BlueUp",
+            Self::Soundboks => "SOUNDBOKS",
+            Self::ParsylInc => "Parsyl Inc",
+            Self::CanopyGrowthCorporation0856 => "Canopy Growth Corporation",
+            Self::HeliosSportsInc => "Helios Sports, Inc.",
+            Self::TapSoundSystem => "Tap Sound System",
+            Self::PektronGroupLimited => "Pektron Group Limited",
+            Self::CognososInc => "Cognosos, Inc.",
+            Self::SubecaInc => "Subeca, Inc.",
+            Self::YealinkXiamenNetworkTechnologyCoLtd => {
+                "Yealink (Xiamen) Network Technology Co.,LTD"
+            }
+            Self::EmbeddedFitnessBV => "Embedded Fitness B.V.",
+            Self::CarolColeCompany => "Carol Cole Company",
+            Self::SafePort => "SafePort",
+            Self::OrsoInc => "ORSO Inc.",
+            Self::BiotechwareSrl => "Biotechware SRL",
+            Self::Arcom => "ARCOM",
+            Self::DoppleTechnologiesBV => "Dopple Technologies B.V.",
+            Self::JujuJointsCanadaCorp => "JUJU JOINTS CANADA CORP.",
+            Self::DnanudgeLimited => "DNANUDGE LIMITED",
+            Self::USoundGmbH => "USound GmbH",
+            Self::DometicCorporation => "Dometic Corporation",
+            Self::PepperlFuchsGmbH => "Pepperl + Fuchs GmbH",
+            Self::FragranceDeliveryTechnologiesLtd => "FRAGRANCE DELIVERY TECHNOLOGIES LTD",
+            Self::TangshanHongJiaElectronicTechnologyCoLtd => {
+                "Tangshan HongJia electronic technology co., LTD."
+            }
+            Self::GeneralLuminaireShanghaiCoLtd => "General Luminaire (Shanghai) Co., Ltd.",
+            Self::DownRangeSystemsLlc => "Down Range Systems LLC",
+            Self::DLinkCorp => "D-Link Corp.",
+            Self::ZorachkaLtd => "Zorachka LTD",
+            Self::TokenizeInc => "Tokenize, Inc.",
+            Self::BeerTechLtd => "BeerTech LTD",
+            Self::PiaggioFastForward => "Piaggio Fast Forward",
+            Self::BpwBergischeAchsenKommanditgesellschaft => {
+                "BPW Bergische Achsen Kommanditgesellschaft"
+            }
+            Self::APuissance3 => "A puissance 3",
+            Self::EtymoticResearchInc => "Etymotic Research, Inc.",
+            Self::VivoMobileCommunicationCoLtd => "vivo Mobile Communication Co., Ltd.",
+            Self::BitwardsOy => "Bitwards Oy",
+            Self::CanopyGrowthCorporation0835 => "Canopy Growth Corporation",
+            Self::RikenKeikiCoLtd => "RIKEN KEIKI CO., LTD.,",
+            Self::ConneqtechBV => "Conneqtech B.V.",
+            Self::IntermotiveInc => "Intermotive,Inc.",
+            Self::FoxbleLlc => "Foxble, LLC",
+            Self::CoreHealthAndFitnessLlc => "Core Health and Fitness LLC",
+            Self::BlippitAb => "Blippit AB",
+            Self::AbbSPA => "ABB S.p.A.",
+            Self::IncusPerformanceLtd => "INCUS PERFORMANCE LTD.",
+            Self::IngicsTechnologyCoLtd => "INGICS TECHNOLOGY CO., LTD.",
+            Self::ShenzhenFitcareElectronicsCoLtd => "shenzhen fitcare electronics Co.,Ltd",
+            Self::MitutoyoCorporation => "Mitutoyo Corporation",
+            Self::HexagonMetrologyDivisionRomer => "HEXAGON METROLOGY DIVISION ROMER",
+            Self::ShanghaiSuishengInformationTechnologyCoLtd => {
+                "Shanghai Suisheng Information Technology Co., Ltd."
+            }
+            Self::Kickmaker => "Kickmaker",
+            Self::HyundaiMotorCompany => "Hyundai Motor Company",
+            Self::CmePteLtd => "CME PTE. LTD.",
+            Self::_8PowerLimited => "8Power Limited",
+            Self::NexiteLtd => "Nexite Ltd",
+            Self::AdafruitIndustries => "adafruit industries",
+            Self::InovaGeophysicalInc => "INOVA Geophysical, Inc.",
+            Self::BrilliantHomeTechnologyInc => "Brilliant Home Technology, Inc.",
+            Self::ESenseLabLtd => "eSenseLab LTD",
+            Self::INformTechnologyGmbH => "iNFORM Technology GmbH",
+            Self::PotrykusHoldingsAndDevelopmentLlc => "Potrykus Holdings and Development LLC",
+            Self::BobrickWashroomEquipmentInc => "Bobrick Washroom Equipment, Inc.",
+            Self::Dim3 => "DIM3",
+            Self::ShenzhenConex => "Shenzhen Conex",
+            Self::HunterDouglasInc => "Hunter Douglas Inc",
+            Self::TatwahSa => "tatwah SA",
+            Self::WangsAllianceCorporation => "Wangs Alliance Corporation",
+            Self::SpicaSystemsLlc => "SPICA SYSTEMS LLC",
+            Self::SkcInc => "SKC Inc",
+            Self::OssurHf => "Ossur hf.",
+            Self::FlextronicsInternationalUsaInc => "Flextronics International USA Inc.",
+            Self::MstreamTechnologiesInc => "Mstream Technologies., Inc.",
+            Self::BeckerAntriebeGmbH => "Becker Antriebe GmbH",
+            Self::LecoCorporation => "LECO Corporation",
+            Self::ParadoxEngineeringSa => "Paradox Engineering SA",
+            Self::TattcomLlc => "TATTCOM LLC",
+            Self::AzbilCo => "Azbil Co.",
+            Self::IngyBV => "Ingy B.V.",
+            Self::NanoleafCanadaLimited => "Nanoleaf Canada Limited",
+            Self::Altaneos => "Altaneos",
+            Self::TrulliAudio => "Trulli Audio",
+            Self::SistemasKernSociedadAnómina => "SISTEMAS KERN, SOCIEDAD ANÓMINA",
+            Self::EcdElectronicComponentsGmbHDresden => "ECD Electronic Components GmbH Dresden",
+            Self::TyriSwedenAb => "TYRI Sweden AB",
+            Self::UrbanmindedLtd => "Urbanminded Ltd",
+            Self::AndonHealthCoLtd => "Andon Health Co.,Ltd",
+            Self::DomintellSA => "Domintell s.a.",
+            Self::NantSoundInc => "NantSound, Inc.",
+            Self::CronusElectronicsLtd => "CRONUS ELECTRONICS LTD",
+            Self::Optek => "Optek",
+            Self::MaxonMotorLtd => "maxon motor ltd.",
+            Self::Birota => "BIROTA",
+            Self::JskCoLtd => "JSK CO., LTD.",
+            Self::RenaultSa => "Renault SA",
+            Self::AccessCoLtd => "Access Co., Ltd",
+            Self::KlipschGroupInc => "Klipsch Group, Inc.",
+            Self::DirectCommunicationSolutionsInc => "Direct Communication Solutions, Inc.",
+            Self::QuipNycInc => "quip NYC Inc.",
+            Self::CesarSystemsLtd => "Cesar Systems Ltd.",
+            Self::ShenzhenTonliScienceAndTechnologyDevelopmentCoLtd => {
+                "Shenzhen TonliScience and Technology Development Co.,Ltd"
+            }
+            Self::BytonNorthAmericaCorporation => "Byton North America Corporation",
+            Self::MedirlabOrvosbiologiaiFejlesztoKorlatoltFelelosseguTarsasag => {
+                "MEDIRLAB Orvosbiologiai Fejleszto Korlatolt Felelossegu Tarsasag"
+            }
+            Self::DigisineEnergytechCoLtd => "DIGISINE ENERGYTECH CO. LTD.",
+            Self::SereneGroupInc => "SERENE GROUP, INC",
+            Self::ZimiInnovationsPtyLtd => "Zimi Innovations Pty Ltd",
+            Self::EMoolaComPtyLtd => "e-moola.com Pty Ltd",
+            Self::AktiebolagetSandvikCoromant => "Aktiebolaget Sandvik Coromant",
+            Self::KidzTekLlc => "KidzTek LLC",
+            Self::JouleIqInc => "Joule IQ, INC.",
+            Self::FrecceLlc => "Frecce LLC",
+            Self::NovabaseSRL => "NOVABASE S.R.L.",
+            Self::ShapeLogInc => "ShapeLog, Inc.",
+            Self::HafeleGmbHAndCoKg => "Häfele GmbH & Co KG",
+            Self::PacketcraftInc => "Packetcraft, Inc.",
+            Self::KomfortIqInc => "Komfort IQ, Inc.",
+            Self::WaybeyondLimited => "Waybeyond Limited",
+            Self::MinutInc => "Minut, Inc.",
+            Self::GeeksmeSL => "Geeksme S.L.",
+            Self::AirohaTechnologyCorp07E3 => "Airoha Technology Corp.",
+            Self::AlfredKaercherSeAndCoKg => "Alfred Kaercher SE & Co. KG",
+            Self::LucieLabs => "Lucie Labs",
+            Self::EdifierInternationalLimited => "Edifier International Limited",
+            Self::SnapOnIncorporated => "Snap-on Incorporated",
+            Self::UnlimitedEngineeringSl => "Unlimited Engineering SL",
+            Self::LinearCircuits => "Linear Circuits",
+            Self::ThingOsGmbHAndCoKg => "ThingOS GmbH & Co KG",
+            Self::RemedeeLabs => "Remedee Labs",
+            Self::StarliteCoLtd => "STARLITE Co., Ltd.",
+            Self::MicroDesignInc => "Micro-Design, Inc.",
+            Self::SolutionsAmbraInc => "SOLUTIONS AMBRA INC.",
+            Self::NanjingQinhengMicroelectronicsCoLtd => {
+                "Nanjing Qinheng Microelectronics Co., Ltd"
+            }
+            Self::EcobeeInc => "ecobee Inc.",
+            Self::HootsClassicGmbH => "hoots classic GmbH",
+            Self::KanoComputingLimited => "Kano Computing Limited",
+            Self::LivnexCoLtd => "LIVNEX Co.,Ltd.",
+            Self::ReactAccessibilityLimited => "React Accessibility Limited",
+            Self::ShanghaiPanchipMicroelectronicsCoLtd => {
+                "Shanghai Panchip Microelectronics Co., Ltd"
+            }
+            Self::HangzhouTuyaInformationTechnologyCoLtd => {
+                "Hangzhou Tuya Information  Technology Co., Ltd"
+            }
+            Self::NeoSensoryInc => "NeoSensory, Inc.",
+            Self::ShanghaiTopChipMicroelectronicsTechCoLtd => {
+                "Shanghai Top-Chip Microelectronics Tech. Co., LTD"
+            }
+            Self::SmartWaveTechnologiesCanadaInc => "Smart Wave Technologies Canada Inc",
+            Self::BarnacleSystemsInc => "Barnacle Systems Inc.",
+            Self::WestPharmaceuticalServicesInc => "West Pharmaceutical Services, Inc.",
+            Self::ModulSystemHhAb => "Modul-System HH AB",
+            Self::SkullcandyInc => "Skullcandy, Inc.",
+            Self::WrldsCreationsAb => "WRLDS Creations AB",
+            Self::IaconicDesignInc => "iaconicDesign Inc.",
+            Self::BlueneticsGmbH => "Bluenetics GmbH",
+            Self::JuneLifeInc => "June Life, Inc.",
+            Self::JohnsonHealthTechNa => "Johnson Health Tech NA",
+            Self::CimTechniquesInc => "CIMTechniques, Inc.",
+            Self::RadinnAb => "Radinn AB",
+            Self::AWChestertonCompany => "A.W. Chesterton Company",
+            Self::BiralAg => "Biral AG",
+            Self::RegulaLtd => "REGULA Ltd.",
+            Self::AxentiaTechnologiesAb => "Axentia Technologies AB",
+            Self::GenedriveDiagnosticsLtd => "Genedrive Diagnostics Ltd",
+            Self::KdCircuitsLlc => "KD CIRCUITS LLC",
+            Self::EpicSRL => "EPIC S.R.L.",
+            Self::BatteryBizInc => "Battery-Biz Inc.",
+            Self::EponaBiotecLimited => "Epona Biotec Limited",
+            Self::ISwip => "iSwip",
+            Self::EtablissementsGeorgesRenault => "ETABLISSEMENTS GEORGES RENAULT",
+            Self::SoundbrennerLimited => "Soundbrenner Limited",
+            Self::CronoChipSL => "CRONO CHIP, S.L.",
+            Self::HormannKgAntriebstechnik => "Hormann KG Antriebstechnik",
+            Self::_2NTelekomunikaceAS => "2N TELEKOMUNIKACE a.s.",
+            Self::MoecoIotInc => "Moeco IOT Inc.",
+            Self::ThomasDynamicsLlc => "Thomas Dynamics, LLC",
+            Self::GvConceptsInc => "GV Concepts Inc.",
+            Self::HongKongBouffaloLabLimited => "Hong Kong Bouffalo Lab Limited",
+            Self::AureaSolucoesTecnologicasLtda => "Aurea Solucoes Tecnologicas Ltda.",
+            Self::NewH3CTechnologiesCoLtd => "New H3C Technologies Co.,Ltd",
+            Self::LoupeDeckOy => "LoupeDeck Oy",
+            Self::GraniteRiverSolutionsInc => "Granite River Solutions, Inc.",
+            Self::TheKrogerCo => "The Kroger Co.",
+            Self::BruelAndKjaerSoundAndVibration => "Bruel & Kjaer Sound & Vibration",
+            Self::ConbeeGmbH => "conbee GmbH",
+            Self::ZumeInc => "Zume, Inc.",
+            Self::MusenConnectInc => "Musen Connect, Inc.",
+            Self::RabLightingInc => "RAB Lighting, Inc.",
+            Self::XiamenMageInformationTechnologyCoLtd => {
+                "Xiamen Mage Information Technology Co., Ltd."
+            }
+            Self::ComcastCable => "Comcast Cable",
+            Self::RokuInc => "Roku, Inc.",
+            Self::ApolloNeuroscienceInc => "Apollo Neuroscience, Inc.",
+            Self::RegentBeleuchtungskorperAg => "Regent Beleuchtungskorper AG",
+            Self::PuneScientificLlp => "Pune Scientific LLP",
+            Self::SmartloxxGmbH => "Smartloxx GmbH",
+            Self::DigibalePtyLtd => "Digibale Pty Ltd",
+            Self::SkyUkLimited => "Sky UK Limited",
+            Self::CstElectronicsProprietaryLimited => "CST ELECTRONICS (PROPRIETARY) LIMITED",
+            Self::GuangDongOppoMobileTelecommunicationsCorpLtd => {
+                "GuangDong Oppo Mobile Telecommunications Corp., Ltd."
+            }
+            Self::PlantChoirInc => "PlantChoir Inc.",
+            Self::HoloKitInc => "HoloKit, Inc.",
+            Self::WaterIDGmbH => "Water-i.d. GmbH",
+            Self::StarLeafLtd => "StarLeaf Ltd",
+            Self::GastecCorporation => "GASTEC CORPORATION",
+            Self::TheCocaColaCompany => "The Coca-Cola Company",
+            Self::AevSpolSRO => "AEV spol. s r.o.",
+            Self::CricutInc => "Cricut, Inc.",
+            Self::ScoscheIndustriesInc => "Scosche Industries, Inc.",
+            Self::KompanAS => "KOMPAN A/S",
+            Self::HannaInstrumentsInc => "Hanna Instruments, Inc.",
+            Self::FujimicNiigataInc => "FUJIMIC NIIGATA, INC.",
+            Self::CybexGmbH => "Cybex GmbH",
+            Self::MinibrewHoldingBV => "MINIBREW HOLDING B.V",
+            Self::OptikamTechInc => "Optikam Tech Inc.",
+            Self::TheWildflowerFoundation => "The Wildflower Foundation",
+            Self::PcbPiezotronicsInc => "PCB Piezotronics, Inc.",
+            Self::BubblyNetLlc => "BubblyNet, LLC",
+            Self::PangaeaSolution => "Pangaea Solution",
+            Self::HlpControlsPtyLimited => "HLP Controls Pty Limited",
+            Self::O2MicroInc => "O2 Micro, Inc.",
+            Self::AudifonGmbHAndCoKg => "audifon GmbH & Co. KG",
+            Self::EsemberLimitedLiabilityCompany => "ESEMBER LIMITED LIABILITY COMPANY",
+            Self::DeviceDriveAs => "DeviceDrive AS",
+            Self::QingpingTechnologyBeijingCoLtd => "Qingping Technology (Beijing) Co., Ltd.",
+            Self::FinchTechnologiesLtd => "Finch Technologies Ltd.",
+            Self::GlenviewSoftwareCorporation => "Glenview Software Corporation",
+            Self::SparkageInc => "Sparkage Inc.",
+            Self::SensoritySRO => "Sensority, s.r.o.",
+            Self::RadiusCoLtd => "radius co., ltd.",
+            Self::AmaterZInc => "AmaterZ, Inc.",
+            Self::NiruhaSystemsPrivateLimited => "Niruha Systems Private Limited",
+            Self::LoopshoreOy => "Loopshore Oy",
+            Self::KoamtacInc => "KOAMTAC INC.",
+            Self::Cue => "Cue",
+            Self::CyberTransportControlGmbH => "Cyber Transport Control GmbH",
+            Self::_4EBusinessGmbH => "4eBusiness GmbH",
+            Self::CMaxAsiaLimited => "C-MAX Asia Limited",
+            Self::EchoflexSolutionsInc => "Echoflex Solutions Inc.",
+            Self::ThirdwayvInc => "Thirdwayv Inc.",
+            Self::CorvexConnectedSafety => "Corvex Connected Safety",
+            Self::InnoConMedicalApS => "InnoCon Medical ApS",
+            Self::SuccessfulEndeavoursPtyLtd => "Successful Endeavours Pty Ltd",
+            Self::WuQiTechnologiesInc => "WuQi technologies, Inc.",
+            Self::GraesslinGmbH => "Graesslin GmbH",
+            Self::NoodleTechnologyInc => "Noodle Technology inc",
+            Self::EngineeredMedicalTechnologies => "Engineered Medical Technologies",
+            Self::DmacMobileDevelopmentsLlc => "Dmac Mobile Developments, LLC",
+            Self::ForceImpactTechnologies => "Force Impact Technologies",
+            Self::PelotonInteractiveInc => "Peloton Interactive Inc.",
+            Self::NittoDenkoAsiaTechnicalCentrePteLtd => {
+                "NITTO DENKO ASIA TECHNICAL CENTRE PTE. LTD."
+            }
+            Self::ArtAndProgramInc => "ART AND PROGRAM, INC.",
+            Self::VoxxInternational => "Voxx International",
+            Self::WwznInformationTechnologyCompanyLimited => {
+                "WWZN Information Technology Company Limited"
+            }
+            Self::PikolinSL => "PIKOLIN S.L.",
+            Self::TerOptaLtd => "TerOpta Ltd",
+            Self::MantisTechLlc => "Mantis Tech LLC",
+            Self::VimarSpA => "Vimar SpA",
+            Self::RemoteSolutionCoLtd => "Remote Solution Co., LTD.",
+            Self::KaterraInc => "Katerra Inc.",
+            Self::RhombusSystemsInc => "RHOMBUS SYSTEMS, INC.",
+            Self::AntitronicsInc => "Antitronics Inc.",
+            Self::SmartSensorDevicesAb => "Smart Sensor Devices AB",
+            Self::HarmanCoLtd => "HARMAN CO.,LTD.",
+            Self::ShanghaiInGeekCyberSecurityCoLtd => "Shanghai InGeek Cyber Security Co., Ltd.",
+            Self::UmanSenseAb => "umanSense AB",
+            Self::ElaInnovation => "ELA Innovation",
+            Self::LumensForLessInc => "Lumens For Less, Inc",
+            Self::BrotherIndustriesLtd => "Brother Industries, Ltd",
+            Self::MichaelParkin => "Michael Parkin",
+            Self::JlgIndustriesInc => "JLG Industries, Inc.",
+            Self::ElatecGmbH => "Elatec GmbH",
+            Self::ChangshaJemoIcDesignCoLtd => "Changsha JEMO IC Design Co.,Ltd",
+            Self::HamiltonProfessionalServicesOfCanadaIncorporated => {
+                "Hamilton Professional Services of Canada Incorporated"
+            }
+            Self::MediatechSRL => "MEDIATECH S.R.L.",
+            Self::EagleDetectionSa => "EAGLE DETECTION SA",
+            Self::AmtechSystemsLlc => "Amtech Systems, LLC",
+            Self::IopoolSA => "iopool s.a.",
+            Self::SarvavidSoftwareSolutionsLlp => "Sarvavid Software Solutions LLP",
+            Self::IllusoryStudiosLlc => "Illusory Studios LLC",
+            Self::DiaodiaoBeijingTechnologyCoLtd => "DIAODIAO (Beijing) Technology Co., Ltd.",
+            Self::GuangZhouKuGouComputerTechnologyCoLtd => {
+                "GuangZhou KuGou Computer Technology Co.Ltd"
+            }
+            Self::OrTechnologiesPtyLtd => "OR Technologies Pty Ltd",
+            Self::SeitecElektronikGmbH => "Seitec Elektronik GmbH",
+            Self::WiznovaInc => "WIZNOVA, Inc.",
+            Self::Socomec => "SOCOMEC",
+            Self::Sanofi => "Sanofi",
+            Self::DmlLlc => "DML LLC",
+            Self::MacSrl => "MAC SRL",
+            Self::HitiqLimited => "HITIQ LIMITED",
+            Self::BeijingUnisocTechnologiesCoLtd => "Beijing Unisoc Technologies Co., Ltd.",
+            Self::BluepackSRL => "Bluepack S.R.L.",
+            Self::BeijingHaoHengTianTechCoLtd => "Beijing Hao Heng Tian Tech Co., Ltd.",
+            Self::AcubitApS => "Acubit ApS",
+            Self::FantiniCosmiSPA => "Fantini Cosmi s.p.a.",
+            Self::ChandlerSystemsInc => "Chandler Systems Inc.",
+            Self::JiangsuQinhengCoLtd => "Jiangsu Qinheng Co., Ltd.",
+            Self::GlassSecurityPteLtd => "Glass Security Pte Ltd",
+            Self::LlcNavitek => "LLC Navitek",
+            Self::LunaXioInc => "Luna XIO, Inc.",
+            Self::UpRightTechnologiesLtd => "UpRight Technologies LTD",
+            Self::DiUsComputingPtyLtd => "DiUS Computing Pty Ltd",
+            Self::IguanavationInc => "Iguanavation, Inc.",
+            Self::DairyTechLlc => "Dairy Tech, LLC",
+            Self::AblicInc => "ABLIC Inc.",
+            Self::WildlifeAcousticsInc => "Wildlife Acoustics, Inc.",
+            Self::OnePlusElectronicsShenzhenCoLtd => "OnePlus Electronics (Shenzhen) Co., Ltd.",
+            Self::OpenPlatformSystemsLlc => "Open Platform Systems LLC",
+            Self::SaferaOy => "Safera Oy",
+            Self::GwaHygieneGmbH => "GWA Hygiene GmbH",
+            Self::BitkeyInc => "Bitkey Inc.",
+            Self::JmrEmbeddedSystemsGmbH => "JMR embedded systems GmbH",
+            Self::SwaraLinkTechnologies => "SwaraLink Technologies",
+            Self::EliLillyAndCompany => "Eli Lilly and Company",
+            Self::StalkitAs => "STALKIT AS",
+            Self::PhcCorporation => "PHC Corporation",
+            Self::TedeeSpZOO => "Tedee Sp. z o.o.",
+            Self::GuangzhouSuperSoundInformationTechnologyCoLtd => {
+                "Guangzhou SuperSound Information Technology Co.,Ltd"
+            }
+            Self::FordMotorCompany => "Ford Motor Company",
+            Self::XiamenEholderElectronicsCoLtd => "Xiamen Eholder Electronics Co.Ltd",
+            Self::CloverNetworkInc => "Clover Network, Inc.",
+            Self::OculeveInc => "Oculeve, Inc.",
+            Self::DongguanLieshengElectronicCoLtd => "Dongguan Liesheng Electronic Co.Ltd",
+            Self::DongguanHeleElectronicsCoLtd => "DONGGUAN HELE ELECTRONICS CO., LTD",
+            Self::ExoTicSystems => "exoTIC Systems",
+            Self::F5SportsInc => "F5 Sports, Inc",
+            Self::Precor => "Precor",
+            Self::RevsmartWearableHkCoLtd => "REVSMART WEARABLE HK CO LTD",
+            Self::CoreiotPtyLtd => "COREIOT PTY LTD",
+            Self::IdibaixEnginneering => "IDIBAIX enginneering",
+            Self::IQsquareBv => "iQsquare BV",
+            Self::Altonics => "Altonics",
+            Self::MbarcLabsInc => "MBARC LABS Inc",
+            Self::MindPeaceSafetyLlc => "MindPeace Safety LLC",
+            Self::RespiriLimited => "Respiri Limited",
+            Self::BullGroupCompanyLimited => "Bull Group Company Limited",
+            Self::AbaxAs => "ABAX AS",
+            Self::AudiodoAb => "Audiodo AB",
+            Self::CaliforniaThingsInc => "California Things Inc.",
+            Self::FiveCoSarl => "FiveCo Sarl",
+            Self::SmartSnuggPtyLtd => "SmartSnugg Pty Ltd",
+            Self::BeijingWinnerMicroelectronicsCoLtd => "Beijing Winner Microelectronics Co.,Ltd",
+            Self::ElementProductsInc => "Element Products, Inc.",
+            Self::HufHulsbeckAndFurstGmbHAndCoKg => "Huf Hülsbeck & Fürst GmbH & Co. KG",
+            Self::CarewearCorp => "Carewear Corp.",
+            Self::BeInteractiveCoLtd => "Be Interactive Co., Ltd",
+            Self::EssityHygieneAndHealthAktiebolag => "Essity Hygiene and Health Aktiebolag",
+            Self::WernherVonBraunCenterForASdvancedResearch => {
+                "Wernher von Braun Center for ASdvanced Research"
+            }
+            Self::AbElectrolux => "AB Electrolux",
+            Self::JbxDesignsInc => "JBX Designs Inc.",
+            Self::BeijingJingdongCenturyTradingCoLtd => {
+                "Beijing Jingdong Century Trading Co., Ltd."
+            }
+            Self::AkcijuSabiedribaSafTehnika => "Akciju sabiedriba \"SAF TEHNIKA\"",
+            Self::PafersTech => "PAFERS TECH",
+            Self::TraqFreqLlc => "TraqFreq LLC",
+            Self::RedpineSignalsInc => "Redpine Signals Inc",
+            Self::MahrGmbH => "Mahr GmbH",
+            Self::EssEmbeddedSystemSolutionsInc => "ESS Embedded System Solutions Inc.",
+            Self::TomCommunicationIndustrialCoLtd => "Tom Communication Industrial Co.,Ltd.",
+            Self::SartoriusAg => "Sartorius AG",
+            Self::EnequiAb => "Enequi AB",
+            Self::HappybrushGmbH => "happybrush GmbH",
+            Self::BodyPlusTechnologyCoLtd => "BodyPlus Technology Co.,Ltd",
+            Self::WilkaSchliesstechnikGmbH => "WILKA Schliesstechnik GmbH",
+            Self::VituloPlusBv => "Vitulo Plus BV",
+            Self::VigilTechnologiesInc => "Vigil Technologies Inc.",
+            Self::ToucheTechnologyLtd => "Touché Technology Ltd",
+            Self::AlfredInternationalInc => "Alfred International Inc.",
+            Self::TrapperDataAb => "Trapper Data AB",
+            Self::ShibutaniCoLtd => "Shibutani Co., Ltd.",
+            Self::ChargyTechnologiesSl => "Chargy Technologies, SL",
+            Self::AlcareCoLtd => "ALCARE Co., Ltd.",
+            Self::AvantisSystemsLimited => "Avantis Systems Limited",
+            Self::JNeadesLtd => "J Neades Ltd",
+            Self::Sigur => "Sigur",
+            Self::HoustonRadarLlc => "Houston Radar LLC",
+            Self::SafeLineSwedenAb => "SafeLine Sweden AB",
+            Self::ZmartfunElectronicsInc => "Zmartfun Electronics, Inc.",
+            Self::AlmendoTechnologiesGmbH => "Almendo Technologies GmbH",
+            Self::VeluxAS => "VELUX A/S",
+            Self::NihonDengyoKousaku => "NIHON DENGYO KOUSAKU",
+            Self::OptexCoLtd => "OPTEX CO.,LTD.",
+            Self::Aluna => "Aluna",
+            Self::SpinlockLtd => "Spinlock Ltd",
+            Self::AlangoTechnologiesLtd => "Alango Technologies Ltd",
+            Self::MilestoneAvTechnologiesLlc => "Milestone AV Technologies LLC",
+            Self::AvayaInc => "Avaya Inc.",
+            Self::HliSolutionsInc => "HLI Solutions Inc.",
+            Self::NavcastInc => "Navcast, Inc.",
+            Self::IntellithingsLtd => "Intellithings Ltd.",
+            Self::IndustrialNetworkControlsLlc => "Industrial Network Controls, LLC",
+            Self::AutomaticLabsInc => "Automatic Labs, Inc.",
+            Self::ZliideTechnologiesApS => "Zliide Technologies ApS",
+            Self::ShanghaiMountainViewSiliconCoLtd => "Shanghai Mountain View Silicon Co.,Ltd.",
+            Self::AwCompany => "AW Company",
+            Self::FubaAutomotiveElectronicsGmbH => "FUBA Automotive Electronics GmbH",
+            Self::JctHealthcarePtyLtd => "JCT Healthcare Pty Ltd",
+            Self::SensirionAg => "Sensirion AG",
+            Self::DynakodeTechnologyPrivateLimited => "DYNAKODE TECHNOLOGY PRIVATE LIMITED",
+            Self::TriTeqLockAndSecurityLlc => "TriTeq Lock and Security, LLC",
+            Self::CeoTronicsAg => "CeoTronics AG",
+            Self::MeyerSoundLaboratoriesIncorporated => "Meyer Sound Laboratories, Incorporated",
+            Self::EtekcityCorporation => "Etekcity Corporation",
+            Self::BelpartsNV => "Belparts N.V.",
+            Self::FiorAndGentz => "FIOR & GENTZ",
+            Self::DigCorporation => "DIG Corporation",
+            Self::DongguanSmartActionTechnologyCoLtd => "Dongguan SmartAction Technology Co.,Ltd.",
+            Self::DyewareLlc => "Dyeware, LLC",
+            Self::ShenzhenZhongguangInfotechTechnologyDevelopmentCoLtd => {
+                "Shenzhen Zhongguang Infotech Technology Development Co., Ltd"
+            }
+            Self::MylapsBV => "MYLAPS B.V.",
+            Self::StorzAndBickelGmbHAndCoKg => "Storz & Bickel GmbH & Co. KG",
+            Self::SomatixInc => "Somatix Inc",
+            Self::SimmTronicLimited => "Simm Tronic Limited",
+            Self::UrbanCompassInc => "Urban Compass, Inc",
+            Self::DreamLabsGmbH => "Dream Labs GmbH",
+            Self::KingIElectronicsCoLtd => "King I Electronics.Co.,Ltd",
+            Self::MeasurlogicInc => "Measurlogic Inc.",
+            Self::AlarmComHoldingsInc => "Alarm.com Holdings, Inc",
+            Self::CameSPA => "CAME S.p.A.",
+            Self::DelcomProductsInc => "Delcom Products Inc.",
+            Self::HitSeedOy => "HitSeed Oy",
+            Self::AbbOy => "ABB Oy",
+            Self::TwsSrl => "TWS Srl",
+            Self::LeaftronixAnalogicSolutionsPrivateLimited => {
+                "Leaftronix Analogic Solutions Private Limited"
+            }
+            Self::BeaconzoneLtd => "Beaconzone Ltd",
+            Self::BeflexInc => "Beflex Inc.",
+            Self::ShadeCraftInc => "ShadeCraft, Inc",
+            Self::ICognizeGmbH => "iCOGNIZE GmbH",
+            Self::SociometricSolutionsInc => "Sociometric Solutions, Inc.",
+            Self::WabilogicLtd => "Wabilogic Ltd.",
+            Self::SencilionOy => "Sencilion Oy",
+            Self::TheHablabApS => "The Hablab ApS",
+            Self::TussockInnovation2013Limited => "Tussock Innovation 2013 Limited",
+            Self::SimpliSafeInc => "SimpliSafe, Inc.",
+            Self::BrkBrandsInc => "BRK Brands, Inc.",
+            Self::ShoofTechnologies => "Shoof Technologies",
+            Self::SenseQInc => "SenseQ Inc.",
+            Self::InnovaSeaSystemsInc => "InnovaSea Systems Inc.",
+            Self::IngchipsTechnologyCoLtd => "Ingchips Technology Co., Ltd.",
+            Self::HmsIndustrialNetworksAb => "HMS Industrial Networks AB",
+            Self::ProdualOy => "Produal Oy",
+            Self::SoundmaxElectronicsLimited => "Soundmax Electronics Limited",
+            Self::GdMideaAirConditioningEquipmentCoLtd => {
+                "GD Midea Air-Conditioning Equipment Co., Ltd."
+            }
+            Self::ChipseaTechnologiesShenZhenCorp => "Chipsea Technologies (ShenZhen) Corp.",
+            Self::RoambeeCorporation => "Roambee Corporation",
+            Self::TekzitelPtyLtd => "TEKZITEL PTY LTD",
+            Self::LimnoCoLtd => "LIMNO Co. Ltd.",
+            Self::NymbusLlc => "Nymbus, LLC",
+            Self::GlobalworxGmbH => "Globalworx GmbH",
+            Self::CardoSystemsLtd => "Cardo Systems, Ltd",
+            Self::ObiqLocationTechnologyInc => "OBIQ Location Technology Inc.",
+            Self::FlowMotionTechnologiesAs => "FlowMotion Technologies AS",
+            Self::DeltaElectronicsInc => "Delta Electronics, Inc.",
+            Self::VakarosLlc => "Vakaros LLC",
+            Self::NoomiAb => "Noomi AB",
+            Self::DragonchipLimited => "Dragonchip Limited",
+            Self::AderoInc => "Adero, Inc.",
+            Self::RandomLabSas => "RandomLab SAS",
+            Self::WoodItSecurityLlc => "Wood IT Security, LLC",
+            Self::StemcoProductsInc => "Stemco Products Inc",
+            Self::GunakarPrivateLimited => "Gunakar Private Limited",
+            Self::KokiHoldingsCoLtd => "Koki Holdings Co., Ltd.",
+            Self::TAndALaboratoriesLlc => "T&A Laboratories LLC",
+            Self::HachDanaher => "Hach - Danaher",
+            Self::GeorgFischerAg => "Georg Fischer AG",
+            Self::CuriePointAb => "Curie Point AB",
+            Self::EccrineSystemsInc => "Eccrine Systems, Inc.",
+            Self::JrmGroupLimited => "JRM Group Limited",
+            Self::RazerInc => "Razer Inc.",
+            Self::JetBeepInc => "JetBeep Inc.",
+            Self::ChangzhouSoundDragonElectronicsAndAcousticsCoLtd => {
+                "Changzhou Sound Dragon Electronics and Acoustics Co., Ltd"
+            }
+            Self::JiangsuTeranovoTechCoLtd => "Jiangsu Teranovo Tech Co., Ltd.",
+            Self::RaytacCorporation => "Raytac Corporation",
+            Self::TacxBV => "Tacx b.v.",
+            Self::AmstedDigitalSolutionsInc => "Amsted Digital Solutions Inc.",
+            Self::CherryGmbH => "Cherry GmbH",
+            Self::InQsCoLtd => "inQs Co., Ltd.",
+            Self::GreenwaldIndustries => "Greenwald Industries",
+            Self::DermalappsLlc => "Dermalapps, LLC",
+            Self::EltakoGmbH => "Eltako GmbH",
+            Self::PhotronLimited => "Photron Limited",
+            Self::TradeFidesAS => "Trade FIDES a.s.",
+            Self::MannkindCorporation => "Mannkind Corporation",
+            Self::NetgridSNCDiBissoliMatteoCamporealeSimoneTognettiFederico => {
+                "NETGRID S.N.C. DI BISSOLI MATTEO, CAMPOREALE SIMONE, TOGNETTI FEDERICO"
+            }
+            Self::MbientLabInc => "MbientLab Inc",
+            Self::FormAthleticaInc => "Form Athletica Inc.",
+            Self::TileInc => "Tile, Inc.",
+            Self::IFarmInc => "I.FARM, INC.",
+            Self::TheEnergyConservatoryInc => "The Energy Conservatory, Inc.",
+            Self::_4IiiiInnovationsInc => "4iiii Innovations Inc.",
+            Self::SabikOffshoreGmbH => "SABIK Offshore GmbH",
+            Self::InnovationFirstInc => "Innovation First, Inc.",
+            Self::ExpaiSolutionsPrivateLimited => "Expai Solutions Private Limited",
+            Self::DecoEnterprisesInc => "Deco Enterprises, Inc.",
+            Self::BeSpoon => "BeSpoon",
+            Self::InnovaIdeasLimited => "Innova Ideas Limited",
+            Self::Kopi => "Kopi",
+            Self::BuzzProductsLtd => "Buzz Products Ltd.",
+            Self::GemaSwitzerlandGmbH => "Gema Switzerland GmbH",
+            Self::HugTechnologyLtd => "Hug Technology Ltd",
+            Self::EurotronikKranjDOO => "Eurotronik Kranj d.o.o.",
+            Self::VensoEcoSolutionsAb => "Venso EcoSolutions AB",
+            Self::ZtoveApS => "Ztove ApS",
+            Self::DewertOkinGmbH => "DewertOkin GmbH",
+            Self::BradyWorldwideInc => "Brady Worldwide Inc.",
+            Self::LivanovaUsaInc => "Livanova USA, Inc.",
+            Self::BlebTechnologySrl => "Bleb Technology srl",
+            Self::SparkTechnologyLabsInc => "Spark Technology Labs Inc.",
+            Self::WtoWerkzeugEinrichtungenGmbH => "WTO Werkzeug-Einrichtungen GmbH",
+            Self::PureInternationalLimited => "Pure International Limited",
+            Self::RhaTechnologiesLtd => "RHA TECHNOLOGIES LTD",
+            Self::AdvancedTelemetrySystemsInc => "Advanced Telemetry Systems, Inc.",
+            Self::ParticleIndustriesInc => "Particle Industries, Inc.",
+            Self::ModeLightingLimited => "Mode Lighting Limited",
+            Self::RtcIndustriesInc => "RTC Industries, Inc.",
+            Self::RicohCompanyLtd => "Ricoh Company Ltd",
+            Self::AloAb => "Alo AB",
+            Self::PanduitCorp => "Panduit Corp.",
+            Self::PixArtImagingInc => "PixArt Imaging Inc.",
+            Self::SesamSolutionsBv => "Sesam Solutions BV",
+            Self::ZoundIndustriesInternationalAb => "Zound Industries International AB",
+            Self::UnSeenTechnologiesOy => "UnSeen Technologies Oy",
+            Self::PayexNorgeAs => "Payex Norge AS",
+            Self::MeshtronixLimited => "Meshtronix Limited",
+            Self::ZhuHaiAdvanProTechnologyCompanyLimited => {
+                "ZhuHai AdvanPro Technology Company Limited"
+            }
+            Self::RenishawPlc => "Renishaw PLC",
+            Self::LedlenserGmbHAndCoKg => "Ledlenser GmbH & Co. KG",
+            Self::MeggittSa => "Meggitt SA",
+            Self::ItzInnovationsUndTechnologiezentrumGmbH => {
+                "ITZ Innovations- und Technologiezentrum GmbH"
+            }
+            Self::StasisLabsInc => "Stasis Labs, Inc.",
+            Self::CoravinInc => "Coravin, Inc.",
+            Self::DigitalMatterPtyLtd => "Digital Matter Pty Ltd",
+            Self::KruxWorksTechnologiesPrivateLimited => "KRUXWorks Technologies Private Limited",
+            Self::ILoqOy => "iLOQ Oy",
+            Self::ZumtobelGroupAg => "Zumtobel Group AG",
+            Self::ScaleTecLtd => "Scale-Tec, Ltd",
+            Self::OpenResearchInstituteInc => "Open Research Institute, Inc.",
+            Self::RyeexTechnologyCoLtd => "Ryeex Technology Co.,Ltd.",
+            Self::UltuneTechnologies => "Ultune Technologies",
+            Self::MedEl => "MED-EL",
+            Self::SgvGroupHoldingGmbHAndCoKg => "SGV Group Holding GmbH & Co. KG",
+            Self::Bm3 => "BM3",
+            Self::ApogeeInstruments => "Apogee Instruments",
+            Self::MakitaCorporation => "makita corporation",
+            Self::BluetrumTechnologyCoLtd => "Bluetrum Technology Co.,Ltd",
+            Self::RevenueCollectionSystemsFranceSas => "Revenue Collection Systems FRANCE SAS",
+            Self::DishNetworkLlc => "Dish Network LLC",
+            Self::LdlTechnology => "LDL TECHNOLOGY",
+            Self::TheIndoorLabLlc => "The Indoor Lab, LLC",
+            Self::XradioTechnologyCoLtd => "Xradio Technology Co.,Ltd.",
+            Self::ContecMedicalSystemsCoLtd => "Contec Medical Systems Co., Ltd.",
+            Self::KromekGroupPlc => "Kromek Group Plc",
+            Self::ProlojikLimited => "Prolojik Limited",
+            Self::ShenzhenMinewTechnologiesCoLtd => "Shenzhen Minew Technologies Co., Ltd.",
+            Self::LxSolutionsPtyLimited => "LX SOLUTIONS PTY LIMITED",
+            Self::GiPInnovationToolsGmbH => "GiP Innovation Tools GmbH",
+            Self::ElectronicaIntegralDeSonidoSA => "ELECTRONICA INTEGRAL DE SONIDO S.A.",
+            Self::Crookwood => "Crookwood",
+            Self::FanstelCorp => "Fanstel Corp",
+            Self::WangiLaiPlt => "Wangi Lai PLT",
+            Self::HugoMullerGmbHAndCoKg => "Hugo Muller GmbH & Co KG",
+            Self::FortioriDesignLlc => "Fortiori Design LLC",
+            Self::AsthreaDOO => "Asthrea D.O.O.",
+            Self::OnkyoCorporation => "ONKYO Corporation",
+            Self::Procept => "Procept",
+            Self::VosslohSchwabeDeutschlandGmbH => "Vossloh-Schwabe Deutschland GmbH",
+            Self::AsPionGmbH => "ASPion GmbH",
+            Self::MinebeaMitsumiInc => "MinebeaMitsumi Inc.",
+            Self::LunaticoAstronomiaSl => "Lunatico Astronomia SL",
+            Self::PhonepePvtLtd => "PHONEPE PVT LTD",
+            Self::EnstoOy => "Ensto Oy",
+            Self::WegSA => "WEG S.A.",
+            Self::Amplifico => "Amplifico",
+            Self::SquarePandaInc => "Square Panda, Inc.",
+            Self::BiovotionAg => "Biovotion AG",
+            Self::PhiladelphiaScientificUKLimited => "Philadelphia Scientific (U.K.) Limited",
+            Self::BeamLabsLlc => "Beam Labs, LLC",
+            Self::NoordungDOO => "Noordung d.o.o.",
+            Self::ForciotOy => "Forciot Oy",
+            Self::PhrameInc => "Phrame Inc.",
+            Self::DiamondKineticsInc => "Diamond Kinetics, Inc.",
+            Self::SensInnovationApS => "SENS Innovation ApS",
+            Self::UnivationsLimited => "Univations Limited",
+            Self::SilexTechnologyInc => "silex technology, inc.",
+            Self::RWBeckettCorporation => "R.W. Beckett Corporation",
+            Self::SixGuysLabsSRO => "Six Guys Labs, s.r.o.",
+            Self::AudioTechnicaCorporation => "Audio-Technica Corporation",
+            Self::WizconnectedCompanyLimited => "WIZCONNECTED COMPANY LIMITED",
+            Self::Os42UgHaftungsbeschraenkt => "OS42 UG (haftungsbeschraenkt)",
+            Self::InterActionCorporation => "INTER ACTION Corporation",
+            Self::OnAssetIntelligenceInc => "OnAsset Intelligence, Inc.",
+            Self::HansDinslageGmbH => "Hans Dinslage GmbH",
+            Self::PlayfinityAs => "Playfinity AS",
+            Self::BeurerGmbH => "Beurer GmbH",
+            Self::AdhGuardianUsaLlc => "ADH GUARDIAN USA LLC",
+            Self::SignifyNetherlandsBV => "Signify Netherlands B.V.",
+            Self::BlueairAb => "Blueair AB",
+            Self::TdkCorporation => "TDK Corporation",
+            Self::VuzixCorporation => "Vuzix Corporation",
+            Self::TriaxTechnologiesInc => "Triax Technologies Inc",
+            Self::IqAirAg => "IQAir AG",
+            Self::BuchiLabortechnikAg => "BUCHI Labortechnik AG",
+            Self::KeySafeCloud => "KeySafe-Cloud",
+            Self::RookeryTechnologyLtd => "Rookery Technology Ltd",
+            Self::JohnDeere => "John Deere",
+            Self::FmwElectronicFuttererUMaierWolfOhg => "FMW electronic Futterer u. Maier-Wolf OHG",
+            Self::Cell2JackLlc => "Cell2Jack LLC",
+            Self::FourthEvolutionInc => "Fourth Evolution Inc",
+            Self::GeberitInternationalAg => "Geberit International AG",
+            Self::SchraderElectronics => "Schrader Electronics",
+            Self::IRobotCorporation => "iRobot Corporation",
+            Self::WellnomicsLtd => "Wellnomics Ltd",
+            Self::NikoNv => "Niko nv",
+            Self::Innoseis => "Innoseis",
+            Self::MasbandoGmbH => "Masbando GmbH",
+            Self::ArbletInc => "Arblet Inc.",
+            Self::KonamiSportsLifeCoLtd => "Konami Sports Life Co., Ltd.",
+            Self::HagleitnerHygieneInternationalGmbH => "Hagleitner Hygiene International GmbH",
+            Self::AnkiInc => "Anki Inc.",
+            Self::TracmoInc => "TRACMO, INC.",
+            Self::DpTechnics => "DPTechnics",
+            Self::GsTag => "GS TAG",
+            Self::ClearityLlc => "Clearity, LLC",
+            Self::SeeScan => "SeeScan",
+            Self::TryAndECoLtd => "Try and E CO.,LTD.",
+            Self::TheLinuxFoundation => "The Linux Foundation",
+            Self::Beken => "beken",
+            Self::SikomAs => "SIKOM AS",
+            Self::WristcamInc => "Wristcam Inc.",
+            Self::FujiXeroxCoLtd => "Fuji Xerox Co., Ltd",
+            Self::GycomSvenskaAb => "Gycom Svenska AB",
+            Self::BayerischeMotorenWerkeAg => "Bayerische Motoren Werke AG",
+            Self::AcsControlSystemGmbH => "ACS-Control-System GmbH",
+            Self::IconmobileGmbH => "iconmobile GmbH",
+            Self::Cowboy => "COWBOY",
+            Self::PressurePro => "PressurePro",
+            Self::MotionInstrumentsInc => "Motion Instruments Inc.",
+            Self::IneoEnergyAndSystems => "INEO ENERGY& SYSTEMS",
+            Self::TaiyoYudenCoLtd => "Taiyo Yuden Co., Ltd",
+            Self::ElementalMachinesInc => "Elemental Machines, Inc.",
+            Self::StApPtronicsGmbH => "stAPPtronics GmbH",
+            Self::HumanIncorporated => "Human, Incorporated",
+            Self::ViperDesignLlc => "Viper Design LLC",
+            Self::VirtualclinicDirectLimited => "VIRTUALCLINIC.DIRECT LIMITED",
+            Self::QtMedicalInc => "QT Medical INC.",
+            Self::EssentimGmbH => "essentim GmbH",
+            Self::PetronicsInc => "Petronics Inc.",
+            Self::AvidIdentificationSystemsInc => "Avid Identification Systems, Inc.",
+            Self::AppliedNeuralResearchCorp => "Applied Neural Research Corp",
+            Self::ToyoElectronicsCorporation => "Toyo Electronics Corporation",
+            Self::FarmJennyLlc => "Farm Jenny LLC",
+            Self::ModumIoAg => "modum.io AG",
+            Self::ZhuhaiJieliTechnologyCoLtd => "Zhuhai Jieli technology Co.,Ltd",
+            Self::TegamInc => "TEGAM, Inc.",
+            Self::LamplightCoLtd => "LAMPLIGHT Co., Ltd.",
+            Self::AcurableLimited => "Acurable Limited",
+            Self::FrogblueTechnologyGmbH => "frogblue TECHNOLOGY GmbH",
+            Self::LindabAb => "Lindab AB",
+            Self::AnovaAppliedElectronics => "Anova Applied Electronics",
+            Self::BiowatchSa => "Biowatch SA",
+            Self::VZugLtd => "V-ZUG Ltd",
+            Self::RjBrandsLlc => "RJ Brands LLC",
+            Self::WattsElectronics => "WATTS ELECTRONICS",
+            Self::LucentWearLlc => "LucentWear LLC",
+            Self::MhlCustomInc => "MHL Custom Inc",
+            Self::TbsElectronicsBV => "TBS Electronics B.V.",
+            Self::SomfySas => "SOMFY SAS",
+            Self::LippertComponentsInc => "Lippert Components, INC",
+            Self::SmartAnimalTrainingSystemsLlc => "Smart Animal Training Systems, LLC",
+            Self::SelveGmbHAndCoKg => "SELVE GmbH & Co. KG",
+            Self::CodecoupSpZOOSpK => "Codecoup sp. z o.o. sp. k.",
+            Self::RuntimeInc => "Runtime, Inc.",
+            Self::GroteIndustries => "Grote Industries",
+            Self::PIEngineering => "P.I.Engineering",
+            Self::NaluMedicalInc => "Nalu Medical, Inc.",
+            Self::RealWorldSystemsCorporation => "Real-World-Systems Corporation",
+            Self::RfidGlobalBySoftworkSrL => "RFID Global by Softwork SrL",
+            Self::UlcRoboticsInc => "ULC Robotics Inc.",
+            Self::LevitonMfgCoInc => "Leviton Mfg. Co., Inc.",
+            Self::OxfordMetricsPlc => "Oxford Metrics plc",
+            Self::Igloohome => "igloohome",
+            Self::SuzhouPairlinkNetworkTechnology => "Suzhou Pairlink Network Technology",
+            Self::AmbystomaLabsInc => "Ambystoma Labs Inc.",
+            Self::BeijingPineconeElectronicsCoLtd => "Beijing Pinecone Electronics Co.,Ltd.",
+            Self::ElecsIndustryCoLtd => "Elecs Industry Co.,Ltd.",
+            Self::Verisilicon => "verisilicon",
+            Self::WhiteHorseScientificLtd => "White Horse Scientific ltd",
+            Self::ParabitSystemsInc => "Parabit Systems, Inc.",
+            Self::CarelIndustriesSPA => "CAREL INDUSTRIES S.P.A.",
+            Self::MedallionInstrumentationSystems => "Medallion Instrumentation Systems",
+            Self::NewTecGmbH => "NewTec GmbH",
+            Self::OvLoopInc => "OV LOOP, INC.",
+            Self::CarmateMfgCoLtd => "CARMATE MFG.CO.,LTD",
+            Self::Inia => "INIA",
+            Self::GoerTekDynaudioCoLtd => "GoerTek Dynaudio Co., Ltd.",
+            Self::NofenceAs => "Nofence AS",
+            Self::TramexLimited => "Tramex Limited",
+            Self::Monidor => "Monidor",
+            Self::TomAllebrandiConsulting => "Tom Allebrandi Consulting",
+            Self::SonosInc => "Sonos Inc",
+            Self::TeleconMobileLimited => "Telecon Mobile Limited",
+            Self::KiirooBv => "Kiiroo BV",
+            Self::OEMControlsInc => "O. E. M. Controls, Inc.",
+            Self::AxiomwareSystemsIncorporated => "Axiomware Systems Incorporated",
+            Self::AdheriumNzLimited => "ADHERIUM(NZ) LIMITED",
+            Self::ShanghaiXiaoyiTechnologyCoLtd => "Shanghai Xiaoyi Technology Co.,Ltd.",
+            Self::DreamDevicesTechnologiesOy => "Dream Devices Technologies Oy",
+            Self::FisherAndPaykelHealthcare => "Fisher & Paykel Healthcare",
+            Self::PolycomInc => "Polycom, Inc.",
+            Self::TandemDiabetesCare => "Tandem Diabetes Care",
+            Self::MacrogigaElectronics => "Macrogiga Electronics",
+            Self::DataflowSystemsLimited => "Dataflow Systems Limited",
+            Self::TeledyneLecroyInc => "Teledyne Lecroy, Inc.",
+            Self::Lazlo326Llc => "Lazlo326, LLC.",
+            Self::RapitagGmbH => "rapitag GmbH",
+            Self::RadioPulseInc => "RadioPulse Inc",
+            Self::MySmartBlinds => "My Smart Blinds",
+            Self::InorProcessAb => "Inor Process AB",
+            Self::KohlerCompany => "Kohler Company",
+            Self::SpauldingClinicalResearch => "Spaulding Clinical Research",
+            Self::Izitherm => "IZITHERM",
+            Self::ViasatGroupSPA => "Viasat Group S.p.A.",
+            Self::Pur3Ltd => "Pur3 Ltd",
+            Self::HendonSemiconductorsPtyLtd => "HENDON SEMICONDUCTORS PTY LTD",
+            Self::MetaPlatformsTechnologiesLlc => "Meta Platforms Technologies, LLC",
+            Self::JungheinrichAktiengesellschaft => "Jungheinrich Aktiengesellschaft",
+            Self::FracarroRadioindustrieSrl => "Fracarro Radioindustrie SRL",
+            Self::MaximIntegratedProducts => "Maxim Integrated Products",
+            Self::StartTodayCoLtd => "START TODAY CO.,LTD.",
+            Self::StarTechnologies => "Star Technologies",
+            Self::AltTeknikLlc => "ALT-TEKNIK LLC",
+            Self::DerichsGmbH => "Derichs GmbH",
+            Self::Legrand => "LEGRAND",
+            Self::HearingLabTechnology => "Hearing Lab Technology",
+            Self::GiraGiersiepenGmbHAndCoKg => "Gira Giersiepen GmbH & Co. KG",
+            Self::CodeBlueCommunications => "Code Blue Communications",
+            Self::BreakwallAnalyticsLlc => "Breakwall Analytics, LLC",
+            Self::LysTechnologiesLtd => "LYS TECHNOLOGIES LTD",
+            Self::AranzMedicalLimited => "ARANZ Medical Limited",
+            Self::ScufGamingInternationalLlc => "Scuf Gaming International, LLC",
+            Self::BecoInc => "Beco, Inc",
+            Self::InstinctPerformance => "Instinct Performance",
+            Self::ToorTechnologiesLlc => "Toor Technologies LLC",
+            Self::DuracellUSOperationsInc => "Duracell U.S. Operations Inc.",
+            Self::OmniRemotes => "OMNI Remotes",
+            Self::EnsembleTechPrivateLimited => "Ensemble Tech Private Limited",
+            Self::WellingtonDriveTechnologiesLtd => "Wellington Drive Technologies Ltd",
+            Self::TrueWearablesInc => "True Wearables, Inc.",
+            Self::GlobalstarInc => "Globalstar, Inc.",
+            Self::IntegralMemroyPlc => "Integral Memroy Plc",
+            Self::AffordableElectronicsInc => "AFFORDABLE ELECTRONICS INC",
+            Self::LightingScienceGroupCorp => "Lighting Science Group Corp.",
+            Self::AntTailCom => "AntTail.com",
+            Self::PsikickInc => "PSIKICK, INC.",
+            Self::ConsumerSleepSolutionsLlc => "Consumer Sleep Solutions LLC",
+            Self::BikeFinderAs => "BikeFinder AS",
+            Self::VizpinInc => "VIZPIN INC.",
+            Self::RedmondIndustrialGroupLlc => "Redmond Industrial Group LLC",
+            Self::LongRangeSystemsLlc => "Long Range Systems, LLC",
+            Self::RionCoLtd => "Rion Co., Ltd.",
+            Self::FlipnaviCoLtd => "Flipnavi Co.,Ltd.",
+            Self::AudionicsSystemInc => "Audionics System, INC.",
+            Self::BodyportInc => "Bodyport Inc.",
+            Self::XiamenEveresportsGoodsCoLtd => "Xiamen Everesports Goods Co., Ltd",
+            Self::CoreTransportTechnologiesNzLimited => "CORE TRANSPORT TECHNOLOGIES NZ LIMITED",
+            Self::BeijingSmartspaceTechnologiesInc => "Beijing Smartspace Technologies Inc.",
+            Self::BeghelliSpa => "Beghelli Spa",
+            Self::SteinelVertriebGmbH => "Steinel Vertrieb GmbH",
+            Self::ThalmicLabsInc => "Thalmic Labs Inc.",
+            Self::FinderSPA => "Finder S.p.A.",
+            Self::SaritaCareTechAps => "Sarita CareTech APS",
+            Self::ProtechSASDiGirardiAndreaAndC => "PROTECH S.A.S. DI GIRARDI ANDREA & C.",
+            Self::HekatronVertriebsGmbH => "Hekatron Vertriebs GmbH",
+            Self::ValveCorporation => "Valve Corporation",
+            Self::Lely => "Lely",
+            Self::FranklinTechnologyInc => "FRANKLIN TECHNOLOGY INC",
+            Self::CandyHouseInc => "CANDY HOUSE, Inc.",
+            Self::NewconOptik => "Newcon Optik",
+            Self::BenegearInc => "benegear, inc.",
+            Self::ArwinTechnologyLimited => "Arwin Technology Limited",
+            Self::OtodynamicsLtd => "Otodynamics Ltd",
+            Self::KrohneMesstechnikGmbH => "KROHNE Messtechnik GmbH",
+            Self::NationalInstruments => "National Instruments",
+            Self::NintendoCoLtd => "Nintendo Co., Ltd.",
+            Self::AvempaceSarl => "Avempace SARL",
+            Self::Sylero => "Sylero",
+            Self::VersaNetworksInc => "Versa Networks, Inc.",
+            Self::Sinnoz => "Sinnoz",
+            Self::FortronikStoritveDOO => "FORTRONIK storitve d.o.o.",
+            Self::Sensome => "Sensome",
+            Self::CarefreeScottFetzerCoInc => "Carefree Scott Fetzer Co Inc",
+            Self::AdvancedElectronicDesignsInc => "Advanced Electronic Designs, Inc.",
+            Self::LinoughInc => "Linough Inc.",
+            Self::SmartTechnologiesAndInvestmentLimited => {
+                "Smart Technologies and Investment Limited"
+            }
+            Self::KnickElektronischeMessgeraeteGmbHAndCoKg => {
+                "Knick Elektronische Messgeraete GmbH & Co. KG"
+            }
+            Self::LogicdataElectronicAndSoftwareEntwicklungsGmbH => {
+                "LOGICDATA Electronic & Software Entwicklungs GmbH"
+            }
+            Self::ApexarTechnologiesSA => "Apexar Technologies S.A.",
+            Self::CandyHooverGroupSRL => "Candy Hoover Group s.r.l",
+            Self::OrthoSensorInc => "OrthoSensor, Inc.",
+            Self::MiwaLockCoLtd => "MIWA LOCK CO.,Ltd",
+            Self::MistSystemsInc => "Mist Systems, Inc.",
+            Self::SharknetSrl => "Sharknet srl",
+            Self::SilverPlusInc => "SilverPlus, Inc",
+            Self::SilergyCorp => "Silergy Corp",
+            Self::Clim8Limited => "CLIM8 LIMITED",
+            Self::TesaSa => "TESA SA",
+            Self::ScreenovateTechnologiesLtd => "Screenovate Technologies Ltd",
+            Self::Prodigy => "prodigy",
+            Self::SavitechCorp => "Savitech Corp.,",
+            Self::OppleLightingCoLtd => "OPPLE Lighting Co., Ltd",
+            Self::MedelaAg => "Medela AG",
+            Self::MetaLogicsCorporation => "MetaLogics Corporation",
+            Self::ZtrControlSystemsLlc => "ZTR Control Systems LLC",
+            Self::SmartComponentTechnologiesLimited => "Smart Component Technologies Limited",
+            Self::FrontiergadgetInc => "Frontiergadget, Inc.",
+            Self::NuraOperationsPtyLtd => "Nura Operations Pty Ltd",
+            Self::CrescoWirelessInc => "CRESCO Wireless, Inc.",
+            Self::DAndMHoldingsInc => "D&M Holdings Inc.",
+            Self::AdoleneInc => "Adolene, Inc.",
+            Self::CenterIdCorp => "Center ID Corp.",
+            Self::LedvanceGmbH => "LEDVANCE GmbH",
+            Self::ExfoInc => "EXFO, Inc.",
+            Self::GeosatisSa => "Geosatis SA",
+            Self::NovartisAg => "Novartis AG",
+            Self::KeynesControlsLtd => "Keynes Controls Ltd",
+            Self::LumenUab => "Lumen UAB",
+            Self::LuneraLightingInc => "Lunera Lighting Inc.",
+            Self::AlbrechtJung => "Albrecht JUNG",
+            Self::HoneywellInternationalInc => "Honeywell International Inc.",
+            Self::HongkongNanoIcTechnologiesCoLimited => {
+                "HONGKONG NANO IC TECHNOLOGIES  CO., LIMITED"
+            }
+            Self::HangzhouIMagicTechnologyCoLtd => "Hangzhou iMagic Technology Co., Ltd",
+            Self::MtgCoLtd => "MTG Co., Ltd.",
+            Self::NsTechInc => "NS Tech, Inc.",
+            Self::IaiCorporation => "IAI Corporation",
+            Self::TargetCorporation => "Target Corporation",
+            Self::SetecPtyLtd => "Setec Pty Ltd",
+            Self::DetectBlueLimited => "Detect Blue Limited",
+            Self::OffLineCoLtd => "OFF Line Co., Ltd.",
+            Self::Edps => "EDPS",
+            Self::AngeeTechnologiesLtd => "Angee Technologies Ltd.",
+            Self::LeicaCameraAg => "Leica Camera AG",
+            Self::TytoLifeLlc => "Tyto Life LLC",
+            Self::MamorioInc => "MAMORIO.inc",
+            Self::AmtronicSverigeAb => "Amtronic Sverige AB",
+            Self::Footmarks => "Footmarks",
+            Self::RbControlsCoLtd => "RB Controls Co., Ltd.",
+            Self::FibroGmbH => "FIBRO GmbH",
+            Self::_9974091CanadaInc => "9974091 Canada Inc.",
+            Self::SoprodSa => "Soprod SA",
+            Self::BrookfieldEquinoxLlc => "Brookfield Equinox LLC",
+            Self::UniElectronicsInc => "UNI-ELECTRONICS, INC.",
+            Self::FoundationEngineeringLlc => "Foundation Engineering LLC",
+            Self::YichipMicroelectronicsHangzhouCoLtd => {
+                "Yichip Microelectronics (Hangzhou) Co.,Ltd."
+            }
+            Self::TrSystemsGmbH => "TRSystems GmbH",
+            Self::OsramGmbH => "OSRAM GmbH",
+            Self::VibrissaInc => "Vibrissa Inc.",
+            Self::ShakeOnBV => "Shake-on B.V.",
+            Self::GarageSmartInc => "Garage Smart, Inc.",
+            Self::AxesSystemSpZOO => "Axes System sp. z o. o.",
+            Self::Yellowcog => "Yellowcog",
+            Self::Hager => "Hager",
+            Self::InPlayInc => "InPlay, Inc.",
+            Self::PhyplusInc => "PHYPLUS Inc",
+            Self::LocorollInc => "Locoroll, Inc",
+            Self::SpecifiKaliLlc => "Specifi-Kali LLC",
+            Self::PolarisInd => "Polaris IND",
+            Self::WiliotLtd => "Wiliot LTD.",
+            Self::MicrosemiCorporation => "Microsemi Corporation",
+            Self::WoosimSystemsInc => "Woosim Systems Inc.",
+            Self::TapkeyGmbH => "Tapkey GmbH",
+            Self::SwingLyncLLC => "SwingLync L. L. C.",
+            Self::BenchmarkDrivesGmbHAndCoKg => "Benchmark Drives GmbH & Co. KG",
+            Self::AndrotecGmbH => "Androtec GmbH",
+            Self::Interactio => "Interactio",
+            Self::ConvergenceSystemsLimited => "Convergence Systems Limited",
+            Self::ShenzhenGoodixTechnologyCoLtd => "Shenzhen Goodix Technology Co., Ltd",
+            Self::McLearLimited => "McLear Limited",
+            Self::PirelliTyreSPA => "Pirelli Tyre S.P.A.",
+            Self::ZanComputeInc => "ZanCompute Inc.",
+            Self::CerevastMedical => "Cerevast Medical",
+            Self::InDreamerTechsolPrivateLimited => "InDreamer Techsol Private Limited",
+            Self::ThebenAg => "Theben AG",
+            Self::KosiLimited => "Kosi Limited",
+            Self::DaisyWorksInc => "DaisyWorks, Inc",
+            Self::Auxivia => "Auxivia",
+            Self::R9TechnologyInc => "R9 Technology, Inc.",
+            Self::MotorolaSolutions => "Motorola Solutions",
+            Self::BirdHomeAutomationGmbH => "Bird Home Automation GmbH",
+            Self::PacificBioscienceLaboratoriesInc => "Pacific Bioscience Laboratories, Inc",
+            Self::BuschJaegerElektroGmbH => "Busch Jaeger Elektro GmbH",
+            Self::StabiloInternational => "STABILO International",
+            Self::RehabtronicsInc => "REHABTRONICS INC.",
+            Self::SmartSolutionTechnologyInc => "Smart Solution Technology, Inc.",
+            Self::AvackOy => "Avack Oy",
+            Self::Woodenshark => "Woodenshark",
+            Self::UnderArmour => "Under Armour",
+            Self::EllieGrid => "EllieGrid",
+            Self::ReactecLimited => "REACTEC LIMITED",
+            Self::GuardtecInc => "Guardtec, Inc.",
+            Self::EmersonElectricCo => "Emerson Electric Co.",
+            Self::LutronElectronicsCoInc => "Lutron Electronics Co., Inc.",
+            Self::_4ModTechnology => "4MOD Technology",
+            Self::IottiveOpcPrivateLimited => "IOTTIVE (OPC) PRIVATE LIMITED",
+            Self::EngineeredAudioLlc => "Engineered Audio, LLC.",
+            Self::FranceschiMarinaSnc => "Franceschi Marina snc",
+            Self::RmAcquisitionLlc => "RM Acquisition LLC",
+            Self::FujifilmCorporation => "FUJIFILM Corporation",
+            Self::BlincamInc => "Blincam, Inc.",
+            Self::LuglocLlc => "LUGLOC LLC",
+            Self::GooeeLimited => "Gooee Limited",
+            Self::_5ThElementLtd => "5th Element Ltd",
+            Self::QueerconInc => "Queercon, Inc",
+            Self::AnloqTechnologiesInc => "Anloq Technologies Inc.",
+            Self::KtsGmbH => "KTS GmbH",
+            Self::OlympusCorporation => "Olympus Corporation",
+            Self::DomSicherheitstechnikGmbHAndCoKg => "DOM Sicherheitstechnik GmbH & Co. KG",
+            Self::GoooledSRL => "GOOOLED S.R.L.",
+            Self::SafetechProductsLlc => "Safetech Products LLC",
+            Self::EnfluxInc => "Enflux Inc.",
+            Self::NovoNordiskAS => "Novo Nordisk A/S",
+            Self::SteinerOptikGmbH => "Steiner-Optik GmbH",
+            Self::ThornwaveLabsInc => "Thornwave Labs Inc",
+            Self::ShanghaiFlycoElectricalApplianceCoLtd => {
+                "Shanghai Flyco Electrical Appliance Co., Ltd."
+            }
+            Self::SvantekSpZOO => "Svantek Sp. z o.o.",
+            Self::InstaGmbH => "Insta GmbH",
+            Self::SeibertWilliamsGlassLlc => "Seibert Williams Glass, LLC",
+            Self::TeAmHutchinsAb => "TeAM Hutchins AB",
+            Self::MantracourtElectronicsLimited => "Mantracourt Electronics Limited",
+            Self::DmetProductsCorp => "Dmet Products Corp.",
+            Self::SospitasSRO => "Sospitas, s.r.o.",
+            Self::StatsportsInternational => "Statsports International",
+            Self::VitInitiativeLlc => "VIT Initiative, LLC",
+            Self::AverosFzco => "Averos FZCO",
+            Self::AlbynMedical => "AlbynMedical",
+            Self::DraegerwerkAgAndCoKGaA => "Draegerwerk AG & Co. KGaA",
+            Self::NeateboxLtd => "Neatebox Ltd",
+            Self::CrestronElectronicsInc => "Crestron Electronics, Inc.",
+            Self::CsrBuildingProductsLimited => "CSR Building Products Limited",
+            Self::SoraaInc => "Soraa Inc.",
+            Self::AnalogDevicesInc => "Analog Devices, Inc.",
+            Self::DiagnopticsTechnologies => "Diagnoptics Technologies",
+            Self::SwiftronixAb => "Swiftronix AB",
+            Self::InuheatGroupAb => "Inuheat Group AB",
+            Self::MobikeHongKongLimited => "mobike (Hong Kong) Limited",
+            Self::TheShadowOnTheMoon => "The Shadow on the Moon",
+            Self::KartographersTechnologiesPvtLtd => "Kartographers Technologies Pvt. Ltd.",
+            Self::WebaSportUndMedArtikelGmbH => "Weba Sport und Med. Artikel GmbH",
+            Self::BiorowerHandelsagenturGmbH => "BIOROWER Handelsagentur GmbH",
+            Self::ErmElectronicSystemsLtd => "ERM Electronic Systems LTD",
+            Self::ShureInc => "Shure Inc",
+            Self::UndagridBV => "Undagrid B.V.",
+            Self::HarbortronicsInc => "Harbortronics, Inc.",
+            Self::LinkioSas => "LINKIO SAS",
+            Self::DiscoverySoundTechnologyLlc => "DISCOVERY SOUND TECHNOLOGY, LLC",
+            Self::BioTexInc => "BioTex, Inc.",
+            Self::DallasLogicCorporation => "Dallas Logic Corporation",
+            Self::VinetechCoLtd => "Vinetech Co., Ltd",
+            Self::GuangzhouFiiOElectronicsTechnologyCoLtd => {
+                "Guangzhou FiiO Electronics Technology Co.,Ltd"
+            }
+            Self::HerbertWaldmannGmbHAndCoKg => "Herbert Waldmann GmbH & Co. KG",
+            Self::GtTronicsHkLtd => "GT-tronics HK Ltd",
+            Self::OvrEngineeredLlc => "ovrEngineered, LLC",
+            Self::PniSensorCorporation => "PNI Sensor Corporation",
+            Self::VypinLlc => "Vypin, LLC",
+            Self::PopperPayAb => "Popper Pay AB",
+            Self::AndXorLlc => "AND!XOR LLC",
+            Self::UhlmannAndZacherGmbH => "Uhlmann & Zacher GmbH",
+            Self::DyOcean => "DyOcean",
+            Self::NVistiLlc => "nVisti, LLC",
+            Self::SituneAs => "Situne AS",
+            Self::RuuviInnovationsLtd => "Ruuvi Innovations Ltd.",
+            Self::MeterGroupIncUsa => "METER Group, Inc. USA",
+            Self::CochlearLimited => "Cochlear Limited",
+            Self::PolymorphicLabsLlc => "Polymorphic Labs LLC",
+            Self::LmtMercerGroupInc => "LMT Mercer Group, Inc",
+            Self::SennheiserElectronicGmbHAndCoKg => "SENNHEISER electronic GmbH & Co. KG",
+            Self::LynxemiPteLtd => "Lynxemi Pte Ltd",
+            Self::AdcTechnologyInc => "ADC Technology, Inc.",
+            Self::SorexWirelessSolutionsGmbH => "SOREX - Wireless Solutions GmbH",
+            Self::MattingAb => "Matting AB",
+            Self::BlueKitchenGmbH => "BlueKitchen GmbH",
+            Self::CompanionMedicalInc => "Companion Medical, Inc.",
+            Self::SLabsSpZOO => "S-Labs Sp. z o.o.",
+            Self::VectronixAg => "Vectronix AG",
+            Self::CpElectronicsLimited => "CP Electronics Limited",
+            Self::TaelekOy => "Taelek Oy",
+            Self::IgarashiEngineering => "Igarashi Engineering",
+            Self::AutomotiveDataSolutionsInc => "Automotive Data Solutions Inc",
+            Self::CentricaConnectedHome => "Centrica Connected Home",
+            Self::DevTecnologiaIndustriaComercioEManutencaoDeEquipamentosLtdaMe => {
+                "DEV TECNOLOGIA INDUSTRIA, COMERCIO E MANUTENCAO DE EQUIPAMENTOS LTDA. - ME"
+            }
+            Self::SkidataAg => "SKIDATA AG",
+            Self::RevolTechnologiesInc => "Revol Technologies Inc",
+            Self::MultiCareSystemsBV => "Multi Care Systems B.V.",
+            Self::PosTuningUdoVosshenrichGmbHAndCoKg => "POS Tuning Udo Vosshenrich GmbH & Co. KG",
+            Self::QuintraxLimited => "Quintrax Limited",
+            Self::DynometricsInc => "Dynometrics Inc.",
+            Self::ProMarkInc => "Pro-Mark, Inc.",
+            Self::OurHubDevIvS => "OurHub Dev IvS",
+            Self::OcclyLlc => "Occly LLC",
+            Self::PowermatLtd => "POWERMAT LTD",
+            Self::MiyoshiElectronicsCorporation => "MIYOSHI ELECTRONICS CORPORATION",
+            Self::SinosunTechnologyCoLtd => "Sinosun Technology Co., Ltd.",
+            Self::MywerkSystemGmbH => "mywerk system GmbH",
+            Self::FarSiteCommunicationsLimited => "FarSite Communications Limited",
+            Self::BlueSparkTechnologies => "Blue Spark Technologies",
+            Self::OxstrenWearableTechnologiesPrivateLimited => {
+                "Oxstren Wearable Technologies Private Limited"
+            }
+            Self::IcomInc => "Icom inc.",
+            Self::IApartmentCoLtd => "iApartment co., ltd.",
+            Self::SteelcaseInc => "Steelcase, Inc.",
+            Self::ControlJPtyLtd => "Control-J Pty Ltd",
+            Self::TiVoCorp => "TiVo Corp",
+            Self::IDesignSRL => "iDesign s.r.l.",
+            Self::DevelcoProductsAS => "Develco Products A/S",
+            Self::PamborLtd => "Pambor Ltd.",
+            Self::BegaGantenbrinkLeuchtenKg => "BEGA Gantenbrink-Leuchten KG",
+            Self::QingdaoRealtimeTechnologyCoLtd => "Qingdao Realtime Technology Co., Ltd.",
+            Self::PmdSolutions => "PMD Solutions",
+            Self::InsigmaInc => "INSIGMA INC.",
+            Self::PalagoAb => "Palago AB",
+            Self::KynesimLtd => "Kynesim Ltd",
+            Self::CodenexOy => "Codenex Oy",
+            Self::CycleLabsSolutionsInc => "CycleLabs Solutions inc.",
+            Self::InternationalForteGroupLlc => "International Forte Group LLC",
+            Self::BellmanAndSymfonGroupAb => "Bellman & Symfon Group AB",
+            Self::FathomSystemsInc => "Fathom Systems Inc.",
+            Self::BonsaiSystemsGmbH => "Bonsai Systems GmbH",
+            Self::VhfElektronikGmbH => "vhf elektronik GmbH",
+            Self::Kolibree => "Kolibree",
+            Self::RealTimeAutomationInc => "Real Time Automation, Inc.",
+            Self::NuvizInc => "Nuviz, Inc.",
+            Self::BostonScientificCorporation => "Boston Scientific Corporation",
+            Self::DeltaTCorporation => "Delta T Corporation",
+            Self::SpaceekLtd => "SPACEEK LTD",
+            Self::_2048450OntarioInc => "2048450 Ontario Inc",
+            Self::LumenetixInc => "Lumenetix, Inc",
+            Self::MiniSolutionCoLtd => "Mini Solution Co., Ltd.",
+            Self::RfInnovation => "RF INNOVATION",
+            Self::NemikConsultingInc => "Nemik Consulting Inc",
+            Self::Atomation => "Atomation",
+            Self::SphinxElectronicsGmbHAndCoKg => "Sphinx Electronics GmbH & Co KG",
+            Self::QorvoUtrechtBV => "Qorvo Utrecht B.V.",
+            Self::SvepDesignCenterAb => "Svep Design Center AB",
+            Self::TunstallNordicAb => "Tunstall Nordic AB",
+            Self::TeenageEngineeringAb => "Teenage Engineering AB",
+            Self::TtsTooltechnicSystemsAgAndCoKg => "TTS Tooltechnic Systems AG & Co. KG",
+            Self::XtravaInc => "Xtrava Inc.",
+            Self::VegaGrieshaberKg => "VEGA Grieshaber KG",
+            Self::LifeStyleLockLlc => "LifeStyle Lock, LLC",
+            Self::NainInc => "Nain Inc.",
+            Self::ShimanoInc => "SHIMANO INC.",
+            Self::_1UpUsaComLlc => "1UP USA.com llc",
+            Self::GrandCentrixGmbH => "Grand Centrix GmbH",
+            Self::FabtronicsAustraliaPtyLtd => "Fabtronics Australia Pty Ltd",
+            Self::NetgearInc => "NETGEAR, Inc.",
+            Self::KobianCanadaInc => "Kobian Canada Inc.",
+            Self::MetanateLimited => "Metanate Limited",
+            Self::TuckerInternationalLlc => "Tucker International LLC",
+            Self::SecomCoLtd => "SECOM CO., LTD.",
+            Self::IProtoXiOy => "iProtoXi Oy",
+            Self::ValencellInc => "Valencell, Inc.",
+            Self::TentacleSyncGmbH => "Tentacle Sync GmbH",
+            Self::ThermomedicsInc => "Thermomedics, Inc.",
+            Self::CoilerCorporation => "Coiler Corporation",
+            Self::DeLaval => "DeLaval",
+            Self::AppsideCoLtd => "Appside co., ltd.",
+            Self::NuhearaLimited => "Nuheara Limited",
+            Self::RadianceTechnologies => "Radiance Technologies",
+            Self::HelvarLtd => "Helvar Ltd",
+            Self::EBestIotInc => "eBest IOT Inc.",
+            Self::DraysonTechnologiesEuropeLimited => "Drayson Technologies (Europe) Limited",
+            Self::BlocksWearablesLtd => "Blocks Wearables Ltd.",
+            Self::HatchBabyInc => "Hatch Baby, Inc.",
+            Self::PillsyInc => "Pillsy Inc.",
+            Self::SilkLabsInc => "Silk Labs, Inc.",
+            Self::AlticorInc => "Alticor Inc.",
+            Self::SnapStykInc => "SnapStyk Inc.",
+            Self::DanfossAS => "Danfoss A/S",
+            Self::MemCachierInc => "MemCachier Inc.",
+            Self::MeshtechAs => "Meshtech AS",
+            Self::TictoNV => "Ticto N.V.",
+            Self::IMicroMedIncorporated => "iMicroMed Incorporated",
+            Self::BdMedical => "BD Medical",
+            Self::ProlonInc => "Prolon Inc.",
+            Self::SmallLoopLlc => "SmallLoop, LLC",
+            Self::FocusFleetAndFuelManagementInc => "Focus fleet and fuel management inc",
+            Self::HusqvarnaAb => "Husqvarna AB",
+            Self::UnifySoftwareAndSolutionsGmbHAndCoKg => {
+                "Unify Software and Solutions GmbH & Co. KG"
+            }
+            Self::TrainesenseLtd => "Trainesense Ltd.",
+            Self::ChargifiLimited => "Chargifi Limited",
+            Self::DelseySa => "DELSEY SA",
+            Self::BackboneLabsInc => "Backbone Labs, Inc.",
+            Self::TecBakeryGmbH => "TecBakery GmbH",
+            Self::KopinCorporation => "Kopin Corporation",
+            Self::DellComputerCorporation => "Dell Computer Corporation",
+            Self::BenningElektrotechnikUndElektronikGmbHAndCoKg => {
+                "Benning Elektrotechnik und Elektronik GmbH & Co. KG"
+            }
+            Self::WaterGuruInc => "WaterGuru, Inc.",
+            Self::OrthoAccelTechnologies => "OrthoAccel Technologies",
+            Self::FridayLabsLimited => "Friday Labs Limited",
+            Self::NovalogyLtd => "Novalogy LTD",
+            Self::FatigueScience => "Fatigue Science",
+            Self::SodaGmbH => "SODA GmbH",
+            Self::UberTechnologiesInc => "Uber Technologies Inc",
+            Self::LightningProtectionInternationalPtyLtd => {
+                "Lightning Protection International Pty Ltd"
+            }
+            Self::WirelessCablesInc => "Wireless Cables Inc",
+            Self::Sefam => "SEFAM",
+            Self::LuidiaInc => "Luidia Inc",
+            Self::FenderMusicalInstruments => "Fender Musical Instruments",
+            Self::CoAxTechnologyInc => "CO-AX Technology, Inc.",
+            Self::SkfUKLimited => "SKF (U.K.) Limited",
+            Self::NorthStarBatteryCompanyLlc => "NorthStar Battery Company, LLC",
+            Self::SenixCorporation => "Senix Corporation",
+            Self::JanaCareInc => "Jana Care Inc.",
+            Self::ZfOpenmaticsSRO => "ZF OPENMATICS s.r.o.",
+            Self::RyseInc => "RYSE INC.",
+            Self::ToGetHomeInc => "ToGetHome Inc.",
+            Self::SwissAudioSa => "Swiss Audio SA",
+            Self::Airtago => "Airtago",
+            Self::VertexInternationalInc => "Vertex International, Inc.",
+            Self::AuthomateInc => "Authomate Inc",
+            Self::GantnerElectronicGmbH => "Gantner Electronic GmbH",
+            Self::SearsHoldingsCorporation => "Sears Holdings Corporation",
+            Self::RelationsInc => "Relations Inc.",
+            Self::IDeveloperItBeratungUg => "i-developer IT Beratung UG",
+            Self::Withings => "Withings",
+            Self::Littelfuse => "Littelfuse",
+            Self::TrimbleInc => "Trimble Inc.",
+            Self::KimberlyClark => "Kimberly-Clark",
+            Self::NoxMedical => "Nox Medical",
+            Self::VyassoftTechnologiesInc => "Vyassoft Technologies Inc",
+            Self::BeconTechnologiesCoLtd => "Becon Technologies Co.,Ltd.",
+            Self::RockfordCorp => "Rockford Corp.",
+            Self::OwlLabsInc => "Owl Labs Inc.",
+            Self::ItonTechnologyCorp => "Iton Technology Corp.",
+            Self::WhereInc => "WHERE, Inc.",
+            Self::PalTechnologiesLtd => "PAL Technologies Ltd",
+            Self::FlowscapeAb => "Flowscape AB",
+            Self::WindowMasterAS => "WindowMaster A/S",
+            Self::HestanSmartCookingInc => "Hestan Smart Cooking Inc.",
+            Self::Clink => "CLINK",
+            Self::FoolographyGmbH => "foolography GmbH",
+            Self::CubeTechnologies => "CUBE TECHNOLOGIES",
+            Self::BasicMicroComInc => "BASIC MICRO.COM,INC.",
+            Self::JigowattsInc => "Jigowatts Inc.",
+            Self::OzoEduInc => "Ozo Edu, Inc.",
+            Self::HelloInc => "Hello Inc.",
+            Self::ShenzhenLemonjoyTechnologyCoLtd => "SHENZHEN LEMONJOY TECHNOLOGY CO., LTD.",
+            Self::ReinerKartengeraeteGmbHAndCoKg => "Reiner Kartengeraete GmbH & Co. KG.",
+            Self::TrueFitnessTechnology => "TRUE Fitness Technology",
+            Self::IoTInstrumentsOy => "IoT Instruments Oy",
+            Self::Ffly4U => "ffly4u",
+            Self::ChipIngAg => "Chip-ing AG",
+            Self::QualcommLifeInc => "Qualcomm Life Inc",
+            Self::SensoanOy => "Sensoan Oy",
+            Self::SpdDevelopmentCompanyLtd => "SPD Development Company Ltd",
+            Self::ActionsTechnologyCoLtd => "Actions Technology Co.,Ltd",
+            Self::GrobTechnologiesLlc => "Grob Technologies, LLC",
+            Self::NathanRhoadesLlc => "Nathan Rhoades LLC",
+            Self::AndreasStihlAgAndCoKg => "Andreas Stihl AG & Co. KG",
+            Self::NimaLabs => "Nima Labs",
+            Self::InstabeatInc => "Instabeat, Inc",
+            Self::EnOceanGmbH => "EnOcean GmbH",
+            Self::_3IWareCoLtd => "3IWare Co., Ltd.",
+            Self::ZenMeLabsLtd => "Zen-Me Labs Ltd",
+            Self::Finsecur => "FINSECUR",
+            Self::YotaDevicesLtd => "Yota Devices LTD",
+            Self::WyzelinkSystemsInc => "Wyzelink Systems Inc.",
+            Self::PegPeregoSpa => "PEG PEREGO SPA",
+            Self::SigmaConnectivityAb => "Sigma Connectivity AB",
+            Self::IotPotIndiaPrivateLimited => "IOT Pot India Private Limited",
+            Self::DensityInc => "Density Inc.",
+            Self::WatteamLtd => "Watteam Ltd",
+            Self::MiraInc => "MIRA, Inc.",
+            Self::ContrinexSA => "CONTRINEX S.A.",
+            Self::WyndTechnologiesInc => "Wynd Technologies, Inc.",
+            Self::VonkilTechnologiesLtd => "Vonkil Technologies Ltd",
+            Self::SysdevSrl => "SYSDEV Srl",
+            Self::In2ThingsAutomationPvtLtd => "In2things Automation Pvt. Ltd.",
+            Self::GallagherGroup => "Gallagher Group",
+            Self::AvvelInternational => "Avvel International",
+            Self::StructuralHealthSystemsInc => "Structural Health Systems, Inc.",
+            Self::Intricon => "Intricon",
+            Self::StJudeMedicalInc => "St. Jude Medical, Inc.",
+            Self::PicoTechnologyInc => "Pico Technology Inc.",
+            Self::CasambiTechnologiesOy => "Casambi Technologies Oy",
+            Self::SnapchatInc => "Snapchat Inc",
+            Self::EmberTechnologiesInc => "Ember Technologies, Inc.",
+            Self::ArchSystemsInc => "Arch Systems Inc.",
+            Self::ILumiSolutionsInc => "iLumi Solutions Inc.",
+            Self::AppliedScienceInc => "Applied Science, Inc.",
+            Self::Amadas => "amadas",
+            Self::AsbBankLtd => "ASB Bank Ltd",
+            Self::Abbott => "Abbott",
+            Self::MaxscendMicroelectronicsCompanyLimited => {
+                "Maxscend Microelectronics Company Limited"
+            }
+            Self::FrederiqueConstantSa => "FREDERIQUE CONSTANT SA",
+            Self::ASafeLimited => "A-Safe Limited",
+            Self::AirblyInc => "Airbly Inc.",
+            Self::Mattel => "Mattel",
+            Self::PetPommInc => "petPOMM, Inc",
+            Self::AlphaNodusInc => "Alpha Nodus, inc.",
+            Self::MidwestInstrumentsAndControls => "Midwest Instruments & Controls",
+            Self::PropagationSystemsLimited => "Propagation Systems Limited",
+            Self::OtodataWirelessNetworkInc => "Otodata Wireless Network Inc.",
+            Self::VibradormGmbH => "VIBRADORM GmbH",
+            Self::CommNSenseCorpDbaVerigo => "Comm-N-Sense Corp DBA Verigo",
+            Self::AllswellInc => "Allswell Inc.",
+            Self::XiQ => "XiQ",
+            Self::SmabloLtd => "Smablo LTD",
+            Self::MeizuTechnologyCoLtd => "Meizu Technology Co., Ltd.",
+            Self::ExonSpZOO => "Exon Sp. z o.o.",
+            Self::ThinkerlySrl => "THINKERLY SRL",
+            Self::EsrilleInc => "Esrille Inc.",
+            Self::AeroScout => "AeroScout",
+            Self::MedelaInc => "Medela, Inc",
+            Self::AceCadEnterpriseCoLtdAcecad => "ACE CAD Enterprise Co., Ltd. (ACECAD)",
+            Self::TokenZeroLtd => "Token Zero Ltd",
+            Self::SmartMovtTechnologyCoLtd => "SmartMovt Technology Co., Ltd",
+            Self::CanduraInstruments => "Candura Instruments",
+            Self::AlpineLabsLlc => "Alpine Labs LLC",
+            Self::IvtWirelessLimited => "IVT Wireless Limited",
+            Self::MolexCorporation => "Molex Corporation",
+            Self::SchoolBoardLimited => "SchoolBoard Limited",
+            Self::CareViewCommunicationsInc => "CareView Communications, Inc.",
+            Self::AleInternational => "ALE International",
+            Self::SouthSiliconValleyMicroelectronics => "South Silicon Valley Microelectronics",
+            Self::NeSt => "NeST",
+            Self::NikonCorporation => "Nikon Corporation",
+            Self::ThetatronicsLtd => "Thetatronics Ltd",
+            Self::LegoSystemAS => "LEGO System A/S",
+            Self::BloksGmbH => "BLOKS GmbH",
+            Self::Sdataway => "SDATAWAY",
+            Self::NetclearanceSystemsInc => "Netclearance Systems, Inc.",
+            Self::LavazzaSPA => "LAVAZZA S.p.A.",
+            Self::TAndD => "T&D",
+            Self::ThingsquareAb => "Thingsquare AB",
+            Self::InfotechSRO => "INFOTECH s.r.o.",
+            Self::XiaomiInc => "Xiaomi Inc.",
+            Self::CrownstoneBV => "Crownstone B.V.",
+            Self::ResmedLtd => "Resmed Ltd",
+            Self::AppionInc => "Appion Inc.",
+            Self::Noke => "Noke",
+            Self::KohlerMiraLimited => "Kohler Mira Limited",
+            Self::ActiveBluCorporation => "ActiveBlu Corporation",
+            Self::KapschTrafficComAb => "Kapsch TrafficCom AB",
+            Self::BluStorPmcInc => "BluStor PMC, Inc.",
+            Self::AtericaInc => "Aterica Inc.",
+            Self::EmbeddedElectronicSolutionsLtdDbaE2Solutions => {
+                "Embedded Electronic Solutions Ltd. dba e2Solutions"
+            }
+            Self::OcosmosCoLtd => "OCOSMOS Co., Ltd.",
+            Self::KronosIncorporated => "Kronos Incorporated",
+            Self::PrecisionOutcomesLtd => "Precision Outcomes Ltd",
+            Self::SharpCorporation => "Sharp Corporation",
+            Self::LlcMegaFService => "LLC \"MEGA-F service\"",
+            Self::SocieteDesProduitsNestleSA => "Société des Produits Nestlé S.A.",
+            Self::LulabytesSL => "lulabytes S.L.",
+            Self::MicrodiaLtd => "MICRODIA Ltd.",
+            Self::CronologicsCorporation => "Cronologics Corporation",
+            Self::ApptionLabsInc => "Apption Labs Inc.",
+            Self::Algoria => "Algoria",
+            Self::ShenzhenIMcoElectronicTechnologyCoLtd => {
+                "Shenzhen iMCO Electronic Technology Co.,Ltd"
+            }
+            Self::PropellerHealth => "Propeller Health",
+            Self::PlejdAb => "Plejd AB",
+            Self::ElectronicTemperatureInstrumentsLtd => "Electronic Temperature Instruments Ltd",
+            Self::ExpainAs => "Expain AS",
+            Self::HolmanIndustries => "Holman Industries",
+            Self::AppNearMeLtd => "AppNearMe Ltd",
+            Self::NixieLabsInc => "Nixie Labs, Inc.",
+            Self::Orbcomm => "ORBCOMM",
+            Self::WazombiLabsOü => "Wazombi Labs OÜ",
+            Self::MotivInc => "Motiv, Inc.",
+            Self::MotiveTechnologiesInc => "MOTIVE TECHNOLOGIES, INC.",
+            Self::AirBoltPtyLtd => "AirBolt Pty Ltd",
+            Self::Zipcar => "Zipcar",
+            Self::BrControlsProductsBv => "BRControls Products BV",
+            Self::SetPointMedical => "SetPoint Medical",
+            Self::LittleBits => "littleBits",
+            Self::MetormoteAb => "Metormote AB",
+            Self::SapheInternational => "Saphe International",
+            Self::BolttSportsTechnologiesPrivateLimited => {
+                "BOLTT Sports technologies Private limited"
+            }
+            Self::BioMechSensorLlc => "BioMech Sensor LLC",
+            Self::FaveroElectronicsSrl => "Favero Electronics Srl",
+            Self::FreelapSa => "FREELAP SA",
+            Self::OnSemiconductor => "ON Semiconductor",
+            Self::WellinksInc => "Wellinks Inc.",
+            Self::InsuletCorporation => "Insulet Corporation",
+            Self::Acromag => "Acromag",
+            Self::NayaHealthInc => "Naya Health, Inc.",
+            Self::Kys => "KYS",
+            Self::EatonCorporation => "Eaton Corporation",
+            Self::MatrixInc => "Matrix Inc.",
+            Self::PhillipsMedisizeAS => "Phillips-Medisize A/S",
+            Self::NovotecMedicalGmbH => "Novotec Medical GmbH",
+            Self::MagniWareLtd => "MagniWare Ltd.",
+            Self::PolymapWireless => "Polymap Wireless",
+            Self::SpectrumBrandsInc => "Spectrum Brands, Inc.",
+            Self::SigmaDesignsInc => "Sigma Designs, Inc.",
+            Self::ToppanFormsCoLtd => "TOPPAN FORMS CO.,LTD.",
+            Self::AlphaAudiotronicsInc => "Alpha Audiotronics, Inc.",
+            Self::IRidingXiamenTechnologyCoLtd => "iRiding(Xiamen)Technology Co.,Ltd.",
+            Self::PiepsGmbH => "Pieps GmbH",
+            Self::BitstrataSystemsInc => "Bitstrata Systems Inc.",
+            Self::HeartlandPaymentSystems => "Heartland Payment Systems",
+            Self::SafeTrustInc => "SafeTrust Inc.",
+            Self::TaserInternationalInc => "TASER International, Inc.",
+            Self::HmElectronicsInc => "HM Electronics, Inc.",
+            Self::LibratoneAS => "Libratone A/S",
+            Self::Vaddio => "Vaddio",
+            Self::VersaMe => "VersaMe",
+            Self::Arioneo => "Arioneo",
+            Self::PreventBiometrics => "Prevent Biometrics",
+            Self::AcuityBrandsLightingInc => "Acuity Brands Lighting, Inc",
+            Self::LocusPositioning => "Locus Positioning",
+            Self::WhirlInc => "Whirl Inc",
+            Self::DrekkerDevelopmentPtyLtd => "Drekker Development Pty. Ltd.",
+            Self::GertecBrasilLtda => "GERTEC BRASIL LTDA.",
+            Self::EtesianTechnologiesLlc => "Etesian Technologies LLC",
+            Self::LetsenseSRL => "Letsense s.r.l.",
+            Self::AutomationComponentsInc => "Automation Components, Inc.",
+            Self::MonitraSa => "Monitra SA",
+            Self::TpvTechnologyLimited => "TPV Technology Limited",
+            Self::Virtuosys => "Virtuosys",
+            Self::CourtneyThorneLimited => "Courtney Thorne Limited",
+            Self::AppceptionInc => "Appception, Inc.",
+            Self::BlueSkyScientificLlc0339 => "Blue Sky Scientific, LLC",
+            Self::CobiGmbH => "COBI GmbH",
+            Self::Ajp2HoldingsLlc => "AJP2 Holdings, LLC",
+            Self::Gistic => "GISTIC",
+            Self::EnlightedInc => "Enlighted Inc",
+            Self::AirthingsAsa => "Airthings ASA",
+            Self::MulTLock => "Mul-T-Lock",
+            Self::ElectrocompanietAS => "Electrocompaniet A.S.",
+            Self::_3FlaresTechnologiesInc => "3flares Technologies Inc.",
+            Self::NorthPoleEngineering => "North Pole Engineering",
+            Self::OttoQInc => "OttoQ Inc",
+            Self::Indoormap => "indoormap",
+            Self::BmInnovationsGmbH => "BM innovations GmbH",
+            Self::NipponSmtCoLtd => "NIPPON SMT.CO.,Ltd",
+            Self::Esylux => "ESYLUX",
+            Self::ElectronicDesignLab => "Electronic Design Lab",
+            Self::EargoInc => "Eargo, Inc.",
+            Self::GrundfosAS => "Grundfos A/S",
+            Self::EssexElectronics => "Essex Electronics",
+            Self::HealthwearTechnologiesChangzhouLtd => "Healthwear Technologies (Changzhou)Ltd",
+            Self::AmotusSolutions => "Amotus Solutions",
+            Self::AstroInc => "Astro, Inc.",
+            Self::RotorBikeComponents => "Rotor Bike Components",
+            Self::CompumedicsLimited => "Compumedics Limited",
+            Self::Jewelbots => "Jewelbots",
+            Self::SonoElectronicsCoLtd => "SONO ELECTRONICS. CO., LTD",
+            Self::MetaSystemSPA => "MetaSystem S.p.A.",
+            Self::EyefiInc => "Eyefi, Inc.",
+            Self::EnterlabApS => "Enterlab ApS",
+            Self::LabSensorSolutions => "Lab Sensor Solutions",
+            Self::HqInc => "HQ Inc",
+            Self::WurthElektronikEiSosGmbHAndCoKg => "Wurth Elektronik eiSos GmbH & Co. KG",
+            Self::EugsterFrismagAg => "Eugster Frismag AG",
+            Self::AspentaInternational => "Aspenta International",
+            Self::ChuoElectronicsCoLtd => "CHUO Electronics CO., LTD.",
+            Self::AgMeasurematicsPvtLtd => "AG Measurematics Pvt. Ltd.",
+            Self::ThermoFisherScientific => "Thermo Fisher Scientific",
+            Self::RiigAiSpZOO => "RIIG AI Sp. z o.o.",
+            Self::DiveNavInc => "DiveNav, Inc.",
+            Self::DucereTechnologiesPvtLtd => "Ducere Technologies Pvt Ltd",
+            Self::PeeqData => "PEEQ DATA",
+            Self::SglItaliaSRL => "SGL Italia S.r.l.",
+            Self::ShortcutLabs => "Shortcut Labs",
+            Self::Deviceworx => "Deviceworx",
+            Self::DevdataSRL => "Devdata S.r.l.",
+            Self::HiltiAg => "Hilti AG",
+            Self::MagnitudeLightingConverters => "Magnitude Lighting Converters",
+            Self::Ellisys => "Ellisys",
+            Self::DolbyLabs => "Dolby Labs",
+            Self::SurefireLlc => "Surefire, LLC",
+            Self::FujiIndustrialCoLtd => "FUJI INDUSTRIAL CO.,LTD.",
+            Self::LifeLaboratoryInc => "Life Laboratory Inc.",
+            Self::SwippApS => "Swipp ApS",
+            Self::OuraHealthLtd => "Oura Health Ltd",
+            Self::IacaElectronique => "IACA electronique",
+            Self::LoopDevicesInc => "Loop Devices, Inc",
+            Self::GiatecScientificInc => "Giatec Scientific Inc.",
+            Self::WorldMotoInc => "World Moto Inc.",
+            Self::SiliconLaboratories => "Silicon Laboratories",
+            Self::LierdaScienceAndTechnologyGroupCoLtd => {
+                "Lierda Science & Technology Group Co., Ltd."
+            }
+            Self::UwannaInc => "Uwanna, Inc.",
+            Self::ShanghaiFrequenMicroelectronicsCoLtd => {
+                "Shanghai Frequen Microelectronics Co., Ltd."
+            }
+            Self::ClariusMobileHealthCorp => "Clarius Mobile Health Corp.",
+            Self::CoStarTEchnologies => "CoSTAR TEchnologies",
+            Self::ImaginationTechnologiesLtd => "IMAGINATION TECHNOLOGIES LTD",
+            Self::RunteqOyLtd => "Runteq Oy Ltd",
+            Self::DreamVisionsCoLtd => "DreamVisions co., Ltd.",
+            Self::IntemoTechnologies => "Intemo Technologies",
+            Self::IndagemTechLlc => "Indagem Tech LLC",
+            Self::VensiInc => "Vensi, Inc.",
+            Self::AuthAirInc => "AuthAir, Inc",
+            Self::GoProInc => "GoPro, Inc.",
+            Self::TheIdeaCaveLlc => "The Idea Cave, LLC",
+            Self::BlackratSoftware => "Blackrat Software",
+            Self::SmartInnovationInc => "SMART-INNOVATION.inc",
+            Self::CitizenHoldingsCoLtd => "Citizen Holdings Co., Ltd.",
+            Self::HtcCorporation => "HTC Corporation",
+            Self::DeltaSystemsInc => "Delta Systems, Inc",
+            Self::ArdicTechnology => "Ardic Technology",
+            Self::FujitsuLimited => "Fujitsu Limited",
+            Self::SensogramTechnologiesInc => "Sensogram Technologies, Inc.",
+            Self::AmericanMusicEnvironments => "American Music Environments",
+            Self::ConnectedYardInc => "Connected Yard, Inc.",
+            Self::Unwire => "Unwire",
+            Self::EspressifSystemsShanghaiCoLtd => "Espressif Systems (Shanghai) Co., Ltd.",
+            Self::BytestormLtd => "Bytestorm Ltd.",
+            Self::CarmanahTechnologiesCorp => "Carmanah Technologies Corp.",
+            Self::NttDocomo => "NTT docomo",
+            Self::VictronEnergyBv => "Victron Energy BV",
+            Self::UniversityOfMichigan => "University of Michigan",
+            Self::BlurProductDevelopment => "Blur Product Development",
+            Self::SamsungSdsCoLtd => "Samsung SDS Co., Ltd.",
+            Self::FlintRehabilitationDevicesLlc => "Flint Rehabilitation Devices, LLC",
+            Self::DeWalchTechnologiesInc => "DeWalch Technologies, Inc.",
+            Self::DigiInternationalIncR => "Digi International Inc (R)",
+            Self::Gilvader => "Gilvader",
+            Self::FlieglAgrartechnikGmbH => "Fliegl Agrartechnik GmbH",
+            Self::Neosfar => "Neosfar",
+            Self::NipponSystemwareCoLtd => "NIPPON SYSTEMWARE CO.,LTD.",
+            Self::SendSolutions => "Send Solutions",
+            Self::OmronCorporation => "OMRON Corporation",
+            Self::SecuyouApS => "Secuyou ApS",
+            Self::PowercastCorporation => "Powercast Corporation",
+            Self::AferoInc => "Afero, Inc.",
+            Self::EmpaticaSrl => "Empatica Srl",
+            Self::_3M => "3M",
+            Self::Anima => "Anima",
+            Self::TevaBrandedPharmaceuticalProductsRAndDInc => {
+                "Teva Branded Pharmaceutical Products R&D, Inc."
+            }
+            Self::BmaErgonomicsBV => "BMA ergonomics b.v.",
+            Self::EijkelkampSoilAndWater => "Eijkelkamp Soil & Water",
+            Self::AinaWirelessInc => "AINA-Wireless Inc.",
+            Self::AbovSemiconductor => "ABOV Semiconductor",
+            Self::PayRangeInc => "PayRange Inc.",
+            Self::OneSpan => "OneSpan",
+            Self::ElectronicsTomorrowLimited => "Electronics Tomorrow Limited",
+            Self::AyatanSensors => "Ayatan Sensors",
+            Self::LenovoSingaporePteLtd => "Lenovo (Singapore) Pte Ltd.",
+            Self::WilsonSportingGoods => "Wilson Sporting Goods",
+            Self::TechtronicPowerToolsTechnologyLimited => {
+                "Techtronic Power Tools Technology Limited"
+            }
+            Self::GuillemotCorporation => "Guillemot Corporation",
+            Self::LineCorporation => "LINE Corporation",
+            Self::DashRobotics => "Dash Robotics",
+            Self::RedbirdFlightSimulations => "Redbird Flight Simulations",
+            Self::SeguroTechnologySpZOO => "Seguro Technology Sp. z o.o.",
+            Self::Chemtronics => "Chemtronics",
+            Self::GenevacLtd => "Genevac Ltd",
+            Self::KohaCoLtd => "Koha.,Co.Ltd",
+            Self::SwissprimeTechnologiesAg => "Swissprime Technologies AG",
+            Self::RinnaiCorporation => "Rinnai Corporation",
+            Self::ChronoTherapeutics => "Chrono Therapeutics",
+            Self::OortTechnologiesLlc => "Oort Technologies LLC",
+            Self::SchneiderElectric => "Schneider Electric",
+            Self::HanshinElectricRailwayCoLtd => "HANSHIN ELECTRIC RAILWAY CO.,LTD.",
+            Self::HyginexInc => "Hyginex, Inc.",
+            Self::ClaberSPA => "CLABER S.P.A.",
+            Self::OuraHealthOy => "Oura Health Oy",
+            Self::RadenInc => "Raden Inc",
+            Self::BestechnicShanghaiLtd => "Bestechnic(Shanghai),Ltd",
+            Self::TechnicolorUsaInc => "Technicolor USA Inc.",
+            Self::WeatherFlowInc => "WeatherFlow, Inc.",
+            Self::RxNetworksInc => "Rx Networks, Inc.",
+            Self::RtbElektronikGmbHAndCoKg => "RTB Elektronik GmbH & Co. KG",
+            Self::BbposLimited => "BBPOS Limited",
+            Self::DopplerLab => "Doppler Lab",
+            Self::Chargelib => "Chargelib",
+            Self::MiSportLtd => "miSport Ltd.",
+            Self::IlluxtronInternationalBV => "Illuxtron international B.V.",
+            Self::RobertBoschGmbH => "Robert Bosch GmbH",
+            Self::TendyronCorporation => "Tendyron Corporation",
+            Self::PacificLockCompany => "Pacific Lock Company",
+            Self::Itude => "Itude",
+            Self::Sera4Ltd => "Sera4 Ltd.",
+            Self::InventureTrackSystems => "InventureTrack Systems",
+            Self::ImpossibleCameraGmbH => "Impossible Camera GmbH",
+            Self::AreusEngineeringGmbH => "Areus Engineering GmbH",
+            Self::KupsonSpolSRO => "Kupson spol. s r.o.",
+            Self::AlottazsLabsLlc => "ALOTTAZS LABS, LLC",
+            Self::BlueSkyScientificLlc029C => "Blue Sky Scientific, LLC",
+            Self::C2DevelopmentInc => "C2 Development, Inc.",
+            Self::CurrantInc => "Currant, Inc.",
+            Self::InexessTechnologySimmaKg => "Inexess Technology Simma KG",
+            Self::EisstLtd => "EISST Ltd",
+            Self::StormPowerLtd => "storm power ltd",
+            Self::Petzl => "Petzl",
+            Self::SivantosGmbH => "Sivantos GmbH",
+            Self::EliasGmbH => "ELIAS GmbH",
+            Self::BlueBite => "Blue Bite",
+            Self::SwiftSensors => "SwiftSensors",
+            Self::CliniCloudInc => "CliniCloud Inc",
+            Self::MultibitOy => "Multibit Oy",
+            Self::ChurchAndDwightCoInc => "Church & Dwight Co., Inc",
+            Self::RfDigitalCorp => "RF Digital Corp",
+            Self::IfLlc => "IF, LLC",
+            Self::NanolinkAps => "NANOLINK APS",
+            Self::CodeGearsLtd => "Code Gears LTD",
+            Self::JetroAs => "Jetro AS",
+            Self::SkTelecom => "SK Telecom",
+            Self::WillowbankElectronicsLtd => "Willowbank Electronics Ltd",
+            Self::WallyVenturesSL => "Wally Ventures S.L.",
+            Self::RfCodeInc => "RF Code, Inc.",
+            Self::WowTechCanadaLtd => "WOWTech Canada Ltd.",
+            Self::SynapseElectronics => "Synapse Electronics",
+            Self::MavenMachinesInc => "Maven Machines, Inc.",
+            Self::SonovaAg => "Sonova AG",
+            Self::StoneL => "StoneL",
+            Self::ItecCorporation => "ITEC corporation",
+            Self::RuwidoAustriaGmbh => "ruwido austria gmbh",
+            Self::HabitAwareLlc => "HabitAware, LLC",
+            Self::HuaweiTechnologiesCoLtd => "HUAWEI Technologies Co., Ltd.",
+            Self::AseptikaLtd => "Aseptika Ltd",
+            Self::DefaAs => "DEFA AS",
+            Self::EkominiInc => "Ekomini inc.",
+            Self::SteuteSchaltgerateGmbHAndCoKg => "steute Schaltgerate GmbH & Co. KG",
+            Self::JohnsonOutdoorsInc => "Johnson Outdoors Inc",
+            Self::BewhereInc => "bewhere inc",
+            Self::EGOElektroGeraetebauGmbH => "E.G.O. Elektro-Geraetebau GmbH",
+            Self::Geotab => "Geotab",
+            Self::MotsaiResearch => "Motsai Research",
+            Self::Oceasoft => "OCEASOFT",
+            Self::AlpsAlpineCoLtd => "Alps Alpine Co., Ltd.",
+            Self::AnimasCorp => "Animas Corp",
+            Self::LsiAdlTechnology => "LSI ADL Technology",
+            Self::AptcodeSolutions => "Aptcode Solutions",
+            Self::FleurbaeyBvba => "FLEURBAEY BVBA",
+            Self::TechnogymSpa => "Technogym SPA",
+            Self::DomsterTadeuszSzydlowski => "Domster Tadeusz Szydlowski",
+            Self::DekaResearchAndDevelopmentCorp => "DEKA Research & Development Corp.",
+            Self::Gemalto => "Gemalto",
+            Self::TorroxGmbHAndCoKg => "Torrox GmbH & Co KG",
+            Self::Cerevo => "Cerevo",
+            Self::XmiSystemsSa => "XMI Systems SA",
+            Self::SchawbelTechnologiesLlc => "Schawbel Technologies LLC",
+            Self::SmkCorporation => "SMK Corporation",
+            Self::DdsInc => "DDS, Inc.",
+            Self::IdentivInc => "Identiv, Inc.",
+            Self::GlacialRidgeTechnologies => "Glacial Ridge Technologies",
+            Self::SecvreGmbH => "SECVRE GmbH",
+            Self::SensaRx => "SensaRx",
+            Self::YardarmTechnologies => "Yardarm Technologies",
+            Self::FlukeCorporation => "Fluke Corporation",
+            Self::LexmarkInternationalInc => "Lexmark International Inc.",
+            Self::NetEaseHangzhouNetworkCoLtd => "NetEase（Hangzhou）Network co.Ltd.",
+            Self::FiveInteractiveLlcDbaZendo => "Five Interactive, LLC dba Zendo",
+            Self::UniversityOfAppliedSciencesValaisHauteEcoleValaisanne => {
+                "University of Applied Sciences Valais/Haute Ecole Valaisanne"
+            }
+            Self::Altyor => "ALTYOR",
+            Self::DevialetSa => "Devialet SA",
+            Self::AdBabbleLocalCommerceInc => "AdBabble Local Commerce Inc.",
+            Self::G24PowerLimited => "G24 Power Limited",
+            Self::DaiNipponPrintingCoLtd => "Dai Nippon Printing Co., Ltd.",
+            Self::Playbrush => "Playbrush",
+            Self::XicatoInc => "Xicato Inc.",
+            Self::UkcTechnosolution => "UKC Technosolution",
+            Self::LumoBodytechInc => "Lumo Bodytech Inc.",
+            Self::SapphireCircuitsLlc => "Sapphire Circuits LLC",
+            Self::SchneiderSchreibgerateGmbH => "Schneider Schreibgeräte GmbH",
+            Self::MicrotronicsEngineeringGmbH => "Microtronics Engineering GmbH",
+            Self::MWaySolutionsGmbH => "M-Way Solutions GmbH",
+            Self::BlueCloverDevices => "Blue Clover Devices",
+            Self::OrlanLlc => "Orlan LLC",
+            Self::UwatecAg => "Uwatec AG",
+            Self::TranscranialLtd => "Transcranial Ltd",
+            Self::ParkerHannifinCorp => "Parker Hannifin Corp",
+            Self::FiftyThreeInc => "FiftyThree Inc.",
+            Self::AcKmeNetworksInc => "ACKme Networks, Inc.",
+            Self::EndressHauser => "Endress+Hauser",
+            Self::IoteraInc => "Iotera Inc",
+            Self::MasimoCorp => "Masimo Corp",
+            Self::_16LabInc => "16Lab Inc",
+            Self::BragiGmbH => "Bragi GmbH",
+            Self::ArgenoxTechnologies => "Argenox Technologies",
+            Self::WaveWareTechnologiesInc => "WaveWare Technologies Inc.",
+            Self::RavenIndustries => "Raven Industries",
+            Self::ViCentraBV => "ViCentra B.V.",
+            Self::Awarepoint => "Awarepoint",
+            Self::BeijingCarePulseElectronicTechnologyCoLtd => {
+                "Beijing CarePulse Electronic Technology Co, Ltd"
+            }
+            Self::AlatechTehnology => "Alatech Tehnology",
+            Self::JinCoLtd => "JIN CO, Ltd",
+            Self::Trakm8Ltd => "Trakm8 Ltd",
+            Self::MsHeliSRL => "MSHeli s.r.l.",
+            Self::PitpatpetLtd => "Pitpatpet Ltd",
+            Self::QrioInc => "Qrio Inc",
+            Self::FengFanBeiJingTechnologyCoLtd => "FengFan (BeiJing) Technology Co, Ltd",
+            Self::ShenzhenSuLongCommunicationLtd => "Shenzhen SuLong Communication Ltd",
+            Self::XSensoSolutionsKft => "x-Senso Solutions Kft",
+            Self::EtaSa => "ETA SA",
+            Self::FosterElectricCompanyLtd => "Foster Electric Company, Ltd",
+            Self::HuamiShanghaiCultureCommunicationCoLtd => {
+                "Huami (Shanghai) Culture Communication CO., LTD"
+            }
+            Self::SiemensAg => "Siemens AG",
+            Self::Lupine => "Lupine",
+            Self::PharynksCorporation => "Pharynks Corporation",
+            Self::TeslaInc => "Tesla, Inc.",
+            Self::StamerMusikanlagenGmbh => "Stamer Musikanlagen GMBH",
+            Self::MuovertiLimited => "Muoverti Limited",
+            Self::TwocanoesLabsLlc => "Twocanoes Labs, LLC",
+            Self::LifeBeamTechnologies => "LifeBEAM Technologies",
+            Self::MerliniaAS => "Merlinia A/S",
+            Self::NestleNespressoSA => "Nestlé Nespresso S.A.",
+            Self::ComarchSa => "Comarch SA",
+            Self::PhilipMorrisProductsSA => "Philip Morris Products S.A.",
+            Self::PraxisDynamics => "Praxis Dynamics",
+            Self::MobiquityNetworksInc => "Mobiquity Networks Inc",
+            Self::ManusMachinaBv => "Manus Machina BV",
+            Self::LusterLeafProductsInc => "Luster Leaf Products  Inc",
+            Self::GoodnetLtd => "Goodnet, Ltd",
+            Self::Edamic => "Edamic",
+            Self::MobicommInc => "Mobicomm Inc",
+            Self::CiscoSystemsInc => "Cisco Systems, Inc",
+            Self::BlueSpeckLabsLlc => "Blue Speck Labs, LLC",
+            Self::DottLimited => "DOTT Limited",
+            Self::HiotechAb => "Hiotech AB",
+            Self::Tech4HomeLda => "Tech4home, Lda",
+            Self::MtiLtd => "MTI Ltd",
+            Self::LukotonExperienceOy => "Lukoton Experience Oy",
+            Self::IkMultimediaProductionSrl => "IK Multimedia Production srl",
+            Self::WylerAg => "Wyler AG",
+            Self::InterplanCoLtd => "Interplan Co., Ltd",
+            Self::TelinkSemiconductorCoLtd => "Telink Semiconductor Co. Ltd",
+            Self::IkeGps => "ikeGPS",
+            Self::ComoduleGmbh => "Comodule GMBH",
+            Self::OmronHealthcareCoLtd => "Omron Healthcare Co., LTD",
+            Self::SimploTechnologyCoLtd => "Simplo Technology Co., LTD",
+            Self::CoroWareTechnologiesInc => "CoroWare Technologies, Inc",
+            Self::JaguarLandRoverLimited => "Jaguar Land Rover Limited",
+            Self::MacnicaInc => "Macnica Inc.",
+            Self::InvisionHeartInc => "InvisionHeart Inc.",
+            Self::LumiGeekLlc => "LumiGeek LLC",
+            Self::StempInc => "STEMP Inc.",
+            Self::OtterProductsLlc => "Otter Products, LLC",
+            Self::SmartboticsInc => "Smartbotics Inc.",
+            Self::TapcentiveInc => "Tapcentive Inc.",
+            Self::KemppiOy => "Kemppi Oy",
+            Self::RigadoLlc => "Rigado LLC",
+            Self::ArTiming => "AR Timing",
+            Self::VerifoneSystemsPteLtdTaiwanBranch => "Verifone Systems Pte Ltd. Taiwan Branch",
+            Self::FreescaleSemiconductorInc => "Freescale Semiconductor, Inc.",
+            Self::RadioSystemsCorporation => "Radio Systems Corporation",
+            Self::KontaktMicroLocationSpZOO => "Kontakt Micro-Location Sp. z o.o.",
+            Self::WahooFitnessLlc => "Wahoo Fitness, LLC",
+            Self::FormLiftingLlc => "Form Lifting, LLC",
+            Self::GozioInc => "Gozio Inc.",
+            Self::MedtronicInc => "Medtronic Inc.",
+            Self::AnykaGuangzhouMicroelectronicsTechnologyCoLtd => {
+                "Anyka (Guangzhou) Microelectronics Technology Co, LTD"
+            }
+            Self::GellinerLimited => "Gelliner Limited",
+            Self::DjoGlobal => "DJO Global",
+            Self::CoolWebthingsLimited => "Cool Webthings Limited",
+            Self::UtcFireAndSecurity => "UTC Fire and Security",
+            Self::TheUniversityOfTokyo => "The University of Tokyo",
+            Self::ItronInc => "Itron, Inc.",
+            Self::ZebraTechnologiesCorporation => "Zebra Technologies Corporation",
+            Self::KloudNation => "KloudNation",
+            Self::FullpowerTechnologiesInc => "Fullpower Technologies, Inc.",
+            Self::ValeoService => "Valeo Service",
+            Self::CuteCircuitLtd => "CuteCircuit LTD",
+            Self::SpreadtrumCommunicationsShanghaiLtd => "Spreadtrum Communications Shanghai Ltd",
+            Self::AutoMapLlc => "AutoMap LLC",
+            Self::AdvancedApplicationDesignInc => "Advanced Application Design, Inc.",
+            Self::SanoInc => "Sano, Inc.",
+            Self::Stir => "STIR",
+            Self::IpsGroupInc => "IPS Group Inc.",
+            Self::TechnologySolutionsUkLtd => "Technology Solutions (UK) Ltd",
+            Self::DynamicDevicesLtd => "Dynamic Devices Ltd",
+            Self::FreedomInnovations => "Freedom Innovations",
+            Self::CaterpillarInc => "Caterpillar Inc",
+            Self::LectronixInc => "Lectronix, Inc.",
+            Self::JollaLtd => "Jolla Ltd",
+            Self::WidexAS => "Widex A/S",
+            Self::BisonGroupLtd => "Bison Group Ltd.",
+            Self::MinelabElectronicsPtyLimited => "Minelab Electronics Pty Limited",
+            Self::KoninklijkePhilipsNV => "Koninklijke Philips N.V.",
+            Self::IParkingLtd => "iParking Ltd.",
+            Self::InnblueConsulting => "Innblue Consulting",
+            Self::LogitechInternationalSa => "Logitech International SA",
+            Self::SavantSystemsLlc => "Savant Systems LLC",
+            Self::CodeCorporation => "Code Corporation",
+            Self::SquadroneSystemsInc => "Squadrone Systems Inc.",
+            Self::GWearablesInc => "G-wearables inc.",
+            Self::EladSrl => "ELAD srl",
+            Self::NewlabSRL => "Newlab S.r.l.",
+            Self::SkyWaveDesign => "Sky Wave Design",
+            Self::GillElectronics => "Gill Electronics",
+            Self::AugustHomeInc => "August Home, Inc",
+            Self::PrimusInterParesLtd => "Primus Inter Pares Ltd",
+            Self::Bsh => "BSH",
+            Self::HouwaSystemDesignKK => "HOUWA SYSTEM DESIGN, k.k.",
+            Self::ChengduSynwingTechnologyLtd => "Chengdu Synwing Technology Ltd",
+            Self::SamLabsLtd => "Sam Labs Ltd.",
+            Self::FetchMyPet => "Fetch My Pet",
+            Self::LaerdalMedicalAs => "Laerdal Medical AS",
+            Self::AviOn => "Avi-on",
+            Self::PolyControlApS => "Poly-Control ApS",
+            Self::AbiogenixInc => "Abiogenix Inc.",
+            Self::HaswareInc => "HASWARE Inc.",
+            Self::BitcrazeAb => "Bitcraze AB",
+            Self::DmeMicroelectronics => "DME Microelectronics",
+            Self::Bunch => "Bunch",
+            Self::TransenergooilAg => "Transenergooil AG",
+            Self::BradatechCorp => "BRADATECH Corp.",
+            Self::PironexGmbH => "pironex GmbH",
+            Self::HongkongOnMicroElectronicsLimited => "Hongkong OnMicro Electronics Limited",
+            Self::PulsateMobileLtd => "Pulsate Mobile Ltd.",
+            Self::SyszoneCoLtd => "Syszone Co., Ltd",
+            Self::SenionLabAb => "SenionLab AB",
+            Self::CochlearBoneAnchoredSolutionsAb => "Cochlear Bone Anchored Solutions AB",
+            Self::StagesCyclingLlc => "Stages Cycling LLC",
+            Self::HanaMicron => "HANA Micron",
+            Self::ID3SL => "i+D3 S.L.",
+            Self::GeneralElectricCompany => "General Electric Company",
+            Self::LmTechnologiesLtd => "LM Technologies Ltd",
+            Self::NestLabsInc => "Nest Labs Inc.",
+            Self::TrineoSpZOO => "Trineo Sp. z o.o.",
+            Self::NytecInc => "Nytec, Inc.",
+            Self::NymiInc => "Nymi Inc.",
+            Self::NetizensSpZOO => "Netizens Sp. z o.o.",
+            Self::StarMicronicsCoLtd => "Star Micronics Co., Ltd.",
+            Self::SunriseMicroDevicesInc => "Sunrise Micro Devices, Inc.",
+            Self::EarlensCorporation => "Earlens Corporation",
+            Self::FlightSafetyInternational => "FlightSafety International",
+            Self::TrividiaHealthInc => "Trividia Health, Inc.",
+            Self::MetaPlatformsInc => "Meta Platforms, Inc.",
+            Self::GeophysicalTechnologyInc => "Geophysical Technology Inc.",
+            Self::CanonInc => "Canon Inc.",
+            Self::Taobao => "Taobao",
+            Self::EnergousCorporation => "ENERGOUS CORPORATION",
+            Self::WilleEngineering => "Wille Engineering",
+            Self::IconHealthAndFitness => "Icon Health and Fitness",
+            Self::MsaInnovationLlc => "MSA Innovation, LLC",
+            Self::Eroad => "EROAD",
+            Self::GigalaneCoLtd => "GIGALANE.CO.,LTD",
+            Self::Fiamm => "FIAMM",
+            Self::ChannelEnterprisesHkLtd => "Channel Enterprises (HK) Ltd.",
+            Self::StrainstallLtd => "Strainstall Ltd",
+            Self::Ceruus => "Ceruus",
+            Self::CvsHealth => "CVS Health",
+            Self::CokiyaIncorporated => "Cokiya Incorporated",
+            Self::CubetechSRO => "CUBETECH s.r.o.",
+            Self::TronForum => "TRON Forum",
+            Self::SaltoSystemsSL => "SALTO SYSTEMS S.L.",
+            Self::VengitKorlatoltFelelosseguTarsasag => "VENGIT Korlatolt Felelossegu Tarsasag",
+            Self::WiSilicaInc => "WiSilica Inc.",
+            Self::PaxtonAccessLtd => "Paxton Access Ltd",
+            Self::Zuli => "Zuli",
+            Self::AcousticStreamCorporation => "Acoustic Stream Corporation",
+            Self::MavericAutomationLlc => "Maveric Automation LLC",
+            Self::CloudleafInc => "Cloudleaf, Inc",
+            Self::FdkCorporation => "FDK CORPORATION",
+            Self::IntellettoTechnologiesInc => "Intelletto Technologies Inc.",
+            Self::FirefliesSystems => "Fireflies Systems",
+            Self::GoogleLlc => "Google LLC",
+            Self::ExtronDesignServices => "Extron Design Services",
+            Self::WiloSe => "Wilo SE",
+            Self::KonicaMinoltaInc => "Konica Minolta, Inc.",
+            Self::AbleTrendTechnologyLimited => "Able Trend Technology Limited",
+            Self::PhysicalEnterprisesInc => "Physical Enterprises Inc.",
+            Self::UnicoRbc => "Unico RBC",
+            Self::SeraphimSenseLtd => "Seraphim Sense Ltd",
+            Self::CoreLightingLtd => "CORE Lighting Ltd",
+            Self::BelAppsLlc => "bel'apps LLC",
+            Self::Nectar => "Nectar",
+            Self::WaltDisney => "Walt Disney",
+            Self::HopUbiquitous => "HOP Ubiquitous",
+            Self::GeckoHealthInnovationsInc => "Gecko Health Innovations, Inc.",
+            Self::GigasetTechnologiesGmbH => "Gigaset Technologies GmbH",
+            Self::XTelWirelessApS => "XTel Wireless ApS",
+            Self::BluDotzLtd => "BluDotz Ltd",
+            Self::BatAndCat => "BatAndCat",
+            Self::MercedesBenzGroupAg => "Mercedes-Benz Group AG",
+            Self::TaskitGmbH => "taskit GmbH",
+            Self::TelemonitorInc => "Telemonitor, Inc.",
+            Self::LapisSemiconductorCoLtd => "LAPIS Semiconductor Co.,Ltd",
+            Self::CasioComputerCoLtd => "CASIO COMPUTER CO., LTD.",
+            Self::ISystInc => "I-SYST inc.",
+            Self::SentriLock => "SentriLock",
+            Self::DynamicControls => "Dynamic Controls",
+            Self::EverykeyInc => "Everykey Inc.",
+            Self::KocomojoLlc => "Kocomojo, LLC",
+            Self::ConnovateTechnologyPrivateLimited => "Connovate Technology Private Limited",
+            Self::AmazonComServicesLlc => "Amazon.com Services LLC",
+            Self::RocheDiabetesCareAg => "Roche Diabetes Care AG",
+            Self::PodoLabsInc => "Podo Labs, Inc",
+            Self::VolanticAb => "Volantic AB",
+            Self::LifeScanInc => "LifeScan Inc",
+            Self::Mysphera => "MYSPHERA",
+            Self::Qblinks => "Qblinks",
+            Self::CopelandColdChainLp => "Copeland Cold Chain LP",
+            Self::Emberlight => "emberlight",
+            Self::SpiceboxLlc => "Spicebox LLC",
+            Self::AscensiaDiabetesCareUsInc => "Ascensia Diabetes Care US Inc.",
+            Self::MishikPteLtd => "MISHIK Pte Ltd",
+            Self::MilwaukeeElectricTools => "Milwaukee Electric Tools",
+            Self::QingdaoYeelinkInformationTechnologyCoLtd => {
+                "Qingdao Yeelink Information Technology Co., Ltd."
+            }
+            Self::PchInternational => "PCH International",
+            Self::MadsGlobalNzLtd => "MADSGlobalNZ Ltd.",
+            Self::Yikes => "yikes",
+            Self::AwoX => "AwoX",
+            Self::TimerCapCo => "Timer Cap Co.",
+            Self::UnikeyTechnologiesInc => "Unikey Technologies, Inc.",
+            Self::EstimoteInc => "Estimote, Inc.",
+            Self::PitiusTecSL => "Pitius Tec S.L.",
+            Self::BiomedicalResearchLtd => "Biomedical Research Ltd.",
+            Self::MicasAg => "micas AG",
+            Self::ChefStepsInc => "ChefSteps, Inc.",
+            Self::InmiteSRO => "Inmite s.r.o.",
+            Self::AnhuiHuamiInformationTechnologyCoLtd => {
+                "Anhui Huami Information Technology Co., Ltd."
+            }
+            Self::AccumulateAb => "Accumulate AB",
+            Self::Netatmo => "NETATMO",
+            Self::PebbleTechnology => "Pebble Technology",
+            Self::RolErgo => "ROL Ergo",
+            Self::VernierSoftwareAndTechnology => "Vernier Software & Technology",
+            Self::OnBeep => "OnBeep",
+            Self::PioneerCorporation => "Pioneer Corporation",
+            Self::BAndWGroupLtd => "B&W Group Ltd.",
+            Self::TangerineInc => "Tangerine, Inc.",
+            Self::HuizhouDesaySvAutomotiveCoLtd => "HUIZHOU DESAY SV AUTOMOTIVE CO., LTD.",
+            Self::MeshNetLtd => "Mesh-Net Ltd",
+            Self::MasterLock => "Master Lock",
+            Self::TivoliAudioLlc => "Tivoli Audio, LLC",
+            Self::PerytonsLtd => "Perytons Ltd.",
+            Self::AmbimatElectronics => "Ambimat Electronics",
+            Self::MightyCastInc => "Mighty Cast, Inc.",
+            Self::Ciright => "Ciright",
+            Self::NovatelWireless => "Novatel Wireless",
+            Self::LintechGmbH => "Lintech GmbH",
+            Self::BkonConnect => "Bkon Connect",
+            Self::GrapeSystemsInc => "Grape Systems Inc.",
+            Self::FedExServices => "FedEx Services",
+            Self::AlpineElectronicsChinaCoLtd => "Alpine Electronics (China) Co., Ltd",
+            Self::BAndBManufacturingCompany => "B&B Manufacturing Company",
+            Self::NodInc => "Nod, Inc.",
+            Self::WirelessWerx => "WirelessWERX",
+            Self::MurataManufacturingCoLtd => "Murata Manufacturing Co., Ltd.",
+            Self::Allegion => "Allegion",
+            Self::TencentHoldingsLtd => "Tencent Holdings Ltd.",
+            Self::FocusSystemsCorporation => "Focus Systems Corporation",
+            Self::NteoInc => "NTEO Inc.",
+            Self::PrestigioPlazaLtd => "Prestigio Plaza Ltd.",
+            Self::SilvairInc => "Silvair, Inc.",
+            Self::AirewareLlc => "Aireware LLC",
+            Self::ResolutionProductsLtd => "Resolution Products, Ltd.",
+            Self::BlueMaestroLimited => "Blue Maestro Limited",
+            Self::MadsInc => "MADS Inc",
+            Self::CypressSemiconductor => "Cypress Semiconductor",
+            Self::WarehouseInnovations => "Warehouse Innovations",
+            Self::ClarionCoInc => "Clarion Co. Inc.",
+            Self::AssaAbloy => "ASSA ABLOY",
+            Self::SonyCorporation => "Sony Corporation",
+            Self::TemecInstrumentsBV => "TEMEC Instruments B.V.",
+            Self::SportIq => "SportIQ",
+            Self::ChangzhouYongseInfotechCoLtd => "Changzhou Yongse Infotech  Co., Ltd.",
+            Self::NimbleDevicesOy => "Nimble Devices Oy",
+            Self::GpsiGroupPtyLtd => "GPSI Group Pty Ltd",
+            Self::SaluticaAlliedSolutions => "Salutica Allied Solutions",
+            Self::PrometheanLtd => "Promethean Ltd.",
+            Self::SeatEs => "SEAT es",
+            Self::HidGlobal => "HID Global",
+            Self::KinsaInc => "Kinsa, Inc",
+            Self::AirTurnInc => "AirTurn, Inc.",
+            Self::SinoWealthElectronicLtd => "Sino Wealth Electronic Ltd.",
+            Self::PorscheAg => "Porsche AG",
+            Self::VolkswagenAg => "Volkswagen AG",
+            Self::SkodaAutoAS => "Skoda Auto a.s.",
+            Self::ArendiAg => "Arendi AG",
+            Self::Baidu => "Baidu",
+            Self::HewlettPackardEnterprise => "Hewlett Packard Enterprise",
+            Self::QualcommLabsInc => "Qualcomm Labs, Inc.",
+            Self::WizeTechnologyCoLtd => "Wize Technology Co., Ltd.",
+            Self::RadiusNetworksInc => "Radius Networks, Inc.",
+            Self::WimotoTechnologiesInc => "Wimoto Technologies Inc",
+            Self::_10AkTechnologies => "10AK Technologies",
+            Self::ESolutions => "e.solutions",
+            Self::Xensr => "Xensr",
+            Self::OpenbrainTechnologiesCoLtd => "Openbrain Technologies, Co., Ltd.",
+            Self::VisyblInc => "Visybl Inc.",
+            Self::SteelseriesApS => "Steelseries ApS",
+            Self::NipponSeikiCoLtd => "Nippon Seiki Co., Ltd.",
+            Self::HiSiliconTechnologiesCoLimited => "HiSilicon Technologies CO., LIMITED",
+            Self::AudiAg => "Audi AG",
+            Self::DensoTenLimited => "DENSO TEN Limited",
+            Self::TransducersDirectLlc => "Transducers Direct, LLC",
+            Self::ERiInc => "ERi, Inc",
+            Self::CodegateLtd => "Codegate Ltd",
+            Self::AtusBv => "Atus BV",
+            Self::ChiconyElectronicsCoLtd => "Chicony Electronics Co., Ltd.",
+            Self::DemantAS => "Demant A/S",
+            Self::InnovativeYachtterSolutions => "Innovative Yachtter Solutions",
+            Self::UbiquitousComputingTechnologyCorporation => {
+                "Ubiquitous Computing Technology Corporation"
+            }
+            Self::PlusLocationSystemsPtyLtd => "PLUS Location Systems Pty Ltd",
+            Self::BangAndOlufsenAS => "Bang & Olufsen A/S",
+            Self::KeiserCorporation => "Keiser Corporation",
+            Self::FugooInc => "Fugoo, Inc.",
+            Self::TomTomInternationalBv => "TomTom International BV",
+            Self::TypoProductsLlc => "Typo Products, LLC",
+            Self::StanleyBlackAndDecker => "Stanley Black and Decker",
+            Self::ValenceTechLimited => "ValenceTech Limited",
+            Self::DelphiCorporation => "Delphi Corporation",
+            Self::KoukaamAS => "KOUKAAM a.s.",
+            Self::CrystalAlarmAb => "Crystal Alarm AB",
+            Self::StickNFind => "StickNFind",
+            Self::AceUniCorpLtd => "AceUni Corp., Ltd.",
+            Self::VsnTechnologiesInc => "VSN Technologies, Inc.",
+            Self::ElcometerLimited => "Elcometer Limited",
+            Self::SmartifierOy => "Smartifier Oy",
+            Self::NautilusInc => "Nautilus Inc.",
+            Self::KentDisplaysInc => "Kent Displays Inc.",
+            Self::MorseProjectInc => "Morse Project Inc.",
+            Self::WitronTechnologyLimited => "Witron Technology Limited",
+            Self::PayPalInc => "PayPal, Inc.",
+            Self::BitsplittersGmbH => "Bitsplitters GmbH",
+            Self::AboveAverageOutcomesInc => "Above Average Outcomes, Inc.",
+            Self::JollyLogicLlc => "Jolly Logic, LLC",
+            Self::BioResearchAssociates => "BioResearch Associates",
+            Self::ServerTechnologyInc => "Server Technology Inc.",
+            Self::NielsenKellerman => "Nielsen-Kellerman",
+            Self::VtrackSystems => "Vtrack Systems",
+            Self::ActsTechnologies => "ACTS Technologies",
+            Self::KsTechnologies => "KS Technologies",
+            Self::Freshtemp => "Freshtemp",
+            Self::EdenSoftwareConsultantsLtd => "Eden Software Consultants Ltd.",
+            Self::LSResearchInc => "L.S. Research, Inc.",
+            Self::InMusicBrandsInc => "inMusic Brands, Inc",
+            Self::SemilinkInc => "Semilink Inc",
+            Self::DanlersLtd => "Danlers Ltd",
+            Self::Google => "Google",
+            Self::MisfitWearablesCorp => "Misfit Wearables Corp",
+            Self::MuzikLlc => "Muzik LLC",
+            Self::HosidenCorporation => "Hosiden Corporation",
+            Self::ProcterAndGamble => "Procter & Gamble",
+            Self::SnuzaPtyLtd => "Snuza (Pty) Ltd",
+            Self::TxtrGmbH => "txtr GmbH",
+            Self::VoyetraTurtleBeach => "Voyetra Turtle Beach",
+            Self::QualcommConnectedExperiencesInc => "Qualcomm Connected Experiences, Inc.",
+            Self::QualcommTechnologiesInc => "Qualcomm Technologies, Inc.",
+            Self::TimexGroupUsaInc => "Timex Group USA, Inc.",
+            Self::AustcoCommunicationSystems => "Austco Communication Systems",
+            Self::Kawantech => "Kawantech",
+            Self::TaixingbangTechnologyHkCoLtd => "Taixingbang Technology (HK) Co,. LTD.",
+            Self::RenesasDesignNetherlandsBV => "Renesas Design Netherlands B.V.",
+            Self::PolarElectroEuropeBV => "Polar Electro Europe B.V.",
+            Self::DexcomInc => "Dexcom, Inc.",
+            Self::ArchosSa => "ARCHOS SA",
+            Self::EveSystemsGmbH => "Eve Systems GmbH",
+            Self::MicrochipTechnologyInc => "Microchip Technology Inc.",
+            Self::BeatsElectronics => "Beats Electronics",
+            Self::BinauricSe => "Binauric SE",
+            Self::Mc10 => "MC10",
+            Self::Evluma => "Evluma",
+            Self::GeLoInc => "GeLo Inc",
+            Self::QuuppaOy => "Quuppa Oy.",
+            Self::SelflyBv => "Selfly BV",
+            Self::OnsetComputerCorporation => "Onset Computer Corporation",
+            Self::LgElectronics => "LG Electronics",
+            Self::AdidasAg => "adidas AG",
+            Self::GeneqInc => "Geneq Inc.",
+            Self::ShenzhenExcelsecuDataTechnologyCoLtd => {
+                "Shenzhen Excelsecu Data Technology Co.,Ltd"
+            }
+            Self::AmiccomElectronicsCorporation => "AMICCOM Electronics Corporation",
+            Self::StalmartTechnologyLimited => "Stalmart Technology Limited",
+            Self::AampOfAmerica => "AAMP of America",
+            Self::AplixCorporation => "Aplix Corporation",
+            Self::AceSensorInc => "Ace Sensor Inc",
+            Self::SPowerElectronicsLimited => "S-Power Electronics Limited",
+            Self::StarkeyHearingTechnologies => "Starkey Hearing Technologies",
+            Self::JohnsonControlsInc => "Johnson Controls, Inc.",
+            Self::QualcommInnovationCenterIncQuIc => "Qualcomm Innovation Center, Inc. (QuIC)",
+            Self::TreLabLtd => "TreLab Ltd",
+            Self::MesoInternational => "Meso international",
+            Self::SwirlNetworks => "Swirl Networks",
+            Self::BdeTechnologyCoLtd => "BDE Technology Co., Ltd.",
+            Self::ClarinoxTechnologiesPtyLtd => "Clarinox Technologies Pty. Ltd.",
+            Self::BekeyAS => "Bekey A/S",
+            Self::SarisCyclingGroupInc => "Saris Cycling Group, Inc",
+            Self::PassifSemiconductorCorp => "Passif Semiconductor Corp",
+            Self::Cinetix => "Cinetix",
+            Self::OmegawaveOy => "Omegawave Oy",
+            Self::PeterSystemtechnikGmbH => "Peter Systemtechnik GmbH",
+            Self::GreenThrottleGames => "Green Throttle Games",
+            Self::IngenieurSystemgruppeZahnGmbH => "Ingenieur-Systemgruppe Zahn GmbH",
+            Self::CaenRfidSrl => "CAEN RFID srl",
+            Self::MarelliEuropeSPA => "MARELLI EUROPE S.P.A.",
+            Self::ArpDevicesLimited => "ARP Devices Limited",
+            Self::VisteonCorporation => "Visteon Corporation",
+            Self::PandaOceanInc => "Panda Ocean Inc.",
+            Self::OtlDynamicsLlc => "OTL Dynamics LLC",
+            Self::LinakAS => "LINAK A/S",
+            Self::MetaWatchLtd => "Meta Watch Ltd.",
+            Self::VertuCorporationLimited => "Vertu Corporation Limited",
+            Self::SrMedizinelektronik => "SR-Medizinelektronik",
+            Self::KensingtonComputerProductsGroup => "Kensington Computer Products Group",
+            Self::SuuntoOy => "Suunto Oy",
+            Self::BoseCorporation => "Bose Corporation",
+            Self::GeoforceInc => "Geoforce Inc.",
+            Self::ColorfyInc => "Colorfy, Inc.",
+            Self::JiangsuToppowerAutomotiveElectronicsCoLtd => {
+                "Jiangsu Toppower Automotive Electronics Co., Ltd."
+            }
+            Self::Alpwise => "Alpwise",
+            Self::ITechDynamicGlobalDistributionLtd => "i.Tech Dynamic Global Distribution Ltd.",
+            Self::Zero1TvGmbH => "zero1.tv GmbH",
+            Self::ConnecteDeviceLtd => "ConnecteDevice Ltd.",
+            Self::OdmTechnologyInc => "ODM Technology, Inc.",
+            Self::NecLightingLtd => "NEC Lighting, Ltd.",
+            Self::AirohaTechnologyCorp0094 => "Airoha Technology Corp.",
+            Self::UniversalElectronicsInc => "Universal Electronics, Inc.",
+            Self::ThinkOpticsInc => "ThinkOptics, Inc.",
+            Self::AdvancedPanmobilSystemsGmbHAndCoKg => "Advanced PANMOBIL systems GmbH & Co. KG",
+            Self::FunaiElectricCoLtd => "Funai Electric Co., Ltd.",
+            Self::TelitWirelessSolutionsGmbH => "Telit Wireless Solutions GmbH",
+            Self::QuinticCorp => "Quintic Corp",
+            Self::ZscanSoftware => "Zscan Software",
+            Self::GimbalInc => "Gimbal Inc.",
+            Self::TopconPositioningSystemsLlc => "Topcon Positioning Systems, LLC",
+            Self::Jawbone => "Jawbone",
+            Self::GnHearingAS => "GN Hearing A/S",
+            Self::Ecotest => "Ecotest",
+            Self::GarminInternationalInc => "Garmin International, Inc.",
+            Self::EquinuxAg => "Equinux AG",
+            Self::BlueRadiosInc => "BlueRadios, Inc.",
+            Self::LudusHelsinkiLtd => "Ludus Helsinki Ltd.",
+            Self::TimeKeepingSystemsInc => "TimeKeeping Systems, Inc.",
+            Self::DseaAS => "DSEA A/S",
+            Self::WuXiVimicro => "WuXi Vimicro",
+            Self::DeLormePublishingCompanyInc => "DeLorme Publishing Company, Inc.",
+            Self::AutonetMobile => "Autonet Mobile",
+            Self::SportsTrackingTechnologiesLtd => "Sports Tracking Technologies Ltd.",
+            Self::SeersTechnologyCoLtd => "Seers Technology Co., Ltd.",
+            Self::AAndRCambridge => "A & R Cambridge",
+            Self::HanlynnTechnologies => "Hanlynn Technologies",
+            Self::MStarSemiconductorInc => "MStar Semiconductor, Inc.",
+            Self::LesswireAg => "lesswire AG",
+            Self::NikeInc => "Nike, Inc.",
+            Self::LairdConnectivityLlc => "Laird Connectivity LLC",
+            Self::CreativeTechnologyLtd => "Creative Technology Ltd.",
+            Self::SamsungElectronicsCoLtd => "Samsung Electronics Co. Ltd.",
+            Self::ZommLlc => "Zomm, LLC",
+            Self::GroupSenseLtd => "Group Sense Ltd.",
+            Self::ShangHaiSuperSmartElectronicsCoLtd => "ShangHai Super Smart Electronics Co. Ltd.",
+            Self::ConnectBlueAb => "connectBlue AB",
+            Self::MonsterLlc => "Monster, LLC",
+            Self::SoundId => "Sound ID",
+            Self::SummitDataCommunicationsInc => "Summit Data Communications, Inc.",
+            Self::BriarTekInc => "BriarTek, Inc",
+            Self::BeautifulEnterpriseCoLtd => "Beautiful Enterprise Co., Ltd.",
+            Self::PolarElectroOy => "Polar Electro OY",
+            Self::LtimindtreeLimited => "LTIMINDTREE LIMITED",
+            Self::AAndDEngineeringInc => "A&D Engineering, Inc.",
+            Self::GeneralMotors => "General Motors",
+            Self::GnAudioAS => "GN Audio A/S",
+            Self::_9SolutionsOy => "9Solutions Oy",
+            Self::HpInc => "HP, Inc.",
+            Self::BandXiInternationalLlc => "Band XI International, LLC",
+            Self::MiCommandInc => "MiCommand Inc.",
+            Self::GibsonGuitars => "Gibson Guitars",
+            Self::RdaMicroelectronics => "RDA Microelectronics",
+            Self::RivieraWavesSAS => "RivieraWaves S.A.S",
+            Self::WicentricInc => "Wicentric, Inc.",
+            Self::StonestreetOneLlc => "Stonestreet One, LLC",
+            Self::RealtekSemiconductorCorporation => "Realtek Semiconductor Corporation",
+            Self::BelkinInternationalInc => "Belkin International, Inc.",
+            Self::RalinkTechnologyCorporation => "Ralink Technology Corporation",
+            Self::EmMicroelectronicMarinSa => "EM Microelectronic-Marin SA",
+            Self::NordicSemiconductorAsa => "Nordic Semiconductor ASA",
+            Self::VizioInc => "Vizio, Inc.",
+            Self::HarmanInternationalIndustriesInc => "Harman International Industries, Inc.",
+            Self::SonyEricssonMobileCommunications => "Sony Ericsson Mobile Communications",
+            Self::PlantronicsInc => "Plantronics, Inc.",
+            Self::_3DiJoyCorporation => "3DiJoy Corporation",
+            Self::Free2MoveAb => "Free2move AB",
+            Self::JAndMCorporation => "J&M Corporation",
+            Self::TzeroTechnologiesInc => "Tzero Technologies, Inc.",
+            Self::SiRfTechnologyInc => "SiRF Technology, Inc.",
+            Self::AptLtd => "APT Ltd.",
+            Self::AvagoTechnologies => "Avago Technologies",
+            Self::StaccatoCommunicationsInc => "Staccato Communications, Inc.",
+            Self::AppleInc => "Apple, Inc.",
+            Self::ContinentalAutomotiveSystems => "Continental Automotive Systems",
+            Self::AccelSemiconductorLtd => "Accel Semiconductor Ltd.",
+            Self::_3DspCorporation => "3DSP Corporation",
+            Self::MarvellTechnologyGroupLtd => "Marvell Technology Group Ltd.",
+            Self::Bluegiga => "Bluegiga",
+            Self::MediaTekInc => "MediaTek, Inc.",
+            Self::AtherosCommunicationsInc => "Atheros Communications, Inc.",
+            Self::SocketMobile => "Socket Mobile",
+            Self::ParrotAutomotiveSas => "PARROT AUTOMOTIVE SAS",
+            Self::ConwiseTechnologyCorporationLtd => "CONWISE Technology Corporation Ltd",
+            Self::IntegratedSiliconSolutionTaiwanInc => "Integrated Silicon Solution Taiwan, Inc.",
+            Self::SeikoEpsonCorporation => "Seiko Epson Corporation",
+            Self::BluetoothSigInc => "Bluetooth SIG, Inc",
+            Self::SystemsAndChipsInc => "Systems and Chips, Inc",
+            Self::IPextremeInc => "IPextreme, Inc.",
+            Self::BlackBerryLimited => "BlackBerry Limited",
+            Self::GennumCorporation => "Gennum Corporation",
+            Self::PanasonicHoldingsCorporation => "Panasonic Holdings Corporation",
+            Self::IntegratedSystemSolutionCorp => "Integrated System Solution Corp.",
+            Self::SyntronixCorporation => "Syntronix Corporation",
+            Self::MobilianCorporation => "Mobilian Corporation",
+            Self::RenesasElectronicsCorporation => "Renesas Electronics Corporation",
+            Self::EclipseHqEspanaSL => "Eclipse (HQ Espana) S.L.",
+            Self::ComputerAccessTechnologyCorporationCatc => {
+                "Computer Access Technology Corporation (CATC)"
+            }
+            Self::CommilLtd => "Commil Ltd",
+            Self::RedMCommunicationsLtd => "Red-M (Communications) Ltd",
+            Self::SynopsysInc => "Synopsys, Inc.",
+            Self::StMicroelectronics => "ST Microelectronics",
+            Self::MewTelTechnologyInc => "MewTel Technology Inc.",
+            Self::NorwoodSystems => "Norwood Systems",
+            Self::GctSemiconductor => "GCT Semiconductor",
+            Self::MacronixInternationalCoLtd => "Macronix International Co. Ltd.",
+            Self::Tenovis => "Tenovis",
+            Self::SymbolTechnologiesInc => "Symbol Technologies, Inc.",
+            Self::HitachiLtd => "Hitachi Ltd",
+            Self::RFMicroDevices => "R F Micro Devices",
+            Self::OpenInterface => "Open Interface",
+            Self::CTechnologies => "C Technologies",
+            Self::NxpBV => "NXP B.V.",
+            Self::Alcatel => "Alcatel",
+            Self::WavePlusTechnologyCoLtd => "WavePlus Technology Co., Ltd.",
+            Self::NecCorporation => "NEC Corporation",
+            Self::MansellaLtd => "Mansella Ltd",
+            Self::BandSpeedInc => "BandSpeed, Inc.",
+            Self::AvmBerlin => "AVM Berlin",
+            Self::Inventel => "Inventel",
+            Self::Qualcomm => "Qualcomm",
+            Self::ConexantSystemsInc => "Conexant Systems Inc.",
+            Self::SigniaTechnologiesInc => "Signia Technologies, Inc.",
+            Self::TtpComLimited => "TTPCom Limited",
+            Self::RohdeAndSchwarzGmbHAndCoKg => "Rohde & Schwarz GmbH & Co. KG",
+            Self::TransilicaInc => "Transilica, Inc.",
+            Self::Newlogic => "Newlogic",
+            Self::KcTechnologyInc => "KC Technology Inc.",
+            Self::RtxAS => "RTX A/S",
+            Self::MitsubishiElectricCorporation => "Mitsubishi Electric Corporation",
+            Self::AtmelCorporation => "Atmel Corporation",
+            Self::ZeevoInc => "Zeevo, Inc.",
+            Self::WidcommInc => "Widcomm, Inc.",
+            Self::MitelSemiconductor => "Mitel Semiconductor",
+            Self::BroadcomCorporation => "Broadcom Corporation",
+            Self::ParthusTechnologiesInc => "Parthus Technologies Inc.",
+            Self::TexasInstrumentsInc => "Texas Instruments Inc.",
+            Self::DigianswerAS => "Digianswer A/S",
+            Self::SiliconWave => "Silicon Wave",
+            Self::QualcommTechnologiesInternationalLtdQtil => {
+                "Qualcomm Technologies International, Ltd. (QTIL)"
+            }
+            Self::InfineonTechnologiesAg => "Infineon Technologies AG",
+            Self::Motorola => "Motorola",
+            Self::Lucent => "Lucent",
+            Self::Microsoft => "Microsoft",
+            Self::_3Com => "3Com",
+            Self::ToshibaCorp => "Toshiba Corp.",
+            Self::IbmCorp => "IBM Corp.",
+            Self::IntelCorp => "Intel Corp.",
+            Self::NokiaMobilePhones => "Nokia Mobile Phones",
+            Self::EricssonAb => "Ericsson AB",
+        }
+    }
+}
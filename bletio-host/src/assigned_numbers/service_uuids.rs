@@ -160,3 +160,82 @@ pub enum ServiceUuid {
     /// Ranging Service (0x185B) — org.bluetooth.service.ranging
     Ranging = 0x185B,
 }
+
+impl ServiceUuid {
+    /// Get the name of the service, as defined in the assigned numbers.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Gap => "GAP",
+            Self::Gatt => "GATT",
+            Self::ImmediateAlert => "Immediate Alert",
+            Self::LinkLoss => "Link Loss",
+            Self::TxPower => "Tx Power",
+            Self::CurrentTime => "Current Time",
+            Self::ReferenceTimeUpdate => "Reference Time Update",
+            Self::NextDstChange => "Next DST Change",
+            Self::Glucose => "Glucose",
+            Self::HealthThermometer => "Health Thermometer",
+            Self::DeviceInformation => "Device Information",
+            Self::HeartRate => "Heart Rate",
+            Self::PhoneAlertStatus => "Phone Alert Status",
+            Self::Battery => "Battery",
+            Self::BloodPressure => "Blood Pressure",
+            Self::AlertNotification => "Alert Notification",
+            Self::HumanInterfaceDevice => "Human Interface Device",
+            Self::ScanParameters => "Scan Parameters",
+            Self::RunningSpeedAndCadence => "Running Speed and Cadence",
+            Self::AutomationIo => "Automation IO",
+            Self::CyclingSpeedAndCadence => "Cycling Speed and Cadence",
+            Self::CyclingPower => "Cycling Power",
+            Self::LocationAndNavigation => "Location and Navigation",
+            Self::EnvironmentalSensing => "Environmental Sensing",
+            Self::BodyComposition => "Body Composition",
+            Self::UserData => "User Data",
+            Self::WeightScale => "Weight Scale",
+            Self::BondManagement => "Bond Management",
+            Self::ContinuousGlucoseMonitoring => "Continuous Glucose Monitoring",
+            Self::InternetProtocolSupport => "Internet Protocol Support",
+            Self::IndoorPositioning => "Indoor Positioning",
+            Self::PulseOximeter => "Pulse Oximeter",
+            Self::HttpProxy => "HTTP Proxy",
+            Self::TransportDiscovery => "Transport Discovery",
+            Self::ObjectTransfer => "Object Transfer",
+            Self::FitnessMachine => "Fitness Machine",
+            Self::MeshProvisioning => "Mesh Provisioning",
+            Self::MeshProxy => "Mesh Proxy",
+            Self::ReconnectionConfiguration => "Reconnection Configuration",
+            Self::InsulinDelivery => "Insulin Delivery",
+            Self::BinarySensor => "Binary Sensor",
+            Self::EmergencyConfiguration => "Emergency Configuration",
+            Self::AuthorizationControl => "Authorization Control",
+            Self::PhysicalActivityMonitor => "Physical Activity Monitor",
+            Self::ElapsedTime => "Elapsed Time",
+            Self::GenericHealthSensor => "Generic Health Sensor",
+            Self::AudioInputControl => "Audio Input Control",
+            Self::VolumeControl => "Volume Control",
+            Self::VolumeOffsetControl => "Volume Offset Control",
+            Self::CoordinatedSetIdentification => "Coordinated Set Identification",
+            Self::DeviceTime => "Device Time",
+            Self::MediaControl => "Media Control",
+            Self::GenericMediaControl => "Generic Media Control",
+            Self::ConstantToneExtension => "Constant Tone Extension",
+            Self::TelephoneBearer => "Telephone Bearer",
+            Self::GenericTelephoneBearer => "Generic Telephone Bearer",
+            Self::MicrophoneControl => "Microphone Control",
+            Self::AudioStreamControl => "Audio Stream Control",
+            Self::BroadcastAudioScan => "Broadcast Audio Scan",
+            Self::PublishedAudioCapabilities => "Published Audio Capabilities",
+            Self::BasicAudioAnnouncement => "Basic Audio Announcement",
+            Self::BroadcastAudioAnnouncement => "Broadcast Audio Announcement",
+            Self::CommonAudio => "Common Audio",
+            Self::HearingAccess => "Hearing Access",
+            Self::TelephonyAndMediaAudio => "Telephony and Media Audio",
+            Self::PublicBroadcastAnnouncement => "Public Broadcast Announcement",
+            Self::ElectronicShelfLabel => "Electronic Shelf Label",
+            Self::GamingAudio => "Gaming Audio",
+            Self::MeshProxySolicitation => "Mesh Proxy Solicitation",
+            Self::IndustrialMeasurementDevice => "Industrial Measurement Device",
+            Self::Ranging => "Ranging",
+        }
+    }
+}
@@ -1,32 +1,96 @@
+use core::cell::RefCell;
+use core::time::Duration;
+
 use bletio_hci::{
-    ConnectionPeerAddress, DisconnectionCompleteEvent, Event, EventList, Hci, HciDriver,
-    LeAdvertisingReport, LeAdvertisingReportEventType, LeConnectionCompleteEvent,
-    LeConnectionUpdateCompleteEvent, LeMetaEvent,
+    ConnectionPeerAddress, DisconnectionCompleteEvent, ErrorCode, Event, EventList, Hci, HciDriver,
+    IsoData, LeAdvertisingReportEventType, LeBigSyncEstablishedEvent, LeBigSyncLostEvent,
+    LeBiginfoAdvertisingReportEvent, LeConnectionCompleteEvent, LeConnectionUpdateCompleteEvent,
+    LeCreateBigCompleteEvent, LeMetaEvent, LeTerminateBigCompleteEvent, Rssi, TimeSource,
+    WithTimeout, ACL_DATA_MAX_SIZE, EVENT_LIST_NB_EVENTS, HCI_DEFAULT_EVENT_QUEUE_SIZE,
+    HCI_DEFAULT_ISO_DATA_QUEUE_SIZE, HCI_MAX_READ_BUFFER_SIZE,
 };
 
-use crate::advertising::FullAdvertisingData;
+use crate::advertising::{AdvertisingDataView, FullAdvertisingDataView, ScanFilter};
 use crate::assigned_numbers::AppearanceValue;
+use crate::host_event::{borrowed_advertising_reports, le_advertising_reports, ScanEvent};
+use crate::recovery::{ControllerResetCause, RecoveryPolicy};
+use crate::scan_response_cache::{
+    PendingAdvertisement, ScanResponseCache, DEFAULT_SCAN_RESPONSE_WINDOW,
+};
+use crate::scanned_devices::ScannedDeviceCache;
 use crate::{BleHost, BleHostObserver, BleHostStates, Error};
 
 #[derive(Debug)]
-pub struct BleDeviceBuilder<'a, O>
-where
+pub struct BleDeviceBuilder<
+    'a,
+    O,
+    T,
+    const EVENT_QUEUE_SIZE: usize = HCI_DEFAULT_EVENT_QUEUE_SIZE,
+    const READ_BUFFER_SIZE: usize = HCI_MAX_READ_BUFFER_SIZE,
+    const EVENT_LIST_SIZE: usize = EVENT_LIST_NB_EVENTS,
+    const ACL_DATA_SIZE: usize = ACL_DATA_MAX_SIZE,
+    const ISO_DATA_QUEUE_SIZE: usize = HCI_DEFAULT_ISO_DATA_QUEUE_SIZE,
+> where
     O: BleHostObserver,
+    T: TimeSource,
 {
     observer: O,
+    time_source: T,
     appearance: Option<AppearanceValue>,
     local_name: Option<&'a str>,
+    recovery_policy: Option<RecoveryPolicy>,
+    scan_response_window: Option<Duration>,
+    scanned_device_timeout: Option<Duration>,
 }
 
-impl<'a, O> BleDeviceBuilder<'a, O>
+impl<
+        'a,
+        O,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >
+    BleDeviceBuilder<
+        'a,
+        O,
+        T,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
 where
     O: BleHostObserver,
+    T: TimeSource,
 {
-    pub fn build(self) -> BleDevice<'a, O> {
+    pub fn build(
+        self,
+    ) -> BleDevice<
+        'a,
+        O,
+        T,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    > {
         BleDevice {
             observer: self.observer,
             appearance: self.appearance.unwrap_or(AppearanceValue::GenericUnknown),
             local_name: self.local_name.unwrap_or("bletio"),
+            recovery_policy: self.recovery_policy.unwrap_or_default(),
+            time_source: self.time_source,
+            scan_response_cache: RefCell::new(ScanResponseCache::new(
+                self.scan_response_window
+                    .unwrap_or(DEFAULT_SCAN_RESPONSE_WINDOW),
+            )),
+            scanned_devices: RefCell::new(ScannedDeviceCache::new(self.scanned_device_timeout)),
+            scan_filter: RefCell::new(None),
         }
     }
 
@@ -39,99 +103,600 @@ where
         self.local_name = Some(local_name);
         self
     }
+
+    pub fn with_recovery_policy(mut self, recovery_policy: RecoveryPolicy) -> Self {
+        self.recovery_policy = Some(recovery_policy);
+        self
+    }
+
+    /// Set the time during which a scannable advertisement received without its scan response
+    /// waits for it before being notified alone.
+    ///
+    /// A zero duration notifies the advertisements immediately, only pairing them with the scan
+    /// responses received in the same batch of events.
+    pub fn with_scan_response_window(mut self, window: Duration) -> Self {
+        self.scan_response_window = Some(window);
+        self
+    }
+
+    /// Track the devices whose advertising reports are notified, considering them lost once
+    /// they have not been seen for `timeout`, see [`scanned_devices`](crate::scanned_devices).
+    ///
+    /// The devices are not tracked if no timeout is given.
+    pub fn with_scanned_device_timeout(mut self, timeout: Duration) -> Self {
+        self.scanned_device_timeout = Some(timeout);
+        self
+    }
 }
 
-pub struct BleDevice<'a, O>
+impl<'a, O, T> BleDeviceBuilder<'a, O, T>
 where
     O: BleHostObserver,
+    T: TimeSource,
+{
+    /// Set the capacities of the HCI of the device, given by the const generic parameters of
+    /// its type as for [`Hci::with_event_queue`], e.g.
+    /// `let device: BleDevice<_, _, 32> = BleDevice::builder(observer, time_source).with_hci_capacities().build();`
+    /// for an event queue able to hold 32 events.
+    pub fn with_hci_capacities<
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >(
+        self,
+    ) -> BleDeviceBuilder<
+        'a,
+        O,
+        T,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    > {
+        BleDeviceBuilder {
+            observer: self.observer,
+            time_source: self.time_source,
+            appearance: self.appearance,
+            local_name: self.local_name,
+            recovery_policy: self.recovery_policy,
+            scan_response_window: self.scan_response_window,
+            scanned_device_timeout: self.scanned_device_timeout,
+        }
+    }
+}
+
+pub struct BleDevice<
+    'a,
+    O,
+    T,
+    const EVENT_QUEUE_SIZE: usize = HCI_DEFAULT_EVENT_QUEUE_SIZE,
+    const READ_BUFFER_SIZE: usize = HCI_MAX_READ_BUFFER_SIZE,
+    const EVENT_LIST_SIZE: usize = EVENT_LIST_NB_EVENTS,
+    const ACL_DATA_SIZE: usize = ACL_DATA_MAX_SIZE,
+    const ISO_DATA_QUEUE_SIZE: usize = HCI_DEFAULT_ISO_DATA_QUEUE_SIZE,
+> where
+    O: BleHostObserver,
+    T: TimeSource,
 {
     observer: O,
     appearance: AppearanceValue,
     local_name: &'a str,
+    recovery_policy: RecoveryPolicy,
+    time_source: T,
+    scan_response_cache: RefCell<ScanResponseCache<T::Instant>>,
+    scanned_devices: RefCell<ScannedDeviceCache<T::Instant>>,
+    scan_filter: RefCell<Option<ScanFilter>>,
 }
 
-impl<'a, O> BleDevice<'a, O>
+impl<'a, O, T> BleDevice<'a, O, T>
 where
     O: BleHostObserver,
+    T: TimeSource,
 {
-    pub fn builder(observer: O) -> BleDeviceBuilder<'a, O> {
+    /// Create a builder for a device notifying `observer` and measuring time with
+    /// `time_source`, e.g. `TokioTimeSource` or `EmbassyTimeSource`.
+    pub fn builder(observer: O, time_source: T) -> BleDeviceBuilder<'a, O, T> {
         BleDeviceBuilder {
             observer,
+            time_source,
             appearance: Default::default(),
             local_name: Default::default(),
+            recovery_policy: Default::default(),
+            scan_response_window: Default::default(),
+            scanned_device_timeout: Default::default(),
         }
     }
+}
 
+impl<
+        'a,
+        O,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >
+    BleDevice<
+        'a,
+        O,
+        T,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
+where
+    O: BleHostObserver,
+    T: TimeSource + Clone,
+{
     pub async fn run<H>(&mut self, hci_driver: H) -> Result<(), Error>
     where
         H: HciDriver,
     {
-        let host = BleHost::setup(Hci::new(hci_driver), self.appearance, self.local_name).await?;
-        let mut host = self.observer.ready(host).await;
+        let mut hci = self.create_hci(hci_driver);
+        let mut host = self.start(&mut hci).await?;
 
         loop {
-            match host.wait_for_event().await {
+            let Some(result) = self.wait_for_event(&mut host).await else {
+                host = self.notify_expired_advertisements(host, &mut |_| {}).await;
+                host = self.notify_lost_scanned_devices(host, &mut |_| {}).await;
+                host = match self.end_expired_limited_discoverable_mode(host).await {
+                    Ok((host, _)) => host,
+                    Err((e, host)) => self.recover_from_error(host, e).await?.0,
+                };
+                host = match self.cancel_expired_connection_establishment(host).await {
+                    Ok((host, _)) => host,
+                    Err((e, host)) => self.recover_from_error(host, e).await?.0,
+                };
+                continue;
+            };
+            if let Some(cause) = self.controller_reset_cause(result.as_ref()) {
+                host = self.reset_controller(host, cause).await?;
+                continue;
+            }
+            match result {
                 Ok(event_list) => {
-                    // Specific handling for LE advertising reports that needs to be grouped together.
-                    if event_list
-                        .iter()
-                        .any(|e| matches!(e, Event::LeMeta(LeMetaEvent::LeAdvertisingReport(_))))
-                    {
-                        host = self.notify_le_advertising_reports(host, &event_list).await;
-                    }
-
-                    // Handling of other events, ignoring the previously handled LE advertising reports.
-                    for event in event_list.iter().filter(|e| {
-                        !matches!(e, Event::LeMeta(LeMetaEvent::LeAdvertisingReport(_)))
-                    }) {
-                        match event {
-                            Event::DisconnectionComplete(disconnection_complete_event) => {
-                                host = self
-                                    .notify_disconnection_complete(
-                                        host,
-                                        disconnection_complete_event,
-                                    )
-                                    .await?;
-                            }
-                            Event::LeMeta(LeMetaEvent::LeConnectionComplete(
-                                le_connection_complete_event,
-                            )) => {
-                                host = self
-                                    .notify_le_connection_complete(
-                                        host,
-                                        le_connection_complete_event,
-                                    )
-                                    .await?;
-                            }
-                            Event::LeMeta(LeMetaEvent::LeConnectionUpdateComplete(
-                                le_connection_update_complete_event,
-                            )) => {
-                                host = self
-                                    .notify_le_connection_update_complete(
-                                        host,
-                                        le_connection_update_complete_event,
-                                    )
-                                    .await?;
-                            }
-                            _ => (),
-                        }
-                    }
+                    host = match self.handle_event_list(host, &event_list).await {
+                        Ok(host) => host,
+                        Err((e, host)) => self.recover_from_error(host, e).await?.0,
+                    };
+                    host = self.notify_received_iso_data(host, &mut |_| {}).await;
                 }
                 Err(Error::Hci(bletio_hci::Error::InvalidPacket)) => {
                     // Ignore invalid HCI packet
                     #[cfg(feature = "defmt")]
                     defmt::warn!("Received invalid HCI packet");
                 }
+                Err(Error::Hci(bletio_hci::Error::EventQueueOverflow)) => {
+                    // Some events have been lost, keep on handling the next ones
+                    #[cfg(feature = "defmt")]
+                    defmt::warn!("Some HCI events have been dropped");
+                }
+                Err(Error::Hci(bletio_hci::Error::IsoDataQueueOverflow)) => {
+                    // Some ISO data has been lost, keep on handling the next ones
+                    #[cfg(feature = "defmt")]
+                    defmt::warn!("Some HCI ISO data packets have been dropped");
+                }
                 Err(e) => return Err(e),
             }
         }
     }
 
+    /// Create the HCI of the device, measuring its timeouts with the time source of the device.
+    pub(crate) fn create_hci<H>(
+        &self,
+        hci_driver: H,
+    ) -> Hci<
+        H,
+        T,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
+    where
+        H: HciDriver,
+    {
+        Hci::with_event_queue(hci_driver, self.time_source.clone())
+    }
+
+    pub(crate) async fn start<'h, H>(
+        &'h self,
+        hci: &'h mut Hci<
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    ) -> Result<
+        BleHostStates<
+            'h,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        Error,
+    >
+    where
+        H: HciDriver,
+    {
+        let host = BleHost::setup(hci, self.appearance, self.local_name, &self.scan_filter).await?;
+        Ok(self.observer.ready(host).await)
+    }
+
+    /// Wait for the next events, or until the time window of an advertisement waiting for its
+    /// scan response, the timeout of a scanned device, the limited discoverable mode or the
+    /// connection establishment expires, in which case `None` is returned.
+    pub(crate) async fn wait_for_event<H>(
+        &self,
+        host: &mut BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    ) -> Option<Result<EventList<EVENT_LIST_SIZE>, Error>>
+    where
+        H: HciDriver,
+    {
+        if !matches!(host, BleHostStates::Scanning(_)) {
+            // Scanning has been stopped, the advertisements will not get their scan response.
+            self.scan_response_cache.borrow_mut().clear();
+        }
+        let next_expiry = self
+            .scan_response_cache
+            .borrow()
+            .next_expiry(&self.time_source);
+        let state_time_left = match host {
+            BleHostStates::Advertising(host) => host.limited_discoverable_time_left(),
+            BleHostStates::Initiating(host) => host.connection_establishment_time_left(),
+            _ => None,
+        };
+        let scanned_device_expiry = self.scanned_devices.borrow().next_expiry(&self.time_source);
+        let timeout = [next_expiry, scanned_device_expiry, state_time_left]
+            .into_iter()
+            .flatten()
+            .min();
+        match timeout {
+            Some(timeout) => host
+                .wait_for_event()
+                .with_timeout(self.time_source.delay(timeout))
+                .await
+                .ok(),
+            None => Some(host.wait_for_event().await),
+        }
+    }
+
+    /// Tell whether the Controller needs to be reset according to the recovery policy, given
+    /// the events received or the error of the last operation.
+    ///
+    /// The other events received along with a Hardware Error event are dropped.
+    pub(crate) fn controller_reset_cause(
+        &self,
+        result: Result<&EventList<EVENT_LIST_SIZE>, &Error>,
+    ) -> Option<ControllerResetCause> {
+        if self.recovery_policy == RecoveryPolicy::Disabled {
+            return None;
+        }
+        match result {
+            Ok(event_list) => event_list.iter().find_map(|event| match event {
+                Event::HardwareError(event) => {
+                    Some(ControllerResetCause::HardwareError(event.hardware_code()))
+                }
+                _ => None,
+            }),
+            Err(Error::Hci(bletio_hci::Error::HciDriver(error))) => {
+                Some(ControllerResetCause::HciDriver(*error))
+            }
+            Err(_) => None,
+        }
+    }
+
+    /// Reset the Controller if the error of an operation requires it according to the recovery
+    /// policy, giving the cause of the reset along with the recovered host, or give the error
+    /// back otherwise.
+    pub(crate) async fn recover_from_error<H>(
+        &self,
+        host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        error: Error,
+    ) -> Result<
+        (
+            BleHostStates<
+                'a,
+                H,
+                T,
+                EVENT_QUEUE_SIZE,
+                READ_BUFFER_SIZE,
+                EVENT_LIST_SIZE,
+                ACL_DATA_SIZE,
+                ISO_DATA_QUEUE_SIZE,
+            >,
+            ControllerResetCause,
+        ),
+        Error,
+    >
+    where
+        H: HciDriver,
+    {
+        match self.controller_reset_cause(Err(&error)) {
+            Some(cause) => Ok((self.reset_controller(host, cause).await?, cause)),
+            None => Err(error),
+        }
+    }
+
+    pub(crate) async fn reset_controller<H>(
+        &self,
+        host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        cause: ControllerResetCause,
+    ) -> Result<
+        BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        Error,
+    >
+    where
+        H: HciDriver,
+    {
+        #[cfg(feature = "defmt")]
+        defmt::warn!("Resetting the controller: {}", cause);
+        let max_attempts = match self.recovery_policy {
+            RecoveryPolicy::ResetController { max_attempts } => max_attempts,
+            RecoveryPolicy::Disabled => 1,
+        };
+        let host = host.recover(max_attempts).await?;
+        // The advertisements received before the reset will not get their scan response.
+        self.scan_response_cache.borrow_mut().clear();
+        Ok(self.observer.controller_reset(host, cause).await)
+    }
+
+    pub(crate) async fn handle_event_list<H>(
+        &self,
+        host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        event_list: &EventList<EVENT_LIST_SIZE>,
+    ) -> Result<
+        BleHostStates<
+            '_,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        (
+            Error,
+            BleHostStates<
+                '_,
+                H,
+                T,
+                EVENT_QUEUE_SIZE,
+                READ_BUFFER_SIZE,
+                EVENT_LIST_SIZE,
+                ACL_DATA_SIZE,
+                ISO_DATA_QUEUE_SIZE,
+            >,
+        ),
+    >
+    where
+        H: HciDriver,
+    {
+        self.handle_event_list_and_publish(host, event_list, &mut |_| {})
+            .await
+    }
+
+    /// Handle an event list, also giving the advertising reports and the scanned device events
+    /// to `publish` once they have been notified to the observer.
+    pub(crate) async fn handle_event_list_and_publish<H, P>(
+        &self,
+        mut host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        event_list: &EventList<EVENT_LIST_SIZE>,
+        publish: &mut P,
+    ) -> Result<
+        BleHostStates<
+            '_,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        (
+            Error,
+            BleHostStates<
+                '_,
+                H,
+                T,
+                EVENT_QUEUE_SIZE,
+                READ_BUFFER_SIZE,
+                EVENT_LIST_SIZE,
+                ACL_DATA_SIZE,
+                ISO_DATA_QUEUE_SIZE,
+            >,
+        ),
+    >
+    where
+        H: HciDriver,
+        P: FnMut(ScanEvent<'_, T::Instant>),
+    {
+        // Specific handling for LE advertising reports that needs to be grouped together.
+        host = self.notify_expired_advertisements(host, publish).await;
+        host = self.notify_lost_scanned_devices(host, publish).await;
+        if event_list
+            .iter()
+            .any(|e| matches!(e, Event::LeMeta(LeMetaEvent::LeAdvertisingReport(_))))
+        {
+            host = self
+                .notify_and_publish_le_advertising_reports(host, event_list, publish)
+                .await;
+        }
+
+        // Handling of other events, ignoring the previously handled LE advertising reports.
+        for event in event_list
+            .iter()
+            .filter(|e| !matches!(e, Event::LeMeta(LeMetaEvent::LeAdvertisingReport(_))))
+        {
+            match event {
+                Event::DisconnectionComplete(disconnection_complete_event) => {
+                    host = self
+                        .notify_disconnection_complete(host, disconnection_complete_event)
+                        .await?;
+                }
+                Event::LeMeta(LeMetaEvent::LeConnectionComplete(le_connection_complete_event)) => {
+                    host = self
+                        .notify_le_connection_complete(host, le_connection_complete_event)
+                        .await?;
+                }
+                Event::LeMeta(LeMetaEvent::LeConnectionUpdateComplete(
+                    le_connection_update_complete_event,
+                )) => {
+                    host = self
+                        .notify_le_connection_update_complete(
+                            host,
+                            le_connection_update_complete_event,
+                        )
+                        .await?;
+                }
+                Event::LeMeta(LeMetaEvent::LeCreateBigComplete(le_create_big_complete_event)) => {
+                    host = self
+                        .notify_le_create_big_complete(host, le_create_big_complete_event)
+                        .await?;
+                }
+                Event::LeMeta(LeMetaEvent::LeTerminateBigComplete(
+                    le_terminate_big_complete_event,
+                )) => {
+                    host = self
+                        .notify_le_terminate_big_complete(host, le_terminate_big_complete_event)
+                        .await?;
+                }
+                Event::LeMeta(LeMetaEvent::LeBigSyncEstablished(le_big_sync_established_event)) => {
+                    host = self
+                        .notify_le_big_sync_established(host, le_big_sync_established_event)
+                        .await?;
+                }
+                Event::LeMeta(LeMetaEvent::LeBigSyncLost(le_big_sync_lost_event)) => {
+                    host = self
+                        .notify_le_big_sync_lost(host, le_big_sync_lost_event)
+                        .await?;
+                }
+                Event::LeMeta(LeMetaEvent::LeBiginfoAdvertisingReport(
+                    le_biginfo_advertising_report_event,
+                )) => {
+                    host = self
+                        .notify_le_biginfo_advertising_report(
+                            host,
+                            le_biginfo_advertising_report_event,
+                        )
+                        .await?;
+                }
+                _ => (),
+            }
+        }
+
+        Ok(host)
+    }
+
     pub async fn notify_disconnection_complete<H>(
         &self,
-        mut host: BleHostStates<'a, H>,
+        mut host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
         event: &DisconnectionCompleteEvent,
-    ) -> Result<BleHostStates<H>, Error>
+    ) -> Result<
+        BleHostStates<
+            '_,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        (
+            Error,
+            BleHostStates<
+                '_,
+                H,
+                T,
+                EVENT_QUEUE_SIZE,
+                READ_BUFFER_SIZE,
+                EVENT_LIST_SIZE,
+                ACL_DATA_SIZE,
+                ISO_DATA_QUEUE_SIZE,
+            >,
+        ),
+    >
     where
         H: HciDriver,
     {
@@ -144,22 +709,282 @@ where
         Ok(self.observer.disconnection_complete(host, event).await)
     }
 
+    pub async fn notify_le_big_sync_established<H>(
+        &self,
+        host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        event: &LeBigSyncEstablishedEvent,
+    ) -> Result<
+        BleHostStates<
+            '_,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        (
+            Error,
+            BleHostStates<
+                '_,
+                H,
+                T,
+                EVENT_QUEUE_SIZE,
+                READ_BUFFER_SIZE,
+                EVENT_LIST_SIZE,
+                ACL_DATA_SIZE,
+                ISO_DATA_QUEUE_SIZE,
+            >,
+        ),
+    >
+    where
+        H: HciDriver,
+    {
+        Ok(self.observer.big_sync_established(host, event).await)
+    }
+
+    pub async fn notify_le_big_sync_lost<H>(
+        &self,
+        host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        event: &LeBigSyncLostEvent,
+    ) -> Result<
+        BleHostStates<
+            '_,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        (
+            Error,
+            BleHostStates<
+                '_,
+                H,
+                T,
+                EVENT_QUEUE_SIZE,
+                READ_BUFFER_SIZE,
+                EVENT_LIST_SIZE,
+                ACL_DATA_SIZE,
+                ISO_DATA_QUEUE_SIZE,
+            >,
+        ),
+    >
+    where
+        H: HciDriver,
+    {
+        Ok(self.observer.big_sync_lost(host, event).await)
+    }
+
+    pub async fn notify_le_biginfo_advertising_report<H>(
+        &self,
+        host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        event: &LeBiginfoAdvertisingReportEvent,
+    ) -> Result<
+        BleHostStates<
+            '_,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        (
+            Error,
+            BleHostStates<
+                '_,
+                H,
+                T,
+                EVENT_QUEUE_SIZE,
+                READ_BUFFER_SIZE,
+                EVENT_LIST_SIZE,
+                ACL_DATA_SIZE,
+                ISO_DATA_QUEUE_SIZE,
+            >,
+        ),
+    >
+    where
+        H: HciDriver,
+    {
+        Ok(self
+            .observer
+            .biginfo_advertising_report_received(host, event)
+            .await)
+    }
+
+    pub async fn notify_le_create_big_complete<H>(
+        &self,
+        host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        event: &LeCreateBigCompleteEvent,
+    ) -> Result<
+        BleHostStates<
+            '_,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        (
+            Error,
+            BleHostStates<
+                '_,
+                H,
+                T,
+                EVENT_QUEUE_SIZE,
+                READ_BUFFER_SIZE,
+                EVENT_LIST_SIZE,
+                ACL_DATA_SIZE,
+                ISO_DATA_QUEUE_SIZE,
+            >,
+        ),
+    >
+    where
+        H: HciDriver,
+    {
+        Ok(self.observer.big_created(host, event).await)
+    }
+
+    pub async fn notify_le_terminate_big_complete<H>(
+        &self,
+        host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        event: &LeTerminateBigCompleteEvent,
+    ) -> Result<
+        BleHostStates<
+            '_,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        (
+            Error,
+            BleHostStates<
+                '_,
+                H,
+                T,
+                EVENT_QUEUE_SIZE,
+                READ_BUFFER_SIZE,
+                EVENT_LIST_SIZE,
+                ACL_DATA_SIZE,
+                ISO_DATA_QUEUE_SIZE,
+            >,
+        ),
+    >
+    where
+        H: HciDriver,
+    {
+        Ok(self.observer.big_terminated(host, event).await)
+    }
+
     pub async fn notify_le_connection_complete<H>(
         &self,
-        mut host: BleHostStates<'a, H>,
+        mut host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
         event: &LeConnectionCompleteEvent,
-    ) -> Result<BleHostStates<H>, Error>
+    ) -> Result<
+        BleHostStates<
+            '_,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        (
+            Error,
+            BleHostStates<
+                '_,
+                H,
+                T,
+                EVENT_QUEUE_SIZE,
+                READ_BUFFER_SIZE,
+                EVENT_LIST_SIZE,
+                ACL_DATA_SIZE,
+                ISO_DATA_QUEUE_SIZE,
+            >,
+        ),
+    >
     where
         H: HciDriver,
     {
         if event.status().is_success() {
             host = match host {
                 BleHostStates::Initiating(h) => BleHostStates::ConnectedCentral(h.change_state()),
-                BleHostStates::Advertising(h) => {
-                    BleHostStates::ConnectedPeripheral(h.stop_advertising().await?.change_state())
-                }
+                BleHostStates::Advertising(h) => match h.stop_advertising().await {
+                    Ok(h) => BleHostStates::ConnectedPeripheral(h.change_state()),
+                    Err((e, h)) => return Err((e, BleHostStates::Advertising(h))),
+                },
                 _host => _host,
             }
+        } else if let BleHostStates::Initiating(h) = host {
+            // The Controller has given up creating the connection.
+            host = BleHostStates::Standby(h.change_state());
         }
 
         Ok(self.observer.connection_complete(host, event).await)
@@ -167,63 +992,184 @@ where
 
     pub async fn notify_le_connection_update_complete<H>(
         &self,
-        host: BleHostStates<'a, H>,
+        host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
         event: &LeConnectionUpdateCompleteEvent,
-    ) -> Result<BleHostStates<H>, Error>
+    ) -> Result<
+        BleHostStates<
+            '_,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        (
+            Error,
+            BleHostStates<
+                '_,
+                H,
+                T,
+                EVENT_QUEUE_SIZE,
+                READ_BUFFER_SIZE,
+                EVENT_LIST_SIZE,
+                ACL_DATA_SIZE,
+                ISO_DATA_QUEUE_SIZE,
+            >,
+        ),
+    >
     where
         H: HciDriver,
     {
         Ok(self.observer.connection_update_complete(host, event).await)
     }
 
+    /// Notify the advertising reports of an event list, each advertising data being combined
+    /// with its corresponding scan response data.
+    ///
+    /// The scannable advertisements whose scan response is not in the event list are kept
+    /// until it is received in a later event list, or until the scan response window expires.
     pub async fn notify_le_advertising_reports<'e, H>(
         &self,
-        mut host: BleHostStates<'a, H>,
-        event_list: &'e EventList,
-    ) -> BleHostStates<'a, H>
+        host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        event_list: &'e EventList<EVENT_LIST_SIZE>,
+    ) -> BleHostStates<
+        'a,
+        H,
+        T,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
     where
         H: HciDriver,
     {
-        fn find_corresponding_scan_response(
-            event_list: &EventList,
-            address: &ConnectionPeerAddress,
-        ) -> Option<LeAdvertisingReport> {
-            for reports in event_list.iter().filter_map(|e| match e {
-                Event::LeMeta(LeMetaEvent::LeAdvertisingReport(reports)) => Some(reports),
-                _ => None,
-            }) {
-                if let Some(report) = reports.iter().find(|r| {
-                    (r.event_type() == LeAdvertisingReportEventType::ScanResponse)
-                        && r.address() == address
-                }) {
-                    return Some(report);
+        self.notify_and_publish_le_advertising_reports(host, event_list, &mut |_| {})
+            .await
+    }
+
+    async fn notify_and_publish_le_advertising_reports<H, P>(
+        &self,
+        mut host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        event_list: &EventList<EVENT_LIST_SIZE>,
+        publish: &mut P,
+    ) -> BleHostStates<
+        'a,
+        H,
+        T,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
+    where
+        H: HciDriver,
+        P: FnMut(ScanEvent<'_, T::Instant>),
+    {
+        for (report, data) in borrowed_advertising_reports(event_list) {
+            // The scan response can only be expected when scan requests are sent.
+            let scannable = matches!(
+                report.event_type(),
+                LeAdvertisingReportEventType::ConnectableUndirected
+                    | LeAdvertisingReportEventType::ScannableUndirected
+            ) && matches!(&host, BleHostStates::Scanning(h) if h.is_active_scanning());
+            // `Some` with the possibly evicted advertisement if this one has been cached.
+            let cached = {
+                let mut cache = self.scan_response_cache.borrow_mut();
+                if scannable && data.scan_response_data().is_none() && cache.is_enabled() {
+                    Some(cache.insert(PendingAdvertisement {
+                        received_at: self.time_source.now(),
+                        event_type: report.event_type(),
+                        address: report.address().clone(),
+                        rssi: report.rssi(),
+                        adv_data: data.advertising_data().into(),
+                    }))
+                } else {
+                    // This report supersedes the advertisement of the same peer that may be waiting.
+                    cache.take(report.address());
+                    None
+                }
+            };
+            if let Some(evicted) = cached {
+                if let Some(advertisement) = evicted {
+                    host = self
+                        .notify_pending_advertisement(host, advertisement, None, publish)
+                        .await;
                 }
+                continue;
             }
 
-            None
+            if !self.is_accepted(report.event_type(), report.address(), report.rssi(), data) {
+                continue;
+            }
+            host = self
+                .observer
+                .advertising_report_received(
+                    host,
+                    report.event_type(),
+                    report.address(),
+                    report.rssi(),
+                    data,
+                )
+                .await;
+            publish(ScanEvent::AdvertisingReport {
+                event_type: report.event_type(),
+                address: report.address(),
+                rssi: report.rssi(),
+                data,
+            });
+            host = self
+                .track_scanned_device(host, report.address(), report.rssi(), data, publish)
+                .await;
         }
 
-        for reports in event_list.iter().filter_map(|e| match e {
-            Event::LeMeta(LeMetaEvent::LeAdvertisingReport(reports)) => Some(reports),
-            _ => None,
+        // The scan responses of advertisements received in previous event lists.
+        for report in le_advertising_reports(event_list).filter(|r| {
+            r.event_type() == LeAdvertisingReportEventType::ScanResponse
+                && !le_advertising_reports(event_list).any(|other| {
+                    other.event_type() != LeAdvertisingReportEventType::ScanResponse
+                        && other.address() == r.address()
+                })
         }) {
-            for report in reports
-                .iter()
-                .filter(|r| r.event_type() != LeAdvertisingReportEventType::ScanResponse)
-            {
-                let adv_data = report.data().into();
-                let scanresp_report =
-                    find_corresponding_scan_response(event_list, report.address());
-                let scanresp_data = scanresp_report.map(|r| r.data().into());
-                let full_adv_data = FullAdvertisingData::try_new(adv_data, scanresp_data).unwrap();
+            let advertisement = self.scan_response_cache.borrow_mut().take(report.address());
+            if let Some(advertisement) = advertisement {
                 host = self
-                    .observer
-                    .advertising_report_received(
+                    .notify_pending_advertisement(
                         host,
-                        report.event_type(),
-                        report.address(),
-                        report.rssi(),
-                        full_adv_data,
+                        advertisement,
+                        Some(report.data().into()),
+                        publish,
                     )
                     .await;
             }
@@ -231,4 +1177,389 @@ where
 
         host
     }
+
+    /// Stop advertising if the limited discoverable mode has expired, telling whether it has.
+    pub(crate) async fn end_expired_limited_discoverable_mode<H>(
+        &self,
+        host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    ) -> Result<
+        (
+            BleHostStates<
+                'a,
+                H,
+                T,
+                EVENT_QUEUE_SIZE,
+                READ_BUFFER_SIZE,
+                EVENT_LIST_SIZE,
+                ACL_DATA_SIZE,
+                ISO_DATA_QUEUE_SIZE,
+            >,
+            bool,
+        ),
+        (
+            Error,
+            BleHostStates<
+                'a,
+                H,
+                T,
+                EVENT_QUEUE_SIZE,
+                READ_BUFFER_SIZE,
+                EVENT_LIST_SIZE,
+                ACL_DATA_SIZE,
+                ISO_DATA_QUEUE_SIZE,
+            >,
+        ),
+    >
+    where
+        H: HciDriver,
+    {
+        match host {
+            BleHostStates::Advertising(host)
+                if host.limited_discoverable_time_left() == Some(Duration::ZERO) =>
+            {
+                match host.stop_advertising().await {
+                    Ok(host) => Ok((self.observer.limited_discoverable_timeout(host).await, true)),
+                    Err((e, host)) => Err((e, BleHostStates::Advertising(host))),
+                }
+            }
+            host => Ok((host, false)),
+        }
+    }
+
+    /// Cancel the connection being created if its timeout has elapsed, telling whether it has.
+    pub(crate) async fn cancel_expired_connection_establishment<H>(
+        &self,
+        host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    ) -> Result<
+        (
+            BleHostStates<
+                'a,
+                H,
+                T,
+                EVENT_QUEUE_SIZE,
+                READ_BUFFER_SIZE,
+                EVENT_LIST_SIZE,
+                ACL_DATA_SIZE,
+                ISO_DATA_QUEUE_SIZE,
+            >,
+            bool,
+        ),
+        (
+            Error,
+            BleHostStates<
+                'a,
+                H,
+                T,
+                EVENT_QUEUE_SIZE,
+                READ_BUFFER_SIZE,
+                EVENT_LIST_SIZE,
+                ACL_DATA_SIZE,
+                ISO_DATA_QUEUE_SIZE,
+            >,
+        ),
+    >
+    where
+        H: HciDriver,
+    {
+        match host {
+            BleHostStates::Initiating(host)
+                if host.connection_establishment_time_left() == Some(Duration::ZERO) =>
+            {
+                match host.cancel_connection().await {
+                    Ok(host) => Ok((
+                        self.observer.connection_establishment_timeout(host).await,
+                        true,
+                    )),
+                    Err((
+                        Error::Hci(bletio_hci::Error::ErrorCode(ErrorCode::CommandDisallowed)),
+                        mut host,
+                    )) => {
+                        // The connection has been established just before the cancellation,
+                        // its pending LE Connection Complete event will be handled next.
+                        host.clear_connection_establishment_timeout();
+                        Ok((BleHostStates::Initiating(host), false))
+                    }
+                    Err((e, host)) => Err((e, BleHostStates::Initiating(host))),
+                }
+            }
+            host => Ok((host, false)),
+        }
+    }
+
+    /// Notify the advertisements whose scan response has not been received in time.
+    pub(crate) async fn notify_expired_advertisements<H, P>(
+        &self,
+        mut host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        publish: &mut P,
+    ) -> BleHostStates<
+        'a,
+        H,
+        T,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
+    where
+        H: HciDriver,
+        P: FnMut(ScanEvent<'_, T::Instant>),
+    {
+        loop {
+            let advertisement = self
+                .scan_response_cache
+                .borrow_mut()
+                .take_expired(&self.time_source);
+            match advertisement {
+                Some(advertisement) => {
+                    host = self
+                        .notify_pending_advertisement(host, advertisement, None, publish)
+                        .await;
+                }
+                None => return host,
+            }
+        }
+    }
+
+    async fn notify_pending_advertisement<H, P>(
+        &self,
+        host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        advertisement: PendingAdvertisement<T::Instant>,
+        scanresp_data: Option<AdvertisingDataView<'_>>,
+        publish: &mut P,
+    ) -> BleHostStates<
+        'a,
+        H,
+        T,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
+    where
+        H: HciDriver,
+        P: FnMut(ScanEvent<'_, T::Instant>),
+    {
+        let data = FullAdvertisingDataView::new((&advertisement.adv_data).into(), scanresp_data);
+        if !self.is_accepted(
+            advertisement.event_type,
+            &advertisement.address,
+            advertisement.rssi,
+            data,
+        ) {
+            return host;
+        }
+        let host = self
+            .observer
+            .advertising_report_received(
+                host,
+                advertisement.event_type,
+                &advertisement.address,
+                advertisement.rssi,
+                data,
+            )
+            .await;
+        publish(ScanEvent::AdvertisingReport {
+            event_type: advertisement.event_type,
+            address: &advertisement.address,
+            rssi: advertisement.rssi,
+            data,
+        });
+        self.track_scanned_device(
+            host,
+            &advertisement.address,
+            advertisement.rssi,
+            data,
+            publish,
+        )
+        .await
+    }
+
+    /// Record a notified advertising report in the table of the scanned devices, notifying
+    /// whether the device has appeared or has been updated.
+    async fn track_scanned_device<H, P>(
+        &self,
+        mut host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        address: &ConnectionPeerAddress,
+        rssi: Option<Rssi>,
+        data: FullAdvertisingDataView<'_>,
+        publish: &mut P,
+    ) -> BleHostStates<
+        'a,
+        H,
+        T,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
+    where
+        H: HciDriver,
+        P: FnMut(ScanEvent<'_, T::Instant>),
+    {
+        let (appeared, evicted, device) = {
+            let mut scanned_devices = self.scanned_devices.borrow_mut();
+            if !scanned_devices.is_enabled() {
+                return host;
+            }
+            let (appeared, evicted) =
+                scanned_devices.record(&self.time_source, address, rssi, data);
+            // INVARIANT: The device has just been recorded.
+            let device = scanned_devices.get(address).cloned().unwrap();
+            (appeared, evicted, device)
+        };
+        if let Some(evicted) = evicted {
+            host = self.observer.scanned_device_lost(host, &evicted).await;
+            publish(ScanEvent::ScannedDeviceLost(&evicted));
+        }
+        if appeared {
+            host = self.observer.scanned_device_appeared(host, &device).await;
+            publish(ScanEvent::ScannedDeviceAppeared(&device));
+        } else {
+            host = self.observer.scanned_device_updated(host, &device).await;
+            publish(ScanEvent::ScannedDeviceUpdated(&device));
+        }
+        host
+    }
+
+    /// Notify the scanned devices that have not been seen for the scanned device timeout.
+    pub(crate) async fn notify_lost_scanned_devices<H, P>(
+        &self,
+        mut host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        publish: &mut P,
+    ) -> BleHostStates<
+        'a,
+        H,
+        T,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
+    where
+        H: HciDriver,
+        P: FnMut(ScanEvent<'_, T::Instant>),
+    {
+        loop {
+            let device = self
+                .scanned_devices
+                .borrow_mut()
+                .take_lost(&self.time_source);
+            match device {
+                Some(device) => {
+                    host = self.observer.scanned_device_lost(host, &device).await;
+                    publish(ScanEvent::ScannedDeviceLost(&device));
+                }
+                None => return host,
+            }
+        }
+    }
+
+    /// Notify the ISO data received since the last call, also giving it to `publish` once it
+    /// has been notified to the observer.
+    pub(crate) async fn notify_received_iso_data<H, P>(
+        &self,
+        mut host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        publish: &mut P,
+    ) -> BleHostStates<
+        'a,
+        H,
+        T,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
+    where
+        H: HciDriver,
+        P: FnMut(IsoData),
+    {
+        while let Some(iso_data) = host.take_iso_data() {
+            host = self.observer.iso_data_received(host, &iso_data).await;
+            publish(iso_data);
+        }
+        host
+    }
+
+    /// Tell whether an advertising report is accepted by the scan filter given when starting
+    /// scanning, if any.
+    fn is_accepted(
+        &self,
+        event_type: LeAdvertisingReportEventType,
+        address: &ConnectionPeerAddress,
+        rssi: Option<Rssi>,
+        data: FullAdvertisingDataView<'_>,
+    ) -> bool {
+        self.scan_filter
+            .borrow()
+            .as_ref()
+            .is_none_or(|filter| filter.matches(event_type, address, rssi, data))
+    }
 }
@@ -1,33 +1,103 @@
+use core::cell::RefCell;
+use core::future::Future;
 use core::marker::PhantomData;
 use core::num::NonZeroU16;
 use core::ops::Deref;
+use core::time::Duration;
+
+use heapless::Vec;
 
 use bletio_hci::{
     ConnectionHandle, ConnectionPeerAddress, DisconnectionCompleteEvent, EventList, EventMask,
-    FilterDuplicates, Hci, HciDriver, LeAdvertisingReportEventType, LeConnectionCompleteEvent,
-    LeConnectionUpdateCompleteEvent, LeEventMask, LeFilterAcceptListAddress, PublicDeviceAddress,
-    RandomStaticDeviceAddress, Reason, Rssi, ScanEnable, SupportedCommands, SupportedFeatures,
-    SupportedLeFeatures, SupportedLeStates,
+    FilterDuplicates, Hci, HciDriver, InitiatorFilterPolicy, IsoData, LeAdvertisingReportEventType,
+    LeBigSyncEstablishedEvent, LeBigSyncLostEvent, LeBiginfoAdvertisingReportEvent,
+    LeConnectionCompleteEvent, LeConnectionUpdateCompleteEvent, LeCreateBigCompleteEvent,
+    LeEventMask, LeFilterAcceptListAddress, LeTerminateBigCompleteEvent, PublicDeviceAddress,
+    RandomStaticDeviceAddress, Reason, Rssi, ScanEnable, ScanType, ScanningFilterPolicy,
+    SupportedCommands, SupportedFeatures, SupportedLeFeatures, SupportedLeStates, TimeSource,
+    ACL_DATA_MAX_SIZE, EVENT_LIST_NB_EVENTS, HCI_DEFAULT_EVENT_QUEUE_SIZE,
+    HCI_DEFAULT_ISO_DATA_QUEUE_SIZE, HCI_MAX_READ_BUFFER_SIZE,
 };
 
+use crate::advertising::gap_modes::{apply_discoverable_mode_flags, apply_gap_modes};
 use crate::advertising::{
-    AdvertisingEnable, AdvertisingParameters, FullAdvertisingData, ScanParameters,
+    AdvertisingData, AdvertisingEnable, AdvertisingParameters, ConnectableMode, DiscoverableMode,
+    FullAdvertisingData, FullAdvertisingDataView, ScanFilter, ScanParameters, ScanResponseData,
+    LIMITED_DISCOVERABLE_TIMEOUT,
 };
 use crate::assigned_numbers::AppearanceValue;
+use crate::connection_establishment::procedure_connection_parameters;
 use crate::device_information::DeviceInformation;
+use crate::isochronous::{
+    BigCreateSyncParameters, BigHandle, BigParameters, BroadcastIsochronousStream,
+};
+use crate::recovery::ControllerResetCause;
+use crate::scanned_devices::ScannedDevice;
 use crate::{ConnectionParameters, ConnectionUpdateParameters, Error};
 
+/// Maximum number of filter accept list devices restored after a reset of the Controller.
+pub(crate) const FILTER_ACCEPT_LIST_MAX_SIZE: usize = 16;
+
 pub trait BleHostState {}
 
-pub struct BleHost<'a, H, State: BleHostState = BleHostStateInitial>
-where
+/// Host in a given state, sending its commands through an HCI measuring time with the `T`
+/// [`TimeSource`], whose capacities are given by the const generic parameters as for [`Hci`].
+pub struct BleHost<
+    'a,
+    H,
+    T,
+    State: BleHostState = BleHostStateInitial,
+    const EVENT_QUEUE_SIZE: usize = HCI_DEFAULT_EVENT_QUEUE_SIZE,
+    const READ_BUFFER_SIZE: usize = HCI_MAX_READ_BUFFER_SIZE,
+    const EVENT_LIST_SIZE: usize = EVENT_LIST_NB_EVENTS,
+    const ACL_DATA_SIZE: usize = ACL_DATA_MAX_SIZE,
+    const ISO_DATA_QUEUE_SIZE: usize = HCI_DEFAULT_ISO_DATA_QUEUE_SIZE,
+> where
     H: HciDriver,
+    T: TimeSource,
 {
-    hci: Hci<H>,
+    // The HCI and the scan filter are kept outside of the host, that is moved at each change of
+    // state and into each observer call, to keep the futures handling it small.
+    hci: &'a mut Hci<
+        H,
+        T,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >,
     device_information: DeviceInformation<'a>,
+    controller_state: ControllerState<T::Instant>,
+    scan_filter: &'a RefCell<Option<ScanFilter>>,
     phantom: PhantomData<State>,
 }
 
+/// State configured in the Controller by the host, restored after a reset of the Controller.
+#[derive(Debug)]
+struct ControllerState<I> {
+    filter_accept_list: Vec<LeFilterAcceptListAddress, FILTER_ACCEPT_LIST_MAX_SIZE>,
+    advertising: Option<(AdvertisingParameters, FullAdvertisingData)>,
+    discoverable_mode: Option<DiscoverableMode>,
+    limited_discoverable_since: Option<I>,
+    connection_deadline: Option<(I, Duration)>,
+    scanning: Option<(ScanParameters, FilterDuplicates)>,
+}
+
+// Not derived to avoid requiring the instant type to implement `Default`.
+impl<I> Default for ControllerState<I> {
+    fn default() -> Self {
+        Self {
+            filter_accept_list: Default::default(),
+            advertising: Default::default(),
+            discoverable_mode: Default::default(),
+            limited_discoverable_since: Default::default(),
+            connection_deadline: Default::default(),
+            scanning: Default::default(),
+        }
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct BleHostStateInitial;
 #[derive(Debug, Default)]
@@ -51,19 +121,91 @@ impl BleHostState for BleHostStateInitiating {}
 impl BleHostState for BleHostStateConnectedCentral {}
 impl BleHostState for BleHostStateConnectedPeripheral {}
 
-impl<'a, H> BleHost<'a, H, BleHostStateInitial>
+impl<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >
+    BleHost<
+        'a,
+        H,
+        T,
+        BleHostStateInitial,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
 where
     H: HciDriver,
+    T: TimeSource,
 {
-    // Perform setup has described in Core specification 4.2, Vol. 6, Part D, 2.1
     pub(crate) async fn setup(
-        mut hci: Hci<H>,
+        hci: &'a mut Hci<
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
         appearance: AppearanceValue,
         local_name: &'a str,
-    ) -> Result<BleHost<'a, H, BleHostStateStandby>, Error>
-    where
-        H: HciDriver,
-    {
+        scan_filter: &'a RefCell<Option<ScanFilter>>,
+    ) -> Result<
+        BleHost<
+            'a,
+            H,
+            T,
+            BleHostStateStandby,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        Error,
+    > {
+        let device_information = Self::setup_controller(hci, appearance, local_name).await?;
+        Ok(BleHost::<
+            H,
+            T,
+            BleHostStateStandby,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        > {
+            hci,
+            device_information,
+            controller_state: Default::default(),
+            scan_filter,
+            phantom: PhantomData,
+        })
+    }
+
+    // Perform setup has described in Core specification 4.2, Vol. 6, Part D, 2.1
+    async fn setup_controller(
+        hci: &mut Hci<
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        appearance: AppearanceValue,
+        local_name: &'a str,
+    ) -> Result<DeviceInformation<'a>, Error> {
         let mut device_information = DeviceInformation {
             appearance,
             local_name,
@@ -86,9 +228,6 @@ where
             | EventMask::ENCRYPTION_CHANGE
             | EventMask::ENCRYPTION_KEY_REFRESH_COMPLETE;
         hci.cmd_set_event_mask(event_mask).await?;
-        if device_information.is_command_supported(SupportedCommands::LE_SET_EVENT_MASK) {
-            hci.cmd_le_set_event_mask(LeEventMask::default()).await?;
-        }
 
         let (le_data_packet_length, num_le_data_packets) = hci.cmd_le_read_buffer_size().await?;
         let le_data_packet_length: Result<NonZeroU16, _> = le_data_packet_length.try_into();
@@ -118,20 +257,140 @@ where
                 hci.cmd_le_read_local_supported_features_page_0().await?;
         }
 
+        if device_information.is_command_supported(SupportedCommands::LE_SET_EVENT_MASK) {
+            let mut le_event_mask = LeEventMask::default();
+            if device_information
+                .is_le_feature_supported(SupportedLeFeatures::ISOCHRONOUS_BROADCASTER)
+            {
+                le_event_mask |=
+                    LeEventMask::LE_CREATE_BIG_COMPLETE | LeEventMask::LE_TERMINATE_BIG_COMPLETE;
+            }
+            if device_information
+                .is_le_feature_supported(SupportedLeFeatures::SYNCHRONIZED_RECEIVER)
+            {
+                le_event_mask |= LeEventMask::LE_BIG_SYNC_ESTABLISHED
+                    | LeEventMask::LE_BIG_SYNC_LOST
+                    | LeEventMask::LE_BIGINFO_ADVERTISING_REPORT;
+            }
+            hci.cmd_le_set_event_mask(le_event_mask).await?;
+        }
+
         device_information.supported_le_states = hci.cmd_le_read_supported_states().await?;
         device_information.public_device_address = hci.cmd_read_bd_addr().await?;
 
-        Ok(BleHost::<H, BleHostStateStandby> {
-            hci,
-            device_information,
-            phantom: PhantomData,
-        })
+        Ok(device_information)
+    }
+
+    /// Reset the Controller and set it up again, restoring the random address, the filter
+    /// accept list and the advertising or scanning that was active.
+    async fn restore(
+        mut self,
+    ) -> Result<
+        BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        (
+            Error,
+            BleHost<
+                'a,
+                H,
+                T,
+                BleHostStateInitial,
+                EVENT_QUEUE_SIZE,
+                READ_BUFFER_SIZE,
+                EVENT_LIST_SIZE,
+                ACL_DATA_SIZE,
+                ISO_DATA_QUEUE_SIZE,
+            >,
+        ),
+    > {
+        // The pending commands and queued events are meaningless now.
+        self.hci.reset_state();
+        if let Err(e) = self.restore_controller().await {
+            return Err((e, self));
+        }
+
+        let host: BleHost<
+            'a,
+            H,
+            T,
+            BleHostStateStandby,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        > = self.change_state();
+        if let Some((adv_params, full_adv_data)) = host.controller_state.advertising.clone() {
+            return match host.start_advertising(&adv_params, &full_adv_data).await {
+                Ok(host) => Ok(BleHostStates::Advertising(host)),
+                Err((e, host)) => Err((e, host.change_state())),
+            };
+        }
+        if let Some((scan_params, filter_duplicates)) = host.controller_state.scanning.clone() {
+            // The scan filter is kept as is.
+            return match host.enable_scanning(&scan_params, filter_duplicates).await {
+                Ok(host) => Ok(BleHostStates::Scanning(host)),
+                Err((e, host)) => Err((e, host.change_state())),
+            };
+        }
+        Ok(BleHostStates::Standby(host))
+    }
+
+    async fn restore_controller(&mut self) -> Result<(), Error> {
+        let mut device_information = Self::setup_controller(
+            self.hci,
+            self.device_information.appearance,
+            self.device_information.local_name,
+        )
+        .await?;
+        if let Some(random_address) = &self.device_information.random_static_device_address {
+            self.hci
+                .cmd_le_set_random_address(random_address.clone())
+                .await?;
+            device_information.random_static_device_address = Some(random_address.clone());
+        }
+        for address in &self.controller_state.filter_accept_list {
+            self.hci
+                .cmd_le_add_device_to_filter_accept_list(address.clone())
+                .await?;
+        }
+        self.device_information = device_information;
+        Ok(())
     }
 }
 
-impl<'a, H> BleHost<'a, H, BleHostStateStandby>
+impl<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >
+    BleHost<
+        'a,
+        H,
+        T,
+        BleHostStateStandby,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
 where
     H: HciDriver,
+    T: TimeSource,
 {
     pub async fn add_le_filter_accept_list_device(
         &mut self,
@@ -141,10 +400,21 @@ where
             .device_information
             .is_command_supported(SupportedCommands::LE_ADD_DEVICE_TO_FILTER_ACCEPT_LIST)
         {
-            Ok(self
-                .hci
-                .cmd_le_add_device_to_filter_accept_list(address.into())
-                .await?)
+            let address = address.into();
+            self.hci
+                .cmd_le_add_device_to_filter_accept_list(address.clone())
+                .await?;
+            if !self.controller_state.filter_accept_list.contains(&address)
+                && self
+                    .controller_state
+                    .filter_accept_list
+                    .push(address)
+                    .is_err()
+            {
+                #[cfg(feature = "defmt")]
+                defmt::warn!("Filter accept list device will not be restored after a reset");
+            }
+            Ok(())
         } else {
             Err(Error::ControllerDoesNotSupportCommand(
                 SupportedCommands::LE_ADD_DEVICE_TO_FILTER_ACCEPT_LIST,
@@ -157,7 +427,9 @@ where
             .device_information
             .is_command_supported(SupportedCommands::LE_CLEAR_FILTER_ACCEPT_LIST)
         {
-            Ok(self.hci.cmd_le_clear_filter_accept_list().await?)
+            self.hci.cmd_le_clear_filter_accept_list().await?;
+            self.controller_state.filter_accept_list.clear();
+            Ok(())
         } else {
             Err(Error::ControllerDoesNotSupportCommand(
                 SupportedCommands::LE_CLEAR_FILTER_ACCEPT_LIST,
@@ -168,11 +440,132 @@ where
     pub async fn connect(
         mut self,
         connection_parameters: &ConnectionParameters,
-    ) -> Result<BleHost<'a, H, BleHostStateInitiating>, Error> {
-        self.hci
+    ) -> Result<
+        BleHost<
+            'a,
+            H,
+            T,
+            BleHostStateInitiating,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        (Error, Self),
+    > {
+        match self
+            .hci
             .cmd_le_create_connection(connection_parameters.deref().clone())
-            .await?;
-        Ok(self.change_state())
+            .await
+        {
+            Ok(()) => {
+                self.controller_state.connection_deadline = None;
+                Ok(self.change_state())
+            }
+            Err(e) => Err((e.into(), self)),
+        }
+    }
+
+    /// Run the GAP auto connection establishment procedure, connecting to the first of the
+    /// given peer devices, e.g. the bonded ones, found advertising.
+    ///
+    /// The filter accept list is replaced by the peer devices, and used as the initiator filter
+    /// policy, the peer address of `connection_parameters` being ignored. The procedure goes
+    /// on until the connection is established or cancelled.
+    pub async fn connect_auto(
+        mut self,
+        peer_addresses: &[LeFilterAcceptListAddress],
+        connection_parameters: &ConnectionParameters,
+    ) -> Result<
+        BleHost<
+            'a,
+            H,
+            T,
+            BleHostStateInitiating,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        (Error, Self),
+    > {
+        let connection_parameters = match procedure_connection_parameters(
+            connection_parameters,
+            InitiatorFilterPolicy::FilterAcceptListUsed,
+            ConnectionPeerAddress::default(),
+        ) {
+            Ok(connection_parameters) => connection_parameters,
+            Err(e) => return Err((e, self)),
+        };
+        if let Err(e) = self.clear_le_filter_accept_list().await {
+            return Err((e, self));
+        }
+        for address in peer_addresses {
+            if let Err(e) = self.add_le_filter_accept_list_device(address.clone()).await {
+                return Err((e, self));
+            }
+        }
+        match self
+            .hci
+            .cmd_le_create_connection(connection_parameters.deref().clone())
+            .await
+        {
+            Ok(()) => {
+                self.controller_state.connection_deadline = None;
+                Ok(self.change_state())
+            }
+            Err(e) => Err((e.into(), self)),
+        }
+    }
+
+    /// Run the GAP direct connection establishment procedure, connecting to the given peer
+    /// device.
+    ///
+    /// The initiator filter policy and the peer address of `connection_parameters` are
+    /// replaced. If the connection has not been established after `timeout`, it is cancelled,
+    /// going back to the standby state, see
+    /// [`BleHostObserver::connection_establishment_timeout`].
+    pub async fn connect_direct(
+        mut self,
+        peer_address: &ConnectionPeerAddress,
+        connection_parameters: &ConnectionParameters,
+        timeout: Duration,
+    ) -> Result<
+        BleHost<
+            'a,
+            H,
+            T,
+            BleHostStateInitiating,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        (Error, Self),
+    > {
+        let connection_parameters = match procedure_connection_parameters(
+            connection_parameters,
+            InitiatorFilterPolicy::FilterAcceptListNotUsed,
+            peer_address.clone(),
+        ) {
+            Ok(connection_parameters) => connection_parameters,
+            Err(e) => return Err((e, self)),
+        };
+        match self
+            .hci
+            .cmd_le_create_connection(connection_parameters.deref().clone())
+            .await
+        {
+            Ok(()) => {
+                self.controller_state.connection_deadline =
+                    Some((self.hci.time_source().now(), timeout));
+                Ok(self.change_state())
+            }
+            Err(e) => Err((e.into(), self)),
+        }
     }
 
     pub async fn create_random_address(&mut self) -> Result<(), Error> {
@@ -218,10 +611,14 @@ where
             .device_information
             .is_command_supported(SupportedCommands::LE_REMOVE_DEVICE_FROM_FILTER_ACCEPT_LIST)
         {
-            Ok(self
-                .hci
-                .cmd_le_remove_device_from_filter_accept_list(address.into())
-                .await?)
+            let address = address.into();
+            self.hci
+                .cmd_le_remove_device_from_filter_accept_list(address.clone())
+                .await?;
+            self.controller_state
+                .filter_accept_list
+                .retain(|a| *a != address);
+            Ok(())
         } else {
             Err(Error::ControllerDoesNotSupportCommand(
                 SupportedCommands::LE_REMOVE_DEVICE_FROM_FILTER_ACCEPT_LIST,
@@ -233,15 +630,45 @@ where
         mut self,
         adv_params: &AdvertisingParameters,
         full_adv_data: &FullAdvertisingData,
-    ) -> Result<BleHost<'a, H, BleHostStateAdvertising>, (Error, Self)> {
-        async fn inner<H>(
-            hci: &mut Hci<H>,
+    ) -> Result<
+        BleHost<
+            'a,
+            H,
+            T,
+            BleHostStateAdvertising,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        (Error, Self),
+    > {
+        async fn inner<
+            H,
+            T,
+            const EVENT_QUEUE_SIZE: usize,
+            const READ_BUFFER_SIZE: usize,
+            const EVENT_LIST_SIZE: usize,
+            const ACL_DATA_SIZE: usize,
+            const ISO_DATA_QUEUE_SIZE: usize,
+        >(
+            hci: &mut Hci<
+                H,
+                T,
+                EVENT_QUEUE_SIZE,
+                READ_BUFFER_SIZE,
+                EVENT_LIST_SIZE,
+                ACL_DATA_SIZE,
+                ISO_DATA_QUEUE_SIZE,
+            >,
             device_information: &mut DeviceInformation<'_>,
             adv_params: &AdvertisingParameters,
             full_adv_data: &FullAdvertisingData,
         ) -> Result<(), Error>
         where
             H: HciDriver,
+            T: TimeSource,
         {
             hci.cmd_le_set_advertising_parameters(adv_params.deref().clone())
                 .await?;
@@ -249,43 +676,139 @@ where
                 hci.cmd_le_read_advertising_channel_tx_power().await?;
 
             let full_adv_data = full_adv_data.fill_automatic_data(device_information)?;
-            let mut scanresp_data = bletio_hci::AdvertisingData::default();
-            let adv_data = (&full_adv_data.adv_data).into();
-            if let Some(data) = &(full_adv_data.scanresp_data) {
-                scanresp_data = data.into();
-            }
-
-            hci.cmd_le_set_advertising_data(adv_data).await?;
-            hci.cmd_le_set_scan_response_data(scanresp_data).await?;
+            hci.cmd_le_set_advertising_data((&full_adv_data.adv_data).into())
+                .await?;
+            hci.cmd_le_set_scan_response_data(full_adv_data.scan_response_hci_data())
+                .await?;
             hci.cmd_le_set_advertising_enable(AdvertisingEnable::Enabled)
                 .await?;
             Ok(())
         }
         match inner(
-            &mut self.hci,
+            self.hci,
             &mut self.device_information,
             adv_params,
             full_adv_data,
         )
         .await
         {
-            Ok(()) => Ok(self.change_state()),
+            Ok(()) => {
+                self.controller_state.advertising =
+                    Some((adv_params.clone(), full_adv_data.clone()));
+                Ok(self.change_state())
+            }
             Err(e) => Err((e, self)),
         }
     }
 
+    /// Start advertising in the given GAP discoverable and connectable modes.
+    ///
+    /// The advertising type, the advertising filter policy and the Flags of the Advertising Data
+    /// are set according to the modes, replacing the ones of `adv_params` and `full_adv_data`.
+    /// In the limited discoverable mode, the advertising is stopped after
+    /// [`LIMITED_DISCOVERABLE_TIMEOUT`], going back to the standby state.
+    pub async fn start_advertising_in_modes(
+        self,
+        discoverable_mode: DiscoverableMode,
+        connectable_mode: &ConnectableMode,
+        adv_params: &AdvertisingParameters,
+        full_adv_data: &FullAdvertisingData,
+    ) -> Result<
+        BleHost<
+            'a,
+            H,
+            T,
+            BleHostStateAdvertising,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        (Error, Self),
+    > {
+        let (adv_params, full_adv_data) = match apply_gap_modes(
+            discoverable_mode,
+            connectable_mode,
+            adv_params,
+            full_adv_data,
+        ) {
+            Ok(result) => result,
+            Err(e) => return Err((e, self)),
+        };
+        let mut host = self.start_advertising(&adv_params, &full_adv_data).await?;
+        host.controller_state.discoverable_mode = Some(discoverable_mode);
+        if discoverable_mode == DiscoverableMode::LimitedDiscoverable {
+            host.controller_state.limited_discoverable_since = Some(host.hci.time_source().now());
+        }
+        Ok(host)
+    }
+
     pub async fn start_scanning(
+        self,
+        scan_params: &ScanParameters,
+        filter_duplicates: FilterDuplicates,
+    ) -> Result<
+        BleHost<
+            'a,
+            H,
+            T,
+            BleHostStateScanning,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        (Error, Self),
+    > {
+        let host = self.enable_scanning(scan_params, filter_duplicates).await?;
+        *host.scan_filter.borrow_mut() = None;
+        Ok(host)
+    }
+
+    async fn enable_scanning(
         mut self,
         scan_params: &ScanParameters,
         filter_duplicates: FilterDuplicates,
-    ) -> Result<BleHost<'a, H, BleHostStateScanning>, (Error, Self)> {
-        async fn inner<H>(
-            hci: &mut Hci<H>,
+    ) -> Result<
+        BleHost<
+            'a,
+            H,
+            T,
+            BleHostStateScanning,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        (Error, Self),
+    > {
+        async fn inner<
+            H,
+            T,
+            const EVENT_QUEUE_SIZE: usize,
+            const READ_BUFFER_SIZE: usize,
+            const EVENT_LIST_SIZE: usize,
+            const ACL_DATA_SIZE: usize,
+            const ISO_DATA_QUEUE_SIZE: usize,
+        >(
+            hci: &mut Hci<
+                H,
+                T,
+                EVENT_QUEUE_SIZE,
+                READ_BUFFER_SIZE,
+                EVENT_LIST_SIZE,
+                ACL_DATA_SIZE,
+                ISO_DATA_QUEUE_SIZE,
+            >,
             scan_params: &ScanParameters,
             filter_duplicates: FilterDuplicates,
         ) -> Result<(), Error>
         where
             H: HciDriver,
+            T: TimeSource,
         {
             hci.cmd_le_set_scan_parameters(scan_params.deref().clone())
                 .await?;
@@ -293,50 +816,466 @@ where
                 .await?;
             Ok(())
         }
-        match inner(&mut self.hci, scan_params, filter_duplicates).await {
-            Ok(()) => Ok(self.change_state()),
+        match inner(self.hci, scan_params, filter_duplicates).await {
+            Ok(()) => {
+                self.controller_state.scanning = Some((scan_params.clone(), filter_duplicates));
+                Ok(self.change_state())
+            }
             Err(e) => Err((e, self)),
         }
     }
+
+    /// Start scanning, only notifying the advertising reports accepted by the given filter.
+    ///
+    /// If requested by the filter, its addresses are programmed into the filter accept list,
+    /// replacing its content, and the scanning filter policy of `scan_params` is changed to use
+    /// it.
+    pub async fn start_scanning_with_filter(
+        mut self,
+        scan_params: &ScanParameters,
+        filter_duplicates: FilterDuplicates,
+        filter: &ScanFilter,
+    ) -> Result<
+        BleHost<
+            'a,
+            H,
+            T,
+            BleHostStateScanning,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        (Error, Self),
+    > {
+        let mut scan_params = scan_params.clone();
+        if let Some(addresses) = filter.filter_accept_list_addresses() {
+            if let Err(e) = self.clear_le_filter_accept_list().await {
+                return Err((e, self));
+            }
+            for address in addresses {
+                if let Err(e) = self.add_le_filter_accept_list_device(address.clone()).await {
+                    return Err((e, self));
+                }
+            }
+            let filter_policy = match scan_params.filter_policy() {
+                ScanningFilterPolicy::ExtendedUnfiltered => ScanningFilterPolicy::ExtendedFiltered,
+                ScanningFilterPolicy::BasicUnfiltered => ScanningFilterPolicy::BasicFiltered,
+                filter_policy => filter_policy,
+            };
+            scan_params = match ScanParameters::builder()
+                .with_type(scan_params.r#type())
+                .with_interval(scan_params.interval())
+                .with_window(scan_params.window())
+                .with_own_address_type(scan_params.own_address_type())
+                .with_filter_policy(filter_policy)
+                .try_build()
+            {
+                Ok(scan_params) => scan_params,
+                Err(e) => return Err((e.into(), self)),
+            };
+        }
+        let host = self
+            .enable_scanning(&scan_params, filter_duplicates)
+            .await?;
+        *host.scan_filter.borrow_mut() = Some(filter.clone());
+        Ok(host)
+    }
 }
 
-impl<'a, H> BleHost<'a, H, BleHostStateAdvertising>
+impl<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >
+    BleHost<
+        'a,
+        H,
+        T,
+        BleHostStateAdvertising,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
 where
     H: HciDriver,
+    T: TimeSource,
 {
-    pub async fn stop_advertising(mut self) -> Result<BleHost<'a, H, BleHostStateStandby>, Error> {
-        self.hci
+    pub async fn stop_advertising(
+        mut self,
+    ) -> Result<
+        BleHost<
+            'a,
+            H,
+            T,
+            BleHostStateStandby,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        (Error, Self),
+    > {
+        match self
+            .hci
             .cmd_le_set_advertising_enable(AdvertisingEnable::Disabled)
+            .await
+        {
+            Ok(()) => {
+                self.controller_state.advertising = None;
+                self.controller_state.discoverable_mode = None;
+                self.controller_state.limited_discoverable_since = None;
+                Ok(self.change_state())
+            }
+            Err(e) => Err((e.into(), self)),
+        }
+    }
+
+    /// Get the time left before the end of the limited discoverable mode, if advertising in
+    /// this mode.
+    pub(crate) fn limited_discoverable_time_left(&self) -> Option<Duration> {
+        let time_source = self.hci.time_source();
+        self.controller_state
+            .limited_discoverable_since
+            .map(|since| LIMITED_DISCOVERABLE_TIMEOUT.saturating_sub(time_source.elapsed(since)))
+    }
+
+    /// Change the Advertising Data while advertising, keeping the current Scan Response Data.
+    ///
+    /// When advertising in GAP modes, the Flags are set according to the discoverable mode.
+    pub async fn update_advertising_data(
+        &mut self,
+        adv_data: &AdvertisingData,
+    ) -> Result<(), Error> {
+        let scanresp_data = self.advertised_data()?.scanresp_data.clone();
+        let full_adv_data = self.with_discoverable_mode_flags(FullAdvertisingData::try_new(
+            adv_data.clone(),
+            scanresp_data,
+        )?)?;
+        let filled_full_adv_data = full_adv_data.fill_automatic_data(&self.device_information)?;
+        self.hci
+            .cmd_le_set_advertising_data((&filled_full_adv_data.adv_data).into())
+            .await?;
+        self.set_advertised_data(full_adv_data);
+        Ok(())
+    }
+
+    /// Change the Scan Response Data while advertising, keeping the current Advertising Data.
+    pub async fn update_scan_response_data(
+        &mut self,
+        scanresp_data: &ScanResponseData,
+    ) -> Result<(), Error> {
+        let adv_data = self.advertised_data()?.adv_data.clone();
+        let full_adv_data = FullAdvertisingData::try_new(adv_data, Some(scanresp_data.clone()))?;
+        let filled_full_adv_data = full_adv_data.fill_automatic_data(&self.device_information)?;
+        self.hci
+            .cmd_le_set_scan_response_data(filled_full_adv_data.scan_response_hci_data())
+            .await?;
+        self.set_advertised_data(full_adv_data);
+        Ok(())
+    }
+
+    /// Change both the Advertising Data and the Scan Response Data while advertising.
+    ///
+    /// The Scan Response Data is emptied if the Full Advertising Data does not contain any. When
+    /// advertising in GAP modes, the Flags are set according to the discoverable mode.
+    pub async fn update_full_advertising_data(
+        &mut self,
+        full_adv_data: &FullAdvertisingData,
+    ) -> Result<(), Error> {
+        self.advertised_data()?;
+        let full_adv_data = self.with_discoverable_mode_flags(full_adv_data.clone())?;
+        let filled_full_adv_data = full_adv_data.fill_automatic_data(&self.device_information)?;
+        self.hci
+            .cmd_le_set_advertising_data((&filled_full_adv_data.adv_data).into())
             .await?;
-        Ok(self.change_state())
+        self.hci
+            .cmd_le_set_scan_response_data(filled_full_adv_data.scan_response_hci_data())
+            .await?;
+        self.set_advertised_data(full_adv_data);
+        Ok(())
+    }
+
+    /// Change the advertised data on a timer.
+    ///
+    /// The payload returned by `payload` for the rotation index 0 is advertised immediately,
+    /// then the one for the next index every `period`, measured with the time source of the
+    /// HCI, e.g. to advertise a counter or to alternate between several beacon formats. The
+    /// rotation ends when `payload` returns `None`, or with an error if the advertised data
+    /// cannot be updated. The events received meanwhile are handled afterwards.
+    pub async fn rotate_advertising_data(
+        &mut self,
+        period: Duration,
+        payload: impl FnMut(u32) -> Option<FullAdvertisingData>,
+    ) -> Result<(), Error> {
+        rotate_advertising_data(self, period, payload).await
+    }
+
+    // Keep the Flags of the GAP discoverable mode when advertising in GAP modes.
+    fn with_discoverable_mode_flags(
+        &self,
+        full_adv_data: FullAdvertisingData,
+    ) -> Result<FullAdvertisingData, Error> {
+        match self.controller_state.discoverable_mode {
+            Some(discoverable_mode) => {
+                apply_discoverable_mode_flags(discoverable_mode, &full_adv_data)
+            }
+            None => Ok(full_adv_data),
+        }
+    }
+
+    fn advertised_data(&self) -> Result<&FullAdvertisingData, Error> {
+        self.controller_state
+            .advertising
+            .as_ref()
+            .map(|(_, full_adv_data)| full_adv_data)
+            .ok_or(Error::InvalidStateForRequest)
+    }
+
+    fn set_advertised_data(&mut self, full_adv_data: FullAdvertisingData) {
+        if let Some((_, advertised_data)) = self.controller_state.advertising.as_mut() {
+            *advertised_data = full_adv_data;
+        }
     }
 }
 
-impl<'a, H> BleHost<'a, H, BleHostStateScanning>
+impl<
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    > AdvertisingDataRotation
+    for BleHost<
+        '_,
+        H,
+        T,
+        BleHostStateAdvertising,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
 where
     H: HciDriver,
+    T: TimeSource,
 {
-    pub async fn stop_scanning(mut self) -> Result<BleHost<'a, H, BleHostStateStandby>, Error> {
-        self.hci
+    type Delay = T::Delay;
+
+    fn delay(&self, period: Duration) -> Self::Delay {
+        self.hci.time_source().delay(period)
+    }
+
+    async fn update_advertised_data(
+        &mut self,
+        full_adv_data: &FullAdvertisingData,
+    ) -> Result<(), Error> {
+        self.update_full_advertising_data(full_adv_data).await
+    }
+}
+
+/// Target of a rotation of the advertised data, see [`BleHost::rotate_advertising_data`].
+pub(crate) trait AdvertisingDataRotation {
+    type Delay: Future<Output = ()>;
+
+    /// Create the delay between two advertised data.
+    fn delay(&self, period: Duration) -> Self::Delay;
+
+    /// Change both the Advertising Data and the Scan Response Data.
+    async fn update_advertised_data(
+        &mut self,
+        full_adv_data: &FullAdvertisingData,
+    ) -> Result<(), Error>;
+}
+
+/// Advertise the payload returned by `payload` for each rotation index in turn, every `period`,
+/// until it returns `None`.
+pub(crate) async fn rotate_advertising_data<R>(
+    rotation: &mut R,
+    period: Duration,
+    mut payload: impl FnMut(u32) -> Option<FullAdvertisingData>,
+) -> Result<(), Error>
+where
+    R: AdvertisingDataRotation,
+{
+    let mut index = 0u32;
+    while let Some(full_adv_data) = payload(index) {
+        rotation.update_advertised_data(&full_adv_data).await?;
+        rotation.delay(period).await;
+        index = index.wrapping_add(1);
+    }
+    Ok(())
+}
+
+impl<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >
+    BleHost<
+        'a,
+        H,
+        T,
+        BleHostStateScanning,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
+where
+    H: HciDriver,
+    T: TimeSource,
+{
+    /// Tell whether scan requests are sent, so that the scan responses are received.
+    pub(crate) fn is_active_scanning(&self) -> bool {
+        self.controller_state
+            .scanning
+            .as_ref()
+            .is_some_and(|(scan_params, _)| scan_params.r#type() == ScanType::ActiveScanning)
+    }
+
+    pub async fn stop_scanning(
+        mut self,
+    ) -> Result<
+        BleHost<
+            'a,
+            H,
+            T,
+            BleHostStateStandby,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        (Error, Self),
+    > {
+        match self
+            .hci
             .cmd_le_set_scan_enable(ScanEnable::Disabled, FilterDuplicates::Disabled)
-            .await?;
-        Ok(self.change_state())
+            .await
+        {
+            Ok(()) => {
+                self.controller_state.scanning = None;
+                Ok(self.change_state())
+            }
+            Err(e) => Err((e.into(), self)),
+        }
     }
 }
 
-impl<'a, H> BleHost<'a, H, BleHostStateInitiating>
+impl<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >
+    BleHost<
+        'a,
+        H,
+        T,
+        BleHostStateInitiating,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
 where
     H: HciDriver,
+    T: TimeSource,
 {
-    pub async fn cancel_connection(mut self) -> Result<BleHost<'a, H, BleHostStateStandby>, Error> {
-        self.hci.cmd_le_create_connection_cancel().await?;
-        Ok(self.change_state())
+    /// Cancel the connection being created.
+    ///
+    /// The cancellation fails with a Command Disallowed error code if the connection has been
+    /// established in the meantime, the host is then given back and its LE Connection Complete
+    /// event is still to be received.
+    pub async fn cancel_connection(
+        mut self,
+    ) -> Result<
+        BleHost<
+            'a,
+            H,
+            T,
+            BleHostStateStandby,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        (Error, Self),
+    > {
+        match self.hci.cmd_le_create_connection_cancel().await {
+            Ok(()) => {
+                self.controller_state.connection_deadline = None;
+                Ok(self.change_state())
+            }
+            Err(e) => Err((e.into(), self)),
+        }
+    }
+
+    /// Forget the timeout of the connection being created, e.g. when it has been established
+    /// while cancelling it.
+    pub(crate) fn clear_connection_establishment_timeout(&mut self) {
+        self.controller_state.connection_deadline = None;
+    }
+
+    /// Get the time left before the connection is cancelled, if it has been created by the
+    /// direct connection establishment procedure.
+    pub(crate) fn connection_establishment_time_left(&self) -> Option<Duration> {
+        let time_source = self.hci.time_source();
+        self.controller_state
+            .connection_deadline
+            .map(|(since, timeout)| timeout.saturating_sub(time_source.elapsed(since)))
     }
 }
 
-impl<H> BleHost<'_, H, BleHostStateConnectedCentral>
+impl<
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >
+    BleHost<
+        '_,
+        H,
+        T,
+        BleHostStateConnectedCentral,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
 where
     H: HciDriver,
+    T: TimeSource,
 {
     pub async fn disconnect(
         &mut self,
@@ -358,9 +1297,29 @@ where
     }
 }
 
-impl<H> BleHost<'_, H, BleHostStateConnectedPeripheral>
+impl<
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >
+    BleHost<
+        '_,
+        H,
+        T,
+        BleHostStateConnectedPeripheral,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
 where
     H: HciDriver,
+    T: TimeSource,
 {
     pub async fn disconnect(
         &mut self,
@@ -382,11 +1341,114 @@ where
     }
 }
 
-impl<'a, H, S> BleHost<'a, H, S>
+impl<
+        'a,
+        H,
+        T,
+        S,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >
+    BleHost<
+        'a,
+        H,
+        T,
+        S,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
 where
     H: HciDriver,
+    T: TimeSource,
     S: BleHostState,
 {
+    /// Create a BIG on the periodic advertising train of the advertising set given in the
+    /// parameters.
+    ///
+    /// bletio does not handle periodic advertising yet, so the advertising set needs to have
+    /// been set up and its periodic advertising enabled by other means.
+    pub async fn create_big(&mut self, parameters: &BigParameters) -> Result<(), Error> {
+        if self
+            .device_information
+            .is_command_supported(SupportedCommands::LE_CREATE_BIG)
+        {
+            Ok(self.hci.cmd_le_create_big(parameters.clone()).await?)
+        } else {
+            Err(Error::ControllerDoesNotSupportCommand(
+                SupportedCommands::LE_CREATE_BIG,
+            ))
+        }
+    }
+
+    /// Synchronize to a BIG described by the BIGInfo of the periodic advertising train given in
+    /// the parameters.
+    ///
+    /// bletio does not handle the synchronization to periodic advertising yet, so the periodic
+    /// advertising sync needs to have been established by other means. Once synchronized, the
+    /// received ISO data is notified with [`BleHostObserver::iso_data_received`].
+    pub async fn create_big_sync(
+        &mut self,
+        parameters: &BigCreateSyncParameters,
+    ) -> Result<(), Error> {
+        if self
+            .device_information
+            .is_command_supported(SupportedCommands::LE_BIG_CREATE_SYNC)
+        {
+            Ok(self.hci.cmd_le_big_create_sync(parameters.clone()).await?)
+        } else {
+            Err(Error::ControllerDoesNotSupportCommand(
+                SupportedCommands::LE_BIG_CREATE_SYNC,
+            ))
+        }
+    }
+
+    pub async fn send_iso_sdu(
+        &mut self,
+        stream: &mut BroadcastIsochronousStream,
+        timestamp: Option<u32>,
+        sdu: &[u8],
+    ) -> Result<(), Error> {
+        let iso_data = stream.next_iso_data(timestamp, sdu)?;
+        Ok(self.hci.send_iso_data(&iso_data).await?)
+    }
+
+    pub async fn terminate_big(
+        &mut self,
+        big_handle: BigHandle,
+        reason: Reason,
+    ) -> Result<(), Error> {
+        if self
+            .device_information
+            .is_command_supported(SupportedCommands::LE_TERMINATE_BIG)
+        {
+            Ok(self.hci.cmd_le_terminate_big(big_handle, reason).await?)
+        } else {
+            Err(Error::ControllerDoesNotSupportCommand(
+                SupportedCommands::LE_TERMINATE_BIG,
+            ))
+        }
+    }
+
+    pub async fn terminate_big_sync(&mut self, big_handle: BigHandle) -> Result<(), Error> {
+        if self
+            .device_information
+            .is_command_supported(SupportedCommands::LE_BIG_TERMINATE_SYNC)
+        {
+            self.hci.cmd_le_big_terminate_sync(big_handle).await?;
+            Ok(())
+        } else {
+            Err(Error::ControllerDoesNotSupportCommand(
+                SupportedCommands::LE_BIG_TERMINATE_SYNC,
+            ))
+        }
+    }
+
     pub fn appearance(&self) -> AppearanceValue {
         self.device_information.appearance
     }
@@ -421,36 +1483,173 @@ where
         &self.device_information.supported_le_states
     }
 
-    pub(crate) fn change_state<NS>(self) -> BleHost<'a, H, NS>
+    pub(crate) fn change_state<NS>(
+        self,
+    ) -> BleHost<
+        'a,
+        H,
+        T,
+        NS,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
     where
         NS: BleHostState,
     {
-        BleHost::<'a, H, NS> {
+        BleHost::<
+            'a,
+            H,
+            T,
+            NS,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        > {
             hci: self.hci,
             device_information: self.device_information,
+            controller_state: self.controller_state,
+            scan_filter: self.scan_filter,
             phantom: PhantomData,
         }
     }
 }
 
-pub enum BleHostStates<'a, H>
-where
+pub enum BleHostStates<
+    'a,
+    H,
+    T,
+    const EVENT_QUEUE_SIZE: usize = HCI_DEFAULT_EVENT_QUEUE_SIZE,
+    const READ_BUFFER_SIZE: usize = HCI_MAX_READ_BUFFER_SIZE,
+    const EVENT_LIST_SIZE: usize = EVENT_LIST_NB_EVENTS,
+    const ACL_DATA_SIZE: usize = ACL_DATA_MAX_SIZE,
+    const ISO_DATA_QUEUE_SIZE: usize = HCI_DEFAULT_ISO_DATA_QUEUE_SIZE,
+> where
     H: HciDriver,
+    T: TimeSource,
 {
-    Initial(BleHost<'a, H, BleHostStateInitial>),
-    Standby(BleHost<'a, H, BleHostStateStandby>),
-    Advertising(BleHost<'a, H, BleHostStateAdvertising>),
-    Scanning(BleHost<'a, H, BleHostStateScanning>),
-    Initiating(BleHost<'a, H, BleHostStateInitiating>),
-    ConnectedCentral(BleHost<'a, H, BleHostStateConnectedCentral>),
-    ConnectedPeripheral(BleHost<'a, H, BleHostStateConnectedPeripheral>),
+    Initial(
+        BleHost<
+            'a,
+            H,
+            T,
+            BleHostStateInitial,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    ),
+    Standby(
+        BleHost<
+            'a,
+            H,
+            T,
+            BleHostStateStandby,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    ),
+    Advertising(
+        BleHost<
+            'a,
+            H,
+            T,
+            BleHostStateAdvertising,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    ),
+    Scanning(
+        BleHost<
+            'a,
+            H,
+            T,
+            BleHostStateScanning,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    ),
+    Initiating(
+        BleHost<
+            'a,
+            H,
+            T,
+            BleHostStateInitiating,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    ),
+    ConnectedCentral(
+        BleHost<
+            'a,
+            H,
+            T,
+            BleHostStateConnectedCentral,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    ),
+    ConnectedPeripheral(
+        BleHost<
+            'a,
+            H,
+            T,
+            BleHostStateConnectedPeripheral,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    ),
 }
 
-impl<H> BleHostStates<'_, H>
+impl<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >
+    BleHostStates<
+        'a,
+        H,
+        T,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
 where
     H: HciDriver,
+    T: TimeSource,
 {
-    pub(crate) async fn wait_for_event(&mut self) -> Result<EventList, Error> {
+    pub(crate) async fn wait_for_event(&mut self) -> Result<EventList<EVENT_LIST_SIZE>, Error> {
         match self {
             Self::Initial(_) => Err(Error::CannotWaitForEventInThisState),
             Self::Standby(host) => Ok(host.hci.wait_for_event().await?),
@@ -461,67 +1660,794 @@ where
             Self::ConnectedPeripheral(host) => Ok(host.hci.wait_for_event().await?),
         }
     }
+
+    pub(crate) fn take_iso_data(&mut self) -> Option<IsoData> {
+        match self {
+            Self::Initial(_) => None,
+            Self::Standby(host) => host.hci.take_iso_data(),
+            Self::Advertising(host) => host.hci.take_iso_data(),
+            Self::Scanning(host) => host.hci.take_iso_data(),
+            Self::Initiating(host) => host.hci.take_iso_data(),
+            Self::ConnectedCentral(host) => host.hci.take_iso_data(),
+            Self::ConnectedPeripheral(host) => host.hci.take_iso_data(),
+        }
+    }
+
+    /// Reset the Controller and restore its state, making up to `max_attempts` attempts.
+    ///
+    /// The connections and the connection being initiated are lost, the host ends up
+    /// advertising or scanning if it was doing so, or in standby otherwise.
+    pub(crate) async fn recover(self, max_attempts: u8) -> Result<Self, Error> {
+        let mut host: BleHost<
+            'a,
+            H,
+            T,
+            BleHostStateInitial,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        > = match self {
+            Self::Initial(host) => host,
+            Self::Standby(host) => host.change_state(),
+            Self::Advertising(host) => host.change_state(),
+            Self::Scanning(host) => host.change_state(),
+            Self::Initiating(host) => host.change_state(),
+            Self::ConnectedCentral(host) => host.change_state(),
+            Self::ConnectedPeripheral(host) => host.change_state(),
+        };
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            match host.restore().await {
+                Ok(host) => return Ok(host),
+                Err((Error::Hci(bletio_hci::Error::HciDriver(_)), h))
+                    if attempts < max_attempts =>
+                {
+                    #[cfg(feature = "defmt")]
+                    defmt::warn!("Failed to recover the controller, retrying");
+                    host = h;
+                }
+                Err((e, _)) => return Err(e),
+            }
+        }
+    }
 }
 
 pub trait BleHostObserver {
     #[allow(unused_variables)]
-    fn advertising_report_received<'a, H>(
+    fn advertising_report_received<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >(
         &self,
-        host: BleHostStates<'a, H>,
+        host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
         event_type: LeAdvertisingReportEventType,
         address: &ConnectionPeerAddress,
         rssi: Option<Rssi>,
-        data: FullAdvertisingData,
-    ) -> impl core::future::Future<Output = BleHostStates<'a, H>>
+        data: FullAdvertisingDataView<'_>,
+    ) -> impl core::future::Future<
+        Output = BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    >
+    where
+        H: HciDriver,
+        T: TimeSource,
+    {
+        async { host }
+    }
+
+    #[allow(unused_variables)]
+    fn big_created<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >(
+        &self,
+        host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        event: &LeCreateBigCompleteEvent,
+    ) -> impl core::future::Future<
+        Output = BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    >
     where
         H: HciDriver,
+        T: TimeSource,
     {
         async { host }
     }
 
     #[allow(unused_variables)]
-    fn connection_complete<'a, H>(
+    fn big_sync_established<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >(
         &self,
-        host: BleHostStates<'a, H>,
+        host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        event: &LeBigSyncEstablishedEvent,
+    ) -> impl core::future::Future<
+        Output = BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    >
+    where
+        H: HciDriver,
+        T: TimeSource,
+    {
+        async { host }
+    }
+
+    #[allow(unused_variables)]
+    fn big_sync_lost<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >(
+        &self,
+        host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        event: &LeBigSyncLostEvent,
+    ) -> impl core::future::Future<
+        Output = BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    >
+    where
+        H: HciDriver,
+        T: TimeSource,
+    {
+        async { host }
+    }
+
+    #[allow(unused_variables)]
+    fn big_terminated<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >(
+        &self,
+        host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        event: &LeTerminateBigCompleteEvent,
+    ) -> impl core::future::Future<
+        Output = BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    >
+    where
+        H: HciDriver,
+        T: TimeSource,
+    {
+        async { host }
+    }
+
+    #[allow(unused_variables)]
+    fn biginfo_advertising_report_received<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >(
+        &self,
+        host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        event: &LeBiginfoAdvertisingReportEvent,
+    ) -> impl core::future::Future<
+        Output = BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    >
+    where
+        H: HciDriver,
+        T: TimeSource,
+    {
+        async { host }
+    }
+
+    #[allow(unused_variables)]
+    fn connection_complete<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >(
+        &self,
+        host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
         event: &LeConnectionCompleteEvent,
-    ) -> impl core::future::Future<Output = BleHostStates<'a, H>>
+    ) -> impl core::future::Future<
+        Output = BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    >
     where
         H: HciDriver,
+        T: TimeSource,
     {
         async { host }
     }
 
     #[allow(unused_variables)]
-    fn connection_update_complete<'a, H>(
+    fn connection_update_complete<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >(
         &self,
-        host: BleHostStates<'a, H>,
+        host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
         event: &LeConnectionUpdateCompleteEvent,
-    ) -> impl core::future::Future<Output = BleHostStates<'a, H>>
+    ) -> impl core::future::Future<
+        Output = BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    >
     where
         H: HciDriver,
+        T: TimeSource,
     {
         async { host }
     }
 
+    /// Called once the Controller has been reset and its state restored by the host, see
+    /// [`RecoveryPolicy`](crate::RecoveryPolicy).
+    ///
+    /// The connections that were established have been lost, no Disconnection Complete event
+    /// is notified for them.
     #[allow(unused_variables)]
-    fn disconnection_complete<'a, H>(
+    fn controller_reset<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >(
         &self,
-        host: BleHostStates<'a, H>,
+        host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        cause: ControllerResetCause,
+    ) -> impl core::future::Future<
+        Output = BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    >
+    where
+        H: HciDriver,
+        T: TimeSource,
+    {
+        async { host }
+    }
+
+    #[allow(unused_variables)]
+    fn disconnection_complete<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >(
+        &self,
+        host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
         event: &DisconnectionCompleteEvent,
-    ) -> impl core::future::Future<Output = BleHostStates<'a, H>>
+    ) -> impl core::future::Future<
+        Output = BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    >
+    where
+        H: HciDriver,
+        T: TimeSource,
+    {
+        async { host }
+    }
+
+    /// Called when ISO data has been received on a BIS of a BIG the host is synchronized to,
+    /// see [`BleHost::create_big_sync`].
+    #[allow(unused_variables)]
+    fn iso_data_received<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >(
+        &self,
+        host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        iso_data: &IsoData,
+    ) -> impl core::future::Future<
+        Output = BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    >
+    where
+        H: HciDriver,
+        T: TimeSource,
+    {
+        async { host }
+    }
+
+    /// Called when a device is seen advertising for the first time since it has been lost, if
+    /// ever, see [`BleDeviceBuilder::with_scanned_device_timeout`](crate::ble_device::BleDeviceBuilder::with_scanned_device_timeout).
+    #[allow(unused_variables)]
+    fn scanned_device_appeared<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >(
+        &self,
+        host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        device: &ScannedDevice<T::Instant>,
+    ) -> impl core::future::Future<
+        Output = BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    >
     where
         H: HciDriver,
+        T: TimeSource,
     {
         async { host }
     }
 
-    fn ready<'a, H>(
+    /// Called when a device has not been seen advertising for the scanned device timeout, or
+    /// when it has been evicted from the full table of the scanned devices.
+    #[allow(unused_variables)]
+    fn scanned_device_lost<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >(
         &self,
-        host: BleHost<'a, H, BleHostStateStandby>,
-    ) -> impl core::future::Future<Output = BleHostStates<'a, H>>
+        host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        device: &ScannedDevice<T::Instant>,
+    ) -> impl core::future::Future<
+        Output = BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    >
     where
         H: HciDriver,
+        T: TimeSource,
+    {
+        async { host }
+    }
+
+    /// Called when a new advertisement of an already seen device has been received, after it
+    /// has been notified with [`BleHostObserver::advertising_report_received`].
+    #[allow(unused_variables)]
+    fn scanned_device_updated<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >(
+        &self,
+        host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        device: &ScannedDevice<T::Instant>,
+    ) -> impl core::future::Future<
+        Output = BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    >
+    where
+        H: HciDriver,
+        T: TimeSource,
+    {
+        async { host }
+    }
+
+    /// Called when the connection created by the direct connection establishment procedure
+    /// has been cancelled because its timeout has elapsed, see [`BleHost::connect_direct`].
+    fn connection_establishment_timeout<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >(
+        &self,
+        host: BleHost<
+            'a,
+            H,
+            T,
+            BleHostStateStandby,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    ) -> impl core::future::Future<
+        Output = BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    >
+    where
+        H: HciDriver,
+        T: TimeSource,
+    {
+        async { BleHostStates::Standby(host) }
+    }
+
+    /// Called when the advertising in the limited discoverable mode has been stopped because
+    /// [`LIMITED_DISCOVERABLE_TIMEOUT`] has elapsed.
+    fn limited_discoverable_timeout<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >(
+        &self,
+        host: BleHost<
+            'a,
+            H,
+            T,
+            BleHostStateStandby,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    ) -> impl core::future::Future<
+        Output = BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    >
+    where
+        H: HciDriver,
+        T: TimeSource,
+    {
+        async { BleHostStates::Standby(host) }
+    }
+
+    fn ready<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >(
+        &self,
+        host: BleHost<
+            'a,
+            H,
+            T,
+            BleHostStateStandby,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    ) -> impl core::future::Future<
+        Output = BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    >
+    where
+        H: HciDriver,
+        T: TimeSource,
     {
         async { BleHostStates::Standby(host) }
     }
 }
+
+/// Observer ignoring all the events, e.g. when they are consumed through the
+/// [`HostEvents`](crate::HostEvents) stream of a [`Runner`](crate::Runner).
+impl BleHostObserver for () {}
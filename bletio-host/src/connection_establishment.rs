@@ -0,0 +1,112 @@
+//! GAP connection establishment procedures.
+//!
+//! The procedures are defined in
+//! [Core Specification 6.0, Vol. 3, Part C, 9.3](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host/generic-access-profile.html):
+//!  - the auto connection establishment procedure connects to the first of a set of peer
+//!    devices found advertising, see [`BleHost::connect_auto`](crate::BleHost::connect_auto)
+//!  - the selective connection establishment procedure scans and connects to the device picked
+//!    among the advertising ones, see
+//!    [`HostHandle::connect_selective`](crate::HostHandle::connect_selective)
+//!  - the direct connection establishment procedure connects to a given peer device, giving up
+//!    after a timeout, see [`BleHost::connect_direct`](crate::BleHost::connect_direct)
+
+use bletio_hci::{ConnectionPeerAddress, InitiatorFilterPolicy};
+
+use crate::{ConnectionParameters, Error};
+
+/// Get the connection parameters to use for a connection establishment procedure, replacing
+/// the initiator filter policy and the peer address of `connection_parameters`.
+pub(crate) fn procedure_connection_parameters(
+    connection_parameters: &ConnectionParameters,
+    initiator_filter_policy: InitiatorFilterPolicy,
+    peer_address: ConnectionPeerAddress,
+) -> Result<ConnectionParameters, Error> {
+    ConnectionParameters::builder()
+        .with_scan_interval(connection_parameters.scan_interval())
+        .with_scan_window(connection_parameters.scan_window())
+        .with_initiator_filter_policy(initiator_filter_policy)
+        .with_peer_address(peer_address)
+        .with_own_address_type(connection_parameters.own_address_type())
+        .with_connection_interval_range(connection_parameters.connection_interval_range().clone())
+        .with_max_latency(connection_parameters.max_latency())
+        .with_supervision_timeout(connection_parameters.supervision_timeout())
+        .with_connection_event_length_range(
+            connection_parameters
+                .connection_event_length_range()
+                .clone(),
+        )
+        .try_build()
+}
+
+#[cfg(test)]
+mod test {
+    use bletio_hci::{
+        connection_interval_range, latency, scan_interval, scan_window, supervision_timeout,
+        OwnAddressType, PublicDeviceAddress,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(
+        InitiatorFilterPolicy::FilterAcceptListUsed,
+        ConnectionPeerAddress::default()
+    )]
+    #[case(
+        InitiatorFilterPolicy::FilterAcceptListNotUsed,
+        ConnectionPeerAddress::PublicDevice(PublicDeviceAddress::new([1, 2, 3, 4, 5, 6]))
+    )]
+    fn test_procedure_connection_parameters(
+        #[case] initiator_filter_policy: InitiatorFilterPolicy,
+        #[case] peer_address: ConnectionPeerAddress,
+    ) -> Result<(), Error> {
+        let connection_parameters = ConnectionParameters::builder()
+            .with_scan_interval(scan_interval!(0x0100))
+            .with_scan_window(scan_window!(0x0080))
+            .with_initiator_filter_policy(InitiatorFilterPolicy::FilterAcceptListUsed)
+            .with_peer_address(ConnectionPeerAddress::PublicDevice(
+                PublicDeviceAddress::new([6, 5, 4, 3, 2, 1]),
+            ))
+            .with_own_address_type(OwnAddressType::RandomDeviceAddress)
+            .with_connection_interval_range(connection_interval_range!(0x0010, 0x0020))
+            .with_max_latency(latency!(2))
+            .with_supervision_timeout(supervision_timeout!(0x0200))
+            .try_build()?;
+        let parameters = procedure_connection_parameters(
+            &connection_parameters,
+            initiator_filter_policy,
+            peer_address.clone(),
+        )?;
+        assert_eq!(
+            parameters.initiator_filter_policy(),
+            initiator_filter_policy
+        );
+        assert_eq!(parameters.peer_address(), &peer_address);
+        assert_eq!(
+            parameters.scan_interval(),
+            connection_parameters.scan_interval()
+        );
+        assert_eq!(
+            parameters.scan_window(),
+            connection_parameters.scan_window()
+        );
+        assert_eq!(
+            parameters.own_address_type(),
+            OwnAddressType::RandomDeviceAddress
+        );
+        assert_eq!(
+            parameters.connection_interval_range(),
+            connection_parameters.connection_interval_range()
+        );
+        assert_eq!(
+            parameters.max_latency(),
+            connection_parameters.max_latency()
+        );
+        assert_eq!(
+            parameters.supervision_timeout(),
+            connection_parameters.supervision_timeout()
+        );
+        Ok(())
+    }
+}
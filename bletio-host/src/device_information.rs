@@ -32,6 +32,10 @@ impl DeviceInformation<'_> {
     pub(crate) fn is_feature_supported(&self, feature: SupportedFeatures) -> bool {
         self.supported_features.contains(feature)
     }
+
+    pub(crate) fn is_le_feature_supported(&self, feature: SupportedLeFeatures) -> bool {
+        self.supported_le_features.contains(feature)
+    }
 }
 
 impl Default for DeviceInformation<'_> {
@@ -92,6 +96,7 @@ mod test {
         assert!(
             !device_information.is_feature_supported(SupportedFeatures::LE_SUPPORTED_CONTROLLER)
         );
+        assert!(!device_information.is_le_feature_supported(SupportedLeFeatures::LE_CODED_PHY));
     }
 
     #[test]
@@ -159,5 +164,8 @@ mod test {
         assert!(!device_information.is_feature_supported(
             SupportedFeatures::SIMULTANEOUS_LE_AND_BREDR_TO_SAME_DEVICE_CAPABLE_CONTROLLER
         ));
+        assert!(device_information.is_le_feature_supported(SupportedLeFeatures::LE_CODED_PHY));
+        assert!(!device_information
+            .is_le_feature_supported(SupportedLeFeatures::ISOCHRONOUS_BROADCASTER));
     }
 }
@@ -0,0 +1,237 @@
+//! GAP discovery procedures.
+//!
+//! The procedures are defined in
+//! [Core Specification 6.0, Vol. 3, Part C, 9.2](https://www.bluetooth.com/wp-content/uploads/Files/Specification/HTML/Core-60/out/en/host/generic-access-profile.html).
+//! They are run through a [`HostHandle`](crate::HostHandle), e.g.:
+//!
+//! ```ignore
+//! let devices: heapless::Vec<AdvertisingReport, 8> = handle
+//!     .discover(&mut events, DiscoveryProcedure::General, &scan_params, &time_source)
+//!     .await?;
+//! ```
+//!
+//! The name discovery procedure only gets the Complete Local Name advertised by the peer
+//! device, reading its Device Name characteristic over GATT is not supported yet.
+
+use core::time::Duration;
+
+use heapless::{String, Vec};
+
+use crate::advertising::{
+    AdStruct, Flags, FullAdvertisingData, LocalNameComplete, LOCAL_NAME_MAX_LENGTH,
+};
+use crate::AdvertisingReport;
+
+/// Minimum time to scan for during the discovery procedures, TGAP(gen_disc_scan_min) and
+/// TGAP(lim_disc_scan_min).
+pub const DISCOVERY_SCAN_DURATION: Duration = Duration::from_millis(10_240);
+
+/// GAP discovery procedure, telling which devices are discovered.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DiscoveryProcedure {
+    /// Discover the devices in the general or limited discoverable mode (default).
+    #[default]
+    General,
+    /// Discover only the devices in the limited discoverable mode.
+    Limited,
+}
+
+impl DiscoveryProcedure {
+    /// Tell whether a device advertising the given data is discovered by the procedure,
+    /// according to the Flags of its Advertising Data.
+    pub fn matches(&self, data: &FullAdvertisingData) -> bool {
+        let discoverable_flags = match self {
+            Self::General => {
+                Flags::LE_LIMITED_DISCOVERABLE_MODE | Flags::LE_GENERAL_DISCOVERABLE_MODE
+            }
+            Self::Limited => Flags::LE_LIMITED_DISCOVERABLE_MODE,
+        };
+        data.advertising_data().iter().any(|ad_struct| {
+            matches!(ad_struct, AdStruct::Flags(flags) if flags.value().intersects(discoverable_flags))
+        })
+    }
+}
+
+/// Result of the GAP name discovery procedure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NameDiscovery {
+    /// The peer device advertises its Complete Local Name.
+    Advertised(String<LOCAL_NAME_MAX_LENGTH>),
+    /// The peer device has been found but does not advertise its Complete Local Name.
+    ///
+    /// The name would then have to be read from its Device Name characteristic after
+    /// connecting, which is not supported yet.
+    NotAdvertised,
+    /// The peer device has not been found.
+    NotFound,
+}
+
+impl NameDiscovery {
+    /// Get the discovered name, if any.
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Self::Advertised(name) => Some(name),
+            Self::NotAdvertised | Self::NotFound => None,
+        }
+    }
+}
+
+/// Add a discovered device to the result list of a discovery procedure.
+///
+/// A device already in the list has its report replaced, unless the previous one contains
+/// some Scan Response Data while the new one does not. The devices not fitting in the list are
+/// dropped.
+// Only used by the runner.
+#[cfg_attr(not(any(feature = "embassy", feature = "tokio")), allow(dead_code))]
+pub(crate) fn add_discovered_device<const N: usize>(
+    devices: &mut Vec<AdvertisingReport, N>,
+    report: AdvertisingReport,
+) {
+    match devices
+        .iter_mut()
+        .find(|device| device.address() == report.address())
+    {
+        Some(device) => {
+            if report.data().scan_response_data().is_some()
+                || device.data().scan_response_data().is_none()
+            {
+                *device = report;
+            }
+        }
+        None => {
+            let _ = devices.push(report);
+        }
+    }
+}
+
+/// Get the Complete Local Name contained in the advertised data, if any.
+// Only used by the runner.
+#[cfg_attr(not(any(feature = "embassy", feature = "tokio")), allow(dead_code))]
+pub(crate) fn complete_local_name(
+    data: &FullAdvertisingData,
+) -> Option<String<LOCAL_NAME_MAX_LENGTH>> {
+    data.iter().find_map(|ad_struct| match ad_struct {
+        AdStruct::LocalName(local_name) if local_name.complete() == LocalNameComplete::Complete => {
+            local_name.value().try_into().ok()
+        }
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use bletio_hci::{
+        ConnectionPeerAddress, LeAdvertisingReportData, LeAdvertisingReportEventType,
+        PublicDeviceAddress, Rssi,
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::advertising::{AdvertisingData, ScanResponseData};
+
+    fn full_adv_data(flags: Option<Flags>, local_name: Option<&str>) -> FullAdvertisingData {
+        let mut adv_data = AdvertisingData::builder();
+        if let Some(flags) = flags {
+            adv_data = adv_data.with_flags(flags).unwrap();
+        }
+        let scanresp_data = local_name.map(|local_name| {
+            let mut data: Vec<u8, 31> = Vec::new();
+            data.push(local_name.len() as u8 + 1).unwrap();
+            data.push(0x09).unwrap();
+            data.extend_from_slice(local_name.as_bytes()).unwrap();
+            ScanResponseData::from(LeAdvertisingReportData::try_from(data.as_slice()).unwrap())
+        });
+        FullAdvertisingData::try_new(adv_data.build(), scanresp_data).unwrap()
+    }
+
+    fn report(address: u8, rssi: i8, data: FullAdvertisingData) -> AdvertisingReport {
+        AdvertisingReport::new(
+            LeAdvertisingReportEventType::ConnectableUndirected,
+            ConnectionPeerAddress::PublicDevice(PublicDeviceAddress::new([address, 0, 0, 0, 0, 0])),
+            Some(Rssi::try_new(rssi).unwrap()),
+            data,
+        )
+    }
+
+    #[rstest]
+    #[case(DiscoveryProcedure::General, None, false)]
+    #[case(DiscoveryProcedure::General, Some(Flags::BREDR_NOT_SUPPORTED), false)]
+    #[case(
+        DiscoveryProcedure::General,
+        Some(Flags::LE_GENERAL_DISCOVERABLE_MODE),
+        true
+    )]
+    #[case(
+        DiscoveryProcedure::General,
+        Some(Flags::LE_LIMITED_DISCOVERABLE_MODE),
+        true
+    )]
+    #[case(
+        DiscoveryProcedure::Limited,
+        Some(Flags::LE_GENERAL_DISCOVERABLE_MODE),
+        false
+    )]
+    #[case(
+        DiscoveryProcedure::Limited,
+        Some(Flags::LE_LIMITED_DISCOVERABLE_MODE),
+        true
+    )]
+    fn test_discovery_procedure_matches(
+        #[case] procedure: DiscoveryProcedure,
+        #[case] flags: Option<Flags>,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(procedure.matches(&full_adv_data(flags, None)), expected);
+    }
+
+    #[test]
+    fn test_add_discovered_device() {
+        let flags = Some(Flags::LE_GENERAL_DISCOVERABLE_MODE);
+        let mut devices: Vec<AdvertisingReport, 2> = Vec::new();
+        add_discovered_device(&mut devices, report(1, -60, full_adv_data(flags, None)));
+        add_discovered_device(
+            &mut devices,
+            report(2, -70, full_adv_data(flags, Some("b"))),
+        );
+        add_discovered_device(
+            &mut devices,
+            report(1, -50, full_adv_data(flags, Some("a"))),
+        );
+        add_discovered_device(&mut devices, report(2, -40, full_adv_data(flags, None)));
+        add_discovered_device(&mut devices, report(3, -30, full_adv_data(flags, None)));
+        assert_eq!(
+            devices,
+            [
+                report(1, -50, full_adv_data(flags, Some("a"))),
+                report(2, -70, full_adv_data(flags, Some("b"))),
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case(full_adv_data(None, Some("bletio")), Some("bletio"))]
+    #[case(full_adv_data(None, None), None)]
+    #[case(
+        FullAdvertisingData::try_new(
+            AdvertisingData::builder()
+                .with_local_name(LocalNameComplete::Shortened(3))
+                .unwrap()
+                .build(),
+            None
+        )
+        .unwrap(),
+        None
+    )]
+    fn test_complete_local_name(#[case] data: FullAdvertisingData, #[case] expected: Option<&str>) {
+        assert_eq!(complete_local_name(&data).as_deref(), expected);
+    }
+
+    #[rstest]
+    #[case(NameDiscovery::Advertised("bletio".try_into().unwrap()), Some("bletio"))]
+    #[case(NameDiscovery::NotAdvertised, None)]
+    #[case(NameDiscovery::NotFound, None)]
+    fn test_name_discovery_name(#[case] discovery: NameDiscovery, #[case] expected: Option<&str>) {
+        assert_eq!(discovery.name(), expected);
+    }
+}
@@ -0,0 +1,360 @@
+//! Typed events of the host, as an alternative to the [`BleHostObserver`](crate::BleHostObserver)
+//! callbacks.
+
+#[cfg(not(feature = "defmt"))]
+use bitflags::bitflags;
+#[cfg(feature = "defmt")]
+use defmt::bitflags;
+
+use bletio_hci::{
+    ConnectionPeerAddress, DisconnectionCompleteEvent, Event, EventList, IsoData,
+    LeAdvertisingReport, LeAdvertisingReportEventType, LeBigSyncEstablishedEvent,
+    LeBigSyncLostEvent, LeBiginfoAdvertisingReportEvent, LeConnectionCompleteEvent,
+    LeConnectionUpdateCompleteEvent, LeCreateBigCompleteEvent, LeMetaEvent,
+    LeTerminateBigCompleteEvent, Rssi,
+};
+
+use crate::advertising::{FullAdvertisingData, FullAdvertisingDataView};
+use crate::recovery::ControllerResetCause;
+use crate::scanned_devices::ScannedDevice;
+
+/// Advertising report received while scanning.
+///
+/// The data of the scan response is included if it has been received along with the
+/// advertising data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AdvertisingReport {
+    event_type: LeAdvertisingReportEventType,
+    address: ConnectionPeerAddress,
+    rssi: Option<Rssi>,
+    data: FullAdvertisingData,
+}
+
+impl AdvertisingReport {
+    pub fn event_type(&self) -> LeAdvertisingReportEventType {
+        self.event_type
+    }
+
+    pub fn address(&self) -> &ConnectionPeerAddress {
+        &self.address
+    }
+
+    pub fn rssi(&self) -> Option<Rssi> {
+        self.rssi
+    }
+
+    pub fn data(&self) -> &FullAdvertisingData {
+        &self.data
+    }
+
+    pub(crate) fn new(
+        event_type: LeAdvertisingReportEventType,
+        address: ConnectionPeerAddress,
+        rssi: Option<Rssi>,
+        data: FullAdvertisingData,
+    ) -> Self {
+        Self {
+            event_type,
+            address,
+            rssi,
+            data,
+        }
+    }
+}
+
+/// Get all the LE advertising reports contained in an event list.
+pub(crate) fn le_advertising_reports<const NB_EVENTS: usize>(
+    event_list: &EventList<NB_EVENTS>,
+) -> impl Iterator<Item = LeAdvertisingReport<'_>> {
+    event_list
+        .iter()
+        .filter_map(|e| match e {
+            Event::LeMeta(LeMetaEvent::LeAdvertisingReport(reports)) => Some(reports),
+            _ => None,
+        })
+        .flat_map(|reports| reports.iter())
+}
+
+/// Get the advertising reports contained in an event list without copying their data, each
+/// advertising data being combined with its corresponding scan response data if it is in the same
+/// event list.
+pub(crate) fn borrowed_advertising_reports<const NB_EVENTS: usize>(
+    event_list: &EventList<NB_EVENTS>,
+) -> impl Iterator<Item = (LeAdvertisingReport<'_>, FullAdvertisingDataView<'_>)> {
+    le_advertising_reports(event_list)
+        .filter(|r| r.event_type() != LeAdvertisingReportEventType::ScanResponse)
+        .map(|report| {
+            let scanresp_data = le_advertising_reports(event_list)
+                .find(|r| {
+                    (r.event_type() == LeAdvertisingReportEventType::ScanResponse)
+                        && r.address() == report.address()
+                })
+                .map(|r| r.data().into());
+            let data = FullAdvertisingDataView::new(report.data().into(), scanresp_data);
+            (report, data)
+        })
+}
+
+/// Event related to scanning that needs to be published once it has been notified to the
+/// observer, borrowing its data until a [`HostEvent`] needs to be created from it.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum ScanEvent<'e, I> {
+    AdvertisingReport {
+        event_type: LeAdvertisingReportEventType,
+        address: &'e ConnectionPeerAddress,
+        rssi: Option<Rssi>,
+        data: FullAdvertisingDataView<'e>,
+    },
+    ScannedDeviceAppeared(&'e ScannedDevice<I>),
+    ScannedDeviceLost(&'e ScannedDevice<I>),
+    ScannedDeviceUpdated(&'e ScannedDevice<I>),
+}
+
+impl<I> From<ScanEvent<'_, I>> for HostEvent<I>
+where
+    I: Clone,
+{
+    fn from(value: ScanEvent<'_, I>) -> Self {
+        match value {
+            ScanEvent::AdvertisingReport {
+                event_type,
+                address,
+                rssi,
+                data,
+            } => Self::AdvertisingReport(AdvertisingReport::new(
+                event_type,
+                address.clone(),
+                rssi,
+                data.into(),
+            )),
+            ScanEvent::ScannedDeviceAppeared(device) => Self::ScannedDeviceAppeared(device.clone()),
+            ScanEvent::ScannedDeviceLost(device) => Self::ScannedDeviceLost(device.clone()),
+            ScanEvent::ScannedDeviceUpdated(device) => Self::ScannedDeviceUpdated(device.clone()),
+        }
+    }
+}
+
+/// Event notified by the host, `I` being the instant type of the time source of the device.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[non_exhaustive]
+pub enum HostEvent<I> {
+    AdvertisingReport(AdvertisingReport),
+    BigCreated(LeCreateBigCompleteEvent),
+    BigSyncEstablished(LeBigSyncEstablishedEvent),
+    BigSyncLost(LeBigSyncLostEvent),
+    BigTerminated(LeTerminateBigCompleteEvent),
+    BiginfoAdvertisingReport(LeBiginfoAdvertisingReportEvent),
+    ConnectionComplete(LeConnectionCompleteEvent),
+    /// The connection created by the direct connection establishment procedure has been
+    /// cancelled because its timeout has elapsed.
+    ConnectionEstablishmentTimeout,
+    ConnectionUpdateComplete(LeConnectionUpdateCompleteEvent),
+    ControllerReset(ControllerResetCause),
+    DisconnectionComplete(DisconnectionCompleteEvent),
+    /// ISO data has been received on a BIS of a BIG the host is synchronized to.
+    IsoDataReceived(IsoData),
+    /// The advertising in the limited discoverable mode has been stopped because
+    /// [`LIMITED_DISCOVERABLE_TIMEOUT`](crate::advertising::LIMITED_DISCOVERABLE_TIMEOUT) has
+    /// elapsed.
+    LimitedDiscoverableTimeout,
+    /// A device has been seen advertising for the first time since it has been lost, if ever.
+    ScannedDeviceAppeared(ScannedDevice<I>),
+    /// A device has not been seen advertising for the scanned device timeout.
+    ScannedDeviceLost(ScannedDevice<I>),
+    /// A new advertisement of an already seen device has been received.
+    ScannedDeviceUpdated(ScannedDevice<I>),
+}
+
+impl<I> HostEvent<I> {
+    /// Get the host events corresponding to the HCI events of an event list, except the
+    /// advertising reports that are published once paired with their scan responses.
+    // Only used by the runner.
+    #[cfg_attr(not(any(feature = "embassy", feature = "tokio")), allow(dead_code))]
+    pub(crate) fn from_event_list<const NB_EVENTS: usize>(
+        event_list: &EventList<NB_EVENTS>,
+    ) -> impl Iterator<Item = Self> + '_ {
+        event_list.iter().filter_map(|event| match event {
+            Event::DisconnectionComplete(event) => Some(Self::DisconnectionComplete(event.clone())),
+            Event::LeMeta(LeMetaEvent::LeConnectionComplete(event)) => {
+                Some(Self::ConnectionComplete(event.clone()))
+            }
+            Event::LeMeta(LeMetaEvent::LeConnectionUpdateComplete(event)) => {
+                Some(Self::ConnectionUpdateComplete(event.clone()))
+            }
+            Event::LeMeta(LeMetaEvent::LeCreateBigComplete(event)) => {
+                Some(Self::BigCreated(event.clone()))
+            }
+            Event::LeMeta(LeMetaEvent::LeTerminateBigComplete(event)) => {
+                Some(Self::BigTerminated(event.clone()))
+            }
+            Event::LeMeta(LeMetaEvent::LeBigSyncEstablished(event)) => {
+                Some(Self::BigSyncEstablished(event.clone()))
+            }
+            Event::LeMeta(LeMetaEvent::LeBigSyncLost(event)) => {
+                Some(Self::BigSyncLost(event.clone()))
+            }
+            Event::LeMeta(LeMetaEvent::LeBiginfoAdvertisingReport(event)) => {
+                Some(Self::BiginfoAdvertisingReport(event.clone()))
+            }
+            _ => None,
+        })
+    }
+
+    /// Get the kind of the event, as a single flag of the [`HostEventMask`].
+    pub fn kind(&self) -> HostEventMask {
+        match self {
+            Self::AdvertisingReport(_) => HostEventMask::ADVERTISING_REPORT,
+            Self::BigCreated(_) => HostEventMask::BIG_CREATED,
+            Self::BigSyncEstablished(_) => HostEventMask::BIG_SYNC_ESTABLISHED,
+            Self::BigSyncLost(_) => HostEventMask::BIG_SYNC_LOST,
+            Self::BigTerminated(_) => HostEventMask::BIG_TERMINATED,
+            Self::BiginfoAdvertisingReport(_) => HostEventMask::BIGINFO_ADVERTISING_REPORT,
+            Self::ConnectionComplete(_) => HostEventMask::CONNECTION_COMPLETE,
+            Self::ConnectionEstablishmentTimeout => HostEventMask::CONNECTION_ESTABLISHMENT_TIMEOUT,
+            Self::ConnectionUpdateComplete(_) => HostEventMask::CONNECTION_UPDATE_COMPLETE,
+            Self::ControllerReset(_) => HostEventMask::CONTROLLER_RESET,
+            Self::DisconnectionComplete(_) => HostEventMask::DISCONNECTION_COMPLETE,
+            Self::IsoDataReceived(_) => HostEventMask::ISO_DATA_RECEIVED,
+            Self::LimitedDiscoverableTimeout => HostEventMask::LIMITED_DISCOVERABLE_TIMEOUT,
+            Self::ScannedDeviceAppeared(_) => HostEventMask::SCANNED_DEVICE_APPEARED,
+            Self::ScannedDeviceLost(_) => HostEventMask::SCANNED_DEVICE_LOST,
+            Self::ScannedDeviceUpdated(_) => HostEventMask::SCANNED_DEVICE_UPDATED,
+        }
+    }
+
+    /// Tell whether the event is one of the kinds of the given mask.
+    pub fn matches(&self, mask: HostEventMask) -> bool {
+        mask.contains(self.kind())
+    }
+}
+
+bitflags! {
+    /// Mask of the kinds of [`HostEvent`], used to filter the events.
+    #[cfg_attr(not(feature = "defmt"), derive(Debug, Clone, Copy, PartialEq, Eq))]
+    pub struct HostEventMask: u16 {
+        const ADVERTISING_REPORT = 1 << 0;
+        const BIG_CREATED = 1 << 1;
+        const BIG_SYNC_ESTABLISHED = 1 << 2;
+        const BIG_SYNC_LOST = 1 << 3;
+        const BIG_TERMINATED = 1 << 4;
+        const BIGINFO_ADVERTISING_REPORT = 1 << 5;
+        const CONNECTION_COMPLETE = 1 << 6;
+        const CONNECTION_UPDATE_COMPLETE = 1 << 7;
+        const DISCONNECTION_COMPLETE = 1 << 8;
+        const CONTROLLER_RESET = 1 << 9;
+        const LIMITED_DISCOVERABLE_TIMEOUT = 1 << 10;
+        const CONNECTION_ESTABLISHMENT_TIMEOUT = 1 << 11;
+        const SCANNED_DEVICE_APPEARED = 1 << 12;
+        const SCANNED_DEVICE_LOST = 1 << 13;
+        const SCANNED_DEVICE_UPDATED = 1 << 14;
+        const ISO_DATA_RECEIVED = 1 << 15;
+        /// All the events related to connections.
+        const CONNECTION = Self::CONNECTION_COMPLETE.bits()
+            | Self::CONNECTION_ESTABLISHMENT_TIMEOUT.bits()
+            | Self::CONNECTION_UPDATE_COMPLETE.bits()
+            | Self::DISCONNECTION_COMPLETE.bits();
+        /// All the events related to Broadcast Isochronous Groups.
+        const BIG = Self::BIG_CREATED.bits()
+            | Self::BIG_SYNC_ESTABLISHED.bits()
+            | Self::BIG_SYNC_LOST.bits()
+            | Self::BIG_TERMINATED.bits()
+            | Self::BIGINFO_ADVERTISING_REPORT.bits()
+            | Self::ISO_DATA_RECEIVED.bits();
+        /// All the events related to the tracking of the scanned devices.
+        const SCANNED_DEVICE = Self::SCANNED_DEVICE_APPEARED.bits()
+            | Self::SCANNED_DEVICE_LOST.bits()
+            | Self::SCANNED_DEVICE_UPDATED.bits();
+    }
+}
+
+impl Default for HostEventMask {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::time::Duration;
+
+    use bletio_hci::{Packet, RandomStaticDeviceAddress};
+
+    use super::*;
+    use crate::advertising::AdStruct;
+
+    fn event_list(packets: &[&[u8]]) -> EventList {
+        let mut event_list = EventList::default();
+        for packet in packets {
+            let Ok((_, Packet::Event(event))) = Packet::parse(packet) else {
+                panic!("invalid event packet");
+            };
+            event_list.push(event).unwrap();
+        }
+        event_list
+    }
+
+    #[test]
+    fn test_host_events_from_event_list() {
+        let event_list = event_list(&[
+            &[4, 5, 4, 0, 1, 0, 19],
+            &[4, 62, 15, 2, 1, 0, 1, 1, 2, 3, 4, 5, 198, 3, 2, 1, 6, 196],
+        ]);
+        let events: heapless::Vec<HostEvent<Duration>, 4> =
+            HostEvent::from_event_list(&event_list).collect();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind(), HostEventMask::DISCONNECTION_COMPLETE);
+        assert!(events[0].matches(HostEventMask::CONNECTION));
+        assert!(!events[0].matches(HostEventMask::ADVERTISING_REPORT | HostEventMask::BIG));
+        assert!(events[0].matches(HostEventMask::default()));
+    }
+
+    #[test]
+    fn test_borrowed_advertising_reports() {
+        let event_list = event_list(&[
+            &[4, 5, 4, 0, 1, 0, 19],
+            &[4, 62, 15, 2, 1, 0, 1, 1, 2, 3, 4, 5, 198, 3, 2, 1, 6, 196],
+            &[
+                4, 62, 18, 2, 1, 4, 1, 1, 2, 3, 4, 5, 198, 6, 5, 9, 98, 108, 101, 116, 190,
+            ],
+        ]);
+        let reports: heapless::Vec<_, 4> = borrowed_advertising_reports(&event_list).collect();
+        assert_eq!(reports.len(), 1);
+        let (report, data) = &reports[0];
+        let report = AdvertisingReport::new(
+            report.event_type(),
+            report.address().clone(),
+            report.rssi(),
+            (*data).into(),
+        );
+        assert_eq!(
+            report.event_type(),
+            LeAdvertisingReportEventType::ConnectableUndirected
+        );
+        assert_eq!(
+            report.address(),
+            &ConnectionPeerAddress::RandomDevice(
+                RandomStaticDeviceAddress::try_new([1, 2, 3, 4, 5, 198])
+                    .unwrap()
+                    .into()
+            )
+        );
+        assert_eq!(report.rssi().map(|rssi| rssi.value()), Some(-60));
+        assert!(report.data().scan_response_data().is_some());
+        assert_eq!(
+            report
+                .data()
+                .iter()
+                .filter(|ad_struct| matches!(ad_struct, AdStruct::LocalName(_)))
+                .count(),
+            1
+        );
+        assert_eq!(
+            le_advertising_reports(&event_list)
+                .filter(|r| r.event_type() == LeAdvertisingReportEventType::ScanResponse)
+                .count(),
+            1
+        );
+    }
+}
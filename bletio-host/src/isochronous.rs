@@ -0,0 +1,117 @@
+//! Broadcast Isochronous Groups handling.
+//!
+//! A BIG is attached to an advertising set that is already doing periodic advertising, identified
+//! by its [`AdvertisingHandle`]. Once the BIG has been created, ISO SDUs can be streamed on each of
+//! its BISes using a [`BroadcastIsochronousStream`].
+//!
+//! Conversely, the synchronization to a BIG needs the [`SyncHandle`] of an established periodic
+//! advertising sync. Once synchronized, the received ISO data is notified with
+//! [`BleHostObserver::iso_data_received`](crate::BleHostObserver::iso_data_received) and
+//! published as [`HostEvent::IsoDataReceived`](crate::HostEvent::IsoDataReceived).
+//!
+//! The periodic advertising and the periodic advertising sync are not handled by bletio yet, so
+//! they need to be set up by other means before creating a BIG or synchronizing to it.
+
+pub use bletio_hci::{
+    AdvertisingHandle, BigCreateSyncParameters, BigHandle, BigParameters, BigSyncTimeout,
+    BroadcastCode, Framing, Packing, Phy, SduInterval, SyncHandle,
+};
+
+use bletio_hci::{ConnectionHandle, IsoData};
+
+use crate::Error;
+
+/// A BIS of a BIG on which ISO SDUs can be sent.
+///
+/// It keeps track of the packet sequence number that needs to be incremented for each sent SDU.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BroadcastIsochronousStream {
+    handle: ConnectionHandle,
+    packet_sequence_number: u16,
+}
+
+impl BroadcastIsochronousStream {
+    /// Create a stream for the BIS identified by the given connection handle.
+    ///
+    /// The BIS connection handles are provided by the LE Create BIG Complete event.
+    pub fn new(handle: ConnectionHandle) -> Self {
+        Self {
+            handle,
+            packet_sequence_number: 0,
+        }
+    }
+
+    /// Get the connection handle of the BIS.
+    pub fn handle(&self) -> ConnectionHandle {
+        self.handle
+    }
+
+    /// Get the packet sequence number that will be used for the next SDU.
+    pub fn packet_sequence_number(&self) -> u16 {
+        self.packet_sequence_number
+    }
+
+    pub(crate) fn next_iso_data(
+        &mut self,
+        timestamp: Option<u32>,
+        sdu: &[u8],
+    ) -> Result<IsoData, Error> {
+        let iso_data = IsoData::try_new_complete_sdu(
+            self.handle,
+            timestamp,
+            self.packet_sequence_number,
+            sdu,
+        )?;
+        self.packet_sequence_number = self.packet_sequence_number.wrapping_add(1);
+        Ok(iso_data)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bletio_hci::IsoPacketBoundaryFlag;
+
+    use super::*;
+
+    #[test]
+    fn test_broadcast_isochronous_stream_next_iso_data() {
+        let handle = ConnectionHandle::default();
+        let mut stream = BroadcastIsochronousStream::new(handle);
+        assert_eq!(stream.handle(), handle);
+        assert_eq!(stream.packet_sequence_number(), 0);
+
+        let iso_data = stream.next_iso_data(None, &[0x01, 0x02, 0x03]).unwrap();
+        assert_eq!(iso_data.handle(), handle);
+        assert_eq!(
+            iso_data.packet_boundary_flag(),
+            IsoPacketBoundaryFlag::CompleteSdu
+        );
+        assert_eq!(iso_data.packet_sequence_number(), Some(0));
+        assert_eq!(iso_data.data(), &[0x01, 0x02, 0x03]);
+        assert_eq!(stream.packet_sequence_number(), 1);
+    }
+
+    #[test]
+    fn test_broadcast_isochronous_stream_packet_sequence_number_wraps() {
+        let mut stream = BroadcastIsochronousStream {
+            handle: ConnectionHandle::default(),
+            packet_sequence_number: u16::MAX,
+        };
+        let iso_data = stream.next_iso_data(Some(1_000), &[0x01]).unwrap();
+        assert_eq!(iso_data.packet_sequence_number(), Some(u16::MAX));
+        assert_eq!(iso_data.timestamp(), Some(1_000));
+        assert_eq!(stream.packet_sequence_number(), 0);
+    }
+
+    #[test]
+    fn test_broadcast_isochronous_stream_sdu_too_big() {
+        let mut stream = BroadcastIsochronousStream::new(ConnectionHandle::default());
+        let err = stream.next_iso_data(None, &[0u8; 300]).unwrap_err();
+        assert_eq!(
+            err,
+            Error::Hci(bletio_hci::Error::DataWillNotFitIsoDataPacket)
+        );
+        assert_eq!(stream.packet_sequence_number(), 0);
+    }
+}
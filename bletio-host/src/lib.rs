@@ -1,3 +1,18 @@
+//! Host part of the bletio BLE stack.
+//!
+//! # Features
+//!
+//! - `tokio` (default): [`Runner`] and its channels based on tokio.
+//! - `embassy`: [`Runner`] and its channels based on embassy-sync.
+//! - `std-time`: standard library time source of bletio-hci, usable with any executor.
+//! - `std`: standard library support of bletio-hci, implies `std-time` and `tokio`.
+//! - `embedded-io-async`: serial port adapter of bletio-hci for the `embedded-io-async` traits.
+//! - `defmt`: `defmt::Format` implementations.
+//!
+//! The time source of a [`BleDevice`] is always given explicitly, so the host itself does not
+//! depend on a runtime. When both `tokio` and `embassy` are enabled, e.g. through the
+//! unification of the features of several dependencies, the [`Runner`] uses the tokio channels.
+
 #![no_std]
 
 use bletio_hci::Error as HciError;
@@ -7,8 +22,16 @@ pub mod advertising;
 pub mod assigned_numbers;
 pub mod ble_device;
 pub mod ble_host;
+pub mod connection_establishment;
 pub mod connection_parameters;
 pub mod connection_update_parameters;
+pub mod discovery;
+pub mod host_event;
+pub mod isochronous;
+pub mod recovery;
+#[cfg(any(feature = "embassy", feature = "tokio"))]
+pub mod runner;
+pub mod scanned_devices;
 pub mod uuid;
 
 pub use ble_device::BleDevice;
@@ -20,8 +43,15 @@ pub use connection_parameters::{ConnectionParameters, ConnectionParametersBuilde
 pub use connection_update_parameters::{
     ConnectionUpdateParameters, ConnectionUpdateParametersBuilder,
 };
+pub use host_event::{AdvertisingReport, HostEvent, HostEventMask};
+pub use recovery::{ControllerResetCause, RecoveryPolicy};
+#[cfg(any(feature = "embassy", feature = "tokio"))]
+pub use runner::{HostChannel, HostEvents, HostHandle, HostRequests, Runner};
+pub use scan_response_cache::DEFAULT_SCAN_RESPONSE_WINDOW;
+pub use scanned_devices::ScannedDevice;
 
 mod device_information;
+mod scan_response_cache;
 
 pub(crate) use device_information::DeviceInformation;
 
@@ -43,10 +73,16 @@ pub enum Error {
     InvalidConnectionParameters,
     /// The provided connection update parameters are invalid.
     InvalidConnectionUpdateParameters,
+    /// The host is not in a state allowing to perform the request.
+    InvalidStateForRequest,
     /// The Bluetooth controller is not LE capable.
     NonLeCapableController,
     /// The Random Static Device Address has already been created.
     RandomAddressAlreadyCreated,
+    /// The runner of the host has stopped, it cannot process requests anymore.
+    RunnerStopped,
+    /// More peer devices than the host can keep in its filter accept list have been given.
+    TooManyPeerDevices,
 }
 
 impl From<AdvertisingError> for Error {
@@ -0,0 +1,41 @@
+//! Recovery of the Controller after a failure.
+//!
+//! When the Controller reports a Hardware Error, or when the communication with it fails, the
+//! host resets it and sets it up again, restoring the random address, the filter accept list
+//! and the advertising or scanning that was active. The observer is then notified through
+//! [`BleHostObserver::controller_reset`](crate::BleHostObserver::controller_reset).
+
+use bletio_hci::HciDriverError;
+
+/// Default number of consecutive attempts to recover the Controller before giving up.
+pub const DEFAULT_RECOVERY_ATTEMPTS: u8 = 3;
+
+/// What to do when the Controller fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum RecoveryPolicy {
+    /// Do not recover the Controller, driver failures are returned by the host and Hardware
+    /// Error events are ignored.
+    Disabled,
+    /// Reset the Controller and restore its state, giving up after `max_attempts` consecutive
+    /// failed attempts.
+    ResetController { max_attempts: u8 },
+}
+
+impl Default for RecoveryPolicy {
+    fn default() -> Self {
+        Self::ResetController {
+            max_attempts: DEFAULT_RECOVERY_ATTEMPTS,
+        }
+    }
+}
+
+/// Reason why the Controller has been reset by the host.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ControllerResetCause {
+    /// The Controller reported a Hardware Error event with the given hardware code.
+    HardwareError(u8),
+    /// The HCI driver failed or a command timed out.
+    HciDriver(HciDriverError),
+}
@@ -0,0 +1,157 @@
+use core::sync::atomic::{AtomicU16, Ordering};
+use core::task::{Context, Poll};
+
+use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
+use embassy_sync::channel::Channel;
+use embassy_sync::mutex::Mutex;
+
+use super::{DefaultInstant, HostEvents, Request};
+use crate::{Error, HostEvent, HostEventMask};
+
+const HOST_EVENT_CHANNEL_SIZE: usize = 8;
+
+/// Channel carrying the requests of the [`HostHandle`]s to the [`Runner`](super::Runner),
+/// and the events from the [`Runner`](super::Runner) to the [`HostEvents`] stream.
+///
+/// It needs to be stored in a `static` so that the handles can be used from any task.
+pub struct HostChannel<I = DefaultInstant> {
+    requests: RequestChannel,
+    events: Channel<CriticalSectionRawMutex, HostEvent<I>, HOST_EVENT_CHANNEL_SIZE>,
+    event_mask: AtomicU16,
+}
+
+/// Part of the [`HostChannel`] used by the [`HostHandle`]s, that does not depend on the type of
+/// the events.
+struct RequestChannel {
+    requests: Channel<CriticalSectionRawMutex, (u32, Request), 1>,
+    responses: Channel<CriticalSectionRawMutex, (u32, Result<(), Error>), 1>,
+    // Only one request is in flight at a time, this holds the identifier of the last one.
+    last_request_id: Mutex<CriticalSectionRawMutex, u32>,
+}
+
+impl<I> HostChannel<I> {
+    pub const fn new() -> Self {
+        Self {
+            requests: RequestChannel {
+                requests: Channel::new(),
+                responses: Channel::new(),
+                last_request_id: Mutex::new(0),
+            },
+            events: Channel::new(),
+            event_mask: AtomicU16::new(HostEventMask::all().bits()),
+        }
+    }
+
+    /// Split the channel into the handle sending the requests, that can be copied, the stream
+    /// of events, and the requests to give to the [`Runner`](super::Runner).
+    pub fn split(&'static self) -> (HostHandle, HostEvents<I>, HostRequests<I>) {
+        (
+            HostHandle {
+                channel: &self.requests,
+            },
+            HostEvents::new(EventReceiver {
+                channel: &self.events,
+                mask: &self.event_mask,
+            }),
+            HostRequests { channel: self },
+        )
+    }
+}
+
+impl<I> Default for HostChannel<I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Handle to send requests to the host run by a [`Runner`](super::Runner) from any task.
+#[derive(Clone, Copy)]
+pub struct HostHandle {
+    channel: &'static RequestChannel,
+}
+
+impl HostHandle {
+    pub(super) async fn request(&self, request: Request) -> Result<(), Error> {
+        let mut last_request_id = self.channel.last_request_id.lock().await;
+        *last_request_id = last_request_id.wrapping_add(1);
+        let id = *last_request_id;
+        self.channel.requests.send((id, request)).await;
+        loop {
+            // Ignore the responses to the requests whose requesting task stopped waiting.
+            let (response_id, result) = self.channel.responses.receive().await;
+            if response_id == id {
+                return result;
+            }
+        }
+    }
+}
+
+/// Side of the [`HostChannel`] used by the [`Runner`](super::Runner), receiving the requests
+/// and sending the events.
+pub struct HostRequests<I: 'static = DefaultInstant> {
+    channel: &'static HostChannel<I>,
+}
+
+impl<I> HostRequests<I> {
+    /// Receive the next request, the handles being static it never returns `None`.
+    pub(super) async fn receive(&mut self) -> Option<(Request, Responder)> {
+        let (id, request) = self.channel.requests.requests.receive().await;
+        Some((
+            request,
+            Responder {
+                channel: &self.channel.requests,
+                id,
+            },
+        ))
+    }
+
+    /// Send an event to the [`HostEvents`] stream, dropping it if it does not match the mask of
+    /// the stream, or if the stream is full.
+    pub(super) fn publish(&mut self, event: HostEvent<I>) {
+        let mask =
+            HostEventMask::from_bits_truncate(self.channel.event_mask.load(Ordering::Relaxed));
+        if !event.matches(mask) {
+            return;
+        }
+        if self.channel.events.try_send(event).is_err() {
+            #[cfg(feature = "defmt")]
+            defmt::warn!("Host event stream is full, dropping event!");
+        }
+    }
+}
+
+pub(super) struct EventReceiver<I: 'static> {
+    channel: &'static Channel<CriticalSectionRawMutex, HostEvent<I>, HOST_EVENT_CHANNEL_SIZE>,
+    mask: &'static AtomicU16,
+}
+
+impl<I> EventReceiver<I> {
+    pub(super) fn mask(&self) -> HostEventMask {
+        HostEventMask::from_bits_truncate(self.mask.load(Ordering::Relaxed))
+    }
+
+    /// Set the mask of the events, also applied by the [`Runner`](super::Runner) so that the
+    /// events that are not wanted do not fill the channel.
+    pub(super) fn set_mask(&self, mask: HostEventMask) {
+        self.mask.store(mask.bits(), Ordering::Relaxed);
+    }
+
+    /// Poll the next event, the channel being static it is never `None`.
+    pub(super) fn poll_receive(&mut self, cx: &mut Context<'_>) -> Poll<Option<HostEvent<I>>> {
+        self.channel.poll_receive(cx).map(Some)
+    }
+}
+
+pub(super) struct Responder {
+    channel: &'static RequestChannel,
+    id: u32,
+}
+
+impl Responder {
+    pub(super) fn respond(self, result: Result<(), Error>) {
+        // Drop a response that has not been received because its requesting task stopped
+        // waiting for it.
+        self.channel.responses.clear();
+        let _ = self.channel.responses.try_send((self.id, result));
+    }
+}
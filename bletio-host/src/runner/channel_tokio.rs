@@ -0,0 +1,146 @@
+extern crate std;
+
+use core::sync::atomic::{AtomicU16, Ordering};
+use core::task::{Context, Poll};
+use std::sync::Arc;
+
+use tokio::sync::{mpsc, oneshot};
+
+use super::{DefaultInstant, HostEvents, Request};
+use crate::{Error, HostEvent, HostEventMask};
+
+const HOST_CHANNEL_SIZE: usize = 4;
+const HOST_EVENT_CHANNEL_SIZE: usize = 8;
+
+type Message = (Request, oneshot::Sender<Result<(), Error>>);
+
+/// Channel carrying the requests of the [`HostHandle`]s to the [`Runner`](super::Runner),
+/// and the events from the [`Runner`](super::Runner) to the [`HostEvents`] stream.
+#[derive(Debug)]
+pub struct HostChannel<I = DefaultInstant> {
+    sender: mpsc::Sender<Message>,
+    receiver: mpsc::Receiver<Message>,
+    event_sender: mpsc::Sender<HostEvent<I>>,
+    event_receiver: mpsc::Receiver<HostEvent<I>>,
+    event_mask: Arc<AtomicU16>,
+}
+
+impl<I> HostChannel<I> {
+    pub fn new() -> Self {
+        let (sender, receiver) = mpsc::channel(HOST_CHANNEL_SIZE);
+        let (event_sender, event_receiver) = mpsc::channel(HOST_EVENT_CHANNEL_SIZE);
+        Self {
+            sender,
+            receiver,
+            event_sender,
+            event_receiver,
+            event_mask: Arc::new(AtomicU16::new(HostEventMask::default().bits())),
+        }
+    }
+
+    /// Split the channel into the handle sending the requests, that can be cloned, the stream
+    /// of events, and the requests to give to the [`Runner`](super::Runner).
+    pub fn split(self) -> (HostHandle, HostEvents<I>, HostRequests<I>) {
+        (
+            HostHandle {
+                sender: self.sender,
+            },
+            HostEvents::new(EventReceiver {
+                receiver: self.event_receiver,
+                mask: self.event_mask.clone(),
+            }),
+            HostRequests {
+                receiver: self.receiver,
+                event_sender: self.event_sender,
+                event_mask: self.event_mask,
+            },
+        )
+    }
+}
+
+impl<I> Default for HostChannel<I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Handle to send requests to the host run by a [`Runner`](super::Runner) from any task.
+#[derive(Debug, Clone)]
+pub struct HostHandle {
+    sender: mpsc::Sender<Message>,
+}
+
+impl HostHandle {
+    pub(super) async fn request(&self, request: Request) -> Result<(), Error> {
+        let (responder, response) = oneshot::channel();
+        self.sender
+            .send((request, responder))
+            .await
+            .map_err(|_| Error::RunnerStopped)?;
+        response.await.map_err(|_| Error::RunnerStopped)?
+    }
+}
+
+/// Side of the [`HostChannel`] used by the [`Runner`](super::Runner), receiving the requests
+/// and sending the events.
+#[derive(Debug)]
+pub struct HostRequests<I = DefaultInstant> {
+    receiver: mpsc::Receiver<Message>,
+    event_sender: mpsc::Sender<HostEvent<I>>,
+    event_mask: Arc<AtomicU16>,
+}
+
+impl<I> HostRequests<I> {
+    /// Receive the next request, or `None` if all the handles have been dropped.
+    pub(super) async fn receive(&mut self) -> Option<(Request, Responder)> {
+        self.receiver
+            .recv()
+            .await
+            .map(|(request, responder)| (request, Responder(responder)))
+    }
+
+    /// Send an event to the [`HostEvents`] stream, dropping it if it does not match the mask of
+    /// the stream, or if the stream is full or has been dropped.
+    pub(super) fn publish(&mut self, event: HostEvent<I>) {
+        let mask = HostEventMask::from_bits_truncate(self.event_mask.load(Ordering::Relaxed));
+        if !event.matches(mask) {
+            return;
+        }
+        if self.event_sender.try_send(event).is_err() {
+            #[cfg(feature = "defmt")]
+            defmt::warn!("Host event stream is full, dropping event!");
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct EventReceiver<I> {
+    receiver: mpsc::Receiver<HostEvent<I>>,
+    mask: Arc<AtomicU16>,
+}
+
+impl<I> EventReceiver<I> {
+    pub(super) fn mask(&self) -> HostEventMask {
+        HostEventMask::from_bits_truncate(self.mask.load(Ordering::Relaxed))
+    }
+
+    /// Set the mask of the events, also applied by the [`Runner`](super::Runner) so that the
+    /// events that are not wanted do not fill the channel.
+    pub(super) fn set_mask(&self, mask: HostEventMask) {
+        self.mask.store(mask.bits(), Ordering::Relaxed);
+    }
+
+    /// Poll the next event, `None` meaning that the [`Runner`](super::Runner) has stopped.
+    pub(super) fn poll_receive(&mut self, cx: &mut Context<'_>) -> Poll<Option<HostEvent<I>>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+pub(super) struct Responder(oneshot::Sender<Result<(), Error>>);
+
+impl Responder {
+    pub(super) fn respond(self, result: Result<(), Error>) {
+        // The requesting task may have stopped waiting for the response, nothing to do then.
+        let _ = self.0.send(result);
+    }
+}
@@ -0,0 +1,964 @@
+//! Host running in a background task, driven through cloneable handles.
+//!
+//! With the [`BleDevice::run`] architecture, the [`BleHostObserver`] callbacks receive and
+//! return the whole [`BleHostStates`], so commands can only be issued from these callbacks.
+//! With the [`Runner`], the host is owned by a single task that processes the HCI events and
+//! the requests sent by the [`HostHandle`]s, that can be used from any other task, e.g. to
+//! start advertising when a button is pressed:
+//!
+//! ```ignore
+//! static HOST_CHANNEL: HostChannel = HostChannel::new(); // With the embassy feature
+//! let (handle, mut events, requests) = HOST_CHANNEL.split();
+//! let runner = Runner::new(BleDevice::builder((), time_source).build(), hci_driver, requests);
+//!
+//! // In one task
+//! runner.run().await?;
+//!
+//! // In another task
+//! handle.start_advertising(&adv_params, &full_adv_data).await?;
+//!
+//! // In yet another task
+//! events.set_mask(HostEventMask::CONNECTION);
+//! while let Some(event) = events.next().await {
+//!     // ...
+//! }
+//! ```
+//!
+//! The [`HostEvents`] stream is an alternative to the [`BleHostObserver`] callbacks, both
+//! receiving all the events. The events are dropped if the stream is not consumed fast enough,
+//! so the events that are not needed should be masked out, which prevents the runner from sending
+//! them at all.
+//!
+//! The channel is provided by tokio (mpsc) with the `tokio` feature or by embassy-sync with
+//! the `embassy` feature, the tokio one being used when both features are enabled.
+
+use core::future::{poll_fn, Future};
+use core::pin::{pin, Pin};
+use core::task::{Context, Poll};
+use core::time::Duration;
+
+use bletio_hci::{
+    ConnectionHandle, ConnectionPeerAddress, DefaultTimeSource, FilterDuplicates, HciDriver,
+    LeAdvertisingReportEventType, LeFilterAcceptListAddress, Reason, ScanType, TimeSource,
+    WithTimeout, ACL_DATA_MAX_SIZE, EVENT_LIST_NB_EVENTS, HCI_DEFAULT_EVENT_QUEUE_SIZE,
+    HCI_DEFAULT_ISO_DATA_QUEUE_SIZE, HCI_MAX_READ_BUFFER_SIZE,
+};
+use heapless::Vec;
+
+use crate::advertising::{
+    AdvertisingData, AdvertisingParameters, ConnectableMode, DiscoverableMode, FullAdvertisingData,
+    ScanFilter, ScanParameters, ScanResponseData,
+};
+use crate::ble_host::{
+    rotate_advertising_data, AdvertisingDataRotation, FILTER_ACCEPT_LIST_MAX_SIZE,
+};
+use crate::discovery::{
+    add_discovered_device, complete_local_name, DiscoveryProcedure, NameDiscovery,
+    DISCOVERY_SCAN_DURATION,
+};
+use crate::{
+    AdvertisingReport, BleDevice, BleHostObserver, BleHostStates, ConnectionParameters,
+    ConnectionUpdateParameters, Error, HostEvent, HostEventMask,
+};
+
+// The tokio channels are used when both the `embassy` and `tokio` features are enabled.
+#[cfg(all(feature = "embassy", not(feature = "tokio")))]
+mod channel_embassy;
+#[cfg(feature = "tokio")]
+mod channel_tokio;
+
+#[cfg(all(feature = "embassy", not(feature = "tokio")))]
+use channel_embassy::EventReceiver;
+#[cfg(all(feature = "embassy", not(feature = "tokio")))]
+pub use channel_embassy::{HostChannel, HostHandle, HostRequests};
+#[cfg(feature = "tokio")]
+use channel_tokio::EventReceiver;
+#[cfg(feature = "tokio")]
+pub use channel_tokio::{HostChannel, HostHandle, HostRequests};
+
+/// Instant type of the events of the host channels by default, that of [`DefaultTimeSource`].
+type DefaultInstant = <DefaultTimeSource as TimeSource>::Instant;
+
+/// Request sent by a [`HostHandle`] to the [`Runner`].
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
+pub(crate) enum Request {
+    CancelConnection,
+    Connect(ConnectionParameters),
+    ConnectAuto(
+        Vec<LeFilterAcceptListAddress, FILTER_ACCEPT_LIST_MAX_SIZE>,
+        ConnectionParameters,
+    ),
+    ConnectDirect(ConnectionPeerAddress, ConnectionParameters, Duration),
+    Disconnect(ConnectionHandle, Reason),
+    StartAdvertising(AdvertisingParameters, FullAdvertisingData),
+    StartAdvertisingInModes(
+        DiscoverableMode,
+        ConnectableMode,
+        AdvertisingParameters,
+        FullAdvertisingData,
+    ),
+    StartScanning(ScanParameters, FilterDuplicates),
+    StartScanningWithFilter(ScanParameters, FilterDuplicates, ScanFilter),
+    StopAdvertising,
+    StopScanning,
+    UpdateAdvertisingData(AdvertisingData),
+    UpdateConnection(ConnectionUpdateParameters),
+    UpdateFullAdvertisingData(FullAdvertisingData),
+    UpdateScanResponseData(ScanResponseData),
+}
+
+impl HostHandle {
+    /// Cancel the connection being created, going back to the standby state.
+    pub async fn cancel_connection(&self) -> Result<(), Error> {
+        self.request(Request::CancelConnection).await
+    }
+
+    /// Run a GAP discovery procedure, the host needs to be in the standby state.
+    ///
+    /// The host scans with the given parameters for [`DISCOVERY_SCAN_DURATION`], then goes back
+    /// to the standby state. The devices discovered by the procedure are returned, each one
+    /// only once, up to `N` devices. The events other than the advertising reports received
+    /// from `events` meanwhile are dropped.
+    pub async fn discover<I, T, const N: usize>(
+        &self,
+        events: &mut HostEvents<I>,
+        procedure: DiscoveryProcedure,
+        scan_params: &ScanParameters,
+        time_source: &T,
+    ) -> Result<Vec<AdvertisingReport, N>, Error>
+    where
+        T: TimeSource,
+    {
+        let mut devices = Vec::new();
+        self.scan_for(
+            events,
+            scan_params,
+            DISCOVERY_SCAN_DURATION,
+            time_source,
+            |report| {
+                if procedure.matches(report.data()) {
+                    add_discovered_device(&mut devices, report);
+                }
+                false
+            },
+        )
+        .await?;
+        Ok(devices)
+    }
+
+    /// Run the GAP name discovery procedure, the host needs to be in the standby state.
+    ///
+    /// The host scans actively, with the given parameters, until it gets the Complete Local Name
+    /// advertised by the peer device or for at most [`DISCOVERY_SCAN_DURATION`], then goes back
+    /// to the standby state. Reading the Device Name characteristic of a peer device that does
+    /// not advertise its Complete Local Name is not supported yet,
+    /// [`NameDiscovery::NotAdvertised`] being returned instead. The events other than the
+    /// advertising reports received from `events` meanwhile are dropped.
+    pub async fn discover_name<I, T>(
+        &self,
+        events: &mut HostEvents<I>,
+        peer_address: &ConnectionPeerAddress,
+        scan_params: &ScanParameters,
+        time_source: &T,
+    ) -> Result<NameDiscovery, Error>
+    where
+        T: TimeSource,
+    {
+        let scan_params = ScanParameters::builder()
+            .with_type(ScanType::ActiveScanning)
+            .with_interval(scan_params.interval())
+            .with_window(scan_params.window())
+            .with_own_address_type(scan_params.own_address_type())
+            .with_filter_policy(scan_params.filter_policy())
+            .try_build()?;
+        let mut discovery = NameDiscovery::NotFound;
+        self.scan_for(
+            events,
+            &scan_params,
+            DISCOVERY_SCAN_DURATION,
+            time_source,
+            |report| {
+                if report.address() == peer_address {
+                    discovery = match complete_local_name(report.data()) {
+                        Some(name) => NameDiscovery::Advertised(name),
+                        None => NameDiscovery::NotAdvertised,
+                    };
+                }
+                matches!(discovery, NameDiscovery::Advertised(_))
+            },
+        )
+        .await?;
+        Ok(discovery)
+    }
+
+    /// Scan for `duration`, giving the advertising reports to `func` until it returns `true`.
+    ///
+    /// The mask of `events` is restored when done, even if the future is dropped.
+    async fn scan_for<I, T>(
+        &self,
+        events: &mut HostEvents<I>,
+        scan_params: &ScanParameters,
+        duration: Duration,
+        time_source: &T,
+        mut func: impl FnMut(AdvertisingReport) -> bool,
+    ) -> Result<(), Error>
+    where
+        T: TimeSource,
+    {
+        self.start_scanning(scan_params, FilterDuplicates::Disabled)
+            .await?;
+        let events = MaskedHostEvents::new(events, HostEventMask::ADVERTISING_REPORT);
+        let start = time_source.now();
+        let result = loop {
+            let time_left = duration.saturating_sub(time_source.elapsed(start));
+            match events
+                .events
+                .next()
+                .with_timeout(time_source.delay(time_left))
+                .await
+            {
+                Ok(Some(HostEvent::AdvertisingReport(report))) => {
+                    if func(report) {
+                        break Ok(());
+                    }
+                }
+                Ok(Some(_)) => {}
+                Ok(None) => break Err(Error::RunnerStopped),
+                Err(_) => break Ok(()),
+            }
+        };
+        drop(events);
+        result?;
+        self.stop_scanning().await
+    }
+
+    /// Create a connection, the host needs to be in the standby state.
+    pub async fn connect(&self, connection_parameters: &ConnectionParameters) -> Result<(), Error> {
+        self.request(Request::Connect(connection_parameters.clone()))
+            .await
+    }
+
+    /// Run the GAP auto connection establishment procedure, the host needs to be in the standby
+    /// state, see [`BleHost::connect_auto`](crate::BleHost::connect_auto).
+    pub async fn connect_auto(
+        &self,
+        peer_addresses: &[LeFilterAcceptListAddress],
+        connection_parameters: &ConnectionParameters,
+    ) -> Result<(), Error> {
+        let peer_addresses =
+            Vec::from_slice(peer_addresses).map_err(|_| Error::TooManyPeerDevices)?;
+        self.request(Request::ConnectAuto(
+            peer_addresses,
+            connection_parameters.clone(),
+        ))
+        .await
+    }
+
+    /// Run the GAP direct connection establishment procedure, the host needs to be in the
+    /// standby state, see [`BleHost::connect_direct`](crate::BleHost::connect_direct).
+    pub async fn connect_direct(
+        &self,
+        peer_address: &ConnectionPeerAddress,
+        connection_parameters: &ConnectionParameters,
+        timeout: Duration,
+    ) -> Result<(), Error> {
+        self.request(Request::ConnectDirect(
+            peer_address.clone(),
+            connection_parameters.clone(),
+            timeout,
+        ))
+        .await
+    }
+
+    /// Run the GAP selective connection establishment procedure, the host needs to be in the
+    /// standby state.
+    ///
+    /// The host scans with the given parameters, giving the reports of connectable advertising
+    /// to `select` until it selects one of them, or for at most `timeout`. It then connects to
+    /// the selected device with the direct connection establishment procedure, using the same
+    /// `timeout`. The address of the selected device is returned, `None` meaning that no device
+    /// has been selected and that the host is back in the standby state. The events other than
+    /// the advertising reports received from `events` while scanning are dropped.
+    pub async fn connect_selective<I, T>(
+        &self,
+        events: &mut HostEvents<I>,
+        scan_params: &ScanParameters,
+        connection_parameters: &ConnectionParameters,
+        timeout: Duration,
+        time_source: &T,
+        mut select: impl FnMut(&AdvertisingReport) -> bool,
+    ) -> Result<Option<ConnectionPeerAddress>, Error>
+    where
+        T: TimeSource,
+    {
+        let mut selected = None;
+        self.scan_for(events, scan_params, timeout, time_source, |report| {
+            if matches!(
+                report.event_type(),
+                LeAdvertisingReportEventType::ConnectableUndirected
+                    | LeAdvertisingReportEventType::ConnectableDirected
+            ) && select(&report)
+            {
+                selected = Some(report.address().clone());
+            }
+            selected.is_some()
+        })
+        .await?;
+        if let Some(peer_address) = &selected {
+            self.connect_direct(peer_address, connection_parameters, timeout)
+                .await?;
+        }
+        Ok(selected)
+    }
+
+    /// Terminate a connection, the host needs to be connected.
+    pub async fn disconnect(
+        &self,
+        connection_handle: ConnectionHandle,
+        reason: Reason,
+    ) -> Result<(), Error> {
+        self.request(Request::Disconnect(connection_handle, reason))
+            .await
+    }
+
+    /// Start advertising, the host needs to be in the standby state.
+    pub async fn start_advertising(
+        &self,
+        adv_params: &AdvertisingParameters,
+        full_adv_data: &FullAdvertisingData,
+    ) -> Result<(), Error> {
+        self.request(Request::StartAdvertising(
+            adv_params.clone(),
+            full_adv_data.clone(),
+        ))
+        .await
+    }
+
+    /// Start advertising in the given GAP discoverable and connectable modes, the host needs to
+    /// be in the standby state.
+    pub async fn start_advertising_in_modes(
+        &self,
+        discoverable_mode: DiscoverableMode,
+        connectable_mode: &ConnectableMode,
+        adv_params: &AdvertisingParameters,
+        full_adv_data: &FullAdvertisingData,
+    ) -> Result<(), Error> {
+        self.request(Request::StartAdvertisingInModes(
+            discoverable_mode,
+            connectable_mode.clone(),
+            adv_params.clone(),
+            full_adv_data.clone(),
+        ))
+        .await
+    }
+
+    /// Start scanning, the host needs to be in the standby state.
+    pub async fn start_scanning(
+        &self,
+        scan_params: &ScanParameters,
+        filter_duplicates: FilterDuplicates,
+    ) -> Result<(), Error> {
+        self.request(Request::StartScanning(
+            scan_params.clone(),
+            filter_duplicates,
+        ))
+        .await
+    }
+
+    /// Start scanning, only notifying the advertising reports accepted by the given filter, the
+    /// host needs to be in the standby state.
+    pub async fn start_scanning_with_filter(
+        &self,
+        scan_params: &ScanParameters,
+        filter_duplicates: FilterDuplicates,
+        filter: &ScanFilter,
+    ) -> Result<(), Error> {
+        self.request(Request::StartScanningWithFilter(
+            scan_params.clone(),
+            filter_duplicates,
+            filter.clone(),
+        ))
+        .await
+    }
+
+    /// Change the advertised data on a timer, the host needs to be advertising.
+    ///
+    /// This is [`BleHost::rotate_advertising_data`](crate::BleHost::rotate_advertising_data)
+    /// with the delays measured with `time_source`, the runner handling the events meanwhile.
+    /// The rotation also ends with an error if the host stops advertising.
+    pub async fn rotate_advertising_data<T>(
+        &self,
+        time_source: &T,
+        period: Duration,
+        payload: impl FnMut(u32) -> Option<FullAdvertisingData>,
+    ) -> Result<(), Error>
+    where
+        T: TimeSource,
+    {
+        let mut rotation = HandleAdvertisingDataRotation {
+            handle: self,
+            time_source,
+        };
+        rotate_advertising_data(&mut rotation, period, payload).await
+    }
+
+    /// Stop advertising, going back to the standby state.
+    pub async fn stop_advertising(&self) -> Result<(), Error> {
+        self.request(Request::StopAdvertising).await
+    }
+
+    /// Stop scanning, going back to the standby state.
+    pub async fn stop_scanning(&self) -> Result<(), Error> {
+        self.request(Request::StopScanning).await
+    }
+
+    /// Change the Advertising Data, the host needs to be advertising.
+    pub async fn update_advertising_data(&self, adv_data: &AdvertisingData) -> Result<(), Error> {
+        self.request(Request::UpdateAdvertisingData(adv_data.clone()))
+            .await
+    }
+
+    /// Update the parameters of a connection, the host needs to be connected.
+    pub async fn update_connection(
+        &self,
+        connection_update_parameters: &ConnectionUpdateParameters,
+    ) -> Result<(), Error> {
+        self.request(Request::UpdateConnection(
+            connection_update_parameters.clone(),
+        ))
+        .await
+    }
+
+    /// Change both the Advertising Data and the Scan Response Data, the host needs to be
+    /// advertising.
+    pub async fn update_full_advertising_data(
+        &self,
+        full_adv_data: &FullAdvertisingData,
+    ) -> Result<(), Error> {
+        self.request(Request::UpdateFullAdvertisingData(full_adv_data.clone()))
+            .await
+    }
+
+    /// Change the Scan Response Data, the host needs to be advertising.
+    pub async fn update_scan_response_data(
+        &self,
+        scanresp_data: &ScanResponseData,
+    ) -> Result<(), Error> {
+        self.request(Request::UpdateScanResponseData(scanresp_data.clone()))
+            .await
+    }
+}
+
+/// Rotation of the advertised data through a [`HostHandle`].
+struct HandleAdvertisingDataRotation<'h, T> {
+    handle: &'h HostHandle,
+    time_source: &'h T,
+}
+
+impl<T> AdvertisingDataRotation for HandleAdvertisingDataRotation<'_, T>
+where
+    T: TimeSource,
+{
+    type Delay = T::Delay;
+
+    fn delay(&self, period: Duration) -> Self::Delay {
+        self.time_source.delay(period)
+    }
+
+    async fn update_advertised_data(
+        &mut self,
+        full_adv_data: &FullAdvertisingData,
+    ) -> Result<(), Error> {
+        self.handle
+            .update_full_advertising_data(full_adv_data)
+            .await
+    }
+}
+
+/// [`HostEvents`] with a temporary mask, the previous one being restored when dropped.
+struct MaskedHostEvents<'e, I: 'static> {
+    events: &'e mut HostEvents<I>,
+    previous_mask: HostEventMask,
+}
+
+impl<'e, I> MaskedHostEvents<'e, I> {
+    fn new(events: &'e mut HostEvents<I>, mask: HostEventMask) -> Self {
+        let previous_mask = events.mask();
+        events.set_mask(mask);
+        Self {
+            events,
+            previous_mask,
+        }
+    }
+}
+
+impl<I> Drop for MaskedHostEvents<'_, I> {
+    fn drop(&mut self) {
+        self.events.set_mask(self.previous_mask);
+    }
+}
+
+/// Stream of the events of the host run by a [`Runner`].
+///
+/// Only the events matching its mask are returned, all of them by default. The [`Runner`] does
+/// not send the other events, so they cannot fill the stream at the expense of the wanted ones.
+pub struct HostEvents<I: 'static = DefaultInstant> {
+    receiver: EventReceiver<I>,
+}
+
+impl<I> HostEvents<I> {
+    fn new(receiver: EventReceiver<I>) -> Self {
+        receiver.set_mask(HostEventMask::default());
+        Self { receiver }
+    }
+
+    pub fn mask(&self) -> HostEventMask {
+        self.receiver.mask()
+    }
+
+    /// Only return the events matching the mask from now on.
+    pub fn set_mask(&mut self, mask: HostEventMask) {
+        self.receiver.set_mask(mask);
+    }
+
+    pub fn with_mask(self, mask: HostEventMask) -> Self {
+        self.receiver.set_mask(mask);
+        self
+    }
+
+    /// Wait for the next event matching the mask, `None` meaning that the [`Runner`] has stopped.
+    pub async fn next(&mut self) -> Option<HostEvent<I>> {
+        poll_fn(|cx| self.poll_next_event(cx)).await
+    }
+
+    /// Wait for the next event matching the mask and the given predicate, the other events being
+    /// dropped.
+    pub async fn next_matching(
+        &mut self,
+        mut predicate: impl FnMut(&HostEvent<I>) -> bool,
+    ) -> Option<HostEvent<I>> {
+        loop {
+            match self.next().await {
+                Some(event) if !predicate(&event) => continue,
+                event => return event,
+            }
+        }
+    }
+
+    fn poll_next_event(&mut self, cx: &mut Context<'_>) -> Poll<Option<HostEvent<I>>> {
+        loop {
+            match self.receiver.poll_receive(cx) {
+                // The events sent before the last change of the mask may not match it.
+                Poll::Ready(Some(event)) if !event.matches(self.mask()) => continue,
+                poll => return poll,
+            }
+        }
+    }
+}
+
+impl<I> futures_core::Stream for HostEvents<I> {
+    type Item = HostEvent<I>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().poll_next_event(cx)
+    }
+}
+
+/// Owner of the host, processing the HCI events and the requests of the [`HostHandle`]s.
+pub struct Runner<
+    'a,
+    O,
+    H,
+    T,
+    const EVENT_QUEUE_SIZE: usize = HCI_DEFAULT_EVENT_QUEUE_SIZE,
+    const READ_BUFFER_SIZE: usize = HCI_MAX_READ_BUFFER_SIZE,
+    const EVENT_LIST_SIZE: usize = EVENT_LIST_NB_EVENTS,
+    const ACL_DATA_SIZE: usize = ACL_DATA_MAX_SIZE,
+    const ISO_DATA_QUEUE_SIZE: usize = HCI_DEFAULT_ISO_DATA_QUEUE_SIZE,
+> where
+    O: BleHostObserver,
+    H: HciDriver,
+    T: TimeSource,
+    T::Instant: 'static,
+{
+    device: BleDevice<
+        'a,
+        O,
+        T,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >,
+    hci_driver: H,
+    requests: HostRequests<T::Instant>,
+}
+
+impl<
+        'a,
+        O,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >
+    Runner<
+        'a,
+        O,
+        H,
+        T,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
+where
+    O: BleHostObserver,
+    H: HciDriver,
+    T: TimeSource + Clone,
+    T::Instant: 'static,
+{
+    pub fn new(
+        device: BleDevice<
+            'a,
+            O,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        hci_driver: H,
+        requests: HostRequests<T::Instant>,
+    ) -> Self {
+        Self {
+            device,
+            hci_driver,
+            requests,
+        }
+    }
+
+    /// Set up the host and process the HCI events and the requests until an error occurs.
+    ///
+    /// The HCI events are notified to the observer of the device, as with [`BleDevice::run`],
+    /// and sent to the [`HostEvents`] stream.
+    pub async fn run(self) -> Result<(), Error> {
+        let Self {
+            device,
+            hci_driver,
+            mut requests,
+        } = self;
+        let mut hci = device.create_hci(hci_driver);
+        let mut host = device.start(&mut hci).await?;
+        let mut requests_open = true;
+
+        loop {
+            let event_list = if requests_open {
+                match select(device.wait_for_event(&mut host), requests.receive()).await {
+                    Either::First(event_list) => event_list,
+                    Either::Second(Some((request, responder))) => {
+                        let result;
+                        (host, result) = process_request(host, request).await;
+                        responder.respond(result);
+                        if let Err(e) = &result {
+                            if let Some(cause) = device.controller_reset_cause(Err(e)) {
+                                host = device.reset_controller(host, cause).await?;
+                                requests.publish(HostEvent::ControllerReset(cause));
+                            }
+                        }
+                        continue;
+                    }
+                    Either::Second(None) => {
+                        // All the handles have been dropped, only process the events.
+                        requests_open = false;
+                        continue;
+                    }
+                }
+            } else {
+                device.wait_for_event(&mut host).await
+            };
+            let Some(event_list) = event_list else {
+                host = device
+                    .notify_expired_advertisements(host, &mut |event| {
+                        requests.publish(event.into())
+                    })
+                    .await;
+                host = device
+                    .notify_lost_scanned_devices(host, &mut |event| requests.publish(event.into()))
+                    .await;
+                host = match device.end_expired_limited_discoverable_mode(host).await {
+                    Ok((host, expired)) => {
+                        if expired {
+                            requests.publish(HostEvent::LimitedDiscoverableTimeout);
+                        }
+                        host
+                    }
+                    Err((e, host)) => {
+                        let (host, cause) = device.recover_from_error(host, e).await?;
+                        requests.publish(HostEvent::ControllerReset(cause));
+                        host
+                    }
+                };
+                host = match device.cancel_expired_connection_establishment(host).await {
+                    Ok((host, expired)) => {
+                        if expired {
+                            requests.publish(HostEvent::ConnectionEstablishmentTimeout);
+                        }
+                        host
+                    }
+                    Err((e, host)) => {
+                        let (host, cause) = device.recover_from_error(host, e).await?;
+                        requests.publish(HostEvent::ControllerReset(cause));
+                        host
+                    }
+                };
+                continue;
+            };
+
+            if let Some(cause) = device.controller_reset_cause(event_list.as_ref()) {
+                host = device.reset_controller(host, cause).await?;
+                requests.publish(HostEvent::ControllerReset(cause));
+                continue;
+            }
+            match event_list {
+                Ok(event_list) => {
+                    let result = device
+                        .handle_event_list_and_publish(host, &event_list, &mut |event| {
+                            requests.publish(event.into())
+                        })
+                        .await;
+                    host = match result {
+                        Ok(host) => {
+                            for event in HostEvent::from_event_list(&event_list) {
+                                requests.publish(event);
+                            }
+                            host
+                        }
+                        Err((e, host)) => {
+                            let (host, cause) = device.recover_from_error(host, e).await?;
+                            requests.publish(HostEvent::ControllerReset(cause));
+                            host
+                        }
+                    };
+                    host = device
+                        .notify_received_iso_data(host, &mut |iso_data| {
+                            requests.publish(HostEvent::IsoDataReceived(iso_data))
+                        })
+                        .await;
+                }
+                Err(Error::Hci(bletio_hci::Error::InvalidPacket)) => {
+                    // Ignore invalid HCI packet
+                    #[cfg(feature = "defmt")]
+                    defmt::warn!("Received invalid HCI packet");
+                }
+                Err(Error::Hci(bletio_hci::Error::EventQueueOverflow)) => {
+                    // Some events have been lost, keep on handling the next ones
+                    #[cfg(feature = "defmt")]
+                    defmt::warn!("Some HCI events have been dropped");
+                }
+                Err(Error::Hci(bletio_hci::Error::IsoDataQueueOverflow)) => {
+                    // Some ISO data has been lost, keep on handling the next ones
+                    #[cfg(feature = "defmt")]
+                    defmt::warn!("Some HCI ISO data packets have been dropped");
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+/// Process a request, returning the new state of the host, which is left in its previous state
+/// if the request has failed.
+async fn process_request<
+    'a,
+    H,
+    T,
+    const EVENT_QUEUE_SIZE: usize,
+    const READ_BUFFER_SIZE: usize,
+    const EVENT_LIST_SIZE: usize,
+    const ACL_DATA_SIZE: usize,
+    const ISO_DATA_QUEUE_SIZE: usize,
+>(
+    host: BleHostStates<
+        'a,
+        H,
+        T,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >,
+    request: Request,
+) -> (
+    BleHostStates<
+        'a,
+        H,
+        T,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >,
+    Result<(), Error>,
+)
+where
+    H: HciDriver,
+    T: TimeSource,
+{
+    match (host, request) {
+        (BleHostStates::Standby(host), Request::StartAdvertising(adv_params, full_adv_data)) => {
+            match host.start_advertising(&adv_params, &full_adv_data).await {
+                Ok(host) => (BleHostStates::Advertising(host), Ok(())),
+                Err((e, host)) => (BleHostStates::Standby(host), Err(e)),
+            }
+        }
+        (
+            BleHostStates::Standby(host),
+            Request::StartAdvertisingInModes(
+                discoverable_mode,
+                connectable_mode,
+                adv_params,
+                full_adv_data,
+            ),
+        ) => {
+            match host
+                .start_advertising_in_modes(
+                    discoverable_mode,
+                    &connectable_mode,
+                    &adv_params,
+                    &full_adv_data,
+                )
+                .await
+            {
+                Ok(host) => (BleHostStates::Advertising(host), Ok(())),
+                Err((e, host)) => (BleHostStates::Standby(host), Err(e)),
+            }
+        }
+        (BleHostStates::Standby(host), Request::StartScanning(scan_params, filter_duplicates)) => {
+            match host.start_scanning(&scan_params, filter_duplicates).await {
+                Ok(host) => (BleHostStates::Scanning(host), Ok(())),
+                Err((e, host)) => (BleHostStates::Standby(host), Err(e)),
+            }
+        }
+        (
+            BleHostStates::Standby(host),
+            Request::StartScanningWithFilter(scan_params, filter_duplicates, filter),
+        ) => match host
+            .start_scanning_with_filter(&scan_params, filter_duplicates, &filter)
+            .await
+        {
+            Ok(host) => (BleHostStates::Scanning(host), Ok(())),
+            Err((e, host)) => (BleHostStates::Standby(host), Err(e)),
+        },
+        (BleHostStates::Standby(host), Request::Connect(connection_parameters)) => {
+            match host.connect(&connection_parameters).await {
+                Ok(host) => (BleHostStates::Initiating(host), Ok(())),
+                Err((e, host)) => (BleHostStates::Standby(host), Err(e)),
+            }
+        }
+        (
+            BleHostStates::Standby(host),
+            Request::ConnectAuto(peer_addresses, connection_parameters),
+        ) => match host
+            .connect_auto(&peer_addresses, &connection_parameters)
+            .await
+        {
+            Ok(host) => (BleHostStates::Initiating(host), Ok(())),
+            Err((e, host)) => (BleHostStates::Standby(host), Err(e)),
+        },
+        (
+            BleHostStates::Standby(host),
+            Request::ConnectDirect(peer_address, connection_parameters, timeout),
+        ) => match host
+            .connect_direct(&peer_address, &connection_parameters, timeout)
+            .await
+        {
+            Ok(host) => (BleHostStates::Initiating(host), Ok(())),
+            Err((e, host)) => (BleHostStates::Standby(host), Err(e)),
+        },
+        (BleHostStates::Advertising(host), Request::StopAdvertising) => {
+            match host.stop_advertising().await {
+                Ok(host) => (BleHostStates::Standby(host), Ok(())),
+                Err((e, host)) => (BleHostStates::Advertising(host), Err(e)),
+            }
+        }
+        (BleHostStates::Scanning(host), Request::StopScanning) => {
+            match host.stop_scanning().await {
+                Ok(host) => (BleHostStates::Standby(host), Ok(())),
+                Err((e, host)) => (BleHostStates::Scanning(host), Err(e)),
+            }
+        }
+        (BleHostStates::Initiating(host), Request::CancelConnection) => {
+            match host.cancel_connection().await {
+                Ok(host) => (BleHostStates::Standby(host), Ok(())),
+                Err((e, host)) => (BleHostStates::Initiating(host), Err(e)),
+            }
+        }
+        (BleHostStates::ConnectedCentral(mut host), Request::Disconnect(handle, reason)) => {
+            let result = host.disconnect(handle, reason).await;
+            (BleHostStates::ConnectedCentral(host), result)
+        }
+        (BleHostStates::ConnectedPeripheral(mut host), Request::Disconnect(handle, reason)) => {
+            let result = host.disconnect(handle, reason).await;
+            (BleHostStates::ConnectedPeripheral(host), result)
+        }
+        (BleHostStates::Advertising(mut host), Request::UpdateAdvertisingData(adv_data)) => {
+            let result = host.update_advertising_data(&adv_data).await;
+            (BleHostStates::Advertising(host), result)
+        }
+        (
+            BleHostStates::Advertising(mut host),
+            Request::UpdateFullAdvertisingData(full_adv_data),
+        ) => {
+            let result = host.update_full_advertising_data(&full_adv_data).await;
+            (BleHostStates::Advertising(host), result)
+        }
+        (BleHostStates::Advertising(mut host), Request::UpdateScanResponseData(scanresp_data)) => {
+            let result = host.update_scan_response_data(&scanresp_data).await;
+            (BleHostStates::Advertising(host), result)
+        }
+        (BleHostStates::ConnectedCentral(mut host), Request::UpdateConnection(parameters)) => {
+            let result = host.update_connection(parameters).await;
+            (BleHostStates::ConnectedCentral(host), result)
+        }
+        (BleHostStates::ConnectedPeripheral(mut host), Request::UpdateConnection(parameters)) => {
+            let result = host.update_connection(parameters).await;
+            (BleHostStates::ConnectedPeripheral(host), result)
+        }
+        (host, _) => (host, Err(Error::InvalidStateForRequest)),
+    }
+}
+
+enum Either<A, B> {
+    First(A),
+    Second(B),
+}
+
+/// Wait for the first of two futures to complete, dropping the other one.
+async fn select<A, B>(first: A, second: B) -> Either<A::Output, B::Output>
+where
+    A: Future,
+    B: Future,
+{
+    let mut first = pin!(first);
+    let mut second = pin!(second);
+    poll_fn(|cx| {
+        if let Poll::Ready(output) = first.as_mut().poll(cx) {
+            return Poll::Ready(Either::First(output));
+        }
+        if let Poll::Ready(output) = second.as_mut().poll(cx) {
+            return Poll::Ready(Either::Second(output));
+        }
+        Poll::Pending
+    })
+    .await
+}
@@ -0,0 +1,257 @@
+//! Pairing of the scan responses received after their advertisements.
+//!
+//! The Controller may report the scan response of a scannable advertisement in a later batch of
+//! events than the advertisement itself. When scanning actively, the scannable advertisements
+//! received without their scan response are then kept for a short time window, waiting for it. They are notified along
+//! with their scan response if it is received in time, otherwise alone once the window has
+//! expired.
+
+use core::time::Duration;
+
+use bletio_hci::{ConnectionPeerAddress, LeAdvertisingReportEventType, Rssi, TimeSource};
+use heapless::Vec;
+
+use crate::advertising::AdvertisingData;
+
+/// Default time during which a scannable advertisement waits for its scan response.
+pub const DEFAULT_SCAN_RESPONSE_WINDOW: Duration = Duration::from_millis(100);
+
+/// Maximum number of scannable advertisements waiting for their scan response at the same time.
+const SCAN_RESPONSE_CACHE_SIZE: usize = 8;
+
+/// Scannable advertisement waiting for its scan response.
+#[derive(Debug, Clone)]
+pub(crate) struct PendingAdvertisement<I> {
+    pub(crate) received_at: I,
+    pub(crate) event_type: LeAdvertisingReportEventType,
+    pub(crate) address: ConnectionPeerAddress,
+    pub(crate) rssi: Option<Rssi>,
+    pub(crate) adv_data: AdvertisingData,
+}
+
+#[derive(Debug)]
+pub(crate) struct ScanResponseCache<I> {
+    window: Duration,
+    pending: Vec<PendingAdvertisement<I>, SCAN_RESPONSE_CACHE_SIZE>,
+}
+
+impl<I> ScanResponseCache<I>
+where
+    I: Copy,
+{
+    pub(crate) fn new(window: Duration) -> Self {
+        Self {
+            window,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Tell whether the scannable advertisements wait for their scan response, that is whether
+    /// the time window is not zero.
+    pub(crate) fn is_enabled(&self) -> bool {
+        !self.window.is_zero()
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.pending.clear();
+    }
+
+    /// Keep an advertisement until its scan response is received.
+    ///
+    /// An advertisement of the same peer that was already waiting is replaced, keeping the
+    /// instant it was received at so that a peer advertising more often than the time window
+    /// is still notified once the window has expired. If the cache is full, the oldest
+    /// advertisement is returned so that it can be notified without waiting anymore.
+    pub(crate) fn insert(
+        &mut self,
+        mut advertisement: PendingAdvertisement<I>,
+    ) -> Option<PendingAdvertisement<I>> {
+        if let Some(waiting) = self
+            .pending
+            .iter_mut()
+            .find(|waiting| waiting.address == advertisement.address)
+        {
+            advertisement.received_at = waiting.received_at;
+            *waiting = advertisement;
+            return None;
+        }
+        let evicted = if self.pending.is_full() {
+            Some(self.pending.remove(0))
+        } else {
+            None
+        };
+        // INVARIANT: There is room for the advertisement since one has been evicted if needed.
+        self.pending.push(advertisement).ok().unwrap();
+        evicted
+    }
+
+    /// Get the advertisement of a peer that is waiting for its scan response.
+    pub(crate) fn take(
+        &mut self,
+        address: &ConnectionPeerAddress,
+    ) -> Option<PendingAdvertisement<I>> {
+        self.pending
+            .iter()
+            .position(|advertisement| &advertisement.address == address)
+            .map(|index| self.pending.remove(index))
+    }
+
+    /// Get the oldest advertisement whose time window has expired.
+    pub(crate) fn take_expired(
+        &mut self,
+        time_source: &impl TimeSource<Instant = I>,
+    ) -> Option<PendingAdvertisement<I>> {
+        match self.pending.first() {
+            Some(advertisement)
+                if time_source.elapsed(advertisement.received_at) >= self.window =>
+            {
+                Some(self.pending.remove(0))
+            }
+            _ => None,
+        }
+    }
+
+    /// Get the time remaining before the time window of the oldest advertisement expires.
+    pub(crate) fn next_expiry(
+        &self,
+        time_source: &impl TimeSource<Instant = I>,
+    ) -> Option<Duration> {
+        self.pending.first().map(|advertisement| {
+            self.window
+                .saturating_sub(time_source.elapsed(advertisement.received_at))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::cell::Cell;
+
+    use bletio_hci::RandomStaticDeviceAddress;
+
+    use super::*;
+
+    /// Time source whose time only advances when told to.
+    #[derive(Default)]
+    struct VirtualTimeSource {
+        now: Cell<Duration>,
+    }
+
+    impl VirtualTimeSource {
+        fn advance(&self, duration: Duration) {
+            self.now.set(self.now.get() + duration);
+        }
+    }
+
+    impl TimeSource for VirtualTimeSource {
+        type Instant = Duration;
+        type Delay = core::future::Ready<()>;
+
+        fn now(&self) -> Self::Instant {
+            self.now.get()
+        }
+
+        fn elapsed(&self, instant: Self::Instant) -> Duration {
+            self.now.get().saturating_sub(instant)
+        }
+
+        fn delay(&self, duration: Duration) -> Self::Delay {
+            self.advance(duration);
+            core::future::ready(())
+        }
+    }
+
+    fn address(first_byte: u8) -> ConnectionPeerAddress {
+        ConnectionPeerAddress::RandomDevice(
+            RandomStaticDeviceAddress::try_new([first_byte, 2, 3, 4, 5, 198])
+                .unwrap()
+                .into(),
+        )
+    }
+
+    fn advertisement(
+        time_source: &VirtualTimeSource,
+        first_address_byte: u8,
+    ) -> PendingAdvertisement<Duration> {
+        PendingAdvertisement {
+            received_at: time_source.now(),
+            event_type: LeAdvertisingReportEventType::ScannableUndirected,
+            address: address(first_address_byte),
+            rssi: None,
+            adv_data: AdvertisingData::default(),
+        }
+    }
+
+    #[test]
+    fn test_scan_response_cache_take() {
+        let time_source = VirtualTimeSource::default();
+        let mut cache = ScanResponseCache::new(DEFAULT_SCAN_RESPONSE_WINDOW);
+        assert!(cache.is_enabled());
+        assert_eq!(cache.next_expiry(&time_source), None);
+
+        assert!(cache.insert(advertisement(&time_source, 1)).is_none());
+        assert!(cache.insert(advertisement(&time_source, 2)).is_none());
+        assert!(cache.insert(advertisement(&time_source, 1)).is_none());
+        assert_eq!(cache.pending.len(), 2);
+
+        assert_eq!(cache.take(&address(1)).map(|a| a.address), Some(address(1)));
+        assert!(cache.take(&address(1)).is_none());
+        assert_eq!(cache.take(&address(2)).map(|a| a.address), Some(address(2)));
+    }
+
+    #[test]
+    fn test_scan_response_cache_expiry() {
+        let time_source = VirtualTimeSource::default();
+        let mut cache = ScanResponseCache::new(Duration::from_millis(100));
+        cache.insert(advertisement(&time_source, 1));
+        time_source.advance(Duration::from_millis(60));
+        cache.insert(advertisement(&time_source, 2));
+        assert_eq!(
+            cache.next_expiry(&time_source),
+            Some(Duration::from_millis(40))
+        );
+        assert!(cache.take_expired(&time_source).is_none());
+
+        time_source.advance(Duration::from_millis(40));
+        assert_eq!(
+            cache.take_expired(&time_source).map(|a| a.address),
+            Some(address(1))
+        );
+        assert!(cache.take_expired(&time_source).is_none());
+        assert_eq!(
+            cache.next_expiry(&time_source),
+            Some(Duration::from_millis(60))
+        );
+    }
+
+    #[test]
+    fn test_scan_response_cache_replacement_keeps_reception_instant() {
+        let time_source = VirtualTimeSource::default();
+        let mut cache = ScanResponseCache::new(Duration::from_millis(100));
+        cache.insert(advertisement(&time_source, 1));
+        for _ in 0..4 {
+            time_source.advance(Duration::from_millis(30));
+            assert!(cache.insert(advertisement(&time_source, 1)).is_none());
+            assert_eq!(cache.pending.len(), 1);
+        }
+        assert_eq!(
+            cache.take_expired(&time_source).map(|a| a.address),
+            Some(address(1))
+        );
+    }
+
+    #[test]
+    fn test_scan_response_cache_full() {
+        let time_source = VirtualTimeSource::default();
+        let mut cache = ScanResponseCache::new(DEFAULT_SCAN_RESPONSE_WINDOW);
+        for i in 0..SCAN_RESPONSE_CACHE_SIZE as u8 {
+            assert!(cache.insert(advertisement(&time_source, i)).is_none());
+        }
+        let evicted = cache.insert(advertisement(&time_source, 100)).unwrap();
+        assert_eq!(evicted.address, address(0));
+
+        cache.clear();
+        assert_eq!(cache.next_expiry(&time_source), None);
+        assert!(!ScanResponseCache::<Duration>::new(Duration::ZERO).is_enabled());
+    }
+}
@@ -0,0 +1,384 @@
+//! Tracking of the devices found advertising while scanning.
+//!
+//! When enabled with
+//! [`BleDeviceBuilder::with_scanned_device_timeout`](crate::ble_device::BleDeviceBuilder::with_scanned_device_timeout),
+//! the advertising reports that are notified, either from passive or active scanning, feed a
+//! table of the devices that have been seen. Each [`ScannedDevice`] keeps when it has been seen
+//! for the last time, how many advertisements have been received from it, a smoothed value of
+//! its RSSI and its last advertising data. A device is considered lost once it has not been
+//! seen for the configured timeout, and is then removed from the table.
+
+use core::time::Duration;
+
+use bletio_hci::{ConnectionPeerAddress, Rssi, TimeSource};
+use heapless::Vec;
+
+use crate::advertising::{FullAdvertisingData, FullAdvertisingDataView};
+
+/// Maximum number of devices tracked at the same time.
+///
+/// When a new device is seen while the table is full, the device that has not been seen for
+/// the longest time is considered lost to make room for it.
+pub const SCANNED_DEVICE_CACHE_SIZE: usize = 16;
+
+/// Weight of a new RSSI value in the exponential moving average giving the smoothed RSSI, as a
+/// power of two: a new value weighs `1 / 2^RSSI_SMOOTHING_SHIFT`.
+pub const RSSI_SMOOTHING_SHIFT: u32 = 2;
+
+/// Number of fractional bits of the fixed-point smoothed RSSI.
+const SMOOTHED_RSSI_FRACTIONAL_BITS: u32 = 8;
+
+/// Device found advertising while scanning.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ScannedDevice<I> {
+    address: ConnectionPeerAddress,
+    last_seen: I,
+    advertisement_count: u32,
+    rssi: Option<Rssi>,
+    // Fixed-point value in 1/256 dBm, so that the device can be compared exactly.
+    smoothed_rssi: Option<i16>,
+    data: FullAdvertisingData,
+}
+
+impl<I> ScannedDevice<I>
+where
+    I: Copy,
+{
+    pub fn address(&self) -> &ConnectionPeerAddress {
+        &self.address
+    }
+
+    /// Get the time at which the last advertisement of the device has been received.
+    pub fn last_seen(&self) -> I {
+        self.last_seen
+    }
+
+    /// Get the number of advertisements received from the device since it has appeared.
+    pub fn advertisement_count(&self) -> u32 {
+        self.advertisement_count
+    }
+
+    /// Get the RSSI of the last advertisement received from the device.
+    pub fn rssi(&self) -> Option<Rssi> {
+        self.rssi
+    }
+
+    /// Get the RSSI of the device in dBm, smoothed with an exponential moving average using
+    /// [`RSSI_SMOOTHING_SHIFT`].
+    pub fn smoothed_rssi(&self) -> Option<f32> {
+        self.smoothed_rssi
+            .map(|smoothed| smoothed as f32 / (1 << SMOOTHED_RSSI_FRACTIONAL_BITS) as f32)
+    }
+
+    /// Get the last advertising data received from the device.
+    ///
+    /// The last scan response data received is kept when a later advertisement is received
+    /// without one, e.g. when the scan request has not been answered.
+    pub fn data(&self) -> &FullAdvertisingData {
+        &self.data
+    }
+
+    fn update(&mut self, seen_at: I, rssi: Option<Rssi>, data: FullAdvertisingDataView<'_>) {
+        self.last_seen = seen_at;
+        self.advertisement_count = self.advertisement_count.saturating_add(1);
+        self.rssi = rssi;
+        if let Some(rssi) = rssi {
+            let rssi = (rssi.value() as i32) << SMOOTHED_RSSI_FRACTIONAL_BITS;
+            let smoothed = match self.smoothed_rssi {
+                Some(smoothed) => {
+                    let smoothed = smoothed as i32;
+                    smoothed + ((rssi - smoothed) >> RSSI_SMOOTHING_SHIFT)
+                }
+                None => rssi,
+            };
+            // The smoothed value stays between the received RSSI values, so it fits.
+            self.smoothed_rssi = Some(smoothed as i16);
+        }
+        let scanresp_data = data
+            .scan_response_data()
+            .map(Into::into)
+            .or(self.data.scanresp_data.take());
+        self.data = FullAdvertisingData {
+            adv_data: data.advertising_data().into(),
+            scanresp_data,
+        };
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ScannedDeviceCache<I> {
+    timeout: Option<Duration>,
+    // The devices are sorted from the one seen the longest time ago to the last one seen.
+    devices: Vec<ScannedDevice<I>, SCANNED_DEVICE_CACHE_SIZE>,
+}
+
+impl<I> ScannedDeviceCache<I>
+where
+    I: Copy,
+{
+    pub(crate) fn new(timeout: Option<Duration>) -> Self {
+        Self {
+            timeout,
+            devices: Vec::new(),
+        }
+    }
+
+    /// Tell whether the scanned devices are tracked, that is whether a timeout has been given.
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.timeout.is_some()
+    }
+
+    pub(crate) fn get(&self, address: &ConnectionPeerAddress) -> Option<&ScannedDevice<I>> {
+        self.devices
+            .iter()
+            .find(|device| &device.address == address)
+    }
+
+    /// Record an advertisement received from a device.
+    ///
+    /// Tell whether the device has just appeared, along with the device that has been evicted
+    /// to make room for it if the table was full.
+    pub(crate) fn record(
+        &mut self,
+        time_source: &impl TimeSource<Instant = I>,
+        address: &ConnectionPeerAddress,
+        rssi: Option<Rssi>,
+        data: FullAdvertisingDataView<'_>,
+    ) -> (bool, Option<ScannedDevice<I>>) {
+        let now = time_source.now();
+        let (mut device, appeared, evicted) = match self
+            .devices
+            .iter()
+            .position(|device| &device.address == address)
+        {
+            Some(index) => (self.devices.remove(index), false, None),
+            None => {
+                let evicted = if self.devices.is_full() {
+                    Some(self.devices.remove(0))
+                } else {
+                    None
+                };
+                let device = ScannedDevice {
+                    address: address.clone(),
+                    last_seen: now,
+                    advertisement_count: 0,
+                    rssi: None,
+                    smoothed_rssi: None,
+                    data: FullAdvertisingData::default(),
+                };
+                (device, true, evicted)
+            }
+        };
+        device.update(now, rssi, data);
+        // INVARIANT: There is room for the device since it has been removed or one has been
+        // evicted if needed.
+        self.devices.push(device).ok().unwrap();
+        (appeared, evicted)
+    }
+
+    /// Get the device seen the longest time ago if it has not been seen for the timeout.
+    pub(crate) fn take_lost(
+        &mut self,
+        time_source: &impl TimeSource<Instant = I>,
+    ) -> Option<ScannedDevice<I>> {
+        match (self.devices.first(), self.timeout) {
+            (Some(device), Some(timeout)) if time_source.elapsed(device.last_seen) >= timeout => {
+                Some(self.devices.remove(0))
+            }
+            _ => None,
+        }
+    }
+
+    /// Get the time remaining before the device seen the longest time ago is lost.
+    pub(crate) fn next_expiry(
+        &self,
+        time_source: &impl TimeSource<Instant = I>,
+    ) -> Option<Duration> {
+        match (self.devices.first(), self.timeout) {
+            (Some(device), Some(timeout)) => {
+                Some(timeout.saturating_sub(time_source.elapsed(device.last_seen)))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::cell::Cell;
+
+    use approx::assert_relative_eq;
+    use bletio_hci::RandomStaticDeviceAddress;
+
+    use super::*;
+    use crate::advertising::{AdvertisingData, Flags, ScanResponseData, ServiceListComplete};
+    use crate::assigned_numbers::ServiceUuid;
+
+    /// Time source whose time only advances when told to.
+    #[derive(Default)]
+    struct VirtualTimeSource {
+        now: Cell<Duration>,
+    }
+
+    impl VirtualTimeSource {
+        fn advance(&self, duration: Duration) {
+            self.now.set(self.now.get() + duration);
+        }
+    }
+
+    impl TimeSource for VirtualTimeSource {
+        type Instant = Duration;
+        type Delay = core::future::Ready<()>;
+
+        fn now(&self) -> Self::Instant {
+            self.now.get()
+        }
+
+        fn elapsed(&self, instant: Self::Instant) -> Duration {
+            self.now.get().saturating_sub(instant)
+        }
+
+        fn delay(&self, duration: Duration) -> Self::Delay {
+            self.advance(duration);
+            core::future::ready(())
+        }
+    }
+
+    fn address(first_byte: u8) -> ConnectionPeerAddress {
+        ConnectionPeerAddress::RandomDevice(
+            RandomStaticDeviceAddress::try_new([first_byte, 2, 3, 4, 5, 198])
+                .unwrap()
+                .into(),
+        )
+    }
+
+    fn rssi(value: i8) -> Option<Rssi> {
+        Some(Rssi::try_new(value).unwrap())
+    }
+
+    #[test]
+    fn test_scanned_device_cache_record() {
+        let time_source = VirtualTimeSource::default();
+        let mut cache = ScannedDeviceCache::new(Some(Duration::from_secs(5)));
+        assert!(cache.is_enabled());
+        assert_eq!(
+            cache.record(&time_source, &address(1), rssi(-60), Default::default()),
+            (true, None)
+        );
+        time_source.advance(Duration::from_secs(1));
+        assert_eq!(
+            cache.record(&time_source, &address(1), None, Default::default()),
+            (false, None)
+        );
+        assert_eq!(
+            cache.record(&time_source, &address(1), rssi(-80), Default::default()),
+            (false, None)
+        );
+        assert_eq!(
+            cache.record(&time_source, &address(2), rssi(-40), Default::default()),
+            (true, None)
+        );
+
+        let device = cache.get(&address(1)).unwrap();
+        assert_eq!(device.address(), &address(1));
+        assert_eq!(device.last_seen(), Duration::from_secs(1));
+        assert_eq!(device.advertisement_count(), 3);
+        assert_eq!(device.rssi(), rssi(-80));
+        assert_relative_eq!(device.smoothed_rssi().unwrap(), -65.0);
+        let device = cache.get(&address(2)).unwrap();
+        assert_eq!(device.advertisement_count(), 1);
+        assert_relative_eq!(device.smoothed_rssi().unwrap(), -40.0);
+        assert!(cache.get(&address(3)).is_none());
+    }
+
+    #[test]
+    fn test_scanned_device_smoothed_rssi_extremes() {
+        let time_source = VirtualTimeSource::default();
+        let mut cache = ScannedDeviceCache::new(Some(Duration::from_secs(5)));
+        cache.record(&time_source, &address(1), rssi(-128), Default::default());
+        cache.record(&time_source, &address(1), rssi(20), Default::default());
+        assert_relative_eq!(
+            cache.get(&address(1)).unwrap().smoothed_rssi().unwrap(),
+            -91.0
+        );
+    }
+
+    #[test]
+    fn test_scanned_device_keeps_last_scan_response_data() {
+        let time_source = VirtualTimeSource::default();
+        let mut cache = ScannedDeviceCache::new(Some(Duration::from_secs(5)));
+        let adv_data = AdvertisingData::builder()
+            .with_flags(Flags::LE_GENERAL_DISCOVERABLE_MODE)
+            .unwrap()
+            .build();
+        let scanresp_data = ScanResponseData::builder()
+            .with_service_uuid16(&[ServiceUuid::Battery], ServiceListComplete::Complete)
+            .unwrap()
+            .build();
+        let data = FullAdvertisingDataView::new((&adv_data).into(), Some((&scanresp_data).into()));
+        cache.record(&time_source, &address(1), None, data);
+        let data = FullAdvertisingDataView::new((&adv_data).into(), None);
+        cache.record(&time_source, &address(1), None, data);
+
+        let device = cache.get(&address(1)).unwrap();
+        assert_eq!(device.data().advertising_data(), &adv_data);
+        assert_eq!(device.data().scan_response_data(), Some(&scanresp_data));
+    }
+
+    #[test]
+    fn test_scanned_device_cache_lost() {
+        let time_source = VirtualTimeSource::default();
+        let mut cache = ScannedDeviceCache::new(Some(Duration::from_secs(5)));
+        assert_eq!(cache.next_expiry(&time_source), None);
+        cache.record(&time_source, &address(1), None, Default::default());
+        time_source.advance(Duration::from_secs(2));
+        cache.record(&time_source, &address(2), None, Default::default());
+        assert_eq!(
+            cache.next_expiry(&time_source),
+            Some(Duration::from_secs(3))
+        );
+        time_source.advance(Duration::from_secs(2));
+        cache.record(&time_source, &address(1), None, Default::default());
+        assert!(cache.take_lost(&time_source).is_none());
+
+        time_source.advance(Duration::from_secs(3));
+        assert_eq!(
+            cache.take_lost(&time_source).map(|device| device.address),
+            Some(address(2))
+        );
+        assert!(cache.take_lost(&time_source).is_none());
+        assert_eq!(
+            cache.next_expiry(&time_source),
+            Some(Duration::from_secs(2))
+        );
+    }
+
+    #[test]
+    fn test_scanned_device_cache_full() {
+        let time_source = VirtualTimeSource::default();
+        let mut cache = ScannedDeviceCache::new(Some(Duration::from_secs(5)));
+        for i in 0..SCANNED_DEVICE_CACHE_SIZE as u8 {
+            assert_eq!(
+                cache.record(&time_source, &address(i), None, Default::default()),
+                (true, None)
+            );
+        }
+        cache.record(&time_source, &address(0), None, Default::default());
+        let (appeared, evicted) =
+            cache.record(&time_source, &address(100), None, Default::default());
+        assert!(appeared);
+        assert_eq!(evicted.map(|device| device.address), Some(address(1)));
+    }
+
+    #[test]
+    fn test_scanned_device_cache_disabled() {
+        let time_source = VirtualTimeSource::default();
+        let mut cache = ScannedDeviceCache::<Duration>::new(None);
+        assert!(!cache.is_enabled());
+        cache.record(&time_source, &address(1), None, Default::default());
+        time_source.advance(Duration::from_secs(3600));
+        assert_eq!(cache.next_expiry(&time_source), None);
+        assert!(cache.take_lost(&time_source).is_none());
+    }
+}
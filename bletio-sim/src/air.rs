@@ -0,0 +1,824 @@
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use bletio_hci::{
+    AclData, AdvertisingData, AdvertisingFilterPolicy, AdvertisingParameters, AdvertisingType,
+    CentralClockAccuracy, CommandCompleteEvent, CommandOpCode, CompletedPackets, ConnectionHandle,
+    ConnectionInterval, ConnectionParameters, ConnectionPeerAddress, DeviceAddress,
+    DisconnectionCompleteEvent, ErrorCode, Event, EventMask, EventParameter, InitiatorFilterPolicy,
+    Latency, LeAdvertisingReport, LeAdvertisingReportData, LeAdvertisingReportEventType,
+    LeAdvertisingReportList, LeConnectionCompleteEvent, LeEventMask, LeFilterAcceptListAddress,
+    LeMetaEvent, NumberOfCompletedPacketsEvent, OwnAddressType, Packet, PacketBoundaryFlag,
+    PublicDeviceAddress, RandomStaticDeviceAddress, Role, Rssi, ScanParameters, ScanType,
+    ScanningFilterPolicy, SupervisionTimeout, HCI_MAX_READ_BUFFER_SIZE,
+};
+use bletio_utils::{Buffer, BufferOps, EncodeToBuffer};
+use tokio::sync::{mpsc, watch};
+use tokio::time::Instant;
+
+use crate::VirtualController;
+
+pub(crate) type DeviceId = usize;
+
+const DEFAULT_RSSI: i8 = -50;
+const HIGH_DUTY_CYCLE_DIRECTED_ADVERTISING_INTERVAL: Duration = Duration::from_micros(3750);
+const HIGH_DUTY_CYCLE_DIRECTED_ADVERTISING_DURATION: Duration = Duration::from_millis(1280);
+const MAX_CONNECTION_HANDLE: u16 = 0x0EFF;
+
+/// Address contained in the LE Advertising Report events for an advertiser.
+fn report_address(address: &DeviceAddress) -> ConnectionPeerAddress {
+    match address {
+        DeviceAddress::Public(address) => ConnectionPeerAddress::PublicDevice(address.clone()),
+        DeviceAddress::Random(address) => ConnectionPeerAddress::RandomDevice(address.clone()),
+    }
+}
+
+/// Address of the peer to connect to, identity addresses are used as is since resolvable
+/// private addresses are not simulated.
+fn peer_address(address: &ConnectionPeerAddress) -> DeviceAddress {
+    match address {
+        ConnectionPeerAddress::PublicDevice(address)
+        | ConnectionPeerAddress::PublicIdentity(address) => address.clone().into(),
+        ConnectionPeerAddress::RandomDevice(address)
+        | ConnectionPeerAddress::RandomIdentity(address) => address.clone().into(),
+    }
+}
+
+/// LE Connection Complete event reporting a connection that has not been established.
+pub(crate) fn le_connection_failure(status: ErrorCode, role: Role) -> Event {
+    Event::LeMeta(LeMetaEvent::LeConnectionComplete(
+        LeConnectionCompleteEvent::new(
+            status,
+            ConnectionHandle::default(),
+            role,
+            DeviceAddress::default(),
+            ConnectionInterval::default(),
+            Latency::default(),
+            SupervisionTimeout::default(),
+            CentralClockAccuracy::Ppm500,
+        ),
+    ))
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Advertising {
+    pub(crate) parameters: AdvertisingParameters,
+    pub(crate) data: AdvertisingData,
+    pub(crate) scan_response_data: AdvertisingData,
+    pub(crate) enabled_at: Option<Instant>,
+}
+
+impl Advertising {
+    fn is_connectable(&self) -> bool {
+        matches!(
+            self.parameters.r#type(),
+            AdvertisingType::ConnectableUndirected
+                | AdvertisingType::ConnectableHighDutyCycleDirected
+                | AdvertisingType::ConnectableLowDutyCycleDirected
+        )
+    }
+
+    fn is_directed(&self) -> bool {
+        matches!(
+            self.parameters.r#type(),
+            AdvertisingType::ConnectableHighDutyCycleDirected
+                | AdvertisingType::ConnectableLowDutyCycleDirected
+        )
+    }
+
+    fn is_high_duty_cycle_directed(&self) -> bool {
+        self.parameters.r#type() == AdvertisingType::ConnectableHighDutyCycleDirected
+    }
+
+    fn is_scannable(&self) -> bool {
+        matches!(
+            self.parameters.r#type(),
+            AdvertisingType::ConnectableUndirected | AdvertisingType::ScannableUndirected
+        )
+    }
+
+    fn filters_scan_requests(&self) -> bool {
+        matches!(
+            self.parameters.filter_policy(),
+            AdvertisingFilterPolicy::ConnectionAllAndScanFilterAcceptList
+                | AdvertisingFilterPolicy::ScanFilterAcceptListAndConnectionFilterAcceptList
+        )
+    }
+
+    fn filters_connection_requests(&self) -> bool {
+        matches!(
+            self.parameters.filter_policy(),
+            AdvertisingFilterPolicy::ScanAllAndConnectionFilterAcceptList
+                | AdvertisingFilterPolicy::ScanFilterAcceptListAndConnectionFilterAcceptList
+        )
+    }
+
+    fn interval(&self) -> Duration {
+        if self.is_high_duty_cycle_directed() {
+            HIGH_DUTY_CYCLE_DIRECTED_ADVERTISING_INTERVAL
+        } else {
+            // Advertising intervals are expressed in units of 0.625 ms
+            Duration::from_micros(self.parameters.interval().min().value() as u64 * 625)
+        }
+    }
+
+    fn event_type(&self) -> LeAdvertisingReportEventType {
+        match self.parameters.r#type() {
+            AdvertisingType::ConnectableUndirected => {
+                LeAdvertisingReportEventType::ConnectableUndirected
+            }
+            AdvertisingType::ConnectableHighDutyCycleDirected
+            | AdvertisingType::ConnectableLowDutyCycleDirected => {
+                LeAdvertisingReportEventType::ConnectableDirected
+            }
+            AdvertisingType::ScannableUndirected => {
+                LeAdvertisingReportEventType::ScannableUndirected
+            }
+            _ => LeAdvertisingReportEventType::NonConnectableUndirected,
+        }
+    }
+
+    /// First advertising event happening at or after the given instant.
+    fn next_event(&self, from: Instant) -> Option<Instant> {
+        let start = self.enabled_at?;
+        if from <= start {
+            return Some(start);
+        }
+        let interval = self.interval().as_nanos().max(1);
+        let elapsed = (from - start).as_nanos();
+        let count = elapsed.div_ceil(interval);
+        Some(start + Duration::from_nanos((count * interval) as u64))
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Scanning {
+    pub(crate) parameters: ScanParameters,
+    pub(crate) enabled: bool,
+    pub(crate) filter_duplicates: bool,
+    /// Advertising events happening from this instant have not been processed yet.
+    pub(crate) next_check: Option<Instant>,
+    pub(crate) reported: Vec<DeviceAddress>,
+}
+
+impl Scanning {
+    fn is_active(&self) -> bool {
+        self.parameters.r#type() == ScanType::ActiveScanning
+    }
+
+    fn uses_filter_accept_list(&self) -> bool {
+        matches!(
+            self.parameters.filter_policy(),
+            ScanningFilterPolicy::BasicFiltered | ScanningFilterPolicy::ExtendedFiltered
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct ConnectionTiming {
+    pub(crate) interval: ConnectionInterval,
+    pub(crate) latency: Latency,
+    pub(crate) supervision_timeout: SupervisionTimeout,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Initiating {
+    pub(crate) parameters: ConnectionParameters,
+    pub(crate) started_at: Instant,
+}
+
+impl Initiating {
+    fn uses_filter_accept_list(&self) -> bool {
+        self.parameters.initiator_filter_policy() == InitiatorFilterPolicy::FilterAcceptListUsed
+    }
+
+    fn timing(&self) -> ConnectionTiming {
+        ConnectionTiming {
+            interval: self.parameters.connection_interval_range().min(),
+            latency: self.parameters.max_latency(),
+            supervision_timeout: self.parameters.supervision_timeout(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Connection {
+    pub(crate) handle: ConnectionHandle,
+    pub(crate) peer: DeviceId,
+    pub(crate) peer_handle: ConnectionHandle,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Faults {
+    pub(crate) command_errors: Vec<(CommandOpCode, ErrorCode)>,
+    pub(crate) ignored_commands: Vec<CommandOpCode>,
+    pub(crate) command_delay: Duration,
+}
+
+#[derive(Debug)]
+pub(crate) struct Device {
+    pub(crate) public_address: PublicDeviceAddress,
+    pub(crate) random_address: Option<RandomStaticDeviceAddress>,
+    pub(crate) event_mask: EventMask,
+    pub(crate) le_event_mask: LeEventMask,
+    pub(crate) advertising: Advertising,
+    pub(crate) scanning: Scanning,
+    pub(crate) initiating: Option<Initiating>,
+    pub(crate) connections: Vec<Connection>,
+    pub(crate) filter_accept_list: Vec<LeFilterAcceptListAddress>,
+    pub(crate) faults: Faults,
+    pub(crate) rssi: Rssi,
+    rng: u64,
+    next_connection_handle: u16,
+    to_host: mpsc::UnboundedSender<Vec<u8>>,
+}
+
+impl Device {
+    fn new(public_address: PublicDeviceAddress, to_host: mpsc::UnboundedSender<Vec<u8>>) -> Self {
+        let mut seed = [0u8; 8];
+        seed[..6].copy_from_slice(public_address.value());
+        Self {
+            public_address,
+            random_address: None,
+            event_mask: EventMask::default(),
+            le_event_mask: LeEventMask::default(),
+            advertising: Default::default(),
+            scanning: Default::default(),
+            initiating: None,
+            connections: Vec::new(),
+            filter_accept_list: Vec::new(),
+            faults: Default::default(),
+            // INVARIANT: The default RSSI is known to be valid.
+            rssi: Rssi::try_new(DEFAULT_RSSI).unwrap(),
+            rng: u64::from_le_bytes(seed) | 1,
+            next_connection_handle: 0,
+            to_host,
+        }
+    }
+
+    /// Address of the device for the given own address type.
+    ///
+    /// Resolvable private addresses are not generated, the identity address is used instead.
+    pub(crate) fn address(&self, own_address_type: OwnAddressType) -> DeviceAddress {
+        match (own_address_type, &self.random_address) {
+            (
+                OwnAddressType::RandomDeviceAddress
+                | OwnAddressType::GeneratedResolvablePrivateAddressFallbackRandom,
+                Some(address),
+            ) => address.clone().into(),
+            _ => self.public_address.clone().into(),
+        }
+    }
+
+    fn advertising_address(&self) -> DeviceAddress {
+        self.address(self.advertising.parameters.own_address_type())
+    }
+
+    fn is_in_filter_accept_list(&self, address: &DeviceAddress) -> bool {
+        self.filter_accept_list
+            .contains(&LeFilterAcceptListAddress::from(address.clone()))
+    }
+
+    pub(crate) fn random(&mut self) -> u64 {
+        // xorshift64
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 7;
+        self.rng ^= self.rng << 17;
+        self.rng
+    }
+
+    fn allocate_connection_handle(&mut self) -> ConnectionHandle {
+        loop {
+            // INVARIANT: The handles are allocated in the valid range.
+            let handle = ConnectionHandle::try_new(self.next_connection_handle).unwrap();
+            self.next_connection_handle = if self.next_connection_handle >= MAX_CONNECTION_HANDLE {
+                0
+            } else {
+                self.next_connection_handle + 1
+            };
+            if !self.connections.iter().any(|c| c.handle == handle) {
+                return handle;
+            }
+        }
+    }
+
+    pub(crate) fn connection(&self, handle: ConnectionHandle) -> Option<Connection> {
+        self.connections
+            .iter()
+            .find(|c| c.handle == handle)
+            .copied()
+    }
+
+    fn is_masked(&self, event: &Event) -> bool {
+        match event {
+            Event::DisconnectionComplete(_) => {
+                !self.event_mask.contains(EventMask::DISCONNECTION_COMPLETE)
+            }
+            Event::LeMeta(event) => {
+                let le_event = match event {
+                    LeMetaEvent::LeConnectionComplete(_) => LeEventMask::LE_CONNECTION_COMPLETE,
+                    LeMetaEvent::LeAdvertisingReport(_) => LeEventMask::LE_ADVERTISING_REPORT,
+                    LeMetaEvent::LeConnectionUpdateComplete(_) => {
+                        LeEventMask::LE_CONNECTION_UPDATE_COMPLETE
+                    }
+                    _ => return false,
+                };
+                !self.event_mask.contains(EventMask::LE_META)
+                    || !self.le_event_mask.contains(le_event)
+            }
+            _ => false,
+        }
+    }
+
+    pub(crate) fn send(&self, packet: Packet) {
+        if matches!(&packet, Packet::Event(event) if self.is_masked(event)) {
+            return;
+        }
+        let mut buffer = Buffer::<HCI_MAX_READ_BUFFER_SIZE>::default();
+        // INVARIANT: The buffer is big enough for any packet sent by the controller.
+        packet.encode(&mut buffer).unwrap();
+        self.send_raw(buffer.data().to_vec());
+    }
+
+    /// Send an already encoded packet, prefixed by its packet indicator, to the host.
+    pub(crate) fn send_raw(&self, packet: Vec<u8>) {
+        // The controller may have been dropped, in which case nobody is listening anymore.
+        let _ = self.to_host.send(packet);
+    }
+
+    pub(crate) fn send_event(&self, event: Event) {
+        self.send(Packet::Event(event));
+    }
+
+    pub(crate) fn reset(&mut self) {
+        self.random_address = None;
+        self.event_mask = EventMask::default();
+        self.le_event_mask = LeEventMask::default();
+        self.advertising = Default::default();
+        self.scanning = Default::default();
+        self.initiating = None;
+        self.filter_accept_list.clear();
+    }
+}
+
+pub(crate) struct AirState {
+    pub(crate) devices: Vec<Device>,
+    changed: watch::Sender<()>,
+}
+
+impl AirState {
+    /// Wake up the controllers so that they reconsider their pending timers.
+    pub(crate) fn notify_change(&self) {
+        self.changed.send_replace(());
+    }
+
+    pub(crate) fn send_event(&self, id: DeviceId, event: Event) {
+        self.devices[id].send_event(event);
+    }
+
+    fn is_advertising_visible(&self, advertiser: DeviceId, observer: DeviceId) -> bool {
+        if advertiser == observer {
+            return false;
+        }
+        let advertising = &self.devices[advertiser].advertising;
+        if advertising.enabled_at.is_none() {
+            return false;
+        }
+        let observer = &self.devices[observer];
+        if advertising.is_directed() {
+            let target = advertising.parameters.peer_address();
+            return *target == observer.address(OwnAddressType::PublicDeviceAddress)
+                || *target == observer.address(OwnAddressType::RandomDeviceAddress);
+        }
+        true
+    }
+
+    fn scannable_advertisers(&self, id: DeviceId) -> impl Iterator<Item = DeviceId> + '_ {
+        let scanning = &self.devices[id].scanning;
+        (0..self.devices.len()).filter(move |&advertiser| {
+            if !scanning.enabled || !self.is_advertising_visible(advertiser, id) {
+                return false;
+            }
+            let address = self.devices[advertiser].advertising_address();
+            if scanning.uses_filter_accept_list()
+                && !self.devices[id].is_in_filter_accept_list(&address)
+            {
+                return false;
+            }
+            !(scanning.filter_duplicates && scanning.reported.contains(&address))
+        })
+    }
+
+    fn connectable_advertisers(&self, id: DeviceId) -> impl Iterator<Item = DeviceId> + '_ {
+        let device = &self.devices[id];
+        (0..self.devices.len()).filter(move |&advertiser| {
+            let Some(initiating) = &device.initiating else {
+                return false;
+            };
+            if !self.is_advertising_visible(advertiser, id) {
+                return false;
+            }
+            let advertiser = &self.devices[advertiser];
+            if !advertiser.advertising.is_connectable() {
+                return false;
+            }
+            let address = advertiser.advertising_address();
+            let accepted = if initiating.uses_filter_accept_list() {
+                device.is_in_filter_accept_list(&address)
+            } else {
+                address == peer_address(initiating.parameters.peer_address())
+            };
+            let allowed_by_advertiser = !advertiser.advertising.filters_connection_requests()
+                || advertiser.is_in_filter_accept_list(
+                    &device.address(initiating.parameters.own_address_type()),
+                );
+            accepted && allowed_by_advertiser
+        })
+    }
+
+    /// Instant at which something needs to happen for the given device, if any.
+    pub(crate) fn next_deadline(&self, id: DeviceId) -> Option<Instant> {
+        let device = &self.devices[id];
+        let mut deadlines = Vec::new();
+        if device.advertising.is_high_duty_cycle_directed() {
+            if let Some(enabled_at) = device.advertising.enabled_at {
+                deadlines.push(enabled_at + HIGH_DUTY_CYCLE_DIRECTED_ADVERTISING_DURATION);
+            }
+        }
+        if let Some(initiating) = &device.initiating {
+            deadlines.extend(self.connectable_advertisers(id).filter_map(|advertiser| {
+                self.devices[advertiser]
+                    .advertising
+                    .next_event(initiating.started_at)
+            }));
+        }
+        if let Some(next_check) = device.scanning.next_check {
+            deadlines.extend(self.scannable_advertisers(id).filter_map(|advertiser| {
+                self.devices[advertiser].advertising.next_event(next_check)
+            }));
+        }
+        deadlines.into_iter().min()
+    }
+
+    /// Handle everything that is due for the given device.
+    pub(crate) fn process_timers(&mut self, id: DeviceId, now: Instant) {
+        self.process_advertising_timeout(id, now);
+        self.process_initiating(id, now);
+        self.process_scanning(id, now);
+    }
+
+    fn process_advertising_timeout(&mut self, id: DeviceId, now: Instant) {
+        let advertising = &mut self.devices[id].advertising;
+        if !advertising.is_high_duty_cycle_directed() {
+            return;
+        }
+        if let Some(enabled_at) = advertising.enabled_at {
+            if now >= enabled_at + HIGH_DUTY_CYCLE_DIRECTED_ADVERTISING_DURATION {
+                advertising.enabled_at = None;
+                self.send_event(
+                    id,
+                    le_connection_failure(ErrorCode::AdvertisingTimeout, Role::Peripheral),
+                );
+                self.notify_change();
+            }
+        }
+    }
+
+    fn process_initiating(&mut self, id: DeviceId, now: Instant) {
+        let Some(initiating) = self.devices[id].initiating.clone() else {
+            return;
+        };
+        let advertiser = self.connectable_advertisers(id).find(|&advertiser| {
+            self.devices[advertiser]
+                .advertising
+                .next_event(initiating.started_at)
+                .is_some_and(|event| event <= now)
+        });
+        if let Some(advertiser) = advertiser {
+            self.connect(id, advertiser, initiating);
+        }
+    }
+
+    fn process_scanning(&mut self, id: DeviceId, now: Instant) {
+        let Some(next_check) = self.devices[id].scanning.next_check else {
+            return;
+        };
+        let advertisers: Vec<DeviceId> = self
+            .scannable_advertisers(id)
+            .filter(|&advertiser| {
+                self.devices[advertiser]
+                    .advertising
+                    .next_event(next_check)
+                    .is_some_and(|event| event <= now)
+            })
+            .collect();
+        for advertiser in advertisers {
+            let scanner = &self.devices[id];
+            let advertising = &self.devices[advertiser].advertising;
+            let address = self.devices[advertiser].advertising_address();
+            let data: &[u8] = if advertising.is_directed() {
+                &[]
+            } else {
+                // Skip the length of the significant part of the advertising data
+                &advertising.data.data()[1..]
+            };
+            scanner.send_event(advertising_report(
+                advertising.event_type(),
+                &address,
+                data,
+                scanner.rssi,
+            ));
+            let scan_request_allowed = !advertising.filters_scan_requests()
+                || self.devices[advertiser].is_in_filter_accept_list(
+                    &scanner.address(scanner.scanning.parameters.own_address_type()),
+                );
+            if scanner.scanning.is_active() && advertising.is_scannable() && scan_request_allowed {
+                scanner.send_event(advertising_report(
+                    LeAdvertisingReportEventType::ScanResponse,
+                    &address,
+                    &advertising.scan_response_data.data()[1..],
+                    scanner.rssi,
+                ));
+            }
+            let scanning = &mut self.devices[id].scanning;
+            if scanning.filter_duplicates {
+                scanning.reported.push(address);
+            }
+        }
+        self.devices[id].scanning.next_check = Some(now + Duration::from_nanos(1));
+    }
+
+    fn connect(&mut self, central: DeviceId, peripheral: DeviceId, initiating: Initiating) {
+        let central_address =
+            self.devices[central].address(initiating.parameters.own_address_type());
+        let peripheral_address = self.devices[peripheral].advertising_address();
+        let central_handle = self.devices[central].allocate_connection_handle();
+        let peripheral_handle = self.devices[peripheral].allocate_connection_handle();
+
+        self.devices[central].initiating = None;
+        self.devices[central].connections.push(Connection {
+            handle: central_handle,
+            peer: peripheral,
+            peer_handle: peripheral_handle,
+        });
+        self.devices[peripheral].advertising.enabled_at = None;
+        self.devices[peripheral].connections.push(Connection {
+            handle: peripheral_handle,
+            peer: central,
+            peer_handle: central_handle,
+        });
+
+        let timing = initiating.timing();
+        let connection_complete = |handle, role, peer_address| {
+            Event::LeMeta(LeMetaEvent::LeConnectionComplete(
+                LeConnectionCompleteEvent::new(
+                    ErrorCode::Success,
+                    handle,
+                    role,
+                    peer_address,
+                    timing.interval,
+                    timing.latency,
+                    timing.supervision_timeout,
+                    CentralClockAccuracy::Ppm500,
+                ),
+            ))
+        };
+        self.send_event(
+            central,
+            connection_complete(central_handle, Role::Central, peripheral_address),
+        );
+        self.send_event(
+            peripheral,
+            connection_complete(peripheral_handle, Role::Peripheral, central_address),
+        );
+        self.notify_change();
+    }
+
+    /// Remove a connection on both sides, sending the Disconnection Complete events with the given
+    /// reasons when requested.
+    pub(crate) fn terminate_connection(
+        &mut self,
+        id: DeviceId,
+        connection: Connection,
+        local_reason: Option<ErrorCode>,
+        peer_reason: ErrorCode,
+    ) {
+        self.devices[id]
+            .connections
+            .retain(|c| c.handle != connection.handle);
+        self.devices[connection.peer]
+            .connections
+            .retain(|c| c.handle != connection.peer_handle);
+        if let Some(local_reason) = local_reason {
+            self.send_event(
+                id,
+                Event::DisconnectionComplete(DisconnectionCompleteEvent::new(
+                    ErrorCode::Success,
+                    connection.handle,
+                    local_reason,
+                )),
+            );
+        }
+        self.send_event(
+            connection.peer,
+            Event::DisconnectionComplete(DisconnectionCompleteEvent::new(
+                ErrorCode::Success,
+                connection.peer_handle,
+                peer_reason,
+            )),
+        );
+    }
+
+    pub(crate) fn forward_acl_data(&mut self, id: DeviceId, acl_data: &AclData) {
+        let Some(connection) = self.devices[id].connection(acl_data.handle()) else {
+            // Data for an unknown connection is silently discarded.
+            return;
+        };
+        let packet_boundary_flag = match acl_data.packet_boundary_flag() {
+            // The non-automatically-flushable start of a PDU is always sent to the host as an
+            // automatically-flushable one.
+            PacketBoundaryFlag::FirstNonAutomaticallyFlushablePacket => {
+                PacketBoundaryFlag::FirstAutomaticallyFlushablePacket
+            }
+            flag => flag,
+        };
+        // INVARIANT: The data comes from an ACL data packet of the same size.
+        let forwarded = AclData::try_new(
+            connection.peer_handle,
+            packet_boundary_flag,
+            acl_data.broadcast_flag(),
+            acl_data.data(),
+        )
+        .unwrap();
+        self.devices[connection.peer].send(Packet::AclData(forwarded));
+        // INVARIANT: A single handle always fits in the event.
+        let completed_packets =
+            NumberOfCompletedPacketsEvent::try_new(&[CompletedPackets::new(acl_data.handle(), 1)])
+                .unwrap();
+        self.send_event(id, Event::NumberOfCompletedPackets(completed_packets));
+    }
+
+    /// Reset the state of a device, its connections are lost from the point of view of the peers.
+    pub(crate) fn reset(&mut self, id: DeviceId) {
+        while let Some(connection) = self.devices[id].connections.first().copied() {
+            self.terminate_connection(id, connection, None, ErrorCode::ConnectionTimeout);
+        }
+        self.devices[id].reset();
+        self.notify_change();
+    }
+}
+
+fn advertising_report(
+    event_type: LeAdvertisingReportEventType,
+    address: &DeviceAddress,
+    data: &[u8],
+    rssi: Rssi,
+) -> Event {
+    // INVARIANT: The advertising data set by the host always fits in a report.
+    let report = LeAdvertisingReport::new(
+        event_type,
+        report_address(address),
+        LeAdvertisingReportData::try_from(data).unwrap(),
+        Some(rssi),
+    );
+    // INVARIANT: A single report always fits in the event.
+    let reports = LeAdvertisingReportList::try_from(&[report][..]).unwrap();
+    Event::LeMeta(LeMetaEvent::LeAdvertisingReport(reports))
+}
+
+/// Virtual radio medium shared by several [`VirtualController`]s.
+///
+/// All the controllers added to the same air can see each other advertising, can scan each
+/// other and can connect to each other. Time is given by the tokio runtime, so a paused clock
+/// can be used to run the simulation as fast as possible while keeping it deterministic.
+///
+/// The radio is ideal: advertising and scanning happen on all channels continuously, every
+/// advertising event is received by all scanners and a connection is established on the first
+/// connectable advertising event following the start of the initiation.
+#[derive(Clone)]
+pub struct VirtualAir {
+    state: Arc<Mutex<AirState>>,
+}
+
+impl VirtualAir {
+    /// Create an empty virtual air.
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(AirState {
+                devices: Vec::new(),
+                changed: watch::Sender::new(()),
+            })),
+        }
+    }
+
+    /// Add a controller with the given public device address.
+    pub fn add_controller(&self, public_address: PublicDeviceAddress) -> VirtualController {
+        let (to_host, from_air) = mpsc::unbounded_channel();
+        let mut state = self.lock();
+        let id = state.devices.len();
+        state.devices.push(Device::new(public_address, to_host));
+        // A controller signals that it is ready to receive commands with a NOP Command Complete.
+        state.send_event(
+            id,
+            Event::CommandComplete(CommandCompleteEvent::new(
+                1,
+                CommandOpCode::Nop,
+                ErrorCode::Success,
+                None::<EventParameter>,
+            )),
+        );
+        let changed = state.changed.subscribe();
+        drop(state);
+        VirtualController::new(self.clone(), id, from_air, changed)
+    }
+
+    pub(crate) fn lock(&self) -> MutexGuard<'_, AirState> {
+        self.state.lock().unwrap()
+    }
+
+    /// Lock the state, unless a panic happened while it was locked.
+    pub(crate) fn try_lock(&self) -> Option<MutexGuard<'_, AirState>> {
+        self.state.lock().ok()
+    }
+}
+
+impl Default for VirtualAir {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bletio_hci::{
+        AdvertisingChannelMap, AdvertisingIntervalRange, PublicDeviceAddress, RandomAddress,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::before_start(0, Some(100))]
+    #[case::at_start(100, Some(100))]
+    #[case::between_events(120, Some(150))]
+    #[case::at_event(150, Some(150))]
+    #[case::after_events(301, Some(350))]
+    fn test_advertising_next_event(#[case] from: u64, #[case] expected: Option<u64>) {
+        let origin = Instant::now();
+        let advertising = Advertising {
+            // 50 ms advertising interval
+            parameters: AdvertisingParameters::try_new(
+                AdvertisingIntervalRange::try_new(80, 80).unwrap(),
+                AdvertisingType::ConnectableUndirected,
+                OwnAddressType::PublicDeviceAddress,
+                DeviceAddress::default(),
+                AdvertisingChannelMap::default(),
+                AdvertisingFilterPolicy::default(),
+            )
+            .unwrap(),
+            enabled_at: Some(origin + Duration::from_millis(100)),
+            ..Default::default()
+        };
+        assert_eq!(
+            advertising.next_event(origin + Duration::from_millis(from)),
+            expected.map(|ms| origin + Duration::from_millis(ms))
+        );
+    }
+
+    #[test]
+    fn test_advertising_next_event_disabled() {
+        assert_eq!(Advertising::default().next_event(Instant::now()), None);
+    }
+
+    #[test]
+    fn test_device_address() {
+        let (to_host, _from_air) = mpsc::unbounded_channel();
+        let public_address = PublicDeviceAddress::new([1, 2, 3, 4, 5, 6]);
+        let mut device = Device::new(public_address.clone(), to_host);
+        let public: DeviceAddress = public_address.into();
+        assert_eq!(device.address(OwnAddressType::PublicDeviceAddress), public);
+        // No random address has been set, fall back to the public address
+        assert_eq!(device.address(OwnAddressType::RandomDeviceAddress), public);
+        let random_address = RandomStaticDeviceAddress::try_new([6, 5, 4, 3, 2, 0xC1]).unwrap();
+        device.random_address = Some(random_address.clone());
+        let random: DeviceAddress = RandomAddress::from(random_address).into();
+        assert_eq!(device.address(OwnAddressType::RandomDeviceAddress), random);
+        assert_eq!(
+            device.address(OwnAddressType::GeneratedResolvablePrivateAddressFallbackRandom),
+            random
+        );
+    }
+
+    #[rstest]
+    #[case::public(
+        ConnectionPeerAddress::PublicIdentity(PublicDeviceAddress::new([1, 2, 3, 4, 5, 6])),
+        PublicDeviceAddress::new([1, 2, 3, 4, 5, 6]).into()
+    )]
+    #[case::random(
+        ConnectionPeerAddress::RandomDevice(RandomAddress::try_from([6, 5, 4, 3, 2, 0xC1]).unwrap()),
+        RandomAddress::try_from([6, 5, 4, 3, 2, 0xC1]).unwrap().into()
+    )]
+    fn test_peer_address(#[case] address: ConnectionPeerAddress, #[case] expected: DeviceAddress) {
+        assert_eq!(peer_address(&address), expected);
+    }
+}
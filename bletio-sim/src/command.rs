@@ -0,0 +1,345 @@
+//! Handling of the HCI commands received by the virtual controller.
+
+use std::num::{NonZeroU16, NonZeroU8};
+
+use bitflags::Flags;
+use bletio_hci::{
+    AdvertisingEnable, BdAddrEventParameter, BufferSizeEventParameter, Command,
+    CommandCompleteEvent, CommandOpCode, CommandStatusEvent, ConnectionUpdateParameters, ErrorCode,
+    Event, EventParameter, FilterAcceptListSizeEventParameter, FilterDuplicates,
+    LeBufferSizeEventParameter, LeConnectionUpdateCompleteEvent, LeMetaEvent, OwnAddressType,
+    RandomNumberEventParameter, Role, ScanEnable, SupportedCommands,
+    SupportedCommandsEventParameter, SupportedFeatures, SupportedFeaturesEventParameter,
+    SupportedLeFeatures, SupportedLeFeaturesEventParameter, SupportedLeStates,
+    SupportedLeStatesEventParameter, TxPowerLevel, TxPowerLevelEventParameter,
+};
+use tokio::time::Instant;
+
+use crate::air::{
+    le_connection_failure, AirState, Connection, ConnectionTiming, DeviceId, Initiating,
+};
+
+const FILTER_ACCEPT_LIST_SIZE: usize = 8;
+const ACL_DATA_PACKET_LENGTH: u16 = 27;
+const TOTAL_NUM_ACL_DATA_PACKETS: u8 = 4;
+const SYNCHRONOUS_DATA_PACKET_LENGTH: u8 = 64;
+const SUPPORTED_LE_STATES: u64 = 0x0000_03FF_FFFF_FFFF;
+
+fn supported_commands() -> SupportedCommands {
+    SupportedCommands::READ_BUFFER_SIZE
+        | SupportedCommands::READ_BD_ADDR
+        | SupportedCommands::LE_SET_EVENT_MASK
+        | SupportedCommands::LE_READ_BUFFER_SIZE
+        | SupportedCommands::LE_READ_LOCAL_SUPPORTED_FEATURES_PAGE_0
+        | SupportedCommands::LE_SET_RANDOM_ADDRESS
+        | SupportedCommands::LE_SET_ADVERTISING_PARAMETERS
+        | SupportedCommands::LE_READ_ADVERTISING_PHYSICAL_CHANNEL_TX_POWER
+        | SupportedCommands::LE_SET_ADVERTISING_DATA
+        | SupportedCommands::LE_SET_SCAN_RESPONSE_DATA
+        | SupportedCommands::LE_SET_ADVERTISING_ENABLE
+        | SupportedCommands::LE_SET_SCAN_PARAMETERS
+        | SupportedCommands::LE_SET_SCAN_ENABLE
+        | SupportedCommands::LE_CREATE_CONNECTION
+        | SupportedCommands::LE_CREATE_CONNECTION_CANCEL
+        | SupportedCommands::LE_READ_FILTER_ACCEPT_LIST_SIZE
+        | SupportedCommands::LE_CLEAR_FILTER_ACCEPT_LIST
+        | SupportedCommands::LE_ADD_DEVICE_TO_FILTER_ACCEPT_LIST
+        | SupportedCommands::LE_REMOVE_DEVICE_FROM_FILTER_ACCEPT_LIST
+        | SupportedCommands::LE_CONNECTION_UPDATE
+        | SupportedCommands::LE_RAND
+        | SupportedCommands::LE_READ_SUPPORTED_STATES
+}
+
+/// Whether the command is answered by a Command Status event instead of a Command Complete event.
+fn has_command_status_response(opcode: CommandOpCode) -> bool {
+    matches!(
+        opcode,
+        CommandOpCode::Disconnect
+            | CommandOpCode::LeCreateConnection
+            | CommandOpCode::LeConnectionUpdate
+    )
+}
+
+/// What needs to be done once the command response has been sent.
+enum FollowUp {
+    None,
+    ConnectionUpdate(Connection, ConnectionTiming),
+    CreateConnectionCancelled,
+    Disconnect(Connection, ErrorCode),
+}
+
+fn connection_update_timing(parameters: &ConnectionUpdateParameters) -> ConnectionTiming {
+    ConnectionTiming {
+        interval: parameters.connection_interval_range().min(),
+        latency: parameters.max_latency(),
+        supervision_timeout: parameters.supervision_timeout(),
+    }
+}
+
+impl AirState {
+    /// Process a command received from the host.
+    ///
+    /// The command is `None` when its parameters could not be decoded.
+    pub(crate) fn process_command(
+        &mut self,
+        id: DeviceId,
+        opcode: CommandOpCode,
+        command: Option<&Command>,
+        now: Instant,
+    ) {
+        let faults = &mut self.devices[id].faults;
+        if let Some(index) = faults.ignored_commands.iter().position(|&o| o == opcode) {
+            faults.ignored_commands.remove(index);
+            return;
+        }
+        let result = match faults.command_errors.iter().position(|&(o, _)| o == opcode) {
+            Some(index) => Err(faults.command_errors.remove(index).1),
+            None => match command {
+                Some(command) => self.execute(id, command, now),
+                None => Err(ErrorCode::InvalidHciCommandParameters),
+            },
+        };
+        let (status, return_parameter, follow_up) = match result {
+            Ok((return_parameter, follow_up)) => (ErrorCode::Success, return_parameter, follow_up),
+            Err(status) => (status, None, FollowUp::None),
+        };
+
+        if has_command_status_response(opcode) {
+            self.send_event(
+                id,
+                Event::CommandStatus(CommandStatusEvent::new(status, 1, opcode)),
+            );
+        } else {
+            self.send_event(
+                id,
+                Event::CommandComplete(CommandCompleteEvent::new(
+                    1,
+                    opcode,
+                    status,
+                    return_parameter,
+                )),
+            );
+        }
+
+        match follow_up {
+            FollowUp::None => (),
+            FollowUp::ConnectionUpdate(connection, timing) => {
+                let connection_update_complete = |handle| {
+                    Event::LeMeta(LeMetaEvent::LeConnectionUpdateComplete(
+                        LeConnectionUpdateCompleteEvent::new(
+                            ErrorCode::Success,
+                            handle,
+                            timing.interval,
+                            timing.latency,
+                            timing.supervision_timeout,
+                        ),
+                    ))
+                };
+                self.send_event(id, connection_update_complete(connection.handle));
+                self.send_event(
+                    connection.peer,
+                    connection_update_complete(connection.peer_handle),
+                );
+            }
+            FollowUp::CreateConnectionCancelled => {
+                self.send_event(
+                    id,
+                    le_connection_failure(ErrorCode::UnknownConnectionIdentifier, Role::Central),
+                );
+            }
+            FollowUp::Disconnect(connection, reason) => {
+                self.terminate_connection(
+                    id,
+                    connection,
+                    Some(ErrorCode::ConnectionTerminatedByLocalHost),
+                    reason,
+                );
+            }
+        }
+        self.notify_change();
+    }
+
+    fn execute(
+        &mut self,
+        id: DeviceId,
+        command: &Command,
+        now: Instant,
+    ) -> Result<(Option<EventParameter>, FollowUp), ErrorCode> {
+        let device = &mut self.devices[id];
+        let return_parameter: EventParameter = match command {
+            Command::Disconnect(handle, reason) => {
+                let connection = device
+                    .connection(*handle)
+                    .ok_or(ErrorCode::UnknownConnectionIdentifier)?;
+                // INVARIANT: All the disconnection reasons are valid error codes.
+                let reason = ErrorCode::try_from(u8::from(*reason)).unwrap();
+                return Ok((None, FollowUp::Disconnect(connection, reason)));
+            }
+            Command::SetEventMask(event_mask) => {
+                device.event_mask = *event_mask;
+                return Ok((None, FollowUp::None));
+            }
+            Command::Reset => {
+                self.reset(id);
+                return Ok((None, FollowUp::None));
+            }
+            Command::ReadLocalSupportedCommands => {
+                SupportedCommandsEventParameter::new(supported_commands()).into()
+            }
+            Command::ReadLocalSupportedFeatures => {
+                SupportedFeaturesEventParameter::new(SupportedFeatures::LE_SUPPORTED_CONTROLLER)
+                    .into()
+            }
+            Command::ReadBufferSize => BufferSizeEventParameter::new(
+                // INVARIANT: The buffer sizes are not zero.
+                NonZeroU16::new(ACL_DATA_PACKET_LENGTH).unwrap(),
+                NonZeroU8::new(SYNCHRONOUS_DATA_PACKET_LENGTH).unwrap(),
+                NonZeroU16::new(TOTAL_NUM_ACL_DATA_PACKETS as u16).unwrap(),
+                0,
+            )
+            .into(),
+            Command::ReadBdAddr => BdAddrEventParameter::new(device.public_address.clone()).into(),
+            Command::LeSetEventMask(le_event_mask) => {
+                device.le_event_mask = *le_event_mask;
+                return Ok((None, FollowUp::None));
+            }
+            Command::LeReadBufferSize => {
+                LeBufferSizeEventParameter::new(ACL_DATA_PACKET_LENGTH, TOTAL_NUM_ACL_DATA_PACKETS)
+                    .into()
+            }
+            Command::LeReadLocalSupportedFeaturesPage0 => {
+                SupportedLeFeaturesEventParameter::new(SupportedLeFeatures::empty()).into()
+            }
+            Command::LeSetRandomAddress(address) => {
+                if device.advertising.enabled_at.is_some() || device.scanning.enabled {
+                    return Err(ErrorCode::CommandDisallowed);
+                }
+                device.random_address = Some(address.clone());
+                return Ok((None, FollowUp::None));
+            }
+            Command::LeSetAdvertisingParameters(parameters) => {
+                if device.advertising.enabled_at.is_some() {
+                    return Err(ErrorCode::CommandDisallowed);
+                }
+                device.advertising.parameters = parameters.clone();
+                return Ok((None, FollowUp::None));
+            }
+            Command::LeReadAdvertisingChannelTxPower => {
+                // INVARIANT: 0 dBm is a valid TX power level.
+                TxPowerLevelEventParameter::new(TxPowerLevel::try_new(0).unwrap()).into()
+            }
+            Command::LeSetAdvertisingData(data) => {
+                device.advertising.data = data.clone();
+                return Ok((None, FollowUp::None));
+            }
+            Command::LeSetScanResponseData(data) => {
+                device.advertising.scan_response_data = data.clone();
+                return Ok((None, FollowUp::None));
+            }
+            Command::LeSetAdvertisingEnable(enable) => {
+                let advertising = &mut device.advertising;
+                if *enable == AdvertisingEnable::Disabled {
+                    advertising.enabled_at = None;
+                } else if advertising.enabled_at.is_none() {
+                    if matches!(
+                        advertising.parameters.own_address_type(),
+                        OwnAddressType::RandomDeviceAddress
+                            | OwnAddressType::GeneratedResolvablePrivateAddressFallbackRandom
+                    ) && device.random_address.is_none()
+                    {
+                        return Err(ErrorCode::InvalidHciCommandParameters);
+                    }
+                    advertising.enabled_at = Some(now);
+                }
+                return Ok((None, FollowUp::None));
+            }
+            Command::LeSetScanParameters(parameters) => {
+                if device.scanning.enabled {
+                    return Err(ErrorCode::CommandDisallowed);
+                }
+                device.scanning.parameters = parameters.clone();
+                return Ok((None, FollowUp::None));
+            }
+            Command::LeSetScanEnable(enable, filter_duplicates) => {
+                let scanning = &mut device.scanning;
+                if *enable == ScanEnable::Disabled {
+                    scanning.enabled = false;
+                    scanning.next_check = None;
+                } else if !scanning.enabled {
+                    scanning.enabled = true;
+                    scanning.reported.clear();
+                    scanning.next_check = Some(now);
+                }
+                scanning.filter_duplicates = *filter_duplicates == FilterDuplicates::Enabled;
+                return Ok((None, FollowUp::None));
+            }
+            Command::LeCreateConnection(parameters) => {
+                if device.initiating.is_some() {
+                    return Err(ErrorCode::CommandDisallowed);
+                }
+                device.initiating = Some(Initiating {
+                    parameters: parameters.clone(),
+                    started_at: now,
+                });
+                return Ok((None, FollowUp::None));
+            }
+            Command::LeCreateConnectionCancel => {
+                if device.initiating.take().is_none() {
+                    return Err(ErrorCode::CommandDisallowed);
+                }
+                return Ok((None, FollowUp::CreateConnectionCancelled));
+            }
+            Command::LeReadFilterAcceptListSize => {
+                FilterAcceptListSizeEventParameter::new(FILTER_ACCEPT_LIST_SIZE).into()
+            }
+            Command::LeClearFilterAcceptList => {
+                device.filter_accept_list.clear();
+                return Ok((None, FollowUp::None));
+            }
+            Command::LeAddDeviceToFilterAcceptList(address) => {
+                if !device.filter_accept_list.contains(address) {
+                    if device.filter_accept_list.len() >= FILTER_ACCEPT_LIST_SIZE {
+                        return Err(ErrorCode::MemoryCapacityExceeded);
+                    }
+                    device.filter_accept_list.push(address.clone());
+                }
+                return Ok((None, FollowUp::None));
+            }
+            Command::LeRemoveDeviceFromFilterAcceptList(address) => {
+                device.filter_accept_list.retain(|a| a != address);
+                return Ok((None, FollowUp::None));
+            }
+            Command::LeConnectionUpdate(parameters) => {
+                let connection = device
+                    .connection(*parameters.connection_handle())
+                    .ok_or(ErrorCode::UnknownConnectionIdentifier)?;
+                return Ok((
+                    None,
+                    FollowUp::ConnectionUpdate(connection, connection_update_timing(parameters)),
+                ));
+            }
+            Command::LeRand => {
+                RandomNumberEventParameter::new(device.random().to_le_bytes()).into()
+            }
+            Command::LeReadSupportedStates => {
+                SupportedLeStatesEventParameter::new(SupportedLeStates::from(SUPPORTED_LE_STATES))
+                    .into()
+            }
+            _ => return Err(ErrorCode::UnknownHciCommand),
+        };
+        Ok((Some(return_parameter), FollowUp::None))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_supported_commands() {
+        let supported = supported_commands();
+        assert!(supported.contains(SupportedCommands::LE_SET_EVENT_MASK));
+        assert!(supported.contains(SupportedCommands::LE_READ_LOCAL_SUPPORTED_FEATURES_PAGE_0));
+        assert!(!supported.contains(SupportedCommands::LE_CREATE_BIG));
+    }
+}
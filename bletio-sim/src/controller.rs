@@ -0,0 +1,826 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use bletio_hci::{
+    CommandOpCode, ErrorCode, Event, HardwareErrorEvent, HciDriver, HciDriverError, Packet,
+    RandomStaticDeviceAddress, Rssi,
+};
+use tokio::sync::{mpsc, watch};
+use tokio::time::Instant;
+
+use crate::air::{DeviceId, VirtualAir};
+
+const COMMAND_PACKET: u8 = 0x01;
+
+/// Opcode of the command packet at the start of the buffer and the bytes following it, for a
+/// complete command packet whose parameters cannot be decoded.
+fn undecodable_command(buf: &[u8]) -> Option<(CommandOpCode, &[u8])> {
+    match buf {
+        [COMMAND_PACKET, opcode_low, opcode_high, len, rest @ ..]
+            if rest.len() >= *len as usize =>
+        {
+            let opcode = u16::from_le_bytes([*opcode_low, *opcode_high]);
+            Some((opcode.into(), &rest[*len as usize..]))
+        }
+        _ => None,
+    }
+}
+
+/// Virtual LE controller, to be given to a host as its [`HciDriver`].
+///
+/// It is created by [`VirtualAir::add_controller`]. Each read returns as many complete HCI packets
+/// as fit in the given buffer, each write needs to contain complete HCI packets prefixed by their
+/// packet indicator, as with the H4 transport.
+///
+/// Dropping the controller is like powering it off: its connections are lost for its peers.
+pub struct VirtualController {
+    air: VirtualAir,
+    id: DeviceId,
+    from_air: mpsc::UnboundedReceiver<Vec<u8>>,
+    changed: watch::Receiver<()>,
+    pending: VecDeque<Vec<u8>>,
+}
+
+impl VirtualController {
+    pub(crate) fn new(
+        air: VirtualAir,
+        id: DeviceId,
+        from_air: mpsc::UnboundedReceiver<Vec<u8>>,
+        changed: watch::Receiver<()>,
+    ) -> Self {
+        Self {
+            air,
+            id,
+            from_air,
+            changed,
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Get a handle to observe the controller and inject faults, that stays usable once the
+    /// controller has been given to a host.
+    pub fn handle(&self) -> ControllerHandle {
+        ControllerHandle {
+            air: self.air.clone(),
+            id: self.id,
+        }
+    }
+
+    fn fill(&mut self, buf: &mut [u8]) -> usize {
+        while let Ok(packet) = self.from_air.try_recv() {
+            self.pending.push_back(packet);
+        }
+        let mut len = 0;
+        while let Some(packet) = self.pending.front_mut() {
+            let available = buf.len() - len;
+            if packet.len() <= available {
+                buf[len..len + packet.len()].copy_from_slice(packet);
+                len += packet.len();
+                self.pending.pop_front();
+            } else {
+                if len == 0 {
+                    // The packet does not fit in an empty buffer, give it in several parts.
+                    buf.copy_from_slice(&packet[..available]);
+                    packet.drain(..available);
+                    len = available;
+                }
+                break;
+            }
+        }
+        len
+    }
+
+    fn process_packets(&mut self, mut buf: &[u8]) -> Result<(), HciDriverError> {
+        let mut state = self.air.lock();
+        let now = Instant::now();
+        // What is due happens before the packets are handled, e.g. a connection gets established
+        // before a command cancelling its creation.
+        state.process_timers(self.id, now);
+        while !buf.is_empty() {
+            let rest = match Packet::parse(buf) {
+                Ok((rest, Packet::Command(command))) => {
+                    state.process_command(self.id, command.opcode(), Some(&command), now);
+                    rest
+                }
+                Ok((rest, Packet::AclData(acl_data))) => {
+                    state.forward_acl_data(self.id, &acl_data);
+                    rest
+                }
+                Ok((rest, Packet::IsoData(_))) => {
+                    // Isochronous data is not simulated
+                    rest
+                }
+                Ok((_, Packet::Event(_))) => return Err(HciDriverError::WriteFailure),
+                Err(_) => {
+                    // A command with invalid parameters is answered with an error status, like
+                    // a real controller would do.
+                    let (opcode, rest) =
+                        undecodable_command(buf).ok_or(HciDriverError::WriteFailure)?;
+                    state.process_command(self.id, opcode, None, now);
+                    rest
+                }
+            };
+            buf = rest;
+        }
+        Ok(())
+    }
+}
+
+impl HciDriver for VirtualController {
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, HciDriverError> {
+        loop {
+            let deadline = {
+                let mut state = self.air.lock();
+                state.process_timers(self.id, Instant::now());
+                state.next_deadline(self.id)
+            };
+            let len = self.fill(buf);
+            if len > 0 {
+                return Ok(len);
+            }
+
+            tokio::select! {
+                packet = self.from_air.recv() => match packet {
+                    Some(packet) => self.pending.push_back(packet),
+                    None => return Err(HciDriverError::ReadFailure),
+                },
+                _ = self.changed.changed() => (),
+                _ = tokio::time::sleep_until(deadline.unwrap_or_else(Instant::now)), if deadline.is_some() => (),
+            }
+        }
+    }
+
+    async fn write(&mut self, buf: &[u8]) -> Result<usize, HciDriverError> {
+        let command_delay = self.air.lock().devices[self.id].faults.command_delay;
+        if !command_delay.is_zero() {
+            tokio::time::sleep(command_delay).await;
+        }
+        self.process_packets(buf)?;
+        Ok(buf.len())
+    }
+}
+
+impl Drop for VirtualController {
+    fn drop(&mut self) {
+        if let Some(mut state) = self.air.try_lock() {
+            state.reset(self.id);
+        }
+    }
+}
+
+/// Handle on a [`VirtualController`] to observe its state and to inject faults and timing.
+#[derive(Clone)]
+pub struct ControllerHandle {
+    air: VirtualAir,
+    id: DeviceId,
+}
+
+impl ControllerHandle {
+    /// Advertising data set by the host.
+    pub fn advertising_data(&self) -> Vec<u8> {
+        // Skip the length of the significant part of the advertising data
+        self.air.lock().devices[self.id].advertising.data.data()[1..].to_vec()
+    }
+
+    /// Number of connections the controller currently has.
+    pub fn connection_count(&self) -> usize {
+        self.air.lock().devices[self.id].connections.len()
+    }
+
+    /// Delay the handling of all the packets written by the host by the given duration.
+    ///
+    /// A delay longer than the HCI command timeout of the host makes its commands time out.
+    pub fn delay_commands(&self, delay: Duration) {
+        self.air.lock().devices[self.id].faults.command_delay = delay;
+    }
+
+    /// Make the next command with the given opcode fail with the given error code, without
+    /// executing it.
+    pub fn fail_next_command(&self, opcode: CommandOpCode, error: ErrorCode) {
+        self.air.lock().devices[self.id]
+            .faults
+            .command_errors
+            .push((opcode, error));
+    }
+
+    /// Silently drop the next command with the given opcode, no response is sent to the host.
+    pub fn ignore_next_command(&self, opcode: CommandOpCode) {
+        self.air.lock().devices[self.id]
+            .faults
+            .ignored_commands
+            .push(opcode);
+    }
+
+    /// Number of devices in the filter accept list of the controller.
+    pub fn filter_accept_list_len(&self) -> usize {
+        self.air.lock().devices[self.id].filter_accept_list.len()
+    }
+
+    /// Simulate a hardware failure of the controller.
+    ///
+    /// The controller loses its state and its connections, as after a reset, and sends a
+    /// Hardware Error event with the given hardware code to the host.
+    pub fn hardware_error(&self, hardware_code: u8) {
+        let mut state = self.air.lock();
+        state.reset(self.id);
+        state.send_event(
+            self.id,
+            Event::HardwareError(HardwareErrorEvent::new(hardware_code)),
+        );
+    }
+
+    /// Send a raw HCI packet, prefixed by its packet indicator, to the host.
+    pub fn inject_packet(&self, packet: &[u8]) {
+        let state = self.air.lock();
+        state.devices[self.id].send_raw(packet.to_vec());
+    }
+
+    /// Whether the controller is currently advertising.
+    pub fn is_advertising(&self) -> bool {
+        self.air.lock().devices[self.id]
+            .advertising
+            .enabled_at
+            .is_some()
+    }
+
+    /// Whether the controller is currently trying to establish a connection.
+    pub fn is_initiating(&self) -> bool {
+        self.air.lock().devices[self.id].initiating.is_some()
+    }
+
+    /// Whether the controller is currently scanning.
+    pub fn is_scanning(&self) -> bool {
+        self.air.lock().devices[self.id].scanning.enabled
+    }
+
+    /// Lose all the connections of the controller, as if the peers went out of range.
+    ///
+    /// Both sides get a Disconnection Complete event with the Connection Timeout reason.
+    pub fn lose_connections(&self) {
+        let mut state = self.air.lock();
+        while let Some(connection) = state.devices[self.id].connections.first().copied() {
+            state.terminate_connection(
+                self.id,
+                connection,
+                Some(ErrorCode::ConnectionTimeout),
+                ErrorCode::ConnectionTimeout,
+            );
+        }
+        state.notify_change();
+    }
+
+    /// Random address set by the host, if any.
+    pub fn random_address(&self) -> Option<RandomStaticDeviceAddress> {
+        self.air.lock().devices[self.id].random_address.clone()
+    }
+
+    /// Scan response data set by the host.
+    pub fn scan_response_data(&self) -> Vec<u8> {
+        // Skip the length of the significant part of the scan response data
+        self.air.lock().devices[self.id]
+            .advertising
+            .scan_response_data
+            .data()[1..]
+            .to_vec()
+    }
+
+    /// Set the RSSI of the advertising reports received by the controller.
+    pub fn set_rssi(&self, rssi: Rssi) {
+        self.air.lock().devices[self.id].rssi = rssi;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bletio_hci::{
+        connection_interval_range, supervision_timeout, AclData, AdvertisingData,
+        AdvertisingEnable, BigHandle, BroadcastFlag, Command, ConnectionEventLengthRange,
+        ConnectionHandle, ConnectionParameters, ConnectionPeerAddress, ConnectionUpdateParameters,
+        EventMask, FilterDuplicates, InitiatorFilterPolicy, Latency, LeEventMask, OwnAddressType,
+        PacketBoundaryFlag, PublicDeviceAddress, Reason, ScanEnable, ScanInterval, ScanParameters,
+        ScanType, ScanWindow, ScanningFilterPolicy, HCI_MAX_READ_BUFFER_SIZE,
+    };
+    use bletio_utils::{Buffer, BufferOps, EncodeToBuffer};
+    use rstest::rstest;
+
+    use super::*;
+
+    const ACL_DATA_PACKET: u8 = 0x02;
+
+    const ADDRESS_A: [u8; 6] = [0x01, 0x00, 0x00, 0x00, 0x00, 0xAA];
+    const ADDRESS_B: [u8; 6] = [0x02, 0x00, 0x00, 0x00, 0x00, 0xBB];
+
+    fn encode(packet: Packet) -> Vec<u8> {
+        let mut buffer = Buffer::<HCI_MAX_READ_BUFFER_SIZE>::default();
+        packet.encode(&mut buffer).unwrap();
+        buffer.data().to_vec()
+    }
+
+    fn command(command: Command) -> Vec<u8> {
+        encode(Packet::Command(command))
+    }
+
+    /// Command packet that cannot be built from a [`Command`], e.g. with invalid parameters.
+    fn raw_command(opcode: u16, parameters: &[u8]) -> Vec<u8> {
+        let mut packet = vec![COMMAND_PACKET];
+        packet.extend_from_slice(&opcode.to_le_bytes());
+        packet.push(parameters.len() as u8);
+        packet.extend_from_slice(parameters);
+        packet
+    }
+
+    fn advertising_data(data: &[u8]) -> AdvertisingData {
+        let mut advertising_data = AdvertisingData::default();
+        advertising_data
+            .fill(|buffer| buffer.copy_from_slice(data))
+            .unwrap();
+        advertising_data
+    }
+
+    fn connection_parameters(peer_address: [u8; 6]) -> ConnectionParameters {
+        ConnectionParameters::try_new(
+            ScanInterval::default(),
+            ScanWindow::default(),
+            InitiatorFilterPolicy::FilterAcceptListNotUsed,
+            ConnectionPeerAddress::PublicDevice(PublicDeviceAddress::new(peer_address)),
+            OwnAddressType::PublicDeviceAddress,
+            connection_interval_range!(0x18, 0x28),
+            Latency::default(),
+            supervision_timeout!(0x2A),
+            ConnectionEventLengthRange::default(),
+        )
+        .unwrap()
+    }
+
+    // The packets are split without being parsed, as the responses to the unknown commands
+    // cannot be decoded.
+    fn split_packets(mut buf: &[u8]) -> Vec<Vec<u8>> {
+        let mut packets = Vec::new();
+        while !buf.is_empty() {
+            let len = match buf[0] {
+                ACL_DATA_PACKET => 5 + u16::from_le_bytes([buf[3], buf[4]]) as usize,
+                _ => 3 + buf[2] as usize,
+            };
+            packets.push(buf[..len].to_vec());
+            buf = &buf[len..];
+        }
+        packets
+    }
+
+    async fn read_packets(controller: &mut VirtualController) -> Vec<Vec<u8>> {
+        let mut buf = [0u8; HCI_MAX_READ_BUFFER_SIZE];
+        let len = controller.read(&mut buf).await.unwrap();
+        split_packets(&buf[..len])
+    }
+
+    async fn write(controller: &mut VirtualController, packet: &[u8]) {
+        assert_eq!(controller.write(packet).await.unwrap(), packet.len());
+    }
+
+    async fn new_controller(air: &VirtualAir, address: [u8; 6]) -> VirtualController {
+        let mut controller = air.add_controller(PublicDeviceAddress::new(address));
+        assert_eq!(
+            read_packets(&mut controller).await,
+            [[0x04, 0x0E, 0x03, 0x01, 0x00, 0x00]]
+        );
+        // Enable the LE Meta event, like the host does during its setup
+        write(
+            &mut controller,
+            &command(Command::SetEventMask(
+                EventMask::default() | EventMask::LE_META,
+            )),
+        )
+        .await;
+        assert_eq!(
+            read_packets(&mut controller).await,
+            [[0x04, 0x0E, 0x04, 0x01, 0x01, 0x0C, 0x00]]
+        );
+        controller
+    }
+
+    async fn start_advertising(controller: &mut VirtualController) {
+        write(
+            controller,
+            &command(Command::LeSetAdvertisingData(advertising_data(&[
+                0x02, 0x01, 0x06,
+            ]))),
+        )
+        .await;
+        write(
+            controller,
+            &command(Command::LeSetScanResponseData(advertising_data(&[
+                0x02, 0x09, 0x41,
+            ]))),
+        )
+        .await;
+        write(
+            controller,
+            &command(Command::LeSetAdvertisingEnable(AdvertisingEnable::Enabled)),
+        )
+        .await;
+        assert_eq!(
+            read_packets(controller).await,
+            [
+                [0x04, 0x0E, 0x04, 0x01, 0x08, 0x20, 0x00],
+                [0x04, 0x0E, 0x04, 0x01, 0x09, 0x20, 0x00],
+                [0x04, 0x0E, 0x04, 0x01, 0x0A, 0x20, 0x00],
+            ]
+        );
+    }
+
+    async fn connect(central: &mut VirtualController, peripheral: &mut VirtualController) {
+        start_advertising(peripheral).await;
+        write(
+            central,
+            &command(Command::LeCreateConnection(connection_parameters(
+                ADDRESS_A,
+            ))),
+        )
+        .await;
+        assert_eq!(
+            read_packets(central).await,
+            [
+                vec![0x04, 0x0F, 0x04, 0x00, 0x01, 0x0D, 0x20],
+                vec![
+                    0x04, 0x3E, 0x13, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+                    0x00, 0xAA, 0x18, 0x00, 0x00, 0x00, 0x2A, 0x00, 0x00
+                ],
+            ]
+        );
+        assert_eq!(
+            read_packets(peripheral).await,
+            [[
+                0x04, 0x3E, 0x13, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00,
+                0xBB, 0x18, 0x00, 0x00, 0x00, 0x2A, 0x00, 0x00
+            ]]
+        );
+    }
+
+    #[rstest]
+    #[case::reset(command(Command::Reset), &[0x04, 0x0E, 0x04, 0x01, 0x03, 0x0C, 0x00])]
+    #[case::read_bd_addr(
+        command(Command::ReadBdAddr),
+        &[0x04, 0x0E, 0x0A, 0x01, 0x09, 0x10, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0xAA]
+    )]
+    #[case::le_read_buffer_size(
+        command(Command::LeReadBufferSize),
+        &[0x04, 0x0E, 0x07, 0x01, 0x02, 0x20, 0x00, 0x1B, 0x00, 0x04]
+    )]
+    #[case::read_local_supported_features(
+        command(Command::ReadLocalSupportedFeatures),
+        &[0x04, 0x0E, 0x0C, 0x01, 0x03, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00]
+    )]
+    #[case::unknown_command(
+        raw_command(0x2014, &[]),
+        &[0x04, 0x0E, 0x04, 0x01, 0x14, 0x20, 0x01]
+    )]
+    #[case::not_simulated_command(
+        command(Command::LeTerminateBig(
+            BigHandle::try_new(0x01).unwrap(),
+            Reason::RemoteUserTerminatedConnection
+        )),
+        &[0x04, 0x0E, 0x04, 0x01, 0x6A, 0x20, 0x01]
+    )]
+    #[case::invalid_parameters(
+        raw_command(0x200A, &[0x02]),
+        &[0x04, 0x0E, 0x04, 0x01, 0x0A, 0x20, 0x12]
+    )]
+    #[case::missing_parameters(
+        raw_command(0x2001, &[0xFF]),
+        &[0x04, 0x0E, 0x04, 0x01, 0x01, 0x20, 0x12]
+    )]
+    #[case::disconnect_unknown_connection(
+        command(Command::Disconnect(
+            ConnectionHandle::default(),
+            Reason::RemoteUserTerminatedConnection
+        )),
+        &[0x04, 0x0F, 0x04, 0x02, 0x01, 0x06, 0x04]
+    )]
+    #[case::create_connection_cancel_not_initiating(
+        command(Command::LeCreateConnectionCancel),
+        &[0x04, 0x0E, 0x04, 0x01, 0x0E, 0x20, 0x0C]
+    )]
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_command_response(#[case] command: Vec<u8>, #[case] expected: &[u8]) {
+        let air = VirtualAir::new();
+        let mut controller = new_controller(&air, ADDRESS_A).await;
+        write(&mut controller, &command).await;
+        assert_eq!(read_packets(&mut controller).await, [expected]);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_read_local_supported_commands() {
+        let air = VirtualAir::new();
+        let mut controller = new_controller(&air, ADDRESS_A).await;
+        write(
+            &mut controller,
+            &command(Command::ReadLocalSupportedCommands),
+        )
+        .await;
+        let packets = read_packets(&mut controller).await;
+        assert_eq!(packets[0].len(), 71);
+        // LE Set Event Mask, LE Read Buffer Size...
+        assert_eq!(packets[0][32], 0xF7);
+    }
+
+    #[rstest]
+    #[case::passive(ScanType::PassiveScanning, 1)]
+    #[case::active(ScanType::ActiveScanning, 2)]
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_scanning(#[case] scan_type: ScanType, #[case] expected_reports: usize) {
+        let air = VirtualAir::new();
+        let mut advertiser = new_controller(&air, ADDRESS_A).await;
+        let mut scanner = new_controller(&air, ADDRESS_B).await;
+        scanner.handle().set_rssi(Rssi::try_new(-60).unwrap());
+        start_advertising(&mut advertiser).await;
+        write(
+            &mut scanner,
+            &command(Command::LeSetScanParameters(
+                ScanParameters::try_new(
+                    scan_type,
+                    ScanInterval::default(),
+                    ScanWindow::default(),
+                    OwnAddressType::PublicDeviceAddress,
+                    ScanningFilterPolicy::BasicUnfiltered,
+                )
+                .unwrap(),
+            )),
+        )
+        .await;
+        write(
+            &mut scanner,
+            &command(Command::LeSetScanEnable(
+                ScanEnable::Enabled,
+                FilterDuplicates::Disabled,
+            )),
+        )
+        .await;
+        let packets = read_packets(&mut scanner).await;
+        assert_eq!(
+            packets[..2],
+            [
+                [0x04, 0x0E, 0x04, 0x01, 0x0B, 0x20, 0x00],
+                [0x04, 0x0E, 0x04, 0x01, 0x0C, 0x20, 0x00],
+            ]
+        );
+        let reports = &packets[2..];
+        assert_eq!(reports.len(), expected_reports);
+        assert_eq!(
+            reports[0],
+            [
+                0x04, 0x3E, 0x0F, 0x02, 0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0xAA, 0x03,
+                0x02, 0x01, 0x06, 0xC4
+            ]
+        );
+        if expected_reports == 2 {
+            assert_eq!(
+                reports[1],
+                [
+                    0x04, 0x3E, 0x0F, 0x02, 0x01, 0x04, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0xAA,
+                    0x03, 0x02, 0x09, 0x41, 0xC4
+                ]
+            );
+        }
+
+        // The next advertising event is 1.28 s later with the default advertising parameters
+        let start = Instant::now();
+        let packets = read_packets(&mut scanner).await;
+        assert_eq!(start.elapsed(), Duration::from_millis(1280));
+        assert_eq!(packets.len(), expected_reports);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_scanning_filter_duplicates() {
+        let air = VirtualAir::new();
+        let mut advertiser = new_controller(&air, ADDRESS_A).await;
+        let mut scanner = new_controller(&air, ADDRESS_B).await;
+        start_advertising(&mut advertiser).await;
+        write(
+            &mut scanner,
+            &command(Command::LeSetScanEnable(
+                ScanEnable::Enabled,
+                FilterDuplicates::Enabled,
+            )),
+        )
+        .await;
+        assert_eq!(read_packets(&mut scanner).await.len(), 2);
+        let read = tokio::time::timeout(Duration::from_secs(10), read_packets(&mut scanner));
+        assert!(read.await.is_err());
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_scanning_masked_reports() {
+        let air = VirtualAir::new();
+        let mut advertiser = new_controller(&air, ADDRESS_A).await;
+        let mut scanner = new_controller(&air, ADDRESS_B).await;
+        start_advertising(&mut advertiser).await;
+        write(
+            &mut scanner,
+            &command(Command::LeSetEventMask(LeEventMask::empty())),
+        )
+        .await;
+        write(
+            &mut scanner,
+            &command(Command::LeSetScanEnable(
+                ScanEnable::Enabled,
+                FilterDuplicates::Disabled,
+            )),
+        )
+        .await;
+        assert_eq!(read_packets(&mut scanner).await.len(), 2);
+        let read = tokio::time::timeout(Duration::from_secs(10), read_packets(&mut scanner));
+        assert!(read.await.is_err());
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_connection_with_acl_data_and_disconnection() {
+        let air = VirtualAir::new();
+        let mut peripheral = new_controller(&air, ADDRESS_A).await;
+        let mut central = new_controller(&air, ADDRESS_B).await;
+        connect(&mut central, &mut peripheral).await;
+        assert_eq!(central.handle().connection_count(), 1);
+        assert!(!peripheral.handle().is_advertising());
+
+        write(
+            &mut central,
+            &encode(Packet::AclData(
+                AclData::try_new(
+                    ConnectionHandle::default(),
+                    PacketBoundaryFlag::FirstNonAutomaticallyFlushablePacket,
+                    BroadcastFlag::PointToPoint,
+                    &[0xAA, 0xBB],
+                )
+                .unwrap(),
+            )),
+        )
+        .await;
+        assert_eq!(
+            read_packets(&mut central).await,
+            [[0x04, 0x13, 0x05, 0x01, 0x00, 0x00, 0x01, 0x00]]
+        );
+        assert_eq!(
+            read_packets(&mut peripheral).await,
+            [[0x02, 0x00, 0x20, 0x02, 0x00, 0xAA, 0xBB]]
+        );
+
+        write(
+            &mut peripheral,
+            &command(Command::LeConnectionUpdate(
+                ConnectionUpdateParameters::try_new(
+                    ConnectionHandle::default(),
+                    connection_interval_range!(0x18, 0x28),
+                    Latency::default(),
+                    supervision_timeout!(0x2A),
+                    ConnectionEventLengthRange::default(),
+                )
+                .unwrap(),
+            )),
+        )
+        .await;
+        assert_eq!(
+            read_packets(&mut peripheral).await,
+            [
+                vec![0x04, 0x0F, 0x04, 0x00, 0x01, 0x13, 0x20],
+                vec![0x04, 0x3E, 0x0A, 0x03, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x2A, 0x00],
+            ]
+        );
+        assert_eq!(
+            read_packets(&mut central).await,
+            [[0x04, 0x3E, 0x0A, 0x03, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x2A, 0x00]]
+        );
+
+        write(
+            &mut central,
+            &command(Command::Disconnect(
+                ConnectionHandle::default(),
+                Reason::RemoteUserTerminatedConnection,
+            )),
+        )
+        .await;
+        assert_eq!(
+            read_packets(&mut central).await,
+            [
+                [0x04, 0x0F, 0x04, 0x00, 0x01, 0x06, 0x04],
+                [0x04, 0x05, 0x04, 0x00, 0x00, 0x00, 0x16],
+            ]
+        );
+        assert_eq!(
+            read_packets(&mut peripheral).await,
+            [[0x04, 0x05, 0x04, 0x00, 0x00, 0x00, 0x13]]
+        );
+        assert_eq!(central.handle().connection_count(), 0);
+        assert_eq!(peripheral.handle().connection_count(), 0);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_create_connection_cancel() {
+        let air = VirtualAir::new();
+        let mut central = new_controller(&air, ADDRESS_B).await;
+        write(
+            &mut central,
+            &command(Command::LeCreateConnection(connection_parameters(
+                ADDRESS_A,
+            ))),
+        )
+        .await;
+        assert_eq!(read_packets(&mut central).await.len(), 1);
+        assert!(central.handle().is_initiating());
+
+        write(&mut central, &command(Command::LeCreateConnectionCancel)).await;
+        let packets = read_packets(&mut central).await;
+        assert_eq!(packets[0], [0x04, 0x0E, 0x04, 0x01, 0x0E, 0x20, 0x00]);
+        // LE Connection Complete with the Unknown Connection Identifier status
+        assert_eq!(packets[1][..5], [0x04, 0x3E, 0x13, 0x01, 0x02]);
+        assert!(!central.handle().is_initiating());
+    }
+
+    #[rstest]
+    #[case::lose_connections(false)]
+    #[case::drop_controller(true)]
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_link_loss(#[case] drop_peripheral: bool) {
+        let air = VirtualAir::new();
+        let mut peripheral = new_controller(&air, ADDRESS_A).await;
+        let mut central = new_controller(&air, ADDRESS_B).await;
+        connect(&mut central, &mut peripheral).await;
+        if drop_peripheral {
+            drop(peripheral);
+        } else {
+            peripheral.handle().lose_connections();
+            assert_eq!(
+                read_packets(&mut peripheral).await,
+                [[0x04, 0x05, 0x04, 0x00, 0x00, 0x00, 0x08]]
+            );
+        }
+        assert_eq!(
+            read_packets(&mut central).await,
+            [[0x04, 0x05, 0x04, 0x00, 0x00, 0x00, 0x08]]
+        );
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_hardware_error() {
+        let air = VirtualAir::new();
+        let mut peripheral = new_controller(&air, ADDRESS_A).await;
+        let mut central = new_controller(&air, ADDRESS_B).await;
+        connect(&mut central, &mut peripheral).await;
+        peripheral.handle().hardware_error(0x2A);
+        assert_eq!(
+            read_packets(&mut peripheral).await,
+            [[0x04, 0x10, 0x01, 0x2A]]
+        );
+        assert_eq!(
+            read_packets(&mut central).await,
+            [[0x04, 0x05, 0x04, 0x00, 0x00, 0x00, 0x08]]
+        );
+        assert_eq!(peripheral.handle().connection_count(), 0);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_fault_injection() {
+        let air = VirtualAir::new();
+        let mut controller = new_controller(&air, ADDRESS_A).await;
+        let handle = controller.handle();
+
+        handle.fail_next_command(CommandOpCode::Reset, ErrorCode::HardwareFailure);
+        write(&mut controller, &command(Command::Reset)).await;
+        assert_eq!(
+            read_packets(&mut controller).await,
+            [[0x04, 0x0E, 0x04, 0x01, 0x03, 0x0C, 0x03]]
+        );
+
+        handle.ignore_next_command(CommandOpCode::Reset);
+        write(&mut controller, &command(Command::Reset)).await;
+        let read = tokio::time::timeout(Duration::from_secs(10), read_packets(&mut controller));
+        assert!(read.await.is_err());
+
+        handle.delay_commands(Duration::from_millis(500));
+        let start = Instant::now();
+        write(&mut controller, &command(Command::Reset)).await;
+        assert_eq!(start.elapsed(), Duration::from_millis(500));
+        assert_eq!(
+            read_packets(&mut controller).await,
+            [[0x04, 0x0E, 0x04, 0x01, 0x03, 0x0C, 0x00]]
+        );
+
+        handle.inject_packet(&[0x04, 0x10, 0x01, 0x00]);
+        assert_eq!(
+            read_packets(&mut controller).await,
+            [[0x04, 0x10, 0x01, 0x00]]
+        );
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn test_write_invalid_packet() {
+        let air = VirtualAir::new();
+        let mut controller = new_controller(&air, ADDRESS_A).await;
+        assert_eq!(
+            controller.write(&[0x04, 0x0E, 0x00]).await,
+            Err(HciDriverError::WriteFailure)
+        );
+        assert_eq!(
+            controller.write(&[0x01, 0x03, 0x0C, 0x01]).await,
+            Err(HciDriverError::WriteFailure)
+        );
+    }
+}
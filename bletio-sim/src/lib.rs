@@ -0,0 +1,30 @@
+//! Virtual LE controller to test the bletio host without hardware.
+//!
+//! A [`VirtualAir`] simulates a radio medium shared by several [`VirtualController`]s. Each of
+//! them is an [`HciDriver`](bletio_hci::HciDriver) that can be given to a host and that answers
+//! the HCI commands like a real LE controller would:
+//!  - the commands of the host setup sequence (reset, reading of the supported commands, features,
+//!    states, buffer sizes and address, setting of the event masks)
+//!  - legacy advertising and scanning, generating LE Advertising Report events on the scanners
+//!  - connection establishment, update and termination, generating LE Connection Complete, LE
+//!    Connection Update Complete and Disconnection Complete events on both sides
+//!  - ACL data, forwarded from one side of a connection to the other
+//!
+//! A [`ControllerHandle`] gives access to the state of a controller once it has been given to a
+//! host and allows injecting command errors, delays and raw packets.
+//!
+//! ```no_run
+//! use bletio_hci::PublicDeviceAddress;
+//! use bletio_sim::VirtualAir;
+//!
+//! let air = VirtualAir::new();
+//! let peripheral = air.add_controller(PublicDeviceAddress::new([0x01, 0, 0, 0, 0, 0]));
+//! let central = air.add_controller(PublicDeviceAddress::new([0x02, 0, 0, 0, 0, 0]));
+//! ```
+
+mod air;
+mod command;
+mod controller;
+
+pub use air::VirtualAir;
+pub use controller::{ControllerHandle, VirtualController};
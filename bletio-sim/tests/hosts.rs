@@ -0,0 +1,2119 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use bletio_hci::{
+    CommandOpCode, ConnectionHandle, ConnectionPeerAddress, DisconnectionCompleteEvent,
+    Error as HciError, ErrorCode, FilterDuplicates, HciDriver, HciDriverError, IsoData,
+    LeAdvertisingReportEventType, LeConnectionCompleteEvent, PublicDeviceAddress, Reason, Role,
+    Rssi, ScanType, TimeSource, TokioTimeSource, HCI_MAX_READ_BUFFER_SIZE,
+};
+use bletio_host::advertising::{
+    AdvertisingData, AdvertisingFilterPolicy, AdvertisingIntervalRange, AdvertisingParameters,
+    ConnectableMode, DiscoverableMode, FullAdvertisingData, FullAdvertisingDataView,
+    LocalNameComplete, ScanFilter, ScanParameters, ScanResponseData, LIMITED_DISCOVERABLE_TIMEOUT,
+};
+use bletio_host::assigned_numbers::CompanyIdentifier;
+use bletio_host::discovery::{DiscoveryProcedure, NameDiscovery, DISCOVERY_SCAN_DURATION};
+use bletio_host::{
+    AdvertisingReport, BleDevice, BleHost, BleHostObserver, BleHostStateStandby, BleHostStates,
+    ConnectionParametersBuilder, ControllerResetCause, Error, HostChannel, HostEvent,
+    HostEventMask, HostEvents, HostHandle, RecoveryPolicy, Runner,
+};
+use bletio_sim::VirtualAir;
+
+const CENTRAL_ADDRESS: [u8; 6] = [0x01, 0x00, 0x00, 0x00, 0x00, 0x00];
+const PERIPHERAL_ADDRESS: [u8; 6] = [0x02, 0x00, 0x00, 0x00, 0x00, 0x00];
+
+#[derive(Debug, Default)]
+struct Journal {
+    connected: bool,
+    disconnected: bool,
+}
+
+#[derive(Debug, Default, Clone)]
+struct Peripheral {
+    journal: Arc<Mutex<Journal>>,
+}
+
+impl BleHostObserver for Peripheral {
+    async fn connection_complete<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >(
+        &self,
+        host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        event: &LeConnectionCompleteEvent,
+    ) -> BleHostStates<
+        'a,
+        H,
+        T,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
+    where
+        H: HciDriver,
+        T: TimeSource,
+    {
+        assert!(event.status().is_success());
+        assert!(matches!(host, BleHostStates::ConnectedPeripheral(_)));
+        self.journal.lock().unwrap().connected = true;
+        host
+    }
+
+    async fn disconnection_complete<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >(
+        &self,
+        host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        _event: &DisconnectionCompleteEvent,
+    ) -> BleHostStates<
+        'a,
+        H,
+        T,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
+    where
+        H: HciDriver,
+        T: TimeSource,
+    {
+        self.journal.lock().unwrap().disconnected = true;
+        host
+    }
+
+    async fn ready<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >(
+        &self,
+        host: BleHost<
+            'a,
+            H,
+            T,
+            BleHostStateStandby,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    ) -> BleHostStates<
+        'a,
+        H,
+        T,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
+    where
+        H: HciDriver,
+        T: TimeSource,
+    {
+        match host
+            .start_advertising(
+                &AdvertisingParameters::default(),
+                &FullAdvertisingData::default(),
+            )
+            .await
+        {
+            Ok(host) => BleHostStates::Advertising(host),
+            Err((err, _)) => panic!("failed to start advertising: {err:?}"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+struct Central {
+    journal: Arc<Mutex<Journal>>,
+}
+
+impl BleHostObserver for Central {
+    async fn advertising_report_received<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >(
+        &self,
+        host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        _event_type: LeAdvertisingReportEventType,
+        address: &ConnectionPeerAddress,
+        _rssi: Option<Rssi>,
+        _data: FullAdvertisingDataView<'_>,
+    ) -> BleHostStates<
+        'a,
+        H,
+        T,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
+    where
+        H: HciDriver,
+        T: TimeSource,
+    {
+        let BleHostStates::Scanning(host) = host else {
+            return host;
+        };
+        assert_eq!(address.value(), &PERIPHERAL_ADDRESS);
+        let host = match host.stop_scanning().await {
+            Ok(host) => host,
+            Err((err, _)) => panic!("failed to stop scanning: {err:?}"),
+        };
+        let parameters = ConnectionParametersBuilder::new()
+            .with_peer_address(address.clone())
+            .try_build()
+            .unwrap();
+        match host.connect(&parameters).await {
+            Ok(host) => BleHostStates::Initiating(host),
+            Err((err, _)) => panic!("failed to connect: {err:?}"),
+        }
+    }
+
+    async fn connection_complete<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >(
+        &self,
+        mut host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        event: &LeConnectionCompleteEvent,
+    ) -> BleHostStates<
+        'a,
+        H,
+        T,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
+    where
+        H: HciDriver,
+        T: TimeSource,
+    {
+        assert!(event.status().is_success());
+        self.journal.lock().unwrap().connected = true;
+        let BleHostStates::ConnectedCentral(central) = &mut host else {
+            panic!("the central is not connected");
+        };
+        central
+            .disconnect(
+                event.connection_handle(),
+                Reason::RemoteUserTerminatedConnection,
+            )
+            .await
+            .unwrap();
+        host
+    }
+
+    async fn disconnection_complete<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >(
+        &self,
+        host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        _event: &DisconnectionCompleteEvent,
+    ) -> BleHostStates<
+        'a,
+        H,
+        T,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
+    where
+        H: HciDriver,
+        T: TimeSource,
+    {
+        self.journal.lock().unwrap().disconnected = true;
+        host
+    }
+
+    async fn ready<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >(
+        &self,
+        host: BleHost<
+            'a,
+            H,
+            T,
+            BleHostStateStandby,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    ) -> BleHostStates<
+        'a,
+        H,
+        T,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
+    where
+        H: HciDriver,
+        T: TimeSource,
+    {
+        match host
+            .start_scanning(&ScanParameters::default(), FilterDuplicates::Enabled)
+            .await
+        {
+            Ok(host) => BleHostStates::Scanning(host),
+            Err((err, _)) => panic!("failed to start scanning: {err:?}"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+struct Scanner {
+    scan_type: ScanType,
+    reports: Arc<Mutex<Vec<(LeAdvertisingReportEventType, bool)>>>,
+}
+
+impl BleHostObserver for Scanner {
+    async fn advertising_report_received<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >(
+        &self,
+        host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        event_type: LeAdvertisingReportEventType,
+        _address: &ConnectionPeerAddress,
+        _rssi: Option<Rssi>,
+        data: FullAdvertisingDataView<'_>,
+    ) -> BleHostStates<
+        'a,
+        H,
+        T,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
+    where
+        H: HciDriver,
+        T: TimeSource,
+    {
+        self.reports
+            .lock()
+            .unwrap()
+            .push((event_type, data.scan_response_data().is_some()));
+        host
+    }
+
+    async fn ready<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >(
+        &self,
+        host: BleHost<
+            'a,
+            H,
+            T,
+            BleHostStateStandby,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    ) -> BleHostStates<
+        'a,
+        H,
+        T,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
+    where
+        H: HciDriver,
+        T: TimeSource,
+    {
+        let scan_params = ScanParameters::builder()
+            .with_type(self.scan_type)
+            .try_build()
+            .unwrap();
+        match host
+            .start_scanning(&scan_params, FilterDuplicates::Disabled)
+            .await
+        {
+            Ok(host) => BleHostStates::Scanning(host),
+            Err((err, _)) => panic!("failed to start scanning: {err:?}"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+struct Resilient {
+    resets: Arc<Mutex<Vec<ControllerResetCause>>>,
+}
+
+impl BleHostObserver for Resilient {
+    async fn controller_reset<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >(
+        &self,
+        host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        cause: ControllerResetCause,
+    ) -> BleHostStates<
+        'a,
+        H,
+        T,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
+    where
+        H: HciDriver,
+        T: TimeSource,
+    {
+        assert!(matches!(host, BleHostStates::Advertising(_)));
+        self.resets.lock().unwrap().push(cause);
+        host
+    }
+
+    async fn ready<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >(
+        &self,
+        mut host: BleHost<
+            'a,
+            H,
+            T,
+            BleHostStateStandby,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    ) -> BleHostStates<
+        'a,
+        H,
+        T,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
+    where
+        H: HciDriver,
+        T: TimeSource,
+    {
+        host.create_random_address().await.unwrap();
+        host.add_le_filter_accept_list_device(PublicDeviceAddress::new(CENTRAL_ADDRESS))
+            .await
+            .unwrap();
+        match host
+            .start_advertising(
+                &AdvertisingParameters::default(),
+                &FullAdvertisingData::default(),
+            )
+            .await
+        {
+            Ok(host) => BleHostStates::Advertising(host),
+            Err((err, _)) => panic!("failed to start advertising: {err:?}"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+struct IsoReceiver {
+    ready: Arc<Mutex<bool>>,
+    received: Arc<Mutex<Vec<IsoData>>>,
+}
+
+impl BleHostObserver for IsoReceiver {
+    async fn iso_data_received<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >(
+        &self,
+        host: BleHostStates<
+            'a,
+            H,
+            T,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+        iso_data: &IsoData,
+    ) -> BleHostStates<
+        'a,
+        H,
+        T,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
+    where
+        H: HciDriver,
+        T: TimeSource,
+    {
+        self.received.lock().unwrap().push(iso_data.clone());
+        host
+    }
+
+    async fn ready<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >(
+        &self,
+        host: BleHost<
+            'a,
+            H,
+            T,
+            BleHostStateStandby,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    ) -> BleHostStates<
+        'a,
+        H,
+        T,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
+    where
+        H: HciDriver,
+        T: TimeSource,
+    {
+        *self.ready.lock().unwrap() = true;
+        BleHostStates::Standby(host)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Discoverable {
+    mode: DiscoverableMode,
+    advertise_name: bool,
+}
+
+impl BleHostObserver for Discoverable {
+    async fn ready<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >(
+        &self,
+        host: BleHost<
+            'a,
+            H,
+            T,
+            BleHostStateStandby,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    ) -> BleHostStates<
+        'a,
+        H,
+        T,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
+    where
+        H: HciDriver,
+        T: TimeSource,
+    {
+        let scanresp_data = self.advertise_name.then(|| {
+            ScanResponseData::builder()
+                .with_local_name(LocalNameComplete::Complete)
+                .unwrap()
+                .build()
+        });
+        match host
+            .start_advertising_in_modes(
+                self.mode,
+                &ConnectableMode::UndirectedConnectable,
+                &AdvertisingParameters::default(),
+                &FullAdvertisingData::try_new(AdvertisingData::default(), scanresp_data).unwrap(),
+            )
+            .await
+        {
+            Ok(host) => BleHostStates::Advertising(host),
+            Err((err, _)) => panic!("failed to start advertising: {err:?}"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+struct Rotating {
+    rotated: Arc<Mutex<bool>>,
+}
+
+impl BleHostObserver for Rotating {
+    async fn ready<
+        'a,
+        H,
+        T,
+        const EVENT_QUEUE_SIZE: usize,
+        const READ_BUFFER_SIZE: usize,
+        const EVENT_LIST_SIZE: usize,
+        const ACL_DATA_SIZE: usize,
+        const ISO_DATA_QUEUE_SIZE: usize,
+    >(
+        &self,
+        host: BleHost<
+            'a,
+            H,
+            T,
+            BleHostStateStandby,
+            EVENT_QUEUE_SIZE,
+            READ_BUFFER_SIZE,
+            EVENT_LIST_SIZE,
+            ACL_DATA_SIZE,
+            ISO_DATA_QUEUE_SIZE,
+        >,
+    ) -> BleHostStates<
+        'a,
+        H,
+        T,
+        EVENT_QUEUE_SIZE,
+        READ_BUFFER_SIZE,
+        EVENT_LIST_SIZE,
+        ACL_DATA_SIZE,
+        ISO_DATA_QUEUE_SIZE,
+    >
+    where
+        H: HciDriver,
+        T: TimeSource,
+    {
+        let mut host = match host
+            .start_advertising(
+                &AdvertisingParameters::default(),
+                &FullAdvertisingData::default(),
+            )
+            .await
+        {
+            Ok(host) => host,
+            Err((err, _)) => panic!("failed to start advertising: {err:?}"),
+        };
+        host.rotate_advertising_data(Duration::from_secs(1), |index| {
+            (index < 3).then(|| {
+                let adv_data = AdvertisingData::builder()
+                    .with_manufacturer_specific_data(
+                        CompanyIdentifier::StMicroelectronics,
+                        &[index as u8 + 1],
+                    )
+                    .unwrap()
+                    .build();
+                FullAdvertisingData::try_new(adv_data, None).unwrap()
+            })
+        })
+        .await
+        .unwrap();
+        *self.rotated.lock().unwrap() = true;
+        BleHostStates::Advertising(host)
+    }
+}
+
+async fn wait_until(condition: impl Fn() -> bool) {
+    while !condition() {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn test_connection_and_disconnection_between_two_hosts() {
+    let air = VirtualAir::new();
+    let central_controller = air.add_controller(PublicDeviceAddress::new(CENTRAL_ADDRESS));
+    let peripheral_controller = air.add_controller(PublicDeviceAddress::new(PERIPHERAL_ADDRESS));
+    let central_handle = central_controller.handle();
+    let central = Central::default();
+    let peripheral = Peripheral::default();
+    let mut central_device = BleDevice::builder(central.clone(), TokioTimeSource).build();
+    let mut peripheral_device = BleDevice::builder(peripheral.clone(), TokioTimeSource).build();
+
+    let done = async {
+        while !(central.journal.lock().unwrap().disconnected
+            && peripheral.journal.lock().unwrap().disconnected)
+        {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    };
+
+    tokio::time::timeout(Duration::from_secs(10), async {
+        tokio::select! {
+            res = central_device.run(central_controller) => panic!("central stopped: {res:?}"),
+            res = peripheral_device.run(peripheral_controller) => panic!("peripheral stopped: {res:?}"),
+            _ = done => {}
+        }
+    })
+    .await
+    .expect("the hosts did not connect and disconnect in time");
+
+    assert!(central.journal.lock().unwrap().connected);
+    assert!(peripheral.journal.lock().unwrap().connected);
+    assert_eq!(central_handle.connection_count(), 0);
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn test_hosts_with_custom_hci_capacities() {
+    let air = VirtualAir::new();
+    let central_controller = air.add_controller(PublicDeviceAddress::new(CENTRAL_ADDRESS));
+    let peripheral_controller = air.add_controller(PublicDeviceAddress::new(PERIPHERAL_ADDRESS));
+    let central = Central::default();
+    let peripheral = Peripheral::default();
+    let mut central_device: BleDevice<_, _, 16, HCI_MAX_READ_BUFFER_SIZE, 8> =
+        BleDevice::builder(central.clone(), TokioTimeSource)
+            .with_hci_capacities()
+            .build();
+    let mut peripheral_device: BleDevice<_, _, 2, HCI_MAX_READ_BUFFER_SIZE, 1, 64, 1> =
+        BleDevice::builder(peripheral.clone(), TokioTimeSource)
+            .with_hci_capacities()
+            .build();
+
+    let done = async {
+        while !(central.journal.lock().unwrap().disconnected
+            && peripheral.journal.lock().unwrap().disconnected)
+        {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    };
+
+    tokio::time::timeout(Duration::from_secs(10), async {
+        tokio::select! {
+            res = Box::pin(central_device.run(central_controller)) => panic!("central stopped: {res:?}"),
+            res = Box::pin(peripheral_device.run(peripheral_controller)) => panic!("peripheral stopped: {res:?}"),
+            _ = done => {}
+        }
+    })
+    .await
+    .expect("the hosts did not connect and disconnect in time");
+
+    assert!(central.journal.lock().unwrap().connected);
+    assert!(peripheral.journal.lock().unwrap().connected);
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn test_host_setup_failure() {
+    let air = VirtualAir::new();
+    let controller = air.add_controller(PublicDeviceAddress::new(CENTRAL_ADDRESS));
+    controller
+        .handle()
+        .fail_next_command(CommandOpCode::Reset, ErrorCode::HardwareFailure);
+    let mut device = BleDevice::builder(Central::default(), TokioTimeSource).build();
+
+    assert_eq!(
+        device.run(controller).await,
+        Err(Error::Hci(HciError::ErrorCode(ErrorCode::HardwareFailure)))
+    );
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn test_host_setup_timeout() {
+    let air = VirtualAir::new();
+    let controller = air.add_controller(PublicDeviceAddress::new(CENTRAL_ADDRESS));
+    controller
+        .handle()
+        .ignore_next_command(CommandOpCode::Reset);
+    let mut device = BleDevice::builder(Central::default(), TokioTimeSource).build();
+
+    assert_eq!(
+        device.run(controller).await,
+        Err(Error::Hci(HciError::HciDriver(HciDriverError::Timeout)))
+    );
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn test_recovery_after_hardware_error() {
+    let air = VirtualAir::new();
+    let controller = air.add_controller(PublicDeviceAddress::new(PERIPHERAL_ADDRESS));
+    let handle = controller.handle();
+    let observer = Resilient::default();
+    let mut device = BleDevice::builder(observer.clone(), TokioTimeSource).build();
+
+    let scenario = async {
+        wait_until(|| handle.is_advertising()).await;
+        let random_address = handle.random_address();
+        assert!(random_address.is_some());
+
+        handle.hardware_error(0x2A);
+        wait_until(|| !observer.resets.lock().unwrap().is_empty()).await;
+        assert!(handle.is_advertising());
+        assert_eq!(handle.random_address(), random_address);
+        assert_eq!(handle.filter_accept_list_len(), 1);
+    };
+
+    tokio::time::timeout(Duration::from_secs(10), async {
+        tokio::select! {
+            res = device.run(controller) => panic!("device stopped: {res:?}"),
+            _ = scenario => {}
+        }
+    })
+    .await
+    .expect("the host did not recover in time");
+
+    assert_eq!(
+        *observer.resets.lock().unwrap(),
+        [ControllerResetCause::HardwareError(0x2A)]
+    );
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn test_hardware_error_without_recovery() {
+    let air = VirtualAir::new();
+    let controller = air.add_controller(PublicDeviceAddress::new(PERIPHERAL_ADDRESS));
+    let handle = controller.handle();
+    let observer = Resilient::default();
+    let mut device = BleDevice::builder(observer.clone(), TokioTimeSource)
+        .with_recovery_policy(RecoveryPolicy::Disabled)
+        .build();
+
+    let scenario = async {
+        wait_until(|| handle.is_advertising()).await;
+        handle.hardware_error(0x2A);
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    };
+
+    tokio::time::timeout(Duration::from_secs(10), async {
+        tokio::select! {
+            res = device.run(controller) => panic!("device stopped: {res:?}"),
+            _ = scenario => {}
+        }
+    })
+    .await
+    .unwrap();
+
+    assert!(!handle.is_advertising());
+    assert!(observer.resets.lock().unwrap().is_empty());
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn test_recovery_after_command_timeout_while_handling_events() {
+    let air = VirtualAir::new();
+    let central_controller = air.add_controller(PublicDeviceAddress::new(CENTRAL_ADDRESS));
+    let peripheral_controller = air.add_controller(PublicDeviceAddress::new(PERIPHERAL_ADDRESS));
+    let handle = peripheral_controller.handle();
+    let observer = Resilient::default();
+    let central = Central::default();
+    let mut peripheral_device = BleDevice::builder(observer.clone(), TokioTimeSource).build();
+    let mut central_device = BleDevice::builder(central.clone(), TokioTimeSource).build();
+
+    let central_run = async {
+        wait_until(|| handle.is_advertising()).await;
+        // The advertising is stopped by the peripheral once connected.
+        handle.ignore_next_command(CommandOpCode::LeSetAdvertisingEnable);
+        central_device.run(central_controller).await
+    };
+    let scenario = async {
+        wait_until(|| !observer.resets.lock().unwrap().is_empty()).await;
+        assert!(handle.is_advertising());
+    };
+
+    tokio::time::timeout(Duration::from_secs(10), async {
+        tokio::select! {
+            res = Box::pin(peripheral_device.run(peripheral_controller)) => panic!("peripheral stopped: {res:?}"),
+            res = Box::pin(central_run) => panic!("central stopped: {res:?}"),
+            _ = scenario => {}
+        }
+    })
+    .await
+    .expect("the host did not recover in time");
+
+    assert!(central.journal.lock().unwrap().connected);
+    assert_eq!(
+        *observer.resets.lock().unwrap(),
+        [ControllerResetCause::HciDriver(HciDriverError::Timeout)]
+    );
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn test_runner_driven_by_host_handle() {
+    fn assert_send<T: Send>(_: &T) {}
+
+    let air = VirtualAir::new();
+    let central_controller = air.add_controller(PublicDeviceAddress::new(CENTRAL_ADDRESS));
+    let peripheral_controller = air.add_controller(PublicDeviceAddress::new(PERIPHERAL_ADDRESS));
+    let central = Central::default();
+    let mut central_device = BleDevice::builder(central.clone(), TokioTimeSource).build();
+    let (handle, events, requests) = HostChannel::new().split();
+    let runner = Runner::new(
+        BleDevice::builder((), TokioTimeSource).build(),
+        peripheral_controller,
+        requests,
+    );
+    assert_send(&handle);
+    assert_send(&events);
+
+    let application = async {
+        let handle: HostHandle = handle.clone();
+        let mut events = events.with_mask(HostEventMask::CONNECTION);
+        assert_eq!(
+            handle.stop_scanning().await,
+            Err(Error::InvalidStateForRequest)
+        );
+        handle
+            .start_advertising(
+                &AdvertisingParameters::default(),
+                &FullAdvertisingData::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            handle
+                .start_advertising(
+                    &AdvertisingParameters::default(),
+                    &FullAdvertisingData::default(),
+                )
+                .await,
+            Err(Error::InvalidStateForRequest)
+        );
+
+        let Some(HostEvent::ConnectionComplete(event)) = events.next().await else {
+            panic!("the peripheral did not get connected");
+        };
+        assert!(event.status().is_success());
+        assert_eq!(event.role(), Role::Peripheral);
+        assert_eq!(
+            events.next().await.map(|event| event.kind()),
+            Some(HostEventMask::DISCONNECTION_COMPLETE)
+        );
+        events
+    };
+
+    let events = tokio::time::timeout(Duration::from_secs(10), async {
+        tokio::select! {
+            res = Box::pin(central_device.run(central_controller)) => panic!("central stopped: {res:?}"),
+            res = Box::pin(runner.run()) => panic!("runner stopped: {res:?}"),
+            events = application => events,
+        }
+    })
+    .await
+    .expect("the hosts did not connect and disconnect in time");
+
+    assert!(central.journal.lock().unwrap().connected);
+    assert_eq!(handle.stop_advertising().await, Err(Error::RunnerStopped));
+    let mut events = events.with_mask(HostEventMask::all());
+    assert_eq!(events.next().await, None);
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn test_late_scan_response_paired_with_advertisement() {
+    const SCANNABLE_ADVERTISEMENT: [u8; 18] =
+        [4, 62, 15, 2, 1, 2, 1, 1, 2, 3, 4, 5, 198, 3, 2, 1, 6, 196];
+    const SCAN_RESPONSE: [u8; 21] = [
+        4, 62, 18, 2, 1, 4, 1, 1, 2, 3, 4, 5, 198, 6, 5, 9, 98, 108, 101, 116, 190,
+    ];
+
+    let air = VirtualAir::new();
+    let controller = air.add_controller(PublicDeviceAddress::new(CENTRAL_ADDRESS));
+    let handle = controller.handle();
+    let observer = Scanner {
+        scan_type: ScanType::ActiveScanning,
+        ..Default::default()
+    };
+    let mut device = BleDevice::builder(observer.clone(), TokioTimeSource).build();
+
+    let scenario = async {
+        wait_until(|| handle.is_scanning()).await;
+        handle.inject_packet(&SCANNABLE_ADVERTISEMENT);
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(observer.reports.lock().unwrap().is_empty());
+        handle.inject_packet(&SCAN_RESPONSE);
+        wait_until(|| !observer.reports.lock().unwrap().is_empty()).await;
+
+        handle.inject_packet(&SCANNABLE_ADVERTISEMENT);
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    };
+
+    tokio::time::timeout(Duration::from_secs(10), async {
+        tokio::select! {
+            res = device.run(controller) => panic!("device stopped: {res:?}"),
+            _ = scenario => {}
+        }
+    })
+    .await
+    .unwrap();
+
+    assert_eq!(
+        *observer.reports.lock().unwrap(),
+        [
+            (LeAdvertisingReportEventType::ScannableUndirected, true),
+            (LeAdvertisingReportEventType::ScannableUndirected, false),
+        ]
+    );
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn test_fast_advertiser_without_scan_response() {
+    const ACTIVE_SCANNER_ADDRESS: [u8; 6] = [0x03, 0x00, 0x00, 0x00, 0x00, 0x00];
+
+    let air = VirtualAir::new();
+    let advertiser_controller = air.add_controller(PublicDeviceAddress::new(PERIPHERAL_ADDRESS));
+    let passive_controller = air.add_controller(PublicDeviceAddress::new(CENTRAL_ADDRESS));
+    let active_controller = air.add_controller(PublicDeviceAddress::new(ACTIVE_SCANNER_ADDRESS));
+    let passive = Scanner::default();
+    let active = Scanner {
+        scan_type: ScanType::ActiveScanning,
+        ..Default::default()
+    };
+    let mut passive_device = BleDevice::builder(passive.clone(), TokioTimeSource).build();
+    let mut active_device = BleDevice::builder(active.clone(), TokioTimeSource).build();
+    let (handle, _events, requests) = HostChannel::new().split();
+    let runner = Runner::new(
+        BleDevice::builder((), TokioTimeSource).build(),
+        advertiser_controller,
+        requests,
+    );
+    // Advertising every 20 ms, the scan requests of the devices that are not in the empty filter
+    // accept list being ignored.
+    let adv_params = AdvertisingParameters::builder()
+        .with_interval(AdvertisingIntervalRange::try_new(0x0020, 0x0020).unwrap())
+        .with_filter_policy(AdvertisingFilterPolicy::ConnectionAllAndScanFilterAcceptList)
+        .try_build()
+        .unwrap();
+
+    let scenario = async {
+        handle
+            .start_advertising(&adv_params, &FullAdvertisingData::default())
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!passive.reports.lock().unwrap().is_empty());
+        tokio::time::sleep(Duration::from_millis(950)).await;
+    };
+
+    tokio::time::timeout(Duration::from_secs(10), async {
+        tokio::select! {
+            res = Box::pin(passive_device.run(passive_controller)) => panic!("passive scanner stopped: {res:?}"),
+            res = Box::pin(active_device.run(active_controller)) => panic!("active scanner stopped: {res:?}"),
+            res = Box::pin(runner.run()) => panic!("advertiser stopped: {res:?}"),
+            _ = scenario => {}
+        }
+    })
+    .await
+    .unwrap();
+
+    // Every advertisement is reported without waiting when scanning passively.
+    let passive_reports = passive.reports.lock().unwrap();
+    assert!(passive_reports.len() >= 45, "{}", passive_reports.len());
+    // The advertisements waiting for their lost scan response are still reported once the
+    // scan response window has expired.
+    let active_reports = active.reports.lock().unwrap();
+    assert!(active_reports.len() >= 8, "{}", active_reports.len());
+    for reports in [&*passive_reports, &*active_reports] {
+        assert!(reports
+            .iter()
+            .all(|report| *report == (LeAdvertisingReportEventType::ConnectableUndirected, false)));
+    }
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn test_masked_events_do_not_fill_the_event_stream() {
+    let air = VirtualAir::new();
+    let central_controller = air.add_controller(PublicDeviceAddress::new(CENTRAL_ADDRESS));
+    let peripheral_controller = air.add_controller(PublicDeviceAddress::new(PERIPHERAL_ADDRESS));
+    let (central_handle, central_events, central_requests) = HostChannel::new().split();
+    let (peripheral_handle, _peripheral_events, peripheral_requests) = HostChannel::new().split();
+    let central_runner = Runner::new(
+        BleDevice::builder((), TokioTimeSource).build(),
+        central_controller,
+        central_requests,
+    );
+    let peripheral_runner = Runner::new(
+        BleDevice::builder((), TokioTimeSource).build(),
+        peripheral_controller,
+        peripheral_requests,
+    );
+    let adv_params = AdvertisingParameters::builder()
+        .with_interval(AdvertisingIntervalRange::try_new(0x0020, 0x0020).unwrap())
+        .try_build()
+        .unwrap();
+    let connection_parameters = ConnectionParametersBuilder::new().try_build().unwrap();
+
+    let application = async {
+        let mut events = central_events.with_mask(HostEventMask::CONNECTION_COMPLETE);
+        peripheral_handle
+            .start_advertising(&adv_params, &FullAdvertisingData::default())
+            .await
+            .unwrap();
+        // Scan long enough to receive many more advertising reports than the stream can hold,
+        // without consuming the stream.
+        central_handle
+            .start_scanning(&ScanParameters::default(), FilterDuplicates::Disabled)
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        central_handle.stop_scanning().await.unwrap();
+
+        central_handle
+            .connect_direct(
+                &ConnectionPeerAddress::PublicDevice(PublicDeviceAddress::new(PERIPHERAL_ADDRESS)),
+                &connection_parameters,
+                Duration::from_secs(5),
+            )
+            .await
+            .unwrap();
+        let Some(HostEvent::ConnectionComplete(event)) = events.next().await else {
+            panic!("the connection has not been reported");
+        };
+        assert!(event.status().is_success());
+    };
+
+    tokio::time::timeout(Duration::from_secs(10), async {
+        tokio::select! {
+            res = Box::pin(central_runner.run()) => panic!("central stopped: {res:?}"),
+            res = Box::pin(peripheral_runner.run()) => panic!("peripheral stopped: {res:?}"),
+            _ = application => {},
+        }
+    })
+    .await
+    .expect("the connection was not reported in time");
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn test_advertising_data_updated_while_advertising() {
+    fn counter_data(counter: u8) -> AdvertisingData {
+        AdvertisingData::builder()
+            .with_manufacturer_specific_data(CompanyIdentifier::StMicroelectronics, &[counter])
+            .unwrap()
+            .build()
+    }
+
+    let air = VirtualAir::new();
+    let controller = air.add_controller(PublicDeviceAddress::new(PERIPHERAL_ADDRESS));
+    let controller_handle = controller.handle();
+    let (handle, _events, requests) = HostChannel::new().split();
+    let runner = Runner::new(
+        BleDevice::builder((), TokioTimeSource).build(),
+        controller,
+        requests,
+    );
+
+    let application = async {
+        assert_eq!(
+            handle.update_advertising_data(&counter_data(0)).await,
+            Err(Error::InvalidStateForRequest)
+        );
+        handle
+            .start_advertising(
+                &AdvertisingParameters::default(),
+                &FullAdvertisingData::default(),
+            )
+            .await
+            .unwrap();
+
+        handle
+            .update_advertising_data(&counter_data(1))
+            .await
+            .unwrap();
+        assert!(controller_handle.is_advertising());
+        assert_eq!(
+            controller_handle.advertising_data(),
+            [0x04, 0xFF, 0x30, 0x00, 0x01]
+        );
+
+        let scanresp_data = ScanResponseData::builder()
+            .with_local_name(LocalNameComplete::Complete)
+            .unwrap()
+            .build();
+        handle
+            .update_scan_response_data(&scanresp_data)
+            .await
+            .unwrap();
+        assert_eq!(
+            controller_handle.scan_response_data(),
+            [0x07, 0x09, b'b', b'l', b'e', b't', b'i', b'o']
+        );
+        assert_eq!(
+            controller_handle.advertising_data(),
+            [0x04, 0xFF, 0x30, 0x00, 0x01]
+        );
+
+        let time_source = TokioTimeSource;
+        let mut advertised = Vec::new();
+        handle
+            .rotate_advertising_data(&time_source, Duration::from_secs(1), |index| {
+                advertised.push(controller_handle.advertising_data());
+                (index < 3).then(|| {
+                    FullAdvertisingData::try_new(counter_data(index as u8 + 2), None).unwrap()
+                })
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            advertised,
+            [
+                vec![0x04, 0xFF, 0x30, 0x00, 0x01],
+                vec![0x04, 0xFF, 0x30, 0x00, 0x02],
+                vec![0x04, 0xFF, 0x30, 0x00, 0x03],
+                vec![0x04, 0xFF, 0x30, 0x00, 0x04],
+            ]
+        );
+        assert!(controller_handle.scan_response_data().is_empty());
+        assert!(controller_handle.is_advertising());
+    };
+
+    tokio::time::timeout(Duration::from_secs(10), async {
+        tokio::select! {
+            res = runner.run() => panic!("runner stopped: {res:?}"),
+            _ = application => {}
+        }
+    })
+    .await
+    .expect("the advertising data was not updated in time");
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn test_advertising_data_rotated_by_the_host() {
+    let air = VirtualAir::new();
+    let controller = air.add_controller(PublicDeviceAddress::new(PERIPHERAL_ADDRESS));
+    let controller_handle = controller.handle();
+    let observer = Rotating::default();
+    let mut device = BleDevice::builder(observer.clone(), TokioTimeSource).build();
+
+    let scenario = async {
+        let start = tokio::time::Instant::now();
+        wait_until(|| *observer.rotated.lock().unwrap()).await;
+        assert!(start.elapsed() >= Duration::from_secs(3));
+        assert_eq!(
+            controller_handle.advertising_data(),
+            [0x04, 0xFF, 0x30, 0x00, 0x03]
+        );
+        assert!(controller_handle.is_advertising());
+    };
+
+    tokio::time::timeout(Duration::from_secs(10), async {
+        tokio::select! {
+            res = device.run(controller) => panic!("device stopped: {res:?}"),
+            _ = scenario => {}
+        }
+    })
+    .await
+    .expect("the advertising data was not rotated in time");
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn test_limited_discoverable_mode_timeout() {
+    let air = VirtualAir::new();
+    let controller = air.add_controller(PublicDeviceAddress::new(PERIPHERAL_ADDRESS));
+    let controller_handle = controller.handle();
+    let (handle, events, requests) = HostChannel::new().split();
+    let runner = Runner::new(
+        BleDevice::builder((), TokioTimeSource).build(),
+        controller,
+        requests,
+    );
+
+    let application = async {
+        let mut events = events.with_mask(HostEventMask::LIMITED_DISCOVERABLE_TIMEOUT);
+        handle
+            .start_advertising_in_modes(
+                DiscoverableMode::LimitedDiscoverable,
+                &ConnectableMode::UndirectedConnectable,
+                &AdvertisingParameters::default(),
+                &FullAdvertisingData::default(),
+            )
+            .await
+            .unwrap();
+        assert!(controller_handle.is_advertising());
+        assert_eq!(controller_handle.advertising_data(), [0x02, 0x01, 0x05]);
+        handle
+            .update_advertising_data(
+                &AdvertisingData::builder()
+                    .with_tx_power_level()
+                    .unwrap()
+                    .build(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            controller_handle.advertising_data(),
+            [0x02, 0x01, 0x05, 0x02, 0x0A, 0x00]
+        );
+
+        tokio::time::sleep(LIMITED_DISCOVERABLE_TIMEOUT - Duration::from_secs(1)).await;
+        assert!(controller_handle.is_advertising());
+        assert_eq!(
+            events.next().await,
+            Some(HostEvent::LimitedDiscoverableTimeout)
+        );
+        assert!(!controller_handle.is_advertising());
+        assert_eq!(
+            handle.stop_advertising().await,
+            Err(Error::InvalidStateForRequest)
+        );
+    };
+
+    tokio::time::timeout(LIMITED_DISCOVERABLE_TIMEOUT * 2, async {
+        tokio::select! {
+            res = runner.run() => panic!("runner stopped: {res:?}"),
+            _ = application => {}
+        }
+    })
+    .await
+    .expect("the limited discoverable mode did not time out");
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn test_discovery_procedures() {
+    fn addresses(devices: &[AdvertisingReport]) -> Vec<[u8; 6]> {
+        devices
+            .iter()
+            .map(|device| *device.address().value())
+            .collect()
+    }
+
+    const LIMITED_ADDRESS: [u8; 6] = [0x03, 0x00, 0x00, 0x00, 0x00, 0x00];
+    const HIDDEN_ADDRESS: [u8; 6] = [0x04, 0x00, 0x00, 0x00, 0x00, 0x00];
+    const UNNAMED_ADDRESS: [u8; 6] = [0x05, 0x00, 0x00, 0x00, 0x00, 0x00];
+
+    let air = VirtualAir::new();
+    let central_controller = air.add_controller(PublicDeviceAddress::new(CENTRAL_ADDRESS));
+    let peripheral = |address, mode, name: Option<&'static str>| {
+        let controller = air.add_controller(PublicDeviceAddress::new(address));
+        let observer = Discoverable {
+            mode,
+            advertise_name: name.is_some(),
+        };
+        let device = BleDevice::builder(observer, TokioTimeSource)
+            .with_local_name(name.unwrap_or_default())
+            .build();
+        (device, controller)
+    };
+    let (mut general_device, general_controller) = peripheral(
+        PERIPHERAL_ADDRESS,
+        DiscoverableMode::GeneralDiscoverable,
+        Some("general"),
+    );
+    let (mut limited_device, limited_controller) = peripheral(
+        LIMITED_ADDRESS,
+        DiscoverableMode::LimitedDiscoverable,
+        Some("limited"),
+    );
+    let (mut hidden_device, hidden_controller) = peripheral(
+        HIDDEN_ADDRESS,
+        DiscoverableMode::NonDiscoverable,
+        Some("hidden"),
+    );
+    let (mut unnamed_device, unnamed_controller) =
+        peripheral(UNNAMED_ADDRESS, DiscoverableMode::GeneralDiscoverable, None);
+    let (handle, mut events, requests) = HostChannel::new().split();
+    let runner = Runner::new(
+        BleDevice::builder((), TokioTimeSource).build(),
+        central_controller,
+        requests,
+    );
+    let scan_params = ScanParameters::default();
+    let time_source = TokioTimeSource;
+
+    let application = async {
+        let start = tokio::time::Instant::now();
+        let devices = handle
+            .discover::<_, _, 4>(
+                &mut events,
+                DiscoveryProcedure::General,
+                &scan_params,
+                &time_source,
+            )
+            .await
+            .unwrap();
+        assert!(start.elapsed() >= DISCOVERY_SCAN_DURATION);
+        let mut found = addresses(&devices);
+        found.sort();
+        assert_eq!(
+            found,
+            [PERIPHERAL_ADDRESS, LIMITED_ADDRESS, UNNAMED_ADDRESS]
+        );
+
+        let devices = handle
+            .discover::<_, _, 4>(
+                &mut events,
+                DiscoveryProcedure::Limited,
+                &scan_params,
+                &time_source,
+            )
+            .await
+            .unwrap();
+        assert_eq!(addresses(&devices), [LIMITED_ADDRESS]);
+
+        for (address, expected) in [
+            (
+                PERIPHERAL_ADDRESS,
+                NameDiscovery::Advertised("general".try_into().unwrap()),
+            ),
+            (
+                HIDDEN_ADDRESS,
+                NameDiscovery::Advertised("hidden".try_into().unwrap()),
+            ),
+            (UNNAMED_ADDRESS, NameDiscovery::NotAdvertised),
+            (CENTRAL_ADDRESS, NameDiscovery::NotFound),
+        ] {
+            let name_found = handle
+                .discover_name(
+                    &mut events,
+                    &ConnectionPeerAddress::PublicDevice(PublicDeviceAddress::new(address)),
+                    &scan_params,
+                    &time_source,
+                )
+                .await
+                .unwrap();
+            assert_eq!(name_found, expected);
+        }
+        assert_eq!(
+            events.mask(),
+            HostEventMask::all(),
+            "the mask of the events has not been restored"
+        );
+
+        events.set_mask(HostEventMask::CONNECTION_COMPLETE);
+        tokio::time::timeout(
+            Duration::from_secs(1),
+            handle.discover::<_, _, 4>(
+                &mut events,
+                DiscoveryProcedure::General,
+                &scan_params,
+                &time_source,
+            ),
+        )
+        .await
+        .expect_err("the discovery procedure has not been cancelled");
+        assert_eq!(
+            events.mask(),
+            HostEventMask::CONNECTION_COMPLETE,
+            "the mask of the events has not been restored after cancellation"
+        );
+        handle.stop_scanning().await.unwrap();
+        handle.stop_scanning().await
+    };
+
+    let result = tokio::time::timeout(Duration::from_secs(120), async {
+        tokio::select! {
+            res = Box::pin(runner.run()) => panic!("runner stopped: {res:?}"),
+            res = Box::pin(general_device.run(general_controller)) => panic!("peripheral stopped: {res:?}"),
+            res = Box::pin(limited_device.run(limited_controller)) => panic!("peripheral stopped: {res:?}"),
+            res = Box::pin(hidden_device.run(hidden_controller)) => panic!("peripheral stopped: {res:?}"),
+            res = Box::pin(unnamed_device.run(unnamed_controller)) => panic!("peripheral stopped: {res:?}"),
+            result = Box::pin(application) => result,
+        }
+    })
+    .await
+    .expect("the discovery procedures did not complete in time");
+    assert_eq!(result, Err(Error::InvalidStateForRequest));
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn test_runner_survives_rejected_requests() {
+    let air = VirtualAir::new();
+    let controller = air.add_controller(PublicDeviceAddress::new(CENTRAL_ADDRESS));
+    let controller_handle = controller.handle();
+    let (handle, _events, requests) = HostChannel::new().split();
+    let runner = Runner::new(
+        BleDevice::builder((), TokioTimeSource).build(),
+        controller,
+        requests,
+    );
+    let command_disallowed = Err(Error::Hci(HciError::ErrorCode(
+        ErrorCode::CommandDisallowed,
+    )));
+
+    let application = async {
+        controller_handle.fail_next_command(
+            CommandOpCode::LeCreateConnection,
+            ErrorCode::CommandDisallowed,
+        );
+        let connection_parameters = ConnectionParametersBuilder::new().try_build().unwrap();
+        assert_eq!(
+            handle.connect(&connection_parameters).await,
+            command_disallowed
+        );
+        assert!(!controller_handle.is_initiating());
+        handle.connect(&connection_parameters).await.unwrap();
+        assert!(controller_handle.is_initiating());
+        handle.cancel_connection().await.unwrap();
+
+        handle
+            .start_advertising(
+                &AdvertisingParameters::default(),
+                &FullAdvertisingData::default(),
+            )
+            .await
+            .unwrap();
+        controller_handle.fail_next_command(
+            CommandOpCode::LeSetAdvertisingEnable,
+            ErrorCode::CommandDisallowed,
+        );
+        assert_eq!(handle.stop_advertising().await, command_disallowed);
+        assert!(controller_handle.is_advertising());
+        handle.stop_advertising().await.unwrap();
+        assert!(!controller_handle.is_advertising());
+
+        handle
+            .start_scanning(&ScanParameters::default(), FilterDuplicates::Disabled)
+            .await
+            .unwrap();
+        controller_handle
+            .fail_next_command(CommandOpCode::LeSetScanEnable, ErrorCode::CommandDisallowed);
+        assert_eq!(handle.stop_scanning().await, command_disallowed);
+        assert!(controller_handle.is_scanning());
+        handle.stop_scanning().await.unwrap();
+        assert!(!controller_handle.is_scanning());
+    };
+
+    tokio::time::timeout(Duration::from_secs(10), async {
+        tokio::select! {
+            res = runner.run() => panic!("runner stopped: {res:?}"),
+            _ = application => {}
+        }
+    })
+    .await
+    .expect("the requests were not processed in time");
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn test_direct_connection_establishment() {
+    const ABSENT_ADDRESS: [u8; 6] = [0x03, 0x00, 0x00, 0x00, 0x00, 0x00];
+
+    let air = VirtualAir::new();
+    let central_controller = air.add_controller(PublicDeviceAddress::new(CENTRAL_ADDRESS));
+    let central_controller_handle = central_controller.handle();
+    let peripheral_controller = air.add_controller(PublicDeviceAddress::new(PERIPHERAL_ADDRESS));
+    let mut peripheral_device = BleDevice::builder(Peripheral::default(), TokioTimeSource).build();
+    let (handle, events, requests) = HostChannel::new().split();
+    let runner = Runner::new(
+        BleDevice::builder((), TokioTimeSource).build(),
+        central_controller,
+        requests,
+    );
+    let connection_parameters = ConnectionParametersBuilder::new().try_build().unwrap();
+
+    let application = async {
+        let mut events = events.with_mask(
+            HostEventMask::CONNECTION_COMPLETE | HostEventMask::CONNECTION_ESTABLISHMENT_TIMEOUT,
+        );
+        let start = tokio::time::Instant::now();
+        handle
+            .connect_direct(
+                &ConnectionPeerAddress::PublicDevice(PublicDeviceAddress::new(ABSENT_ADDRESS)),
+                &connection_parameters,
+                Duration::from_secs(5),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            events.next().await,
+            Some(HostEvent::ConnectionEstablishmentTimeout)
+        );
+        assert!(start.elapsed() >= Duration::from_secs(5));
+        assert!(!central_controller_handle.is_initiating());
+        // The cancellation of the connection creation is reported by a failed connection
+        let Some(HostEvent::ConnectionComplete(event)) = events.next().await else {
+            panic!("the cancellation of the connection creation has not been reported");
+        };
+        assert_eq!(event.status(), ErrorCode::UnknownConnectionIdentifier);
+
+        handle
+            .connect_direct(
+                &ConnectionPeerAddress::PublicDevice(PublicDeviceAddress::new(PERIPHERAL_ADDRESS)),
+                &connection_parameters,
+                Duration::from_secs(5),
+            )
+            .await
+            .unwrap();
+        let Some(HostEvent::ConnectionComplete(event)) = events.next().await else {
+            panic!("the central did not get connected");
+        };
+        assert!(event.status().is_success());
+        assert_eq!(event.role(), Role::Central);
+        assert_eq!(
+            event.peer_address(),
+            &PublicDeviceAddress::new(PERIPHERAL_ADDRESS).into()
+        );
+        assert_eq!(
+            handle.cancel_connection().await,
+            Err(Error::InvalidStateForRequest)
+        );
+    };
+
+    tokio::time::timeout(Duration::from_secs(60), async {
+        tokio::select! {
+            res = Box::pin(peripheral_device.run(peripheral_controller)) => panic!("peripheral stopped: {res:?}"),
+            res = Box::pin(runner.run()) => panic!("runner stopped: {res:?}"),
+            _ = application => {},
+        }
+    })
+    .await
+    .expect("the direct connection establishment did not complete in time");
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn test_connection_established_while_cancelling_on_timeout() {
+    let air = VirtualAir::new();
+    let central_controller = air.add_controller(PublicDeviceAddress::new(CENTRAL_ADDRESS));
+    let central_controller_handle = central_controller.handle();
+    let peripheral_controller = air.add_controller(PublicDeviceAddress::new(PERIPHERAL_ADDRESS));
+    let mut peripheral_device = BleDevice::builder(Peripheral::default(), TokioTimeSource).build();
+    let (handle, events, requests) = HostChannel::new().split();
+    let runner = Runner::new(
+        BleDevice::builder((), TokioTimeSource).build(),
+        central_controller,
+        requests,
+    );
+
+    // The peripheral starts advertising between the sending of the LE Create Connection Cancel
+    // command on timeout and its handling by the Controller, that connects first.
+    let peripheral = async {
+        tokio::time::sleep(Duration::from_millis(5050)).await;
+        peripheral_device.run(peripheral_controller).await
+    };
+    let application = async {
+        let mut events = events.with_mask(
+            HostEventMask::CONNECTION_COMPLETE | HostEventMask::CONNECTION_ESTABLISHMENT_TIMEOUT,
+        );
+        handle
+            .connect_direct(
+                &ConnectionPeerAddress::PublicDevice(PublicDeviceAddress::new(PERIPHERAL_ADDRESS)),
+                &ConnectionParametersBuilder::new().try_build().unwrap(),
+                Duration::from_secs(5),
+            )
+            .await
+            .unwrap();
+        central_controller_handle.delay_commands(Duration::from_millis(100));
+        let Some(HostEvent::ConnectionComplete(event)) = events.next().await else {
+            panic!("the central did not get connected");
+        };
+        assert!(event.status().is_success());
+        assert_eq!(event.role(), Role::Central);
+        assert_eq!(central_controller_handle.connection_count(), 1);
+        assert_eq!(
+            handle.cancel_connection().await,
+            Err(Error::InvalidStateForRequest)
+        );
+    };
+
+    tokio::time::timeout(Duration::from_secs(10), async {
+        tokio::select! {
+            res = Box::pin(peripheral) => panic!("peripheral stopped: {res:?}"),
+            res = Box::pin(runner.run()) => panic!("runner stopped: {res:?}"),
+            _ = application => {},
+        }
+    })
+    .await
+    .expect("the connection establishment did not complete in time");
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn test_auto_connection_establishment() {
+    const BONDED_ADDRESS: [u8; 6] = [0x03, 0x00, 0x00, 0x00, 0x00, 0x00];
+
+    let air = VirtualAir::new();
+    let central_controller = air.add_controller(PublicDeviceAddress::new(CENTRAL_ADDRESS));
+    let central_controller_handle = central_controller.handle();
+    let peripheral_controller = air.add_controller(PublicDeviceAddress::new(PERIPHERAL_ADDRESS));
+    let mut peripheral_device = BleDevice::builder(Peripheral::default(), TokioTimeSource).build();
+    let (handle, events, requests) = HostChannel::new().split();
+    let runner = Runner::new(
+        BleDevice::builder((), TokioTimeSource).build(),
+        central_controller,
+        requests,
+    );
+
+    let application = async {
+        let mut events = events.with_mask(HostEventMask::CONNECTION_COMPLETE);
+        handle
+            .connect_auto(
+                &[
+                    PublicDeviceAddress::new(BONDED_ADDRESS).into(),
+                    PublicDeviceAddress::new(PERIPHERAL_ADDRESS).into(),
+                ],
+                &ConnectionParametersBuilder::new().try_build().unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(central_controller_handle.filter_accept_list_len(), 2);
+        let Some(HostEvent::ConnectionComplete(event)) = events.next().await else {
+            panic!("the central did not get connected");
+        };
+        assert!(event.status().is_success());
+        assert_eq!(event.role(), Role::Central);
+        assert_eq!(
+            event.peer_address(),
+            &PublicDeviceAddress::new(PERIPHERAL_ADDRESS).into()
+        );
+    };
+
+    tokio::time::timeout(Duration::from_secs(10), async {
+        tokio::select! {
+            res = Box::pin(peripheral_device.run(peripheral_controller)) => panic!("peripheral stopped: {res:?}"),
+            res = Box::pin(runner.run()) => panic!("runner stopped: {res:?}"),
+            _ = application => {},
+        }
+    })
+    .await
+    .expect("the auto connection establishment did not complete in time");
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn test_selective_connection_establishment() {
+    const OTHER_ADDRESS: [u8; 6] = [0x03, 0x00, 0x00, 0x00, 0x00, 0x00];
+
+    let air = VirtualAir::new();
+    let central_controller = air.add_controller(PublicDeviceAddress::new(CENTRAL_ADDRESS));
+    let peripherals = [PERIPHERAL_ADDRESS, OTHER_ADDRESS].map(|address| {
+        (
+            BleDevice::builder(Peripheral::default(), TokioTimeSource).build(),
+            air.add_controller(PublicDeviceAddress::new(address)),
+        )
+    });
+    let [(mut peripheral_device, peripheral_controller), (mut other_device, other_controller)] =
+        peripherals;
+    let (handle, mut events, requests) = HostChannel::new().split();
+    let runner = Runner::new(
+        BleDevice::builder((), TokioTimeSource).build(),
+        central_controller,
+        requests,
+    );
+
+    let application = async {
+        let selected = handle
+            .connect_selective(
+                &mut events,
+                &ScanParameters::default(),
+                &ConnectionParametersBuilder::new().try_build().unwrap(),
+                Duration::from_secs(5),
+                &TokioTimeSource,
+                |report| report.address().value() == &OTHER_ADDRESS,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            selected,
+            Some(ConnectionPeerAddress::PublicDevice(
+                PublicDeviceAddress::new(OTHER_ADDRESS)
+            ))
+        );
+        events.set_mask(HostEventMask::CONNECTION_COMPLETE);
+        let Some(HostEvent::ConnectionComplete(event)) = events.next().await else {
+            panic!("the central did not get connected");
+        };
+        assert!(event.status().is_success());
+        assert_eq!(
+            event.peer_address(),
+            &PublicDeviceAddress::new(OTHER_ADDRESS).into()
+        );
+    };
+
+    tokio::time::timeout(Duration::from_secs(10), async {
+        tokio::select! {
+            res = Box::pin(peripheral_device.run(peripheral_controller)) => panic!("peripheral stopped: {res:?}"),
+            res = Box::pin(other_device.run(other_controller)) => panic!("peripheral stopped: {res:?}"),
+            res = Box::pin(runner.run()) => panic!("runner stopped: {res:?}"),
+            _ = application => {},
+        }
+    })
+    .await
+    .expect("the selective connection establishment did not complete in time");
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn test_scanning_with_filter() {
+    const OTHER_ADDRESS: [u8; 6] = [0x03, 0x00, 0x00, 0x00, 0x00, 0x00];
+
+    async fn reported_addresses(events: &mut HostEvents) -> Vec<[u8; 6]> {
+        let mut addresses = Vec::new();
+        let _ = tokio::time::timeout(Duration::from_secs(2), async {
+            while let Some(HostEvent::AdvertisingReport(report)) = events.next().await {
+                addresses.push(*report.address().value());
+            }
+        })
+        .await;
+        addresses
+    }
+
+    let air = VirtualAir::new();
+    let central_controller = air.add_controller(PublicDeviceAddress::new(CENTRAL_ADDRESS));
+    let central_controller_handle = central_controller.handle();
+    let peripheral = |address, name| {
+        let controller = air.add_controller(PublicDeviceAddress::new(address));
+        let device = BleDevice::builder(
+            Discoverable {
+                mode: DiscoverableMode::GeneralDiscoverable,
+                advertise_name: true,
+            },
+            TokioTimeSource,
+        )
+        .with_local_name(name)
+        .build();
+        (device, controller)
+    };
+    let (mut matching_device, matching_controller) = peripheral(PERIPHERAL_ADDRESS, "match");
+    let (mut other_device, other_controller) = peripheral(OTHER_ADDRESS, "other");
+    let (handle, events, requests) = HostChannel::new().split();
+    let runner = Runner::new(
+        BleDevice::builder((), TokioTimeSource).build(),
+        central_controller,
+        requests,
+    );
+    let scan_params = ScanParameters::builder()
+        .with_type(ScanType::ActiveScanning)
+        .try_build()
+        .unwrap();
+
+    let application = async {
+        let mut events = events.with_mask(HostEventMask::ADVERTISING_REPORT);
+        let filter = ScanFilter::builder()
+            .with_name_prefix("mat")
+            .unwrap()
+            .with_connectable_only()
+            .build();
+        handle
+            .start_scanning_with_filter(&scan_params, FilterDuplicates::Disabled, &filter)
+            .await
+            .unwrap();
+        let addresses = reported_addresses(&mut events).await;
+        assert!(!addresses.is_empty());
+        assert!(addresses
+            .iter()
+            .all(|address| *address == PERIPHERAL_ADDRESS));
+        assert_eq!(central_controller_handle.filter_accept_list_len(), 0);
+        handle.stop_scanning().await.unwrap();
+
+        let filter = ScanFilter::builder()
+            .with_address(PublicDeviceAddress::new(OTHER_ADDRESS))
+            .unwrap()
+            .with_filter_accept_list()
+            .build();
+        handle
+            .start_scanning_with_filter(&scan_params, FilterDuplicates::Disabled, &filter)
+            .await
+            .unwrap();
+        assert_eq!(central_controller_handle.filter_accept_list_len(), 1);
+        let addresses = reported_addresses(&mut events).await;
+        assert!(!addresses.is_empty());
+        assert!(addresses.iter().all(|address| *address == OTHER_ADDRESS));
+        handle.stop_scanning().await.unwrap();
+
+        handle
+            .start_scanning(&scan_params, FilterDuplicates::Disabled)
+            .await
+            .unwrap();
+        let addresses = reported_addresses(&mut events).await;
+        assert!(addresses.contains(&PERIPHERAL_ADDRESS));
+    };
+
+    tokio::time::timeout(Duration::from_secs(30), async {
+        tokio::select! {
+            res = Box::pin(matching_device.run(matching_controller)) => panic!("peripheral stopped: {res:?}"),
+            res = Box::pin(other_device.run(other_controller)) => panic!("peripheral stopped: {res:?}"),
+            res = Box::pin(runner.run()) => panic!("runner stopped: {res:?}"),
+            _ = application => {},
+        }
+    })
+    .await
+    .expect("the filtered scanning did not complete in time");
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn test_scanned_devices_tracking() {
+    const SCANNED_DEVICE_TIMEOUT: Duration = Duration::from_secs(3);
+
+    let air = VirtualAir::new();
+    let central_controller = air.add_controller(PublicDeviceAddress::new(CENTRAL_ADDRESS));
+    let peripheral_controller = air.add_controller(PublicDeviceAddress::new(PERIPHERAL_ADDRESS));
+    let (central_handle, central_events, central_requests) = HostChannel::new().split();
+    let central_runner = Runner::new(
+        BleDevice::builder((), TokioTimeSource)
+            .with_scanned_device_timeout(SCANNED_DEVICE_TIMEOUT)
+            .build(),
+        central_controller,
+        central_requests,
+    );
+    let (peripheral_handle, _, peripheral_requests) = HostChannel::new().split();
+    let peripheral_runner = Runner::new(
+        BleDevice::builder((), TokioTimeSource).build(),
+        peripheral_controller,
+        peripheral_requests,
+    );
+    let peripheral_address = ConnectionPeerAddress::PublicDevice(PERIPHERAL_ADDRESS.into());
+
+    let application = async {
+        let mut events = central_events.with_mask(HostEventMask::SCANNED_DEVICE);
+        peripheral_handle
+            .start_advertising(
+                &AdvertisingParameters::default(),
+                &FullAdvertisingData::default(),
+            )
+            .await
+            .unwrap();
+        central_handle
+            .start_scanning(&ScanParameters::default(), FilterDuplicates::Disabled)
+            .await
+            .unwrap();
+
+        let Some(HostEvent::ScannedDeviceAppeared(device)) = events.next().await else {
+            panic!("the peripheral has not appeared");
+        };
+        assert_eq!(device.address(), &peripheral_address);
+        assert_eq!(device.advertisement_count(), 1);
+        assert_eq!(
+            device.smoothed_rssi(),
+            device.rssi().map(|rssi| rssi.value() as f32)
+        );
+        let Some(HostEvent::ScannedDeviceUpdated(device)) = events.next().await else {
+            panic!("the peripheral has not been updated");
+        };
+        assert_eq!(device.address(), &peripheral_address);
+        assert_eq!(device.advertisement_count(), 2);
+
+        peripheral_handle.stop_advertising().await.unwrap();
+        let stopped_at = tokio::time::Instant::now();
+        let lost = loop {
+            match events.next().await {
+                Some(HostEvent::ScannedDeviceUpdated(_)) => continue,
+                Some(HostEvent::ScannedDeviceLost(device)) => break device,
+                event => panic!("unexpected event {event:?}"),
+            }
+        };
+        assert_eq!(lost.address(), &peripheral_address);
+        assert!(lost.last_seen() <= stopped_at);
+        assert!(lost.last_seen().elapsed() >= SCANNED_DEVICE_TIMEOUT);
+        assert!(stopped_at.elapsed() <= SCANNED_DEVICE_TIMEOUT);
+    };
+
+    tokio::time::timeout(Duration::from_secs(30), async {
+        tokio::select! {
+            res = Box::pin(central_runner.run()) => panic!("central stopped: {res:?}"),
+            res = Box::pin(peripheral_runner.run()) => panic!("peripheral stopped: {res:?}"),
+            _ = application => {},
+        }
+    })
+    .await
+    .expect("the peripheral has not been tracked in time");
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn test_iso_data_received() {
+    const ISO_DATA: [u8; 12] = [5, 32, 32, 7, 0, 2, 1, 3, 0, 0xAA, 0xBB, 0xCC];
+
+    let air = VirtualAir::new();
+    let controller = air.add_controller(PublicDeviceAddress::new(PERIPHERAL_ADDRESS));
+    let controller_handle = controller.handle();
+    let observer = IsoReceiver::default();
+    let (_handle, events, requests) = HostChannel::new().split();
+    let runner = Runner::new(
+        BleDevice::builder(observer.clone(), TokioTimeSource).build(),
+        controller,
+        requests,
+    );
+    let expected = IsoData::try_new_complete_sdu(
+        ConnectionHandle::try_new(0x0020).unwrap(),
+        None,
+        0x0102,
+        &[0xAA, 0xBB, 0xCC],
+    )
+    .unwrap();
+
+    let application = async {
+        let mut events = events.with_mask(HostEventMask::BIG);
+        wait_until(|| *observer.ready.lock().unwrap()).await;
+        controller_handle.inject_packet(&ISO_DATA);
+        controller_handle.inject_packet(&ISO_DATA);
+        for _ in 0..2 {
+            assert_eq!(
+                events.next().await,
+                Some(HostEvent::IsoDataReceived(expected.clone()))
+            );
+        }
+    };
+
+    tokio::time::timeout(Duration::from_secs(10), async {
+        tokio::select! {
+            res = runner.run() => panic!("runner stopped: {res:?}"),
+            _ = application => {}
+        }
+    })
+    .await
+    .expect("the ISO data was not received in time");
+
+    assert_eq!(
+        *observer.received.lock().unwrap(),
+        [expected.clone(), expected]
+    );
+}
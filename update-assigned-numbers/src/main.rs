@@ -254,6 +254,10 @@ fn generate_appearance_values(
         })
         .collect();
     let appearance_str = appearance_strs.join("\n");
+    let appearance_names: Vec<(String, String)> = flattened_appearance_values
+        .iter()
+        .map(|item| (item.normalized_name.clone(), item.name.clone()))
+        .collect();
 
     let dest_path = output_folder.join("appearance_values.rs");
     fs::write(
@@ -281,8 +285,11 @@ use crate::advertising::AdvertisingError;
 pub enum AppearanceValue {{
 {}
 }}
-"#,
-            revision, appearance_str
+
+{}"#,
+            revision,
+            appearance_str,
+            name_method("AppearanceValue", "appearance", &appearance_names)
         ),
     )?;
 
@@ -372,14 +379,18 @@ fn generate_company_identifiers(
     company_identifiers
         .iter_mut()
         .for_each(|item| item.generate_normalized_name(&mut normalized_names_count_map));
-    let company_identifiers: Vec<String> = company_identifiers
+    let company_identifiers_strs: Vec<String> = company_identifiers
         .iter_mut()
         .map(|item| {
             item.rectify_normalized_name(&normalized_names_count_map);
             item.to_string()
         })
         .collect();
-    let company_identifiers_str = company_identifiers.join("\n");
+    let company_identifiers_str = company_identifiers_strs.join("\n");
+    let company_names: Vec<(String, String)> = company_identifiers
+        .iter()
+        .map(|item| (item.normalized_name.clone(), item.name.clone()))
+        .collect();
 
     let dest_path = output_folder.join("company_identifiers.rs");
     fs::write(
@@ -406,8 +417,11 @@ use crate::advertising::AdvertisingError;
 pub enum CompanyIdentifier {{
 {}
 }}
-"#,
-            revision, company_identifiers_str
+
+{}"#,
+            revision,
+            company_identifiers_str,
+            name_method("CompanyIdentifier", "company", &company_names)
         ),
     )?;
 
@@ -456,6 +470,10 @@ fn generate_service_uuids(
     let yaml: HashMap<String, Vec<ServiceUuid>> = serde_yml::from_str(&yaml_str)?;
     let uuids_strs: Vec<String> = yaml["uuids"].iter().map(|item| item.to_string()).collect();
     let uuids_str = uuids_strs.join("\n");
+    let service_names: Vec<(String, String)> = yaml["uuids"]
+        .iter()
+        .map(|item| (item.normalized_name(), item.name.clone()))
+        .collect();
 
     let dest_path = output_folder.join("service_uuids.rs");
     fs::write(
@@ -482,8 +500,11 @@ use crate::advertising::AdvertisingError;
 pub enum ServiceUuid {{
 {}
 }}
-"#,
-            revision, uuids_str
+
+{}"#,
+            revision,
+            uuids_str,
+            name_method("ServiceUuid", "service", &service_names)
         ),
     )?;
 
@@ -568,6 +589,26 @@ pub enum ProvisionedUriScheme {{
     Ok(())
 }
 
+/// Generate the `name` method of an assigned numbers enum, from its variants and their names.
+fn name_method(enum_name: &str, description: &str, variants: &[(String, String)]) -> String {
+    let arms: Vec<String> = variants
+        .iter()
+        .map(|(variant, name)| format!("\t\t\tSelf::{variant} => {name:?},"))
+        .collect();
+    format!(
+        r#"impl {enum_name} {{
+    /// Get the name of the {description}, as defined in the assigned numbers.
+    pub const fn name(&self) -> &'static str {{
+        match self {{
+{}
+        }}
+    }}
+}}
+"#,
+        arms.join("\n")
+    )
+}
+
 fn rustfmt(file_path: &Path) -> Result<(), anyhow::Error> {
     Command::new("rustfmt")
         .arg("--edition")